@@ -0,0 +1,66 @@
+//! `bridge-cli costs`: aggregate sidecar fee spend from the fee journal.
+
+use clap::Parser;
+use std::{
+    fs::File,
+    path::PathBuf,
+    time::{SystemTime, UNIX_EPOCH},
+};
+use tempo_bridge::costs::{CostReport, parse_window, read_fee_records};
+
+#[derive(Parser, Debug)]
+pub struct CostsArgs {
+    /// Path to the sidecar's fee journal (one JSON receipt record per line).
+    #[arg(long)]
+    pub journal: PathBuf,
+
+    /// Report window, e.g. "24h", "7d", or "2w".
+    #[arg(long, default_value = "7d")]
+    pub window: String,
+
+    /// Report end time as a Unix timestamp. Defaults to now; set it to
+    /// reproduce a historical report.
+    #[arg(long)]
+    pub now: Option<u64>,
+}
+
+impl CostsArgs {
+    pub fn run(self) -> eyre::Result<()> {
+        let window_secs = parse_window(&self.window)?;
+        let now = match self.now {
+            Some(now) => now,
+            None => SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+        };
+
+        let records = read_fee_records(File::open(&self.journal)?)?;
+        let report = CostReport::aggregate(records, now, window_secs);
+
+        if report.buckets.is_empty() {
+            println!("no fee records in the last {}", self.window);
+            return Ok(());
+        }
+
+        println!("fees over the last {}:", self.window);
+        for chain_id in report.chains() {
+            println!("chain {chain_id}:");
+            for ((chain, flow), costs) in &report.buckets {
+                if *chain != chain_id {
+                    continue;
+                }
+                println!(
+                    "  {:<21} {:>6} txs  {} wei",
+                    flow.as_str(),
+                    costs.count,
+                    costs.total_wei
+                );
+            }
+            println!(
+                "  {:<21} {} wei ({} wei/month projected)",
+                "total",
+                report.chain_total_wei(chain_id),
+                report.projected_monthly_wei(chain_id)
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+//! `bridge-cli decode-certificate`: decode a hex finalization certificate.
+//!
+//! Light-client submission failures usually come down to "which block does
+//! this certificate actually finalize, and under which epoch's key?" — this
+//! prints the decoded structure so operators can answer that without a node.
+
+use alloy_primitives::{hex, keccak256};
+use clap::Parser;
+use commonware_codec::{Encode as _, ReadExt as _};
+use commonware_consensus::simplex::{scheme::bls12381_threshold::vrf::Scheme, types::Finalization};
+use commonware_cryptography::{
+    bls12381::primitives::variant::{MinSig, Variant},
+    ed25519::PublicKey,
+};
+use commonware_runtime::{Runner as _, deterministic};
+use tempo_commonware_node::consensus::Digest;
+use tempo_primitives::TempoHeader;
+
+#[derive(Parser, Debug)]
+pub struct DecodeCertificateArgs {
+    /// Hex-encoded finalization certificate (with or without 0x prefix).
+    pub certificate: String,
+
+    /// Hex-encoded BLS identity (threshold group public key) of the epoch that
+    /// produced the certificate. When omitted, the certificate is only
+    /// decoded, not verified.
+    #[arg(long)]
+    pub identity: Option<String>,
+
+    /// Hex-encoded RLP of the finalized header. The certificate commits only
+    /// to the block hash; pass the header to resolve and cross-check the
+    /// height.
+    #[arg(long)]
+    pub header_rlp: Option<String>,
+}
+
+impl DecodeCertificateArgs {
+    pub fn run(self) -> eyre::Result<()> {
+        let bytes = hex::decode(self.certificate.trim_start_matches("0x"))?;
+        let finalization =
+            Finalization::<Scheme<PublicKey, MinSig>, Digest>::read(&mut bytes.as_slice())
+                .map_err(|err| eyre::eyre!("malformed finalization certificate: {err}"))?;
+
+        println!(
+            "epoch:          {}",
+            finalization.proposal.round.epoch().get()
+        );
+        println!(
+            "view:           {}",
+            finalization.proposal.round.view().get()
+        );
+        println!("digest:         {}", finalization.proposal.payload.0);
+        match finalization.certificate.get() {
+            Some(signature) => {
+                println!(
+                    "signature:      {}",
+                    hex::encode(signature.signature.encode())
+                );
+                println!(
+                    "seed signature: {}",
+                    hex::encode(signature.seed_signature.encode())
+                );
+            }
+            None => println!("signature:      <not recovered>"),
+        }
+
+        // Height lives in the header, not the certificate; resolve it when the
+        // operator supplies the header RLP.
+        if let Some(header_rlp) = &self.header_rlp {
+            let header_bytes = hex::decode(header_rlp.trim_start_matches("0x"))?;
+            let block_hash = keccak256(&header_bytes);
+            let header: TempoHeader = alloy_rlp::Decodable::decode(&mut header_bytes.as_slice())
+                .map_err(|err| eyre::eyre!("malformed header RLP: {err}"))?;
+            println!("height:         {}", header.inner.number);
+            if finalization.proposal.payload.0 != block_hash {
+                eyre::bail!(
+                    "certificate finalizes {}, not the supplied header ({block_hash})",
+                    finalization.proposal.payload.0
+                );
+            }
+            println!("✓ certificate binding: finalizes the supplied header");
+        }
+
+        let Some(identity) = self.identity else {
+            println!("- certificate signature: skipped (pass --identity to verify)");
+            return Ok(());
+        };
+        let identity_bytes = hex::decode(identity.trim_start_matches("0x"))?;
+        let identity = <MinSig as Variant>::Public::read(&mut identity_bytes.as_slice())
+            .map_err(|err| eyre::eyre!("invalid BLS identity: {err}"))?;
+
+        let verified = deterministic::Runner::default().start(|mut context| async move {
+            finalization.verify(
+                &mut context,
+                &Scheme::certificate_verifier(tempo_commonware_node::NAMESPACE, identity),
+                &commonware_parallel::Sequential,
+            )
+        });
+        if !verified {
+            eyre::bail!("certificate signature verification failed");
+        }
+        println!("✓ certificate signature: valid threshold signature for provided identity");
+
+        Ok(())
+    }
+}
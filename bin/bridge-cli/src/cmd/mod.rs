@@ -0,0 +1,8 @@
+pub mod costs;
+pub mod decode_certificate;
+pub mod profiles;
+pub mod prune;
+pub mod replay;
+pub mod retry;
+pub mod top;
+pub mod verify_proof;
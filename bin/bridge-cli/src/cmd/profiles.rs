@@ -0,0 +1,44 @@
+//! `bridge-cli profiles`: inspect the environment profiles file.
+
+use crate::profile::ProfileOpts;
+use clap::Parser;
+
+#[derive(Parser, Debug)]
+pub struct ProfilesArgs {
+    /// Profile to show resolved, with any override flags applied. When
+    /// omitted, lists every profile in the file.
+    pub name: Option<String>,
+}
+
+impl ProfilesArgs {
+    pub fn run(self, mut opts: ProfileOpts) -> eyre::Result<()> {
+        let store = opts.store()?;
+
+        let Some(name) = self.name.or(opts.profile.take()) else {
+            let (path, _) = opts.profiles_path()?;
+            if store.profiles.is_empty() {
+                println!("no profiles defined in {}", path.display());
+                return Ok(());
+            }
+            println!("profiles in {}:", path.display());
+            for (name, profile) in &store.profiles {
+                println!(
+                    "  {name}: chain {} via {} (escrow {})",
+                    profile.chain_id, profile.rpc_url, profile.escrow
+                );
+            }
+            return Ok(());
+        };
+
+        opts.profile = Some(name);
+        let resolved = opts.resolve_with(&store)?;
+        println!(
+            "profile {}:",
+            resolved.profile.as_deref().expect("profile was set")
+        );
+        println!("  rpc_url:  {}", resolved.rpc_url);
+        println!("  chain_id: {}", resolved.chain_id);
+        println!("  escrow:   {}", resolved.escrow);
+        Ok(())
+    }
+}
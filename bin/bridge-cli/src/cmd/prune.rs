@@ -0,0 +1,40 @@
+//! `bridge-cli prune`: apply the retention policy to a deposit state file
+//! offline.
+
+use clap::Parser;
+use std::path::PathBuf;
+use tempo_bridge::deposit_expiry::{DEFAULT_RETENTION_BLOCKS, StateManager};
+
+#[derive(Parser, Debug)]
+pub struct PruneArgs {
+    /// Path to the sidecar's deposit state JSON (written by the bridge ExEx).
+    #[arg(long)]
+    pub state: PathBuf,
+
+    /// Current origin block height (the retention window is measured back
+    /// from here).
+    #[arg(long)]
+    pub block: u64,
+
+    /// Origin blocks a completed deposit record is retained. Pending and
+    /// expired deposits are always kept.
+    #[arg(long, default_value_t = DEFAULT_RETENTION_BLOCKS)]
+    pub retention_blocks: u64,
+}
+
+impl PruneArgs {
+    pub fn run(self) -> eyre::Result<()> {
+        let mut manager = StateManager::load(&self.state)?;
+
+        let pruned = manager.prune(self.block, self.retention_blocks);
+        if pruned > 0 {
+            manager.save(&self.state)?;
+        }
+        println!(
+            "pruned {pruned} completed deposit records ({} remaining, {} pruned lifetime)",
+            manager.len(),
+            manager.pruned_total()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+//! `bridge-cli replay`: reconstruct the causal timeline of a deposit or burn
+//! from the sidecar's journals.
+
+use alloy_primitives::B256;
+use clap::Parser;
+use std::{fs::File, path::PathBuf};
+use tempo_bridge::{
+    audit_log::read_audit_records, deposit_expiry::StateManager, replay::build_trace,
+    unlock_submitter::UnlockJournal,
+};
+
+#[derive(Parser, Debug)]
+pub struct ReplayArgs {
+    /// Path to the sidecar's deposit state JSON (written by the bridge ExEx).
+    #[arg(long)]
+    pub state: PathBuf,
+
+    /// Path to the signature audit log. Read without chain verification so a
+    /// tampered log can still be replayed; verify it separately.
+    #[arg(long)]
+    pub audit_log: Option<PathBuf>,
+
+    /// Path to the unlock journal, for the burn side of the flow.
+    #[arg(long)]
+    pub unlock_journal: Option<PathBuf>,
+
+    /// Deposit or burn id to replay.
+    pub id: B256,
+}
+
+impl ReplayArgs {
+    pub fn run(self) -> eyre::Result<()> {
+        let manager = StateManager::load(&self.state)?;
+        let state = manager.get(self.id);
+
+        let audit_records = match &self.audit_log {
+            Some(path) => read_audit_records(File::open(path)?)?,
+            None => Vec::new(),
+        };
+
+        let unlock_journal = self
+            .unlock_journal
+            .as_ref()
+            .map(UnlockJournal::open)
+            .transpose()?;
+        let unlock = unlock_journal.as_ref().and_then(|j| j.get(self.id));
+
+        match build_trace(self.id, state, &audit_records, unlock) {
+            Some(trace) => print!("{trace}"),
+            None => eyre::bail!("no journal knows deposit or burn {}", self.id),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,74 @@
+//! `bridge-cli retry`: re-pend an expired deposit for another signature
+//! collection round.
+
+use alloy_primitives::B256;
+use clap::Parser;
+use std::path::PathBuf;
+use tempo_bridge::deposit_expiry::{DepositState, StateManager};
+
+#[derive(Parser, Debug)]
+pub struct RetryArgs {
+    /// Path to the sidecar's deposit state JSON (written by the bridge ExEx).
+    #[arg(long)]
+    pub state: PathBuf,
+
+    /// Deposit id to retry.
+    pub deposit: B256,
+
+    /// Current origin block height (restarts the expiry clock from here).
+    #[arg(long)]
+    pub block: u64,
+
+    /// Restart signature collection under a new validator-set epoch instead
+    /// of the stranded one. Use after validator churn left the deposit short
+    /// of threshold.
+    #[arg(long)]
+    pub escalate: bool,
+
+    /// Validator-set epoch to escalate into. Required with `--escalate`.
+    #[arg(long, required_if_eq("escalate", "true"))]
+    pub epoch: Option<u64>,
+}
+
+impl RetryArgs {
+    pub fn run(self) -> eyre::Result<()> {
+        let mut manager = StateManager::load(&self.state)?;
+
+        if self.escalate {
+            let epoch = self.epoch.expect("clap enforces --epoch with --escalate");
+            manager.escalate(self.deposit, self.block, epoch)?;
+            println!(
+                "deposit {} re-pended under epoch {epoch} at block {}",
+                self.deposit, self.block
+            );
+        } else {
+            manager.retry(self.deposit, self.block)?;
+            let Some(DepositState::Pending {
+                validator_epoch, ..
+            }) = manager.get(self.deposit)
+            else {
+                unreachable!("retry leaves the deposit pending");
+            };
+            println!(
+                "deposit {} re-pended under its original epoch {validator_epoch} at block {}",
+                self.deposit, self.block
+            );
+        }
+
+        if let Some(progress) = manager.progress(self.deposit) {
+            match progress.weighted {
+                Some((signed, required)) => println!(
+                    "signature progress: weight {signed} of {required} ({} signatures)",
+                    progress.signatures
+                ),
+                None => println!(
+                    "signature progress: {} of {} signatures",
+                    progress.signatures, progress.threshold
+                ),
+            }
+        }
+
+        manager.save(&self.state)?;
+        Ok(())
+    }
+}
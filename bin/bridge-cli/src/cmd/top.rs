@@ -0,0 +1,342 @@
+//! `bridge-cli top`: live terminal dashboard over the sidecar's health endpoint.
+
+use clap::Parser;
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    execute,
+    terminal::{EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode},
+};
+use ratatui::{
+    Frame, Terminal,
+    backend::CrosstermBackend,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Paragraph, Row, Table},
+};
+use std::{
+    io,
+    time::{Duration, Instant},
+};
+use tempo_bridge::health::HealthSnapshot;
+
+/// How long to block on terminal events between redraws.
+const EVENT_POLL: Duration = Duration::from_millis(250);
+
+#[derive(Parser, Debug)]
+pub struct TopArgs {
+    /// URL of the sidecar's health endpoint.
+    #[arg(long, default_value = "http://127.0.0.1:9656/health")]
+    pub health_url: String,
+
+    /// Seconds between refreshes.
+    #[arg(long, default_value_t = 2)]
+    pub refresh: u64,
+}
+
+impl TopArgs {
+    pub async fn run(self) -> eyre::Result<()> {
+        enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen)?;
+        let mut terminal = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+
+        // Run the loop to completion before restoring the terminal, so a
+        // fetch or render error still leaves the operator's shell usable.
+        let result = self.event_loop(&mut terminal).await;
+
+        disable_raw_mode()?;
+        execute!(io::stdout(), LeaveAlternateScreen)?;
+        result
+    }
+
+    async fn event_loop(
+        &self,
+        terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    ) -> eyre::Result<()> {
+        let client = reqwest::Client::new();
+        let refresh = Duration::from_secs(self.refresh.max(1));
+        let mut app = App::default();
+        let mut next_refresh = Instant::now();
+
+        loop {
+            if Instant::now() >= next_refresh {
+                match fetch_snapshot(&client, &self.health_url).await {
+                    Ok(snapshot) => {
+                        app.snapshot = Some(snapshot);
+                        app.fetch_error = None;
+                    }
+                    Err(err) => app.fetch_error = Some(err.to_string()),
+                }
+                next_refresh = Instant::now() + refresh;
+            }
+
+            terminal.draw(|frame| draw(frame, &app))?;
+
+            if event::poll(EVENT_POLL)?
+                && let Event::Key(key) = event::read()?
+                && key.kind == KeyEventKind::Press
+            {
+                let ctrl_c =
+                    key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL);
+                if matches!(key.code, KeyCode::Char('q') | KeyCode::Esc) || ctrl_c {
+                    return Ok(());
+                }
+                if key.code == KeyCode::Char('r') {
+                    next_refresh = Instant::now();
+                }
+            }
+        }
+    }
+}
+
+/// Fetches and decodes one snapshot from the health endpoint.
+async fn fetch_snapshot(client: &reqwest::Client, url: &str) -> eyre::Result<HealthSnapshot> {
+    let body = client
+        .get(url)
+        .send()
+        .await?
+        .error_for_status()?
+        .text()
+        .await?;
+    Ok(serde_json::from_str(&body)?)
+}
+
+/// Dashboard state between redraws: the last good snapshot plus the error
+/// from the most recent fetch, if it failed. Keeping the stale snapshot on
+/// screen lets the operator keep reading while the sidecar restarts.
+#[derive(Default)]
+struct App {
+    snapshot: Option<HealthSnapshot>,
+    fetch_error: Option<String>,
+}
+
+fn draw(frame: &mut Frame<'_>, app: &App) {
+    let [header, body] =
+        Layout::vertical([Constraint::Length(1), Constraint::Min(0)]).areas(frame.area());
+
+    frame.render_widget(Paragraph::new(header_line(app)), header);
+
+    let Some(snapshot) = &app.snapshot else {
+        frame.render_widget(
+            Paragraph::new("waiting for the first snapshot... (q to quit)"),
+            body,
+        );
+        return;
+    };
+
+    let [top, middle, bottom] = Layout::vertical([
+        Constraint::Ratio(1, 3),
+        Constraint::Ratio(1, 3),
+        Constraint::Ratio(1, 3),
+    ])
+    .areas(body);
+    let [chains, deposits] =
+        Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)]).areas(top);
+    let [burns, unlocks] =
+        Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)]).areas(middle);
+    let [errors, balances] =
+        Layout::horizontal([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)]).areas(bottom);
+
+    draw_chains(frame, chains, snapshot);
+    draw_deposits(frame, deposits, snapshot);
+    draw_burns(frame, burns, snapshot);
+    draw_unlocks(frame, unlocks, snapshot);
+    draw_errors(frame, errors, snapshot);
+    draw_balances(frame, balances, snapshot);
+}
+
+fn header_line(app: &App) -> Line<'static> {
+    let mut spans = vec![Span::styled(
+        "bridge top",
+        Style::default().add_modifier(Modifier::BOLD),
+    )];
+    if let Some(snapshot) = &app.snapshot {
+        spans.push(Span::raw(format!(
+            "  up {}  {} deposits in flight",
+            format_age(snapshot.uptime_secs),
+            snapshot.total_deposits()
+        )));
+    }
+    match &app.fetch_error {
+        Some(err) => spans.push(Span::styled(
+            format!("  fetch failed: {err}"),
+            Style::default().fg(Color::Red),
+        )),
+        None => spans.push(Span::raw("  q quit, r refresh")),
+    }
+    Line::from(spans)
+}
+
+fn draw_chains(frame: &mut Frame<'_>, area: Rect, snapshot: &HealthSnapshot) {
+    let rows = snapshot.chains.iter().map(|chain| {
+        let lag = chain.lag();
+        let style = if lag > 10 {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default()
+        };
+        Row::new(vec![
+            chain.name.clone(),
+            chain.chain_id.to_string(),
+            chain.cursor.to_string(),
+            chain.head.to_string(),
+            lag.to_string(),
+        ])
+        .style(style)
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Min(10),
+            Constraint::Length(8),
+            Constraint::Length(12),
+            Constraint::Length(12),
+            Constraint::Length(8),
+        ],
+    )
+    .header(Row::new(vec!["chain", "id", "cursor", "head", "lag"]).style(header_style()))
+    .block(Block::bordered().title("chain cursors"));
+    frame.render_widget(table, area);
+}
+
+fn draw_deposits(frame: &mut Frame<'_>, area: Rect, snapshot: &HealthSnapshot) {
+    let rows = snapshot
+        .deposits_by_state
+        .iter()
+        .map(|(state, count)| Row::new(vec![state.clone(), count.to_string()]));
+    let table = Table::new(rows, [Constraint::Min(12), Constraint::Length(8)])
+        .header(Row::new(vec!["state", "count"]).style(header_style()))
+        .block(Block::bordered().title("deposits by state"));
+    frame.render_widget(table, area);
+}
+
+fn draw_burns(frame: &mut Frame<'_>, area: Rect, snapshot: &HealthSnapshot) {
+    let rows = snapshot.recent_burns.iter().map(|burn| {
+        Row::new(vec![
+            short_hash(burn.burn_id.as_slice()),
+            burn.origin_chain_id.to_string(),
+            burn.amount.to_string(),
+            burn.state.clone(),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(14),
+            Constraint::Length(8),
+            Constraint::Min(12),
+            Constraint::Length(10),
+        ],
+    )
+    .header(Row::new(vec!["burn", "chain", "amount", "state"]).style(header_style()))
+    .block(Block::bordered().title("recent burns"));
+    frame.render_widget(table, area);
+}
+
+fn draw_unlocks(frame: &mut Frame<'_>, area: Rect, snapshot: &HealthSnapshot) {
+    let rows = snapshot.unlock_queue.iter().map(|unlock| {
+        Row::new(vec![
+            short_hash(unlock.burn_id.as_slice()),
+            unlock.origin_chain_id.to_string(),
+            format_age(unlock.queued_secs),
+            unlock.attempts.to_string(),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(14),
+            Constraint::Length(8),
+            Constraint::Length(10),
+            Constraint::Length(10),
+        ],
+    )
+    .header(Row::new(vec!["burn", "chain", "queued", "attempts"]).style(header_style()))
+    .block(Block::bordered().title("unlock queue"));
+    frame.render_widget(table, area);
+}
+
+fn draw_errors(frame: &mut Frame<'_>, area: Rect, snapshot: &HealthSnapshot) {
+    let rows = snapshot
+        .error_counters
+        .iter()
+        .map(|(kind, count)| Row::new(vec![kind.clone(), count.to_string()]));
+    let table = Table::new(rows, [Constraint::Min(16), Constraint::Length(8)])
+        .header(Row::new(vec!["error", "count"]).style(header_style()))
+        .block(Block::bordered().title(format!("errors ({} total)", snapshot.total_errors())));
+    frame.render_widget(table, area);
+}
+
+fn draw_balances(frame: &mut Frame<'_>, area: Rect, snapshot: &HealthSnapshot) {
+    let rows = snapshot.balances.iter().map(|balance| {
+        Row::new(vec![
+            balance.role.clone(),
+            short_hash(balance.address.as_slice()),
+            balance.chain_id.to_string(),
+            format!("{} wei", balance.balance_wei),
+        ])
+    });
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(12),
+            Constraint::Length(14),
+            Constraint::Length(8),
+            Constraint::Min(16),
+        ],
+    )
+    .header(Row::new(vec!["role", "address", "chain", "balance"]).style(header_style()))
+    .block(Block::bordered().title("balances"));
+    frame.render_widget(table, area);
+}
+
+fn header_style() -> Style {
+    Style::default().add_modifier(Modifier::BOLD)
+}
+
+/// Renders a duration in seconds as the largest two units, e.g. `3m12s`.
+fn format_age(secs: u64) -> String {
+    if secs >= 86_400 {
+        format!("{}d{}h", secs / 86_400, (secs % 86_400) / 3600)
+    } else if secs >= 3600 {
+        format!("{}h{}m", secs / 3600, (secs % 3600) / 60)
+    } else if secs >= 60 {
+        format!("{}m{}s", secs / 60, secs % 60)
+    } else {
+        format!("{secs}s")
+    }
+}
+
+/// Renders the first and last four bytes of a hash or address, e.g.
+/// `1111..2222`.
+fn short_hash(bytes: &[u8]) -> String {
+    let head: String = bytes.iter().take(4).map(|b| format!("{b:02x}")).collect();
+    let tail: String = bytes
+        .iter()
+        .rev()
+        .take(4)
+        .rev()
+        .map(|b| format!("{b:02x}"))
+        .collect();
+    format!("{head}..{tail}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::b256;
+
+    #[test]
+    fn format_age_picks_the_largest_two_units() {
+        assert_eq!(format_age(45), "45s");
+        assert_eq!(format_age(192), "3m12s");
+        assert_eq!(format_age(3660), "1h1m");
+        assert_eq!(format_age(90_000), "1d1h");
+    }
+
+    #[test]
+    fn short_hash_keeps_both_ends() {
+        let id = b256!("0x1111111100000000000000000000000000000000000000000000000022222222");
+        assert_eq!(short_hash(id.as_slice()), "11111111..22222222");
+    }
+}
@@ -0,0 +1,79 @@
+//! `bridge-cli verify-proof`: offline verification of a burn proof JSON.
+
+use alloy_primitives::{hex, keccak256};
+use clap::Parser;
+use commonware_codec::ReadExt as _;
+use commonware_consensus::simplex::{scheme::bls12381_threshold::vrf::Scheme, types::Finalization};
+use commonware_cryptography::{
+    bls12381::primitives::variant::{MinSig, Variant},
+    ed25519::PublicKey,
+};
+use commonware_runtime::{Runner as _, deterministic};
+use std::path::PathBuf;
+use tempo_bridge::proof::{BurnProof, verify_burn_proof};
+use tempo_commonware_node::consensus::Digest;
+
+#[derive(Parser, Debug)]
+pub struct VerifyProofArgs {
+    /// Path to the burn proof JSON (receipt RLP, proof nodes, header,
+    /// finalization certificate).
+    pub proof: PathBuf,
+
+    /// Hex-encoded BLS identity (threshold public key) of the epoch that
+    /// finalized the block. When omitted, the certificate signature is not
+    /// checked, only its binding to the block hash.
+    #[arg(long)]
+    pub identity: Option<String>,
+}
+
+impl VerifyProofArgs {
+    pub fn run(self) -> eyre::Result<()> {
+        let proof: BurnProof = serde_json::from_slice(&std::fs::read(&self.proof)?)?;
+
+        // Steps 1-2: header decoding and receipt trie inclusion.
+        for step in verify_burn_proof(&proof)? {
+            println!("✓ {}: {}", step.name, step.detail);
+        }
+
+        // Step 3: the certificate must finalize exactly this block hash.
+        let block_hash = keccak256(&proof.header_rlp);
+        let finalization = Finalization::<Scheme<PublicKey, MinSig>, Digest>::read(
+            &mut proof.finalization_certificate.as_ref(),
+        )
+        .map_err(|err| eyre::eyre!("malformed finalization certificate: {err}"))?;
+        if finalization.proposal.payload.0 != block_hash {
+            eyre::bail!(
+                "certificate finalizes {}, not the proof's block {block_hash}",
+                finalization.proposal.payload.0
+            );
+        }
+        println!(
+            "✓ certificate binding: finalizes block {block_hash} (round {:?})",
+            finalization.proposal.round
+        );
+
+        // Step 4: threshold signature, if the identity is known.
+        let Some(identity) = self.identity else {
+            println!("- certificate signature: skipped (pass --identity to verify)");
+            return Ok(());
+        };
+        let identity_bytes = hex::decode(identity.trim_start_matches("0x"))?;
+        let identity = <MinSig as Variant>::Public::read(&mut identity_bytes.as_slice())
+            .map_err(|err| eyre::eyre!("invalid BLS identity: {err}"))?;
+
+        let verified = deterministic::Runner::default().start(|mut context| async move {
+            finalization.verify(
+                &mut context,
+                &Scheme::certificate_verifier(tempo_commonware_node::NAMESPACE, identity),
+                &commonware_parallel::Sequential,
+            )
+        });
+        if !verified {
+            eyre::bail!("certificate signature verification failed");
+        }
+        println!("✓ certificate signature: valid threshold signature for provided identity");
+
+        println!("burn proof OK");
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use crate::opts::{BridgeCli, BridgeCliSubcommand};
+use clap::Parser;
+
+mod cmd;
+mod opts;
+mod profile;
+
+#[tokio::main]
+async fn main() -> eyre::Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(tracing_subscriber::EnvFilter::from_default_env())
+        .init();
+
+    let args = BridgeCli::parse();
+
+    match args.cmd {
+        BridgeCliSubcommand::VerifyProof(cmd) => cmd.run(),
+        BridgeCliSubcommand::Retry(cmd) => cmd.run(),
+        BridgeCliSubcommand::DecodeCertificate(cmd) => cmd.run(),
+        BridgeCliSubcommand::Prune(cmd) => cmd.run(),
+        BridgeCliSubcommand::Costs(cmd) => cmd.run(),
+        BridgeCliSubcommand::Replay(cmd) => cmd.run(),
+        BridgeCliSubcommand::Profiles(cmd) => cmd.run(args.profile),
+        BridgeCliSubcommand::Top(cmd) => cmd.run().await,
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{
+    cmd::{
+        costs::CostsArgs, decode_certificate::DecodeCertificateArgs, profiles::ProfilesArgs,
+        prune::PruneArgs, replay::ReplayArgs, retry::RetryArgs, top::TopArgs,
+        verify_proof::VerifyProofArgs,
+    },
+    profile::ProfileOpts,
+};
+use clap::{Parser, Subcommand};
+
+#[derive(Parser, Debug)]
+#[command(version, about, long_about = None)]
+pub struct BridgeCli {
+    /// Environment profile selection and per-invocation overrides.
+    #[command(flatten)]
+    pub profile: ProfileOpts,
+
+    #[command(subcommand)]
+    pub cmd: BridgeCliSubcommand,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum BridgeCliSubcommand {
+    /// Verify a burn proof offline against its header and finalization certificate.
+    VerifyProof(VerifyProofArgs),
+    /// Re-pend an expired deposit, optionally escalating to a new validator-set epoch.
+    Retry(RetryArgs),
+    /// Decode a hex finalization certificate and optionally verify its signature.
+    DecodeCertificate(DecodeCertificateArgs),
+    /// Prune completed deposit records older than the retention window.
+    Prune(PruneArgs),
+    /// Report per-flow, per-chain fee spend from the sidecar's fee journal.
+    Costs(CostsArgs),
+    /// Reconstruct the causal timeline of a deposit or burn from the
+    /// sidecar's journals.
+    Replay(ReplayArgs),
+    /// List environment profiles, or show one resolved with overrides applied.
+    Profiles(ProfilesArgs),
+    /// Live terminal dashboard polling the sidecar's health endpoint.
+    Top(TopArgs),
+}
@@ -0,0 +1,277 @@
+//! Named environment profiles for `bridge-cli`.
+//!
+//! A profiles file maps environment names (`mainnet`, `testnet`, `local`, ...)
+//! to the origin RPC URL, chain id, and escrow contract address for that
+//! environment, so operators select `--profile testnet` instead of repeating
+//! the full flag list — and cannot accidentally mix a mainnet escrow with a
+//! testnet RPC. Individual fields can still be overridden per invocation with
+//! `--rpc-url`, `--chain-id`, and `--escrow`.
+//!
+//! The file lives at `<config dir>/tempo/bridge-profiles.toml` by default and
+//! can be relocated with `--profiles-file` or `TEMPO_BRIDGE_PROFILES`:
+//!
+//! ```toml
+//! [profiles.mainnet]
+//! rpc_url = "wss://eth.example"
+//! chain_id = 1
+//! escrow = "0x1111111111111111111111111111111111111111"
+//!
+//! [profiles.testnet]
+//! rpc_url = "wss://sepolia.example"
+//! chain_id = 11155111
+//! escrow = "0x2222222222222222222222222222222222222222"
+//! ```
+
+use alloy_primitives::Address;
+use clap::Args;
+use serde::Deserialize;
+use std::{collections::BTreeMap, path::PathBuf};
+
+/// Environment variable naming an alternative profiles file.
+pub const PROFILES_FILE_ENV: &str = "TEMPO_BRIDGE_PROFILES";
+
+/// One environment's settings, as stored in the profiles file.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Profile {
+    /// Origin-chain RPC endpoint.
+    pub rpc_url: String,
+    /// Origin chain id.
+    pub chain_id: u64,
+    /// Escrow contract address on the origin chain.
+    pub escrow: Address,
+}
+
+/// The parsed profiles file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProfileStore {
+    /// Profiles keyed by environment name. `BTreeMap` so listings are sorted.
+    #[serde(default)]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+impl ProfileStore {
+    /// Parses a profiles file from its TOML source.
+    pub fn from_toml_str(source: &str) -> eyre::Result<Self> {
+        Ok(toml::from_str(source)?)
+    }
+
+    /// Loads the profiles file at `path`, or an empty store if the default
+    /// location is simply absent (`explicit` marks a user-supplied path, which
+    /// must exist).
+    pub fn load(path: &PathBuf, explicit: bool) -> eyre::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(source) => Self::from_toml_str(&source)
+                .map_err(|err| eyre::eyre!("malformed profiles file {}: {err}", path.display())),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound && !explicit => {
+                Ok(Self::default())
+            }
+            Err(err) => Err(eyre::eyre!(
+                "cannot read profiles file {}: {err}",
+                path.display()
+            )),
+        }
+    }
+
+    /// Looks up `name`, listing the available profiles on a miss.
+    pub fn get(&self, name: &str) -> eyre::Result<&Profile> {
+        self.profiles.get(name).ok_or_else(|| {
+            let available = self
+                .profiles
+                .keys()
+                .map(String::as_str)
+                .collect::<Vec<_>>()
+                .join(", ");
+            if available.is_empty() {
+                eyre::eyre!("unknown profile {name:?}: the profiles file defines none")
+            } else {
+                eyre::eyre!("unknown profile {name:?}: available profiles are {available}")
+            }
+        })
+    }
+}
+
+/// Profile selection and per-invocation overrides, available on every
+/// subcommand.
+#[derive(Args, Debug, Default)]
+pub struct ProfileOpts {
+    /// Named environment profile to load settings from.
+    #[arg(long, global = true, env = "TEMPO_BRIDGE_PROFILE")]
+    pub profile: Option<String>,
+
+    /// Path to the profiles file. Defaults to
+    /// `<config dir>/tempo/bridge-profiles.toml`.
+    #[arg(long, global = true, env = PROFILES_FILE_ENV)]
+    pub profiles_file: Option<PathBuf>,
+
+    /// Override the profile's origin RPC endpoint for this invocation.
+    #[arg(long, global = true)]
+    pub rpc_url: Option<String>,
+
+    /// Override the profile's origin chain id for this invocation.
+    #[arg(long, global = true)]
+    pub chain_id: Option<u64>,
+
+    /// Override the profile's escrow contract address for this invocation.
+    #[arg(long, global = true)]
+    pub escrow: Option<Address>,
+}
+
+/// A profile with per-invocation overrides applied — what a command actually
+/// connects to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResolvedEnv {
+    /// Name of the underlying profile, or `None` when assembled purely from
+    /// override flags.
+    pub profile: Option<String>,
+    /// Origin-chain RPC endpoint.
+    pub rpc_url: String,
+    /// Origin chain id.
+    pub chain_id: u64,
+    /// Escrow contract address on the origin chain.
+    pub escrow: Address,
+}
+
+impl ProfileOpts {
+    /// The profiles file location: the explicit flag/env value, else the
+    /// default under the user's config directory.
+    pub fn profiles_path(&self) -> eyre::Result<(PathBuf, bool)> {
+        if let Some(path) = &self.profiles_file {
+            return Ok((path.clone(), true));
+        }
+        let dir = dirs_next::config_dir()
+            .ok_or_else(|| eyre::eyre!("no config directory; pass --profiles-file"))?;
+        Ok((dir.join("tempo").join("bridge-profiles.toml"), false))
+    }
+
+    /// Loads the profiles file selected by these options.
+    pub fn store(&self) -> eyre::Result<ProfileStore> {
+        let (path, explicit) = self.profiles_path()?;
+        ProfileStore::load(&path, explicit)
+    }
+
+    /// Resolves the environment: the selected profile with overrides applied,
+    /// or — with no `--profile` — an environment assembled entirely from the
+    /// override flags, all of which are then required.
+    pub fn resolve(&self) -> eyre::Result<ResolvedEnv> {
+        if self.profile.is_some() {
+            return self.resolve_with(&self.store()?);
+        }
+        self.resolve_with(&ProfileStore::default())
+    }
+
+    /// [`Self::resolve`] against an already-loaded store.
+    pub fn resolve_with(&self, store: &ProfileStore) -> eyre::Result<ResolvedEnv> {
+        if let Some(name) = &self.profile {
+            let profile = store.get(name)?;
+            return Ok(ResolvedEnv {
+                profile: Some(name.clone()),
+                rpc_url: self
+                    .rpc_url
+                    .clone()
+                    .unwrap_or_else(|| profile.rpc_url.clone()),
+                chain_id: self.chain_id.unwrap_or(profile.chain_id),
+                escrow: self.escrow.unwrap_or(profile.escrow),
+            });
+        }
+
+        let missing = |flag| eyre::eyre!("pass --profile or give {flag} explicitly");
+        Ok(ResolvedEnv {
+            profile: None,
+            rpc_url: self.rpc_url.clone().ok_or_else(|| missing("--rpc-url"))?,
+            chain_id: self.chain_id.ok_or_else(|| missing("--chain-id"))?,
+            escrow: self.escrow.ok_or_else(|| missing("--escrow"))?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = r#"
+[profiles.mainnet]
+rpc_url = "wss://eth.example"
+chain_id = 1
+escrow = "0x1111111111111111111111111111111111111111"
+
+[profiles.testnet]
+rpc_url = "wss://sepolia.example"
+chain_id = 11155111
+escrow = "0x2222222222222222222222222222222222222222"
+"#;
+
+    fn opts(profile: Option<&str>) -> ProfileOpts {
+        ProfileOpts {
+            profile: profile.map(str::to_owned),
+            ..ProfileOpts::default()
+        }
+    }
+
+    #[test]
+    fn parses_and_lists_sorted() {
+        let store = ProfileStore::from_toml_str(EXAMPLE).unwrap();
+        assert_eq!(
+            store.profiles.keys().collect::<Vec<_>>(),
+            ["mainnet", "testnet"]
+        );
+        assert_eq!(store.get("mainnet").unwrap().chain_id, 1);
+    }
+
+    #[test]
+    fn unknown_profile_names_the_alternatives() {
+        let store = ProfileStore::from_toml_str(EXAMPLE).unwrap();
+        let err = store.get("staging").unwrap_err().to_string();
+        assert!(err.contains("mainnet, testnet"), "{err}");
+    }
+
+    #[test]
+    fn unknown_field_is_rejected() {
+        let err = ProfileStore::from_toml_str(
+            "[profiles.local]\nrpc_url = \"ws://localhost\"\nchain_id = 1\n\
+             escrow = \"0x1111111111111111111111111111111111111111\"\ntypo = 1\n",
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(err.contains("typo"), "{err}");
+    }
+
+    #[test]
+    fn overrides_win_over_the_profile() {
+        let store = ProfileStore::from_toml_str(EXAMPLE).unwrap();
+
+        let plain = opts(Some("testnet")).resolve_with(&store).unwrap();
+        assert_eq!(plain.rpc_url, "wss://sepolia.example");
+        assert_eq!(plain.chain_id, 11155111);
+
+        let overridden = ProfileOpts {
+            rpc_url: Some("wss://other.example".into()),
+            chain_id: Some(42),
+            ..opts(Some("testnet"))
+        }
+        .resolve_with(&store)
+        .unwrap();
+        assert_eq!(overridden.profile.as_deref(), Some("testnet"));
+        assert_eq!(overridden.rpc_url, "wss://other.example");
+        assert_eq!(overridden.chain_id, 42);
+        // Untouched fields still come from the profile.
+        assert_eq!(overridden.escrow, store.get("testnet").unwrap().escrow);
+    }
+
+    #[test]
+    fn no_profile_requires_every_flag() {
+        let err = opts(None).resolve().unwrap_err().to_string();
+        assert!(err.contains("--rpc-url"), "{err}");
+
+        let full = ProfileOpts {
+            rpc_url: Some("ws://localhost:8546".into()),
+            chain_id: Some(31337),
+            escrow: Some(Address::with_last_byte(3)),
+            ..opts(None)
+        };
+        let resolved = full.resolve().unwrap();
+        assert_eq!(resolved.profile, None);
+        assert_eq!(resolved.chain_id, 31337);
+    }
+}
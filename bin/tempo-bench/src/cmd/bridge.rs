@@ -0,0 +1,575 @@
+//! Bridge end-to-end latency benchmark.
+//!
+//! Repeatedly performs deposit→mint and burn→unlock cycles against a devnet
+//! (Anvil origin chain + Tempo node + bridge sidecar) and reports the
+//! distribution of end-to-end latencies broken down by pipeline stage
+//! (detection, signing, threshold, finalization, relay), to catch regressions
+//! in the sidecar pipeline.
+//!
+//! Stage timestamps come from the sidecar's per-operation status endpoint
+//! (`GET {sidecar_url}/operations/{tx_hash}`); the benchmark only measures
+//! submission time locally so clock skew between bench host and sidecar
+//! cancels out of every stage except detection.
+
+use alloy::{
+    network::EthereumWallet,
+    primitives::{Address, B256, U256, utils::format_units},
+    providers::{Provider, ProviderBuilder},
+    signers::local::PrivateKeySigner,
+    sol,
+    transports::http::reqwest::{Client, Url},
+};
+use clap::Parser;
+use eyre::{Context, OptionExt, eyre};
+use reth_tracing::{
+    RethTracer, Tracer,
+    tracing::{info, warn},
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::File,
+    io::BufWriter,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+use tokio::time::sleep;
+
+sol! {
+    #[sol(rpc)]
+    interface IBenchEscrow {
+        /// Locks the attached value for `recipient` on Tempo.
+        function deposit(address recipient) external payable;
+    }
+
+    #[sol(rpc)]
+    interface IBenchBridgeToken {
+        /// Burns bridged funds, unlocking them for `recipient` on the origin chain.
+        function burn(address recipient, uint256 amount) external;
+    }
+}
+
+/// Default Anvil dev account #0 private key.
+const ANVIL_DEV_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+/// Interval between sidecar status polls.
+const STATUS_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Run bridge deposit→mint / burn→unlock latency benchmarking
+#[derive(Parser, Debug)]
+pub struct BridgeBenchArgs {
+    /// Origin chain (Anvil) RPC endpoint.
+    #[arg(long, default_value = "http://localhost:8546")]
+    origin_url: Url,
+
+    /// Tempo node RPC endpoint.
+    #[arg(long, default_value = "http://localhost:8545")]
+    tempo_url: Url,
+
+    /// Bridge sidecar status API endpoint.
+    #[arg(long, default_value = "http://localhost:9650")]
+    sidecar_url: Url,
+
+    /// Escrow contract address on the origin chain.
+    #[arg(long)]
+    escrow: Address,
+
+    /// Bridged token address on Tempo used for the burn leg.
+    #[arg(long)]
+    bridge_token: Address,
+
+    /// Number of deposit→mint→burn→unlock cycles to run.
+    #[arg(short, long, default_value_t = 20)]
+    cycles: usize,
+
+    /// Amount per deposit in origin-chain wei.
+    #[arg(long, default_value_t = 1_000_000_000_000_000u128)]
+    amount: u128,
+
+    /// Private key used on both chains (defaults to Anvil dev account #0).
+    #[arg(long, default_value = ANVIL_DEV_KEY)]
+    private_key: String,
+
+    /// Per-operation timeout in seconds before a cycle is counted as failed.
+    #[arg(long, default_value_t = 120)]
+    timeout: u64,
+
+    /// Skip the burn→unlock leg and only benchmark deposits.
+    #[arg(long)]
+    deposits_only: bool,
+
+    /// Write the raw per-cycle stage samples as JSON to this path.
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+/// Pipeline stages reported by the sidecar, in order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Stage {
+    /// Submission → sidecar observes the origin/Tempo event.
+    Detection,
+    /// Detection → local threshold share produced.
+    Signing,
+    /// Local share → threshold certificate assembled.
+    Threshold,
+    /// Certificate → consensus finalization of the authorization.
+    Finalization,
+    /// Finalization → mint/unlock transaction lands on the target chain.
+    Relay,
+}
+
+impl Stage {
+    const ALL: [Self; 5] = [
+        Self::Detection,
+        Self::Signing,
+        Self::Threshold,
+        Self::Finalization,
+        Self::Relay,
+    ];
+
+    const fn name(&self) -> &'static str {
+        match self {
+            Self::Detection => "detection",
+            Self::Signing => "signing",
+            Self::Threshold => "threshold",
+            Self::Finalization => "finalization",
+            Self::Relay => "relay",
+        }
+    }
+}
+
+/// Bridge direction for one benchmark leg.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum Direction {
+    DepositMint,
+    BurnUnlock,
+}
+
+impl Direction {
+    const fn name(&self) -> &'static str {
+        match self {
+            Self::DepositMint => "deposit→mint",
+            Self::BurnUnlock => "burn→unlock",
+        }
+    }
+}
+
+/// Per-operation stage timestamps reported by the sidecar, in unix millis.
+///
+/// Fields are `None` while the operation has not reached the stage yet.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct OperationStatus {
+    detected_at: Option<u64>,
+    signed_at: Option<u64>,
+    threshold_at: Option<u64>,
+    finalized_at: Option<u64>,
+    relayed_at: Option<u64>,
+}
+
+impl OperationStatus {
+    fn is_complete(&self) -> bool {
+        self.relayed_at.is_some()
+    }
+
+    /// Splits the completed pipeline into per-stage durations.
+    ///
+    /// `submitted_at` is the bench-local submission wall-clock in unix millis; it only
+    /// feeds the detection stage, which therefore includes bench↔sidecar clock skew.
+    fn stage_durations(&self, submitted_at: u64) -> eyre::Result<Vec<(Stage, Duration)>> {
+        let detected = self.detected_at.ok_or_eyre("missing detectedAt")?;
+        let signed = self.signed_at.ok_or_eyre("missing signedAt")?;
+        let threshold = self.threshold_at.ok_or_eyre("missing thresholdAt")?;
+        let finalized = self.finalized_at.ok_or_eyre("missing finalizedAt")?;
+        let relayed = self.relayed_at.ok_or_eyre("missing relayedAt")?;
+
+        let checkpoints = [
+            submitted_at,
+            detected,
+            signed,
+            threshold,
+            finalized,
+            relayed,
+        ];
+        if checkpoints.windows(2).any(|pair| pair[1] < pair[0]) {
+            return Err(eyre!(
+                "sidecar reported non-monotonic stage timestamps: {checkpoints:?}"
+            ));
+        }
+
+        Ok(Stage::ALL
+            .iter()
+            .zip(checkpoints.windows(2))
+            .map(|(stage, pair)| (*stage, Duration::from_millis(pair[1] - pair[0])))
+            .collect())
+    }
+}
+
+/// One completed cycle leg, as written to `--output`.
+#[derive(Debug, Serialize)]
+struct CycleSample {
+    cycle: usize,
+    direction: Direction,
+    tx_hash: B256,
+    stages: Vec<(Stage, Duration)>,
+    end_to_end: Duration,
+}
+
+/// Collected samples for one (direction, stage) pair.
+#[derive(Debug, Default)]
+struct LatencySummary {
+    samples: Vec<Duration>,
+}
+
+impl LatencySummary {
+    fn record(&mut self, sample: Duration) {
+        self.samples.push(sample);
+    }
+
+    fn percentile(&self, pct: f64) -> Duration {
+        let mut sorted = self.samples.clone();
+        sorted.sort_unstable();
+        if sorted.is_empty() {
+            return Duration::ZERO;
+        }
+        let rank = ((pct / 100.0) * (sorted.len() - 1) as f64).round() as usize;
+        sorted[rank.min(sorted.len() - 1)]
+    }
+
+    fn max(&self) -> Duration {
+        self.samples.iter().copied().max().unwrap_or_default()
+    }
+}
+
+impl BridgeBenchArgs {
+    pub async fn run(self) -> eyre::Result<()> {
+        RethTracer::new().init()?;
+
+        let signer: PrivateKeySigner = self.private_key.parse().context("invalid --private-key")?;
+        let recipient = signer.address();
+        let wallet = EthereumWallet::from(signer);
+
+        let origin_provider = ProviderBuilder::new()
+            .wallet(wallet.clone())
+            .connect_http(self.origin_url.clone());
+        let tempo_provider = ProviderBuilder::new()
+            .wallet(wallet)
+            .connect_http(self.tempo_url.clone());
+
+        let origin_chain_id = origin_provider.get_chain_id().await?;
+        let tempo_chain_id = tempo_provider.get_chain_id().await?;
+        info!(
+            origin_chain_id,
+            tempo_chain_id,
+            escrow = %self.escrow,
+            bridge_token = %self.bridge_token,
+            cycles = self.cycles,
+            "Starting bridge latency benchmark"
+        );
+
+        let escrow = IBenchEscrow::new(self.escrow, origin_provider);
+        let token = IBenchBridgeToken::new(self.bridge_token, tempo_provider);
+        let status_client = Client::new();
+        let timeout = Duration::from_secs(self.timeout);
+        let amount = U256::from(self.amount);
+
+        let mut summaries: Vec<((Direction, Stage), LatencySummary)> = Vec::new();
+        let mut end_to_end: Vec<(Direction, LatencySummary)> = Vec::new();
+        let mut cycle_samples = Vec::new();
+        let mut failures = 0usize;
+
+        for cycle in 0..self.cycles {
+            // Deposit→mint leg.
+            let submitted_at = unix_millis();
+            let pending = escrow
+                .deposit(recipient)
+                .value(amount)
+                .send()
+                .await
+                .context("failed to send deposit")?;
+            let tx_hash = *pending.tx_hash();
+            pending
+                .watch()
+                .await
+                .context("deposit transaction not mined")?;
+
+            match self
+                .await_pipeline(&status_client, tx_hash, submitted_at, timeout)
+                .await
+            {
+                Ok(sample) => record_sample(
+                    &mut summaries,
+                    &mut end_to_end,
+                    &mut cycle_samples,
+                    cycle,
+                    Direction::DepositMint,
+                    tx_hash,
+                    sample,
+                ),
+                Err(err) => {
+                    warn!(cycle, %tx_hash, %err, "deposit→mint cycle failed");
+                    failures += 1;
+                    continue;
+                }
+            }
+
+            if self.deposits_only {
+                continue;
+            }
+
+            // Burn→unlock leg, returning the just-minted funds.
+            let submitted_at = unix_millis();
+            let pending = token
+                .burn(recipient, amount)
+                .send()
+                .await
+                .context("failed to send burn")?;
+            let tx_hash = *pending.tx_hash();
+            pending
+                .watch()
+                .await
+                .context("burn transaction not mined")?;
+
+            match self
+                .await_pipeline(&status_client, tx_hash, submitted_at, timeout)
+                .await
+            {
+                Ok(sample) => record_sample(
+                    &mut summaries,
+                    &mut end_to_end,
+                    &mut cycle_samples,
+                    cycle,
+                    Direction::BurnUnlock,
+                    tx_hash,
+                    sample,
+                ),
+                Err(err) => {
+                    warn!(cycle, %tx_hash, %err, "burn→unlock cycle failed");
+                    failures += 1;
+                }
+            }
+        }
+
+        self.report(&summaries, &end_to_end, failures)?;
+
+        if let Some(path) = &self.output {
+            let writer = BufWriter::new(File::create(path)?);
+            serde_json::to_writer_pretty(writer, &cycle_samples)?;
+            info!(path = %path.display(), samples = cycle_samples.len(), "Wrote raw samples");
+        }
+
+        if failures > 0 {
+            return Err(eyre!("{failures} cycle legs failed or timed out"));
+        }
+        Ok(())
+    }
+
+    /// Polls the sidecar until the operation for `tx_hash` completes, returning
+    /// its per-stage durations.
+    async fn await_pipeline(
+        &self,
+        client: &Client,
+        tx_hash: B256,
+        submitted_at: u64,
+        timeout: Duration,
+    ) -> eyre::Result<Vec<(Stage, Duration)>> {
+        let url = self
+            .sidecar_url
+            .join(&format!("operations/{tx_hash}"))
+            .context("invalid sidecar URL")?;
+        let started = Instant::now();
+
+        loop {
+            if started.elapsed() > timeout {
+                return Err(eyre!("pipeline did not complete within {timeout:?}"));
+            }
+
+            let response = client.get(url.clone()).send().await;
+            match response {
+                // 404 means the sidecar has not detected the operation yet.
+                Ok(response) if response.status().is_success() => {
+                    let status: OperationStatus = response.json().await?;
+                    if status.is_complete() {
+                        return status.stage_durations(submitted_at);
+                    }
+                }
+                Ok(_) => {}
+                Err(err) => warn!(%err, "sidecar status request failed; retrying"),
+            }
+
+            sleep(STATUS_POLL_INTERVAL).await;
+        }
+    }
+
+    fn report(
+        &self,
+        summaries: &[((Direction, Stage), LatencySummary)],
+        end_to_end: &[(Direction, LatencySummary)],
+        failures: usize,
+    ) -> eyre::Result<()> {
+        for direction in [Direction::DepositMint, Direction::BurnUnlock] {
+            if self.deposits_only && direction == Direction::BurnUnlock {
+                continue;
+            }
+
+            info!("=== {} stage latencies ===", direction.name());
+            for stage in Stage::ALL {
+                let Some((_, summary)) =
+                    summaries.iter().find(|(key, _)| *key == (direction, stage))
+                else {
+                    continue;
+                };
+                info!(
+                    "{:>13}: p50 {:>8.1?}  p90 {:>8.1?}  p99 {:>8.1?}  max {:>8.1?}  (n={})",
+                    stage.name(),
+                    summary.percentile(50.0),
+                    summary.percentile(90.0),
+                    summary.percentile(99.0),
+                    summary.max(),
+                    summary.samples.len(),
+                );
+            }
+
+            if let Some((_, summary)) = end_to_end.iter().find(|(key, _)| *key == direction) {
+                info!(
+                    "{:>13}: p50 {:>8.1?}  p90 {:>8.1?}  p99 {:>8.1?}  max {:>8.1?}  (n={})",
+                    "end-to-end",
+                    summary.percentile(50.0),
+                    summary.percentile(90.0),
+                    summary.percentile(99.0),
+                    summary.max(),
+                    summary.samples.len(),
+                );
+            }
+        }
+
+        info!(
+            failures,
+            amount = %format_units(U256::from(self.amount), "ether").unwrap_or_default(),
+            "Bridge latency benchmark finished"
+        );
+        Ok(())
+    }
+}
+
+fn record_sample(
+    summaries: &mut Vec<((Direction, Stage), LatencySummary)>,
+    end_to_end: &mut Vec<(Direction, LatencySummary)>,
+    cycle_samples: &mut Vec<CycleSample>,
+    cycle: usize,
+    direction: Direction,
+    tx_hash: B256,
+    stages: Vec<(Stage, Duration)>,
+) {
+    let total: Duration = stages.iter().map(|(_, duration)| *duration).sum();
+
+    for (stage, duration) in &stages {
+        match summaries
+            .iter_mut()
+            .find(|(key, _)| *key == (direction, *stage))
+        {
+            Some((_, summary)) => summary.record(*duration),
+            None => {
+                let mut summary = LatencySummary::default();
+                summary.record(*duration);
+                summaries.push(((direction, *stage), summary));
+            }
+        }
+    }
+    match end_to_end.iter_mut().find(|(key, _)| *key == direction) {
+        Some((_, summary)) => summary.record(total),
+        None => {
+            let mut summary = LatencySummary::default();
+            summary.record(total);
+            end_to_end.push((direction, summary));
+        }
+    }
+
+    cycle_samples.push(CycleSample {
+        cycle,
+        direction,
+        tx_hash,
+        stages,
+        end_to_end: total,
+    });
+}
+
+/// Current wall clock in unix milliseconds.
+fn unix_millis() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("system clock before unix epoch")
+        .as_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(
+        detected: u64,
+        signed: u64,
+        threshold: u64,
+        finalized: u64,
+        relayed: u64,
+    ) -> OperationStatus {
+        OperationStatus {
+            detected_at: Some(detected),
+            signed_at: Some(signed),
+            threshold_at: Some(threshold),
+            finalized_at: Some(finalized),
+            relayed_at: Some(relayed),
+        }
+    }
+
+    #[test]
+    fn test_stage_durations_split_pipeline() {
+        let stages = status(1_100, 1_150, 1_400, 1_900, 2_000)
+            .stage_durations(1_000)
+            .expect("complete pipeline");
+
+        assert_eq!(
+            stages,
+            vec![
+                (Stage::Detection, Duration::from_millis(100)),
+                (Stage::Signing, Duration::from_millis(50)),
+                (Stage::Threshold, Duration::from_millis(250)),
+                (Stage::Finalization, Duration::from_millis(500)),
+                (Stage::Relay, Duration::from_millis(100)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_stage_durations_reject_non_monotonic_timestamps() {
+        status(1_100, 1_050, 1_400, 1_900, 2_000)
+            .stage_durations(1_000)
+            .expect_err("signed before detected must be rejected");
+    }
+
+    #[test]
+    fn test_incomplete_status_is_not_complete() {
+        let mut incomplete = status(1_100, 1_150, 1_400, 1_900, 2_000);
+        incomplete.relayed_at = None;
+        assert!(!incomplete.is_complete());
+        incomplete
+            .stage_durations(1_000)
+            .expect_err("missing relay timestamp must be rejected");
+    }
+
+    #[test]
+    fn test_latency_summary_percentiles() {
+        let mut summary = LatencySummary::default();
+        for millis in [10u64, 20, 30, 40, 50, 60, 70, 80, 90, 100] {
+            summary.record(Duration::from_millis(millis));
+        }
+
+        assert_eq!(summary.percentile(50.0), Duration::from_millis(50));
+        assert_eq!(summary.percentile(90.0), Duration::from_millis(90));
+        assert_eq!(summary.percentile(99.0), Duration::from_millis(100));
+        assert_eq!(summary.max(), Duration::from_millis(100));
+
+        assert_eq!(LatencySummary::default().percentile(50.0), Duration::ZERO);
+    }
+}
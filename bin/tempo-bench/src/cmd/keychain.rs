@@ -0,0 +1,515 @@
+//! AccountKeychain workload benchmark (TIP-1011).
+//!
+//! Provisions a large number of access keys with varied restrictions
+//! (unrestricted, expiring, spending-limited, call-scoped, use-limited) and
+//! then issues TIP-20 transfers signed by those keys, measuring
+//! `sendRawTransaction` latency (which includes pool-side keychain signature
+//! validation) and execution gas against a root-signed baseline, to catch
+//! regressions in the TIP-1011 enforcement path.
+
+use alloy::{
+    eips::Encodable2718,
+    network::ReceiptResponse,
+    primitives::{Address, U256},
+    providers::{DynProvider, Provider, ProviderBuilder},
+    signers::{
+        SignerSync,
+        local::{MnemonicBuilder, PrivateKeySigner, coins_bip39::English},
+    },
+    sol_types::SolCall,
+    transports::http::reqwest::Url,
+};
+use clap::Parser;
+use eyre::Context;
+use futures::{StreamExt, stream};
+use reth_tracing::{
+    RethTracer, Tracer,
+    tracing::{debug, info},
+};
+use serde::Serialize;
+use std::{
+    fs::File,
+    io::BufWriter,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+use tempo_alloy::{
+    TempoNetwork,
+    provider::keychain::{CallScopeBuilder, KeyRestrictions, authorize_key},
+};
+use tempo_contracts::precompiles::ITIP20;
+use tempo_precompiles::tip_fee_manager::DEFAULT_FEE_TOKEN;
+use tempo_primitives::{
+    SignatureType, TempoTransaction, TempoTxEnvelope,
+    transaction::{
+        TokenLimit,
+        tempo_transaction::Call,
+        tt_signature::{KeychainSignature, PrimitiveSignature, TempoSignature},
+    },
+};
+
+/// Default test mnemonic (Anvil's), whose accounts the devnet faucet funds.
+const TEST_MNEMONIC: &str = "test test test test test test test test test test test junk";
+
+/// Run AccountKeychain provisioning + scoped-call benchmarking
+#[derive(Parser, Debug)]
+pub struct KeychainBenchArgs {
+    /// Tempo node RPC endpoint.
+    #[arg(long, default_value = "http://localhost:8545")]
+    target_url: Url,
+
+    /// Number of root accounts to derive from the mnemonic.
+    #[arg(short, long, default_value_t = 10)]
+    accounts: u32,
+
+    /// Mnemonic for deriving root accounts.
+    #[arg(short, long, default_value = TEST_MNEMONIC)]
+    mnemonic: String,
+
+    /// Access keys to provision per root account.
+    #[arg(short, long, default_value_t = 100)]
+    keys_per_account: usize,
+
+    /// Transactions to send per provisioned access key.
+    #[arg(short, long, default_value_t = 4)]
+    calls_per_key: usize,
+
+    /// Fee token used for fees, spending limits, and transfers.
+    #[arg(long, default_value_t = DEFAULT_FEE_TOKEN)]
+    fee_token: Address,
+
+    /// Maximum in-flight RPC requests.
+    #[arg(long, default_value_t = 100)]
+    max_concurrent_requests: usize,
+
+    /// Gas limit for key-provisioning transactions.
+    #[arg(long, default_value_t = 1_000_000)]
+    authorize_gas_limit: u64,
+
+    /// Gas limit for transfer transactions.
+    #[arg(long, default_value_t = 300_000)]
+    transfer_gas_limit: u64,
+
+    /// Fund the root accounts via `tempo_fundAddress` before the run.
+    #[arg(long)]
+    faucet: bool,
+
+    /// Per-receipt timeout in seconds.
+    #[arg(long, default_value_t = 30)]
+    timeout: u64,
+
+    /// Write the raw per-phase samples as JSON to this path.
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+/// One timed transaction: submission latency plus receipt-reported gas.
+#[derive(Clone, Copy, Debug, Serialize)]
+struct TxSample {
+    /// `eth_sendRawTransaction` round-trip in microseconds. Pool validation
+    /// (signature recovery, keychain lookup, scope checks) happens inline, so
+    /// this is the validation-overhead signal.
+    send_latency_us: u64,
+    /// Gas charged at execution.
+    gas_used: u64,
+}
+
+/// Aggregated stats for one phase.
+#[derive(Debug, Serialize)]
+struct PhaseStats {
+    phase: &'static str,
+    transactions: usize,
+    failed: usize,
+    elapsed_secs: f64,
+    tps: f64,
+    send_latency_us_p50: u64,
+    send_latency_us_p95: u64,
+    gas_used_p50: u64,
+    gas_used_p95: u64,
+    gas_used_avg: u64,
+}
+
+#[derive(Serialize)]
+struct KeychainBenchReport {
+    accounts: u32,
+    keys_per_account: usize,
+    calls_per_key: usize,
+    phases: Vec<PhaseStats>,
+    provisioning: Vec<TxSample>,
+    scoped_calls: Vec<TxSample>,
+    baseline_calls: Vec<TxSample>,
+}
+
+/// A root account with its locally tracked sequential nonce.
+struct RootAccount {
+    signer: PrivateKeySigner,
+    nonce: u64,
+}
+
+impl KeychainBenchArgs {
+    pub async fn run(self) -> eyre::Result<()> {
+        RethTracer::new().init()?;
+
+        let provider: DynProvider<TempoNetwork> =
+            ProviderBuilder::new_with_network::<TempoNetwork>()
+                .connect_http(self.target_url.clone())
+                .erased();
+        let chain_id = provider.get_chain_id().await?;
+        let gas_price = provider.get_gas_price().await?;
+
+        let mut roots = Vec::with_capacity(self.accounts as usize);
+        for index in 0..self.accounts {
+            let signer = MnemonicBuilder::<English>::default()
+                .phrase(self.mnemonic.as_str())
+                .index(index)?
+                .build()?;
+            roots.push(RootAccount { signer, nonce: 0 });
+        }
+
+        if self.faucet {
+            info!(accounts = roots.len(), "Funding root accounts from faucet");
+            for root in &roots {
+                provider
+                    .raw_request::<_, Vec<alloy::primitives::B256>>(
+                        "tempo_fundAddress".into(),
+                        (root.signer.address(),),
+                    )
+                    .await
+                    .context("Failed to fund account from faucet")?;
+            }
+        }
+
+        for root in &mut roots {
+            root.nonce = provider
+                .get_transaction_count(root.signer.address())
+                .await?;
+        }
+
+        // Phase 1: provision keys with restrictions cycling through the
+        // TIP-1011 feature set. Every variant still permits the phase-2
+        // transfer so enforcement cost is measured on the success path.
+        let mut access_keys = Vec::new();
+        let mut provisioning_txs = Vec::new();
+        for root in &mut roots {
+            let recipient = root.signer.address();
+            for key_index in 0..self.keys_per_account {
+                let key_signer = PrivateKeySigner::random();
+                let restrictions = self.key_restrictions(key_index, recipient);
+                let tx = TempoTransaction {
+                    chain_id,
+                    max_priority_fee_per_gas: gas_price,
+                    max_fee_per_gas: gas_price,
+                    gas_limit: self.authorize_gas_limit,
+                    calls: vec![authorize_key(
+                        key_signer.address(),
+                        SignatureType::Secp256k1,
+                        restrictions,
+                    )],
+                    nonce_key: U256::ZERO,
+                    nonce: root.nonce,
+                    fee_token: Some(self.fee_token),
+                    fee_token_preferences: vec![],
+                    fee_payer_signature: None,
+                    valid_before: None,
+                    valid_after: None,
+                    access_list: Default::default(),
+                    key_authorization: None,
+                    tempo_authorization_list: vec![],
+                    unknown_fields: Default::default(),
+                };
+                root.nonce += 1;
+                provisioning_txs.push(encode_root_signed(tx, &root.signer)?);
+                access_keys.push((key_signer, recipient));
+            }
+        }
+
+        info!(keys = access_keys.len(), "Provisioning access keys");
+        let (provisioning, provisioning_stats) = self
+            .send_phase("provisioning", &provider, provisioning_txs)
+            .await?;
+
+        // Phase 2: transfers signed by the provisioned access keys. The
+        // keychain wraps each signature, so the pool resolves the root
+        // account and enforces scopes/limits per transaction.
+        let mut scoped_txs = Vec::new();
+        for root in &mut roots {
+            let root_addr = root.signer.address();
+            for (key_signer, recipient) in access_keys
+                .iter()
+                .filter(|(_, recipient)| *recipient == root_addr)
+            {
+                for _ in 0..self.calls_per_key {
+                    let tx = self.transfer_tx(chain_id, gas_price, root.nonce, *recipient);
+                    root.nonce += 1;
+                    scoped_txs.push(encode_key_signed(tx, key_signer, root_addr)?);
+                }
+            }
+        }
+
+        info!(
+            transactions = scoped_txs.len(),
+            "Sending key-signed transfers"
+        );
+        let (scoped_calls, scoped_stats) = self
+            .send_phase("scoped_calls", &provider, scoped_txs)
+            .await?;
+
+        // Phase 3: identical transfers signed by the root keys, as the
+        // no-keychain baseline the scoped numbers are compared against.
+        let mut baseline_txs = Vec::new();
+        for root in &mut roots {
+            let recipient = root.signer.address();
+            for _ in 0..self.calls_per_key {
+                let tx = self.transfer_tx(chain_id, gas_price, root.nonce, recipient);
+                root.nonce += 1;
+                baseline_txs.push(encode_root_signed(tx, &root.signer)?);
+            }
+        }
+
+        info!(
+            transactions = baseline_txs.len(),
+            "Sending root-signed baseline transfers"
+        );
+        let (baseline_calls, baseline_stats) = self
+            .send_phase("baseline_calls", &provider, baseline_txs)
+            .await?;
+
+        let overhead_gas = scoped_stats
+            .gas_used_p50
+            .saturating_sub(baseline_stats.gas_used_p50);
+        let overhead_latency_us = scoped_stats
+            .send_latency_us_p50
+            .saturating_sub(baseline_stats.send_latency_us_p50);
+        info!(
+            overhead_gas,
+            overhead_latency_us, "Keychain enforcement overhead (p50, scoped vs baseline)"
+        );
+
+        let report = KeychainBenchReport {
+            accounts: self.accounts,
+            keys_per_account: self.keys_per_account,
+            calls_per_key: self.calls_per_key,
+            phases: vec![provisioning_stats, scoped_stats, baseline_stats],
+            provisioning,
+            scoped_calls,
+            baseline_calls,
+        };
+        if let Some(path) = &self.output {
+            let writer = BufWriter::new(File::create(path)?);
+            serde_json::to_writer_pretty(writer, &report)?;
+            info!(path = %path.display(), "Wrote keychain benchmark report");
+        }
+
+        Ok(())
+    }
+
+    /// Restrictions for the `key_index`-th key of an account, cycling through
+    /// the TIP-1011 feature set.
+    fn key_restrictions(&self, key_index: usize, recipient: Address) -> KeyRestrictions {
+        let far_future = u64::MAX / 2;
+        let generous_limit = U256::from(u128::MAX);
+        match key_index % 5 {
+            // Unrestricted key: the cheapest possible keychain path.
+            0 => KeyRestrictions::default(),
+            // Expiring key with no other restrictions.
+            1 => KeyRestrictions::default().with_expiry(far_future),
+            // Spending-limited key (limit checked and decremented per transfer).
+            2 => KeyRestrictions::default().with_limits(vec![TokenLimit {
+                token: self.fee_token,
+                limit: generous_limit,
+                period: 0,
+            }]),
+            // Call-scoped key: transfer on the fee token, any recipient.
+            3 => KeyRestrictions::default().with_allowed_calls(vec![
+                CallScopeBuilder::new(self.fee_token)
+                    .transfer(vec![])
+                    .build(),
+            ]),
+            // Everything at once: recipient-scoped, spending- and use-limited.
+            _ => KeyRestrictions::default()
+                .with_expiry(far_future)
+                .with_limits(vec![TokenLimit {
+                    token: self.fee_token,
+                    limit: generous_limit,
+                    period: 0,
+                }])
+                .with_allowed_calls(vec![
+                    CallScopeBuilder::new(self.fee_token)
+                        .transfer(vec![recipient])
+                        .build(),
+                ])
+                .with_max_uses(self.calls_per_key as u64),
+        }
+    }
+
+    /// A minimal TIP-20 transfer transaction paying fees in the fee token.
+    fn transfer_tx(
+        &self,
+        chain_id: u64,
+        gas_price: u128,
+        nonce: u64,
+        recipient: Address,
+    ) -> TempoTransaction {
+        TempoTransaction {
+            chain_id,
+            max_priority_fee_per_gas: gas_price,
+            max_fee_per_gas: gas_price,
+            gas_limit: self.transfer_gas_limit,
+            calls: vec![Call {
+                to: self.fee_token.into(),
+                value: U256::ZERO,
+                input: ITIP20::transferCall {
+                    to: recipient,
+                    amount: U256::ONE,
+                }
+                .abi_encode()
+                .into(),
+            }],
+            nonce_key: U256::ZERO,
+            nonce,
+            fee_token: Some(self.fee_token),
+            fee_token_preferences: vec![],
+            fee_payer_signature: None,
+            valid_before: None,
+            valid_after: None,
+            access_list: Default::default(),
+            key_authorization: None,
+            tempo_authorization_list: vec![],
+            unknown_fields: Default::default(),
+        }
+    }
+
+    /// Sends the pre-encoded transactions of one phase, timing each
+    /// submission, then collects receipts and aggregates stats.
+    async fn send_phase(
+        &self,
+        phase: &'static str,
+        provider: &DynProvider<TempoNetwork>,
+        transactions: Vec<Vec<u8>>,
+    ) -> eyre::Result<(Vec<TxSample>, PhaseStats)> {
+        let total = transactions.len();
+        let started = Instant::now();
+
+        let pending = stream::iter(transactions)
+            .map(|bytes| async move {
+                let send_started = Instant::now();
+                let result = provider.send_raw_transaction(&bytes).await;
+                (send_started.elapsed(), result)
+            })
+            .buffer_unordered(self.max_concurrent_requests)
+            .collect::<Vec<_>>()
+            .await;
+
+        let mut samples = Vec::with_capacity(total);
+        let mut failed = 0usize;
+        let receipt_timeout = Duration::from_secs(self.timeout);
+        let receipts = stream::iter(pending)
+            .map(|(latency, result)| async move {
+                let pending_tx = match result {
+                    Ok(pending_tx) => pending_tx,
+                    Err(err) => {
+                        debug!(?err, "Transaction submission failed");
+                        return (latency, None);
+                    }
+                };
+                let receipt = pending_tx
+                    .with_timeout(Some(receipt_timeout))
+                    .get_receipt()
+                    .await;
+                (latency, receipt.ok())
+            })
+            .buffer_unordered(self.max_concurrent_requests)
+            .collect::<Vec<_>>()
+            .await;
+
+        for (latency, receipt) in receipts {
+            match receipt {
+                Some(receipt) if receipt.status() => samples.push(TxSample {
+                    send_latency_us: latency.as_micros() as u64,
+                    gas_used: receipt.gas_used(),
+                }),
+                _ => failed += 1,
+            }
+        }
+
+        let stats = summarize(phase, &samples, failed, started.elapsed());
+        info!(
+            phase,
+            transactions = stats.transactions,
+            failed = stats.failed,
+            tps = format!("{:.1}", stats.tps),
+            send_latency_us_p50 = stats.send_latency_us_p50,
+            send_latency_us_p95 = stats.send_latency_us_p95,
+            gas_used_p50 = stats.gas_used_p50,
+            gas_used_p95 = stats.gas_used_p95,
+            "Phase complete"
+        );
+        Ok((samples, stats))
+    }
+}
+
+/// Signs and encodes a transaction with the account's root key.
+fn encode_root_signed(tx: TempoTransaction, signer: &impl SignerSync) -> eyre::Result<Vec<u8>> {
+    let signature = signer.sign_hash_sync(&tx.signature_hash())?;
+    let signature = TempoSignature::Primitive(PrimitiveSignature::Secp256k1(signature));
+    let envelope: TempoTxEnvelope = tx.into_signed(signature).into();
+    Ok(envelope.encoded_2718())
+}
+
+/// Signs and encodes a transaction with a keychain access key on behalf of
+/// `root_addr`.
+fn encode_key_signed(
+    tx: TempoTransaction,
+    key_signer: &impl SignerSync,
+    root_addr: Address,
+) -> eyre::Result<Vec<u8>> {
+    let sig_hash = KeychainSignature::signing_hash(tx.signature_hash(), root_addr);
+    let signature = key_signer.sign_hash_sync(&sig_hash)?;
+    let signature = TempoSignature::Keychain(KeychainSignature::new(
+        root_addr,
+        PrimitiveSignature::Secp256k1(signature),
+    ));
+    let envelope: TempoTxEnvelope = tx.into_signed(signature).into();
+    Ok(envelope.encoded_2718())
+}
+
+/// Aggregates one phase's samples into summary stats.
+fn summarize(
+    phase: &'static str,
+    samples: &[TxSample],
+    failed: usize,
+    elapsed: Duration,
+) -> PhaseStats {
+    let mut latencies: Vec<u64> = samples.iter().map(|s| s.send_latency_us).collect();
+    let mut gas: Vec<u64> = samples.iter().map(|s| s.gas_used).collect();
+    latencies.sort_unstable();
+    gas.sort_unstable();
+
+    let gas_used_avg = if gas.is_empty() {
+        0
+    } else {
+        gas.iter().sum::<u64>() / gas.len() as u64
+    };
+
+    PhaseStats {
+        phase,
+        transactions: samples.len(),
+        failed,
+        elapsed_secs: elapsed.as_secs_f64(),
+        tps: samples.len() as f64 / elapsed.as_secs_f64().max(f64::EPSILON),
+        send_latency_us_p50: percentile(&latencies, 50),
+        send_latency_us_p95: percentile(&latencies, 95),
+        gas_used_p50: percentile(&gas, 50),
+        gas_used_p95: percentile(&gas, 95),
+        gas_used_avg,
+    }
+}
+
+/// Nearest-rank percentile of an ascending-sorted slice; 0 when empty.
+fn percentile(sorted: &[u64], pct: usize) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let rank = (sorted.len() * pct).div_ceil(100).max(1) - 1;
+    sorted[rank.min(sorted.len() - 1)]
+}
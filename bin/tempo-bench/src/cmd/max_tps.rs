@@ -163,6 +163,33 @@ pub struct MaxTpsArgs {
     #[arg(long, default_value_t = 0.0)]
     swap_weight: f64,
 
+    /// A weight that determines the likelihood of generating a DEX cancel transaction.
+    ///
+    /// Cancels consume per-signer order ids seeded at setup (see `--seed-orders`); once a
+    /// signer's pool is drained, the slot falls back to placing an order.
+    #[arg(long, default_value_t = 0.0)]
+    cancel_order_weight: f64,
+
+    /// Number of user tokens (and DEX pairs) to create when any DEX weight is non-zero.
+    ///
+    /// Generated DEX traffic picks pairs with Zipf-distributed popularity, so a larger
+    /// token set concentrates most flow on the first few pairs like production traffic.
+    #[arg(long, default_value_t = 8)]
+    dex_tokens: usize,
+
+    /// Zipf exponent for DEX token-pair popularity (0 = uniform).
+    #[arg(long, default_value_t = 1.0)]
+    dex_zipf_alpha: f64,
+
+    /// Order-size distribution for generated DEX orders and swaps:
+    /// `fixed:<amount>`, `uniform:<min>..<max>`, or `pareto:<min>,<alpha>`.
+    #[arg(long, default_value_t = dex::OrderSizeDistribution::Pareto { min: MIN_ORDER_AMOUNT, alpha: 1.5 })]
+    order_size_dist: dex::OrderSizeDistribution,
+
+    /// Resting orders to place per signer at setup to feed `--cancel-order-weight`.
+    #[arg(long, default_value_t = 64)]
+    seed_orders: usize,
+
     /// A weight that determines the likelihood of generating an ERC-20 transfer transaction.
     #[arg(long, default_value_t = 0.0)]
     erc20_weight: f64,
@@ -318,6 +345,10 @@ impl MaxTpsArgs {
             self.mpp_weight == 0.0 || self.mpp_contract_address.is_some(),
             "--mpp-contract-address is required when --mpp-weight > 0"
         );
+        eyre::ensure!(
+            self.dex_tokens > 0,
+            "--dex-tokens must be at least 1 when DEX traffic is generated"
+        );
 
         let signer_providers = signer_provider_manager.signer_providers();
 
@@ -426,9 +457,11 @@ impl MaxTpsArgs {
 
         // Setup DEX tokens, pairs, and liquidity only if any DEX transaction type has non-zero
         // weight. Otherwise, use the fee token for TIP-20 transfers directly.
-        let (quote_token, user_tokens) = if self.place_order_weight > 0.0 || self.swap_weight > 0.0
-        {
-            let user_tokens = 2;
+        let dex_enabled = self.place_order_weight > 0.0
+            || self.swap_weight > 0.0
+            || self.cancel_order_weight > 0.0;
+        let (quote_token, user_tokens) = if dex_enabled {
+            let user_tokens = self.dex_tokens;
             info!(user_tokens, "Setting up DEX");
             let (quote_token, user_tokens) = dex::setup(
                 signer_providers,
@@ -443,6 +476,27 @@ impl MaxTpsArgs {
             (None, vec![self.fee_token])
         };
 
+        // Build the order-flow generator and seed the cancel pools.
+        let order_flow = if dex_enabled {
+            let flow = Arc::new(dex::OrderFlow::new(
+                user_tokens.clone(),
+                self.dex_zipf_alpha,
+                self.order_size_dist.clone(),
+            ));
+            if self.cancel_order_weight > 0.0 {
+                dex::seed_cancelable_orders(
+                    signer_providers,
+                    &flow,
+                    self.seed_orders,
+                    self.max_concurrent_requests,
+                )
+                .await?;
+            }
+            Some(flow)
+        } else {
+            None
+        };
+
         let erc20_tokens = if self.erc20_weight > 0.0 {
             let num_erc20_tokens = 1;
             info!(num_erc20_tokens, "Setting up ERC-20 tokens");
@@ -479,6 +533,8 @@ impl MaxTpsArgs {
             (self.tip20_virtual_weight * Self::WEIGHT_PRECISION).trunc() as u64;
         let place_order_weight = (self.place_order_weight * Self::WEIGHT_PRECISION).trunc() as u64;
         let swap_weight = (self.swap_weight * Self::WEIGHT_PRECISION).trunc() as u64;
+        let cancel_order_weight =
+            (self.cancel_order_weight * Self::WEIGHT_PRECISION).trunc() as u64;
         let erc20_weight = (self.erc20_weight * Self::WEIGHT_PRECISION).trunc() as u64;
         let mpp_weight = (self.mpp_weight * Self::WEIGHT_PRECISION).trunc() as u64;
 
@@ -510,6 +566,7 @@ impl MaxTpsArgs {
             tip20_virtual_weight,
             place_order_weight,
             swap_weight,
+            cancel_order_weight,
             erc20_weight,
             mpp_weight,
             quote_token,
@@ -520,6 +577,7 @@ impl MaxTpsArgs {
             recipients,
             expiry_secs,
             virtual_master_ids,
+            order_flow,
         };
 
         info!(total_txs, "Generating and sending transactions");
@@ -610,6 +668,7 @@ impl MaxTpsArgs {
             tip20_virtual_transfers = counters.tip20_virtual_transfers.load(Ordering::Relaxed),
             swaps = counters.swaps.load(Ordering::Relaxed),
             orders = counters.orders.load(Ordering::Relaxed),
+            cancels = counters.cancels.load(Ordering::Relaxed),
             erc20_transfers = counters.erc20_transfers.load(Ordering::Relaxed),
             mpp_open_close = counters.mpp_open_close.load(Ordering::Relaxed),
             success = counters.success.load(Ordering::Relaxed),
@@ -701,6 +760,7 @@ struct TransactionCounters {
     tip20_virtual_transfers: Arc<AtomicUsize>,
     swaps: Arc<AtomicUsize>,
     orders: Arc<AtomicUsize>,
+    cancels: Arc<AtomicUsize>,
     erc20_transfers: Arc<AtomicUsize>,
     mpp_open_close: Arc<AtomicUsize>,
     /// Sending counters
@@ -716,6 +776,7 @@ struct GenerateTransactionsInput {
     tip20_virtual_weight: u64,
     place_order_weight: u64,
     swap_weight: u64,
+    cancel_order_weight: u64,
     erc20_weight: u64,
     mpp_weight: u64,
     quote_token: Option<Address>,
@@ -730,6 +791,9 @@ struct GenerateTransactionsInput {
     expiry_secs: Option<u64>,
     /// Registered master IDs for constructing virtual address recipients.
     virtual_master_ids: Vec<MasterId>,
+    /// DEX order-flow generator (Zipf pair popularity, sized orders, cancel pools).
+    /// `Some` whenever any DEX weight is non-zero.
+    order_flow: Option<Arc<dex::OrderFlow>>,
 }
 
 /// Returns an infinite stream of futures, each generating, signing, and encoding one transaction.
@@ -743,6 +807,7 @@ fn generate_transactions<F: TxFiller<TempoNetwork> + 'static>(
         tip20_virtual_weight,
         place_order_weight,
         swap_weight,
+        cancel_order_weight,
         erc20_weight,
         mpp_weight,
         quote_token,
@@ -753,9 +818,10 @@ fn generate_transactions<F: TxFiller<TempoNetwork> + 'static>(
         recipients,
         expiry_secs,
         virtual_master_ids,
+        order_flow,
     } = input;
 
-    const TX_TYPES: usize = 6;
+    const TX_TYPES: usize = 7;
     // Weights for random sampling for each transaction type
     let tx_weights: [u64; TX_TYPES] = [
         tip20_weight,
@@ -764,6 +830,7 @@ fn generate_transactions<F: TxFiller<TempoNetwork> + 'static>(
         place_order_weight,
         erc20_weight,
         mpp_weight,
+        cancel_order_weight,
     ];
     // Cached gas estimates for each transaction type
     let gas_estimates: [Arc<OnceLock<(u128, u128, u64)>>; TX_TYPES] = Default::default();
@@ -779,6 +846,7 @@ fn generate_transactions<F: TxFiller<TempoNetwork> + 'static>(
         let user_tokens = user_tokens.clone();
         let erc20_tokens = erc20_tokens.clone();
         let virtual_master_ids = virtual_master_ids.clone();
+        let order_flow = order_flow.clone();
         let counters = counters.clone();
 
         async move {
@@ -827,24 +895,33 @@ fn generate_transactions<F: TxFiller<TempoNetwork> + 'static>(
                     counters.swaps.fetch_add(1, Ordering::Relaxed);
                     let exchange =
                         IStablecoinDEXInstance::new(STABLECOIN_DEX_ADDRESS, provider.clone());
+                    let flow = order_flow
+                        .as_ref()
+                        .expect("order_flow must be set when swap_weight > 0");
 
-                    // Swap minimum possible amount
+                    // Swap a sampled amount on a Zipf-popular pair
                     let quote_token =
                         quote_token.expect("quote_token must be set when swap_weight > 0");
                     exchange
-                        .quoteSwapExactAmountIn(token, quote_token, 1)
+                        .quoteSwapExactAmountIn(
+                            flow.sample_token(),
+                            quote_token,
+                            flow.sample_amount(),
+                        )
                         .into_transaction_request()
                 }
                 3 => {
                     counters.orders.fetch_add(1, Ordering::Relaxed);
                     let exchange =
                         IStablecoinDEXInstance::new(STABLECOIN_DEX_ADDRESS, provider.clone());
+                    let flow = order_flow
+                        .as_ref()
+                        .expect("order_flow must be set when place_order_weight > 0");
 
-                    // Place an order at a random tick that's a multiple of `TICK_SPACING`
-                    let tick = random_range(MIN_TICK / TICK_SPACING..=MAX_TICK / TICK_SPACING)
-                        * TICK_SPACING;
+                    // Place a sampled order: Zipf pair, distributed size, random side and tick
+                    let (token, amount, is_bid, tick) = flow.sample_place();
                     exchange
-                        .place(token, MIN_ORDER_AMOUNT, true, tick)
+                        .place(token, amount, is_bid, tick)
                         .into_transaction_request()
                 }
                 4 => {
@@ -877,6 +954,29 @@ fn generate_transactions<F: TxFiller<TempoNetwork> + 'static>(
                     );
                     mpp::build_open_and_close(contract, payer, token, salt, channel_id)
                 }
+                6 => {
+                    let exchange =
+                        IStablecoinDEXInstance::new(STABLECOIN_DEX_ADDRESS, provider.clone());
+                    let flow = order_flow
+                        .as_ref()
+                        .expect("order_flow must be set when cancel_order_weight > 0");
+
+                    match flow.pop_cancelable(signer.address()) {
+                        Some(order_id) => {
+                            counters.cancels.fetch_add(1, Ordering::Relaxed);
+                            exchange.cancel(order_id).into_transaction_request()
+                        }
+                        None => {
+                            // Seeded pool drained for this signer — keep the slot busy
+                            // with a place instead.
+                            counters.orders.fetch_add(1, Ordering::Relaxed);
+                            let (token, amount, is_bid, tick) = flow.sample_place();
+                            exchange
+                                .place(token, amount, is_bid, tick)
+                                .into_transaction_request()
+                        }
+                    }
+                }
                 _ => unreachable!("Only {TX_TYPES} transaction types are supported"),
             };
 
@@ -1044,8 +1144,11 @@ struct BenchmarkMetadata {
     tip20_virtual_weight: f64,
     place_order_weight: f64,
     swap_weight: f64,
+    cancel_order_weight: f64,
     erc20_weight: f64,
     mpp_weight: f64,
+    dex_zipf_alpha: f64,
+    order_size_dist: String,
 }
 
 #[derive(Serialize)]
@@ -1129,8 +1232,11 @@ pub async fn generate_report(
         tip20_virtual_weight: args.tip20_virtual_weight,
         place_order_weight: args.place_order_weight,
         swap_weight: args.swap_weight,
+        cancel_order_weight: args.cancel_order_weight,
         erc20_weight: args.erc20_weight,
         mpp_weight: args.mpp_weight,
+        dex_zipf_alpha: args.dex_zipf_alpha,
+        order_size_dist: args.order_size_dist.to_string(),
     };
 
     let report = BenchmarkReport {
@@ -1,9 +1,211 @@
 use super::*;
 use alloy::providers::DynProvider;
 use indicatif::ProgressIterator;
+use std::{collections::HashMap, fmt, sync::Mutex};
 use tempo_contracts::precompiles::{IStablecoinDEX, PATH_USD_ADDRESS};
 use tempo_precompiles::tip20::U128_MAX;
 
+/// Liquidity seeded per signer and token by [`setup`]'s flip orders. Also used as the
+/// upper clamp for sampled order sizes so the generated flow stays fillable.
+const SEED_LIQUIDITY: u128 = 1_000_000_000_000;
+
+/// Order-size distribution for generated DEX traffic.
+///
+/// Parsed from `fixed:<amount>`, `uniform:<min>..<max>`, or `pareto:<min>,<alpha>`.
+/// Samples are clamped to `MIN_ORDER_AMOUNT..=SEED_LIQUIDITY`.
+#[derive(Clone, Debug)]
+pub(super) enum OrderSizeDistribution {
+    /// Every order has the same size.
+    Fixed(u128),
+    /// Sizes drawn uniformly from `min..=max`.
+    Uniform { min: u128, max: u128 },
+    /// Heavy-tailed sizes: `min / U^(1/alpha)` for uniform `U`, matching the
+    /// many-small-orders-few-large-orders shape of production flow.
+    Pareto { min: u128, alpha: f64 },
+}
+
+impl OrderSizeDistribution {
+    fn sample(&self) -> u128 {
+        let raw = match self {
+            Self::Fixed(amount) => *amount,
+            Self::Uniform { min, max } => random_range(*min..=*max),
+            Self::Pareto { min, alpha } => {
+                let u: f64 = random_range(0.0..1.0);
+                // Float-to-int casts saturate, so the tail cannot overflow.
+                ((*min as f64) / (1.0 - u).powf(1.0 / alpha)) as u128
+            }
+        };
+        raw.clamp(MIN_ORDER_AMOUNT, SEED_LIQUIDITY)
+    }
+}
+
+impl FromStr for OrderSizeDistribution {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (kind, params) = s
+            .split_once(':')
+            .ok_or_else(|| format!("invalid order size distribution: {s}"))?;
+        let parse = |v: &str| v.parse::<u128>().map_err(|e| e.to_string());
+        match kind {
+            "fixed" => Ok(Self::Fixed(parse(params)?)),
+            "uniform" => {
+                let (min, max) = params
+                    .split_once("..")
+                    .ok_or_else(|| format!("expected uniform:<min>..<max>, got: {s}"))?;
+                Ok(Self::Uniform {
+                    min: parse(min)?,
+                    max: parse(max)?,
+                })
+            }
+            "pareto" => {
+                let (min, alpha) = params
+                    .split_once(',')
+                    .ok_or_else(|| format!("expected pareto:<min>,<alpha>, got: {s}"))?;
+                Ok(Self::Pareto {
+                    min: parse(min)?,
+                    alpha: alpha
+                        .parse()
+                        .map_err(|e: std::num::ParseFloatError| e.to_string())?,
+                })
+            }
+            other => Err(format!("unknown order size distribution: {other}")),
+        }
+    }
+}
+
+impl fmt::Display for OrderSizeDistribution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fixed(amount) => write!(f, "fixed:{amount}"),
+            Self::Uniform { min, max } => write!(f, "uniform:{min}..{max}"),
+            Self::Pareto { min, alpha } => write!(f, "pareto:{min},{alpha}"),
+        }
+    }
+}
+
+/// Order-flow scenario generator shared across transaction generators.
+///
+/// Token pairs are sampled with Zipf-distributed popularity (rank = creation order),
+/// order sizes from the configured [`OrderSizeDistribution`], and cancels consume
+/// per-maker order ids harvested by [`seed_cancelable_orders`] — mirroring production
+/// traffic where a few pairs dominate and most orders are small.
+pub(super) struct OrderFlow {
+    /// User tokens by popularity rank.
+    tokens: Vec<Address>,
+    /// Unnormalized cumulative Zipf weights aligned with `tokens`.
+    cumulative: Vec<f64>,
+    size_dist: OrderSizeDistribution,
+    /// Cancelable order ids per maker (cancels must be signed by the maker).
+    cancelable: Mutex<HashMap<Address, Vec<u128>>>,
+}
+
+impl OrderFlow {
+    pub(super) fn new(
+        tokens: Vec<Address>,
+        zipf_alpha: f64,
+        size_dist: OrderSizeDistribution,
+    ) -> Self {
+        assert!(!tokens.is_empty(), "order flow requires at least one token");
+        let mut cumulative = Vec::with_capacity(tokens.len());
+        let mut total = 0.0;
+        for rank in 0..tokens.len() {
+            total += 1.0 / ((rank + 1) as f64).powf(zipf_alpha);
+            cumulative.push(total);
+        }
+        Self {
+            tokens,
+            cumulative,
+            size_dist,
+            cancelable: Mutex::default(),
+        }
+    }
+
+    /// Samples a token with Zipf-distributed popularity.
+    pub(super) fn sample_token(&self) -> Address {
+        let total = *self.cumulative.last().expect("tokens is non-empty");
+        let r = random_range(0.0..total);
+        let idx = self.cumulative.partition_point(|&c| c <= r);
+        self.tokens[idx.min(self.tokens.len() - 1)]
+    }
+
+    /// Samples an order/swap size from the configured distribution.
+    pub(super) fn sample_amount(&self) -> u128 {
+        self.size_dist.sample()
+    }
+
+    /// Samples a full place order: Zipf token, distributed size, random side, and a
+    /// random tick on the spacing grid.
+    pub(super) fn sample_place(&self) -> (Address, u128, bool, i16) {
+        let tick = random_range(MIN_TICK / TICK_SPACING..=MAX_TICK / TICK_SPACING) * TICK_SPACING;
+        (
+            self.sample_token(),
+            self.sample_amount(),
+            rand::random(),
+            tick,
+        )
+    }
+
+    /// Records a seeded order id that `maker` can later cancel.
+    pub(super) fn push_cancelable(&self, maker: Address, order_id: u128) {
+        self.cancelable
+            .lock()
+            .expect("cancelable lock poisoned")
+            .entry(maker)
+            .or_default()
+            .push(order_id);
+    }
+
+    /// Takes a cancelable order id owned by `maker`, if any remain.
+    pub(super) fn pop_cancelable(&self, maker: Address) -> Option<u128> {
+        self.cancelable
+            .lock()
+            .expect("cancelable lock poisoned")
+            .get_mut(&maker)?
+            .pop()
+    }
+}
+
+/// Places `orders_per_signer` resting orders per signer and records their order ids in
+/// the flow's cancel pool, so generated cancel traffic targets real maker-owned orders.
+pub(super) async fn seed_cancelable_orders(
+    signer_providers: &[(Secp256k1Signer, DynProvider<TempoNetwork>)],
+    flow: &OrderFlow,
+    orders_per_signer: usize,
+    max_concurrent_requests: usize,
+) -> eyre::Result<()> {
+    info!(orders_per_signer, "Seeding cancelable DEX orders");
+    let progress = ProgressBar::new((signer_providers.len() * orders_per_signer) as u64);
+
+    stream::iter(signer_providers.iter().flat_map(|(signer, provider)| {
+        (0..orders_per_signer).map(move |_| {
+            let maker = signer.address();
+            let exchange = IStablecoinDEXInstance::new(STABLECOIN_DEX_ADDRESS, provider.clone());
+            let (token, amount, is_bid, tick) = flow.sample_place();
+            async move {
+                let receipt = exchange
+                    .place(token, amount, is_bid, tick)
+                    .send()
+                    .await?
+                    .get_receipt()
+                    .await?;
+                let event = receipt
+                    .decoded_log::<IStablecoinDEX::OrderPlaced>()
+                    .ok_or_eyre("OrderPlaced event not found")?;
+                eyre::Ok((maker, event.orderId))
+            }
+        })
+    }))
+    .buffer_unordered(max_concurrent_requests)
+    .try_for_each(|(maker, order_id)| {
+        flow.push_cancelable(maker, order_id);
+        progress.inc(1);
+        futures::future::ready(Ok(()))
+    })
+    .await
+    .context("Failed to seed cancelable DEX orders")
+}
+
 /// This method performs a one-time setup for sending a lot of transactions:
 /// * Deploys the specified number of user tokens.
 /// * Creates DEX pairs of user tokens with the quote token.
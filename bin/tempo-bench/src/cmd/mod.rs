@@ -1,2 +1,4 @@
+pub mod bridge;
+pub mod keychain;
 pub mod max_tps;
 mod signer_providers;
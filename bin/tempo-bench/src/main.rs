@@ -16,5 +16,7 @@ async fn main() -> eyre::Result<()> {
 
     match args.cmd {
         TempoBenchSubcommand::RunMaxTps(cmd) => cmd.run().await,
+        TempoBenchSubcommand::Bridge(cmd) => cmd.run().await,
+        TempoBenchSubcommand::Keychain(cmd) => cmd.run().await,
     }
 }
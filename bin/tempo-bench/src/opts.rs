@@ -1,4 +1,4 @@
-use crate::cmd::max_tps::MaxTpsArgs;
+use crate::cmd::{bridge::BridgeBenchArgs, keychain::KeychainBenchArgs, max_tps::MaxTpsArgs};
 use clap::{Parser, Subcommand};
 
 #[derive(Parser, Debug)]
@@ -11,4 +11,8 @@ pub struct TempoBench {
 #[derive(Subcommand, Debug)]
 pub enum TempoBenchSubcommand {
     RunMaxTps(MaxTpsArgs),
+    /// Benchmark bridge deposit→mint / burn→unlock latency per pipeline stage.
+    Bridge(BridgeBenchArgs),
+    /// Benchmark AccountKeychain key provisioning and key-signed call overhead.
+    Keychain(KeychainBenchArgs),
 }
@@ -34,6 +34,12 @@ pub(crate) struct TelemetryArgs {
     /// The interval at which to push Prometheus metrics.
     #[arg(long, default_value = "10s")]
     pub(crate) telemetry_metrics_interval: SignedDuration,
+
+    /// Also export tracing spans (notably the per-transaction `tx_stage`
+    /// spans) as OTLP traces. Off by default because traces are much higher
+    /// volume than logs and metrics.
+    #[arg(long, requires = "telemetry_url")]
+    pub(crate) telemetry_traces: bool,
 }
 
 impl TelemetryArgs {
@@ -78,9 +84,20 @@ impl TelemetryArgs {
             .join("api/v1/import/prometheus")
             .wrap_err("failed to construct metrics URL")?;
 
+        // Build traces OTLP URL (Victoria Metrics OTLP path), if enabled
+        let traces_otlp_url = self
+            .telemetry_traces
+            .then(|| {
+                base_url_no_creds
+                    .join("opentelemetry/v1/traces")
+                    .wrap_err("failed to construct traces OTLP URL")
+            })
+            .transpose()?;
+
         Ok(Some(TelemetryConfig {
             logs_otlp_url,
             logs_otlp_filter: DEFAULT_LOGS_OTLP_FILTER.to_string(),
+            traces_otlp_url,
             metrics_prometheus_url,
             metrics_prometheus_interval: self.telemetry_metrics_interval,
             metrics_auth_header: Some(auth_header),
@@ -139,6 +156,8 @@ pub(crate) struct TelemetryConfig {
     pub(crate) logs_otlp_url: Url,
     /// OTLP logs filter level.
     pub(crate) logs_otlp_filter: String,
+    /// OTLP traces endpoint (without credentials), if trace export is enabled.
+    pub(crate) traces_otlp_url: Option<Url>,
     /// Prometheus metrics push endpoint (without credentials).
     /// Used for both consensus and execution metrics.
     pub(crate) metrics_prometheus_url: Url,
@@ -65,8 +65,11 @@ use tempo_faucet::{
 };
 use tempo_node::{
     TempoFullNode, TempoNodeArgs,
+    light_client::{self, NodeLightClientSource},
     node::TempoNode,
     rpc::consensus::{TempoConsensusApiServer, TempoConsensusRpc},
+    rpc::peers::{TempoPeersApiServer, TempoPeersRpc},
+    rpc::preconfirmation::{TempoPreConfirmationApiServer, TempoPreConfirmationRpc},
     telemetry::{PrometheusMetricsConfig, install_prometheus_metrics},
 };
 use tokio::sync::oneshot;
@@ -374,6 +377,12 @@ fn main() -> eyre::Result<()> {
             .parse()
             .wrap_err("invalid default logs filter")?;
 
+        // Optionally export spans as OTLP traces so per-transaction `tx_stage`
+        // spans can be joined into stage-by-stage latency timelines.
+        if let Some(traces_url) = &config.traces_otlp_url {
+            cli.traces.otlp = Some(traces_url.clone());
+        }
+
         telemetry_config.replace(config);
     }
 
@@ -385,9 +394,14 @@ fn main() -> eyre::Result<()> {
 
     let shutdown_token = tokio_util::sync::CancellationToken::new();
     let cl_feed_state = consensus_feed::FeedStateHandle::new();
+    let cl_peer_state = tempo_commonware_node::PeerStateHandle::new();
+    let cl_preconf_state = tempo_commonware_node::PreconfirmationHandle::new();
 
     let shutdown_token_clone = shutdown_token.clone();
     let cl_feed_state_clone = cl_feed_state.clone();
+    let cl_light_client_feed = cl_feed_state.clone();
+    let cl_peer_state_clone = cl_peer_state.clone();
+    let cl_preconf_state_clone = cl_preconf_state.clone();
     let consensus_handle = thread::spawn(move || {
         // Exit early if we are not executing `tempo node` command.
         if !is_node {
@@ -473,8 +487,14 @@ fn main() -> eyre::Result<()> {
                     );
                 }
 
-                let consensus_stack =
-                    run_consensus_stack(&ctx, args.consensus, node, cl_feed_state_clone);
+                let consensus_stack = run_consensus_stack(
+                    &ctx,
+                    args.consensus,
+                    node,
+                    cl_feed_state_clone,
+                    cl_peer_state_clone,
+                    cl_preconf_state_clone,
+                );
                 tokio::pin!(consensus_stack);
                 loop {
                     tokio::select!(
@@ -590,6 +610,11 @@ fn main() -> eyre::Result<()> {
                 if validator_key.is_some() {
                     ctx.modules
                         .merge_configured(TempoConsensusRpc::new(cl_feed_state).into_rpc())?;
+                    ctx.modules
+                        .merge_configured(TempoPeersRpc::new(cl_peer_state).into_rpc())?;
+                    ctx.modules.merge_configured(
+                        TempoPreConfirmationRpc::new(cl_preconf_state).into_rpc(),
+                    )?;
                 }
 
                 Ok(())
@@ -652,6 +677,17 @@ fn main() -> eyre::Result<()> {
             });
         }
 
+        // Serve the light client header endpoints (RLP headers, finalization
+        // certificates, receipt proofs) if enabled.
+        if let Some(addr) = args.node_args.light_client_addr {
+            let source = NodeLightClientSource::new(node.provider.clone(), cl_light_client_feed);
+            node.tasks().spawn_task(async move {
+                if let Err(err) = light_client::serve(addr, source).await {
+                    warn!(%err, "light client header server exited");
+                }
+            });
+        }
+
         let _ = args_and_node_handle_tx.send((node, args));
 
         // TODO: emit these inside a span
@@ -18,7 +18,7 @@ use alloy_signer_local::PrivateKeySigner;
 use alloy_signer_trezor::{HDPath as TrezorHDPath, TrezorSigner};
 use alloy_sol_types::SolCall;
 use clap::Subcommand;
-use commonware_codec::{DecodeExt as _, Encode as _, ReadExt as _};
+use commonware_codec::{DecodeExt as _, Encode as _};
 use commonware_consensus::types::{Epocher as _, FixedEpocher, Height};
 use commonware_cryptography::{
     Signer as _,
@@ -96,6 +96,10 @@ pub(crate) enum TempoSubcommand {
     )]
     Remove(ExtArgs),
 
+    /// Inspect and manage the consensus peer set of a running node.
+    #[command(subcommand)]
+    Peers(PeersSubcommand),
+
     /// List installed extensions.
     #[command(override_usage = "tempo list")]
     List(ExtArgs),
@@ -109,6 +113,10 @@ impl ExtendedCommand for TempoSubcommand {
                 Ok(())
             }
             Self::P2pProxy(cmd) => runner.run_command_until_exit(|_| cmd.run()),
+            Self::Peers(cmd) => {
+                runner.run_blocking_until_ctrl_c(cmd.run())?;
+                Ok(())
+            }
             Self::InitFromBinaryDump(cmd) => {
                 let runtime = runner.runtime();
                 runner.run_blocking_until_ctrl_c(
@@ -996,7 +1004,7 @@ impl ValidatorInfo {
                 ));
             }
 
-            let dkg_outcome = OnchainDkgOutcome::read(&mut extra_data.as_ref())
+            let dkg_outcome = OnchainDkgOutcome::from_extra_data(extra_data.as_ref())
                 .wrap_err("failed to decode DKG outcome from extra_data")?;
 
             let key = PublicKey::decode(&mut &pubkey_bytes[..])
@@ -1162,7 +1170,7 @@ impl ValidatorsInfo {
             ));
         }
 
-        let dkg_outcome = OnchainDkgOutcome::read(&mut extra_data.as_ref())
+        let dkg_outcome = OnchainDkgOutcome::from_extra_data(extra_data.as_ref())
             .wrap_err("failed to decode DKG outcome from extra_data")?;
 
         let tx = TransactionRequest::default()
@@ -1238,6 +1246,86 @@ impl ValidatorsInfo {
     }
 }
 
+/// Subcommands for inspecting and managing the consensus peer set.
+///
+/// These talk to the `admin` namespace of a running validator's RPC server,
+/// which is only available if the node was started with a consensus key.
+#[derive(Debug, Subcommand)]
+pub(crate) enum PeersSubcommand {
+    /// List the peers the node currently tracks, including blocked peers.
+    List(PeersArgs),
+    /// Block a peer so it is excluded from future peer set registrations.
+    Block(PeerToggleArgs),
+    /// Unblock a previously blocked peer.
+    Unblock(PeerToggleArgs),
+}
+
+#[derive(Debug, clap::Args)]
+pub(crate) struct PeersArgs {
+    /// RPC URL of the node to query.
+    #[arg(long, default_value = "http://localhost:8545")]
+    rpc_url: String,
+}
+
+#[derive(Debug, clap::Args)]
+pub(crate) struct PeerToggleArgs {
+    /// The ed25519 public key of the peer (0x-prefixed hex).
+    #[arg(value_name = "PUBLIC_KEY")]
+    public_key: B256,
+
+    /// RPC URL of the node to query.
+    #[arg(long, default_value = "http://localhost:8545")]
+    rpc_url: String,
+}
+
+impl PeersSubcommand {
+    async fn run(self) -> eyre::Result<()> {
+        use tempo_node::rpc::peers::TempoPeersApiClient as _;
+
+        let connect = |url: &str| {
+            jsonrpsee::http_client::HttpClientBuilder::default()
+                .build(url)
+                .wrap_err("failed to create RPC client")
+        };
+
+        match self {
+            Self::List(args) => {
+                let client = connect(&args.rpc_url)?;
+                let snapshot = client
+                    .validator_peers()
+                    .await
+                    .wrap_err("failed to query validator peers")?;
+                println!("{}", serde_json::to_string_pretty(&snapshot)?);
+            }
+            Self::Block(args) => {
+                let client = connect(&args.rpc_url)?;
+                let changed = client
+                    .block_validator_peer(args.public_key)
+                    .await
+                    .wrap_err("failed to block peer")?;
+                if changed {
+                    println!("blocked peer {}", args.public_key);
+                } else {
+                    println!("peer {} was already blocked", args.public_key);
+                }
+            }
+            Self::Unblock(args) => {
+                let client = connect(&args.rpc_url)?;
+                let changed = client
+                    .unblock_validator_peer(args.public_key)
+                    .await
+                    .wrap_err("failed to unblock peer")?;
+                if changed {
+                    println!("unblocked peer {}", args.public_key);
+                } else {
+                    println!("peer {} was not blocked", args.public_key);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 fn key_from_file<P: AsRef<Path>>(p: P) -> eyre::Result<PrivateKeySigner> {
     let raw = std::fs::read(p).wrap_err("failed reading key from file")?;
     let bytes = alloy::hex::decode(&raw).wrap_err("failed decoding file contents from hex")?;
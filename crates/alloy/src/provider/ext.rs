@@ -1,5 +1,6 @@
 use alloy_contract::Result as ContractResult;
-use alloy_primitives::{Address, U256};
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::{Address, B256, Bytes, U256};
 use alloy_provider::{
     Identity, Provider, ProviderBuilder,
     fillers::{JoinFill, RecommendedFillers},
@@ -10,7 +11,7 @@ use tempo_contracts::precompiles::{
     IAccountKeychain::{IAccountKeychainInstance, KeyInfo},
     getAllowedCallsReturn, getRemainingLimitReturn,
 };
-use tempo_primitives::transaction::CallScope;
+use tempo_primitives::transaction::{AASigned, CallScope, DICTIONARY_ID_NONE};
 
 use crate::{
     TempoFillers, TempoNetwork,
@@ -118,6 +119,37 @@ pub trait TempoProviderExt: Provider<TempoNetwork> {
             .parse::<TempoHardfork>()
             .is_ok_and(|h| h >= hardfork))
     }
+
+    /// Sends a signed transaction using the compressed transport encoding.
+    ///
+    /// Wraps the canonical payload in a compressed envelope and submits it via
+    /// `tempo_sendRawTransactionCompressed` (available from T4); the returned
+    /// hash is the canonical transaction hash. Falls back to the canonical
+    /// `eth_sendRawTransaction` when compression does not shrink the payload.
+    async fn send_raw_transaction_compressed(
+        &self,
+        tx: &AASigned,
+    ) -> Result<B256, alloy_transport::TransportError>
+    where
+        Self: Sized,
+    {
+        match tx.eip2718_encode_compressed(DICTIONARY_ID_NONE) {
+            Some(compressed) => {
+                self.raw_request(
+                    "tempo_sendRawTransactionCompressed".into(),
+                    (Bytes::from(compressed),),
+                )
+                .await
+            }
+            None => {
+                self.raw_request(
+                    "eth_sendRawTransaction".into(),
+                    (Bytes::from(tx.encoded_2718()),),
+                )
+                .await
+            }
+        }
+    }
 }
 
 #[cfg_attr(target_family = "wasm", async_trait::async_trait(?Send))]
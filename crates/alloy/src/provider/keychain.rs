@@ -5,11 +5,11 @@ use alloy_sol_types::SolCall;
 use tempo_contracts::precompiles::{
     ACCOUNT_KEYCHAIN_ADDRESS,
     IAccountKeychain::{
-        KeyRestrictions as AbiKeyRestrictions, LegacyTokenLimit as AbiLegacyTokenLimit,
-        TokenLimit as AbiTokenLimit, removeAllowedCallsCall, revokeKeyCall, setAllowedCallsCall,
-        updateSpendingLimitCall,
+        KeyRestrictions as AbiKeyRestrictions, LegacyKeyRestrictions as AbiLegacyKeyRestrictions,
+        LegacyTokenLimit as AbiLegacyTokenLimit, TokenLimit as AbiTokenLimit,
+        removeAllowedCallsCall, revokeKeyCall, setAllowedCallsCall, updateSpendingLimitCall,
     },
-    ITIP20, authorizeKeyCall, legacyAuthorizeKeyCall,
+    ITIP20, authorizeKeyCall, legacyAuthorizeKeyCall, t3AuthorizeKeyCall,
 };
 use tempo_primitives::{
     SignatureType,
@@ -25,6 +25,8 @@ pub struct KeyRestrictions {
     limits: Option<Vec<TokenLimit>>,
     /// Optional call scopes. `None` means unrestricted calls.
     allowed_calls: Option<Vec<CallScope>>,
+    /// Optional maximum number of transactions. `None` means unlimited uses.
+    max_uses: Option<u64>,
 }
 
 impl KeyRestrictions {
@@ -46,6 +48,12 @@ impl KeyRestrictions {
         self
     }
 
+    /// Limit the key to at most `max_uses` transactions.
+    pub fn with_max_uses(mut self, max_uses: u64) -> Self {
+        self.max_uses = Some(max_uses);
+        self
+    }
+
     /// Deny all spending (enforce limits with an empty allowlist).
     pub fn with_no_spending(mut self) -> Self {
         self.limits = Some(Vec::new());
@@ -114,6 +122,11 @@ impl KeyRestrictions {
         self.allowed_calls.as_deref()
     }
 
+    /// Returns the maximum number of transaction uses, if limited.
+    pub fn max_uses(&self) -> Option<u64> {
+        self.max_uses
+    }
+
     fn has_periodic_limits(&self) -> bool {
         self.limits
             .as_ref()
@@ -131,6 +144,7 @@ impl From<KeyRestrictions> for AbiKeyRestrictions {
             expiry,
             limits,
             allowed_calls,
+            max_uses,
         } = restrictions;
 
         Self {
@@ -151,6 +165,7 @@ impl From<KeyRestrictions> for AbiKeyRestrictions {
                 .into_iter()
                 .map(Into::into)
                 .collect(),
+            maxUses: max_uses.unwrap_or(0),
         }
     }
 }
@@ -246,6 +261,8 @@ pub enum KeychainBuildError {
     LegacyPeriodicLimits,
     /// Legacy authorizeKey cannot encode call-scope restrictions.
     LegacyCallScopes,
+    /// Legacy authorizeKey cannot encode transaction use limits.
+    LegacyMaxUses,
 }
 
 impl std::error::Error for KeychainBuildError {}
@@ -258,6 +275,7 @@ impl fmt::Display for KeychainBuildError {
             Self::LegacyCallScopes => {
                 "legacy authorizeKey does not support call-scope restrictions"
             }
+            Self::LegacyMaxUses => "legacy authorizeKey does not support transaction use limits",
         };
         write!(f, "{msg}")
     }
@@ -275,11 +293,15 @@ pub fn authorize_key_legacy(
     if restrictions.has_periodic_limits() {
         return Err(KeychainBuildError::LegacyPeriodicLimits);
     }
+    if restrictions.max_uses.is_some() {
+        return Err(KeychainBuildError::LegacyMaxUses);
+    }
 
     let KeyRestrictions {
         expiry,
         limits,
         allowed_calls: _,
+        max_uses: _,
     } = restrictions;
     let enforce_limits = limits.is_some();
     let limits = limits
@@ -300,7 +322,34 @@ pub fn authorize_key_legacy(
     }))
 }
 
-/// Build an `authorizeKey(address,uint8,KeyRestrictions)` precompile call.
+/// Build a T3-era `authorizeKey(address,uint8,LegacyKeyRestrictions)` call.
+///
+/// Fails if `restrictions` carry transaction use limits, which only the T4+
+/// overload built by [`authorize_key`] can encode.
+pub fn authorize_key_t3(
+    key_id: Address,
+    signature_type: SignatureType,
+    restrictions: KeyRestrictions,
+) -> Result<Call, KeychainBuildError> {
+    if restrictions.max_uses.is_some() {
+        return Err(KeychainBuildError::LegacyMaxUses);
+    }
+
+    let config: AbiKeyRestrictions = restrictions.into();
+    Ok(account_keychain_call(t3AuthorizeKeyCall {
+        keyId: key_id,
+        signatureType: signature_type.into(),
+        config: AbiLegacyKeyRestrictions {
+            expiry: config.expiry,
+            enforceLimits: config.enforceLimits,
+            limits: config.limits,
+            allowAnyCalls: config.allowAnyCalls,
+            allowedCalls: config.allowedCalls,
+        },
+    }))
+}
+
+/// Build an `authorizeKey(address,uint8,KeyRestrictions)` precompile call (T4+).
 pub fn authorize_key(
     key_id: Address,
     signature_type: SignatureType,
@@ -410,6 +459,7 @@ mod tests {
         assert!(decoded.config.limits.is_empty());
         assert!(decoded.config.allowAnyCalls);
         assert!(decoded.config.allowedCalls.is_empty());
+        assert_eq!(decoded.config.maxUses, 0);
     }
 
     #[test]
@@ -470,6 +520,37 @@ mod tests {
         )
         .expect_err("legacy ABI should reject periodic limits");
         assert_eq!(periodic, KeychainBuildError::LegacyPeriodicLimits);
+
+        let bounded = authorize_key_legacy(
+            address!("0x1111111111111111111111111111111111111111"),
+            SignatureType::Secp256k1,
+            KeyRestrictions::default().with_max_uses(3),
+        )
+        .expect_err("legacy ABI should reject use limits");
+        assert_eq!(bounded, KeychainBuildError::LegacyMaxUses);
+    }
+
+    #[test]
+    fn test_authorize_key_t3_encodes_without_max_uses() {
+        let bounded = authorize_key_t3(
+            address!("0x1111111111111111111111111111111111111111"),
+            SignatureType::Secp256k1,
+            KeyRestrictions::default().with_max_uses(3),
+        )
+        .expect_err("T3 ABI should reject use limits");
+        assert_eq!(bounded, KeychainBuildError::LegacyMaxUses);
+
+        let call = authorize_key_t3(
+            address!("0x1111111111111111111111111111111111111111"),
+            SignatureType::Secp256k1,
+            KeyRestrictions::default().with_no_calls(),
+        )
+        .expect("T3 restrictions are compatible");
+
+        let decoded = t3AuthorizeKeyCall::abi_decode(&call.input).expect("decode T3 authorizeKey");
+        assert_eq!(decoded.config.expiry, u64::MAX);
+        assert!(!decoded.config.allowAnyCalls);
+        assert!(decoded.config.allowedCalls.is_empty());
     }
 
     #[test]
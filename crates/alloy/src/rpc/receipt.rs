@@ -3,7 +3,7 @@ use alloy_network::ReceiptResponse;
 use alloy_primitives::{Address, B256, BlockHash, TxHash};
 use alloy_rpc_types_eth::{Log, TransactionReceipt};
 use serde::{Deserialize, Serialize};
-use tempo_primitives::TempoReceipt;
+use tempo_primitives::{TempoReceipt, TempoReceiptFields};
 
 /// Tempo RPC receipt type.
 #[derive(Debug, Clone, Serialize, Deserialize, derive_more::Deref, derive_more::DerefMut)]
@@ -15,14 +15,10 @@ pub struct TempoTransactionReceipt {
     #[deref_mut]
     pub inner: TransactionReceipt<ReceiptWithBloom<TempoReceipt<Log>>>,
 
-    /// Token that was used to pay fees for the transaction.
-    ///
-    /// None if the transaction was free.
-    #[serde(default, skip_serializing_if = "Option::is_none")]
-    pub fee_token: Option<Address>,
-
-    /// Address that paid the fees for the transaction.
-    pub fee_payer: Address,
+    /// Tempo-specific fields (fee token, fee payer, token-denominated fee
+    /// amount, key authorization result).
+    #[serde(flatten)]
+    pub tempo: TempoReceiptFields,
 }
 
 impl ReceiptResponse for TempoTransactionReceipt {
@@ -41,6 +41,10 @@ pub struct TempoTransactionRequest {
     #[serde(default)]
     pub fee_token: Option<Address>,
 
+    /// Ordered list of acceptable fee tokens; mutually exclusive with `fee_token`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fee_token_preferences: Vec<Address>,
+
     /// Optional nonce key for a 2D [`TempoTransaction`].
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub nonce_key: Option<U256>,
@@ -281,11 +285,13 @@ impl TempoTransactionRequest {
             max_fee_per_gas,
             max_priority_fee_per_gas,
             fee_token: self.fee_token,
+            fee_token_preferences: self.fee_token_preferences,
             access_list: self.inner.access_list.unwrap_or_default(),
             calls,
             tempo_authorization_list: self.tempo_authorization_list,
             nonce_key: self.nonce_key.unwrap_or_default(),
             key_authorization: self.key_authorization,
+            unknown_fields: Default::default(),
         })
     }
 }
@@ -603,6 +609,7 @@ mod tests {
             max_fee_per_gas: 1000000000,
             max_priority_fee_per_gas: 1000000,
             fee_token: None,
+            fee_token_preferences: vec![],
             access_list: Default::default(),
             calls: vec![Call {
                 to: address!("0x86A2EE8FAf9A840F7a2c64CA3d51209F9A02081D").into(),
@@ -612,6 +619,7 @@ mod tests {
             tempo_authorization_list: vec![],
             nonce_key: TEMPO_EXPIRING_NONCE_KEY,
             key_authorization: None,
+            unknown_fields: Default::default(),
         };
 
         let request: TempoTransactionRequest = tx.into();
@@ -682,6 +690,7 @@ mod tests {
             max_fee_per_gas: 1000000000,
             max_priority_fee_per_gas: 1000000,
             fee_token: None,
+            fee_token_preferences: vec![],
             access_list: Default::default(),
             calls: vec![Call {
                 to: address!("0x86A2EE8FAf9A840F7a2c64CA3d51209F9A02081D").into(),
@@ -691,6 +700,7 @@ mod tests {
             tempo_authorization_list: vec![],
             nonce_key: Default::default(),
             key_authorization: None,
+            unknown_fields: Default::default(),
         };
 
         let request: TempoTransactionRequest = tx.into();
@@ -457,6 +457,7 @@ mod tests {
             max_fee_per_gas: 1_000_000_000,
             max_priority_fee_per_gas: 1_000_000,
             fee_token: None,
+            fee_token_preferences: vec![],
             access_list: Default::default(),
             calls: vec![Call {
                 to: target.into(),
@@ -466,6 +467,7 @@ mod tests {
             tempo_authorization_list: vec![],
             nonce_key: Default::default(),
             key_authorization: None,
+            unknown_fields: Default::default(),
         };
         let hash = tx.fee_payer_signature_hash(sender);
         let fee_payer_sig = sponsor.sign_hash_sync(&hash).expect("sign");
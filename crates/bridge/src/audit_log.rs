@@ -0,0 +1,374 @@
+//! Append-only, hash-chained audit log of bridge signatures.
+//!
+//! Every signature the validator produces is recorded as an [`AuditRecord`]
+//! before the signature leaves the process. Records form a hash chain: each one
+//! commits to the hash of its predecessor, so removing, reordering, or editing
+//! any record breaks verification of everything after it. After an incident an
+//! operator can hand the log to `xtask verify-audit-log` and prove exactly what
+//! their validator signed and when.
+//!
+//! The on-disk format is one JSON record per line, which keeps appends atomic
+//! on POSIX filesystems and the log greppable.
+
+use alloy_primitives::{B256, Bytes, keccak256};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufRead as _, BufReader, Read, Seek as _, SeekFrom, Write as _},
+    path::Path,
+};
+
+/// One signing event produced by the bridge validator.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditRecord {
+    /// Zero-based position in the log. Strictly sequential.
+    pub sequence: u64,
+    /// Identifier of the deposit (or withdrawal) the signature covers.
+    pub deposit_id: B256,
+    /// The digest that was signed.
+    pub digest: B256,
+    /// Unix timestamp (seconds) at which the signature was produced.
+    pub timestamp: u64,
+    /// Identifier of the key that signed, e.g. a key fingerprint or HSM slot.
+    pub signer_key_id: String,
+    /// The signature itself.
+    pub signature: Bytes,
+    /// Hash of the previous record ([`B256::ZERO`] for the first record).
+    pub prev_hash: B256,
+}
+
+impl AuditRecord {
+    /// Returns the hash chaining this record to its successor.
+    ///
+    /// Computed over a fixed binary encoding rather than the JSON serialization
+    /// so that formatting differences cannot affect the chain.
+    pub fn hash(&self) -> B256 {
+        let mut buf = Vec::with_capacity(32 * 5 + 16);
+        buf.extend_from_slice(&self.sequence.to_be_bytes());
+        buf.extend_from_slice(self.deposit_id.as_slice());
+        buf.extend_from_slice(self.digest.as_slice());
+        buf.extend_from_slice(&self.timestamp.to_be_bytes());
+        buf.extend_from_slice(keccak256(self.signer_key_id.as_bytes()).as_slice());
+        buf.extend_from_slice(keccak256(&self.signature).as_slice());
+        buf.extend_from_slice(self.prev_hash.as_slice());
+        keccak256(buf)
+    }
+}
+
+/// Errors raised while appending to or verifying an audit log.
+#[derive(Debug, thiserror::Error)]
+pub enum AuditLogError {
+    /// The log file could not be read or written.
+    #[error("audit log io error: {0}")]
+    Io(#[from] std::io::Error),
+    /// A line is not a valid JSON record.
+    #[error("malformed record on line {line}: {err}")]
+    MalformedRecord {
+        /// One-based line number.
+        line: usize,
+        /// Underlying JSON error.
+        err: serde_json::Error,
+    },
+    /// A record's sequence number does not follow its predecessor's.
+    #[error("record {got} out of sequence, expected {expected}")]
+    OutOfSequence {
+        /// Sequence number expected at this position.
+        expected: u64,
+        /// Sequence number found.
+        got: u64,
+    },
+    /// A record does not commit to the hash of its predecessor.
+    #[error("hash chain broken at record {sequence}: expected prev {expected}, got {got}")]
+    BrokenChain {
+        /// Sequence number of the offending record.
+        sequence: u64,
+        /// Hash of the preceding record.
+        expected: B256,
+        /// `prev_hash` the record actually carries.
+        got: B256,
+    },
+    /// A record's timestamp precedes its predecessor's.
+    #[error("timestamp regression at record {sequence}")]
+    TimestampRegression {
+        /// Sequence number of the offending record.
+        sequence: u64,
+    },
+}
+
+/// Append-only writer over an audit log file.
+///
+/// Opening an existing log resumes the hash chain from its last record.
+#[derive(Debug)]
+pub struct AuditLog {
+    file: File,
+    next_sequence: u64,
+    prev_hash: B256,
+}
+
+impl AuditLog {
+    /// Opens (or creates) the log at `path` and resumes its chain.
+    ///
+    /// The existing contents are verified first, so a log that was tampered
+    /// with offline is rejected rather than silently extended.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, AuditLogError> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(path)?;
+
+        let summary = verify_audit_log(BufReader::new(&mut file))?;
+        file.seek(SeekFrom::End(0))?;
+
+        Ok(Self {
+            file,
+            next_sequence: summary.records,
+            prev_hash: summary.head,
+        })
+    }
+
+    /// Number of records the log currently holds.
+    pub fn len(&self) -> u64 {
+        self.next_sequence
+    }
+
+    /// Returns true if the log holds no records.
+    pub fn is_empty(&self) -> bool {
+        self.next_sequence == 0
+    }
+
+    /// Appends a signing event and flushes it to disk before returning.
+    ///
+    /// Must be called before the signature is released to the network, so the
+    /// log provably covers everything the validator ever signed.
+    pub fn append(
+        &mut self,
+        deposit_id: B256,
+        digest: B256,
+        timestamp: u64,
+        signer_key_id: impl Into<String>,
+        signature: impl Into<Bytes>,
+    ) -> Result<AuditRecord, AuditLogError> {
+        let record = AuditRecord {
+            sequence: self.next_sequence,
+            deposit_id,
+            digest,
+            timestamp,
+            signer_key_id: signer_key_id.into(),
+            signature: signature.into(),
+            prev_hash: self.prev_hash,
+        };
+
+        let mut line = serde_json::to_string(&record).expect("audit record serializes");
+        line.push('\n');
+        self.file.write_all(line.as_bytes())?;
+        self.file.sync_data()?;
+
+        self.next_sequence += 1;
+        self.prev_hash = record.hash();
+        Ok(record)
+    }
+}
+
+/// Outcome of a successful audit log verification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuditSummary {
+    /// Number of records in the log.
+    pub records: u64,
+    /// Hash of the last record ([`B256::ZERO`] for an empty log).
+    pub head: B256,
+    /// Timestamp of the first record, if any.
+    pub first_timestamp: Option<u64>,
+    /// Timestamp of the last record, if any.
+    pub last_timestamp: Option<u64>,
+}
+
+/// Reads an audit log into records without verifying the hash chain.
+///
+/// Used by `bridge-cli replay`, which must still reconstruct timelines from a
+/// log whose chain no longer verifies — that is exactly the kind of incident
+/// being investigated. Run `xtask verify-audit-log` separately to prove
+/// integrity.
+pub fn read_audit_records(reader: impl Read) -> Result<Vec<AuditRecord>, AuditLogError> {
+    let mut records = Vec::new();
+    for (idx, line) in BufReader::new(reader).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: AuditRecord = serde_json::from_str(&line)
+            .map_err(|err| AuditLogError::MalformedRecord { line: idx + 1, err })?;
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// Verifies an audit log: JSON well-formedness, strictly sequential numbering,
+/// an unbroken hash chain, and monotonically non-decreasing timestamps.
+pub fn verify_audit_log(reader: impl Read) -> Result<AuditSummary, AuditLogError> {
+    let mut summary = AuditSummary {
+        records: 0,
+        head: B256::ZERO,
+        first_timestamp: None,
+        last_timestamp: None,
+    };
+
+    for (idx, line) in BufReader::new(reader).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: AuditRecord = serde_json::from_str(&line)
+            .map_err(|err| AuditLogError::MalformedRecord { line: idx + 1, err })?;
+
+        if record.sequence != summary.records {
+            return Err(AuditLogError::OutOfSequence {
+                expected: summary.records,
+                got: record.sequence,
+            });
+        }
+        if record.prev_hash != summary.head {
+            return Err(AuditLogError::BrokenChain {
+                sequence: record.sequence,
+                expected: summary.head,
+                got: record.prev_hash,
+            });
+        }
+        if summary
+            .last_timestamp
+            .is_some_and(|last| record.timestamp < last)
+        {
+            return Err(AuditLogError::TimestampRegression {
+                sequence: record.sequence,
+            });
+        }
+
+        summary.head = record.hash();
+        summary.records += 1;
+        summary.first_timestamp.get_or_insert(record.timestamp);
+        summary.last_timestamp = Some(record.timestamp);
+    }
+
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(sequence: u64, timestamp: u64, prev_hash: B256) -> AuditRecord {
+        AuditRecord {
+            sequence,
+            deposit_id: B256::with_last_byte(sequence as u8 + 1),
+            digest: B256::with_last_byte(0xd0),
+            timestamp,
+            signer_key_id: "hsm-slot-1".to_string(),
+            signature: Bytes::from_static(&[0xaa; 65]),
+            prev_hash,
+        }
+    }
+
+    fn to_log(records: &[AuditRecord]) -> Vec<u8> {
+        let mut out = Vec::new();
+        for record in records {
+            out.extend_from_slice(serde_json::to_string(record).unwrap().as_bytes());
+            out.push(b'\n');
+        }
+        out
+    }
+
+    #[test]
+    fn test_append_and_verify_roundtrip() {
+        let dir = std::env::temp_dir().join(format!("audit-log-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("log.jsonl");
+        let _ = std::fs::remove_file(&path);
+
+        let mut log = AuditLog::open(&path).unwrap();
+        assert!(log.is_empty());
+        for i in 0..3u64 {
+            log.append(
+                B256::with_last_byte(i as u8),
+                B256::with_last_byte(0xd0),
+                1_000 + i,
+                "hsm-slot-1",
+                vec![0xaa; 65],
+            )
+            .unwrap();
+        }
+        drop(log);
+
+        // Reopening resumes the chain and appending still verifies.
+        let mut log = AuditLog::open(&path).unwrap();
+        assert_eq!(log.len(), 3);
+        log.append(
+            B256::with_last_byte(9),
+            B256::with_last_byte(0xd0),
+            2_000,
+            "hsm-slot-1",
+            vec![0xbb; 65],
+        )
+        .unwrap();
+        drop(log);
+
+        let summary = verify_audit_log(File::open(&path).unwrap()).unwrap();
+        assert_eq!(summary.records, 4);
+        assert_eq!(summary.first_timestamp, Some(1_000));
+        assert_eq!(summary.last_timestamp, Some(2_000));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_tampered_record_breaks_chain() {
+        let first = record(0, 1_000, B256::ZERO);
+        let mut second = record(1, 1_001, first.hash());
+        let log = to_log(&[first.clone(), second.clone()]);
+        assert_eq!(verify_audit_log(log.as_slice()).unwrap().records, 2);
+
+        // Editing the first record invalidates the second's prev hash.
+        let mut tampered_first = first;
+        tampered_first.digest = B256::with_last_byte(0xff);
+        let log = to_log(&[tampered_first, second.clone()]);
+        assert!(matches!(
+            verify_audit_log(log.as_slice()).unwrap_err(),
+            AuditLogError::BrokenChain { sequence: 1, .. }
+        ));
+
+        // As does rewriting the second record's own prev hash.
+        second.prev_hash = B256::with_last_byte(0x01);
+        let log = to_log(&[record(0, 1_000, B256::ZERO), second]);
+        assert!(matches!(
+            verify_audit_log(log.as_slice()).unwrap_err(),
+            AuditLogError::BrokenChain { sequence: 1, .. }
+        ));
+    }
+
+    #[test]
+    fn test_dropped_record_detected() {
+        let first = record(0, 1_000, B256::ZERO);
+        let second = record(1, 1_001, first.hash());
+        let third = record(2, 1_002, second.hash());
+
+        let log = to_log(&[first, third]);
+        assert!(matches!(
+            verify_audit_log(log.as_slice()).unwrap_err(),
+            AuditLogError::OutOfSequence {
+                expected: 1,
+                got: 2
+            }
+        ));
+    }
+
+    #[test]
+    fn test_timestamp_regression_detected() {
+        let first = record(0, 1_000, B256::ZERO);
+        let second = record(1, 999, first.hash());
+        let log = to_log(&[first, second]);
+        assert!(matches!(
+            verify_audit_log(log.as_slice()).unwrap_err(),
+            AuditLogError::TimestampRegression { sequence: 1 }
+        ));
+    }
+}
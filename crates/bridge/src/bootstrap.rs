@@ -0,0 +1,331 @@
+//! Cold-start discovery of the bridge validator set from the Tempo chain.
+//!
+//! On first run the sidecar has no persisted state and historically required
+//! the validator set, signature threshold, and epoch in its config — values
+//! that drift the moment the set rotates. Instead, [`bootstrap`] reads the
+//! active validator set from the ValidatorConfig precompile and the threshold
+//! from the DKG outcome sealed at the epoch's boundary block, using any values
+//! that *are* configured only as a consistency check against chain state.
+
+use alloy_primitives::Address;
+use std::collections::BTreeSet;
+
+/// The DKG outcome sealed at an epoch's boundary block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DkgOutcome {
+    /// Boundary block the ceremony completed at.
+    pub boundary_block: u64,
+    /// Number of validator signatures required to authorize a mint.
+    pub threshold: usize,
+    /// Validators that completed the ceremony and hold key shares.
+    pub participants: Vec<Address>,
+}
+
+/// Read access to the Tempo-side chain state the bootstrap needs.
+///
+/// Implemented over the node's RPC in the sidecar binary; tests use an
+/// in-memory mock.
+#[async_trait::async_trait]
+pub trait ChainStateSource: Send + Sync {
+    /// Reads the active validator set from the ValidatorConfig precompile.
+    async fn validator_set(&self) -> eyre::Result<Vec<Address>>;
+
+    /// Reads the current validator-set epoch.
+    async fn current_epoch(&self) -> eyre::Result<u64>;
+
+    /// Returns the DKG outcome sealed at the boundary block of `epoch`, or
+    /// `None` if the ceremony has not completed.
+    async fn dkg_outcome(&self, epoch: u64) -> eyre::Result<Option<DkgOutcome>>;
+}
+
+/// Optional operator-configured values checked against discovered chain state.
+///
+/// All fields default to `None`, which is the intended cold-start shape: the
+/// chain is the source of truth and nothing needs to be configured. Populated
+/// fields do not override discovery — they fail the bootstrap when they
+/// disagree with the chain, catching configs that went stale across a rotation.
+#[derive(Debug, Clone, Default)]
+pub struct BootstrapConfig {
+    /// Expected validator set, order-insensitive.
+    pub validators: Option<Vec<Address>>,
+    /// Expected signature threshold.
+    pub threshold: Option<usize>,
+    /// Expected validator-set epoch.
+    pub epoch: Option<u64>,
+}
+
+/// Error returned when discovery fails or config disagrees with chain state.
+#[derive(Debug, thiserror::Error)]
+pub enum BootstrapError {
+    /// The ValidatorConfig precompile returned no validators.
+    #[error("chain reports an empty validator set")]
+    EmptyValidatorSet,
+    /// No DKG outcome is sealed for the current epoch yet.
+    #[error("DKG for epoch {0} has not completed at its boundary block")]
+    DkgIncomplete(u64),
+    /// The sealed threshold cannot be met by the participant set.
+    #[error("threshold {threshold} is unsatisfiable with {participants} participants")]
+    UnsatisfiableThreshold {
+        threshold: usize,
+        participants: usize,
+    },
+    /// The DKG participants are not the active validator set.
+    #[error("DKG participants for epoch {0} do not match the active validator set")]
+    ParticipantMismatch(u64),
+    /// The configured validator set disagrees with the chain.
+    #[error("configured validator set does not match chain state")]
+    ValidatorSetMismatch,
+    /// The configured threshold disagrees with the chain.
+    #[error("configured threshold {configured} does not match chain threshold {discovered}")]
+    ThresholdMismatch {
+        configured: usize,
+        discovered: usize,
+    },
+    /// The configured epoch disagrees with the chain.
+    #[error("configured epoch {configured} does not match chain epoch {discovered}")]
+    EpochMismatch { configured: u64, discovered: u64 },
+    /// The chain could not be read.
+    #[error(transparent)]
+    Client(#[from] eyre::Report),
+}
+
+/// The discovered validator-set state the sidecar starts from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ValidatorSetState {
+    /// Validator-set epoch the state belongs to.
+    pub epoch: u64,
+    /// Boundary block the epoch's DKG completed at.
+    pub boundary_block: u64,
+    /// Active validators holding key shares.
+    pub validators: Vec<Address>,
+    /// Signatures required to authorize a mint.
+    pub threshold: usize,
+}
+
+/// Discovers the validator set, threshold, and epoch from chain state and
+/// validates any configured values against them.
+///
+/// # Errors
+/// - [`BootstrapError::EmptyValidatorSet`] / [`BootstrapError::DkgIncomplete`] /
+///   [`BootstrapError::UnsatisfiableThreshold`] / [`BootstrapError::ParticipantMismatch`]
+///   when chain state itself is not in a startable shape
+/// - the `*Mismatch` variants when a configured value disagrees with the chain
+pub async fn bootstrap<C: ChainStateSource>(
+    client: &C,
+    config: &BootstrapConfig,
+) -> Result<ValidatorSetState, BootstrapError> {
+    let validators = client.validator_set().await?;
+    if validators.is_empty() {
+        return Err(BootstrapError::EmptyValidatorSet);
+    }
+
+    let epoch = client.current_epoch().await?;
+    let outcome = client
+        .dkg_outcome(epoch)
+        .await?
+        .ok_or(BootstrapError::DkgIncomplete(epoch))?;
+
+    if outcome.threshold == 0 || outcome.threshold > outcome.participants.len() {
+        return Err(BootstrapError::UnsatisfiableThreshold {
+            threshold: outcome.threshold,
+            participants: outcome.participants.len(),
+        });
+    }
+
+    // The ceremony must have been run by exactly the active set; a partial
+    // overlap means the set rotated between the boundary block and now.
+    let active: BTreeSet<Address> = validators.iter().copied().collect();
+    let participants: BTreeSet<Address> = outcome.participants.iter().copied().collect();
+    if active != participants {
+        return Err(BootstrapError::ParticipantMismatch(epoch));
+    }
+
+    if let Some(configured) = config.validators.as_ref() {
+        let configured: BTreeSet<Address> = configured.iter().copied().collect();
+        if configured != active {
+            return Err(BootstrapError::ValidatorSetMismatch);
+        }
+    }
+
+    if let Some(configured) = config.threshold
+        && configured != outcome.threshold
+    {
+        return Err(BootstrapError::ThresholdMismatch {
+            configured,
+            discovered: outcome.threshold,
+        });
+    }
+
+    if let Some(configured) = config.epoch
+        && configured != epoch
+    {
+        return Err(BootstrapError::EpochMismatch {
+            configured,
+            discovered: epoch,
+        });
+    }
+
+    tracing::info!(
+        epoch,
+        boundary_block = outcome.boundary_block,
+        validators = validators.len(),
+        threshold = outcome.threshold,
+        "bootstrapped validator set from chain state",
+    );
+
+    Ok(ValidatorSetState {
+        epoch,
+        boundary_block: outcome.boundary_block,
+        validators,
+        threshold: outcome.threshold,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockChain {
+        validators: Vec<Address>,
+        epoch: u64,
+        outcome: Option<DkgOutcome>,
+    }
+
+    #[async_trait::async_trait]
+    impl ChainStateSource for MockChain {
+        async fn validator_set(&self) -> eyre::Result<Vec<Address>> {
+            Ok(self.validators.clone())
+        }
+
+        async fn current_epoch(&self) -> eyre::Result<u64> {
+            Ok(self.epoch)
+        }
+
+        async fn dkg_outcome(&self, epoch: u64) -> eyre::Result<Option<DkgOutcome>> {
+            Ok((epoch == self.epoch)
+                .then(|| self.outcome.clone())
+                .flatten())
+        }
+    }
+
+    fn validators(count: u8) -> Vec<Address> {
+        (1..=count).map(Address::with_last_byte).collect()
+    }
+
+    fn chain(count: u8, epoch: u64, threshold: usize) -> MockChain {
+        MockChain {
+            validators: validators(count),
+            epoch,
+            outcome: Some(DkgOutcome {
+                boundary_block: 1_000,
+                threshold,
+                participants: validators(count),
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn cold_start_discovers_state_without_config() {
+        let state = bootstrap(&chain(4, 7, 3), &BootstrapConfig::default())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            state,
+            ValidatorSetState {
+                epoch: 7,
+                boundary_block: 1_000,
+                validators: validators(4),
+                threshold: 3,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn consistent_config_passes_and_stale_config_fails() {
+        let chain = chain(4, 7, 3);
+
+        // Order of the configured set does not matter.
+        let mut reversed = validators(4);
+        reversed.reverse();
+        let consistent = BootstrapConfig {
+            validators: Some(reversed),
+            threshold: Some(3),
+            epoch: Some(7),
+        };
+        assert!(bootstrap(&chain, &consistent).await.is_ok());
+
+        let stale_set = BootstrapConfig {
+            validators: Some(validators(3)),
+            ..Default::default()
+        };
+        assert!(matches!(
+            bootstrap(&chain, &stale_set).await,
+            Err(BootstrapError::ValidatorSetMismatch)
+        ));
+
+        let stale_threshold = BootstrapConfig {
+            threshold: Some(2),
+            ..Default::default()
+        };
+        assert!(matches!(
+            bootstrap(&chain, &stale_threshold).await,
+            Err(BootstrapError::ThresholdMismatch {
+                configured: 2,
+                discovered: 3,
+            })
+        ));
+
+        let stale_epoch = BootstrapConfig {
+            epoch: Some(6),
+            ..Default::default()
+        };
+        assert!(matches!(
+            bootstrap(&chain, &stale_epoch).await,
+            Err(BootstrapError::EpochMismatch {
+                configured: 6,
+                discovered: 7,
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn rejects_unstartable_chain_state() {
+        let empty = MockChain {
+            validators: vec![],
+            epoch: 7,
+            outcome: None,
+        };
+        assert!(matches!(
+            bootstrap(&empty, &BootstrapConfig::default()).await,
+            Err(BootstrapError::EmptyValidatorSet)
+        ));
+
+        let mut incomplete = chain(4, 7, 3);
+        incomplete.outcome = None;
+        assert!(matches!(
+            bootstrap(&incomplete, &BootstrapConfig::default()).await,
+            Err(BootstrapError::DkgIncomplete(7))
+        ));
+
+        let unsatisfiable = chain(4, 7, 5);
+        assert!(matches!(
+            bootstrap(&unsatisfiable, &BootstrapConfig::default()).await,
+            Err(BootstrapError::UnsatisfiableThreshold {
+                threshold: 5,
+                participants: 4,
+            })
+        ));
+    }
+
+    #[tokio::test]
+    async fn rejects_dkg_run_by_a_different_set() {
+        let mut rotated = chain(4, 7, 3);
+        rotated.validators = validators(5);
+
+        assert!(matches!(
+            bootstrap(&rotated, &BootstrapConfig::default()).await,
+            Err(BootstrapError::ParticipantMismatch(7))
+        ));
+    }
+}
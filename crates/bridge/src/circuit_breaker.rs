@@ -0,0 +1,420 @@
+//! Circuit breaker that halts bridge signing on anomalous deposit activity.
+//!
+//! Two classes of anomalies trip the breaker:
+//! - observed deposit volume deviating from the historical baseline by more
+//!   than a configurable number of standard deviations, and
+//! - the origin escrow's balance decreasing outside of an unlock we submitted.
+//!
+//! A tripped breaker is sticky: signing stays halted until an operator runs
+//! `bridge-cli unlock --resume`, which calls [`CircuitBreaker::resume`]. This
+//! is a safety net against escrow contract exploits, so we deliberately never
+//! auto-resume.
+
+use alloy_primitives::U256;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{HashMap, VecDeque},
+    time::{Duration, Instant},
+};
+
+/// Default deviation threshold in standard deviations.
+pub const DEFAULT_SIGMA_THRESHOLD: f64 = 6.0;
+
+/// Default observation window over which deposit volume is aggregated.
+pub const DEFAULT_VOLUME_WINDOW: Duration = Duration::from_secs(10 * 60);
+
+/// Default number of windows required before volume anomaly detection arms.
+/// Until the baseline has this many samples, only the escrow balance check is active.
+pub const DEFAULT_MIN_BASELINE_WINDOWS: usize = 12;
+
+/// Maximum number of historical windows retained for the baseline.
+const MAX_BASELINE_WINDOWS: usize = 288;
+
+/// Configuration for the anomaly-detecting circuit breaker.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CircuitBreakerConfig {
+    /// Trip when a window's deposit volume deviates from the baseline mean by
+    /// more than this many standard deviations.
+    pub sigma_threshold: f64,
+    /// Length of a single volume aggregation window.
+    pub volume_window: Duration,
+    /// Number of completed windows required before the volume check arms.
+    pub min_baseline_windows: usize,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            sigma_threshold: DEFAULT_SIGMA_THRESHOLD,
+            volume_window: DEFAULT_VOLUME_WINDOW,
+            min_baseline_windows: DEFAULT_MIN_BASELINE_WINDOWS,
+        }
+    }
+}
+
+/// Why a breaker tripped. Recorded per scope and surfaced via logs and `bridge-cli status`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum TripReason {
+    /// Deposit volume in the current window deviated from the baseline.
+    DepositVolumeAnomaly {
+        /// Volume observed in the offending window.
+        observed: U256,
+        /// Baseline mean volume per window.
+        baseline_mean: f64,
+        /// Deviation of the observed volume, in standard deviations.
+        sigma: f64,
+    },
+    /// The escrow balance decreased without a matching unlock from this sidecar.
+    EscrowBalanceDecrease {
+        /// Balance at the previous observation.
+        previous: U256,
+        /// Balance at the current observation.
+        current: U256,
+    },
+    /// Reconciliation found the escrow balance drifting from Tempo's minted
+    /// supply beyond the configured tolerance.
+    EscrowReconciliationDrift {
+        /// Observed escrow balance on the origin chain.
+        escrow_balance: U256,
+        /// Expected balance: total minted minus processed burns.
+        expected_balance: U256,
+    },
+    /// Tripped manually, e.g. via `bridge-cli lock`.
+    Manual,
+}
+
+impl std::fmt::Display for TripReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::DepositVolumeAnomaly {
+                observed,
+                baseline_mean,
+                sigma,
+            } => write!(
+                f,
+                "deposit volume anomaly: observed {observed} vs baseline mean {baseline_mean:.0} ({sigma:.1}σ)"
+            ),
+            Self::EscrowBalanceDecrease { previous, current } => {
+                write!(
+                    f,
+                    "escrow balance decreased unexpectedly: {previous} -> {current}"
+                )
+            }
+            Self::EscrowReconciliationDrift {
+                escrow_balance,
+                expected_balance,
+            } => write!(
+                f,
+                "escrow balance {escrow_balance} drifted from expected {expected_balance}"
+            ),
+            Self::Manual => write!(f, "manually tripped by operator"),
+        }
+    }
+}
+
+/// Scope of a breaker trip or resume.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BreakerScope {
+    /// Applies to a single origin chain.
+    Chain(u64),
+    /// Applies to all chains; halts every signer.
+    Global,
+}
+
+/// Per-chain anomaly detection state.
+#[derive(Debug, Default)]
+struct ChainBreaker {
+    /// Completed window volumes forming the baseline (most recent last).
+    baseline: VecDeque<u128>,
+    /// Volume accumulated in the currently open window.
+    current_volume: U256,
+    /// When the currently open window started.
+    window_start: Option<Instant>,
+    /// Last observed escrow balance.
+    last_escrow_balance: Option<U256>,
+    /// Set when this chain's breaker has tripped.
+    tripped: Option<TripReason>,
+}
+
+impl ChainBreaker {
+    /// Mean and standard deviation of the baseline window volumes.
+    fn baseline_stats(&self) -> (f64, f64) {
+        let n = self.baseline.len() as f64;
+        let mean = self.baseline.iter().map(|&v| v as f64).sum::<f64>() / n;
+        let variance = self
+            .baseline
+            .iter()
+            .map(|&v| (v as f64 - mean).powi(2))
+            .sum::<f64>()
+            / n;
+        (mean, variance.sqrt())
+    }
+}
+
+/// Anomaly-detecting circuit breaker gating all bridge signing.
+///
+/// Deposit observations and escrow balance reads are fed in by the origin
+/// watcher; the signer checks [`Self::is_signing_allowed`] before producing
+/// any mint authorization.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    chains: HashMap<u64, ChainBreaker>,
+    /// Set when the global breaker has tripped; overrides all per-chain state.
+    global_tripped: Option<TripReason>,
+}
+
+impl CircuitBreaker {
+    /// Creates a breaker with the given configuration. All scopes start closed
+    /// (signing allowed).
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            chains: HashMap::new(),
+            global_tripped: None,
+        }
+    }
+
+    /// Returns whether signing is currently allowed for the given origin chain.
+    pub fn is_signing_allowed(&self, chain_id: u64) -> bool {
+        self.global_tripped.is_none()
+            && self
+                .chains
+                .get(&chain_id)
+                .is_none_or(|c| c.tripped.is_none())
+    }
+
+    /// Returns the trip reason for the given scope, if tripped.
+    pub fn trip_reason(&self, scope: BreakerScope) -> Option<&TripReason> {
+        match scope {
+            BreakerScope::Global => self.global_tripped.as_ref(),
+            BreakerScope::Chain(id) => self.chains.get(&id).and_then(|c| c.tripped.as_ref()),
+        }
+    }
+
+    /// Records an observed deposit on the origin chain.
+    ///
+    /// Volume is aggregated per window; when a window closes its total is
+    /// checked against the baseline before being appended to it. Returns the
+    /// trip reason if this observation tripped the chain's breaker.
+    pub fn record_deposit(
+        &mut self,
+        chain_id: u64,
+        amount: U256,
+        now: Instant,
+    ) -> Option<TripReason> {
+        let window = self.config.volume_window;
+        let sigma_threshold = self.config.sigma_threshold;
+        let min_windows = self.config.min_baseline_windows;
+        let chain = self.chains.entry(chain_id).or_default();
+
+        let window_start = *chain.window_start.get_or_insert(now);
+        if now.duration_since(window_start) >= window {
+            // Close the current window: check it against the baseline, then fold it in.
+            let closed = chain.current_volume.saturating_to::<u128>();
+            let anomaly = (chain.baseline.len() >= min_windows)
+                .then(|| {
+                    let (mean, std_dev) = chain.baseline_stats();
+                    let sigma = if std_dev > 0.0 {
+                        (closed as f64 - mean).abs() / std_dev
+                    } else {
+                        0.0
+                    };
+                    (std_dev > 0.0 && sigma > sigma_threshold).then_some(
+                        TripReason::DepositVolumeAnomaly {
+                            observed: U256::from(closed),
+                            baseline_mean: mean,
+                            sigma,
+                        },
+                    )
+                })
+                .flatten();
+
+            chain.baseline.push_back(closed);
+            if chain.baseline.len() > MAX_BASELINE_WINDOWS {
+                chain.baseline.pop_front();
+            }
+            chain.current_volume = U256::ZERO;
+            chain.window_start = Some(now);
+
+            if let Some(reason) = anomaly {
+                tracing::error!(target: "bridge::breaker", chain_id, %reason, "circuit breaker tripped");
+                chain.tripped = Some(reason.clone());
+                return Some(reason);
+            }
+        }
+
+        chain.current_volume = chain.current_volume.saturating_add(amount);
+        None
+    }
+
+    /// Records an observed escrow balance on the origin chain.
+    ///
+    /// `expected_outflow` is the total of unlocks this sidecar submitted since
+    /// the previous observation; any decrease beyond it trips the chain's
+    /// breaker. Returns the trip reason if tripped.
+    pub fn observe_escrow_balance(
+        &mut self,
+        chain_id: u64,
+        balance: U256,
+        expected_outflow: U256,
+    ) -> Option<TripReason> {
+        let chain = self.chains.entry(chain_id).or_default();
+        let previous = chain.last_escrow_balance.replace(balance);
+        let Some(previous) = previous else {
+            return None;
+        };
+
+        let floor = previous.saturating_sub(expected_outflow);
+        if balance < floor {
+            let reason = TripReason::EscrowBalanceDecrease {
+                previous,
+                current: balance,
+            };
+            tracing::error!(target: "bridge::breaker", chain_id, %reason, "circuit breaker tripped");
+            chain.tripped = Some(reason.clone());
+            return Some(reason);
+        }
+        None
+    }
+
+    /// Trips the given scope. Used for manual locks and by detectors that live
+    /// outside this module.
+    pub fn trip(&mut self, scope: BreakerScope, reason: TripReason) {
+        match scope {
+            BreakerScope::Global => self.global_tripped = Some(reason),
+            BreakerScope::Chain(id) => {
+                self.chains.entry(id).or_default().tripped = Some(reason);
+            }
+        }
+    }
+
+    /// Resumes signing for the given scope.
+    ///
+    /// This is only reachable through `bridge-cli unlock --resume`; the breaker
+    /// never resumes on its own. Resuming a chain also discards its baseline so
+    /// detection re-arms from fresh data. Returns whether the scope was tripped.
+    pub fn resume(&mut self, scope: BreakerScope) -> bool {
+        match scope {
+            BreakerScope::Global => self.global_tripped.take().is_some(),
+            BreakerScope::Chain(id) => match self.chains.get_mut(&id) {
+                Some(chain) => {
+                    let was_tripped = chain.tripped.take().is_some();
+                    chain.baseline.clear();
+                    chain.current_volume = U256::ZERO;
+                    chain.window_start = None;
+                    was_tripped
+                }
+                None => false,
+            },
+        }
+    }
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new(CircuitBreakerConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker() -> CircuitBreaker {
+        CircuitBreaker::new(CircuitBreakerConfig {
+            sigma_threshold: 3.0,
+            volume_window: Duration::from_secs(60),
+            min_baseline_windows: 4,
+        })
+    }
+
+    #[test]
+    fn volume_anomaly_trips_chain() {
+        let mut b = breaker();
+        let start = Instant::now();
+        // Establish a baseline of ~100 per window with slight jitter.
+        for w in 0..8u64 {
+            let volume = 100 + (w % 2);
+            b.record_deposit(
+                1,
+                U256::from(volume),
+                start + Duration::from_secs(w * 60 + 1),
+            );
+        }
+        assert!(b.is_signing_allowed(1));
+
+        // A window with 100x the baseline volume must trip on close.
+        b.record_deposit(
+            1,
+            U256::from(10_000u64),
+            start + Duration::from_secs(8 * 60 + 1),
+        );
+        let reason = b.record_deposit(1, U256::from(1u64), start + Duration::from_secs(9 * 60 + 1));
+        assert!(matches!(
+            reason,
+            Some(TripReason::DepositVolumeAnomaly { .. })
+        ));
+        assert!(!b.is_signing_allowed(1));
+        // Other chains are unaffected.
+        assert!(b.is_signing_allowed(2));
+    }
+
+    #[test]
+    fn escrow_decrease_trips_unless_expected() {
+        let mut b = breaker();
+        assert_eq!(
+            b.observe_escrow_balance(1, U256::from(1000), U256::ZERO),
+            None
+        );
+        // Decrease covered by our own unlock is fine.
+        assert_eq!(
+            b.observe_escrow_balance(1, U256::from(900), U256::from(100)),
+            None
+        );
+        // Unexplained decrease trips.
+        let reason = b.observe_escrow_balance(1, U256::from(800), U256::ZERO);
+        assert!(matches!(
+            reason,
+            Some(TripReason::EscrowBalanceDecrease { .. })
+        ));
+        assert!(!b.is_signing_allowed(1));
+    }
+
+    #[test]
+    fn global_trip_halts_all_chains_and_requires_manual_resume() {
+        let mut b = breaker();
+        b.trip(BreakerScope::Global, TripReason::Manual);
+        assert!(!b.is_signing_allowed(1));
+        assert!(!b.is_signing_allowed(2));
+        assert!(b.resume(BreakerScope::Global));
+        assert!(b.is_signing_allowed(1));
+        // Resuming an untripped scope reports false.
+        assert!(!b.resume(BreakerScope::Global));
+    }
+
+    #[test]
+    fn resume_clears_baseline() {
+        let mut b = breaker();
+        let start = Instant::now();
+        for w in 0..6u64 {
+            b.record_deposit(
+                1,
+                U256::from(100u64),
+                start + Duration::from_secs(w * 60 + 1),
+            );
+        }
+        b.trip(BreakerScope::Chain(1), TripReason::Manual);
+        assert!(b.resume(BreakerScope::Chain(1)));
+        // Detection re-arms from scratch: a huge window right after resume
+        // cannot trip because the baseline is empty.
+        b.record_deposit(
+            1,
+            U256::from(1_000_000u64),
+            start + Duration::from_secs(7 * 60),
+        );
+        let reason = b.record_deposit(1, U256::from(1u64), start + Duration::from_secs(8 * 60 + 1));
+        assert_eq!(reason, None);
+        assert!(b.is_signing_allowed(1));
+    }
+}
@@ -0,0 +1,317 @@
+//! Clock-skew sanity checks across the chains the bridge straddles.
+//!
+//! Deposit finality decisions and period-based limits mix timestamps from
+//! three clocks: origin chain block timestamps, Tempo block timestamps, and
+//! the sidecar's local (ideally NTP-disciplined) clock. A validator with a
+//! badly skewed clock could consider a deposit final before the origin chain
+//! does, or release period-based limits early. [`ClockMonitor`] tracks the
+//! offset of each chain clock against local time, warns once skew crosses a
+//! soft threshold, and reports a halt once it crosses a hard one so callers
+//! can pause finality decisions until the clocks agree again.
+//!
+//! Unlike the [`crate::circuit_breaker`], a skew halt is not sticky: clock
+//! skew is expected to clear on its own once NTP catches up, so the monitor
+//! recovers as soon as fresh observations fall back under the threshold.
+
+use serde::{Deserialize, Serialize};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Default skew at which the monitor starts warning. Chain timestamps lag
+/// local time by up to a block interval even on healthy clocks, so this
+/// stays comfortably above typical block times.
+pub const DEFAULT_WARN_SKEW: Duration = Duration::from_secs(30);
+
+/// Default skew at which the monitor reports a halt. Past this, finality
+/// decisions and period-based limits can no longer be trusted.
+pub const DEFAULT_HALT_SKEW: Duration = Duration::from_secs(5 * 60);
+
+/// Configuration for the clock-skew monitor.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClockSanityConfig {
+    /// Warn once a chain clock's offset against local time exceeds this.
+    pub warn_skew: Duration,
+    /// Report a halt once the offset exceeds this.
+    pub halt_skew: Duration,
+}
+
+impl Default for ClockSanityConfig {
+    fn default() -> Self {
+        Self {
+            warn_skew: DEFAULT_WARN_SKEW,
+            halt_skew: DEFAULT_HALT_SKEW,
+        }
+    }
+}
+
+/// Which clock a timestamp was taken from. Recorded on state entries so an
+/// audit can tell whether a decision was made on chain time or local time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TimeSource {
+    /// The sidecar's local NTP-adjusted clock.
+    Local,
+    /// The latest observed origin chain block timestamp.
+    OriginChain,
+    /// The latest observed Tempo block timestamp.
+    TempoChain,
+}
+
+impl std::fmt::Display for TimeSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Local => write!(f, "local"),
+            Self::OriginChain => write!(f, "origin chain"),
+            Self::TempoChain => write!(f, "tempo chain"),
+        }
+    }
+}
+
+/// A timestamp annotated with the clock it was taken from and how far that
+/// clock sat from local time when it was read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnnotatedTime {
+    /// The timestamp, in Unix seconds.
+    pub unix_secs: u64,
+    /// The clock the timestamp was taken from.
+    pub source: TimeSource,
+    /// Offset of `source` against the local clock in seconds (positive when
+    /// the source runs ahead of local time). Zero for [`TimeSource::Local`].
+    pub skew_secs: i64,
+}
+
+/// Verdict of a skew assessment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClockStatus {
+    /// All observed clocks agree within the warn threshold.
+    Synchronized,
+    /// A chain clock's offset exceeds the warn threshold but not the halt
+    /// threshold. Operations may continue; the skew has been logged.
+    Skewed {
+        /// The most skewed chain clock.
+        source: TimeSource,
+        /// Its offset against local time in seconds.
+        skew_secs: i64,
+    },
+    /// A chain clock's offset exceeds the halt threshold. Finality decisions
+    /// and period-based limits should pause until the clocks agree again.
+    Halted {
+        /// The most skewed chain clock.
+        source: TimeSource,
+        /// Its offset against local time in seconds.
+        skew_secs: i64,
+    },
+}
+
+/// A chain timestamp paired with the local time it was observed at, so the
+/// offset stays meaningful as local time advances.
+#[derive(Debug, Clone, Copy)]
+struct Observation {
+    chain_secs: u64,
+    local_secs: u64,
+}
+
+impl Observation {
+    /// Offset of the chain clock against local time, positive when the chain
+    /// clock runs ahead.
+    fn skew_secs(&self) -> i64 {
+        self.chain_secs as i64 - self.local_secs as i64
+    }
+}
+
+/// Tracks the offsets of the origin and Tempo chain clocks against local
+/// time and grades them against the configured thresholds.
+#[derive(Debug, Default)]
+pub struct ClockMonitor {
+    config: ClockSanityConfig,
+    origin: Option<Observation>,
+    tempo: Option<Observation>,
+}
+
+impl ClockMonitor {
+    /// Creates a monitor with the given thresholds.
+    pub fn new(config: ClockSanityConfig) -> Self {
+        Self {
+            config,
+            origin: None,
+            tempo: None,
+        }
+    }
+
+    /// Records an origin chain block timestamp observed at `local_secs`.
+    pub fn observe_origin(&mut self, chain_secs: u64, local_secs: u64) {
+        self.origin = Some(Observation {
+            chain_secs,
+            local_secs,
+        });
+    }
+
+    /// Records a Tempo block timestamp observed at `local_secs`.
+    pub fn observe_tempo(&mut self, chain_secs: u64, local_secs: u64) {
+        self.tempo = Some(Observation {
+            chain_secs,
+            local_secs,
+        });
+    }
+
+    /// Grades the currently observed offsets against the thresholds.
+    ///
+    /// Warns via `tracing` while the skew is between the two thresholds.
+    /// Unobserved clocks are not graded: before the first block from a chain
+    /// arrives there is nothing to compare.
+    pub fn assess(&self) -> ClockStatus {
+        let worst = [
+            (TimeSource::OriginChain, self.origin),
+            (TimeSource::TempoChain, self.tempo),
+        ]
+        .into_iter()
+        .filter_map(|(source, obs)| obs.map(|obs| (source, obs.skew_secs())))
+        .max_by_key(|(_, skew)| skew.unsigned_abs());
+
+        let Some((source, skew_secs)) = worst else {
+            return ClockStatus::Synchronized;
+        };
+
+        if skew_secs.unsigned_abs() >= self.config.halt_skew.as_secs() {
+            tracing::error!(
+                target: "bridge",
+                %source,
+                skew_secs,
+                "clock skew exceeds halt threshold; pausing finality decisions"
+            );
+            ClockStatus::Halted { source, skew_secs }
+        } else if skew_secs.unsigned_abs() >= self.config.warn_skew.as_secs() {
+            tracing::warn!(
+                target: "bridge",
+                %source,
+                skew_secs,
+                "clock skew exceeds warn threshold"
+            );
+            ClockStatus::Skewed { source, skew_secs }
+        } else {
+            ClockStatus::Synchronized
+        }
+    }
+
+    /// Picks the clock a state record should be stamped with.
+    ///
+    /// Local time is preferred. The exception is when both chain clocks
+    /// agree with each other while both disagree with local time beyond the
+    /// warn threshold — then the local clock is the outlier, and the origin
+    /// chain clock (which drives deposit finality) is used instead.
+    pub fn annotate(&self, local_secs: u64) -> AnnotatedTime {
+        if let (Some(origin), Some(tempo)) = (self.origin, self.tempo) {
+            let warn = self.config.warn_skew.as_secs();
+            let chains_agree = origin.skew_secs().abs_diff(tempo.skew_secs()) < warn;
+            let local_is_outlier = origin.skew_secs().unsigned_abs() >= warn
+                && tempo.skew_secs().unsigned_abs() >= warn;
+
+            if chains_agree && local_is_outlier {
+                let skew_secs = origin.skew_secs();
+                return AnnotatedTime {
+                    unix_secs: local_secs.saturating_add_signed(skew_secs),
+                    source: TimeSource::OriginChain,
+                    skew_secs,
+                };
+            }
+        }
+
+        AnnotatedTime {
+            unix_secs: local_secs,
+            source: TimeSource::Local,
+            skew_secs: 0,
+        }
+    }
+}
+
+/// The local clock, in Unix seconds. Callers pass this into the monitor so
+/// tests can substitute a fixed time.
+pub fn local_now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOW: u64 = 1_700_000_000;
+
+    fn monitor() -> ClockMonitor {
+        ClockMonitor::new(ClockSanityConfig::default())
+    }
+
+    #[test]
+    fn synchronized_within_warn_threshold() {
+        let mut monitor = monitor();
+        monitor.observe_origin(NOW - 12, NOW);
+        monitor.observe_tempo(NOW - 2, NOW);
+        assert_eq!(monitor.assess(), ClockStatus::Synchronized);
+    }
+
+    #[test]
+    fn warns_between_thresholds() {
+        let mut monitor = monitor();
+        monitor.observe_origin(NOW - 60, NOW);
+        monitor.observe_tempo(NOW, NOW);
+        assert_eq!(
+            monitor.assess(),
+            ClockStatus::Skewed {
+                source: TimeSource::OriginChain,
+                skew_secs: -60,
+            }
+        );
+    }
+
+    #[test]
+    fn halts_past_hard_threshold_in_either_direction() {
+        let mut monitor = monitor();
+        monitor.observe_origin(NOW, NOW);
+        // A chain clock running ahead of local time is just as suspect.
+        monitor.observe_tempo(NOW + 600, NOW);
+        assert_eq!(
+            monitor.assess(),
+            ClockStatus::Halted {
+                source: TimeSource::TempoChain,
+                skew_secs: 600,
+            }
+        );
+    }
+
+    #[test]
+    fn unobserved_clocks_are_not_graded() {
+        assert_eq!(monitor().assess(), ClockStatus::Synchronized);
+    }
+
+    #[test]
+    fn annotate_prefers_local_time() {
+        let mut monitor = monitor();
+        monitor.observe_origin(NOW - 12, NOW);
+        monitor.observe_tempo(NOW, NOW);
+        let annotated = monitor.annotate(NOW);
+        assert_eq!(annotated.source, TimeSource::Local);
+        assert_eq!(annotated.unix_secs, NOW);
+        assert_eq!(annotated.skew_secs, 0);
+    }
+
+    #[test]
+    fn annotate_falls_back_to_origin_when_local_is_outlier() {
+        // Both chains agree with each other and both sit ~10 minutes ahead
+        // of local time: the local clock is the odd one out.
+        let mut monitor = monitor();
+        monitor.observe_origin(NOW + 600, NOW);
+        monitor.observe_tempo(NOW + 605, NOW);
+
+        let annotated = monitor.annotate(NOW);
+        assert_eq!(annotated.source, TimeSource::OriginChain);
+        assert_eq!(annotated.skew_secs, 600);
+        assert_eq!(annotated.unix_secs, NOW + 600);
+
+        // If the chains disagree with each other too, nothing is trustworthy
+        // enough to override local time.
+        monitor.observe_tempo(NOW - 600, NOW);
+        assert_eq!(monitor.annotate(NOW).source, TimeSource::Local);
+    }
+}
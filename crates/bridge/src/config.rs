@@ -0,0 +1,558 @@
+//! Unified, validated sidecar configuration.
+//!
+//! Replaces ad-hoc per-module parsing with one typed schema: the launcher
+//! loads a TOML file into [`BridgeConfig`], applies `TEMPO_BRIDGE_*`
+//! environment overrides, and runs cross-field validation before any module
+//! is constructed, so misconfigurations (a quorum requirement without a
+//! secondary RPC, a separate broadcaster without a key) fail at startup with
+//! every problem reported at once instead of surfacing mid-flight.
+//!
+//! Secrets are wrapped in [`Secret`], which redacts on both `Debug` and
+//! serialization; [`BridgeConfig::to_redacted_toml`] therefore emits the
+//! resolved effective config safely, which is what the sidecar's
+//! `--print-config` flag prints.
+
+use alloy_primitives::{Address, U256};
+use serde::{Deserialize, Serialize, Serializer};
+use std::{collections::HashSet, fmt, path::PathBuf, time::Duration};
+
+use crate::{
+    origin_watcher::EscrowConfig,
+    protocol_fee::{MAX_PROTOCOL_FEE_BPS, ProtocolFeeConfig},
+    prune::PruneConfig,
+};
+
+/// Prefix shared by all environment overrides.
+///
+/// A variable overrides one leaf field, with `__` separating the section from
+/// the field: `TEMPO_BRIDGE_ORIGIN__RPC_URL`, `TEMPO_BRIDGE_SUBMITTER__BROADCASTER_KEY`.
+pub const ENV_PREFIX: &str = "TEMPO_BRIDGE_";
+
+/// A sensitive value (private key, auth token) that never leaves the process
+/// in clear text: `Debug` and serialization both emit `<redacted>`.
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+#[serde(transparent)]
+pub struct Secret(String);
+
+impl Secret {
+    /// Wraps a sensitive value.
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(value.into())
+    }
+
+    /// Returns the clear-text value for use at the call site that needs it.
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Debug for Secret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Secret(<redacted>)")
+    }
+}
+
+impl Serialize for Secret {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str("<redacted>")
+    }
+}
+
+/// Top-level sidecar configuration schema.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BridgeConfig {
+    /// Directory holding the sidecar's persistent journals.
+    pub journal_dir: PathBuf,
+    /// Origin-chain connectivity and escrow set.
+    pub origin: OriginSection,
+    /// Unlock submission behaviour.
+    #[serde(default)]
+    pub submitter: SubmitterSection,
+    /// Completed-record pruning.
+    #[serde(default)]
+    pub prune: PruneSection,
+    /// Protocol fee mirror of the on-chain parameters.
+    #[serde(default)]
+    pub fees: FeesSection,
+}
+
+/// `[origin]` section.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct OriginSection {
+    /// Origin chain RPC endpoint. `ws://`/`wss://` enables subscriptions.
+    pub rpc_url: String,
+    /// Independent second RPC endpoint, required when `require_quorum` is set.
+    #[serde(default)]
+    pub secondary_rpc_url: Option<String>,
+    /// Require both RPC endpoints to agree on observed deposits before signing.
+    #[serde(default)]
+    pub require_quorum: bool,
+    /// Interval between `eth_getLogs` polls in polling mode, in seconds.
+    #[serde(default = "default_poll_interval_secs")]
+    pub poll_interval_secs: u64,
+    /// Escrow contracts whose deposit events are watched.
+    pub escrows: Vec<EscrowEntry>,
+}
+
+/// One `[[origin.escrows]]` entry.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct EscrowEntry {
+    /// Escrow contract address.
+    pub address: Address,
+    /// Blocks that must be built on top of a deposit before it is forwarded.
+    #[serde(default)]
+    pub confirmations: u64,
+    /// Per-deposit amount cap; deposits above it are left for manual review.
+    #[serde(default)]
+    pub cap: Option<U256>,
+}
+
+/// `[submitter]` section.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct SubmitterSection {
+    /// Submit unlocks from a dedicated broadcaster account instead of the
+    /// validator key. Requires `broadcaster_key`.
+    #[serde(default)]
+    pub separate_broadcaster: bool,
+    /// Private key of the broadcaster account.
+    #[serde(default)]
+    pub broadcaster_key: Option<Secret>,
+    /// Per-burn submitter rotation interval in seconds; `0` disables the
+    /// election and every sidecar submits.
+    #[serde(default)]
+    pub failover_interval_secs: u64,
+    /// Run the full pipeline against production traffic but never broadcast:
+    /// every would-be unlock is logged with its gas estimate instead. Used to
+    /// validate new chain integrations before going live.
+    #[serde(default)]
+    pub dry_run: bool,
+}
+
+/// `[prune]` section.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PruneSection {
+    /// Origin blocks a completed deposit record is retained before pruning.
+    pub retention_blocks: u64,
+    /// Interval between compaction runs, in seconds.
+    pub interval_secs: u64,
+}
+
+impl Default for PruneSection {
+    fn default() -> Self {
+        let defaults = PruneConfig::default();
+        Self {
+            retention_blocks: defaults.retention_blocks,
+            interval_secs: defaults.interval.as_secs(),
+        }
+    }
+}
+
+/// `[fees]` section: mirror of the on-chain protocol fee parameters.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct FeesSection {
+    /// Protocol fee in basis points taken from every bridged amount.
+    pub fee_bps: u64,
+    /// Treasury address the fee portion is routed to.
+    pub treasury: Address,
+}
+
+impl Default for FeesSection {
+    fn default() -> Self {
+        let defaults = ProtocolFeeConfig::default();
+        Self {
+            fee_bps: defaults.fee_bps,
+            treasury: defaults.treasury,
+        }
+    }
+}
+
+impl FeesSection {
+    /// The typed fee configuration the bridge modules consume.
+    pub fn as_protocol_fee_config(&self) -> ProtocolFeeConfig {
+        ProtocolFeeConfig {
+            fee_bps: self.fee_bps,
+            treasury: self.treasury,
+        }
+    }
+}
+
+fn default_poll_interval_secs() -> u64 {
+    12
+}
+
+/// Error loading or validating the configuration.
+#[derive(Debug, thiserror::Error)]
+pub enum ConfigError {
+    /// The file could not be read.
+    #[error("failed to read config file: {0}")]
+    Io(#[from] std::io::Error),
+    /// The TOML did not match the schema.
+    #[error("failed to parse config: {0}")]
+    Parse(#[from] toml::de::Error),
+    /// An environment override used an unknown field path.
+    #[error("unknown config override {key}")]
+    UnknownEnvKey {
+        /// Full variable name.
+        key: String,
+    },
+    /// An environment override value did not parse as the field's type.
+    #[error("invalid value for {key}: {reason}")]
+    InvalidEnvValue {
+        /// Full variable name.
+        key: String,
+        /// Parse failure description.
+        reason: String,
+    },
+    /// Cross-field validation failed; all problems are reported together.
+    #[error("invalid config: {}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join("; "))]
+    Invalid(Vec<ValidationError>),
+}
+
+/// A single cross-field validation failure.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ValidationError {
+    /// `origin.require_quorum` without `origin.secondary_rpc_url`.
+    #[error("origin.require_quorum requires origin.secondary_rpc_url")]
+    QuorumRequiresSecondaryRpc,
+    /// `submitter.separate_broadcaster` without `submitter.broadcaster_key`.
+    #[error("submitter.separate_broadcaster requires submitter.broadcaster_key")]
+    BroadcasterKeyRequired,
+    /// No escrows configured: the sidecar would watch nothing.
+    #[error("origin.escrows must not be empty")]
+    NoEscrows,
+    /// The same escrow address appears twice.
+    #[error("duplicate escrow address {0}")]
+    DuplicateEscrow(Address),
+    /// A zero poll interval would spin the watcher.
+    #[error("origin.poll_interval_secs must be non-zero")]
+    ZeroPollInterval,
+    /// `fees.fee_bps` above the on-chain cap.
+    #[error("fees.fee_bps must not exceed {MAX_PROTOCOL_FEE_BPS}")]
+    FeeTooHigh,
+    /// A non-zero fee configured without a treasury to route it to.
+    #[error("fees.fee_bps requires fees.treasury")]
+    FeeRequiresTreasury,
+}
+
+impl BridgeConfig {
+    /// Loads the config from a TOML file, applies `TEMPO_BRIDGE_*` overrides
+    /// from the process environment, and validates it.
+    pub fn load(path: impl AsRef<std::path::Path>) -> Result<Self, ConfigError> {
+        let mut config = Self::from_toml_str(&std::fs::read_to_string(path)?)?;
+        config.apply_env_overrides(std::env::vars())?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// Parses the TOML schema without overrides or validation.
+    pub fn from_toml_str(toml: &str) -> Result<Self, ConfigError> {
+        Ok(toml::from_str(toml)?)
+    }
+
+    /// Applies environment overrides from `vars`.
+    ///
+    /// Only scalar leaf fields can be overridden; list-valued fields
+    /// (escrows) come from the file. Variables with the prefix but an unknown
+    /// path are an error so typos do not silently fall back to file values.
+    pub fn apply_env_overrides(
+        &mut self,
+        vars: impl IntoIterator<Item = (String, String)>,
+    ) -> Result<(), ConfigError> {
+        for (key, value) in vars {
+            let Some(path) = key.strip_prefix(ENV_PREFIX) else {
+                continue;
+            };
+            let parse_u64 = |value: &str| {
+                value
+                    .parse::<u64>()
+                    .map_err(|err| ConfigError::InvalidEnvValue {
+                        key: key.clone(),
+                        reason: err.to_string(),
+                    })
+            };
+            let parse_bool = |value: &str| {
+                value
+                    .parse::<bool>()
+                    .map_err(|err| ConfigError::InvalidEnvValue {
+                        key: key.clone(),
+                        reason: err.to_string(),
+                    })
+            };
+            match path {
+                "JOURNAL_DIR" => self.journal_dir = PathBuf::from(value),
+                "ORIGIN__RPC_URL" => self.origin.rpc_url = value,
+                "ORIGIN__SECONDARY_RPC_URL" => self.origin.secondary_rpc_url = Some(value),
+                "ORIGIN__REQUIRE_QUORUM" => self.origin.require_quorum = parse_bool(&value)?,
+                "ORIGIN__POLL_INTERVAL_SECS" => self.origin.poll_interval_secs = parse_u64(&value)?,
+                "SUBMITTER__SEPARATE_BROADCASTER" => {
+                    self.submitter.separate_broadcaster = parse_bool(&value)?
+                }
+                "SUBMITTER__BROADCASTER_KEY" => {
+                    self.submitter.broadcaster_key = Some(Secret::new(value))
+                }
+                "SUBMITTER__FAILOVER_INTERVAL_SECS" => {
+                    self.submitter.failover_interval_secs = parse_u64(&value)?
+                }
+                "PRUNE__RETENTION_BLOCKS" => self.prune.retention_blocks = parse_u64(&value)?,
+                "PRUNE__INTERVAL_SECS" => self.prune.interval_secs = parse_u64(&value)?,
+                "FEES__FEE_BPS" => self.fees.fee_bps = parse_u64(&value)?,
+                "FEES__TREASURY" => {
+                    self.fees.treasury =
+                        value
+                            .parse::<Address>()
+                            .map_err(|err| ConfigError::InvalidEnvValue {
+                                key: key.clone(),
+                                reason: err.to_string(),
+                            })?
+                }
+                _ => return Err(ConfigError::UnknownEnvKey { key }),
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs cross-field validation, collecting every problem.
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let mut errors = Vec::new();
+
+        if self.origin.require_quorum && self.origin.secondary_rpc_url.is_none() {
+            errors.push(ValidationError::QuorumRequiresSecondaryRpc);
+        }
+        if self.submitter.separate_broadcaster && self.submitter.broadcaster_key.is_none() {
+            errors.push(ValidationError::BroadcasterKeyRequired);
+        }
+        if self.origin.escrows.is_empty() {
+            errors.push(ValidationError::NoEscrows);
+        }
+        let mut seen = HashSet::new();
+        for escrow in &self.origin.escrows {
+            if !seen.insert(escrow.address) {
+                errors.push(ValidationError::DuplicateEscrow(escrow.address));
+            }
+        }
+        if self.origin.poll_interval_secs == 0 {
+            errors.push(ValidationError::ZeroPollInterval);
+        }
+        match self.fees.as_protocol_fee_config().validate() {
+            Ok(()) => {}
+            Err(crate::protocol_fee::ProtocolFeeError::FeeTooHigh { .. }) => {
+                errors.push(ValidationError::FeeTooHigh);
+            }
+            Err(crate::protocol_fee::ProtocolFeeError::MissingTreasury) => {
+                errors.push(ValidationError::FeeRequiresTreasury);
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::Invalid(errors))
+        }
+    }
+
+    /// Renders the resolved effective config as TOML with secrets redacted,
+    /// for `--print-config`.
+    pub fn to_redacted_toml(&self) -> String {
+        toml::to_string_pretty(self).expect("config schema serializes to TOML")
+    }
+
+    /// The escrow set as [`origin_watcher`](crate::origin_watcher) configs.
+    pub fn escrow_configs(&self) -> Vec<EscrowConfig> {
+        self.origin
+            .escrows
+            .iter()
+            .map(|escrow| EscrowConfig {
+                address: escrow.address,
+                confirmations: escrow.confirmations,
+                cap: escrow.cap,
+            })
+            .collect()
+    }
+
+    /// The `[prune]` section as a [`PruneConfig`].
+    pub fn prune_config(&self) -> PruneConfig {
+        PruneConfig {
+            retention_blocks: self.prune.retention_blocks,
+            interval: Duration::from_secs(self.prune.interval_secs),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::address;
+
+    const EXAMPLE: &str = r#"
+journal_dir = "/var/lib/tempo/bridge"
+
+[origin]
+rpc_url = "wss://origin.example"
+poll_interval_secs = 6
+
+[[origin.escrows]]
+address = "0x1111111111111111111111111111111111111111"
+confirmations = 6
+
+[submitter]
+failover_interval_secs = 300
+"#;
+
+    #[test]
+    fn parses_example_and_validates() {
+        let config = BridgeConfig::from_toml_str(EXAMPLE).unwrap();
+        config.validate().unwrap();
+
+        assert_eq!(config.origin.poll_interval_secs, 6);
+        assert_eq!(config.escrow_configs().len(), 1);
+        assert_eq!(
+            config.escrow_configs()[0].address,
+            address!("0x1111111111111111111111111111111111111111")
+        );
+        let prune = config.prune_config();
+        assert_eq!(
+            prune.retention_blocks,
+            PruneConfig::default().retention_blocks
+        );
+        assert_eq!(prune.interval, PruneConfig::default().interval);
+    }
+
+    #[test]
+    fn env_overrides_replace_file_values() {
+        let mut config = BridgeConfig::from_toml_str(EXAMPLE).unwrap();
+        config
+            .apply_env_overrides([
+                ("TEMPO_BRIDGE_ORIGIN__RPC_URL".into(), "wss://other".into()),
+                ("TEMPO_BRIDGE_PRUNE__RETENTION_BLOCKS".into(), "42".into()),
+                ("UNRELATED_VAR".into(), "ignored".into()),
+            ])
+            .unwrap();
+
+        assert_eq!(config.origin.rpc_url, "wss://other");
+        assert_eq!(config.prune.retention_blocks, 42);
+
+        let typo = config
+            .apply_env_overrides([("TEMPO_BRIDGE_ORIGIN__RPC_ULR".into(), "x".into())])
+            .unwrap_err();
+        assert!(matches!(typo, ConfigError::UnknownEnvKey { .. }));
+
+        let bad_value = config
+            .apply_env_overrides([(
+                "TEMPO_BRIDGE_PRUNE__RETENTION_BLOCKS".into(),
+                "not-a-number".into(),
+            )])
+            .unwrap_err();
+        assert!(matches!(bad_value, ConfigError::InvalidEnvValue { .. }));
+    }
+
+    #[test]
+    fn cross_field_validation_collects_all_problems() {
+        let mut config = BridgeConfig::from_toml_str(EXAMPLE).unwrap();
+        config.origin.require_quorum = true;
+        config.submitter.separate_broadcaster = true;
+        config.origin.escrows.clear();
+        config.origin.poll_interval_secs = 0;
+
+        let ConfigError::Invalid(errors) = config.validate().unwrap_err() else {
+            panic!("expected validation errors");
+        };
+        assert_eq!(
+            errors,
+            vec![
+                ValidationError::QuorumRequiresSecondaryRpc,
+                ValidationError::BroadcasterKeyRequired,
+                ValidationError::NoEscrows,
+                ValidationError::ZeroPollInterval,
+            ]
+        );
+
+        // Satisfying the requirements clears the quorum/broadcaster errors.
+        config.origin.secondary_rpc_url = Some("https://second.example".into());
+        config.submitter.broadcaster_key = Some(Secret::new("0xsecret"));
+        let ConfigError::Invalid(errors) = config.validate().unwrap_err() else {
+            panic!("expected validation errors");
+        };
+        assert_eq!(
+            errors,
+            vec![
+                ValidationError::NoEscrows,
+                ValidationError::ZeroPollInterval
+            ]
+        );
+    }
+
+    #[test]
+    fn fee_section_is_validated_and_overridable() {
+        let mut config = BridgeConfig::from_toml_str(EXAMPLE).unwrap();
+        assert!(!config.fees.as_protocol_fee_config().is_enabled());
+
+        config
+            .apply_env_overrides([
+                ("TEMPO_BRIDGE_FEES__FEE_BPS".into(), "25".into()),
+                (
+                    "TEMPO_BRIDGE_FEES__TREASURY".into(),
+                    "0x2222222222222222222222222222222222222222".into(),
+                ),
+            ])
+            .unwrap();
+        assert_eq!(config.fees.fee_bps, 25);
+        assert_eq!(
+            config.fees.treasury,
+            address!("0x2222222222222222222222222222222222222222")
+        );
+        config.validate().unwrap();
+
+        // A fee without a treasury, or above the cap, fails validation.
+        config.fees.treasury = Address::ZERO;
+        config.fees.fee_bps = MAX_PROTOCOL_FEE_BPS + 1;
+        let ConfigError::Invalid(errors) = config.validate().unwrap_err() else {
+            panic!("expected validation errors");
+        };
+        assert_eq!(errors, vec![ValidationError::FeeTooHigh]);
+
+        config.fees.fee_bps = 25;
+        let ConfigError::Invalid(errors) = config.validate().unwrap_err() else {
+            panic!("expected validation errors");
+        };
+        assert_eq!(errors, vec![ValidationError::FeeRequiresTreasury]);
+    }
+
+    #[test]
+    fn duplicate_escrows_are_rejected() {
+        let mut config = BridgeConfig::from_toml_str(EXAMPLE).unwrap();
+        config.origin.escrows.push(config.origin.escrows[0].clone());
+
+        let ConfigError::Invalid(errors) = config.validate().unwrap_err() else {
+            panic!("expected validation errors");
+        };
+        assert_eq!(
+            errors,
+            vec![ValidationError::DuplicateEscrow(address!(
+                "0x1111111111111111111111111111111111111111"
+            ))]
+        );
+    }
+
+    #[test]
+    fn redacted_output_never_contains_secrets() {
+        let mut config = BridgeConfig::from_toml_str(EXAMPLE).unwrap();
+        config.submitter.broadcaster_key = Some(Secret::new("0xdeadbeef"));
+
+        let rendered = config.to_redacted_toml();
+        assert!(!rendered.contains("0xdeadbeef"));
+        assert!(rendered.contains("<redacted>"));
+        // Debug output is redacted too.
+        assert!(!format!("{config:?}").contains("0xdeadbeef"));
+
+        assert_eq!(
+            config.submitter.broadcaster_key.unwrap().expose(),
+            "0xdeadbeef"
+        );
+    }
+}
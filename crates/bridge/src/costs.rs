@@ -0,0 +1,298 @@
+//! Fee accounting for sidecar-submitted transactions.
+//!
+//! The sidecar pays gas in three places: relaying origin headers into the
+//! Tempo light client, submitting unlocks on origin chain escrows, and
+//! submitting threshold signatures on Tempo. Each confirmed receipt is
+//! journaled as a [`FeeRecord`] (one JSON record per line, same on-disk shape
+//! as the unlock journal), and [`CostReport::aggregate`] folds a time window
+//! of records into per-flow, per-chain totals with a monthly projection for
+//! budgeting.
+
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::BTreeMap,
+    io::{BufRead as _, BufReader, Read},
+};
+
+/// Seconds in the 30-day month used for cost projection.
+const MONTH_SECS: u64 = 30 * 24 * 60 * 60;
+
+/// Which bridge flow paid a fee.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Flow {
+    /// Origin chain header relayed into the Tempo light client.
+    HeaderRelay,
+    /// Unlock transaction submitted to an origin chain escrow.
+    Unlock,
+    /// Threshold signature submission on Tempo.
+    SignatureSubmission,
+}
+
+impl Flow {
+    /// Human-readable flow name, matching the serialized form.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::HeaderRelay => "header_relay",
+            Self::Unlock => "unlock",
+            Self::SignatureSubmission => "signature_submission",
+        }
+    }
+}
+
+/// One confirmed fee-paying transaction, derived from its receipt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeeRecord {
+    /// Chain the fee was paid on (origin chain id, or Tempo's).
+    pub chain_id: u64,
+    /// Flow that submitted the transaction.
+    pub flow: Flow,
+    /// Unix timestamp (seconds) of the block containing the receipt.
+    pub timestamp: u64,
+    /// Gas consumed per the receipt.
+    pub gas_used: u64,
+    /// Effective gas price per the receipt, in wei per gas.
+    pub effective_gas_price: u128,
+}
+
+impl FeeRecord {
+    /// Total fee paid, in wei of the submitting chain's native asset.
+    pub fn cost_wei(&self) -> u128 {
+        u128::from(self.gas_used).saturating_mul(self.effective_gas_price)
+    }
+}
+
+/// Errors raised while reading a fee journal or parsing report options.
+#[derive(Debug, thiserror::Error)]
+pub enum CostsError {
+    /// The journal could not be read.
+    #[error("fee journal io error: {0}")]
+    Io(#[from] std::io::Error),
+    /// A line is not a valid JSON record.
+    #[error("malformed fee record on line {line}: {err}")]
+    MalformedEntry {
+        /// One-based line number.
+        line: usize,
+        /// Underlying JSON error.
+        err: serde_json::Error,
+    },
+    /// The window string is not of the form `<n>(h|d|w)`.
+    #[error("invalid window {0:?}: expected e.g. \"24h\", \"7d\", or \"2w\"")]
+    InvalidWindow(String),
+}
+
+/// Reads a line-delimited JSON fee journal into records.
+pub fn read_fee_records(reader: impl Read) -> Result<Vec<FeeRecord>, CostsError> {
+    let mut records = Vec::new();
+    for (idx, line) in BufReader::new(reader).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let record: FeeRecord = serde_json::from_str(&line)
+            .map_err(|err| CostsError::MalformedEntry { line: idx + 1, err })?;
+        records.push(record);
+    }
+    Ok(records)
+}
+
+/// Parses a report window like `"24h"`, `"7d"`, or `"2w"` into seconds.
+pub fn parse_window(window: &str) -> Result<u64, CostsError> {
+    let invalid = || CostsError::InvalidWindow(window.to_string());
+
+    let (count, unit) = window.split_at(window.len().saturating_sub(1));
+    let count: u64 = count.parse().map_err(|_| invalid())?;
+    let unit_secs = match unit {
+        "h" => 60 * 60,
+        "d" => 24 * 60 * 60,
+        "w" => 7 * 24 * 60 * 60,
+        _ => return Err(invalid()),
+    };
+    count
+        .checked_mul(unit_secs)
+        .filter(|secs| *secs > 0)
+        .ok_or_else(invalid)
+}
+
+/// Aggregated fees for one (chain, flow) bucket.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FlowCosts {
+    /// Number of fee-paying transactions.
+    pub count: u64,
+    /// Total fees paid, in wei.
+    pub total_wei: u128,
+}
+
+/// Per-flow, per-chain fee totals over a report window.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CostReport {
+    /// Length of the report window in seconds.
+    pub window_secs: u64,
+    /// Totals keyed by `(chain_id, flow)`, ordered for stable output.
+    pub buckets: BTreeMap<(u64, Flow), FlowCosts>,
+}
+
+impl CostReport {
+    /// Aggregates records whose timestamp falls within `window_secs` of `now`
+    /// (inclusive on both ends). Older records are ignored, so a long-lived
+    /// journal can be re-reported over any window without pruning.
+    pub fn aggregate(
+        records: impl IntoIterator<Item = FeeRecord>,
+        now: u64,
+        window_secs: u64,
+    ) -> Self {
+        let cutoff = now.saturating_sub(window_secs);
+
+        let mut buckets: BTreeMap<(u64, Flow), FlowCosts> = BTreeMap::new();
+        for record in records {
+            if record.timestamp < cutoff || record.timestamp > now {
+                continue;
+            }
+            let bucket = buckets.entry((record.chain_id, record.flow)).or_default();
+            bucket.count += 1;
+            bucket.total_wei = bucket.total_wei.saturating_add(record.cost_wei());
+        }
+
+        Self {
+            window_secs,
+            buckets,
+        }
+    }
+
+    /// Total fees across all chains and flows, in wei.
+    pub fn total_wei(&self) -> u128 {
+        self.buckets
+            .values()
+            .fold(0u128, |acc, b| acc.saturating_add(b.total_wei))
+    }
+
+    /// Total fees paid on one chain across all flows, in wei.
+    pub fn chain_total_wei(&self, chain_id: u64) -> u128 {
+        self.buckets
+            .iter()
+            .filter(|((chain, _), _)| *chain == chain_id)
+            .fold(0u128, |acc, (_, b)| acc.saturating_add(b.total_wei))
+    }
+
+    /// Chain ids present in the report, in ascending order.
+    pub fn chains(&self) -> Vec<u64> {
+        let mut chains: Vec<u64> = self.buckets.keys().map(|(chain, _)| *chain).collect();
+        chains.dedup();
+        chains
+    }
+
+    /// Projects the windowed spend onto a 30-day month, per chain.
+    ///
+    /// Linear extrapolation: `chain_total * 30d / window`. Zero-length
+    /// windows project to zero rather than dividing by zero.
+    pub fn projected_monthly_wei(&self, chain_id: u64) -> u128 {
+        if self.window_secs == 0 {
+            return 0;
+        }
+        self.chain_total_wei(chain_id)
+            .saturating_mul(u128::from(MONTH_SECS))
+            / u128::from(self.window_secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(chain_id: u64, flow: Flow, timestamp: u64, gas_used: u64, price: u128) -> FeeRecord {
+        FeeRecord {
+            chain_id,
+            flow,
+            timestamp,
+            gas_used,
+            effective_gas_price: price,
+        }
+    }
+
+    #[test]
+    fn aggregate_buckets_per_chain_and_flow() {
+        let now = 1_000_000;
+        let records = vec![
+            record(1, Flow::HeaderRelay, now - 10, 100_000, 20),
+            record(1, Flow::HeaderRelay, now - 20, 100_000, 30),
+            record(1, Flow::Unlock, now - 30, 200_000, 10),
+            record(42, Flow::SignatureSubmission, now - 40, 50_000, 5),
+            // Outside the window: ignored.
+            record(1, Flow::HeaderRelay, now - 10_000, 100_000, 1_000),
+            // In the future (clock skew): ignored.
+            record(1, Flow::HeaderRelay, now + 10, 100_000, 1_000),
+        ];
+
+        let report = CostReport::aggregate(records, now, 3_600);
+
+        assert_eq!(
+            report.buckets[&(1, Flow::HeaderRelay)],
+            FlowCosts {
+                count: 2,
+                total_wei: 100_000 * 20 + 100_000 * 30,
+            }
+        );
+        assert_eq!(
+            report.buckets[&(1, Flow::Unlock)],
+            FlowCosts {
+                count: 1,
+                total_wei: 200_000 * 10,
+            }
+        );
+        assert_eq!(report.chain_total_wei(1), 5_000_000 + 2_000_000);
+        assert_eq!(report.chain_total_wei(42), 250_000);
+        assert_eq!(report.total_wei(), 7_000_000 + 250_000);
+        assert_eq!(report.chains(), vec![1, 42]);
+    }
+
+    #[test]
+    fn monthly_projection_extrapolates_linearly() {
+        let now = 1_000_000;
+        let day = 24 * 60 * 60;
+        let report = CostReport::aggregate(
+            vec![record(1, Flow::Unlock, now - 1, 1_000, 1_000)],
+            now,
+            // A 3-day window projects to 10x over a 30-day month.
+            3 * day,
+        );
+
+        assert_eq!(report.chain_total_wei(1), 1_000_000);
+        assert_eq!(report.projected_monthly_wei(1), 10_000_000);
+        // Chains with no spend project to zero.
+        assert_eq!(report.projected_monthly_wei(2), 0);
+    }
+
+    #[test]
+    fn parse_window_accepts_hours_days_weeks() {
+        assert_eq!(parse_window("24h").unwrap(), 24 * 60 * 60);
+        assert_eq!(parse_window("7d").unwrap(), 7 * 24 * 60 * 60);
+        assert_eq!(parse_window("2w").unwrap(), 14 * 24 * 60 * 60);
+
+        for bad in ["", "7", "d", "0d", "-1d", "7x", "1.5d"] {
+            assert!(
+                matches!(parse_window(bad), Err(CostsError::InvalidWindow(_))),
+                "expected {bad:?} to be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn read_fee_records_round_trips_journal_lines() {
+        let records = vec![
+            record(1, Flow::HeaderRelay, 100, 21_000, 7),
+            record(2, Flow::SignatureSubmission, 200, 80_000, 3),
+        ];
+        let journal = records
+            .iter()
+            .map(|r| serde_json::to_string(r).unwrap())
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let parsed = read_fee_records(journal.as_bytes()).unwrap();
+        assert_eq!(parsed, records);
+
+        let err = read_fee_records("not json".as_bytes()).unwrap_err();
+        assert!(matches!(err, CostsError::MalformedEntry { line: 1, .. }));
+    }
+}
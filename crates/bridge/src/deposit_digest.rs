@@ -0,0 +1,241 @@
+//! Time-bounded deposit signing digests.
+//!
+//! A validator signature over a deposit used to be valid forever: a digest
+//! covering only the deposit fields could be replayed months later, after the
+//! validator set that produced it had churned out. The digest now commits to a
+//! `valid_until` timestamp, so every approval expires. Verifiers reject
+//! signatures past their deadline as well as deadlines further in the future
+//! than the maximum window, which bounds what a signer with a skewed clock (or
+//! a compromised signer trying to stockpile approvals) can produce.
+//!
+//! The domain tag is versioned: v1 digests (no expiry) and v2 digests can
+//! never collide, so the rollout cannot be downgraded by replaying a v1
+//! signature against a v2 verifier.
+
+use crate::{origin_watcher::Deposit, protocol_fee::FeeSplit};
+use alloy_primitives::{Address, B256, keccak256};
+use std::time::Duration;
+
+/// Domain separation tag for v2 (expiring) deposit digests.
+pub const DEPOSIT_DIGEST_DOMAIN_V2: &[u8] = b"TEMPO_BRIDGE_DEPOSIT_V2";
+
+/// Domain separation tag for v3 (fee-aware) deposit digests.
+pub const DEPOSIT_DIGEST_DOMAIN_V3: &[u8] = b"TEMPO_BRIDGE_DEPOSIT_V3";
+
+/// Default validity window for a deposit signature.
+///
+/// Long enough to ride out origin chain reorgs and sidecar restarts, short
+/// enough that a signature cannot outlive the validator-set epoch it was
+/// produced under.
+pub const DEFAULT_SIGNATURE_VALIDITY: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Hard cap on the validity window a verifier accepts.
+///
+/// A digest whose deadline lies further in the future than this is rejected
+/// outright, regardless of the local validity configuration.
+pub const MAX_SIGNATURE_VALIDITY: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// Validity window applied when producing deposit signatures.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignatureValidityConfig {
+    /// How long a freshly produced signature remains acceptable.
+    pub validity_window: Duration,
+}
+
+impl Default for SignatureValidityConfig {
+    fn default() -> Self {
+        Self {
+            validity_window: DEFAULT_SIGNATURE_VALIDITY,
+        }
+    }
+}
+
+impl SignatureValidityConfig {
+    /// Returns the `valid_until` deadline for a signature produced at `now`
+    /// (Unix seconds).
+    pub fn deadline_from(&self, now: u64) -> u64 {
+        now.saturating_add(self.validity_window.as_secs())
+    }
+}
+
+/// Reasons a time-bounded digest fails verification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum DigestValidityError {
+    /// The signature deadline has passed.
+    #[error("deposit signature expired at {valid_until}, now {now}")]
+    Expired {
+        /// Deadline committed to in the digest.
+        valid_until: u64,
+        /// Verifier's current time.
+        now: u64,
+    },
+    /// The deadline is further out than [`MAX_SIGNATURE_VALIDITY`] allows.
+    #[error("deposit signature deadline {valid_until} exceeds the maximum validity window")]
+    WindowTooLong {
+        /// Deadline committed to in the digest.
+        valid_until: u64,
+        /// Verifier's current time.
+        now: u64,
+    },
+}
+
+/// Computes the v2 digest a validator signs to approve `deposit`.
+///
+/// Commits to the deposit id (which itself covers escrow, transaction hash and
+/// log index), the Tempo recipient, the amount, and the `valid_until` deadline
+/// in Unix seconds. The same preimage layout is verified on-chain, so any
+/// field mismatch — including a tampered deadline — invalidates the signature.
+pub fn deposit_signing_digest(deposit: &Deposit, valid_until: u64) -> B256 {
+    let mut preimage = Vec::with_capacity(DEPOSIT_DIGEST_DOMAIN_V2.len() + 32 + 20 + 32 + 8);
+    preimage.extend_from_slice(DEPOSIT_DIGEST_DOMAIN_V2);
+    preimage.extend_from_slice(deposit.id().as_slice());
+    preimage.extend_from_slice(deposit.recipient.as_slice());
+    preimage.extend_from_slice(&deposit.amount.to_be_bytes::<32>());
+    preimage.extend_from_slice(&valid_until.to_be_bytes());
+    keccak256(preimage)
+}
+
+/// Computes the v3 digest a validator signs to approve `deposit` with a
+/// protocol fee applied.
+///
+/// Extends the v2 preimage with the fee split: validators commit to the exact
+/// net amount the recipient is minted, the exact fee, and the treasury it is
+/// routed to. A sidecar whose mirrored fee parameters drift from the on-chain
+/// configuration therefore produces signatures the verifier rejects instead of
+/// mints with the wrong fee. The versioned domain tag keeps v2 and v3 digests
+/// from ever colliding.
+pub fn deposit_signing_digest_v3(
+    deposit: &Deposit,
+    split: &FeeSplit,
+    treasury: Address,
+    valid_until: u64,
+) -> B256 {
+    debug_assert_eq!(
+        split.gross, deposit.amount,
+        "fee split must cover the deposit amount"
+    );
+    let mut preimage =
+        Vec::with_capacity(DEPOSIT_DIGEST_DOMAIN_V3.len() + 32 + 20 + 32 + 32 + 20 + 8);
+    preimage.extend_from_slice(DEPOSIT_DIGEST_DOMAIN_V3);
+    preimage.extend_from_slice(deposit.id().as_slice());
+    preimage.extend_from_slice(deposit.recipient.as_slice());
+    preimage.extend_from_slice(&split.net.to_be_bytes::<32>());
+    preimage.extend_from_slice(&split.fee.to_be_bytes::<32>());
+    preimage.extend_from_slice(treasury.as_slice());
+    preimage.extend_from_slice(&valid_until.to_be_bytes());
+    keccak256(preimage)
+}
+
+/// Checks the deadline of a v2 digest against the verifier's clock.
+///
+/// Mirrors the check performed by the on-chain verifier: the deadline must not
+/// have passed and must not claim a window longer than
+/// [`MAX_SIGNATURE_VALIDITY`].
+pub fn check_digest_validity(valid_until: u64, now: u64) -> Result<(), DigestValidityError> {
+    if valid_until < now {
+        return Err(DigestValidityError::Expired { valid_until, now });
+    }
+    if valid_until - now > MAX_SIGNATURE_VALIDITY.as_secs() {
+        return Err(DigestValidityError::WindowTooLong { valid_until, now });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Address, U256};
+
+    fn deposit() -> Deposit {
+        Deposit {
+            escrow: Address::with_last_byte(1),
+            block_number: 100,
+            tx_hash: B256::with_last_byte(2),
+            log_index: 3,
+            from: Address::with_last_byte(4),
+            recipient: Address::with_last_byte(5),
+            amount: U256::from(1_000_000u64),
+        }
+    }
+
+    #[test]
+    fn digest_commits_to_the_deadline() {
+        let deposit = deposit();
+        let digest = deposit_signing_digest(&deposit, 1_000);
+        assert_ne!(digest, deposit_signing_digest(&deposit, 1_001));
+
+        let mut other = deposit.clone();
+        other.recipient = Address::with_last_byte(6);
+        assert_ne!(digest, deposit_signing_digest(&other, 1_000));
+    }
+
+    #[test]
+    fn v3_digest_commits_to_the_fee_split() {
+        let deposit = deposit();
+        let config = crate::protocol_fee::ProtocolFeeConfig {
+            fee_bps: 25,
+            treasury: Address::with_last_byte(0xee),
+        };
+        let split = config.split(deposit.amount);
+        let digest = deposit_signing_digest_v3(&deposit, &split, config.treasury, 1_000);
+
+        // A different fee, treasury, or deadline yields a different digest.
+        let other_split = crate::protocol_fee::ProtocolFeeConfig {
+            fee_bps: 50,
+            ..config
+        }
+        .split(deposit.amount);
+        assert_ne!(
+            digest,
+            deposit_signing_digest_v3(&deposit, &other_split, config.treasury, 1_000)
+        );
+        assert_ne!(
+            digest,
+            deposit_signing_digest_v3(&deposit, &split, Address::with_last_byte(0xdd), 1_000)
+        );
+        assert_ne!(
+            digest,
+            deposit_signing_digest_v3(&deposit, &split, config.treasury, 1_001)
+        );
+
+        // Domain versioning keeps a zero-fee v3 digest distinct from v2.
+        let free_split = crate::protocol_fee::ProtocolFeeConfig::default().split(deposit.amount);
+        assert_ne!(
+            deposit_signing_digest_v3(&deposit, &free_split, Address::ZERO, 1_000),
+            deposit_signing_digest(&deposit, 1_000)
+        );
+    }
+
+    #[test]
+    fn validity_check_rejects_expired_and_oversized_windows() {
+        let now = 1_000_000;
+        assert_eq!(check_digest_validity(now, now), Ok(()));
+        assert_eq!(
+            check_digest_validity(now + DEFAULT_SIGNATURE_VALIDITY.as_secs(), now),
+            Ok(())
+        );
+        assert_eq!(
+            check_digest_validity(now - 1, now),
+            Err(DigestValidityError::Expired {
+                valid_until: now - 1,
+                now,
+            })
+        );
+        assert_eq!(
+            check_digest_validity(now + MAX_SIGNATURE_VALIDITY.as_secs() + 1, now),
+            Err(DigestValidityError::WindowTooLong {
+                valid_until: now + MAX_SIGNATURE_VALIDITY.as_secs() + 1,
+                now,
+            })
+        );
+    }
+
+    #[test]
+    fn default_config_produces_deadline_within_the_hard_cap() {
+        let config = SignatureValidityConfig::default();
+        let now = 42;
+        let deadline = config.deadline_from(now);
+        assert_eq!(deadline, now + DEFAULT_SIGNATURE_VALIDITY.as_secs());
+        assert_eq!(check_digest_validity(deadline, now), Ok(()));
+    }
+}
@@ -0,0 +1,598 @@
+//! Stale deposit expiry and escalation.
+//!
+//! A deposit that never reaches its signature threshold — typically because
+//! the validator set churned mid-collection — would otherwise sit in the
+//! sidecar's state forever. [`StateManager`] tracks every deposit's signature
+//! progress and, once a pending deposit is `expiry_blocks` old, marks it
+//! expired and surfaces a [`BridgeEvent::DepositExpired`] for the operator
+//! notifier. Expired deposits are not lost: `bridge-cli retry --escalate`
+//! re-pends them under the new validator-set epoch so signature collection
+//! can restart from scratch.
+
+use crate::{notify::BridgeEvent, weights::ValidatorWeights};
+use alloy_primitives::{Address, B256};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+};
+
+/// Default number of origin blocks a deposit may stay pending before it is
+/// marked expired. Generous enough to ride out collection hiccups, short
+/// enough that a deposit stranded by validator churn surfaces within hours.
+pub const DEFAULT_EXPIRY_BLOCKS: u64 = 1800;
+
+/// Default number of origin blocks a completed deposit record is retained
+/// before pruning — roughly seven days of 12-second origin blocks. Pending
+/// and expired deposits are never pruned: both are still actionable.
+pub const DEFAULT_RETENTION_BLOCKS: u64 = 50_400;
+
+/// Lifecycle of a tracked deposit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    tag = "state",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum DepositState {
+    /// Signature collection in progress.
+    Pending {
+        /// Origin block the deposit was first observed at (expiry clock).
+        first_seen_block: u64,
+        /// Validator-set epoch the signatures are being collected under.
+        validator_epoch: u64,
+        /// Validators that have signed so far.
+        signatures: BTreeSet<Address>,
+    },
+    /// The deposit went stale before reaching threshold.
+    Expired {
+        /// Origin block the deposit was first observed at.
+        first_seen_block: u64,
+        /// Block at which the expiry policy fired.
+        expired_at_block: u64,
+        /// Epoch the stranded collection ran under.
+        validator_epoch: u64,
+        /// Signatures that had been collected when the deposit expired.
+        signatures_collected: usize,
+    },
+    /// The signature threshold was reached.
+    Completed {
+        /// Block at which the final signature arrived.
+        completed_at_block: u64,
+    },
+}
+
+/// Error returned when an operation does not apply to the deposit's state.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum DepositStateError {
+    /// The deposit id is not tracked.
+    #[error("unknown deposit {0}")]
+    UnknownDeposit(B256),
+    /// The operation requires a different lifecycle state.
+    #[error("deposit {id} is not {expected}")]
+    WrongState {
+        /// The deposit.
+        id: B256,
+        /// The state the operation requires.
+        expected: &'static str,
+    },
+}
+
+/// Tracks deposit signature progress and applies the expiry policy.
+///
+/// The manager is serialized to disk by the sidecar between runs, which is
+/// also how `bridge-cli retry` reaches it offline.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StateManager {
+    /// Blocks a deposit may stay pending before expiring.
+    expiry_blocks: u64,
+    /// Signatures required to complete a deposit.
+    threshold: usize,
+    /// All tracked deposits, keyed by deposit id.
+    deposits: BTreeMap<B256, DepositState>,
+    /// Total deposits expired over the manager's lifetime (metric).
+    expired_total: u64,
+    /// Total completed deposit records pruned over the manager's lifetime
+    /// (metric). Defaulted so state files written before pruning existed
+    /// still load.
+    #[serde(default)]
+    pruned_total: u64,
+    /// Per-validator signature weights for the current epoch, installed via
+    /// [`Self::set_weights`]. When set, completion is decided by weighted
+    /// quorum instead of signature count. Defaulted so state files written
+    /// before weighting existed still load (and keep counting).
+    #[serde(default)]
+    weights: Option<ValidatorWeights>,
+}
+
+/// Signature progress of a pending deposit, for status output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DepositProgress {
+    /// Distinct validator signatures collected.
+    pub signatures: usize,
+    /// Signature count required when no weight snapshot is configured.
+    pub threshold: usize,
+    /// Weighted progress as `(signed weight, required weight)`, when a weight
+    /// snapshot is configured.
+    pub weighted: Option<(u128, u128)>,
+}
+
+impl StateManager {
+    /// Creates a manager with the given expiry policy and signature threshold.
+    pub fn new(expiry_blocks: u64, threshold: usize) -> Self {
+        Self {
+            expiry_blocks,
+            threshold,
+            deposits: BTreeMap::new(),
+            expired_total: 0,
+            pruned_total: 0,
+            weights: None,
+        }
+    }
+
+    /// Installs (or refreshes) the per-validator weight snapshot. From here
+    /// on, pending deposits complete by weighted quorum rather than signature
+    /// count.
+    ///
+    /// Nothing in this tree calls this yet: it is the hook the embedding
+    /// sidecar must call at each validator-set epoch refresh, with weights
+    /// fetched from `ValidatorConfig`. A manager that never sees a snapshot
+    /// keeps completing deposits by signature count.
+    pub fn set_weights(&mut self, weights: ValidatorWeights) {
+        self.weights = Some(weights);
+    }
+
+    /// Signature progress of a pending deposit, or `None` when the deposit is
+    /// unknown or no longer pending.
+    pub fn progress(&self, id: B256) -> Option<DepositProgress> {
+        let DepositState::Pending { signatures, .. } = self.deposits.get(&id)? else {
+            return None;
+        };
+        Some(DepositProgress {
+            signatures: signatures.len(),
+            threshold: self.threshold,
+            weighted: self.weights.as_ref().map(|weights| {
+                (
+                    weights.signed_weight(signatures.iter()),
+                    weights.threshold(),
+                )
+            }),
+        })
+    }
+
+    /// Loads a manager previously written with [`Self::save`].
+    pub fn load(path: &Path) -> eyre::Result<Self> {
+        Ok(serde_json::from_slice(&std::fs::read(path)?)?)
+    }
+
+    /// Persists the manager as JSON.
+    pub fn save(&self, path: &Path) -> eyre::Result<()> {
+        Ok(std::fs::write(path, serde_json::to_vec_pretty(self)?)?)
+    }
+
+    /// Returns the tracked state of `id`, if any.
+    pub fn get(&self, id: B256) -> Option<&DepositState> {
+        self.deposits.get(&id)
+    }
+
+    /// Total deposits expired over the manager's lifetime.
+    pub fn expired_total(&self) -> u64 {
+        self.expired_total
+    }
+
+    /// Total completed deposit records pruned over the manager's lifetime.
+    pub fn pruned_total(&self) -> u64 {
+        self.pruned_total
+    }
+
+    /// Number of deposits currently tracked.
+    pub fn len(&self) -> usize {
+        self.deposits.len()
+    }
+
+    /// Returns true if no deposits are tracked.
+    pub fn is_empty(&self) -> bool {
+        self.deposits.is_empty()
+    }
+
+    /// Drops completed deposit records older than `retention_blocks` at the
+    /// given block height, returning how many were pruned.
+    ///
+    /// Pending deposits are still collecting signatures and expired deposits
+    /// are still retryable via `bridge-cli retry`, so both are always kept
+    /// regardless of age.
+    pub fn prune(&mut self, block: u64, retention_blocks: u64) -> u64 {
+        let before = self.deposits.len();
+        self.deposits.retain(|_, state| match state {
+            DepositState::Completed { completed_at_block } => {
+                block.saturating_sub(*completed_at_block) < retention_blocks
+            }
+            DepositState::Pending { .. } | DepositState::Expired { .. } => true,
+        });
+        let pruned = (before - self.deposits.len()) as u64;
+        self.pruned_total += pruned;
+        pruned
+    }
+
+    /// Starts tracking a newly observed deposit. A deposit that is already
+    /// tracked is left untouched (observation is idempotent).
+    pub fn record_deposit(&mut self, id: B256, block: u64, validator_epoch: u64) {
+        self.deposits.entry(id).or_insert(DepositState::Pending {
+            first_seen_block: block,
+            validator_epoch,
+            signatures: BTreeSet::new(),
+        });
+    }
+
+    /// Records a validator signature for a pending deposit. Returns `true`
+    /// when this signature completed the threshold — the weighted quorum when
+    /// a weight snapshot is configured, the signature count otherwise.
+    pub fn record_signature(
+        &mut self,
+        id: B256,
+        validator: Address,
+        block: u64,
+    ) -> Result<bool, DepositStateError> {
+        let state = self
+            .deposits
+            .get_mut(&id)
+            .ok_or(DepositStateError::UnknownDeposit(id))?;
+        let DepositState::Pending { signatures, .. } = state else {
+            return Err(DepositStateError::WrongState {
+                id,
+                expected: "pending",
+            });
+        };
+        signatures.insert(validator);
+        let complete = match &self.weights {
+            // Weighted quorum: heavy validators may finalize with few
+            // signatures, light ones must accumulate more.
+            Some(weights) => weights.is_quorum(signatures.iter()),
+            None => signatures.len() >= self.threshold,
+        };
+        if complete {
+            *state = DepositState::Completed {
+                completed_at_block: block,
+            };
+            return Ok(true);
+        }
+        Ok(false)
+    }
+
+    /// Applies the expiry policy at the given block height.
+    ///
+    /// Every pending deposit older than `expiry_blocks` transitions to
+    /// expired; the returned events are handed to the operator notifier.
+    pub fn on_new_block(&mut self, block: u64) -> Vec<BridgeEvent> {
+        let mut events = Vec::new();
+        for (id, state) in &mut self.deposits {
+            let DepositState::Pending {
+                first_seen_block,
+                validator_epoch,
+                signatures,
+            } = state
+            else {
+                continue;
+            };
+            if block.saturating_sub(*first_seen_block) < self.expiry_blocks {
+                continue;
+            }
+
+            let signatures_collected = signatures.len();
+            tracing::warn!(
+                deposit_id = %id,
+                first_seen_block,
+                signatures_collected,
+                threshold = self.threshold,
+                "deposit expired before reaching signature threshold",
+            );
+            events.push(BridgeEvent::DepositExpired {
+                deposit_id: *id,
+                first_seen_block: *first_seen_block,
+                signatures_collected,
+                threshold: self.threshold,
+            });
+            *state = DepositState::Expired {
+                first_seen_block: *first_seen_block,
+                expired_at_block: block,
+                validator_epoch: *validator_epoch,
+                signatures_collected,
+            };
+            self.expired_total += 1;
+        }
+        events
+    }
+
+    /// Re-pends an expired deposit under the same epoch, keeping nothing but
+    /// the deposit id — collected signatures are from the stranded epoch and
+    /// are discarded.
+    pub fn retry(&mut self, id: B256, block: u64) -> Result<(), DepositStateError> {
+        let epoch = self.expired_epoch(id)?;
+        self.repend(id, block, epoch);
+        Ok(())
+    }
+
+    /// Re-pends an expired deposit under a new validator-set epoch, so
+    /// signature collection restarts against the current validator set.
+    pub fn escalate(
+        &mut self,
+        id: B256,
+        block: u64,
+        new_epoch: u64,
+    ) -> Result<(), DepositStateError> {
+        self.expired_epoch(id)?;
+        self.repend(id, block, new_epoch);
+        Ok(())
+    }
+
+    /// Returns the stranded epoch of an expired deposit, or the applicable
+    /// state error.
+    fn expired_epoch(&self, id: B256) -> Result<u64, DepositStateError> {
+        match self.deposits.get(&id) {
+            Some(DepositState::Expired {
+                validator_epoch, ..
+            }) => Ok(*validator_epoch),
+            Some(_) => Err(DepositStateError::WrongState {
+                id,
+                expected: "expired",
+            }),
+            None => Err(DepositStateError::UnknownDeposit(id)),
+        }
+    }
+
+    fn repend(&mut self, id: B256, block: u64, validator_epoch: u64) {
+        self.deposits.insert(
+            id,
+            DepositState::Pending {
+                first_seen_block: block,
+                validator_epoch,
+                signatures: BTreeSet::new(),
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validator(byte: u8) -> Address {
+        Address::repeat_byte(byte)
+    }
+
+    #[test]
+    fn deposit_completes_at_threshold() {
+        let mut manager = StateManager::new(100, 2);
+        let id = B256::with_last_byte(1);
+        manager.record_deposit(id, 10, 7);
+
+        assert_eq!(manager.record_signature(id, validator(1), 11), Ok(false));
+        assert_eq!(manager.record_signature(id, validator(2), 12), Ok(true));
+        assert_eq!(
+            manager.get(id),
+            Some(&DepositState::Completed {
+                completed_at_block: 12
+            })
+        );
+
+        // Completed deposits never expire.
+        assert!(manager.on_new_block(10_000).is_empty());
+    }
+
+    #[test]
+    fn weighted_quorum_overrides_the_signature_count() {
+        let mut manager = StateManager::new(100, 3);
+        manager.set_weights(ValidatorWeights::new([
+            (validator(1), 70),
+            (validator(2), 20),
+            (validator(3), 5),
+            (validator(4), 5),
+        ]));
+        let id = B256::with_last_byte(1);
+        manager.record_deposit(id, 10, 7);
+
+        // Two light validators satisfy neither count nor weight (25 of 67).
+        assert_eq!(manager.record_signature(id, validator(3), 11), Ok(false));
+        assert_eq!(manager.record_signature(id, validator(4), 12), Ok(false));
+        assert_eq!(
+            manager.progress(id),
+            Some(DepositProgress {
+                signatures: 2,
+                threshold: 3,
+                weighted: Some((10, 67)),
+            })
+        );
+
+        // The heavy validator tips the weight over threshold with only three
+        // signatures collected — weight decides, not count.
+        assert_eq!(manager.record_signature(id, validator(1), 13), Ok(true));
+        assert!(matches!(
+            manager.get(id),
+            Some(DepositState::Completed { .. })
+        ));
+        // Completed deposits report no progress.
+        assert_eq!(manager.progress(id), None);
+    }
+
+    #[test]
+    fn unknown_signers_never_reach_weighted_quorum() {
+        let mut manager = StateManager::new(100, 2);
+        manager.set_weights(ValidatorWeights::new([(validator(1), 10)]));
+        let id = B256::with_last_byte(1);
+        manager.record_deposit(id, 10, 7);
+
+        // Count threshold would be met, but neither signer carries weight.
+        assert_eq!(manager.record_signature(id, validator(8), 11), Ok(false));
+        assert_eq!(manager.record_signature(id, validator(9), 12), Ok(false));
+        assert!(matches!(
+            manager.get(id),
+            Some(DepositState::Pending { .. })
+        ));
+    }
+
+    #[test]
+    fn stale_pending_deposit_expires_and_emits_event() {
+        let mut manager = StateManager::new(100, 2);
+        let id = B256::with_last_byte(1);
+        manager.record_deposit(id, 10, 7);
+        manager.record_signature(id, validator(1), 11).unwrap();
+
+        // One block short of the policy: still pending.
+        assert!(manager.on_new_block(109).is_empty());
+
+        let events = manager.on_new_block(110);
+        assert_eq!(
+            events,
+            vec![BridgeEvent::DepositExpired {
+                deposit_id: id,
+                first_seen_block: 10,
+                signatures_collected: 1,
+                threshold: 2,
+            }]
+        );
+        assert_eq!(manager.expired_total(), 1);
+
+        // Late signatures for the stranded collection are rejected.
+        assert_eq!(
+            manager.record_signature(id, validator(2), 111),
+            Err(DepositStateError::WrongState {
+                id,
+                expected: "pending"
+            })
+        );
+    }
+
+    #[test]
+    fn escalate_restarts_collection_under_new_epoch() {
+        let mut manager = StateManager::new(100, 2);
+        let id = B256::with_last_byte(1);
+        manager.record_deposit(id, 10, 7);
+        manager.record_signature(id, validator(1), 11).unwrap();
+        manager.on_new_block(200);
+
+        // Escalation only applies to expired deposits.
+        let other = B256::with_last_byte(2);
+        manager.record_deposit(other, 200, 8);
+        assert_eq!(
+            manager.escalate(other, 200, 8),
+            Err(DepositStateError::WrongState {
+                id: other,
+                expected: "expired"
+            })
+        );
+
+        manager.escalate(id, 201, 8).unwrap();
+        assert_eq!(
+            manager.get(id),
+            Some(&DepositState::Pending {
+                first_seen_block: 201,
+                validator_epoch: 8,
+                signatures: BTreeSet::new(),
+            })
+        );
+
+        // Collection completes under the new epoch.
+        manager.record_signature(id, validator(3), 202).unwrap();
+        assert_eq!(manager.record_signature(id, validator(4), 203), Ok(true));
+    }
+
+    #[test]
+    fn retry_keeps_the_stranded_epoch() {
+        let mut manager = StateManager::new(100, 2);
+        let id = B256::with_last_byte(1);
+        manager.record_deposit(id, 10, 7);
+        manager.on_new_block(200);
+
+        manager.retry(id, 201).unwrap();
+        assert_eq!(
+            manager.get(id),
+            Some(&DepositState::Pending {
+                first_seen_block: 201,
+                validator_epoch: 7,
+                signatures: BTreeSet::new(),
+            })
+        );
+    }
+
+    #[test]
+    fn unknown_deposit_is_rejected() {
+        let mut manager = StateManager::new(100, 2);
+        let id = B256::with_last_byte(9);
+        assert_eq!(
+            manager.record_signature(id, validator(1), 1),
+            Err(DepositStateError::UnknownDeposit(id))
+        );
+        assert_eq!(
+            manager.escalate(id, 1, 2),
+            Err(DepositStateError::UnknownDeposit(id))
+        );
+    }
+
+    #[test]
+    fn prune_drops_only_old_completed_records() {
+        let mut manager = StateManager::new(100, 1);
+
+        // Expired: recorded early, never signed.
+        let expired = B256::with_last_byte(1);
+        manager.record_deposit(expired, 10, 7);
+
+        // Old completed: finalized long before the retention window.
+        let completed_old = B256::with_last_byte(2);
+        manager.record_deposit(completed_old, 10, 7);
+        manager
+            .record_signature(completed_old, validator(1), 11)
+            .unwrap();
+
+        manager.on_new_block(200);
+
+        // Recent completed and still-pending deposits.
+        let completed_new = B256::with_last_byte(3);
+        manager.record_deposit(completed_new, 900, 7);
+        manager
+            .record_signature(completed_new, validator(1), 901)
+            .unwrap();
+        let pending = B256::with_last_byte(4);
+        manager.record_deposit(pending, 950, 7);
+
+        assert_eq!(manager.prune(1000, 200), 1);
+        assert_eq!(manager.pruned_total(), 1);
+        assert_eq!(manager.len(), 3);
+        assert_eq!(manager.get(completed_old), None);
+        // Unfinalized records survive regardless of age.
+        assert!(matches!(
+            manager.get(expired),
+            Some(DepositState::Expired { .. })
+        ));
+        assert!(matches!(
+            manager.get(pending),
+            Some(DepositState::Pending { .. })
+        ));
+        assert!(matches!(
+            manager.get(completed_new),
+            Some(DepositState::Completed { .. })
+        ));
+
+        // Pruning is idempotent until more records age out.
+        assert_eq!(manager.prune(1000, 200), 0);
+    }
+
+    #[test]
+    fn state_roundtrips_through_disk() {
+        let mut manager = StateManager::new(100, 2);
+        let id = B256::with_last_byte(1);
+        manager.record_deposit(id, 10, 7);
+        manager.record_signature(id, validator(1), 11).unwrap();
+        manager.on_new_block(200);
+
+        let dir = std::env::temp_dir().join("tempo-bridge-deposit-expiry-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("state.json");
+        manager.save(&path).unwrap();
+
+        let loaded = StateManager::load(&path).unwrap();
+        assert_eq!(loaded.get(id), manager.get(id));
+        assert_eq!(loaded.expired_total(), 1);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
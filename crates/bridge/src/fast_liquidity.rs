@@ -0,0 +1,545 @@
+//! Deposit finality hooks for instant-mint partners.
+//!
+//! Bridging UX is dominated by the origin chain's confirmation depth: a
+//! deposit is observed within seconds but not forwarded to the signer
+//! pipeline until it is buried. A configured fast liquidity provider can
+//! close that gap by fronting the recipient on Tempo as soon as the deposit
+//! is observed, at its own reorg risk. [`FastLiquidityHub`] announces
+//! observed-but-unfinalized deposits over a subscription the sidecar exposes
+//! to the provider, tracks each deposit through to finalization (or loss to
+//! a reorg), and keeps per-deposit reconciliation records so the provider's
+//! outstanding exposure is auditable at any time.
+
+use crate::origin_watcher::Deposit;
+use alloy_primitives::{Address, B256, U256};
+use std::collections::BTreeMap;
+use tokio::sync::broadcast;
+
+/// Default capacity of the event subscription channel. A provider that lags
+/// further than this behind loses events and must resynchronize from
+/// [`FastLiquidityHub::reconciliation`].
+pub const DEFAULT_EVENT_BUFFER: usize = 256;
+
+/// Configuration for the fast liquidity extension point, part of the bridge
+/// ExEx config. Absent config disables the feature entirely.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FastLiquidityConfig {
+    /// The provider fronting liquidity. Recorded in reconciliation entries;
+    /// the subscription API authenticates against it.
+    pub provider: Address,
+    /// Escrows whose deposits are announced. Empty means all watched escrows.
+    pub escrows: Vec<Address>,
+    /// Cap on the provider's aggregate unsettled fronted amount. Fronts that
+    /// would exceed the cap are rejected.
+    pub max_exposure: Option<U256>,
+    /// Capacity of the event subscription channel.
+    pub event_buffer: usize,
+}
+
+impl FastLiquidityConfig {
+    /// Creates a config announcing all watched escrows with no exposure cap.
+    pub fn new(provider: Address) -> Self {
+        Self {
+            provider,
+            escrows: Vec::new(),
+            max_exposure: None,
+            event_buffer: DEFAULT_EVENT_BUFFER,
+        }
+    }
+
+    /// Restricts announcements to the given escrows.
+    pub fn with_escrows(mut self, escrows: Vec<Address>) -> Self {
+        self.escrows = escrows;
+        self
+    }
+
+    /// Caps the provider's aggregate unsettled fronted amount.
+    pub fn with_max_exposure(mut self, max_exposure: U256) -> Self {
+        self.max_exposure = Some(max_exposure);
+        self
+    }
+
+    /// Returns true if deposits to `escrow` are announced.
+    pub fn covers(&self, escrow: Address) -> bool {
+        self.escrows.is_empty() || self.escrows.contains(&escrow)
+    }
+}
+
+/// An event on the fast liquidity subscription.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FastLiquidityEvent {
+    /// A deposit was observed on the origin chain but is not yet buried under
+    /// its escrow's confirmation depth. The provider may front the recipient.
+    DepositObserved {
+        /// The observed deposit.
+        deposit: Deposit,
+        /// Origin block at which the deposit was observed.
+        observed_at_block: u64,
+    },
+    /// A previously announced deposit reached finality and entered the signer
+    /// pipeline. Any front against it will be repaid by the canonical mint.
+    DepositFinalized {
+        /// Id of the finalized deposit.
+        deposit_id: B256,
+        /// Origin block at which the confirmation depth was reached.
+        finalized_at_block: u64,
+    },
+    /// A previously announced deposit will never finalize — typically it was
+    /// reorged out of the origin chain. A front against it is the provider's
+    /// loss to recover out of band.
+    DepositDropped {
+        /// Id of the dropped deposit.
+        deposit_id: B256,
+        /// Why the deposit was dropped.
+        reason: String,
+    },
+}
+
+/// Lifecycle of an announced deposit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrontState {
+    /// Announced to the provider; no front reported yet.
+    Announced {
+        /// Origin block at which the deposit was observed.
+        observed_at_block: u64,
+    },
+    /// The provider reported fronting the recipient.
+    Fronted {
+        /// Origin block at which the deposit was observed.
+        observed_at_block: u64,
+        /// Amount the provider fronted on Tempo.
+        fronted_amount: U256,
+        /// Tempo transaction the front was paid in.
+        front_tx: B256,
+    },
+    /// The deposit finalized. A recorded front is settled by the canonical
+    /// mint.
+    Finalized {
+        /// Origin block at which the confirmation depth was reached.
+        finalized_at_block: u64,
+        /// Amount that had been fronted, if any.
+        fronted_amount: Option<U256>,
+    },
+    /// The deposit was lost to a reorg (or otherwise dropped) before
+    /// finalizing.
+    Dropped {
+        /// Why the deposit was dropped.
+        reason: String,
+        /// Amount that had been fronted, if any — the provider's loss.
+        fronted_amount: Option<U256>,
+    },
+}
+
+/// Error returned when an operation does not apply to a tracked deposit.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum FastLiquidityError {
+    /// The deposit id is not tracked.
+    #[error("unknown deposit {0}")]
+    UnknownDeposit(B256),
+    /// The operation requires a different lifecycle state.
+    #[error("deposit {id} is not {expected}")]
+    WrongState {
+        /// The deposit.
+        id: B256,
+        /// The state the operation requires.
+        expected: &'static str,
+    },
+    /// Recording the front would exceed the configured exposure cap.
+    #[error("front of {requested} exceeds remaining exposure headroom {headroom}")]
+    ExposureExceeded {
+        /// Amount the provider tried to front.
+        requested: U256,
+        /// Headroom left under the cap before this front.
+        headroom: U256,
+    },
+}
+
+/// Per-deposit reconciliation record, served by the sidecar's status API so
+/// the provider (and operators) can audit outstanding exposure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReconciliationEntry {
+    /// The deposit.
+    pub deposit_id: B256,
+    /// Escrow the deposit was made to.
+    pub escrow: Address,
+    /// Recipient on Tempo.
+    pub recipient: Address,
+    /// Deposited amount.
+    pub deposit_amount: U256,
+    /// Amount the provider fronted, if it did.
+    pub fronted_amount: Option<U256>,
+    /// Lifecycle state of the deposit.
+    pub state: FrontState,
+}
+
+impl ReconciliationEntry {
+    /// Returns true if a front against this deposit is still unsettled —
+    /// the deposit has neither finalized nor been dropped.
+    pub fn outstanding(&self) -> bool {
+        matches!(
+            self.state,
+            FrontState::Fronted { .. } | FrontState::Announced { .. }
+        ) && self.fronted_amount.is_some()
+    }
+}
+
+/// Announces observed deposits to the fast liquidity provider and tracks
+/// them through to finalization.
+///
+/// The ExEx feeds it from the origin watcher: [`observe`](Self::observe) on
+/// every raw deposit log, then [`finalize`](Self::finalize) or
+/// [`drop_deposit`](Self::drop_deposit) once the confirmation depth resolves
+/// the deposit's fate.
+#[derive(Debug)]
+pub struct FastLiquidityHub {
+    config: FastLiquidityConfig,
+    events: broadcast::Sender<FastLiquidityEvent>,
+    /// All tracked deposits, keyed by deposit id.
+    deposits: BTreeMap<B256, (Deposit, FrontState)>,
+}
+
+impl FastLiquidityHub {
+    /// Creates a hub for the given config.
+    pub fn new(config: FastLiquidityConfig) -> Self {
+        let (events, _) = broadcast::channel(config.event_buffer.max(1));
+        Self {
+            config,
+            events,
+            deposits: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the configured provider address.
+    pub fn provider(&self) -> Address {
+        self.config.provider
+    }
+
+    /// Opens a subscription to fast liquidity events. This is what the
+    /// sidecar's subscription API hands to the provider.
+    pub fn subscribe(&self) -> broadcast::Receiver<FastLiquidityEvent> {
+        self.events.subscribe()
+    }
+
+    /// Announces an observed-but-unfinalized deposit to the provider.
+    ///
+    /// Deposits to escrows outside the configured set are ignored, as are
+    /// re-observations of an already tracked deposit (subscription and
+    /// polling paths can both report the same log). Returns true if the
+    /// deposit was announced.
+    pub fn observe(&mut self, deposit: Deposit, observed_at_block: u64) -> bool {
+        if !self.config.covers(deposit.escrow) {
+            return false;
+        }
+        let id = deposit.id();
+        if self.deposits.contains_key(&id) {
+            return false;
+        }
+        self.deposits.insert(
+            id,
+            (deposit.clone(), FrontState::Announced { observed_at_block }),
+        );
+        let _ = self.events.send(FastLiquidityEvent::DepositObserved {
+            deposit,
+            observed_at_block,
+        });
+        true
+    }
+
+    /// Records that the provider fronted `amount` for the deposit in Tempo
+    /// transaction `front_tx`.
+    ///
+    /// Fails if the deposit is not in the announced state or if the front
+    /// would push aggregate unsettled exposure past the configured cap.
+    pub fn record_front(
+        &mut self,
+        id: B256,
+        amount: U256,
+        front_tx: B256,
+    ) -> Result<(), FastLiquidityError> {
+        if let Some(cap) = self.config.max_exposure {
+            let headroom = cap.saturating_sub(self.outstanding_exposure());
+            if amount > headroom {
+                return Err(FastLiquidityError::ExposureExceeded {
+                    requested: amount,
+                    headroom,
+                });
+            }
+        }
+        let (_, state) = self
+            .deposits
+            .get_mut(&id)
+            .ok_or(FastLiquidityError::UnknownDeposit(id))?;
+        let FrontState::Announced { observed_at_block } = *state else {
+            return Err(FastLiquidityError::WrongState {
+                id,
+                expected: "announced",
+            });
+        };
+        *state = FrontState::Fronted {
+            observed_at_block,
+            fronted_amount: amount,
+            front_tx,
+        };
+        Ok(())
+    }
+
+    /// Marks a deposit as finalized: it reached its escrow's confirmation
+    /// depth and entered the signer pipeline.
+    pub fn finalize(
+        &mut self,
+        id: B256,
+        finalized_at_block: u64,
+    ) -> Result<(), FastLiquidityError> {
+        let (_, state) = self
+            .deposits
+            .get_mut(&id)
+            .ok_or(FastLiquidityError::UnknownDeposit(id))?;
+        let fronted_amount = match *state {
+            FrontState::Announced { .. } => None,
+            FrontState::Fronted { fronted_amount, .. } => Some(fronted_amount),
+            _ => {
+                return Err(FastLiquidityError::WrongState {
+                    id,
+                    expected: "announced or fronted",
+                });
+            }
+        };
+        *state = FrontState::Finalized {
+            finalized_at_block,
+            fronted_amount,
+        };
+        let _ = self.events.send(FastLiquidityEvent::DepositFinalized {
+            deposit_id: id,
+            finalized_at_block,
+        });
+        Ok(())
+    }
+
+    /// Marks a deposit as dropped: it was reorged out (or otherwise will
+    /// never finalize). A front against it is surfaced as the provider's
+    /// loss in reconciliation data.
+    pub fn drop_deposit(
+        &mut self,
+        id: B256,
+        reason: impl Into<String>,
+    ) -> Result<(), FastLiquidityError> {
+        let (_, state) = self
+            .deposits
+            .get_mut(&id)
+            .ok_or(FastLiquidityError::UnknownDeposit(id))?;
+        let fronted_amount = match *state {
+            FrontState::Announced { .. } => None,
+            FrontState::Fronted { fronted_amount, .. } => Some(fronted_amount),
+            _ => {
+                return Err(FastLiquidityError::WrongState {
+                    id,
+                    expected: "announced or fronted",
+                });
+            }
+        };
+        let reason = reason.into();
+        *state = FrontState::Dropped {
+            reason: reason.clone(),
+            fronted_amount,
+        };
+        let _ = self.events.send(FastLiquidityEvent::DepositDropped {
+            deposit_id: id,
+            reason,
+        });
+        Ok(())
+    }
+
+    /// Aggregate fronted amount not yet settled by finalization (or written
+    /// off by a drop).
+    pub fn outstanding_exposure(&self) -> U256 {
+        self.deposits
+            .values()
+            .filter_map(|(_, state)| match state {
+                FrontState::Fronted { fronted_amount, .. } => Some(*fronted_amount),
+                _ => None,
+            })
+            .fold(U256::ZERO, |acc, amount| acc.saturating_add(amount))
+    }
+
+    /// Per-deposit reconciliation data, ordered by deposit id.
+    pub fn reconciliation(&self) -> Vec<ReconciliationEntry> {
+        self.deposits
+            .iter()
+            .map(|(id, (deposit, state))| ReconciliationEntry {
+                deposit_id: *id,
+                escrow: deposit.escrow,
+                recipient: deposit.recipient,
+                deposit_amount: deposit.amount,
+                fronted_amount: match state {
+                    FrontState::Fronted { fronted_amount, .. } => Some(*fronted_amount),
+                    FrontState::Finalized { fronted_amount, .. }
+                    | FrontState::Dropped { fronted_amount, .. } => *fronted_amount,
+                    FrontState::Announced { .. } => None,
+                },
+                state: state.clone(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deposit(escrow: Address, log_index: u64, amount: u64) -> Deposit {
+        Deposit {
+            escrow,
+            block_number: 100,
+            tx_hash: B256::repeat_byte(0xd0),
+            log_index,
+            from: Address::repeat_byte(0x0f),
+            recipient: Address::repeat_byte(0x0e),
+            amount: U256::from(amount),
+        }
+    }
+
+    #[test]
+    fn observe_announces_covered_escrows_once() {
+        let escrow = Address::repeat_byte(0x01);
+        let other = Address::repeat_byte(0x02);
+        let config =
+            FastLiquidityConfig::new(Address::repeat_byte(0xaa)).with_escrows(vec![escrow]);
+        let mut hub = FastLiquidityHub::new(config);
+        let mut events = hub.subscribe();
+
+        let dep = deposit(escrow, 0, 500);
+        assert!(hub.observe(dep.clone(), 100));
+        // Re-observation (polling overlap) and uncovered escrows are silent.
+        assert!(!hub.observe(dep.clone(), 101));
+        assert!(!hub.observe(deposit(other, 1, 500), 100));
+
+        assert_eq!(
+            events.try_recv().unwrap(),
+            FastLiquidityEvent::DepositObserved {
+                deposit: dep,
+                observed_at_block: 100,
+            }
+        );
+        assert!(events.try_recv().is_err());
+    }
+
+    #[test]
+    fn front_settles_on_finalization() {
+        let escrow = Address::repeat_byte(0x01);
+        let mut hub = FastLiquidityHub::new(FastLiquidityConfig::new(Address::repeat_byte(0xaa)));
+        let dep = deposit(escrow, 0, 500);
+        let id = dep.id();
+
+        hub.observe(dep, 100);
+        hub.record_front(id, U256::from(500u64), B256::repeat_byte(0xf1))
+            .unwrap();
+        assert_eq!(hub.outstanding_exposure(), U256::from(500u64));
+
+        // A second front against the same deposit is rejected.
+        assert_eq!(
+            hub.record_front(id, U256::from(1u64), B256::repeat_byte(0xf2)),
+            Err(FastLiquidityError::WrongState {
+                id,
+                expected: "announced",
+            })
+        );
+
+        hub.finalize(id, 112).unwrap();
+        assert_eq!(hub.outstanding_exposure(), U256::ZERO);
+
+        let entries = hub.reconciliation();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].fronted_amount, Some(U256::from(500u64)));
+        assert!(!entries[0].outstanding());
+        assert!(matches!(
+            entries[0].state,
+            FrontState::Finalized {
+                finalized_at_block: 112,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn exposure_cap_rejects_oversized_fronts() {
+        let escrow = Address::repeat_byte(0x01);
+        let config = FastLiquidityConfig::new(Address::repeat_byte(0xaa))
+            .with_max_exposure(U256::from(700u64));
+        let mut hub = FastLiquidityHub::new(config);
+
+        let first = deposit(escrow, 0, 500);
+        let second = deposit(escrow, 1, 500);
+        let (first_id, second_id) = (first.id(), second.id());
+        hub.observe(first, 100);
+        hub.observe(second, 100);
+
+        hub.record_front(first_id, U256::from(500u64), B256::repeat_byte(0xf1))
+            .unwrap();
+        assert_eq!(
+            hub.record_front(second_id, U256::from(500u64), B256::repeat_byte(0xf2)),
+            Err(FastLiquidityError::ExposureExceeded {
+                requested: U256::from(500u64),
+                headroom: U256::from(200u64),
+            })
+        );
+
+        // Settling the first front frees the headroom.
+        hub.finalize(first_id, 112).unwrap();
+        hub.record_front(second_id, U256::from(500u64), B256::repeat_byte(0xf2))
+            .unwrap();
+    }
+
+    #[test]
+    fn dropped_deposit_surfaces_provider_loss() {
+        let escrow = Address::repeat_byte(0x01);
+        let mut hub = FastLiquidityHub::new(FastLiquidityConfig::new(Address::repeat_byte(0xaa)));
+        let dep = deposit(escrow, 0, 500);
+        let id = dep.id();
+        let mut events = hub.subscribe();
+
+        hub.observe(dep, 100);
+        hub.record_front(id, U256::from(500u64), B256::repeat_byte(0xf1))
+            .unwrap();
+        hub.drop_deposit(id, "reorged out at depth 7").unwrap();
+
+        // The written-off front no longer counts as exposure, but stays in
+        // reconciliation data as a loss.
+        assert_eq!(hub.outstanding_exposure(), U256::ZERO);
+        let entries = hub.reconciliation();
+        assert_eq!(entries[0].fronted_amount, Some(U256::from(500u64)));
+        assert!(matches!(entries[0].state, FrontState::Dropped { .. }));
+
+        // Lifecycle is terminal: no further transitions.
+        assert_eq!(
+            hub.finalize(id, 120),
+            Err(FastLiquidityError::WrongState {
+                id,
+                expected: "announced or fronted",
+            })
+        );
+
+        // Observed + dropped both went out on the subscription.
+        events.try_recv().unwrap();
+        assert_eq!(
+            events.try_recv().unwrap(),
+            FastLiquidityEvent::DepositDropped {
+                deposit_id: id,
+                reason: "reorged out at depth 7".into(),
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_deposit_errors() {
+        let mut hub = FastLiquidityHub::new(FastLiquidityConfig::new(Address::repeat_byte(0xaa)));
+        let id = B256::repeat_byte(0x99);
+        assert_eq!(
+            hub.record_front(id, U256::from(1u64), B256::ZERO),
+            Err(FastLiquidityError::UnknownDeposit(id))
+        );
+        assert_eq!(
+            hub.finalize(id, 1),
+            Err(FastLiquidityError::UnknownDeposit(id))
+        );
+    }
+}
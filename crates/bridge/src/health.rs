@@ -0,0 +1,191 @@
+//! Snapshot schema for the sidecar's health endpoint.
+//!
+//! The sidecar serves one [`HealthSnapshot`] as JSON from its health
+//! endpoint; `bridge-cli top` polls it to drive the live dashboard. The
+//! shape is additive-only: fields may be added in later releases but never
+//! renamed or removed, and deserialization ignores unknown fields, so an
+//! older CLI keeps working against a newer sidecar.
+
+use alloy_primitives::{Address, B256, U256};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// One point-in-time view of the sidecar's operational state.
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthSnapshot {
+    /// Seconds the sidecar process has been running.
+    pub uptime_secs: u64,
+    /// Unix timestamp (seconds) at which the snapshot was taken.
+    pub timestamp: u64,
+    /// Watcher progress per configured chain (origin chains and Tempo).
+    pub chains: Vec<ChainCursor>,
+    /// Count of in-flight deposits keyed by state name
+    /// (e.g. `pending`, `signed`, `submitted`).
+    pub deposits_by_state: BTreeMap<String, u64>,
+    /// Most recent burns observed on Tempo, newest first.
+    pub recent_burns: Vec<BurnSummary>,
+    /// Unlocks waiting to be submitted to origin chain escrows, oldest first.
+    pub unlock_queue: Vec<QueuedUnlock>,
+    /// Cumulative error counts since startup, keyed by error kind.
+    pub error_counters: BTreeMap<String, u64>,
+    /// Native-asset balances of the accounts the sidecar spends from.
+    pub balances: Vec<AccountBalance>,
+}
+
+impl HealthSnapshot {
+    /// Total number of in-flight deposits across all states.
+    pub fn total_deposits(&self) -> u64 {
+        self.deposits_by_state.values().sum()
+    }
+
+    /// Total number of errors across all kinds.
+    pub fn total_errors(&self) -> u64 {
+        self.error_counters.values().sum()
+    }
+}
+
+/// Watcher progress on one chain.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChainCursor {
+    /// EIP-155 chain id.
+    pub chain_id: u64,
+    /// Operator-facing chain name from the sidecar config.
+    pub name: String,
+    /// Last block the watcher has fully processed.
+    pub cursor: u64,
+    /// Chain head as last reported by the RPC.
+    pub head: u64,
+}
+
+impl ChainCursor {
+    /// Blocks the watcher is behind the head.
+    pub const fn lag(&self) -> u64 {
+        self.head.saturating_sub(self.cursor)
+    }
+}
+
+/// One recent burn observed on Tempo.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BurnSummary {
+    /// Identifier of the burn.
+    pub burn_id: B256,
+    /// Origin chain the unlock is destined for.
+    pub origin_chain_id: u64,
+    /// Burned amount, in the token's native units.
+    pub amount: U256,
+    /// Current processing state, e.g. `observed`, `signed`, `unlocked`.
+    pub state: String,
+    /// Unix timestamp (seconds) of the block containing the burn.
+    pub timestamp: u64,
+}
+
+/// One unlock waiting for submission to an origin chain escrow.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueuedUnlock {
+    /// Identifier of the burn the unlock settles.
+    pub burn_id: B256,
+    /// Origin chain the unlock will be submitted to.
+    pub origin_chain_id: u64,
+    /// Seconds the unlock has been queued.
+    pub queued_secs: u64,
+    /// Submission attempts so far.
+    pub attempts: u32,
+}
+
+/// Native-asset balance of one account the sidecar spends from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountBalance {
+    /// What the account is used for, e.g. `signer` or `broadcaster`.
+    pub role: String,
+    /// The account's address.
+    pub address: Address,
+    /// Chain the balance was read on.
+    pub chain_id: u64,
+    /// Balance in wei of the chain's native asset.
+    pub balance_wei: U256,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{address, b256};
+
+    fn snapshot() -> HealthSnapshot {
+        HealthSnapshot {
+            uptime_secs: 3600,
+            timestamp: 1_900_000_000,
+            chains: vec![ChainCursor {
+                chain_id: 1,
+                name: "mainnet".into(),
+                cursor: 95,
+                head: 100,
+            }],
+            deposits_by_state: BTreeMap::from([("pending".into(), 3), ("signed".into(), 1)]),
+            recent_burns: vec![BurnSummary {
+                burn_id: b256!(
+                    "0x1111111111111111111111111111111111111111111111111111111111111111"
+                ),
+                origin_chain_id: 1,
+                amount: U256::from(42u64),
+                state: "signed".into(),
+                timestamp: 1_899_999_990,
+            }],
+            unlock_queue: vec![QueuedUnlock {
+                burn_id: b256!(
+                    "0x2222222222222222222222222222222222222222222222222222222222222222"
+                ),
+                origin_chain_id: 1,
+                queued_secs: 120,
+                attempts: 2,
+            }],
+            error_counters: BTreeMap::from([("rpc_timeout".into(), 5)]),
+            balances: vec![AccountBalance {
+                role: "broadcaster".into(),
+                address: address!("0x00000000000000000000000000000000000000aa"),
+                chain_id: 1,
+                balance_wei: U256::from(1_000_000_000_000_000_000u128),
+            }],
+        }
+    }
+
+    #[test]
+    fn round_trips_through_json() {
+        let snapshot = snapshot();
+        let json = serde_json::to_string(&snapshot).unwrap();
+        let decoded: HealthSnapshot = serde_json::from_str(&json).unwrap();
+        assert_eq!(decoded, snapshot);
+    }
+
+    #[test]
+    fn ignores_unknown_fields_from_newer_sidecars() {
+        let mut json = serde_json::to_value(snapshot()).unwrap();
+        json.as_object_mut()
+            .unwrap()
+            .insert("futureField".into(), serde_json::json!({ "nested": true }));
+        let decoded: HealthSnapshot = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded, snapshot());
+    }
+
+    #[test]
+    fn lag_saturates_when_cursor_is_ahead_of_head() {
+        let cursor = ChainCursor {
+            chain_id: 1,
+            name: "mainnet".into(),
+            cursor: 101,
+            head: 100,
+        };
+        assert_eq!(cursor.lag(), 0);
+    }
+
+    #[test]
+    fn totals_sum_across_keys() {
+        let snapshot = snapshot();
+        assert_eq!(snapshot.total_deposits(), 4);
+        assert_eq!(snapshot.total_errors(), 5);
+    }
+}
@@ -0,0 +1,36 @@
+//! Bridge execution extension (ExEx) for the Tempo node.
+//!
+//! The bridge sidecar watches escrow contracts on origin chains, signs mint
+//! authorizations on Tempo, and submits unlocks back to the origin chain.
+//! Modules here are shared between the ExEx itself and `bridge-cli`.
+
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+pub mod audit_log;
+pub mod bootstrap;
+pub mod circuit_breaker;
+pub mod clock_sanity;
+pub mod config;
+pub mod costs;
+pub mod deposit_digest;
+pub mod deposit_expiry;
+pub mod fast_liquidity;
+pub mod health;
+pub mod migration;
+pub mod notify;
+pub mod origin_adapter;
+pub mod origin_watcher;
+pub mod proof;
+pub mod protocol_fee;
+pub mod prune;
+pub mod receipt_envelope;
+pub mod reconcile;
+pub mod reload;
+pub mod replay;
+pub mod self_test;
+pub mod signature_batch;
+pub mod signature_dedup;
+pub mod submitter_election;
+pub mod unlock_submitter;
+pub mod weights;
@@ -0,0 +1,334 @@
+//! Versioned origin-contract sets and migration cutover.
+//!
+//! Upgrading the escrow + light client contracts on an origin chain means new
+//! addresses: the sidecar must keep draining deposits made against the v1
+//! contracts while only admitting new deposits against v2 from a configured
+//! cutover block onward. [`MigrationCoordinator`] pins every deposit to the
+//! contract set that was active at its origin block, rejects deposits made
+//! against a retired set after its cutover, and guards completions so a
+//! deposit can neither be lost in the handover nor minted twice.
+
+use crate::origin_watcher::{Deposit, EscrowConfig};
+use alloy_primitives::{Address, B256};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// One deployed generation of origin-chain bridge contracts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ContractSet {
+    /// Monotonically increasing contract-set version.
+    pub version: u32,
+    /// Escrow contracts of this generation.
+    pub escrows: Vec<EscrowConfig>,
+    /// Light client contract unlocks are submitted against.
+    pub light_client: Address,
+}
+
+impl ContractSet {
+    /// Returns true if `escrow` belongs to this set.
+    pub fn has_escrow(&self, escrow: Address) -> bool {
+        self.escrows.iter().any(|config| config.address == escrow)
+    }
+}
+
+/// Error returned for invalid migration schedules or completions.
+#[derive(Debug, thiserror::Error, PartialEq, Eq)]
+pub enum MigrationError {
+    /// The upgrade does not activate after every scheduled set.
+    #[error("cutover block {0} does not follow the previous activation")]
+    NonMonotonicCutover(u64),
+    /// The upgrade does not increase the contract-set version.
+    #[error("contract set version {0} does not increase")]
+    NonMonotonicVersion(u32),
+    /// The deposit id was never admitted.
+    #[error("unknown deposit {0}")]
+    UnknownDeposit(B256),
+    /// The deposit was already completed once.
+    #[error("deposit {0} was already minted")]
+    AlreadyMinted(B256),
+}
+
+/// The contract sets of one origin chain, keyed by activation block.
+///
+/// Exactly one set is authoritative for any origin block: the latest set
+/// whose activation block is not above it.
+#[derive(Debug, Clone)]
+pub struct VersionedContracts {
+    /// Sets ordered by activation block; the first entry activates at
+    /// block 0.
+    sets: Vec<(u64, ContractSet)>,
+}
+
+impl VersionedContracts {
+    /// Creates a schedule with `genesis` active from block 0.
+    pub fn new(genesis: ContractSet) -> Self {
+        Self {
+            sets: vec![(0, genesis)],
+        }
+    }
+
+    /// Schedules an upgrade: `set` becomes authoritative for deposits from
+    /// `cutover_block` onward, retiring the previous set for new deposits.
+    pub fn schedule_upgrade(
+        &mut self,
+        cutover_block: u64,
+        set: ContractSet,
+    ) -> Result<(), MigrationError> {
+        let (last_cutover, last_set) = self.sets.last().expect("at least the genesis set");
+        if cutover_block <= *last_cutover {
+            return Err(MigrationError::NonMonotonicCutover(cutover_block));
+        }
+        if set.version <= last_set.version {
+            return Err(MigrationError::NonMonotonicVersion(set.version));
+        }
+        self.sets.push((cutover_block, set));
+        Ok(())
+    }
+
+    /// Returns the set authoritative for deposits at `block`.
+    pub fn active_at(&self, block: u64) -> &ContractSet {
+        &self
+            .sets
+            .iter()
+            .rev()
+            .find(|(cutover, _)| *cutover <= block)
+            .expect("the genesis set activates at block 0")
+            .1
+    }
+
+    /// Every escrow the watcher must keep log filters on. Retired escrows
+    /// stay watched: deposits included just before the cutover can still
+    /// surface afterwards and must drain, not disappear.
+    pub fn watched_escrows(&self) -> Vec<EscrowConfig> {
+        self.sets
+            .iter()
+            .flat_map(|(_, set)| set.escrows.iter().cloned())
+            .collect()
+    }
+}
+
+/// Why a deposit was not admitted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rejection {
+    /// The escrow belongs to no scheduled contract set.
+    UnknownEscrow,
+    /// The escrow's set was not authoritative at the deposit's origin block —
+    /// a deposit against retired v1 contracts after the cutover, or against
+    /// v2 contracts before it.
+    InactiveSet,
+    /// The deposit was already admitted once.
+    Duplicate,
+}
+
+/// Pins deposits to contract-set versions across a migration cutover.
+///
+/// The sidecar feeds every observed deposit through [`admit`](Self::admit)
+/// and reports mints through [`complete`](Self::complete);
+/// [`drained`](Self::drained) tells the operator when the retired set has no
+/// in-flight deposits left and its infrastructure can be torn down.
+#[derive(Debug)]
+pub struct MigrationCoordinator {
+    contracts: VersionedContracts,
+    /// Admitted-but-unminted deposits, keyed by id, valued by set version.
+    in_flight: BTreeMap<B256, u32>,
+    /// Deposits minted exactly once.
+    completed: BTreeSet<B256>,
+}
+
+impl MigrationCoordinator {
+    /// Creates a coordinator over the given schedule.
+    pub fn new(contracts: VersionedContracts) -> Self {
+        Self {
+            contracts,
+            in_flight: BTreeMap::new(),
+            completed: BTreeSet::new(),
+        }
+    }
+
+    /// Returns the schedule.
+    pub fn contracts(&self) -> &VersionedContracts {
+        &self.contracts
+    }
+
+    /// Admits a deposit against the set that was authoritative at its origin
+    /// block, regardless of when it was observed — v1 deposits included
+    /// before the cutover drain normally even if they surface after it.
+    pub fn admit(&mut self, deposit: &Deposit) -> Result<u32, Rejection> {
+        let active = self.contracts.active_at(deposit.block_number);
+        if !active.has_escrow(deposit.escrow) {
+            return Err(
+                if self
+                    .contracts
+                    .sets
+                    .iter()
+                    .any(|(_, set)| set.has_escrow(deposit.escrow))
+                {
+                    Rejection::InactiveSet
+                } else {
+                    Rejection::UnknownEscrow
+                },
+            );
+        }
+        let id = deposit.id();
+        if self.completed.contains(&id) || self.in_flight.contains_key(&id) {
+            return Err(Rejection::Duplicate);
+        }
+        self.in_flight.insert(id, active.version);
+        Ok(active.version)
+    }
+
+    /// Records that the deposit was minted on Tempo. Returns the version it
+    /// was admitted under; a second completion of the same id fails rather
+    /// than double-minting.
+    pub fn complete(&mut self, id: B256) -> Result<u32, MigrationError> {
+        if self.completed.contains(&id) {
+            return Err(MigrationError::AlreadyMinted(id));
+        }
+        let version = self
+            .in_flight
+            .remove(&id)
+            .ok_or(MigrationError::UnknownDeposit(id))?;
+        self.completed.insert(id);
+        Ok(version)
+    }
+
+    /// Deposit ids still in flight under `version`.
+    pub fn in_flight(&self, version: u32) -> Vec<B256> {
+        self.in_flight
+            .iter()
+            .filter(|(_, v)| **v == version)
+            .map(|(id, _)| *id)
+            .collect()
+    }
+
+    /// Returns true once no admitted deposit of `version` is unminted — the
+    /// signal that the retired contract set can be torn down.
+    pub fn drained(&self, version: u32) -> bool {
+        self.in_flight.values().all(|v| *v != version)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::U256;
+
+    const CUTOVER: u64 = 100;
+
+    fn set(version: u32, escrow: Address, light_client: Address) -> ContractSet {
+        ContractSet {
+            version,
+            escrows: vec![EscrowConfig::new(escrow)],
+            light_client,
+        }
+    }
+
+    fn deposit(escrow: Address, block_number: u64, log_index: u64) -> Deposit {
+        Deposit {
+            escrow,
+            block_number,
+            tx_hash: B256::with_last_byte(block_number as u8),
+            log_index,
+            from: Address::ZERO,
+            recipient: Address::with_last_byte(9),
+            amount: U256::from(100u64),
+        }
+    }
+
+    fn schedule(v1_escrow: Address, v2_escrow: Address) -> VersionedContracts {
+        let mut contracts =
+            VersionedContracts::new(set(1, v1_escrow, Address::with_last_byte(0xa1)));
+        contracts
+            .schedule_upgrade(CUTOVER, set(2, v2_escrow, Address::with_last_byte(0xa2)))
+            .unwrap();
+        contracts
+    }
+
+    #[test]
+    fn upgrade_schedule_must_move_forward() {
+        let v1 = Address::with_last_byte(1);
+        let mut contracts = schedule(v1, Address::with_last_byte(2));
+        assert_eq!(
+            contracts.schedule_upgrade(CUTOVER, set(3, v1, Address::ZERO)),
+            Err(MigrationError::NonMonotonicCutover(CUTOVER))
+        );
+        assert_eq!(
+            contracts.schedule_upgrade(CUTOVER + 1, set(2, v1, Address::ZERO)),
+            Err(MigrationError::NonMonotonicVersion(2))
+        );
+    }
+
+    #[test]
+    fn migration_cutover_drains_v1_without_loss_or_double_mint() {
+        let v1_escrow = Address::with_last_byte(1);
+        let v2_escrow = Address::with_last_byte(2);
+        let mut coordinator = MigrationCoordinator::new(schedule(v1_escrow, v2_escrow));
+
+        // Both generations stay on the watcher's filter list during the
+        // migration window.
+        assert_eq!(
+            coordinator.contracts().watched_escrows(),
+            vec![EscrowConfig::new(v1_escrow), EscrowConfig::new(v2_escrow)]
+        );
+
+        // Traffic against v1 before the cutover.
+        let early = deposit(v1_escrow, 50, 0);
+        assert_eq!(coordinator.admit(&early), Ok(1));
+
+        // A v1 deposit included just before the cutover but observed after
+        // it still drains against v1.
+        let in_flight = deposit(v1_escrow, CUTOVER - 1, 1);
+        assert_eq!(coordinator.admit(&in_flight), Ok(1));
+
+        // From the cutover block, v1 is retired and v2 is authoritative.
+        assert_eq!(
+            coordinator.admit(&deposit(v1_escrow, CUTOVER, 0)),
+            Err(Rejection::InactiveSet)
+        );
+        let v2_deposit = deposit(v2_escrow, CUTOVER + 5, 0);
+        assert_eq!(coordinator.admit(&v2_deposit), Ok(2));
+
+        // v2 deposits dated before the cutover are equally invalid, as is
+        // anything from an unknown escrow.
+        assert_eq!(
+            coordinator.admit(&deposit(v2_escrow, 50, 2)),
+            Err(Rejection::InactiveSet)
+        );
+        assert_eq!(
+            coordinator.admit(&deposit(Address::with_last_byte(7), 50, 0)),
+            Err(Rejection::UnknownEscrow)
+        );
+
+        // Draining: v1 is done only once both its deposits are minted.
+        assert!(!coordinator.drained(1));
+        assert_eq!(coordinator.complete(early.id()), Ok(1));
+        assert_eq!(coordinator.in_flight(1), vec![in_flight.id()]);
+        assert_eq!(coordinator.complete(in_flight.id()), Ok(1));
+        assert!(coordinator.drained(1));
+        assert!(!coordinator.drained(2));
+
+        // No deposit can be minted twice, and a re-observation of a minted
+        // deposit is a duplicate rather than a fresh admission.
+        assert_eq!(
+            coordinator.complete(early.id()),
+            Err(MigrationError::AlreadyMinted(early.id()))
+        );
+        assert_eq!(coordinator.admit(&early), Err(Rejection::Duplicate));
+
+        // The v2 deposit completes under its own version.
+        assert_eq!(coordinator.complete(v2_deposit.id()), Ok(2));
+        assert!(coordinator.drained(2));
+    }
+
+    #[test]
+    fn completion_requires_admission() {
+        let mut coordinator = MigrationCoordinator::new(schedule(
+            Address::with_last_byte(1),
+            Address::with_last_byte(2),
+        ));
+        let id = B256::with_last_byte(0x99);
+        assert_eq!(
+            coordinator.complete(id),
+            Err(MigrationError::UnknownDeposit(id))
+        );
+    }
+}
@@ -0,0 +1,486 @@
+//! Operator notification hooks for critical bridge events.
+//!
+//! The bridge is designed to fail closed (halt signing) rather than fail
+//! loud, so operators need an out-of-band signal when it does. This module
+//! renders critical events into webhook payloads — generic JSON, Slack, or
+//! PagerDuty — and delivers them through a pluggable transport, with
+//! per-event-kind rate limiting so a flapping origin RPC cannot turn into a
+//! notification storm. The ExEx wires a [`Notifier`] from its config next to
+//! the circuit breaker and unlock submitter.
+
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use alloy_primitives::B256;
+use url::Url;
+
+/// Default minimum interval between notifications of the same event kind.
+pub const DEFAULT_MIN_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Payload format expected by a notification endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EndpointKind {
+    /// Generic webhook; receives the full event as JSON.
+    Webhook,
+    /// Slack incoming webhook; receives a `{"text": ...}` message.
+    Slack,
+    /// PagerDuty Events API v2; receives a `trigger` event.
+    Pagerduty,
+}
+
+/// A single notification endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationEndpoint {
+    /// Where the payload is POSTed. For PagerDuty this is the Events API URL
+    /// including the routing key.
+    pub url: Url,
+    /// Payload format for this endpoint.
+    pub kind: EndpointKind,
+    /// Optional payload template overriding the format default. Supports the
+    /// placeholders `{{event}}`, `{{severity}}`, `{{summary}}`, and
+    /// `{{details}}` (JSON-encoded).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+}
+
+/// Notification configuration, part of the bridge ExEx config.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationConfig {
+    /// Endpoints notified on every critical event.
+    pub endpoints: Vec<NotificationEndpoint>,
+    /// Minimum interval between notifications of the same event kind.
+    /// Suppressed events are counted and logged, not queued.
+    pub min_interval: Duration,
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        Self {
+            endpoints: Vec::new(),
+            min_interval: DEFAULT_MIN_INTERVAL,
+        }
+    }
+}
+
+/// A critical event worth waking an operator for.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BridgeEvent {
+    /// The circuit breaker halted signing.
+    CircuitBreakerTripped {
+        /// Scope (token / escrow) the breaker tripped for.
+        scope: String,
+        /// Human-readable trip reason.
+        reason: String,
+    },
+    /// An unlock kept failing after retries.
+    RepeatedUnlockFailures {
+        /// Burn the unlock corresponds to.
+        burn_id: B256,
+        /// Number of consecutive failed attempts.
+        attempts: u32,
+        /// Error from the last attempt.
+        last_error: String,
+    },
+    /// The mint precompile rejected a validator signature.
+    SignatureRejected {
+        /// Burn or deposit the signature was produced for.
+        burn_id: B256,
+        /// Rejection reason as reported by the precompile.
+        reason: String,
+    },
+    /// A deposit went stale before reaching its signature threshold.
+    DepositExpired {
+        /// The expired deposit.
+        deposit_id: B256,
+        /// Origin block the deposit was first observed at.
+        first_seen_block: u64,
+        /// Signatures collected before expiry.
+        signatures_collected: usize,
+        /// Signatures that would have been required.
+        threshold: usize,
+    },
+    /// Two origin RPC endpoints disagree about chain state.
+    OriginRpcDivergence {
+        /// Block height reported by the primary endpoint.
+        primary_block: u64,
+        /// Block height reported by the secondary endpoint.
+        secondary_block: u64,
+        /// What diverged (heads, logs, balances, ...).
+        detail: String,
+    },
+}
+
+impl BridgeEvent {
+    /// Stable event kind identifier, also the rate-limiting key.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::CircuitBreakerTripped { .. } => "circuit_breaker_tripped",
+            Self::RepeatedUnlockFailures { .. } => "repeated_unlock_failures",
+            Self::SignatureRejected { .. } => "signature_rejected",
+            Self::DepositExpired { .. } => "deposit_expired",
+            Self::OriginRpcDivergence { .. } => "origin_rpc_divergence",
+        }
+    }
+
+    /// Severity in PagerDuty terms (`critical` or `warning`).
+    pub fn severity(&self) -> &'static str {
+        match self {
+            Self::CircuitBreakerTripped { .. } | Self::SignatureRejected { .. } => "critical",
+            Self::RepeatedUnlockFailures { .. }
+            | Self::DepositExpired { .. }
+            | Self::OriginRpcDivergence { .. } => "warning",
+        }
+    }
+
+    /// One-line human-readable summary.
+    pub fn summary(&self) -> String {
+        match self {
+            Self::CircuitBreakerTripped { scope, reason } => {
+                format!("bridge circuit breaker tripped for `{scope}`: {reason}")
+            }
+            Self::RepeatedUnlockFailures {
+                burn_id,
+                attempts,
+                last_error,
+            } => {
+                format!("unlock for burn {burn_id} failed {attempts} times: {last_error}")
+            }
+            Self::SignatureRejected { burn_id, reason } => {
+                format!("precompile rejected validator signature for {burn_id}: {reason}")
+            }
+            Self::DepositExpired {
+                deposit_id,
+                signatures_collected,
+                threshold,
+                ..
+            } => {
+                format!(
+                    "deposit {deposit_id} expired with {signatures_collected}/{threshold} \
+                     signatures; escalate with `bridge-cli retry --escalate`"
+                )
+            }
+            Self::OriginRpcDivergence {
+                primary_block,
+                secondary_block,
+                detail,
+            } => {
+                format!(
+                    "origin RPC endpoints diverged (primary at {primary_block}, \
+                     secondary at {secondary_block}): {detail}"
+                )
+            }
+        }
+    }
+
+    /// Structured event fields for machine consumption.
+    pub fn details(&self) -> serde_json::Value {
+        match self {
+            Self::CircuitBreakerTripped { scope, reason } => serde_json::json!({
+                "scope": scope,
+                "reason": reason,
+            }),
+            Self::RepeatedUnlockFailures {
+                burn_id,
+                attempts,
+                last_error,
+            } => serde_json::json!({
+                "burnId": burn_id,
+                "attempts": attempts,
+                "lastError": last_error,
+            }),
+            Self::SignatureRejected { burn_id, reason } => serde_json::json!({
+                "burnId": burn_id,
+                "reason": reason,
+            }),
+            Self::DepositExpired {
+                deposit_id,
+                first_seen_block,
+                signatures_collected,
+                threshold,
+            } => serde_json::json!({
+                "depositId": deposit_id,
+                "firstSeenBlock": first_seen_block,
+                "signaturesCollected": signatures_collected,
+                "threshold": threshold,
+            }),
+            Self::OriginRpcDivergence {
+                primary_block,
+                secondary_block,
+                detail,
+            } => serde_json::json!({
+                "primaryBlock": primary_block,
+                "secondaryBlock": secondary_block,
+                "detail": detail,
+            }),
+        }
+    }
+}
+
+/// Abstraction over payload delivery, so the notifier can be exercised
+/// without a live endpoint.
+#[async_trait::async_trait]
+pub trait NotificationTransport: Send + Sync {
+    /// POSTs `body` (a JSON document) to `url`.
+    async fn deliver(&self, url: &Url, body: &str) -> eyre::Result<()>;
+}
+
+/// Renders, rate limits, and delivers [`BridgeEvent`]s to all configured
+/// endpoints. Delivery is best-effort: a failing endpoint is logged and does
+/// not block the others, and never the bridge itself.
+pub struct Notifier<T> {
+    config: NotificationConfig,
+    transport: T,
+    last_sent: HashMap<&'static str, Instant>,
+    suppressed: u64,
+}
+
+impl<T: NotificationTransport> Notifier<T> {
+    /// Creates a notifier from its config and a delivery transport.
+    pub fn new(config: NotificationConfig, transport: T) -> Self {
+        Self {
+            config,
+            transport,
+            last_sent: HashMap::new(),
+            suppressed: 0,
+        }
+    }
+
+    /// Number of notifications dropped by the rate limiter.
+    pub fn suppressed(&self) -> u64 {
+        self.suppressed
+    }
+
+    /// Notifies all endpoints about `event`.
+    ///
+    /// Returns `false` if the event was suppressed because another event of
+    /// the same kind fired within `min_interval`.
+    pub async fn notify(&mut self, event: &BridgeEvent) -> bool {
+        let now = Instant::now();
+        if let Some(last) = self.last_sent.get(event.kind())
+            && now.duration_since(*last) < self.config.min_interval
+        {
+            self.suppressed += 1;
+            tracing::debug!(
+                kind = event.kind(),
+                suppressed = self.suppressed,
+                "notification rate limited",
+            );
+            return false;
+        }
+        self.last_sent.insert(event.kind(), now);
+
+        for endpoint in &self.config.endpoints {
+            let body = render(endpoint, event);
+            if let Err(error) = self.transport.deliver(&endpoint.url, &body).await {
+                tracing::warn!(
+                    %error,
+                    url = %endpoint.url,
+                    kind = event.kind(),
+                    "failed delivering notification",
+                );
+            }
+        }
+        true
+    }
+}
+
+/// Renders `event` into the payload `endpoint` expects.
+fn render(endpoint: &NotificationEndpoint, event: &BridgeEvent) -> String {
+    if let Some(template) = &endpoint.template {
+        return template
+            .replace("{{event}}", event.kind())
+            .replace("{{severity}}", event.severity())
+            .replace("{{summary}}", &event.summary())
+            .replace("{{details}}", &event.details().to_string());
+    }
+
+    match endpoint.kind {
+        EndpointKind::Webhook => serde_json::json!({
+            "event": event.kind(),
+            "severity": event.severity(),
+            "summary": event.summary(),
+            "details": event.details(),
+        })
+        .to_string(),
+        EndpointKind::Slack => serde_json::json!({
+            "text": format!("[{}] {}", event.severity(), event.summary()),
+        })
+        .to_string(),
+        EndpointKind::Pagerduty => serde_json::json!({
+            "event_action": "trigger",
+            "dedup_key": event.kind(),
+            "payload": {
+                "summary": event.summary(),
+                "severity": event.severity(),
+                "source": "tempo-bridge",
+                "custom_details": event.details(),
+            },
+        })
+        .to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct RecordingTransport {
+        delivered: Mutex<Vec<(Url, String)>>,
+    }
+
+    #[async_trait::async_trait]
+    impl NotificationTransport for RecordingTransport {
+        async fn deliver(&self, url: &Url, body: &str) -> eyre::Result<()> {
+            self.delivered
+                .lock()
+                .unwrap()
+                .push((url.clone(), body.to_string()));
+            Ok(())
+        }
+    }
+
+    fn endpoint(kind: EndpointKind, template: Option<&str>) -> NotificationEndpoint {
+        NotificationEndpoint {
+            url: "https://hooks.example.com/bridge".parse().unwrap(),
+            kind,
+            template: template.map(str::to_string),
+        }
+    }
+
+    fn trip_event() -> BridgeEvent {
+        BridgeEvent::CircuitBreakerTripped {
+            scope: "escrow-usdc".to_string(),
+            reason: "deposit volume anomaly".to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn renders_per_endpoint_formats() {
+        let config = NotificationConfig {
+            endpoints: vec![
+                endpoint(EndpointKind::Webhook, None),
+                endpoint(EndpointKind::Slack, None),
+                endpoint(EndpointKind::Pagerduty, None),
+            ],
+            min_interval: DEFAULT_MIN_INTERVAL,
+        };
+        let mut notifier = Notifier::new(config, RecordingTransport::default());
+
+        assert!(notifier.notify(&trip_event()).await);
+
+        let delivered = notifier.transport.delivered.lock().unwrap();
+        assert_eq!(delivered.len(), 3);
+
+        let webhook: serde_json::Value = serde_json::from_str(&delivered[0].1).unwrap();
+        assert_eq!(webhook["event"], "circuit_breaker_tripped");
+        assert_eq!(webhook["severity"], "critical");
+        assert_eq!(webhook["details"]["scope"], "escrow-usdc");
+
+        let slack: serde_json::Value = serde_json::from_str(&delivered[1].1).unwrap();
+        assert!(
+            slack["text"]
+                .as_str()
+                .unwrap()
+                .starts_with("[critical] bridge circuit breaker tripped")
+        );
+
+        let pagerduty: serde_json::Value = serde_json::from_str(&delivered[2].1).unwrap();
+        assert_eq!(pagerduty["event_action"], "trigger");
+        assert_eq!(pagerduty["payload"]["severity"], "critical");
+    }
+
+    #[tokio::test]
+    async fn custom_template_substitutes_placeholders() {
+        let config = NotificationConfig {
+            endpoints: vec![endpoint(
+                EndpointKind::Webhook,
+                Some(r#"{"kind":"{{event}}","msg":"{{summary}}","data":{{details}}}"#),
+            )],
+            min_interval: DEFAULT_MIN_INTERVAL,
+        };
+        let mut notifier = Notifier::new(config, RecordingTransport::default());
+
+        assert!(
+            notifier
+                .notify(&BridgeEvent::OriginRpcDivergence {
+                    primary_block: 100,
+                    secondary_block: 90,
+                    detail: "heads".to_string(),
+                })
+                .await
+        );
+
+        let delivered = notifier.transport.delivered.lock().unwrap();
+        let body: serde_json::Value = serde_json::from_str(&delivered[0].1).unwrap();
+        assert_eq!(body["kind"], "origin_rpc_divergence");
+        assert_eq!(body["data"]["primaryBlock"], 100);
+    }
+
+    #[tokio::test]
+    async fn rate_limits_per_event_kind() {
+        let config = NotificationConfig {
+            endpoints: vec![endpoint(EndpointKind::Webhook, None)],
+            min_interval: Duration::from_secs(3600),
+        };
+        let mut notifier = Notifier::new(config, RecordingTransport::default());
+
+        // First of a kind goes through; an immediate repeat is suppressed.
+        assert!(notifier.notify(&trip_event()).await);
+        assert!(!notifier.notify(&trip_event()).await);
+        assert_eq!(notifier.suppressed(), 1);
+
+        // A different kind is not affected by the trip's rate limit.
+        assert!(
+            notifier
+                .notify(&BridgeEvent::SignatureRejected {
+                    burn_id: B256::ZERO,
+                    reason: "bad quorum".to_string(),
+                })
+                .await
+        );
+
+        assert_eq!(notifier.transport.delivered.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn delivery_failure_does_not_block_other_endpoints() {
+        struct FailingFirst {
+            inner: RecordingTransport,
+        }
+
+        #[async_trait::async_trait]
+        impl NotificationTransport for FailingFirst {
+            async fn deliver(&self, url: &Url, body: &str) -> eyre::Result<()> {
+                if url.path().ends_with("/down") {
+                    eyre::bail!("endpoint unreachable");
+                }
+                self.inner.deliver(url, body).await
+            }
+        }
+
+        let down = NotificationEndpoint {
+            url: "https://hooks.example.com/down".parse().unwrap(),
+            kind: EndpointKind::Webhook,
+            template: None,
+        };
+        let config = NotificationConfig {
+            endpoints: vec![down, endpoint(EndpointKind::Slack, None)],
+            min_interval: DEFAULT_MIN_INTERVAL,
+        };
+        let mut notifier = Notifier::new(
+            config,
+            FailingFirst {
+                inner: RecordingTransport::default(),
+            },
+        );
+
+        assert!(notifier.notify(&trip_event()).await);
+        assert_eq!(notifier.transport.inner.delivered.lock().unwrap().len(), 1);
+    }
+}
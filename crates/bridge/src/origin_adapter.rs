@@ -0,0 +1,217 @@
+//! Chain-family adapters for origin chains.
+//!
+//! [`crate::origin_watcher::OriginClient`] implementations talk to a concrete
+//! RPC endpoint, but everything chain-*family* specific — how a deposit event
+//! is laid out in a log, what counts as a final block, how the escrow unlock
+//! call is encoded — is the same for every EVM origin and entirely different
+//! for, say, an L2 with single-slot finality or a non-EVM chain. That shared
+//! surface lives behind [`OriginChainAdapter`] so a new origin chain means a
+//! new adapter, not a rewrite of the watcher or submitter plumbing.
+
+use crate::origin_watcher::Deposit;
+use alloy_primitives::{Address, B256, Bytes, U256, keccak256};
+
+/// A raw log fetched from an origin chain, before any event decoding.
+///
+/// Mirrors the EVM log shape since that is the lowest common denominator the
+/// current origins produce; non-EVM adapters are expected to map their native
+/// event representation into this.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawOriginLog {
+    /// Contract that emitted the log.
+    pub address: Address,
+    /// Indexed topics, including the event signature topic.
+    pub topics: Vec<B256>,
+    /// Non-indexed event data.
+    pub data: Bytes,
+    /// Block in which the log was included.
+    pub block_number: u64,
+    /// Transaction that emitted the log.
+    pub tx_hash: B256,
+    /// Index of the log within the block.
+    pub log_index: u64,
+}
+
+/// An unlock to be encoded into an escrow call on the origin chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnlockCall {
+    /// Burn id doubling as the escrow's idempotency salt.
+    pub burn_id: B256,
+    /// Recipient of the unlocked funds on the origin chain.
+    pub recipient: Address,
+    /// Amount to unlock.
+    pub amount: U256,
+}
+
+/// Everything chain-family specific about an origin chain.
+///
+/// Implementations must be stateless with respect to individual deposits:
+/// confirmation tracking, caps, and journaling all stay in the watcher and
+/// submitter, which only consume the adapter's decoded output.
+pub trait OriginChainAdapter: Send + Sync {
+    /// Chain family name used in logs and metrics (e.g. `"evm"`).
+    fn name(&self) -> &'static str;
+
+    /// Decodes a raw log into a [`Deposit`].
+    ///
+    /// Returns `None` for logs that are not deposit events of this chain
+    /// family (wrong signature topic, malformed layout); such logs are
+    /// skipped, not treated as errors, since escrow contracts emit other
+    /// events too.
+    fn decode_deposit(&self, log: &RawOriginLog) -> Option<Deposit>;
+
+    /// Applies the chain's finality rule: the highest block at `head` that is
+    /// final under the given confirmation depth.
+    ///
+    /// EVM chains subtract the depth; a chain with protocol-level finality may
+    /// ignore `confirmations` entirely.
+    fn finalized_block(&self, head: u64, confirmations: u64) -> u64;
+
+    /// Encodes the escrow unlock call for this chain family.
+    fn encode_unlock_call(&self, unlock: &UnlockCall) -> Bytes;
+}
+
+/// The deposit event every EVM escrow emits:
+/// `Deposited(address indexed from, address indexed recipient, uint256 amount)`.
+pub const EVM_DEPOSIT_EVENT_SIGNATURE: &str = "Deposited(address,address,uint256)";
+
+/// The unlock function every EVM escrow exposes:
+/// `unlock(bytes32 burnId, address recipient, uint256 amount)`.
+pub const EVM_UNLOCK_FUNCTION_SIGNATURE: &str = "unlock(bytes32,address,uint256)";
+
+/// Adapter for EVM origin chains (Ethereum mainnet and EVM-equivalent L2s
+/// whose finality is expressed as a confirmation depth).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EvmOriginAdapter;
+
+impl EvmOriginAdapter {
+    /// Signature topic of the escrow deposit event.
+    pub fn deposit_topic() -> B256 {
+        keccak256(EVM_DEPOSIT_EVENT_SIGNATURE.as_bytes())
+    }
+
+    /// 4-byte selector of the escrow unlock function.
+    pub fn unlock_selector() -> [u8; 4] {
+        keccak256(EVM_UNLOCK_FUNCTION_SIGNATURE.as_bytes())[..4]
+            .try_into()
+            .expect("hash is 32 bytes")
+    }
+}
+
+impl OriginChainAdapter for EvmOriginAdapter {
+    fn name(&self) -> &'static str {
+        "evm"
+    }
+
+    fn decode_deposit(&self, log: &RawOriginLog) -> Option<Deposit> {
+        let [signature, from, recipient] = log.topics.as_slice() else {
+            return None;
+        };
+        if *signature != Self::deposit_topic() || log.data.len() != 32 {
+            return None;
+        }
+
+        Some(Deposit {
+            escrow: log.address,
+            block_number: log.block_number,
+            tx_hash: log.tx_hash,
+            log_index: log.log_index,
+            from: Address::from_word(*from),
+            recipient: Address::from_word(*recipient),
+            amount: U256::from_be_slice(&log.data),
+        })
+    }
+
+    fn finalized_block(&self, head: u64, confirmations: u64) -> u64 {
+        head.saturating_sub(confirmations)
+    }
+
+    fn encode_unlock_call(&self, unlock: &UnlockCall) -> Bytes {
+        let mut calldata = Vec::with_capacity(4 + 3 * 32);
+        calldata.extend_from_slice(&Self::unlock_selector());
+        calldata.extend_from_slice(unlock.burn_id.as_slice());
+        calldata.extend_from_slice(&B256::left_padding_from(unlock.recipient.as_slice())[..]);
+        calldata.extend_from_slice(&unlock.amount.to_be_bytes::<32>());
+        calldata.into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deposit_log() -> RawOriginLog {
+        RawOriginLog {
+            address: Address::with_last_byte(1),
+            topics: vec![
+                EvmOriginAdapter::deposit_topic(),
+                Address::with_last_byte(2).into_word(),
+                Address::with_last_byte(3).into_word(),
+            ],
+            data: U256::from(1_000u64).to_be_bytes::<32>().into(),
+            block_number: 42,
+            tx_hash: B256::with_last_byte(4),
+            log_index: 7,
+        }
+    }
+
+    #[test]
+    fn decodes_deposit_event() {
+        let deposit = EvmOriginAdapter.decode_deposit(&deposit_log()).unwrap();
+        assert_eq!(
+            deposit,
+            Deposit {
+                escrow: Address::with_last_byte(1),
+                block_number: 42,
+                tx_hash: B256::with_last_byte(4),
+                log_index: 7,
+                from: Address::with_last_byte(2),
+                recipient: Address::with_last_byte(3),
+                amount: U256::from(1_000u64),
+            }
+        );
+    }
+
+    #[test]
+    fn skips_non_deposit_logs() {
+        // Wrong signature topic.
+        let mut log = deposit_log();
+        log.topics[0] = B256::with_last_byte(0xFF);
+        assert!(EvmOriginAdapter.decode_deposit(&log).is_none());
+
+        // Missing indexed topic.
+        let mut log = deposit_log();
+        log.topics.pop();
+        assert!(EvmOriginAdapter.decode_deposit(&log).is_none());
+
+        // Malformed data section.
+        let mut log = deposit_log();
+        log.data = Bytes::from_static(&[0u8; 31]);
+        assert!(EvmOriginAdapter.decode_deposit(&log).is_none());
+    }
+
+    #[test]
+    fn finality_is_a_confirmation_depth() {
+        assert_eq!(EvmOriginAdapter.finalized_block(100, 12), 88);
+        // Near genesis the rule saturates instead of underflowing.
+        assert_eq!(EvmOriginAdapter.finalized_block(5, 12), 0);
+    }
+
+    #[test]
+    fn unlock_call_layout() {
+        let unlock = UnlockCall {
+            burn_id: B256::with_last_byte(1),
+            recipient: Address::with_last_byte(2),
+            amount: U256::from(3u64),
+        };
+        let calldata = EvmOriginAdapter.encode_unlock_call(&unlock);
+
+        assert_eq!(calldata.len(), 4 + 3 * 32);
+        assert_eq!(calldata[..4], EvmOriginAdapter::unlock_selector());
+        assert_eq!(calldata[4..36], unlock.burn_id[..]);
+        // Address is left-padded into its word.
+        assert!(calldata[36..48].iter().all(|byte| *byte == 0));
+        assert_eq!(calldata[48..68], unlock.recipient[..]);
+        assert_eq!(calldata[68..100], unlock.amount.to_be_bytes::<32>());
+    }
+}
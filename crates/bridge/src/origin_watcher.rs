@@ -0,0 +1,590 @@
+//! Watches the origin chain escrows for deposits.
+//!
+//! Several escrow contracts (e.g. USDC, USDT, PYUSD) can be watched per origin
+//! chain, each with its own confirmation depth and deposit cap; log filters are
+//! multiplexed across all of them over a single connection.
+//!
+//! When the configured RPC URL is `ws://`/`wss://`, deposits are detected via
+//! an `eth_subscribe("logs")` subscription for sub-second latency, with
+//! heartbeat-based liveness detection and automatic resubscription on
+//! disconnect. After repeated subscription failures the watcher degrades to
+//! HTTP-style polling so deposits keep flowing, just with higher latency.
+
+use alloy_primitives::{Address, B256, U256, keccak256};
+use futures::{Stream, StreamExt};
+use std::{collections::BTreeMap, pin::Pin, time::Duration};
+use tokio::sync::mpsc;
+use url::Url;
+
+/// Default polling interval when running in (or degraded to) polling mode.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Default duration without any subscription activity before the connection is
+/// considered dead and resubscribed.
+pub const DEFAULT_HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default number of consecutive subscription failures before falling back to
+/// polling.
+pub const DEFAULT_MAX_WS_FAILURES: u32 = 5;
+
+/// A watched escrow contract on the origin chain.
+///
+/// One origin chain commonly carries several escrows (USDC, USDT, PYUSD),
+/// each with its own risk profile — hence per-escrow confirmation depth and
+/// deposit cap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EscrowConfig {
+    /// Escrow contract address.
+    pub address: Address,
+    /// Blocks that must be built on top of a deposit before it is forwarded.
+    pub confirmations: u64,
+    /// Per-deposit amount cap. Deposits above the cap are dropped with a
+    /// warning and left for manual review instead of being auto-signed.
+    pub cap: Option<U256>,
+}
+
+impl EscrowConfig {
+    /// Creates an escrow config with no confirmation depth and no cap.
+    pub fn new(address: Address) -> Self {
+        Self {
+            address,
+            confirmations: 0,
+            cap: None,
+        }
+    }
+
+    /// Sets the confirmation depth.
+    pub fn with_confirmations(mut self, confirmations: u64) -> Self {
+        self.confirmations = confirmations;
+        self
+    }
+
+    /// Sets the per-deposit amount cap.
+    pub fn with_cap(mut self, cap: U256) -> Self {
+        self.cap = Some(cap);
+        self
+    }
+}
+
+/// Configuration for the origin chain watcher.
+#[derive(Debug, Clone)]
+pub struct OriginWatcherConfig {
+    /// Origin chain RPC endpoint. `ws://`/`wss://` enables subscriptions.
+    pub rpc_url: Url,
+    /// Escrow contracts whose deposit events are watched.
+    pub escrows: Vec<EscrowConfig>,
+    /// Interval between `eth_getLogs` polls in polling mode.
+    pub poll_interval: Duration,
+    /// Duration without subscription activity before resubscribing.
+    pub heartbeat_timeout: Duration,
+    /// Consecutive subscription failures before degrading to polling.
+    pub max_ws_failures: u32,
+}
+
+impl OriginWatcherConfig {
+    /// Creates a config with default timings for the given endpoint and escrows.
+    pub fn new(rpc_url: Url, escrows: Vec<EscrowConfig>) -> Self {
+        Self {
+            rpc_url,
+            escrows,
+            poll_interval: DEFAULT_POLL_INTERVAL,
+            heartbeat_timeout: DEFAULT_HEARTBEAT_TIMEOUT,
+            max_ws_failures: DEFAULT_MAX_WS_FAILURES,
+        }
+    }
+
+    /// Returns the config of the escrow at `address`, if watched.
+    pub fn escrow(&self, address: Address) -> Option<&EscrowConfig> {
+        self.escrows.iter().find(|escrow| escrow.address == address)
+    }
+
+    /// Returns true if the endpoint supports `eth_subscribe`.
+    pub fn supports_subscriptions(&self) -> bool {
+        matches!(self.rpc_url.scheme(), "ws" | "wss")
+    }
+}
+
+/// A deposit observed on an origin chain escrow.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Deposit {
+    /// Escrow contract the deposit was made to.
+    pub escrow: Address,
+    /// Block in which the deposit was included.
+    pub block_number: u64,
+    /// Transaction hash of the deposit.
+    pub tx_hash: B256,
+    /// Index of the deposit log within the block.
+    pub log_index: u64,
+    /// Depositor on the origin chain.
+    pub from: Address,
+    /// Recipient on Tempo.
+    pub recipient: Address,
+    /// Deposited amount.
+    pub amount: U256,
+}
+
+impl Deposit {
+    /// Unique deposit id: `keccak256(escrow ++ tx_hash ++ log_index)`.
+    ///
+    /// The escrow address is part of the preimage so deposits on different
+    /// escrows of the same origin chain can never alias.
+    pub fn id(&self) -> B256 {
+        let mut preimage = Vec::with_capacity(20 + 32 + 8);
+        preimage.extend_from_slice(self.escrow.as_slice());
+        preimage.extend_from_slice(self.tx_hash.as_slice());
+        preimage.extend_from_slice(&self.log_index.to_be_bytes());
+        keccak256(preimage)
+    }
+}
+
+/// A boxed stream of deposits produced by a log subscription.
+pub type DepositStream = Pin<Box<dyn Stream<Item = Deposit> + Send>>;
+
+/// Abstraction over the origin chain RPC, so the watcher loop can be exercised
+/// without a live endpoint.
+#[async_trait::async_trait]
+pub trait OriginClient: Send + Sync {
+    /// Returns the latest (finality-adjusted) block number.
+    async fn latest_block(&self) -> eyre::Result<u64>;
+
+    /// Returns deposits to `escrow` in the inclusive block range.
+    async fn deposits_in_range(
+        &self,
+        escrow: Address,
+        from: u64,
+        to: u64,
+    ) -> eyre::Result<Vec<Deposit>>;
+
+    /// Opens an `eth_subscribe("logs")` subscription multiplexed across all
+    /// configured escrows.
+    async fn subscribe_deposits(&self) -> eyre::Result<DepositStream>;
+}
+
+/// Transport the watcher is currently using.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatcherMode {
+    /// Log subscription over WebSocket.
+    Subscription,
+    /// Periodic `eth_getLogs` polling.
+    Polling,
+}
+
+/// Watches the origin chain for escrow deposits and forwards them to the
+/// signer pipeline.
+pub struct OriginWatcher<C> {
+    config: OriginWatcherConfig,
+    client: C,
+    deposits: mpsc::UnboundedSender<Deposit>,
+    /// Consecutive subscription failures since the last successful event.
+    ws_failures: u32,
+    /// Last block fully covered per escrow, to avoid re-emitting deposits.
+    covered: BTreeMap<Address, u64>,
+    /// Subscription deposits waiting for their escrow's confirmation depth.
+    pending: Vec<Deposit>,
+    /// Highest block observed via the subscription.
+    head: u64,
+    mode: WatcherMode,
+}
+
+impl<C: OriginClient> OriginWatcher<C> {
+    /// Creates a watcher starting in subscription mode when the endpoint
+    /// supports it, polling mode otherwise.
+    pub fn new(
+        config: OriginWatcherConfig,
+        client: C,
+        deposits: mpsc::UnboundedSender<Deposit>,
+        start_block: u64,
+    ) -> Self {
+        let mode = if config.supports_subscriptions() {
+            WatcherMode::Subscription
+        } else {
+            WatcherMode::Polling
+        };
+        let covered = config
+            .escrows
+            .iter()
+            .map(|escrow| (escrow.address, start_block))
+            .collect();
+        Self {
+            config,
+            client,
+            deposits,
+            ws_failures: 0,
+            covered,
+            pending: Vec::new(),
+            head: start_block,
+            mode,
+        }
+    }
+
+    /// Returns the transport currently in use.
+    pub fn mode(&self) -> WatcherMode {
+        self.mode
+    }
+
+    /// Runs the watcher until the deposit channel is closed.
+    pub async fn run(mut self) {
+        loop {
+            let closed = match self.mode {
+                WatcherMode::Subscription => self.run_subscription().await,
+                WatcherMode::Polling => self.run_polling().await,
+            };
+            if closed {
+                return;
+            }
+        }
+    }
+
+    /// Drives the subscription until it fails or goes silent. Returns true if
+    /// the deposit channel closed.
+    ///
+    /// Consecutive failures are counted across resubscriptions; once
+    /// [`OriginWatcherConfig::max_ws_failures`] is reached the watcher degrades
+    /// to polling mode.
+    async fn run_subscription(&mut self) -> bool {
+        let mut stream = match self.client.subscribe_deposits().await {
+            Ok(stream) => stream,
+            Err(err) => {
+                tracing::warn!(target: "bridge::origin_watcher", %err, "log subscription failed");
+                return self.record_ws_failure().await;
+            }
+        };
+        tracing::debug!(target: "bridge::origin_watcher", "log subscription established");
+
+        loop {
+            match tokio::time::timeout(self.config.heartbeat_timeout, stream.next()).await {
+                Ok(Some(deposit)) => {
+                    self.ws_failures = 0;
+                    self.head = self.head.max(deposit.block_number);
+                    if self.buffer_and_flush(deposit) {
+                        return true;
+                    }
+                }
+                Ok(None) => {
+                    tracing::warn!(target: "bridge::origin_watcher", "log subscription ended, resubscribing");
+                    return self.record_ws_failure().await;
+                }
+                Err(_) => {
+                    // No activity within the heartbeat window: the connection
+                    // may be half-dead, so proactively resubscribe.
+                    tracing::warn!(
+                        target: "bridge::origin_watcher",
+                        timeout = ?self.config.heartbeat_timeout,
+                        "no subscription activity, resubscribing"
+                    );
+                    return self.record_ws_failure().await;
+                }
+            }
+        }
+    }
+
+    /// Records a subscription failure, degrading to polling mode once the
+    /// failure budget is exhausted. Returns true if the channel closed while
+    /// catching up over HTTP.
+    async fn record_ws_failure(&mut self) -> bool {
+        self.ws_failures += 1;
+        if self.ws_failures >= self.config.max_ws_failures {
+            tracing::error!(
+                target: "bridge::origin_watcher",
+                failures = self.ws_failures,
+                "subscription failing persistently, falling back to polling"
+            );
+            self.mode = WatcherMode::Polling;
+        }
+        // Backfill anything missed while the subscription was down.
+        self.poll_once().await
+    }
+
+    /// Runs the polling loop. Returns true when the deposit channel closes.
+    async fn run_polling(&mut self) -> bool {
+        loop {
+            if self.poll_once().await {
+                return true;
+            }
+            tokio::time::sleep(self.config.poll_interval).await;
+        }
+    }
+
+    /// Polls every escrow for deposits since its last covered block, honoring
+    /// each escrow's confirmation depth. Returns true if the deposit channel
+    /// closed.
+    async fn poll_once(&mut self) -> bool {
+        let latest = match self.client.latest_block().await {
+            Ok(latest) => latest,
+            Err(err) => {
+                tracing::warn!(target: "bridge::origin_watcher", %err, "failed to fetch latest block");
+                return false;
+            }
+        };
+
+        for escrow in self.config.escrows.clone() {
+            let confirmed = latest.saturating_sub(escrow.confirmations);
+            let from = self.covered.get(&escrow.address).copied().unwrap_or(0) + 1;
+            if confirmed < from {
+                continue;
+            }
+
+            match self
+                .client
+                .deposits_in_range(escrow.address, from, confirmed)
+                .await
+            {
+                Ok(found) => {
+                    self.covered.insert(escrow.address, confirmed);
+                    for deposit in found {
+                        if self.admit_and_send(deposit) {
+                            return true;
+                        }
+                    }
+                }
+                Err(err) => {
+                    tracing::warn!(
+                        target: "bridge::origin_watcher",
+                        escrow = %escrow.address,
+                        %err,
+                        "eth_getLogs poll failed"
+                    );
+                }
+            }
+        }
+        false
+    }
+
+    /// Buffers a subscription deposit and forwards every pending deposit whose
+    /// escrow confirmation depth is satisfied at the current head. Returns true
+    /// if the deposit channel closed.
+    fn buffer_and_flush(&mut self, deposit: Deposit) -> bool {
+        self.pending.push(deposit);
+        let mut i = 0;
+        while i < self.pending.len() {
+            let confirmations = self
+                .config
+                .escrow(self.pending[i].escrow)
+                .map_or(0, |escrow| escrow.confirmations);
+            if self.pending[i].block_number.saturating_add(confirmations) <= self.head {
+                let deposit = self.pending.remove(i);
+                if self.admit_and_send(deposit) {
+                    return true;
+                }
+            } else {
+                i += 1;
+            }
+        }
+        false
+    }
+
+    /// Applies the escrow's deposit cap and forwards the deposit, marking its
+    /// block as covered for that escrow. Returns true if the channel closed.
+    fn admit_and_send(&mut self, deposit: Deposit) -> bool {
+        if let Some(cap) = self.config.escrow(deposit.escrow).and_then(|e| e.cap)
+            && deposit.amount > cap
+        {
+            tracing::warn!(
+                target: "bridge::origin_watcher",
+                deposit_id = %deposit.id(),
+                escrow = %deposit.escrow,
+                amount = %deposit.amount,
+                %cap,
+                "deposit exceeds escrow cap, dropping for manual review"
+            );
+            return false;
+        }
+
+        let covered = self.covered.entry(deposit.escrow).or_default();
+        *covered = (*covered).max(deposit.block_number);
+        self.deposits.send(deposit).is_err()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU32, AtomicU64, Ordering};
+
+    /// Client whose subscriptions always fail, with deposits only reachable
+    /// via polling.
+    struct FailingWsClient {
+        subscribe_attempts: AtomicU32,
+        latest: AtomicU64,
+    }
+
+    #[async_trait::async_trait]
+    impl OriginClient for FailingWsClient {
+        async fn latest_block(&self) -> eyre::Result<u64> {
+            Ok(self.latest.load(Ordering::SeqCst))
+        }
+
+        async fn deposits_in_range(
+            &self,
+            escrow: Address,
+            from: u64,
+            to: u64,
+        ) -> eyre::Result<Vec<Deposit>> {
+            Ok((from..=to)
+                .map(|block_number| Deposit {
+                    escrow,
+                    block_number,
+                    tx_hash: B256::with_last_byte(block_number as u8),
+                    log_index: 0,
+                    from: Address::ZERO,
+                    recipient: Address::ZERO,
+                    amount: U256::from(1u64),
+                })
+                .collect())
+        }
+
+        async fn subscribe_deposits(&self) -> eyre::Result<DepositStream> {
+            self.subscribe_attempts.fetch_add(1, Ordering::SeqCst);
+            eyre::bail!("connection refused")
+        }
+    }
+
+    fn ws_config() -> OriginWatcherConfig {
+        OriginWatcherConfig {
+            poll_interval: Duration::from_millis(1),
+            heartbeat_timeout: Duration::from_millis(50),
+            max_ws_failures: 3,
+            ..OriginWatcherConfig::new(
+                "ws://localhost:8546".parse().unwrap(),
+                vec![EscrowConfig::new(Address::ZERO)],
+            )
+        }
+    }
+
+    #[test]
+    fn http_url_starts_in_polling_mode() {
+        let config = OriginWatcherConfig::new(
+            "http://localhost:8545".parse().unwrap(),
+            vec![EscrowConfig::new(Address::ZERO)],
+        );
+        assert!(!config.supports_subscriptions());
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let watcher = OriginWatcher::new(config, failing_client(), tx, 0);
+        assert_eq!(watcher.mode(), WatcherMode::Polling);
+    }
+
+    fn failing_client() -> FailingWsClient {
+        FailingWsClient {
+            subscribe_attempts: AtomicU32::new(0),
+            latest: AtomicU64::new(0),
+        }
+    }
+
+    #[tokio::test]
+    async fn falls_back_to_polling_after_repeated_ws_failures() {
+        let client = failing_client();
+        client.latest.store(3, Ordering::SeqCst);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut watcher = OriginWatcher::new(ws_config(), client, tx, 0);
+        assert_eq!(watcher.mode(), WatcherMode::Subscription);
+
+        // Each failed subscription attempt records a failure and backfills.
+        for _ in 0..3 {
+            watcher.run_subscription().await;
+        }
+        assert_eq!(watcher.mode(), WatcherMode::Polling);
+        assert_eq!(watcher.client.subscribe_attempts.load(Ordering::SeqCst), 3);
+
+        // The backfill polls delivered the deposits despite the dead socket.
+        let mut received = Vec::new();
+        while let Ok(deposit) = rx.try_recv() {
+            received.push(deposit.block_number);
+        }
+        assert_eq!(received, vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn polling_tracks_covered_blocks() {
+        let client = failing_client();
+        client.latest.store(2, Ordering::SeqCst);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut watcher = OriginWatcher::new(ws_config(), client, tx, 0);
+
+        assert!(!watcher.poll_once().await);
+        // A second poll at the same height must not re-emit deposits.
+        assert!(!watcher.poll_once().await);
+
+        let mut received = Vec::new();
+        while let Ok(deposit) = rx.try_recv() {
+            received.push(deposit.block_number);
+        }
+        assert_eq!(received, vec![1, 2]);
+    }
+
+    #[tokio::test]
+    async fn polling_honors_per_escrow_confirmation_depth() {
+        let shallow = Address::with_last_byte(1);
+        let deep = Address::with_last_byte(2);
+        let config = OriginWatcherConfig {
+            poll_interval: Duration::from_millis(1),
+            ..OriginWatcherConfig::new(
+                "http://localhost:8545".parse().unwrap(),
+                vec![
+                    EscrowConfig::new(shallow),
+                    EscrowConfig::new(deep).with_confirmations(2),
+                ],
+            )
+        };
+        let client = failing_client();
+        client.latest.store(5, Ordering::SeqCst);
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut watcher = OriginWatcher::new(config, client, tx, 0);
+
+        assert!(!watcher.poll_once().await);
+
+        let mut received: BTreeMap<Address, Vec<u64>> = BTreeMap::new();
+        while let Ok(deposit) = rx.try_recv() {
+            received
+                .entry(deposit.escrow)
+                .or_default()
+                .push(deposit.block_number);
+        }
+        // The shallow escrow follows the head, the deep one trails by 2 blocks.
+        assert_eq!(received[&shallow], vec![1, 2, 3, 4, 5]);
+        assert_eq!(received[&deep], vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn cap_drops_oversized_deposits() {
+        let escrow = Address::with_last_byte(1);
+        let config = OriginWatcherConfig::new(
+            "http://localhost:8545".parse().unwrap(),
+            vec![EscrowConfig::new(escrow).with_cap(U256::from(10u64))],
+        );
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut watcher = OriginWatcher::new(config, failing_client(), tx, 0);
+
+        let deposit = |amount: u64| Deposit {
+            escrow,
+            block_number: 1,
+            tx_hash: B256::with_last_byte(1),
+            log_index: 0,
+            from: Address::ZERO,
+            recipient: Address::ZERO,
+            amount: U256::from(amount),
+        };
+        assert!(!watcher.admit_and_send(deposit(5)));
+        assert!(!watcher.admit_and_send(deposit(50)));
+
+        assert_eq!(rx.try_recv().unwrap().amount, U256::from(5u64));
+        assert!(rx.try_recv().is_err(), "over-cap deposit must be dropped");
+    }
+
+    #[test]
+    fn deposit_id_includes_the_escrow_address() {
+        let deposit = |escrow: Address| Deposit {
+            escrow,
+            block_number: 1,
+            tx_hash: B256::with_last_byte(1),
+            log_index: 0,
+            from: Address::ZERO,
+            recipient: Address::ZERO,
+            amount: U256::from(1u64),
+        };
+        // Same log position on two escrows must produce distinct deposit ids.
+        assert_ne!(
+            deposit(Address::with_last_byte(1)).id(),
+            deposit(Address::with_last_byte(2)).id()
+        );
+    }
+}
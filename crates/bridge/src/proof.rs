@@ -0,0 +1,677 @@
+//! Offline verification of burn proofs.
+//!
+//! A burn proof ties a receipt to a finalized Tempo block: the RLP-encoded
+//! receipt, the Merkle-Patricia proof nodes from the block's receipt trie, the
+//! RLP-encoded header, and the finalization certificate for the block hash.
+//! Everything here is verifiable without network access; certificate signature
+//! verification lives in `bridge-cli`, which knows the consensus identity.
+
+use crate::receipt_envelope::{ReceiptEnvelopeError, decode_receipt_envelope};
+use alloy_primitives::{B256, Bytes, keccak256};
+use serde::{Deserialize, Serialize};
+
+/// A burn proof as exported by `bridge-cli export-proof` / the bridge RPC.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BurnProof {
+    /// RLP-encoded Tempo block header.
+    pub header_rlp: Bytes,
+    /// RLP-encoded receipt (typed receipt envelope).
+    pub receipt_rlp: Bytes,
+    /// Merkle-Patricia proof nodes from the receipts root to the receipt,
+    /// ordered root-first.
+    pub proof_nodes: Vec<Bytes>,
+    /// Index of the transaction within the block.
+    pub tx_index: u64,
+    /// Finalization certificate over the block hash.
+    pub finalization_certificate: Bytes,
+}
+
+/// A single passed verification step, for the `verify-proof` trace output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VerifyStep {
+    /// Short name of the check.
+    pub name: &'static str,
+    /// Human-readable detail (hashes, indices).
+    pub detail: String,
+}
+
+/// Burn proof verification failure.
+#[derive(Debug, thiserror::Error)]
+pub enum ProofError {
+    #[error("malformed RLP in {context}: {err}")]
+    Rlp {
+        context: &'static str,
+        err: alloy_rlp::Error,
+    },
+
+    #[error("proof node {index} hash mismatch: expected {expected}, got {actual}")]
+    NodeHashMismatch {
+        index: usize,
+        expected: B256,
+        actual: B256,
+    },
+
+    #[error("proof exhausted before reaching the receipt (consumed {consumed} nodes)")]
+    ProofExhausted { consumed: usize },
+
+    #[error("trie path diverges at node {index}: key not present")]
+    KeyNotPresent { index: usize },
+
+    #[error("proof resolves to a different receipt than the one provided")]
+    ReceiptMismatch,
+
+    #[error("trie node {index} has invalid structure: {reason}")]
+    InvalidNode { index: usize, reason: &'static str },
+
+    #[error("receipt envelope: {0}")]
+    Receipt(#[from] ReceiptEnvelopeError),
+}
+
+/// Verifies the burn proof offline and returns the step-by-step trace.
+///
+/// Checks, in order:
+/// 1. the header decodes and its hash is well-formed,
+/// 2. the receipt envelope decodes as a known transaction type — legacy or
+///    typed, including EIP-4844 blob-carrying transactions,
+/// 3. the receipt trie proof links `receipt_rlp` at `tx_index` to the
+///    header's `receiptsRoot`.
+///
+/// The finalization certificate is decoded and bound to the block hash by the
+/// caller (it requires the consensus identity to check the signature).
+pub fn verify_burn_proof(proof: &BurnProof) -> Result<Vec<VerifyStep>, ProofError> {
+    let mut trace = Vec::new();
+
+    // Step 1: header.
+    let block_hash = keccak256(&proof.header_rlp);
+    let receipts_root = extract_receipts_root(&proof.header_rlp)?;
+    trace.push(VerifyStep {
+        name: "header",
+        detail: format!("block hash {block_hash}, receiptsRoot {receipts_root}"),
+    });
+
+    // Step 2: receipt envelope. Burns emitted from within blob-carrying
+    // (EIP-4844) transactions are as provable as any other; what must be
+    // rejected is an envelope type the decoder does not understand.
+    let receipt = decode_receipt_envelope(&proof.receipt_rlp)?;
+    trace.push(VerifyStep {
+        name: "receipt envelope",
+        detail: format!(
+            "{} receipt, status {}, {} logs",
+            receipt.tx_type,
+            receipt.status,
+            receipt.logs.len()
+        ),
+    });
+
+    // Step 3: receipt inclusion. The trie key is the RLP-encoded tx index.
+    let key = alloy_rlp::encode(proof.tx_index);
+    verify_trie_inclusion(receipts_root, &key, &proof.receipt_rlp, &proof.proof_nodes)?;
+    trace.push(VerifyStep {
+        name: "receipt inclusion",
+        detail: format!(
+            "receipt at index {} proven against receiptsRoot with {} nodes",
+            proof.tx_index,
+            proof.proof_nodes.len()
+        ),
+    });
+
+    Ok(trace)
+}
+
+/// Extracts the `receiptsRoot` field from an RLP-encoded header.
+///
+/// The header layout is shared with Ethereum for the first fields:
+/// `[parentHash, ommersHash, beneficiary, stateRoot, transactionsRoot,
+/// receiptsRoot, ...]`, so the receipts root is the sixth item.
+fn extract_receipts_root(header_rlp: &[u8]) -> Result<B256, ProofError> {
+    let rlp = |err| ProofError::Rlp {
+        context: "header",
+        err,
+    };
+
+    let mut buf = header_rlp;
+    let header = alloy_rlp::Header::decode(&mut buf).map_err(rlp)?;
+    if !header.list {
+        return Err(rlp(alloy_rlp::Error::UnexpectedString));
+    }
+    let mut item = buf;
+    for _ in 0..5 {
+        let h = alloy_rlp::Header::decode(&mut item).map_err(rlp)?;
+        item = &item[h.payload_length..];
+    }
+    let h = alloy_rlp::Header::decode(&mut item).map_err(rlp)?;
+    if h.list || h.payload_length != 32 {
+        return Err(rlp(alloy_rlp::Error::UnexpectedLength));
+    }
+    Ok(B256::from_slice(&item[..32]))
+}
+
+/// Splits an RLP list node into its raw items. Each item is returned as
+/// `(full_encoding, payload, is_list)`.
+fn rlp_list_items(node: &[u8]) -> Result<Vec<(&[u8], &[u8], bool)>, alloy_rlp::Error> {
+    let mut buf = node;
+    let header = alloy_rlp::Header::decode(&mut buf)?;
+    if !header.list {
+        return Err(alloy_rlp::Error::UnexpectedString);
+    }
+    let mut items = Vec::new();
+    while !buf.is_empty() {
+        let full = buf;
+        let h = alloy_rlp::Header::decode(&mut buf)?;
+        if buf.len() < h.payload_length {
+            return Err(alloy_rlp::Error::InputTooShort);
+        }
+        let payload = &buf[..h.payload_length];
+        let consumed = full.len() - buf.len() + h.payload_length;
+        items.push((&full[..consumed], payload, h.list));
+        buf = &buf[h.payload_length..];
+    }
+    Ok(items)
+}
+
+/// Converts a byte key into nibbles.
+fn nibbles(key: &[u8]) -> Vec<u8> {
+    key.iter().flat_map(|b| [b >> 4, b & 0x0f]).collect()
+}
+
+/// Decodes a hex-prefix encoded path, returning `(nibbles, is_leaf)`.
+fn decode_hp_path(payload: &[u8]) -> Result<(Vec<u8>, bool), alloy_rlp::Error> {
+    let Some(&first) = payload.first() else {
+        return Err(alloy_rlp::Error::InputTooShort);
+    };
+    let is_leaf = first & 0x20 != 0;
+    let mut path = Vec::with_capacity(payload.len() * 2);
+    if first & 0x10 != 0 {
+        path.push(first & 0x0f);
+    }
+    path.extend(nibbles(&payload[1..]));
+    Ok((path, is_leaf))
+}
+
+/// Verifies a Merkle-Patricia inclusion proof of `value` at `key` under `root`.
+///
+/// `nodes` must be ordered root-first. Inline (sub-32-byte) child nodes are
+/// followed within their parent encoding without consuming a proof node.
+fn verify_trie_inclusion(
+    root: B256,
+    key: &[u8],
+    value: &[u8],
+    nodes: &[Bytes],
+) -> Result<(), ProofError> {
+    let mut expected = root;
+    let mut path = nibbles(key);
+    let mut path_offset = 0usize;
+    let mut node_iter = nodes.iter().enumerate();
+
+    loop {
+        let Some((index, node)) = node_iter.next() else {
+            return Err(ProofError::ProofExhausted {
+                consumed: nodes.len(),
+            });
+        };
+        let actual = keccak256(node);
+        if actual != expected {
+            return Err(ProofError::NodeHashMismatch {
+                index,
+                expected,
+                actual,
+            });
+        }
+
+        // Walk within this node, following inline children without consuming
+        // further proof nodes.
+        let mut current: &[u8] = node;
+        loop {
+            let items = rlp_list_items(current).map_err(|err| ProofError::Rlp {
+                context: "proof node",
+                err,
+            })?;
+            match items.len() {
+                17 => {
+                    if path_offset == path.len() {
+                        // Branch value slot holds the receipt.
+                        let (_, payload, _) = items[16];
+                        return if payload == value {
+                            Ok(())
+                        } else {
+                            Err(ProofError::ReceiptMismatch)
+                        };
+                    }
+                    let nibble = path[path_offset] as usize;
+                    path_offset += 1;
+                    let (full, payload, is_list) = items[nibble];
+                    if payload.is_empty() && !is_list {
+                        return Err(ProofError::KeyNotPresent { index });
+                    }
+                    if is_list {
+                        // Inline child node.
+                        current = full;
+                        continue;
+                    }
+                    if payload.len() != 32 {
+                        return Err(ProofError::InvalidNode {
+                            index,
+                            reason: "branch child is neither empty, a hash, nor inline",
+                        });
+                    }
+                    expected = B256::from_slice(payload);
+                    break;
+                }
+                2 => {
+                    let (_, path_payload, _) = items[0];
+                    let (child_full, child_payload, child_is_list) = items[1];
+                    let (node_path, is_leaf) =
+                        decode_hp_path(path_payload).map_err(|err| ProofError::Rlp {
+                            context: "node path",
+                            err,
+                        })?;
+                    if path.len() - path_offset < node_path.len()
+                        || path[path_offset..path_offset + node_path.len()] != node_path[..]
+                    {
+                        return Err(ProofError::KeyNotPresent { index });
+                    }
+                    path_offset += node_path.len();
+                    if is_leaf {
+                        if path_offset != path.len() {
+                            return Err(ProofError::KeyNotPresent { index });
+                        }
+                        return if child_payload == value {
+                            Ok(())
+                        } else {
+                            Err(ProofError::ReceiptMismatch)
+                        };
+                    }
+                    if child_is_list {
+                        current = child_full;
+                        continue;
+                    }
+                    if child_payload.len() != 32 {
+                        return Err(ProofError::InvalidNode {
+                            index,
+                            reason: "extension child is neither a hash nor inline",
+                        });
+                    }
+                    expected = B256::from_slice(child_payload);
+                    break;
+                }
+                _ => {
+                    return Err(ProofError::InvalidNode {
+                        index,
+                        reason: "expected a branch (17 items) or leaf/extension (2 items)",
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Builds the receipts trie over `receipts` (typed receipt envelopes, in
+/// transaction order) and returns `(receipts_root, proof_nodes)` for the
+/// receipt at `tx_index`, root-first, in the shape [`verify_trie_inclusion`]
+/// accepts. Returns `None` when `tx_index` is out of range.
+pub fn build_receipt_proof(receipts: &[Bytes], tx_index: u64) -> Option<(B256, Vec<Bytes>)> {
+    if tx_index as usize >= receipts.len() {
+        return None;
+    }
+
+    let mut entries: Vec<(Vec<u8>, &[u8])> = receipts
+        .iter()
+        .enumerate()
+        .map(|(index, receipt)| (nibbles(&alloy_rlp::encode(index as u64)), receipt.as_ref()))
+        .collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    let target = nibbles(&alloy_rlp::encode(tx_index));
+
+    let mut proof_rev = Vec::new();
+    let root_node = build_trie_node(&entries, 0, &target, true, &mut proof_rev);
+    let root = keccak256(&root_node);
+    proof_rev.reverse();
+    Some((root, proof_rev))
+}
+
+/// Hex-prefix encodes a nibble path for a leaf or extension node.
+fn hp_encode(path: &[u8], is_leaf: bool) -> Vec<u8> {
+    let flag = if is_leaf { 0x20 } else { 0x00 };
+    let mut out = Vec::with_capacity(path.len() / 2 + 1);
+    let rest = if path.len() % 2 == 1 {
+        out.push(flag | 0x10 | path[0]);
+        &path[1..]
+    } else {
+        out.push(flag);
+        path
+    };
+    for pair in rest.chunks(2) {
+        out.push((pair[0] << 4) | pair[1]);
+    }
+    out
+}
+
+/// Wraps already-encoded RLP items into a list.
+fn rlp_list(items: &[Vec<u8>]) -> Vec<u8> {
+    let payload_length = items.iter().map(Vec::len).sum();
+    let mut out = Vec::with_capacity(payload_length + 3);
+    alloy_rlp::Header {
+        list: true,
+        payload_length,
+    }
+    .encode(&mut out);
+    for item in items {
+        out.extend_from_slice(item);
+    }
+    out
+}
+
+/// References a child node inside its parent: inline when the encoding is
+/// shorter than 32 bytes, by hash otherwise.
+fn child_reference(encoding: &[u8]) -> Vec<u8> {
+    if encoding.len() < 32 {
+        encoding.to_vec()
+    } else {
+        alloy_rlp::encode(keccak256(encoding))
+    }
+}
+
+/// Recursively builds the trie node covering `entries` (sorted by path, all
+/// sharing their first `depth` nibbles), collecting hashed nodes along the
+/// `target` path into `proof_rev`, deepest-first. The root node is always
+/// collected, since verification hashes it against the root directly.
+fn build_trie_node(
+    entries: &[(Vec<u8>, &[u8])],
+    depth: usize,
+    target: &[u8],
+    on_path: bool,
+    proof_rev: &mut Vec<Bytes>,
+) -> Vec<u8> {
+    debug_assert!(!entries.is_empty());
+
+    let encoding = if entries.len() == 1 {
+        let (path, value) = &entries[0];
+        rlp_list(&[
+            alloy_rlp::encode(hp_encode(&path[depth..], true).as_slice()),
+            alloy_rlp::encode(*value),
+        ])
+    } else {
+        // Longest common prefix past `depth` across all entries.
+        let first = &entries[0].0;
+        let mut common = first.len() - depth;
+        for (path, _) in &entries[1..] {
+            let shared = path[depth..]
+                .iter()
+                .zip(&first[depth..])
+                .take_while(|(a, b)| a == b)
+                .count();
+            common = common.min(shared);
+        }
+
+        if common > 0 {
+            // Extension node over the shared prefix.
+            let prefix = &first[depth..depth + common];
+            let child_on_path = on_path
+                && target.len() >= depth + common
+                && target[depth..depth + common] == *prefix;
+            let child = build_trie_node(entries, depth + common, target, child_on_path, proof_rev);
+            rlp_list(&[
+                alloy_rlp::encode(hp_encode(prefix, false).as_slice()),
+                child_reference(&child),
+            ])
+        } else {
+            // Branch node; an entry terminating at this depth (sorted first)
+            // occupies the value slot.
+            let mut items: Vec<Vec<u8>> = vec![vec![alloy_rlp::EMPTY_STRING_CODE]; 17];
+            let mut rest = entries;
+            if rest[0].0.len() == depth {
+                items[16] = alloy_rlp::encode(rest[0].1);
+                rest = &rest[1..];
+            }
+            while !rest.is_empty() {
+                let nibble = rest[0].0[depth];
+                let count = rest
+                    .iter()
+                    .take_while(|(path, _)| path[depth] == nibble)
+                    .count();
+                let child_on_path = on_path && target.get(depth) == Some(&nibble);
+                let child =
+                    build_trie_node(&rest[..count], depth + 1, target, child_on_path, proof_rev);
+                items[nibble as usize] = child_reference(&child);
+                rest = &rest[count..];
+            }
+            rlp_list(&items)
+        }
+    };
+
+    if on_path && (depth == 0 || encoding.len() >= 32) {
+        proof_rev.push(encoding.clone().into());
+    }
+    encoding
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_rlp::Encodable;
+
+    /// Builds a single-leaf trie containing `value` at `key` and returns
+    /// `(root, proof_nodes)`.
+    fn single_leaf_trie(key: &[u8], value: &[u8]) -> (B256, Vec<Bytes>) {
+        // Leaf node: [hp(path, leaf), value]
+        let path = nibbles(key);
+        let mut hp = Vec::new();
+        if path.len() % 2 == 0 {
+            hp.push(0x20);
+        } else {
+            hp.push(0x30 | path[0]);
+        }
+        let rest = if path.len() % 2 == 0 {
+            &path[..]
+        } else {
+            &path[1..]
+        };
+        for pair in rest.chunks(2) {
+            hp.push((pair[0] << 4) | pair[1]);
+        }
+
+        let mut node = Vec::new();
+        let payload_len = {
+            let mut tmp = Vec::new();
+            hp.as_slice().encode(&mut tmp);
+            value.encode(&mut tmp);
+            tmp.len()
+        };
+        alloy_rlp::Header {
+            list: true,
+            payload_length: payload_len,
+        }
+        .encode(&mut node);
+        hp.as_slice().encode(&mut node);
+        value.encode(&mut node);
+
+        (keccak256(&node), vec![node.into()])
+    }
+
+    #[test]
+    fn verifies_single_leaf_inclusion() {
+        let key = alloy_rlp::encode(0u64);
+        let value = b"receipt payload".as_slice();
+        let (root, proof) = single_leaf_trie(&key, value);
+        verify_trie_inclusion(root, &key, value, &proof).unwrap();
+    }
+
+    #[test]
+    fn rejects_wrong_value() {
+        let key = alloy_rlp::encode(0u64);
+        let (root, proof) = single_leaf_trie(&key, b"receipt payload");
+        let err = verify_trie_inclusion(root, &key, b"other payload", &proof).unwrap_err();
+        assert!(matches!(err, ProofError::ReceiptMismatch));
+    }
+
+    #[test]
+    fn rejects_tampered_node() {
+        let key = alloy_rlp::encode(0u64);
+        let value = b"receipt payload".as_slice();
+        let (root, mut proof) = single_leaf_trie(&key, value);
+        let mut tampered = proof[0].to_vec();
+        *tampered.last_mut().unwrap() ^= 0x01;
+        proof[0] = tampered.into();
+        let err = verify_trie_inclusion(root, &key, value, &proof).unwrap_err();
+        assert!(matches!(err, ProofError::NodeHashMismatch { .. }));
+    }
+
+    #[test]
+    fn rejects_missing_key() {
+        let key = alloy_rlp::encode(0u64);
+        let other_key = alloy_rlp::encode(1u64);
+        let value = b"receipt payload".as_slice();
+        let (root, proof) = single_leaf_trie(&key, value);
+        let err = verify_trie_inclusion(root, &other_key, value, &proof).unwrap_err();
+        assert!(matches!(err, ProofError::KeyNotPresent { .. }));
+    }
+
+    /// Synthetic typed receipt envelopes of varying size, so the trie
+    /// exercises inline and hashed nodes.
+    fn dummy_receipts(count: usize) -> Vec<Bytes> {
+        (0..count)
+            .map(|index| {
+                let mut receipt = vec![0x76];
+                receipt.extend_from_slice(format!("receipt {index} ").repeat(3).as_bytes());
+                Bytes::from(receipt)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn built_proofs_verify_for_every_index() {
+        for count in [1usize, 2, 5, 17, 100] {
+            let receipts = dummy_receipts(count);
+            let mut roots = Vec::new();
+            for index in 0..count as u64 {
+                let (root, proof) = build_receipt_proof(&receipts, index).unwrap();
+                let key = alloy_rlp::encode(index);
+                verify_trie_inclusion(root, &key, &receipts[index as usize], &proof)
+                    .unwrap_or_else(|err| panic!("index {index} of {count}: {err}"));
+                roots.push(root);
+            }
+            assert!(
+                roots.windows(2).all(|pair| pair[0] == pair[1]),
+                "all proofs for {count} receipts must share one root"
+            );
+        }
+    }
+
+    #[test]
+    fn built_proof_rejects_other_receipt() {
+        let receipts = dummy_receipts(4);
+        let (root, proof) = build_receipt_proof(&receipts, 1).unwrap();
+        let key = alloy_rlp::encode(1u64);
+        let err = verify_trie_inclusion(root, &key, &receipts[2], &proof).unwrap_err();
+        assert!(matches!(err, ProofError::ReceiptMismatch));
+    }
+
+    #[test]
+    fn build_rejects_out_of_range_index() {
+        assert!(build_receipt_proof(&dummy_receipts(3), 3).is_none());
+        assert!(build_receipt_proof(&[], 0).is_none());
+    }
+
+    /// A minimal receipt envelope of the given type: empty logs, success
+    /// status. Type 0 produces the bare legacy list.
+    fn receipt_envelope(tx_type: u8) -> Bytes {
+        let mut payload = Vec::new();
+        1u8.encode(&mut payload);
+        21_000u64.encode(&mut payload);
+        [0u8; 256].as_slice().encode(&mut payload);
+        alloy_rlp::Header {
+            list: true,
+            payload_length: 0,
+        }
+        .encode(&mut payload);
+
+        let mut envelope = Vec::new();
+        if tx_type != 0 {
+            envelope.push(tx_type);
+        }
+        alloy_rlp::Header {
+            list: true,
+            payload_length: payload.len(),
+        }
+        .encode(&mut envelope);
+        envelope.extend_from_slice(&payload);
+        envelope.into()
+    }
+
+    /// A minimal header: six 32-byte fields with `receipts_root` sixth, the
+    /// layout [`extract_receipts_root`] expects.
+    fn header_with_receipts_root(receipts_root: B256) -> Bytes {
+        let mut payload = Vec::new();
+        for _ in 0..5 {
+            B256::ZERO.encode(&mut payload);
+        }
+        receipts_root.encode(&mut payload);
+
+        let mut header = Vec::new();
+        alloy_rlp::Header {
+            list: true,
+            payload_length: payload.len(),
+        }
+        .encode(&mut header);
+        header.extend_from_slice(&payload);
+        header.into()
+    }
+
+    #[test]
+    fn verifies_proofs_for_every_envelope_type() {
+        // One receipt per transaction type, legacy through EIP-4844 and
+        // EIP-7702, in one block.
+        let receipts: Vec<Bytes> = [0u8, 1, 2, 3, 4].map(receipt_envelope).to_vec();
+        for tx_index in 0..receipts.len() as u64 {
+            let (root, proof_nodes) = build_receipt_proof(&receipts, tx_index).unwrap();
+            let proof = BurnProof {
+                header_rlp: header_with_receipts_root(root),
+                receipt_rlp: receipts[tx_index as usize].clone(),
+                proof_nodes,
+                tx_index,
+                finalization_certificate: Bytes::new(),
+            };
+            verify_burn_proof(&proof).unwrap_or_else(|err| panic!("type {tx_index}: {err}"));
+        }
+    }
+
+    #[test]
+    fn traces_the_blob_carrying_receipt_type() {
+        let receipts = vec![receipt_envelope(3)];
+        let (root, proof_nodes) = build_receipt_proof(&receipts, 0).unwrap();
+        let proof = BurnProof {
+            header_rlp: header_with_receipts_root(root),
+            receipt_rlp: receipts[0].clone(),
+            proof_nodes,
+            tx_index: 0,
+            finalization_certificate: Bytes::new(),
+        };
+
+        let trace = verify_burn_proof(&proof).unwrap();
+        let envelope_step = trace
+            .iter()
+            .find(|step| step.name == "receipt envelope")
+            .unwrap();
+        assert!(envelope_step.detail.contains("eip-4844"));
+    }
+
+    #[test]
+    fn rejects_unknown_envelope_type() {
+        let receipts = vec![receipt_envelope(5)];
+        let (root, proof_nodes) = build_receipt_proof(&receipts, 0).unwrap();
+        let proof = BurnProof {
+            header_rlp: header_with_receipts_root(root),
+            receipt_rlp: receipts[0].clone(),
+            proof_nodes,
+            tx_index: 0,
+            finalization_certificate: Bytes::new(),
+        };
+
+        let err = verify_burn_proof(&proof).unwrap_err();
+        assert!(matches!(
+            err,
+            ProofError::Receipt(ReceiptEnvelopeError::UnknownType(0x05))
+        ));
+    }
+}
@@ -0,0 +1,281 @@
+//! Protocol fees on bridge mints and unlocks.
+//!
+//! Governance may take a cut of every bridged amount, expressed in basis
+//! points and routed to a treasury address. The authoritative parameters live
+//! on-chain next to the mint verifier; the sidecar mirrors them through
+//! [`ProtocolFeeConfig`] and performs the identical integer split, because the
+//! deposit digest validators sign commits to the exact net amount the
+//! recipient is minted and the exact fee the treasury receives — a sidecar
+//! whose mirror drifts from the on-chain parameters produces signatures the
+//! verifier rejects rather than mints with the wrong fee.
+//!
+//! Every assessed fee is journaled as a [`ProtocolFeeRecord`] (one JSON record
+//! per line, same on-disk shape as the unlock journal) so treasury routing can
+//! be reconciled against on-chain balances after the fact.
+
+use alloy_primitives::{Address, B256, U256};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::{File, OpenOptions},
+    io::{BufRead as _, BufReader, Seek as _, SeekFrom, Write as _},
+    path::Path,
+};
+
+/// Basis point denominator: 10_000 bps = 100%.
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Hard cap on the protocol fee governance may configure (10%).
+///
+/// Mirrored from the on-chain bound; a mirror claiming a larger fee is a
+/// misconfiguration, not a governance decision.
+pub const MAX_PROTOCOL_FEE_BPS: u64 = 1_000;
+
+/// Errors raised validating a protocol fee configuration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ProtocolFeeError {
+    /// The configured fee exceeds [`MAX_PROTOCOL_FEE_BPS`].
+    #[error("protocol fee {bps} bps exceeds the {MAX_PROTOCOL_FEE_BPS} bps cap")]
+    FeeTooHigh {
+        /// Configured fee in basis points.
+        bps: u64,
+    },
+    /// A non-zero fee is configured without a treasury to route it to.
+    #[error("protocol fee is enabled but no treasury address is configured")]
+    MissingTreasury,
+}
+
+/// Mirror of the on-chain protocol fee parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProtocolFeeConfig {
+    /// Fee in basis points taken from every bridged amount.
+    pub fee_bps: u64,
+    /// Address the fee portion is minted (or unlocked) to.
+    pub treasury: Address,
+}
+
+impl Default for ProtocolFeeConfig {
+    fn default() -> Self {
+        Self {
+            fee_bps: 0,
+            treasury: Address::ZERO,
+        }
+    }
+}
+
+impl ProtocolFeeConfig {
+    /// Returns true if a fee is actually taken.
+    pub fn is_enabled(&self) -> bool {
+        self.fee_bps > 0
+    }
+
+    /// Validates the mirrored parameters against the on-chain bounds.
+    pub fn validate(&self) -> Result<(), ProtocolFeeError> {
+        if self.fee_bps > MAX_PROTOCOL_FEE_BPS {
+            return Err(ProtocolFeeError::FeeTooHigh { bps: self.fee_bps });
+        }
+        if self.is_enabled() && self.treasury.is_zero() {
+            return Err(ProtocolFeeError::MissingTreasury);
+        }
+        Ok(())
+    }
+
+    /// Splits a gross bridged amount into the treasury fee and the net amount
+    /// the recipient receives.
+    ///
+    /// The fee is floored (`gross * bps / 10_000` in integer arithmetic), so
+    /// rounding dust always favors the recipient — the same rule the on-chain
+    /// verifier applies.
+    pub fn split(&self, gross: U256) -> FeeSplit {
+        let fee = gross * U256::from(self.fee_bps) / U256::from(BPS_DENOMINATOR);
+        FeeSplit {
+            gross,
+            fee,
+            net: gross - fee,
+        }
+    }
+}
+
+/// A gross bridged amount split into its fee and net portions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeSplit {
+    /// Amount deposited (or burned) on the origin side.
+    pub gross: U256,
+    /// Portion routed to the treasury.
+    pub fee: U256,
+    /// Portion the recipient is minted (or unlocked).
+    pub net: U256,
+}
+
+/// One assessed protocol fee, journaled when the digest is produced.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProtocolFeeRecord {
+    /// Deposit (or burn) the fee was assessed on.
+    pub deposit_id: B256,
+    /// Gross bridged amount.
+    pub gross: U256,
+    /// Fee routed to the treasury.
+    pub fee: U256,
+    /// Net amount signed for the recipient.
+    pub net: U256,
+    /// Treasury the fee portion goes to.
+    pub treasury: Address,
+    /// Unix timestamp (seconds) the fee was assessed at.
+    pub timestamp: u64,
+}
+
+/// Errors raised while reading or writing the protocol fee journal.
+#[derive(Debug, thiserror::Error)]
+pub enum ProtocolFeeJournalError {
+    /// The journal file could not be read or written.
+    #[error("protocol fee journal io error: {0}")]
+    Io(#[from] std::io::Error),
+    /// A line is not a valid JSON record.
+    #[error("malformed record on line {line}: {err}")]
+    MalformedRecord {
+        /// One-based line number.
+        line: usize,
+        /// Underlying JSON error.
+        err: serde_json::Error,
+    },
+}
+
+/// Append-only journal of assessed protocol fees.
+#[derive(Debug)]
+pub struct ProtocolFeeJournal {
+    file: File,
+    records: Vec<ProtocolFeeRecord>,
+}
+
+impl ProtocolFeeJournal {
+    /// Opens (or creates) the journal at `path` and replays its records.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, ProtocolFeeJournalError> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(path)?;
+
+        let mut records = Vec::new();
+        for (idx, line) in BufReader::new(&mut file).lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record: ProtocolFeeRecord = serde_json::from_str(&line)
+                .map_err(|err| ProtocolFeeJournalError::MalformedRecord { line: idx + 1, err })?;
+            records.push(record);
+        }
+        file.seek(SeekFrom::End(0))?;
+
+        Ok(Self { file, records })
+    }
+
+    /// Records an assessed fee and flushes it to disk before returning.
+    pub fn record(&mut self, record: ProtocolFeeRecord) -> Result<(), ProtocolFeeJournalError> {
+        let mut line = serde_json::to_string(&record).expect("protocol fee record serializes");
+        line.push('\n');
+        self.file.write_all(line.as_bytes())?;
+        self.file.sync_data()?;
+        self.records.push(record);
+        Ok(())
+    }
+
+    /// All fees journaled so far, in append order.
+    pub fn records(&self) -> &[ProtocolFeeRecord] {
+        &self.records
+    }
+
+    /// Sum of all fees journaled for `treasury`, for reconciliation against
+    /// the on-chain treasury balance.
+    pub fn total_routed_to(&self, treasury: Address) -> U256 {
+        self.records
+            .iter()
+            .filter(|record| record.treasury == treasury)
+            .fold(U256::ZERO, |acc, record| acc.saturating_add(record.fee))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(bps: u64) -> ProtocolFeeConfig {
+        ProtocolFeeConfig {
+            fee_bps: bps,
+            treasury: Address::with_last_byte(0xee),
+        }
+    }
+
+    #[test]
+    fn split_floors_fee_in_favor_of_recipient() {
+        // 25 bps of 40_001 = 100.0025, floored to 100.
+        let split = config(25).split(U256::from(40_001u64));
+        assert_eq!(split.fee, U256::from(100u64));
+        assert_eq!(split.net, U256::from(39_901u64));
+        assert_eq!(split.gross, split.fee + split.net);
+    }
+
+    #[test]
+    fn disabled_config_takes_no_fee() {
+        let config = ProtocolFeeConfig::default();
+        assert!(!config.is_enabled());
+        assert_eq!(config.validate(), Ok(()));
+
+        let split = config.split(U256::from(1_000_000u64));
+        assert_eq!(split.fee, U256::ZERO);
+        assert_eq!(split.net, split.gross);
+    }
+
+    #[test]
+    fn validate_rejects_oversized_fee_and_missing_treasury() {
+        assert_eq!(
+            config(MAX_PROTOCOL_FEE_BPS + 1).validate(),
+            Err(ProtocolFeeError::FeeTooHigh {
+                bps: MAX_PROTOCOL_FEE_BPS + 1
+            })
+        );
+        assert_eq!(config(MAX_PROTOCOL_FEE_BPS).validate(), Ok(()));
+
+        let no_treasury = ProtocolFeeConfig {
+            fee_bps: 10,
+            treasury: Address::ZERO,
+        };
+        assert_eq!(
+            no_treasury.validate(),
+            Err(ProtocolFeeError::MissingTreasury)
+        );
+    }
+
+    #[test]
+    fn journal_round_trips_and_sums_per_treasury() {
+        let dir = std::env::temp_dir().join(format!("protocol-fee-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("fees.jsonl");
+        let _ = std::fs::remove_file(&path);
+
+        let treasury = Address::with_last_byte(0xee);
+        let other = Address::with_last_byte(0xdd);
+        let record = |id: u8, fee: u64, treasury| ProtocolFeeRecord {
+            deposit_id: B256::with_last_byte(id),
+            gross: U256::from(10_000u64),
+            fee: U256::from(fee),
+            net: U256::from(10_000 - fee),
+            treasury,
+            timestamp: 1_000 + id as u64,
+        };
+
+        let mut journal = ProtocolFeeJournal::open(&path).unwrap();
+        journal.record(record(1, 25, treasury)).unwrap();
+        journal.record(record(2, 25, treasury)).unwrap();
+        journal.record(record(3, 40, other)).unwrap();
+        drop(journal);
+
+        let journal = ProtocolFeeJournal::open(&path).unwrap();
+        assert_eq!(journal.records().len(), 3);
+        assert_eq!(journal.total_routed_to(treasury), U256::from(50u64));
+        assert_eq!(journal.total_routed_to(other), U256::from(40u64));
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
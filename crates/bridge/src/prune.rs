@@ -0,0 +1,132 @@
+//! Periodic compaction of the sidecar's deposit state.
+//!
+//! [`StateManager`] records keep accumulating as deposits complete, so a
+//! long-running sidecar would grow its state file (and every load/save of it)
+//! without bound. The compaction task periodically applies the retention
+//! policy — completed records older than `retention_blocks` are dropped,
+//! pending and expired records are always kept — and rewrites the state file
+//! whenever anything was pruned. The same policy is reachable offline through
+//! `bridge-cli prune`.
+
+use crate::deposit_expiry::StateManager;
+use std::{path::Path, sync::Arc, time::Duration};
+use tokio::sync::RwLock;
+
+/// Default interval between compaction runs.
+pub const DEFAULT_COMPACTION_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// Retention policy and cadence for the compaction task.
+#[derive(Debug, Clone, Copy)]
+pub struct PruneConfig {
+    /// Origin blocks a completed deposit record is retained before pruning.
+    pub retention_blocks: u64,
+    /// How often the compaction task runs.
+    pub interval: Duration,
+}
+
+impl Default for PruneConfig {
+    fn default() -> Self {
+        Self {
+            retention_blocks: crate::deposit_expiry::DEFAULT_RETENTION_BLOCKS,
+            interval: DEFAULT_COMPACTION_INTERVAL,
+        }
+    }
+}
+
+/// Applies the retention policy once and persists the state file if anything
+/// was pruned. Returns the number of pruned records.
+pub fn compact_once(
+    manager: &mut StateManager,
+    state_file: &Path,
+    block: u64,
+    retention_blocks: u64,
+) -> eyre::Result<u64> {
+    let pruned = manager.prune(block, retention_blocks);
+    if pruned > 0 {
+        manager.save(state_file)?;
+        tracing::info!(
+            pruned,
+            pruned_total = manager.pruned_total(),
+            tracked = manager.len(),
+            "pruned finalized deposit records",
+        );
+    }
+    Ok(pruned)
+}
+
+/// Runs compaction forever at the configured interval.
+///
+/// `latest_block` supplies the current origin block height each run; the
+/// retention window is measured against it. Compaction failures are logged
+/// and retried on the next tick rather than tearing the task down — a
+/// transient write error must not stop deposit tracking.
+pub async fn run_compaction<F>(
+    manager: Arc<RwLock<StateManager>>,
+    state_file: impl AsRef<Path>,
+    config: PruneConfig,
+    latest_block: F,
+) where
+    F: Fn() -> u64 + Send,
+{
+    let state_file = state_file.as_ref();
+    let mut interval = tokio::time::interval(config.interval);
+    // The first tick fires immediately; skip it so a freshly started sidecar
+    // does not compact before it has observed anything.
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        let block = latest_block();
+        let mut manager = manager.write().await;
+        if let Err(err) = compact_once(&mut manager, state_file, block, config.retention_blocks) {
+            tracing::warn!(%err, "deposit state compaction failed; retrying next interval");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Address, B256};
+
+    fn temp_state_file(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("tempo-bridge-prune-{}-{name}", std::process::id()))
+    }
+
+    fn manager_with_completed_deposit(completed_at: u64) -> StateManager {
+        let mut manager = StateManager::new(100, 1);
+        let id = B256::with_last_byte(1);
+        manager.record_deposit(id, completed_at, 7);
+        manager
+            .record_signature(id, Address::repeat_byte(1), completed_at)
+            .unwrap();
+        manager
+    }
+
+    #[test]
+    fn compact_once_persists_only_when_something_was_pruned() {
+        let path = temp_state_file("persist");
+        let mut manager = manager_with_completed_deposit(10);
+
+        // Nothing is old enough yet: the state file must not be touched.
+        assert_eq!(compact_once(&mut manager, &path, 50, 100).unwrap(), 0);
+        assert!(!path.exists());
+
+        // The record ages out: pruned and persisted.
+        assert_eq!(compact_once(&mut manager, &path, 200, 100).unwrap(), 1);
+        let reloaded = StateManager::load(&path).unwrap();
+        assert!(reloaded.is_empty());
+        assert_eq!(reloaded.pruned_total(), 1);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn default_config_uses_the_documented_retention() {
+        let config = PruneConfig::default();
+        assert_eq!(
+            config.retention_blocks,
+            crate::deposit_expiry::DEFAULT_RETENTION_BLOCKS
+        );
+        assert_eq!(config.interval, DEFAULT_COMPACTION_INTERVAL);
+    }
+}
@@ -0,0 +1,359 @@
+//! Decoding of typed transaction receipt envelopes from origin chains.
+//!
+//! Origin chains serve receipts as typed envelopes (EIP-2718): a one-byte
+//! transaction type followed by the RLP receipt payload, or a bare RLP list
+//! for legacy transactions. Escrow deposits emitted from within blob-carrying
+//! EIP-4844 transactions (type 3) produce perfectly ordinary logs, but a
+//! decoder that only recognizes legacy and EIP-1559 envelopes would drop them
+//! — and with them the deposit. This module decodes every envelope type the
+//! current origins produce so the watcher's receipt backfill and the burn
+//! proof verifier treat blob-carrying transactions like any other.
+
+use crate::origin_adapter::RawOriginLog;
+use alloy_primitives::{Address, B256, Bytes};
+use alloy_rlp::Decodable as _;
+
+/// Transaction type of a receipt envelope (EIP-2718).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OriginTxType {
+    /// Pre-EIP-2718 transaction, encoded as a bare RLP list.
+    Legacy,
+    /// EIP-2930 access-list transaction (type 1).
+    Eip2930,
+    /// EIP-1559 dynamic-fee transaction (type 2).
+    Eip1559,
+    /// EIP-4844 blob-carrying transaction (type 3).
+    Eip4844,
+    /// EIP-7702 set-code transaction (type 4).
+    Eip7702,
+}
+
+impl OriginTxType {
+    /// Maps an envelope type byte to its transaction type. Legacy receipts
+    /// have no type byte and never reach this.
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            0x01 => Some(Self::Eip2930),
+            0x02 => Some(Self::Eip1559),
+            0x03 => Some(Self::Eip4844),
+            0x04 => Some(Self::Eip7702),
+            _ => None,
+        }
+    }
+
+    /// Returns true for blob-carrying (EIP-4844) transactions.
+    pub fn is_blob_carrying(&self) -> bool {
+        matches!(self, Self::Eip4844)
+    }
+}
+
+impl std::fmt::Display for OriginTxType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Self::Legacy => "legacy",
+            Self::Eip2930 => "eip-2930",
+            Self::Eip1559 => "eip-1559",
+            Self::Eip4844 => "eip-4844",
+            Self::Eip7702 => "eip-7702",
+        };
+        f.write_str(name)
+    }
+}
+
+/// Receipt envelope decoding failure.
+#[derive(Debug, thiserror::Error)]
+pub enum ReceiptEnvelopeError {
+    /// The envelope is empty.
+    #[error("empty receipt envelope")]
+    Empty,
+
+    /// The envelope carries a transaction type this decoder does not know.
+    #[error("unknown receipt envelope type {0:#04x}")]
+    UnknownType(u8),
+
+    /// The receipt payload is not valid RLP.
+    #[error("malformed RLP in {context}: {err}")]
+    Rlp {
+        context: &'static str,
+        err: alloy_rlp::Error,
+    },
+}
+
+/// One log from a decoded receipt: `[address, topics, data]`.
+///
+/// Block context (number, transaction hash, log index) lives on the receipt's
+/// surroundings, not in the receipt itself — [`DecodedReceipt::raw_logs`]
+/// attaches it when handing logs to an [`crate::origin_adapter`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReceiptLog {
+    /// Contract that emitted the log.
+    pub address: Address,
+    /// Indexed topics, including the event signature topic.
+    pub topics: Vec<B256>,
+    /// Non-indexed event data.
+    pub data: Bytes,
+}
+
+/// A decoded receipt, independent of which envelope type carried it.
+///
+/// All envelope types share the payload layout
+/// `[status, cumulativeGasUsed, logsBloom, logs]` — EIP-4844 changed the
+/// transaction, not the receipt — so once the type byte is stripped the
+/// decoding is uniform.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedReceipt {
+    /// Transaction type taken from the envelope prefix.
+    pub tx_type: OriginTxType,
+    /// Post-execution status: true on success.
+    pub status: bool,
+    /// Cumulative gas used in the block up to and including this transaction.
+    pub cumulative_gas_used: u64,
+    /// Logs emitted by the transaction, in emission order.
+    pub logs: Vec<ReceiptLog>,
+}
+
+impl DecodedReceipt {
+    /// Attaches block context to the receipt's logs, producing
+    /// [`RawOriginLog`]s an adapter can decode deposits from. Log indices are
+    /// assigned sequentially from `first_log_index`, the block-wide index of
+    /// this receipt's first log.
+    pub fn raw_logs(
+        &self,
+        block_number: u64,
+        tx_hash: B256,
+        first_log_index: u64,
+    ) -> Vec<RawOriginLog> {
+        self.logs
+            .iter()
+            .enumerate()
+            .map(|(offset, log)| RawOriginLog {
+                address: log.address,
+                topics: log.topics.clone(),
+                data: log.data.clone(),
+                block_number,
+                tx_hash,
+                log_index: first_log_index + offset as u64,
+            })
+            .collect()
+    }
+}
+
+/// Decodes a receipt envelope: a bare RLP list for legacy receipts, or a
+/// type byte in `0x01..=0x04` followed by the RLP payload for typed receipts
+/// — including the `0x03` EIP-4844 envelope blob-carrying transactions
+/// produce.
+pub fn decode_receipt_envelope(envelope: &[u8]) -> Result<DecodedReceipt, ReceiptEnvelopeError> {
+    let Some(&first) = envelope.first() else {
+        return Err(ReceiptEnvelopeError::Empty);
+    };
+
+    let (tx_type, payload) = if first >= 0xc0 {
+        // Legacy receipts are an RLP list with no envelope prefix.
+        (OriginTxType::Legacy, envelope)
+    } else {
+        let tx_type =
+            OriginTxType::from_byte(first).ok_or(ReceiptEnvelopeError::UnknownType(first))?;
+        (tx_type, &envelope[1..])
+    };
+
+    let rlp = |context| move |err| ReceiptEnvelopeError::Rlp { context, err };
+
+    let mut buf = payload;
+    let header = alloy_rlp::Header::decode(&mut buf).map_err(rlp("receipt"))?;
+    if !header.list {
+        return Err(rlp("receipt")(alloy_rlp::Error::UnexpectedString));
+    }
+
+    let status = u8::decode(&mut buf).map_err(rlp("status"))?;
+    let cumulative_gas_used = u64::decode(&mut buf).map_err(rlp("cumulativeGasUsed"))?;
+    let _bloom = alloy_rlp::Header::decode_bytes(&mut buf, false).map_err(rlp("logsBloom"))?;
+
+    let mut logs_buf = alloy_rlp::Header::decode_bytes(&mut buf, true).map_err(rlp("logs"))?;
+    let mut logs = Vec::new();
+    while !logs_buf.is_empty() {
+        logs.push(decode_log(&mut logs_buf)?);
+    }
+
+    Ok(DecodedReceipt {
+        tx_type,
+        status: status != 0,
+        cumulative_gas_used,
+        logs,
+    })
+}
+
+/// Decodes one `[address, topics, data]` log entry.
+fn decode_log(buf: &mut &[u8]) -> Result<ReceiptLog, ReceiptEnvelopeError> {
+    let rlp = |context| move |err| ReceiptEnvelopeError::Rlp { context, err };
+
+    let mut log_buf = alloy_rlp::Header::decode_bytes(buf, true).map_err(rlp("log"))?;
+    let address = Address::decode(&mut log_buf).map_err(rlp("log address"))?;
+
+    let mut topics_buf =
+        alloy_rlp::Header::decode_bytes(&mut log_buf, true).map_err(rlp("log topics"))?;
+    let mut topics = Vec::new();
+    while !topics_buf.is_empty() {
+        topics.push(B256::decode(&mut topics_buf).map_err(rlp("log topic"))?);
+    }
+
+    let data = Bytes::decode(&mut log_buf).map_err(rlp("log data"))?;
+    Ok(ReceiptLog {
+        address,
+        topics,
+        data,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::origin_adapter::{EvmOriginAdapter, OriginChainAdapter as _};
+    use alloy_primitives::U256;
+    use alloy_rlp::Encodable as _;
+
+    /// Encodes a receipt envelope fixture: `tx_type` of 0 produces a bare
+    /// legacy RLP list, anything else the EIP-2718 prefixed form.
+    fn receipt_fixture(tx_type: u8, status: bool, gas: u64, logs: &[ReceiptLog]) -> Bytes {
+        let mut logs_payload = Vec::new();
+        for log in logs {
+            let mut topics_payload = Vec::new();
+            for topic in &log.topics {
+                topic.encode(&mut topics_payload);
+            }
+            let mut log_payload = Vec::new();
+            log.address.encode(&mut log_payload);
+            alloy_rlp::Header {
+                list: true,
+                payload_length: topics_payload.len(),
+            }
+            .encode(&mut log_payload);
+            log_payload.extend_from_slice(&topics_payload);
+            log.data.encode(&mut log_payload);
+
+            alloy_rlp::Header {
+                list: true,
+                payload_length: log_payload.len(),
+            }
+            .encode(&mut logs_payload);
+            logs_payload.extend_from_slice(&log_payload);
+        }
+
+        let mut payload = Vec::new();
+        (status as u8).encode(&mut payload);
+        gas.encode(&mut payload);
+        [0u8; 256].as_slice().encode(&mut payload);
+        alloy_rlp::Header {
+            list: true,
+            payload_length: logs_payload.len(),
+        }
+        .encode(&mut payload);
+        payload.extend_from_slice(&logs_payload);
+
+        let mut envelope = Vec::new();
+        if tx_type != 0 {
+            envelope.push(tx_type);
+        }
+        alloy_rlp::Header {
+            list: true,
+            payload_length: payload.len(),
+        }
+        .encode(&mut envelope);
+        envelope.extend_from_slice(&payload);
+        envelope.into()
+    }
+
+    /// A deposit event log as the EVM escrow emits it.
+    fn deposit_log() -> ReceiptLog {
+        ReceiptLog {
+            address: Address::with_last_byte(1),
+            topics: vec![
+                EvmOriginAdapter::deposit_topic(),
+                Address::with_last_byte(2).into_word(),
+                Address::with_last_byte(3).into_word(),
+            ],
+            data: U256::from(1_000u64).to_be_bytes::<32>().into(),
+        }
+    }
+
+    #[test]
+    fn decodes_every_envelope_type() {
+        for (byte, expected) in [
+            (0x00, OriginTxType::Legacy),
+            (0x01, OriginTxType::Eip2930),
+            (0x02, OriginTxType::Eip1559),
+            (0x03, OriginTxType::Eip4844),
+            (0x04, OriginTxType::Eip7702),
+        ] {
+            let envelope = receipt_fixture(byte, true, 21_000, &[deposit_log()]);
+            let receipt = decode_receipt_envelope(&envelope)
+                .unwrap_or_else(|err| panic!("type {byte:#04x}: {err}"));
+            assert_eq!(receipt.tx_type, expected);
+            assert!(receipt.status);
+            assert_eq!(receipt.cumulative_gas_used, 21_000);
+            assert_eq!(receipt.logs, vec![deposit_log()]);
+        }
+    }
+
+    #[test]
+    fn rejects_unknown_and_empty_envelopes() {
+        let envelope = receipt_fixture(0x05, true, 21_000, &[]);
+        assert!(matches!(
+            decode_receipt_envelope(&envelope).unwrap_err(),
+            ReceiptEnvelopeError::UnknownType(0x05)
+        ));
+        assert!(matches!(
+            decode_receipt_envelope(&[]).unwrap_err(),
+            ReceiptEnvelopeError::Empty
+        ));
+    }
+
+    #[test]
+    fn rejects_truncated_payload() {
+        let envelope = receipt_fixture(0x03, true, 21_000, &[deposit_log()]);
+        let err = decode_receipt_envelope(&envelope[..envelope.len() / 2]).unwrap_err();
+        assert!(matches!(err, ReceiptEnvelopeError::Rlp { .. }));
+    }
+
+    #[test]
+    fn blob_carrying_receipt_yields_decodable_deposit() {
+        // A deposit emitted from within a blob-carrying transaction must flow
+        // through the adapter exactly like one from a plain transfer.
+        let envelope = receipt_fixture(0x03, true, 120_000, &[deposit_log()]);
+        let receipt = decode_receipt_envelope(&envelope).unwrap();
+        assert!(receipt.tx_type.is_blob_carrying());
+
+        let raw = receipt.raw_logs(42, B256::with_last_byte(4), 7);
+        assert_eq!(raw.len(), 1);
+        let deposit = EvmOriginAdapter.decode_deposit(&raw[0]).unwrap();
+        assert_eq!(deposit.escrow, Address::with_last_byte(1));
+        assert_eq!(deposit.block_number, 42);
+        assert_eq!(deposit.log_index, 7);
+        assert_eq!(deposit.amount, U256::from(1_000u64));
+    }
+
+    #[test]
+    fn raw_logs_assign_sequential_indices() {
+        let other = ReceiptLog {
+            address: Address::with_last_byte(9),
+            topics: vec![B256::with_last_byte(0xFF)],
+            data: Bytes::new(),
+        };
+        let envelope = receipt_fixture(0x03, true, 90_000, &[other, deposit_log()]);
+        let receipt = decode_receipt_envelope(&envelope).unwrap();
+
+        let raw = receipt.raw_logs(10, B256::with_last_byte(1), 3);
+        assert_eq!(raw[0].log_index, 3);
+        assert_eq!(raw[1].log_index, 4);
+        // The non-deposit log is skipped by the adapter, not an error.
+        assert!(EvmOriginAdapter.decode_deposit(&raw[0]).is_none());
+        assert!(EvmOriginAdapter.decode_deposit(&raw[1]).is_some());
+    }
+
+    #[test]
+    fn failed_receipt_reports_status() {
+        let envelope = receipt_fixture(0x02, false, 21_000, &[]);
+        let receipt = decode_receipt_envelope(&envelope).unwrap();
+        assert!(!receipt.status);
+        assert!(receipt.logs.is_empty());
+    }
+}
@@ -0,0 +1,399 @@
+//! Periodic escrow balance reconciliation.
+//!
+//! The bridge invariant is that the origin escrow holds exactly what Tempo
+//! has minted against it: for every mapped token,
+//! `escrow balance == total minted - processed burns`. The reconciliation
+//! task re-derives both sides at a fixed cadence and records the drift
+//! between them. A surplus (escrow holds more than expected) is normal while
+//! deposits are in flight; a deficit means escrowed funds left without a
+//! matching burn. Either direction trips the chain's
+//! [`CircuitBreaker`] once it exceeds the configured tolerance, so the
+//! tolerance should cover the deposit volume typically in flight between an
+//! origin lock and the Tempo mint.
+
+use crate::circuit_breaker::{BreakerScope, CircuitBreaker, TripReason};
+use alloy_primitives::{Address, U256};
+use serde::Serialize;
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::sync::RwLock;
+
+/// Default interval between reconciliation runs.
+pub const DEFAULT_RECONCILE_INTERVAL: Duration = Duration::from_secs(15 * 60);
+
+/// Cadence and tolerance for the reconciliation task.
+#[derive(Debug, Clone, Copy)]
+pub struct ReconcileConfig {
+    /// How often the reconciliation task runs.
+    pub interval: Duration,
+    /// Largest absolute drift, in token base units, tolerated before the
+    /// chain's breaker trips.
+    pub drift_tolerance: U256,
+}
+
+impl Default for ReconcileConfig {
+    fn default() -> Self {
+        Self {
+            interval: DEFAULT_RECONCILE_INTERVAL,
+            drift_tolerance: U256::ZERO,
+        }
+    }
+}
+
+/// An escrow on an origin chain and the Tempo token minted against it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TokenMapping {
+    /// Origin chain the escrow is deployed on.
+    pub chain_id: u64,
+    /// Escrow contract address on the origin chain.
+    pub escrow: Address,
+    /// Tempo token minted for deposits into this escrow.
+    pub token: Address,
+}
+
+/// Balance reads the reconciler needs from the outside world.
+///
+/// The sidecar implements this over its origin chain providers and the Tempo
+/// RPC; tests substitute a mock.
+#[async_trait::async_trait]
+pub trait ReconcileSource: Send + Sync {
+    /// The escrow's current token balance on the origin chain.
+    async fn escrow_balance(&self, chain_id: u64, escrow: Address) -> eyre::Result<U256>;
+
+    /// Cumulative amount ever minted on Tempo for the mapped token.
+    async fn minted_total(&self, token: Address) -> eyre::Result<U256>;
+
+    /// Cumulative amount of burns against this escrow that have been
+    /// processed (unlocked on the origin chain).
+    async fn processed_burn_total(&self, chain_id: u64, escrow: Address) -> eyre::Result<U256>;
+}
+
+/// Which side of the invariant is heavier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum DriftDirection {
+    /// Escrow balance matches the expected balance exactly.
+    Balanced,
+    /// The escrow holds more than expected, e.g. deposits still in flight.
+    Surplus,
+    /// The escrow holds less than expected: funds left without a burn.
+    Deficit,
+}
+
+/// Outcome of reconciling one token mapping. Serialized into
+/// `bridge-cli status` output.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DriftRecord {
+    /// Origin chain of the escrow.
+    pub chain_id: u64,
+    /// Escrow contract that was reconciled.
+    pub escrow: Address,
+    /// Tempo token mapped to the escrow.
+    pub token: Address,
+    /// Observed escrow balance.
+    pub escrow_balance: U256,
+    /// Expected balance: total minted minus processed burns.
+    pub expected_balance: U256,
+    /// Absolute difference between observed and expected.
+    pub drift: U256,
+    /// Which side is heavier.
+    pub direction: DriftDirection,
+}
+
+impl DriftRecord {
+    /// Whether the drift is within the given tolerance.
+    pub fn within_tolerance(&self, tolerance: U256) -> bool {
+        self.drift <= tolerance
+    }
+}
+
+/// Reconciles every configured token mapping and remembers the latest drift
+/// per escrow for health reporting.
+#[derive(Debug)]
+pub struct Reconciler {
+    config: ReconcileConfig,
+    mappings: Vec<TokenMapping>,
+    /// Latest drift per `(chain_id, escrow)`, replaced on every run.
+    last_drift: HashMap<(u64, Address), DriftRecord>,
+}
+
+impl Reconciler {
+    /// Creates a reconciler over the given token mappings.
+    pub fn new(config: ReconcileConfig, mappings: Vec<TokenMapping>) -> Self {
+        Self {
+            config,
+            mappings,
+            last_drift: HashMap::new(),
+        }
+    }
+
+    /// The latest drift recorded for an escrow, if it has been reconciled.
+    pub fn last_drift(&self, chain_id: u64, escrow: Address) -> Option<&DriftRecord> {
+        self.last_drift.get(&(chain_id, escrow))
+    }
+
+    /// Latest drift records for every reconciled escrow, for status output.
+    pub fn drift_records(&self) -> impl Iterator<Item = &DriftRecord> {
+        self.last_drift.values()
+    }
+
+    /// True when every reconciled escrow is within tolerance. Escrows that
+    /// have not been reconciled yet do not count against health.
+    pub fn healthy(&self) -> bool {
+        self.last_drift
+            .values()
+            .all(|record| record.within_tolerance(self.config.drift_tolerance))
+    }
+
+    /// Reconciles every mapping once.
+    ///
+    /// Mappings whose balance reads fail are logged and skipped — an
+    /// unreachable RPC is not evidence of drift — keeping their previous
+    /// record. Any mapping whose drift exceeds the tolerance trips its
+    /// chain's breaker.
+    pub async fn reconcile_once<S: ReconcileSource>(
+        &mut self,
+        source: &S,
+        breaker: &mut CircuitBreaker,
+    ) {
+        for mapping in self.mappings.clone() {
+            let record = match reconcile_mapping(source, mapping).await {
+                Ok(record) => record,
+                Err(err) => {
+                    tracing::warn!(
+                        target: "bridge::reconcile",
+                        chain_id = mapping.chain_id,
+                        escrow = %mapping.escrow,
+                        %err,
+                        "reconciliation read failed; keeping previous record",
+                    );
+                    continue;
+                }
+            };
+
+            if !record.within_tolerance(self.config.drift_tolerance) {
+                let reason = TripReason::EscrowReconciliationDrift {
+                    escrow_balance: record.escrow_balance,
+                    expected_balance: record.expected_balance,
+                };
+                tracing::error!(
+                    target: "bridge::reconcile",
+                    chain_id = mapping.chain_id,
+                    escrow = %mapping.escrow,
+                    %reason,
+                    "escrow drift exceeds tolerance; tripping circuit breaker",
+                );
+                breaker.trip(BreakerScope::Chain(mapping.chain_id), reason);
+            } else if record.direction != DriftDirection::Balanced {
+                tracing::info!(
+                    target: "bridge::reconcile",
+                    chain_id = mapping.chain_id,
+                    escrow = %mapping.escrow,
+                    drift = %record.drift,
+                    direction = ?record.direction,
+                    "escrow drift within tolerance",
+                );
+            }
+
+            self.last_drift
+                .insert((mapping.chain_id, mapping.escrow), record);
+        }
+    }
+}
+
+/// Reads both sides of the invariant for one mapping and computes the drift.
+async fn reconcile_mapping<S: ReconcileSource>(
+    source: &S,
+    mapping: TokenMapping,
+) -> eyre::Result<DriftRecord> {
+    let escrow_balance = source
+        .escrow_balance(mapping.chain_id, mapping.escrow)
+        .await?;
+    let minted = source.minted_total(mapping.token).await?;
+    let burned = source
+        .processed_burn_total(mapping.chain_id, mapping.escrow)
+        .await?;
+
+    // More processed burns than mints is itself drift: the expected balance
+    // floors at zero and the full escrow balance shows up as surplus.
+    let expected_balance = minted.saturating_sub(burned);
+    let (drift, direction) = match escrow_balance.cmp(&expected_balance) {
+        std::cmp::Ordering::Equal => (U256::ZERO, DriftDirection::Balanced),
+        std::cmp::Ordering::Greater => (escrow_balance - expected_balance, DriftDirection::Surplus),
+        std::cmp::Ordering::Less => (expected_balance - escrow_balance, DriftDirection::Deficit),
+    };
+
+    Ok(DriftRecord {
+        chain_id: mapping.chain_id,
+        escrow: mapping.escrow,
+        token: mapping.token,
+        escrow_balance,
+        expected_balance,
+        drift,
+        direction,
+    })
+}
+
+/// Runs reconciliation forever at the configured interval.
+///
+/// Shares the breaker with the signer so a detected drift halts signing on
+/// the affected chain before the next authorization goes out.
+pub async fn run_reconciliation<S: ReconcileSource>(
+    reconciler: Arc<RwLock<Reconciler>>,
+    source: S,
+    breaker: Arc<RwLock<CircuitBreaker>>,
+) {
+    let interval_duration = reconciler.read().await.config.interval;
+    let mut interval = tokio::time::interval(interval_duration);
+    // The first tick fires immediately; take it so a freshly started sidecar
+    // verifies the invariant before it signs anything.
+    loop {
+        interval.tick().await;
+        let mut reconciler = reconciler.write().await;
+        let mut breaker = breaker.write().await;
+        reconciler.reconcile_once(&source, &mut breaker).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct MockSource {
+        escrow_balances: HashMap<(u64, Address), U256>,
+        minted: HashMap<Address, U256>,
+        burned: HashMap<(u64, Address), U256>,
+    }
+
+    #[async_trait::async_trait]
+    impl ReconcileSource for MockSource {
+        async fn escrow_balance(&self, chain_id: u64, escrow: Address) -> eyre::Result<U256> {
+            self.escrow_balances
+                .get(&(chain_id, escrow))
+                .copied()
+                .ok_or_else(|| eyre::eyre!("connection refused"))
+        }
+
+        async fn minted_total(&self, token: Address) -> eyre::Result<U256> {
+            Ok(self.minted.get(&token).copied().unwrap_or_default())
+        }
+
+        async fn processed_burn_total(&self, chain_id: u64, escrow: Address) -> eyre::Result<U256> {
+            Ok(self
+                .burned
+                .get(&(chain_id, escrow))
+                .copied()
+                .unwrap_or_default())
+        }
+    }
+
+    const CHAIN: u64 = 8453;
+
+    fn mapping() -> TokenMapping {
+        TokenMapping {
+            chain_id: CHAIN,
+            escrow: Address::with_last_byte(1),
+            token: Address::with_last_byte(2),
+        }
+    }
+
+    fn source(escrow_balance: u64, minted: u64, burned: u64) -> MockSource {
+        let mapping = mapping();
+        MockSource {
+            escrow_balances: HashMap::from([((CHAIN, mapping.escrow), U256::from(escrow_balance))]),
+            minted: HashMap::from([(mapping.token, U256::from(minted))]),
+            burned: HashMap::from([((CHAIN, mapping.escrow), U256::from(burned))]),
+        }
+    }
+
+    fn reconciler(tolerance: u64) -> Reconciler {
+        Reconciler::new(
+            ReconcileConfig {
+                interval: DEFAULT_RECONCILE_INTERVAL,
+                drift_tolerance: U256::from(tolerance),
+            },
+            vec![mapping()],
+        )
+    }
+
+    #[tokio::test]
+    async fn balanced_escrow_records_zero_drift() {
+        let mut reconciler = reconciler(0);
+        let mut breaker = CircuitBreaker::default();
+
+        // 1000 minted, 300 burned and unlocked: the escrow should hold 700.
+        reconciler
+            .reconcile_once(&source(700, 1000, 300), &mut breaker)
+            .await;
+
+        let record = reconciler.last_drift(CHAIN, mapping().escrow).unwrap();
+        assert_eq!(record.drift, U256::ZERO);
+        assert_eq!(record.direction, DriftDirection::Balanced);
+        assert!(reconciler.healthy());
+        assert!(breaker.is_signing_allowed(CHAIN));
+    }
+
+    #[tokio::test]
+    async fn drift_within_tolerance_is_recorded_without_tripping() {
+        let mut reconciler = reconciler(100);
+        let mut breaker = CircuitBreaker::default();
+
+        // 50 surplus: an in-flight deposit not yet minted on Tempo.
+        reconciler
+            .reconcile_once(&source(750, 1000, 300), &mut breaker)
+            .await;
+
+        let record = reconciler.last_drift(CHAIN, mapping().escrow).unwrap();
+        assert_eq!(record.drift, U256::from(50));
+        assert_eq!(record.direction, DriftDirection::Surplus);
+        assert!(reconciler.healthy());
+        assert!(breaker.is_signing_allowed(CHAIN));
+    }
+
+    #[tokio::test]
+    async fn deficit_beyond_tolerance_trips_the_chain_breaker() {
+        let mut reconciler = reconciler(100);
+        let mut breaker = CircuitBreaker::default();
+
+        // The escrow is 200 short of the 700 it should hold.
+        reconciler
+            .reconcile_once(&source(500, 1000, 300), &mut breaker)
+            .await;
+
+        let record = reconciler.last_drift(CHAIN, mapping().escrow).unwrap();
+        assert_eq!(record.drift, U256::from(200));
+        assert_eq!(record.direction, DriftDirection::Deficit);
+        assert!(!reconciler.healthy());
+        assert!(!breaker.is_signing_allowed(CHAIN));
+        assert!(matches!(
+            breaker.trip_reason(BreakerScope::Chain(CHAIN)),
+            Some(TripReason::EscrowReconciliationDrift { .. })
+        ));
+        // Other chains keep signing.
+        assert!(breaker.is_signing_allowed(CHAIN + 1));
+    }
+
+    #[tokio::test]
+    async fn read_failure_keeps_previous_record_and_does_not_trip() {
+        let mut reconciler = reconciler(0);
+        let mut breaker = CircuitBreaker::default();
+
+        reconciler
+            .reconcile_once(&source(700, 1000, 300), &mut breaker)
+            .await;
+
+        // The origin RPC goes away: the previous record must survive and an
+        // unreachable endpoint must not read as drift.
+        let unreachable = MockSource {
+            escrow_balances: HashMap::new(),
+            minted: HashMap::new(),
+            burned: HashMap::new(),
+        };
+        reconciler.reconcile_once(&unreachable, &mut breaker).await;
+
+        let record = reconciler.last_drift(CHAIN, mapping().escrow).unwrap();
+        assert_eq!(record.escrow_balance, U256::from(700));
+        assert!(reconciler.healthy());
+        assert!(breaker.is_signing_allowed(CHAIN));
+    }
+}
@@ -0,0 +1,279 @@
+//! Hot reload of the sidecar configuration.
+//!
+//! Routine parameter changes — poll intervals, confirmation depths, caps,
+//! pruning and fee tunables — should not cost bridge downtime. The sidecar
+//! holds its validated [`BridgeConfig`] behind a [`ConfigWatch`]; watchers
+//! grab an immutable snapshot per iteration and a reload atomically swaps the
+//! snapshot the next iteration sees. A SIGHUP (see [`reload_on_sighup`])
+//! re-reads the config file, re-applies environment overrides, re-runs the
+//! full cross-field validation, and only then publishes the new snapshot, so
+//! a botched edit is rejected with the running configuration untouched.
+//!
+//! Identity-level settings — the journal directory, RPC endpoints, the
+//! broadcaster key, the escrow set itself — change what the sidecar *is*
+//! rather than how it behaves, and still require a restart: a reload that
+//! touches one is rejected as [`ReloadError::ImmutableFieldChanged`].
+
+use std::{
+    path::Path,
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use tracing::{info, warn};
+
+use crate::config::{BridgeConfig, ConfigError};
+
+/// Shared handle to the atomically swappable configuration snapshot.
+///
+/// Cloning is cheap; every clone observes the same snapshot and generation.
+#[derive(Debug, Clone)]
+pub struct ConfigWatch {
+    current: Arc<RwLock<Arc<BridgeConfig>>>,
+    generation: Arc<AtomicU64>,
+}
+
+/// Errors applying a configuration reload.
+#[derive(Debug, thiserror::Error)]
+pub enum ReloadError {
+    /// The candidate failed to load or validate; the running snapshot is kept.
+    #[error(transparent)]
+    Config(#[from] ConfigError),
+    /// The candidate changes a setting that requires a restart.
+    #[error("{field} cannot be changed by a reload; restart the sidecar")]
+    ImmutableFieldChanged {
+        /// Dotted path of the offending field.
+        field: &'static str,
+    },
+}
+
+/// Outcome of a successful reload.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReloadOutcome {
+    /// A new snapshot was published under this generation.
+    Applied {
+        /// Generation of the published snapshot.
+        generation: u64,
+    },
+    /// The candidate matched the running configuration; nothing was swapped.
+    Unchanged,
+}
+
+impl ConfigWatch {
+    /// Wraps an already-validated startup configuration.
+    pub fn new(config: BridgeConfig) -> Self {
+        Self {
+            current: Arc::new(RwLock::new(Arc::new(config))),
+            generation: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// The current snapshot.
+    ///
+    /// Snapshots are immutable: a concurrent reload swaps the handle out
+    /// underneath but never mutates a snapshot a watcher already holds, so a
+    /// single watcher iteration always sees one coherent configuration.
+    pub fn snapshot(&self) -> Arc<BridgeConfig> {
+        self.current
+            .read()
+            .expect("config watch lock not poisoned")
+            .clone()
+    }
+
+    /// Monotonic counter bumped on every applied reload, for logs and
+    /// liveness checks.
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    /// Re-reads the config file (with `TEMPO_BRIDGE_*` overrides and full
+    /// validation, exactly like startup) and publishes it if acceptable.
+    pub fn reload_from_file(&self, path: impl AsRef<Path>) -> Result<ReloadOutcome, ReloadError> {
+        self.apply(BridgeConfig::load(path)?)
+    }
+
+    /// Validates `candidate` against the running snapshot and publishes it.
+    pub fn apply(&self, candidate: BridgeConfig) -> Result<ReloadOutcome, ReloadError> {
+        candidate.validate()?;
+
+        let current = self.snapshot();
+        if let Some(field) = immutable_field_changed(&current, &candidate) {
+            return Err(ReloadError::ImmutableFieldChanged { field });
+        }
+        if *current == candidate {
+            return Ok(ReloadOutcome::Unchanged);
+        }
+
+        *self
+            .current
+            .write()
+            .expect("config watch lock not poisoned") = Arc::new(candidate);
+        let generation = self.generation.fetch_add(1, Ordering::AcqRel) + 1;
+        Ok(ReloadOutcome::Applied { generation })
+    }
+}
+
+/// Returns the first restart-only field that differs between the running and
+/// candidate configurations.
+///
+/// Per-escrow tunables (confirmations, caps) may change freely; the set of
+/// watched escrow addresses may not, because it determines the watcher's
+/// subscriptions and journal layout.
+fn immutable_field_changed(
+    current: &BridgeConfig,
+    candidate: &BridgeConfig,
+) -> Option<&'static str> {
+    if current.journal_dir != candidate.journal_dir {
+        return Some("journal_dir");
+    }
+    if current.origin.rpc_url != candidate.origin.rpc_url {
+        return Some("origin.rpc_url");
+    }
+    if current.origin.secondary_rpc_url != candidate.origin.secondary_rpc_url {
+        return Some("origin.secondary_rpc_url");
+    }
+    if current.origin.require_quorum != candidate.origin.require_quorum {
+        return Some("origin.require_quorum");
+    }
+    if current.submitter.separate_broadcaster != candidate.submitter.separate_broadcaster {
+        return Some("submitter.separate_broadcaster");
+    }
+    if current.submitter.broadcaster_key != candidate.submitter.broadcaster_key {
+        return Some("submitter.broadcaster_key");
+    }
+    let addresses = |config: &BridgeConfig| {
+        let mut addresses = config
+            .origin
+            .escrows
+            .iter()
+            .map(|escrow| escrow.address)
+            .collect::<Vec<_>>();
+        addresses.sort_unstable();
+        addresses
+    };
+    if addresses(current) != addresses(candidate) {
+        return Some("origin.escrows (set of addresses)");
+    }
+    None
+}
+
+/// Reloads the configuration from `path` every time the process receives
+/// SIGHUP, until the signal stream closes.
+///
+/// Rejected reloads are logged and leave the running snapshot in place, so an
+/// operator can fix the file and send SIGHUP again without a restart.
+#[cfg(unix)]
+pub async fn reload_on_sighup(watch: ConfigWatch, path: impl AsRef<Path>) -> std::io::Result<()> {
+    use tokio::signal::unix::{SignalKind, signal};
+
+    let path = path.as_ref();
+    let mut hangup = signal(SignalKind::hangup())?;
+    while hangup.recv().await.is_some() {
+        match watch.reload_from_file(path) {
+            Ok(ReloadOutcome::Applied { generation }) => {
+                info!(generation, path = %path.display(), "configuration reloaded");
+            }
+            Ok(ReloadOutcome::Unchanged) => {
+                info!(path = %path.display(), "configuration unchanged");
+            }
+            Err(error) => {
+                warn!(%error, "configuration reload rejected; keeping running snapshot");
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::U256;
+
+    const EXAMPLE: &str = r#"
+journal_dir = "/var/lib/tempo/bridge"
+
+[origin]
+rpc_url = "wss://origin.example"
+poll_interval_secs = 6
+
+[[origin.escrows]]
+address = "0x1111111111111111111111111111111111111111"
+confirmations = 6
+"#;
+
+    fn config() -> BridgeConfig {
+        BridgeConfig::from_toml_str(EXAMPLE).unwrap()
+    }
+
+    #[test]
+    fn applied_reload_swaps_the_snapshot_for_all_clones() {
+        let watch = ConfigWatch::new(config());
+        let clone = watch.clone();
+        let before = watch.snapshot();
+        assert_eq!(watch.generation(), 0);
+
+        let mut tuned = config();
+        tuned.origin.poll_interval_secs = 3;
+        tuned.origin.escrows[0].confirmations = 12;
+        tuned.origin.escrows[0].cap = Some(U256::from(1_000_000u64));
+        assert_eq!(
+            watch.apply(tuned).unwrap(),
+            ReloadOutcome::Applied { generation: 1 }
+        );
+
+        // Both handles observe the swap; the old snapshot is untouched.
+        assert_eq!(clone.snapshot().origin.poll_interval_secs, 3);
+        assert_eq!(clone.snapshot().origin.escrows[0].confirmations, 12);
+        assert_eq!(clone.generation(), 1);
+        assert_eq!(before.origin.poll_interval_secs, 6);
+
+        // An identical candidate is a no-op.
+        let again = clone.snapshot().as_ref().clone();
+        assert_eq!(watch.apply(again).unwrap(), ReloadOutcome::Unchanged);
+        assert_eq!(watch.generation(), 1);
+    }
+
+    #[test]
+    fn invalid_candidate_keeps_the_running_snapshot() {
+        let watch = ConfigWatch::new(config());
+
+        let mut broken = config();
+        broken.origin.poll_interval_secs = 0;
+        assert!(matches!(
+            watch.apply(broken),
+            Err(ReloadError::Config(ConfigError::Invalid(_)))
+        ));
+
+        assert_eq!(watch.snapshot().origin.poll_interval_secs, 6);
+        assert_eq!(watch.generation(), 0);
+    }
+
+    #[test]
+    fn immutable_fields_require_a_restart() {
+        let watch = ConfigWatch::new(config());
+
+        let mut moved = config();
+        moved.origin.rpc_url = "wss://elsewhere.example".into();
+        assert!(matches!(
+            watch.apply(moved),
+            Err(ReloadError::ImmutableFieldChanged {
+                field: "origin.rpc_url"
+            })
+        ));
+
+        let mut grown = config();
+        grown.origin.escrows.push(crate::config::EscrowEntry {
+            address: alloy_primitives::Address::with_last_byte(2),
+            confirmations: 0,
+            cap: None,
+        });
+        assert!(matches!(
+            watch.apply(grown),
+            Err(ReloadError::ImmutableFieldChanged { .. })
+        ));
+
+        assert_eq!(watch.generation(), 0);
+    }
+}
@@ -0,0 +1,318 @@
+//! Causal replay of a single deposit or burn for incident investigation.
+//!
+//! `bridge-cli replay` takes a deposit (or burn) id and reconstructs its full
+//! timeline from the artifacts the sidecar journals as it works: the deposit
+//! state file, the signature audit log, and the unlock journal. Chain-side
+//! facts — origin block heights, signing timestamps, broadcast transaction
+//! hashes — were recorded into those journals at write time, so the trace is
+//! built entirely offline and works even while the chains involved are
+//! unreachable or the incident is still unfolding.
+
+use crate::{
+    audit_log::AuditRecord, deposit_expiry::DepositState, unlock_submitter::UnlockAttempt,
+};
+use alloy_primitives::B256;
+use std::fmt;
+
+/// One step in a reconstructed flow timeline, in causal order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceEvent {
+    /// The origin deposit event was observed by the watcher.
+    DepositObserved {
+        /// Origin block the deposit was first seen at.
+        block: u64,
+        /// Validator-set epoch signature collection started under.
+        validator_epoch: u64,
+    },
+    /// A validator produced a signature over the flow's digest.
+    SignatureProduced {
+        /// Unix timestamp (seconds) the signature was produced at.
+        timestamp: u64,
+        /// Key that signed, e.g. a fingerprint or HSM slot.
+        signer_key_id: String,
+        /// Digest that was signed.
+        digest: B256,
+    },
+    /// Signature collection reached threshold.
+    ThresholdReached {
+        /// Block at which the final signature arrived.
+        block: u64,
+    },
+    /// The deposit went stale before reaching threshold.
+    Expired {
+        /// Block at which the expiry policy fired.
+        block: u64,
+        /// Signatures that had been collected by then.
+        signatures_collected: usize,
+    },
+    /// An unlock was journaled as an intent but not (knowingly) broadcast.
+    UnlockIntent {
+        /// Nonce reserved for the transaction.
+        nonce: u64,
+    },
+    /// An unlock transaction was broadcast on the origin chain.
+    UnlockSubmitted {
+        /// Hash of the broadcast transaction.
+        tx_hash: B256,
+        /// Nonce it was broadcast with.
+        nonce: u64,
+    },
+}
+
+impl fmt::Display for TraceEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DepositObserved {
+                block,
+                validator_epoch,
+            } => write!(
+                f,
+                "deposit observed at origin block {block}, collecting under epoch {validator_epoch}"
+            ),
+            Self::SignatureProduced {
+                timestamp,
+                signer_key_id,
+                digest,
+            } => write!(
+                f,
+                "signature by {signer_key_id} at t={timestamp} over digest {digest}"
+            ),
+            Self::ThresholdReached { block } => {
+                write!(f, "signature threshold reached at block {block}")
+            }
+            Self::Expired {
+                block,
+                signatures_collected,
+            } => write!(
+                f,
+                "expired at block {block} with {signatures_collected} signature(s) collected"
+            ),
+            Self::UnlockIntent { nonce } => {
+                write!(
+                    f,
+                    "unlock journaled at nonce {nonce}, broadcast unconfirmed"
+                )
+            }
+            Self::UnlockSubmitted { tx_hash, nonce } => {
+                write!(f, "unlock tx {tx_hash} broadcast at nonce {nonce}")
+            }
+        }
+    }
+}
+
+/// The reconstructed timeline of one deposit or burn.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayTrace {
+    /// The deposit (or burn) id the trace covers.
+    pub id: B256,
+    /// Events in causal order.
+    pub events: Vec<TraceEvent>,
+}
+
+impl fmt::Display for ReplayTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "replay of {}:", self.id)?;
+        for (idx, event) in self.events.iter().enumerate() {
+            writeln!(f, "  {}. {event}", idx + 1)?;
+        }
+        Ok(())
+    }
+}
+
+/// Builds the causal trace for `id` from whichever journals know about it.
+///
+/// Ordering follows the flow's lifecycle rather than a single clock, since
+/// the sources record different time bases (origin blocks for deposit state,
+/// unix seconds for signatures): observation, then signatures in log order,
+/// then the terminal deposit state, then unlock submission. Returns `None`
+/// when no journal has ever seen the id.
+pub fn build_trace(
+    id: B256,
+    state: Option<&DepositState>,
+    audit_records: &[AuditRecord],
+    unlock: Option<&UnlockAttempt>,
+) -> Option<ReplayTrace> {
+    let mut events = Vec::new();
+
+    match state {
+        Some(
+            DepositState::Pending {
+                first_seen_block,
+                validator_epoch,
+                ..
+            }
+            | DepositState::Expired {
+                first_seen_block,
+                validator_epoch,
+                ..
+            },
+        ) => events.push(TraceEvent::DepositObserved {
+            block: *first_seen_block,
+            validator_epoch: *validator_epoch,
+        }),
+        // Completed records no longer carry their observation block.
+        Some(DepositState::Completed { .. }) | None => {}
+    }
+
+    for record in audit_records.iter().filter(|r| r.deposit_id == id) {
+        events.push(TraceEvent::SignatureProduced {
+            timestamp: record.timestamp,
+            signer_key_id: record.signer_key_id.clone(),
+            digest: record.digest,
+        });
+    }
+
+    match state {
+        Some(DepositState::Completed { completed_at_block }) => {
+            events.push(TraceEvent::ThresholdReached {
+                block: *completed_at_block,
+            });
+        }
+        Some(DepositState::Expired {
+            expired_at_block,
+            signatures_collected,
+            ..
+        }) => events.push(TraceEvent::Expired {
+            block: *expired_at_block,
+            signatures_collected: *signatures_collected,
+        }),
+        Some(DepositState::Pending { .. }) | None => {}
+    }
+
+    if let Some(attempt) = unlock {
+        events.push(match attempt.tx_hash {
+            Some(tx_hash) => TraceEvent::UnlockSubmitted {
+                tx_hash,
+                nonce: attempt.nonce,
+            },
+            None => TraceEvent::UnlockIntent {
+                nonce: attempt.nonce,
+            },
+        });
+    }
+
+    if events.is_empty() {
+        return None;
+    }
+    Some(ReplayTrace { id, events })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::Bytes;
+    use std::collections::BTreeSet;
+
+    fn signature_record(id: B256, timestamp: u64, signer: &str) -> AuditRecord {
+        AuditRecord {
+            sequence: 0,
+            deposit_id: id,
+            digest: B256::with_last_byte(0xd0),
+            timestamp,
+            signer_key_id: signer.to_string(),
+            signature: Bytes::from_static(&[0xaa; 65]),
+            prev_hash: B256::ZERO,
+        }
+    }
+
+    #[test]
+    fn completed_flow_replays_in_causal_order() {
+        let id = B256::with_last_byte(1);
+        let state = DepositState::Completed {
+            completed_at_block: 120,
+        };
+        let records = vec![
+            signature_record(id, 1_000, "hsm-slot-1"),
+            // A record for an unrelated deposit must not leak into the trace.
+            signature_record(B256::with_last_byte(2), 1_001, "hsm-slot-1"),
+            signature_record(id, 1_002, "hsm-slot-2"),
+        ];
+        let unlock = UnlockAttempt {
+            burn_id: id,
+            nonce: 7,
+            tx_hash: Some(B256::with_last_byte(0x77)),
+        };
+
+        let trace = build_trace(id, Some(&state), &records, Some(&unlock)).unwrap();
+        assert_eq!(trace.events.len(), 4);
+        assert!(matches!(
+            trace.events[0],
+            TraceEvent::SignatureProduced {
+                timestamp: 1_000,
+                ..
+            }
+        ));
+        assert!(matches!(
+            trace.events[1],
+            TraceEvent::SignatureProduced {
+                timestamp: 1_002,
+                ..
+            }
+        ));
+        assert_eq!(trace.events[2], TraceEvent::ThresholdReached { block: 120 });
+        assert!(matches!(
+            trace.events[3],
+            TraceEvent::UnlockSubmitted { nonce: 7, .. }
+        ));
+
+        let rendered = trace.to_string();
+        assert!(rendered.contains("hsm-slot-2"));
+        assert!(rendered.contains("nonce 7"));
+    }
+
+    #[test]
+    fn expired_flow_reports_observation_and_expiry() {
+        let id = B256::with_last_byte(3);
+        let state = DepositState::Expired {
+            first_seen_block: 50,
+            expired_at_block: 150,
+            validator_epoch: 4,
+            signatures_collected: 1,
+        };
+        let records = vec![signature_record(id, 900, "hsm-slot-1")];
+
+        let trace = build_trace(id, Some(&state), &records, None).unwrap();
+        assert_eq!(
+            trace.events,
+            vec![
+                TraceEvent::DepositObserved {
+                    block: 50,
+                    validator_epoch: 4,
+                },
+                TraceEvent::SignatureProduced {
+                    timestamp: 900,
+                    signer_key_id: "hsm-slot-1".to_string(),
+                    digest: B256::with_last_byte(0xd0),
+                },
+                TraceEvent::Expired {
+                    block: 150,
+                    signatures_collected: 1,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn pending_flow_shows_collection_in_progress() {
+        let id = B256::with_last_byte(4);
+        let state = DepositState::Pending {
+            first_seen_block: 10,
+            validator_epoch: 2,
+            signatures: BTreeSet::new(),
+        };
+
+        let trace = build_trace(id, Some(&state), &[], None).unwrap();
+        assert_eq!(
+            trace.events,
+            vec![TraceEvent::DepositObserved {
+                block: 10,
+                validator_epoch: 2,
+            }]
+        );
+    }
+
+    #[test]
+    fn unknown_id_yields_no_trace() {
+        assert_eq!(build_trace(B256::with_last_byte(9), None, &[], None), None);
+    }
+}
@@ -0,0 +1,356 @@
+//! Deterministic startup self-test for the bridge sidecar.
+//!
+//! Run via `--self-test`, this validates the sidecar configuration end-to-end
+//! before the ExEx starts signing: the signer can produce a signature, every
+//! origin chain RPC is reachable and reports the configured chain id, the
+//! light client and escrow contracts are deployed with the expected bytecode
+//! hash, the consensus RPC serves finalization certificates, and the state
+//! file is writable. Every check runs even after an earlier one fails so a
+//! single pass surfaces all misconfigurations; the sidecar prints the report
+//! as JSON and exits non-zero if any check failed.
+
+use alloy_primitives::{Address, B256, keccak256};
+use serde::Serialize;
+use std::{fs::OpenOptions, path::PathBuf};
+
+/// Digest signed during the signer check. Fixed so the self-test never
+/// produces a signature over data that could be mistaken for a real
+/// authorization.
+pub fn self_test_digest() -> B256 {
+    keccak256(b"tempo-bridge-self-test")
+}
+
+/// Abstraction over the external systems probed by the self-test.
+///
+/// The sidecar implements this over its real signer, origin chain providers
+/// and consensus RPC client; tests substitute a mock.
+#[async_trait::async_trait]
+pub trait SelfTestProbe: Send + Sync {
+    /// Signs `digest` with the sidecar's bridge key and verifies the result
+    /// against its own public key. Errors if the key is missing, locked, or
+    /// produces an invalid signature.
+    async fn sign_test_digest(&self, digest: B256) -> eyre::Result<()>;
+
+    /// Queries `eth_chainId` on the RPC endpoint configured for origin chain
+    /// `chain`, returning the id the node actually reports.
+    async fn origin_chain_id(&self, chain: u64) -> eyre::Result<u64>;
+
+    /// Returns the keccak hash of the deployed bytecode at `address` on
+    /// origin chain `chain`. An address with no code hashes to
+    /// `keccak256([])`.
+    async fn code_hash(&self, chain: u64, address: Address) -> eyre::Result<B256>;
+
+    /// Returns the height of the latest finalization certificate served by
+    /// the consensus RPC, or `None` if it has none yet.
+    async fn latest_certificate_height(&self) -> eyre::Result<Option<u64>>;
+}
+
+/// A contract the self-test expects to find deployed on an origin chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ContractExpectation {
+    /// Address the contract is deployed at.
+    pub address: Address,
+    /// Expected keccak hash of the deployed bytecode.
+    pub code_hash: B256,
+}
+
+/// Expectations for one configured origin chain.
+#[derive(Debug, Clone)]
+pub struct OriginChainCheck {
+    /// Chain id the RPC endpoint must report.
+    pub chain_id: u64,
+    /// The Tempo light client contract on this chain.
+    pub light_client: ContractExpectation,
+    /// Escrow contracts watched on this chain.
+    pub escrows: Vec<ContractExpectation>,
+}
+
+/// Everything the self-test validates.
+#[derive(Debug, Clone)]
+pub struct SelfTestConfig {
+    /// Origin chains the sidecar is configured to watch.
+    pub origin_chains: Vec<OriginChainCheck>,
+    /// Path of the sidecar's persistent state file.
+    pub state_file: PathBuf,
+}
+
+/// Outcome of a single check.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CheckResult {
+    /// Stable name of the check, e.g. `origin[8453].chain_id`.
+    pub name: String,
+    /// Whether the check passed.
+    pub passed: bool,
+    /// Human-readable detail: what was observed, or the error.
+    pub detail: String,
+}
+
+/// Structured report of a full self-test run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SelfTestReport {
+    /// All executed checks, in execution order.
+    pub checks: Vec<CheckResult>,
+}
+
+impl SelfTestReport {
+    /// True if every check passed.
+    pub fn passed(&self) -> bool {
+        self.checks.iter().all(|check| check.passed)
+    }
+
+    /// Process exit code the sidecar should terminate with.
+    pub fn exit_code(&self) -> i32 {
+        if self.passed() { 0 } else { 1 }
+    }
+
+    fn record(&mut self, name: impl Into<String>, result: eyre::Result<String>) {
+        let name = name.into();
+        match result {
+            Ok(detail) => self.checks.push(CheckResult {
+                name,
+                passed: true,
+                detail,
+            }),
+            Err(err) => self.checks.push(CheckResult {
+                name,
+                passed: false,
+                detail: format!("{err:#}"),
+            }),
+        }
+    }
+}
+
+/// Runs every configured check and returns the full report.
+pub async fn run_self_test<P: SelfTestProbe>(probe: &P, config: &SelfTestConfig) -> SelfTestReport {
+    let mut report = SelfTestReport { checks: Vec::new() };
+
+    report.record(
+        "signer",
+        probe
+            .sign_test_digest(self_test_digest())
+            .await
+            .map(|()| "signed and verified self-test digest".to_string()),
+    );
+
+    for chain in &config.origin_chains {
+        report.record(
+            format!("origin[{}].chain_id", chain.chain_id),
+            match probe.origin_chain_id(chain.chain_id).await {
+                Ok(reported) if reported == chain.chain_id => {
+                    Ok(format!("rpc reports chain id {reported}"))
+                }
+                Ok(reported) => Err(eyre::eyre!(
+                    "rpc reports chain id {reported}, config expects {}",
+                    chain.chain_id
+                )),
+                Err(err) => Err(err.wrap_err("rpc unreachable")),
+            },
+        );
+
+        report.record(
+            format!("origin[{}].light_client", chain.chain_id),
+            check_contract(probe, chain.chain_id, chain.light_client).await,
+        );
+
+        for escrow in &chain.escrows {
+            report.record(
+                format!("origin[{}].escrow[{}]", chain.chain_id, escrow.address),
+                check_contract(probe, chain.chain_id, *escrow).await,
+            );
+        }
+    }
+
+    report.record(
+        "consensus.certificates",
+        match probe.latest_certificate_height().await {
+            Ok(Some(height)) => Ok(format!(
+                "latest finalization certificate at height {height}"
+            )),
+            Ok(None) => Err(eyre::eyre!(
+                "consensus rpc has no finalization certificates"
+            )),
+            Err(err) => Err(err.wrap_err("consensus rpc unreachable")),
+        },
+    );
+
+    report.record(
+        "state_file",
+        // Open for append so an existing state file is not truncated; the
+        // self-test must never modify sidecar state.
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&config.state_file)
+            .map(|_| format!("{} is writable", config.state_file.display()))
+            .map_err(|err| eyre::eyre!("{} is not writable: {err}", config.state_file.display())),
+    );
+
+    report
+}
+
+async fn check_contract<P: SelfTestProbe>(
+    probe: &P,
+    chain: u64,
+    expected: ContractExpectation,
+) -> eyre::Result<String> {
+    let observed = probe.code_hash(chain, expected.address).await?;
+    if observed == expected.code_hash {
+        Ok(format!("code hash {observed} matches"))
+    } else if observed == keccak256([]) {
+        Err(eyre::eyre!("no code deployed at {}", expected.address))
+    } else {
+        Err(eyre::eyre!(
+            "code hash {observed} does not match expected {}",
+            expected.code_hash
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    struct MockProbe {
+        signer_ok: bool,
+        chain_ids: HashMap<u64, u64>,
+        code: HashMap<(u64, Address), B256>,
+        certificate_height: Option<u64>,
+    }
+
+    #[async_trait::async_trait]
+    impl SelfTestProbe for MockProbe {
+        async fn sign_test_digest(&self, _digest: B256) -> eyre::Result<()> {
+            if self.signer_ok {
+                Ok(())
+            } else {
+                Err(eyre::eyre!("signer key not available"))
+            }
+        }
+
+        async fn origin_chain_id(&self, chain: u64) -> eyre::Result<u64> {
+            self.chain_ids
+                .get(&chain)
+                .copied()
+                .ok_or_else(|| eyre::eyre!("connection refused"))
+        }
+
+        async fn code_hash(&self, chain: u64, address: Address) -> eyre::Result<B256> {
+            Ok(self
+                .code
+                .get(&(chain, address))
+                .copied()
+                .unwrap_or_else(|| keccak256([])))
+        }
+
+        async fn latest_certificate_height(&self) -> eyre::Result<Option<u64>> {
+            Ok(self.certificate_height)
+        }
+    }
+
+    fn config(state_file: PathBuf) -> (SelfTestConfig, MockProbe) {
+        let light_client = ContractExpectation {
+            address: Address::with_last_byte(1),
+            code_hash: B256::with_last_byte(0xaa),
+        };
+        let escrow = ContractExpectation {
+            address: Address::with_last_byte(2),
+            code_hash: B256::with_last_byte(0xbb),
+        };
+        let config = SelfTestConfig {
+            origin_chains: vec![OriginChainCheck {
+                chain_id: 8453,
+                light_client,
+                escrows: vec![escrow],
+            }],
+            state_file,
+        };
+        let probe = MockProbe {
+            signer_ok: true,
+            chain_ids: HashMap::from([(8453, 8453)]),
+            code: HashMap::from([
+                ((8453, light_client.address), light_client.code_hash),
+                ((8453, escrow.address), escrow.code_hash),
+            ]),
+            certificate_height: Some(42),
+        };
+        (config, probe)
+    }
+
+    fn temp_state_file(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "bridge-self-test-{}-{name}.json",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[tokio::test]
+    async fn healthy_config_passes_every_check() {
+        let path = temp_state_file("healthy");
+        let (config, probe) = config(path.clone());
+
+        let report = run_self_test(&probe, &config).await;
+        assert!(report.passed(), "{report:?}");
+        assert_eq!(report.exit_code(), 0);
+        // signer, chain id, light client, escrow, certificates, state file.
+        assert_eq!(report.checks.len(), 6);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn chain_id_mismatch_fails_but_other_checks_still_run() {
+        let path = temp_state_file("chain-id");
+        let (config, mut probe) = config(path.clone());
+        probe.chain_ids.insert(8453, 1);
+
+        let report = run_self_test(&probe, &config).await;
+        assert!(!report.passed());
+        assert_eq!(report.exit_code(), 1);
+        assert_eq!(report.checks.len(), 6);
+        let failed: Vec<_> = report.checks.iter().filter(|c| !c.passed).collect();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].name, "origin[8453].chain_id");
+        assert!(failed[0].detail.contains("config expects 8453"));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn missing_contract_code_is_reported_distinctly() {
+        let path = temp_state_file("no-code");
+        let (config, mut probe) = config(path.clone());
+        probe.code.remove(&(8453, Address::with_last_byte(2)));
+
+        let report = run_self_test(&probe, &config).await;
+        let escrow_check = report
+            .checks
+            .iter()
+            .find(|c| c.name.starts_with("origin[8453].escrow"))
+            .unwrap();
+        assert!(!escrow_check.passed);
+        assert!(escrow_check.detail.contains("no code deployed"));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn missing_certificates_and_unwritable_state_fail() {
+        let path = std::env::temp_dir()
+            .join("bridge-self-test-does-not-exist")
+            .join("state.json");
+        let (config, mut probe) = config(path);
+        probe.signer_ok = false;
+        probe.certificate_height = None;
+
+        let report = run_self_test(&probe, &config).await;
+        let failed: Vec<_> = report
+            .checks
+            .iter()
+            .filter(|c| !c.passed)
+            .map(|c| c.name.as_str())
+            .collect();
+        assert_eq!(failed, ["signer", "consensus.certificates", "state_file"]);
+    }
+}
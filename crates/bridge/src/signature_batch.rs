@@ -0,0 +1,244 @@
+//! Batched signature submission to Tempo.
+//!
+//! After a backfill (or any origin-chain catch-up) a validator can hold
+//! signatures for hundreds of deposits at once. Submitting each one as its
+//! own Tempo transaction wastes per-transaction overhead and floods the pool;
+//! instead the signer path queues ready signatures and flushes them through
+//! the batched `submitSignatures(bytes32[] ids, bytes[] sigs)` entrypoint,
+//! one transaction per batch.
+//!
+//! A batch is flushed as soon as it reaches the configured size, or once its
+//! oldest entry exceeds the configured age — so a lone signature during quiet
+//! periods is never held longer than the age bound.
+
+use alloy_primitives::{B256, Bytes};
+use std::{collections::VecDeque, time::Duration};
+
+/// Default number of signatures flushed in a single Tempo transaction.
+pub const DEFAULT_MAX_BATCH_SIZE: usize = 100;
+
+/// Default age bound: the oldest queued signature is never held longer than
+/// this before a flush.
+pub const DEFAULT_MAX_BATCH_AGE: Duration = Duration::from_secs(2);
+
+/// Batching bounds for the signer submission path.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignatureBatchConfig {
+    /// Flush as soon as this many signatures are queued.
+    pub max_batch_size: usize,
+    /// Flush once the oldest queued signature is this old.
+    pub max_batch_age: Duration,
+}
+
+impl Default for SignatureBatchConfig {
+    fn default() -> Self {
+        Self {
+            max_batch_size: DEFAULT_MAX_BATCH_SIZE,
+            max_batch_age: DEFAULT_MAX_BATCH_AGE,
+        }
+    }
+}
+
+/// One flushed batch, shaped for the `submitSignatures(bytes32[] ids,
+/// bytes[] sigs)` precompile entrypoint: `ids[i]` is approved by `sigs[i]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureBatch {
+    /// Deposit ids, in queue order.
+    pub ids: Vec<B256>,
+    /// Signatures over the corresponding deposit digests.
+    pub sigs: Vec<Bytes>,
+}
+
+impl SignatureBatch {
+    /// Number of signatures in the batch.
+    pub fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns true if the batch is empty.
+    pub fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+}
+
+#[derive(Debug)]
+struct PendingSignature {
+    deposit_id: B256,
+    signature: Bytes,
+    /// Unix seconds the signature was queued at.
+    queued_at: u64,
+}
+
+/// FIFO queue that groups ready signatures into bounded batches.
+///
+/// Purely in-memory: signatures are cheap to re-derive from the journal on
+/// restart, unlike unlock attempts, so there is no on-disk state here.
+#[derive(Debug)]
+pub struct SignatureBatcher {
+    config: SignatureBatchConfig,
+    queue: VecDeque<PendingSignature>,
+}
+
+impl SignatureBatcher {
+    /// Creates an empty batcher with the given bounds.
+    pub fn new(config: SignatureBatchConfig) -> Self {
+        Self {
+            config,
+            queue: VecDeque::new(),
+        }
+    }
+
+    /// Number of signatures currently queued.
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Returns true if nothing is queued.
+    pub fn is_empty(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Queues a signature for `deposit_id`, produced at `now` (Unix seconds).
+    ///
+    /// A deposit id already in the queue is ignored: re-signing after a
+    /// restart must not put the same approval in one batch twice. Returns a
+    /// full batch when the queue reaches the size bound.
+    pub fn push(&mut self, deposit_id: B256, signature: Bytes, now: u64) -> Option<SignatureBatch> {
+        if self
+            .queue
+            .iter()
+            .any(|entry| entry.deposit_id == deposit_id)
+        {
+            return None;
+        }
+        self.queue.push_back(PendingSignature {
+            deposit_id,
+            signature,
+            queued_at: now,
+        });
+        (self.queue.len() >= self.config.max_batch_size).then(|| self.drain_batch())
+    }
+
+    /// Returns a batch if the oldest queued signature has exceeded the age
+    /// bound, draining up to the size bound.
+    pub fn take_due(&mut self, now: u64) -> Option<SignatureBatch> {
+        let oldest = self.queue.front()?;
+        (now.saturating_sub(oldest.queued_at) >= self.config.max_batch_age.as_secs())
+            .then(|| self.drain_batch())
+    }
+
+    /// Drains everything queued, regardless of size or age. Used on shutdown
+    /// so no approval is lost with the process.
+    pub fn take_all(&mut self) -> Option<SignatureBatch> {
+        (!self.queue.is_empty()).then(|| {
+            let mut batch = self.drain_batch();
+            while !self.queue.is_empty() {
+                let more = self.drain_batch();
+                batch.ids.extend(more.ids);
+                batch.sigs.extend(more.sigs);
+            }
+            batch
+        })
+    }
+
+    /// Drains up to `max_batch_size` entries from the front of the queue.
+    fn drain_batch(&mut self) -> SignatureBatch {
+        let count = self.queue.len().min(self.config.max_batch_size);
+        let mut ids = Vec::with_capacity(count);
+        let mut sigs = Vec::with_capacity(count);
+        for entry in self.queue.drain(..count) {
+            ids.push(entry.deposit_id);
+            sigs.push(entry.signature);
+        }
+        SignatureBatch { ids, sigs }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(size: usize, age_secs: u64) -> SignatureBatchConfig {
+        SignatureBatchConfig {
+            max_batch_size: size,
+            max_batch_age: Duration::from_secs(age_secs),
+        }
+    }
+
+    fn sig(byte: u8) -> Bytes {
+        Bytes::from(vec![byte; 65])
+    }
+
+    #[test]
+    fn flushes_when_the_size_bound_is_reached() {
+        let mut batcher = SignatureBatcher::new(config(3, 60));
+
+        assert!(batcher.push(B256::with_last_byte(1), sig(1), 0).is_none());
+        assert!(batcher.push(B256::with_last_byte(2), sig(2), 0).is_none());
+        let batch = batcher.push(B256::with_last_byte(3), sig(3), 0).unwrap();
+
+        assert_eq!(
+            batch.ids,
+            vec![
+                B256::with_last_byte(1),
+                B256::with_last_byte(2),
+                B256::with_last_byte(3),
+            ]
+        );
+        assert_eq!(batch.sigs, vec![sig(1), sig(2), sig(3)]);
+        assert!(batcher.is_empty());
+    }
+
+    #[test]
+    fn flushes_when_the_oldest_entry_exceeds_the_age_bound() {
+        let mut batcher = SignatureBatcher::new(config(100, 2));
+
+        batcher.push(B256::with_last_byte(1), sig(1), 10);
+        assert!(batcher.take_due(11).is_none());
+
+        batcher.push(B256::with_last_byte(2), sig(2), 11);
+        let batch = batcher.take_due(12).unwrap();
+        assert_eq!(batch.len(), 2);
+        assert!(batcher.is_empty());
+        assert!(batcher.take_due(100).is_none());
+    }
+
+    #[test]
+    fn duplicate_deposit_ids_are_queued_once() {
+        let mut batcher = SignatureBatcher::new(config(2, 60));
+
+        assert!(batcher.push(B256::with_last_byte(1), sig(1), 0).is_none());
+        assert!(batcher.push(B256::with_last_byte(1), sig(9), 0).is_none());
+        assert_eq!(batcher.len(), 1);
+
+        let batch = batcher.push(B256::with_last_byte(2), sig(2), 0).unwrap();
+        assert_eq!(batch.sigs, vec![sig(1), sig(2)]);
+    }
+
+    #[test]
+    fn age_flush_respects_the_size_bound() {
+        let mut batcher = SignatureBatcher::new(config(2, 1));
+
+        // Three entries queued within one tick: a due flush drains at most
+        // max_batch_size, leaving the remainder for the next flush.
+        batcher.push(B256::with_last_byte(1), sig(1), 0);
+        batcher.push(B256::with_last_byte(2), sig(2), 0);
+        batcher.push(B256::with_last_byte(3), sig(3), 0);
+        // push() at the size bound already flushed the first two.
+        assert_eq!(batcher.len(), 1);
+
+        let batch = batcher.take_due(5).unwrap();
+        assert_eq!(batch.ids, vec![B256::with_last_byte(3)]);
+    }
+
+    #[test]
+    fn take_all_drains_everything() {
+        let mut batcher = SignatureBatcher::new(config(2, 60));
+        assert!(batcher.take_all().is_none());
+
+        batcher.push(B256::with_last_byte(1), sig(1), 0);
+        let batch = batcher.take_all().unwrap();
+        assert_eq!(batch.ids, vec![B256::with_last_byte(1)]);
+        assert!(batcher.is_empty());
+    }
+}
@@ -0,0 +1,346 @@
+//! Cross-restart deduplication of Tempo signature submissions.
+//!
+//! The signer path re-derives signatures from the origin-chain journal on
+//! restart, so a sidecar that crashed after submitting but before observing
+//! the result will happily sign the same deposits again. The precompile
+//! rejects the duplicate, but only after the transaction paid its gas and
+//! left a rejection in the logs. Before queueing a signature, the deduper
+//! therefore asks the chain whether this validator's signature for the
+//! deposit id is already recorded (via the precompile's per-validator view)
+//! and skips it if so. Confirmed ids are persisted to a journal so restarts
+//! do not even re-query them — a recorded signature never un-records.
+
+use crate::signature_batch::SignatureBatch;
+use alloy_primitives::{Address, B256};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashSet,
+    fs::{File, OpenOptions},
+    io::{BufRead as _, BufReader, Seek as _, SeekFrom, Write as _},
+    path::Path,
+};
+
+/// Read access to the bridge precompile's signature state on Tempo.
+///
+/// Implemented over the node's RPC in the sidecar binary; tests use an
+/// in-memory mock.
+#[async_trait::async_trait]
+pub trait SignatureStateSource: Send + Sync {
+    /// Returns true if `validator`'s signature for `deposit_id` is already
+    /// recorded by the bridge precompile.
+    async fn has_signature(&self, deposit_id: B256, validator: Address) -> eyre::Result<bool>;
+}
+
+/// One journaled confirmation that this validator's signature is on-chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RecordedSignature {
+    /// Deposit whose signature the chain confirmed.
+    deposit_id: B256,
+}
+
+/// Errors raised while reading or writing the dedup journal.
+#[derive(Debug, thiserror::Error)]
+pub enum DedupJournalError {
+    /// The journal file could not be read or written.
+    #[error("signature dedup journal io error: {0}")]
+    Io(#[from] std::io::Error),
+    /// A line is not a valid JSON record.
+    #[error("malformed journal entry on line {line}: {err}")]
+    MalformedEntry {
+        /// One-based line number.
+        line: usize,
+        /// Underlying JSON error.
+        err: serde_json::Error,
+    },
+}
+
+/// Append-only journal of deposit ids whose signatures the chain confirmed.
+///
+/// Same on-disk shape as the unlock journal: one JSON record per line,
+/// appended and fsynced before the result is acted on. Entries are only ever
+/// added — the precompile never forgets a recorded signature, so neither does
+/// the journal.
+#[derive(Debug)]
+pub struct DedupJournal {
+    file: File,
+    recorded: HashSet<B256>,
+}
+
+impl DedupJournal {
+    /// Opens (or creates) the journal at `path` and replays its entries.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, DedupJournalError> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(path)?;
+
+        let mut recorded = HashSet::new();
+        for (idx, line) in BufReader::new(&mut file).lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let entry: RecordedSignature = serde_json::from_str(&line)
+                .map_err(|err| DedupJournalError::MalformedEntry { line: idx + 1, err })?;
+            recorded.insert(entry.deposit_id);
+        }
+        file.seek(SeekFrom::End(0))?;
+
+        Ok(Self { file, recorded })
+    }
+
+    /// Returns true if the deposit id is journaled as recorded on-chain.
+    pub fn contains(&self, deposit_id: B256) -> bool {
+        self.recorded.contains(&deposit_id)
+    }
+
+    /// Number of journaled deposit ids.
+    pub fn len(&self) -> usize {
+        self.recorded.len()
+    }
+
+    /// Returns true if nothing is journaled.
+    pub fn is_empty(&self) -> bool {
+        self.recorded.is_empty()
+    }
+
+    /// Appends a confirmed deposit id and flushes it to disk before
+    /// returning. Re-recording a known id is a no-op.
+    pub fn record(&mut self, deposit_id: B256) -> Result<(), DedupJournalError> {
+        if !self.recorded.insert(deposit_id) {
+            return Ok(());
+        }
+        let mut line = serde_json::to_string(&RecordedSignature { deposit_id })
+            .expect("recorded signature serializes");
+        line.push('\n');
+        self.file.write_all(line.as_bytes())?;
+        self.file.sync_data()?;
+        Ok(())
+    }
+}
+
+/// Outcome of a pre-submission signature check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureCheck {
+    /// The chain has no signature from this validator yet; submit.
+    NotRecorded,
+    /// The signature is already on-chain; skip. `cached` is true when the
+    /// journal answered without an RPC round-trip.
+    AlreadyRecorded {
+        /// Whether the journal (rather than a fresh chain query) decided.
+        cached: bool,
+    },
+}
+
+/// Skips signature submissions the chain has already recorded, remembering
+/// confirmations across restarts.
+pub struct SignatureDeduper<C> {
+    client: C,
+    journal: DedupJournal,
+    /// The local validator whose signatures are being deduplicated.
+    validator: Address,
+}
+
+impl<C: SignatureStateSource> SignatureDeduper<C> {
+    /// Creates a deduper for `validator` over the given client and replayed
+    /// journal.
+    pub fn new(client: C, journal: DedupJournal, validator: Address) -> Self {
+        Self {
+            client,
+            journal,
+            validator,
+        }
+    }
+
+    /// Decides whether the signature for `deposit_id` still needs to be
+    /// submitted. The journal is consulted first; on a miss the precompile's
+    /// view is queried and a positive answer is journaled, so each recorded
+    /// deposit costs at most one RPC round-trip across all restarts.
+    pub async fn check(&mut self, deposit_id: B256) -> eyre::Result<SignatureCheck> {
+        if self.journal.contains(deposit_id) {
+            return Ok(SignatureCheck::AlreadyRecorded { cached: true });
+        }
+
+        if self
+            .client
+            .has_signature(deposit_id, self.validator)
+            .await?
+        {
+            self.journal.record(deposit_id)?;
+            tracing::debug!(
+                target: "bridge::signature_dedup",
+                %deposit_id,
+                "signature already recorded on-chain, skipping submission"
+            );
+            return Ok(SignatureCheck::AlreadyRecorded { cached: false });
+        }
+
+        Ok(SignatureCheck::NotRecorded)
+    }
+
+    /// Drops already-recorded ids from a flushed batch, returning what is
+    /// left to submit. An empty result means the whole batch was duplicates
+    /// and no transaction should be sent.
+    pub async fn filter_batch(&mut self, batch: SignatureBatch) -> eyre::Result<SignatureBatch> {
+        let mut ids = Vec::with_capacity(batch.ids.len());
+        let mut sigs = Vec::with_capacity(batch.sigs.len());
+        for (id, sig) in batch.ids.into_iter().zip(batch.sigs) {
+            if self.check(id).await? == SignatureCheck::NotRecorded {
+                ids.push(id);
+                sigs.push(sig);
+            }
+        }
+        Ok(SignatureBatch { ids, sigs })
+    }
+
+    /// Journals `deposit_id` after the chain confirmed our own submission,
+    /// so a restart does not re-query (or re-sign) it.
+    pub fn mark_recorded(&mut self, deposit_id: B256) -> Result<(), DedupJournalError> {
+        self.journal.record(deposit_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::Bytes;
+    use std::sync::{
+        Mutex,
+        atomic::{AtomicU32, Ordering},
+    };
+
+    #[derive(Default)]
+    struct MockChain {
+        recorded: Mutex<Vec<B256>>,
+        queries: AtomicU32,
+    }
+
+    #[async_trait::async_trait]
+    impl SignatureStateSource for MockChain {
+        async fn has_signature(&self, deposit_id: B256, _validator: Address) -> eyre::Result<bool> {
+            self.queries.fetch_add(1, Ordering::SeqCst);
+            Ok(self.recorded.lock().unwrap().contains(&deposit_id))
+        }
+    }
+
+    fn temp_journal(name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "signature-dedup-{}-{name}.jsonl",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    fn deduper(client: MockChain, path: &Path) -> SignatureDeduper<MockChain> {
+        SignatureDeduper::new(
+            client,
+            DedupJournal::open(path).unwrap(),
+            Address::with_last_byte(0xAA),
+        )
+    }
+
+    #[tokio::test]
+    async fn unrecorded_signature_is_submitted() {
+        let path = temp_journal("unrecorded");
+        let mut deduper = deduper(MockChain::default(), &path);
+
+        let check = deduper.check(B256::with_last_byte(1)).await.unwrap();
+        assert_eq!(check, SignatureCheck::NotRecorded);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn recorded_signature_is_skipped_and_persisted() {
+        let path = temp_journal("recorded");
+        let deposit_id = B256::with_last_byte(2);
+        let client = MockChain::default();
+        client.recorded.lock().unwrap().push(deposit_id);
+        let mut deduper = deduper(client, &path);
+
+        // First check hits the chain and journals the confirmation.
+        let check = deduper.check(deposit_id).await.unwrap();
+        assert_eq!(check, SignatureCheck::AlreadyRecorded { cached: false });
+        assert_eq!(deduper.client.queries.load(Ordering::SeqCst), 1);
+
+        // Second check answers from the journal, no RPC.
+        let check = deduper.check(deposit_id).await.unwrap();
+        assert_eq!(check, SignatureCheck::AlreadyRecorded { cached: true });
+        assert_eq!(deduper.client.queries.load(Ordering::SeqCst), 1);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn confirmations_survive_a_restart() {
+        let path = temp_journal("restart");
+        let deposit_id = B256::with_last_byte(3);
+
+        let client = MockChain::default();
+        client.recorded.lock().unwrap().push(deposit_id);
+        let mut deduper = deduper(client, &path);
+        deduper.check(deposit_id).await.unwrap();
+
+        // A fresh deduper over the replayed journal skips without querying,
+        // even against a chain mock that has forgotten the signature.
+        let mut deduper = self::deduper(MockChain::default(), &path);
+        let check = deduper.check(deposit_id).await.unwrap();
+        assert_eq!(check, SignatureCheck::AlreadyRecorded { cached: true });
+        assert_eq!(deduper.client.queries.load(Ordering::SeqCst), 0);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn filter_batch_drops_only_duplicates() {
+        let path = temp_journal("batch");
+        let client = MockChain::default();
+        client
+            .recorded
+            .lock()
+            .unwrap()
+            .push(B256::with_last_byte(2));
+        let mut deduper = deduper(client, &path);
+
+        let batch = SignatureBatch {
+            ids: vec![
+                B256::with_last_byte(1),
+                B256::with_last_byte(2),
+                B256::with_last_byte(3),
+            ],
+            sigs: vec![
+                Bytes::from(vec![1u8; 65]),
+                Bytes::from(vec![2u8; 65]),
+                Bytes::from(vec![3u8; 65]),
+            ],
+        };
+        let filtered = deduper.filter_batch(batch).await.unwrap();
+
+        assert_eq!(
+            filtered.ids,
+            vec![B256::with_last_byte(1), B256::with_last_byte(3)]
+        );
+        assert_eq!(
+            filtered.sigs,
+            vec![Bytes::from(vec![1u8; 65]), Bytes::from(vec![3u8; 65])]
+        );
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn mark_recorded_skips_future_checks() {
+        let path = temp_journal("mark");
+        let deposit_id = B256::with_last_byte(4);
+        let mut deduper = deduper(MockChain::default(), &path);
+
+        assert_eq!(
+            deduper.check(deposit_id).await.unwrap(),
+            SignatureCheck::NotRecorded
+        );
+        deduper.mark_recorded(deposit_id).unwrap();
+
+        let check = deduper.check(deposit_id).await.unwrap();
+        assert_eq!(check, SignatureCheck::AlreadyRecorded { cached: true });
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,231 @@
+//! Deterministic per-burn submitter election across sidecars.
+//!
+//! When several validators run sidecars, every one of them observes the same
+//! burns and would race to submit the same unlock transaction, wasting gas on
+//! all but the first. The election removes the race without coordination: each
+//! burn is assigned a primary submitter by hashing the burn id onto the sorted
+//! active validator set, and responsibility rotates to the next validator after
+//! every failover interval so a crashed or partitioned primary cannot stall
+//! unlocks indefinitely. The rotation clock is anchored to the moment a sidecar
+//! first saw the burn, journaled to disk so restarts do not reset it.
+//!
+//! The election is an optimization, not a safety mechanism: the escrow contract
+//! and the [`crate::unlock_submitter`] journal still guarantee at most one
+//! effective unlock even if two sidecars disagree about whose turn it is.
+
+use alloy_primitives::{B256, U256};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs::{File, OpenOptions},
+    io::{BufRead as _, BufReader, Seek as _, SeekFrom, Write as _},
+    path::Path,
+    time::Duration,
+};
+
+use crate::unlock_submitter::UnlockJournalError;
+
+/// Deterministic mapping from burns to the validator responsible for
+/// submitting their unlock.
+///
+/// All sidecars construct the election from the same active validator set and
+/// failover interval, so they agree on the designated submitter for any burn
+/// at any point in time (up to clock skew of at most one failover interval).
+#[derive(Debug, Clone)]
+pub struct SubmitterElection {
+    /// Sorted, deduplicated validator set the burns are hashed onto.
+    validators: Vec<B256>,
+    /// This sidecar's validator key.
+    local: B256,
+    /// How long each validator holds responsibility for a burn before it
+    /// passes to the next one.
+    failover_interval: Duration,
+}
+
+impl SubmitterElection {
+    /// Creates an election over the given validator set.
+    ///
+    /// The set is sorted and deduplicated so that all sidecars derive the same
+    /// assignment regardless of the order they learned the validators in.
+    pub fn new(mut validators: Vec<B256>, local: B256, failover_interval: Duration) -> Self {
+        validators.sort_unstable();
+        validators.dedup();
+        Self {
+            validators,
+            local,
+            failover_interval,
+        }
+    }
+
+    /// The validator currently responsible for submitting the burn's unlock,
+    /// given how long the burn has been eligible for submission.
+    ///
+    /// The primary is `burn_id mod n`; every elapsed failover interval shifts
+    /// responsibility one validator further, wrapping around the set. Returns
+    /// `None` for an empty validator set.
+    pub fn designated_submitter(&self, burn_id: B256, eligible_for: Duration) -> Option<B256> {
+        if self.validators.is_empty() {
+            return None;
+        }
+
+        let n = self.validators.len();
+        let primary = (U256::from_be_bytes(burn_id.0) % U256::from(n)).to::<usize>();
+        let rotations = if self.failover_interval.is_zero() {
+            0
+        } else {
+            (eligible_for.as_secs() / self.failover_interval.as_secs().max(1)) as usize
+        };
+
+        Some(self.validators[(primary + rotations) % n])
+    }
+
+    /// Returns true if this sidecar is currently the designated submitter for
+    /// the burn.
+    pub fn is_local_turn(&self, burn_id: B256, eligible_for: Duration) -> bool {
+        self.designated_submitter(burn_id, eligible_for) == Some(self.local)
+    }
+}
+
+/// When a sidecar first saw a burn, anchoring its failover rotation clock.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BurnFirstSeen {
+    /// Burn the record is for.
+    pub burn_id: B256,
+    /// Unix timestamp (seconds) of the first observation.
+    pub first_seen_unix: u64,
+}
+
+/// Append-only journal of first-seen times, replayed on startup.
+///
+/// Same on-disk shape as the unlock journal: one JSON record per line, fsynced
+/// before use, so a restarted sidecar resumes the same rotation clock instead
+/// of treating every pending burn as freshly eligible.
+#[derive(Debug)]
+pub struct ElectionJournal {
+    file: File,
+    entries: HashMap<B256, u64>,
+}
+
+impl ElectionJournal {
+    /// Opens (or creates) the journal at `path` and replays its entries.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, UnlockJournalError> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(path)?;
+
+        let mut entries = HashMap::new();
+        for (idx, line) in BufReader::new(&mut file).lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record: BurnFirstSeen = serde_json::from_str(&line)
+                .map_err(|err| UnlockJournalError::MalformedEntry { line: idx + 1, err })?;
+            entries.insert(record.burn_id, record.first_seen_unix);
+        }
+        file.seek(SeekFrom::End(0))?;
+
+        Ok(Self { file, entries })
+    }
+
+    /// Returns the journaled first-seen time for the burn, recording (and
+    /// flushing) `now_unix` if the burn is new.
+    pub fn first_seen(&mut self, burn_id: B256, now_unix: u64) -> Result<u64, UnlockJournalError> {
+        if let Some(first_seen) = self.entries.get(&burn_id) {
+            return Ok(*first_seen);
+        }
+
+        let record = BurnFirstSeen {
+            burn_id,
+            first_seen_unix: now_unix,
+        };
+        let mut line = serde_json::to_string(&record).expect("first-seen record serializes");
+        line.push('\n');
+        self.file.write_all(line.as_bytes())?;
+        self.file.sync_data()?;
+        self.entries.insert(burn_id, now_unix);
+
+        Ok(now_unix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn election(n: u8, local: B256, failover_secs: u64) -> SubmitterElection {
+        let validators = (1..=n).map(B256::with_last_byte).collect();
+        SubmitterElection::new(validators, local, Duration::from_secs(failover_secs))
+    }
+
+    #[test]
+    fn primary_is_deterministic_across_sidecars() {
+        let burn_id = B256::with_last_byte(7);
+        let from_a =
+            election(3, B256::with_last_byte(1), 60).designated_submitter(burn_id, Duration::ZERO);
+        let from_b =
+            election(3, B256::with_last_byte(2), 60).designated_submitter(burn_id, Duration::ZERO);
+        assert_eq!(from_a, from_b);
+
+        // 7 mod 3 == 1 selects the second validator of the sorted set.
+        assert_eq!(from_a, Some(B256::with_last_byte(2)));
+    }
+
+    #[test]
+    fn failover_rotates_and_wraps() {
+        let election = election(3, B256::with_last_byte(1), 60);
+        let burn_id = B256::with_last_byte(6); // primary index 0
+
+        assert_eq!(
+            election.designated_submitter(burn_id, Duration::from_secs(59)),
+            Some(B256::with_last_byte(1))
+        );
+        assert_eq!(
+            election.designated_submitter(burn_id, Duration::from_secs(60)),
+            Some(B256::with_last_byte(2))
+        );
+        assert_eq!(
+            election.designated_submitter(burn_id, Duration::from_secs(120)),
+            Some(B256::with_last_byte(3))
+        );
+        // After a full cycle responsibility returns to the primary.
+        assert_eq!(
+            election.designated_submitter(burn_id, Duration::from_secs(180)),
+            Some(B256::with_last_byte(1))
+        );
+    }
+
+    #[test]
+    fn empty_validator_set_elects_nobody() {
+        let election =
+            SubmitterElection::new(Vec::new(), B256::with_last_byte(1), Duration::from_secs(60));
+        assert_eq!(
+            election.designated_submitter(B256::with_last_byte(1), Duration::ZERO),
+            None
+        );
+        assert!(!election.is_local_turn(B256::with_last_byte(1), Duration::ZERO));
+    }
+
+    #[test]
+    fn first_seen_survives_restart() {
+        let path = std::env::temp_dir().join(format!(
+            "election-journal-{}-first-seen.jsonl",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        let burn_id = B256::with_last_byte(9);
+
+        let mut journal = ElectionJournal::open(&path).unwrap();
+        assert_eq!(journal.first_seen(burn_id, 1000).unwrap(), 1000);
+        // A later observation does not move the anchor.
+        assert_eq!(journal.first_seen(burn_id, 2000).unwrap(), 1000);
+        drop(journal);
+
+        let mut journal = ElectionJournal::open(&path).unwrap();
+        assert_eq!(journal.first_seen(burn_id, 3000).unwrap(), 1000);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,533 @@
+//! Exactly-once unlock submission to the origin chain escrow.
+//!
+//! Unlock transactions carry the burn id as a deterministic idempotency salt,
+//! so the escrow contract itself rejects a second unlock for the same burn.
+//! That alone is not enough to avoid wasted gas or a deadlocked submitter: a
+//! crash between submit and confirm would leave the process unsure whether a
+//! transaction is already in flight. The submitter therefore journals every
+//! attempt to disk *before* broadcasting, checks `isUnlocked` on-chain and the
+//! mempool (by nonce and tx hash) on every attempt, and only re-submits once
+//! it has positive evidence the previous transaction is gone.
+
+use crate::submitter_election::{ElectionJournal, SubmitterElection};
+use alloy_primitives::B256;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs::{File, OpenOptions},
+    io::{BufRead as _, BufReader, Seek as _, SeekFrom, Write as _},
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+/// Abstraction over the origin chain RPC used for unlock submission.
+///
+/// Separate from [`crate::origin_watcher::OriginClient`] because unlocks flow
+/// in the opposite direction and need a funded submitter account.
+#[async_trait::async_trait]
+pub trait UnlockOriginClient: Send + Sync {
+    /// Returns true if the escrow has already processed an unlock for this
+    /// burn id (the contract keys unlocks by burn id).
+    async fn is_unlocked(&self, burn_id: B256) -> eyre::Result<bool>;
+
+    /// Next nonce of the submitter account, including mempool transactions.
+    async fn pending_nonce(&self) -> eyre::Result<u64>;
+
+    /// Confirmed (latest-state) nonce of the submitter account.
+    async fn confirmed_nonce(&self) -> eyre::Result<u64>;
+
+    /// Returns true if the transaction is known to the node, pending or mined.
+    async fn transaction_known(&self, tx_hash: B256) -> eyre::Result<bool>;
+
+    /// Broadcasts an unlock for `burn_id` at the given nonce, returning the
+    /// transaction hash. The burn id doubles as the on-chain idempotency salt.
+    async fn submit_unlock(&self, burn_id: B256, nonce: u64) -> eyre::Result<B256>;
+
+    /// Estimates the gas an unlock for `burn_id` would consume, without
+    /// broadcasting anything. Only called in dry-run mode.
+    async fn estimate_unlock_gas(&self, burn_id: B256) -> eyre::Result<u64>;
+}
+
+/// One journaled unlock attempt. The latest entry per burn id wins on replay.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnlockAttempt {
+    /// Burn this unlock settles.
+    pub burn_id: B256,
+    /// Nonce reserved for (or used by) the transaction.
+    pub nonce: u64,
+    /// Hash of the broadcast transaction. `None` while the attempt is only an
+    /// intent, i.e. journaled but not yet (knowingly) broadcast.
+    pub tx_hash: Option<B256>,
+}
+
+/// Errors raised while reading or writing the unlock journal.
+#[derive(Debug, thiserror::Error)]
+pub enum UnlockJournalError {
+    /// The journal file could not be read or written.
+    #[error("unlock journal io error: {0}")]
+    Io(#[from] std::io::Error),
+    /// A line is not a valid JSON record.
+    #[error("malformed journal entry on line {line}: {err}")]
+    MalformedEntry {
+        /// One-based line number.
+        line: usize,
+        /// Underlying JSON error.
+        err: serde_json::Error,
+    },
+}
+
+/// Append-only journal of unlock attempts, replayed on startup.
+///
+/// Same on-disk shape as the audit log: one JSON record per line, appended and
+/// fsynced before the corresponding network action, so the journal always
+/// knows at least as much as the chain does.
+#[derive(Debug)]
+pub struct UnlockJournal {
+    file: File,
+    entries: HashMap<B256, UnlockAttempt>,
+}
+
+impl UnlockJournal {
+    /// Opens (or creates) the journal at `path` and replays its entries.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, UnlockJournalError> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .read(true)
+            .append(true)
+            .open(path)?;
+
+        let mut entries = HashMap::new();
+        for (idx, line) in BufReader::new(&mut file).lines().enumerate() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let attempt: UnlockAttempt = serde_json::from_str(&line)
+                .map_err(|err| UnlockJournalError::MalformedEntry { line: idx + 1, err })?;
+            entries.insert(attempt.burn_id, attempt);
+        }
+        file.seek(SeekFrom::End(0))?;
+
+        Ok(Self { file, entries })
+    }
+
+    /// Returns the latest journaled attempt for the burn, if any.
+    pub fn get(&self, burn_id: B256) -> Option<&UnlockAttempt> {
+        self.entries.get(&burn_id)
+    }
+
+    /// Appends an attempt and flushes it to disk before returning.
+    pub fn record(&mut self, attempt: UnlockAttempt) -> Result<(), UnlockJournalError> {
+        let mut line = serde_json::to_string(&attempt).expect("unlock attempt serializes");
+        line.push('\n');
+        self.file.write_all(line.as_bytes())?;
+        self.file.sync_data()?;
+        self.entries.insert(attempt.burn_id, attempt);
+        Ok(())
+    }
+}
+
+/// Outcome of an idempotent unlock attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnlockStatus {
+    /// The escrow already processed this burn; nothing was submitted.
+    AlreadyUnlocked,
+    /// A previously broadcast transaction is still in flight; nothing was
+    /// submitted.
+    InFlight {
+        /// Hash of the pending transaction, if the journal recorded one before
+        /// the last shutdown.
+        tx_hash: Option<B256>,
+        /// Nonce the pending transaction occupies.
+        nonce: u64,
+    },
+    /// A transaction was broadcast (first attempt or re-submission after the
+    /// previous one was dropped).
+    Submitted {
+        /// Hash of the broadcast transaction.
+        tx_hash: B256,
+        /// Nonce it was broadcast with.
+        nonce: u64,
+    },
+    /// Dry-run mode: the unlock passed every check and would have been
+    /// broadcast; nothing was journaled or submitted.
+    WouldSubmit {
+        /// Nonce the transaction would have been broadcast with.
+        nonce: u64,
+        /// Simulated gas estimate for the unlock.
+        estimated_gas: u64,
+    },
+    /// Another validator's sidecar is currently designated to submit this
+    /// burn; nothing was submitted.
+    NotOurTurn {
+        /// The validator currently responsible, or `None` if the election has
+        /// an empty validator set.
+        submitter: Option<B256>,
+    },
+}
+
+/// Submits unlocks with exactly-once semantics across process restarts.
+pub struct UnlockSubmitter<C> {
+    client: C,
+    journal: UnlockJournal,
+    election: Option<(SubmitterElection, ElectionJournal)>,
+    dry_run: bool,
+}
+
+impl<C: UnlockOriginClient> UnlockSubmitter<C> {
+    /// Creates a submitter over the given client and replayed journal.
+    pub fn new(client: C, journal: UnlockJournal) -> Self {
+        Self {
+            client,
+            journal,
+            election: None,
+            dry_run: false,
+        }
+    }
+
+    /// Enables competing-sidecar leader election: only the validator elected
+    /// for a burn broadcasts its unlock, with time-based failover anchored to
+    /// the journaled first-seen time.
+    pub fn with_election(mut self, election: SubmitterElection, journal: ElectionJournal) -> Self {
+        self.election = Some((election, journal));
+        self
+    }
+
+    /// Enables dry-run mode: the full decision procedure runs against live
+    /// traffic, but instead of broadcasting, each would-be unlock is logged
+    /// with its simulated gas estimate. Nothing is journaled either, so a
+    /// later live run starts from a clean slate.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// Submits an unlock for `burn_id` unless it is already settled or in
+    /// flight.
+    ///
+    /// The decision procedure, in order:
+    /// 1. `isUnlocked` on-chain: the escrow is the source of truth, so a burn
+    ///    unlocked by any path (including a pre-crash submission) is final.
+    /// 2. A journaled tx hash still known to the node means the previous
+    ///    transaction is pending or mined; never double-submit.
+    /// 3. A journaled intent without a hash (crash mid-broadcast) is resolved
+    ///    via the account nonce: if the reserved nonce is still unconfirmed but
+    ///    occupied in the mempool, the broadcast likely went out, so wait
+    ///    rather than race it.
+    /// 4. If leader election is enabled and another validator is currently
+    ///    designated for this burn, defer to them rather than race.
+    /// 5. Otherwise the previous attempt is provably gone and a fresh
+    ///    transaction is journaled and broadcast — or, in dry-run mode, logged
+    ///    with its gas estimate instead.
+    pub async fn submit(&mut self, burn_id: B256) -> eyre::Result<UnlockStatus> {
+        if self.client.is_unlocked(burn_id).await? {
+            return Ok(UnlockStatus::AlreadyUnlocked);
+        }
+
+        if let Some(attempt) = self.journal.get(burn_id).copied() {
+            if let Some(tx_hash) = attempt.tx_hash {
+                if self.client.transaction_known(tx_hash).await? {
+                    return Ok(UnlockStatus::InFlight {
+                        tx_hash: Some(tx_hash),
+                        nonce: attempt.nonce,
+                    });
+                }
+            } else {
+                let confirmed = self.client.confirmed_nonce().await?;
+                let pending = self.client.pending_nonce().await?;
+                if attempt.nonce >= confirmed && pending > attempt.nonce {
+                    // Something occupies the reserved nonce in the mempool,
+                    // most likely our own broadcast whose hash we failed to
+                    // journal. Waiting is safe: either it mines (and
+                    // `isUnlocked` turns true) or it drops (and the nonce
+                    // frees up).
+                    return Ok(UnlockStatus::InFlight {
+                        tx_hash: None,
+                        nonce: attempt.nonce,
+                    });
+                }
+            }
+            tracing::warn!(
+                target: "bridge::unlock_submitter",
+                %burn_id,
+                nonce = attempt.nonce,
+                "previous unlock attempt dropped, re-submitting"
+            );
+        }
+
+        // Election gate, checked after the in-flight checks so a sidecar keeps
+        // reporting its own pending transaction even once responsibility has
+        // rotated to another validator.
+        if let Some((election, election_journal)) = self.election.as_mut() {
+            let now_unix = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs();
+            let first_seen = election_journal.first_seen(burn_id, now_unix)?;
+            let eligible_for = Duration::from_secs(now_unix.saturating_sub(first_seen));
+            if !election.is_local_turn(burn_id, eligible_for) {
+                return Ok(UnlockStatus::NotOurTurn {
+                    submitter: election.designated_submitter(burn_id, eligible_for),
+                });
+            }
+        }
+
+        let nonce = self.client.pending_nonce().await?;
+
+        if self.dry_run {
+            let estimated_gas = self.client.estimate_unlock_gas(burn_id).await?;
+            tracing::info!(
+                target: "bridge::unlock_submitter",
+                %burn_id,
+                nonce,
+                estimated_gas,
+                "dry run: would submit unlock"
+            );
+            return Ok(UnlockStatus::WouldSubmit {
+                nonce,
+                estimated_gas,
+            });
+        }
+
+        // Journal the intent before broadcasting so a crash in between leaves
+        // a record that resolves via the nonce checks above.
+        self.journal.record(UnlockAttempt {
+            burn_id,
+            nonce,
+            tx_hash: None,
+        })?;
+
+        let tx_hash = self.client.submit_unlock(burn_id, nonce).await?;
+
+        self.journal.record(UnlockAttempt {
+            burn_id,
+            nonce,
+            tx_hash: Some(tx_hash),
+        })?;
+
+        Ok(UnlockStatus::Submitted { tx_hash, nonce })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::{
+        Mutex,
+        atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering},
+    };
+
+    #[derive(Default)]
+    struct MockClient {
+        unlocked: AtomicBool,
+        confirmed_nonce: AtomicU64,
+        pending_nonce: AtomicU64,
+        known_txs: Mutex<Vec<B256>>,
+        submissions: AtomicU32,
+    }
+
+    #[async_trait::async_trait]
+    impl UnlockOriginClient for MockClient {
+        async fn is_unlocked(&self, _burn_id: B256) -> eyre::Result<bool> {
+            Ok(self.unlocked.load(Ordering::SeqCst))
+        }
+
+        async fn pending_nonce(&self) -> eyre::Result<u64> {
+            Ok(self.pending_nonce.load(Ordering::SeqCst))
+        }
+
+        async fn confirmed_nonce(&self) -> eyre::Result<u64> {
+            Ok(self.confirmed_nonce.load(Ordering::SeqCst))
+        }
+
+        async fn transaction_known(&self, tx_hash: B256) -> eyre::Result<bool> {
+            Ok(self.known_txs.lock().unwrap().contains(&tx_hash))
+        }
+
+        async fn submit_unlock(&self, burn_id: B256, nonce: u64) -> eyre::Result<B256> {
+            let count = self.submissions.fetch_add(1, Ordering::SeqCst);
+            let tx_hash = B256::with_last_byte(burn_id[31].wrapping_add(count as u8 + 1));
+            self.known_txs.lock().unwrap().push(tx_hash);
+            self.pending_nonce.store(nonce + 1, Ordering::SeqCst);
+            Ok(tx_hash)
+        }
+
+        async fn estimate_unlock_gas(&self, _burn_id: B256) -> eyre::Result<u64> {
+            Ok(90_000)
+        }
+    }
+
+    fn temp_journal(name: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!(
+            "unlock-journal-{}-{name}.jsonl",
+            std::process::id()
+        ));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+
+    #[tokio::test]
+    async fn already_unlocked_short_circuits() {
+        let path = temp_journal("unlocked");
+        let client = MockClient::default();
+        client.unlocked.store(true, Ordering::SeqCst);
+        let mut submitter = UnlockSubmitter::new(client, UnlockJournal::open(&path).unwrap());
+
+        let status = submitter.submit(B256::with_last_byte(1)).await.unwrap();
+        assert_eq!(status, UnlockStatus::AlreadyUnlocked);
+        assert_eq!(submitter.client.submissions.load(Ordering::SeqCst), 0);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn pending_transaction_is_never_resubmitted() {
+        let path = temp_journal("pending");
+        let burn_id = B256::with_last_byte(2);
+
+        let mut submitter =
+            UnlockSubmitter::new(MockClient::default(), UnlockJournal::open(&path).unwrap());
+        let status = submitter.submit(burn_id).await.unwrap();
+        let UnlockStatus::Submitted { tx_hash, nonce } = status else {
+            panic!("expected submission, got {status:?}");
+        };
+
+        // A second call (e.g. after restart with the same journal) sees the
+        // pending transaction and does nothing.
+        let journal = UnlockJournal::open(&path).unwrap();
+        assert_eq!(journal.get(burn_id).unwrap().tx_hash, Some(tx_hash));
+        let client = std::mem::take(&mut submitter.client);
+        let mut submitter = UnlockSubmitter::new(client, journal);
+        let status = submitter.submit(burn_id).await.unwrap();
+        assert_eq!(
+            status,
+            UnlockStatus::InFlight {
+                tx_hash: Some(tx_hash),
+                nonce,
+            }
+        );
+        assert_eq!(submitter.client.submissions.load(Ordering::SeqCst), 1);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn dropped_transaction_is_resubmitted_with_fresh_nonce() {
+        let path = temp_journal("dropped");
+        let burn_id = B256::with_last_byte(3);
+
+        let mut submitter =
+            UnlockSubmitter::new(MockClient::default(), UnlockJournal::open(&path).unwrap());
+        submitter.submit(burn_id).await.unwrap();
+
+        // The transaction evaporates from the mempool without mining.
+        submitter.client.known_txs.lock().unwrap().clear();
+        submitter.client.pending_nonce.store(5, Ordering::SeqCst);
+        submitter.client.confirmed_nonce.store(5, Ordering::SeqCst);
+
+        let status = submitter.submit(burn_id).await.unwrap();
+        assert!(matches!(status, UnlockStatus::Submitted { nonce: 5, .. }));
+        assert_eq!(submitter.client.submissions.load(Ordering::SeqCst), 2);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn crash_between_journal_and_broadcast_waits_on_occupied_nonce() {
+        let path = temp_journal("crash");
+        let burn_id = B256::with_last_byte(4);
+
+        // Simulate a crash after journaling the intent: entry without a hash.
+        let mut journal = UnlockJournal::open(&path).unwrap();
+        journal
+            .record(UnlockAttempt {
+                burn_id,
+                nonce: 7,
+                tx_hash: None,
+            })
+            .unwrap();
+        drop(journal);
+
+        // The broadcast did go out: nonce 7 is occupied but unconfirmed.
+        let client = MockClient::default();
+        client.confirmed_nonce.store(7, Ordering::SeqCst);
+        client.pending_nonce.store(8, Ordering::SeqCst);
+
+        let mut submitter = UnlockSubmitter::new(client, UnlockJournal::open(&path).unwrap());
+        let status = submitter.submit(burn_id).await.unwrap();
+        assert_eq!(
+            status,
+            UnlockStatus::InFlight {
+                tx_hash: None,
+                nonce: 7,
+            }
+        );
+        assert_eq!(submitter.client.submissions.load(Ordering::SeqCst), 0);
+
+        // Once the nonce is consumed by something else and the burn is still
+        // locked, the submitter proves the old attempt dead and retries.
+        submitter.client.confirmed_nonce.store(8, Ordering::SeqCst);
+        let status = submitter.submit(burn_id).await.unwrap();
+        assert!(matches!(status, UnlockStatus::Submitted { nonce: 8, .. }));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn dry_run_logs_instead_of_broadcasting() {
+        let path = temp_journal("dry-run");
+        let burn_id = B256::with_last_byte(6);
+
+        let mut submitter =
+            UnlockSubmitter::new(MockClient::default(), UnlockJournal::open(&path).unwrap())
+                .with_dry_run(true);
+
+        let status = submitter.submit(burn_id).await.unwrap();
+        assert_eq!(
+            status,
+            UnlockStatus::WouldSubmit {
+                nonce: 0,
+                estimated_gas: 90_000,
+            }
+        );
+        assert_eq!(submitter.client.submissions.load(Ordering::SeqCst), 0);
+        // Nothing was journaled: a later live run starts from a clean slate.
+        assert!(submitter.journal.get(burn_id).is_none());
+
+        // The on-chain short circuit still applies in dry-run mode.
+        submitter.client.unlocked.store(true, Ordering::SeqCst);
+        let status = submitter.submit(burn_id).await.unwrap();
+        assert_eq!(status, UnlockStatus::AlreadyUnlocked);
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn election_defers_to_designated_submitter() {
+        let path = temp_journal("election");
+        let election_path = temp_journal("election-state");
+        let local = B256::with_last_byte(1);
+        let peer = B256::with_last_byte(2);
+        let election = SubmitterElection::new(vec![local, peer], local, Duration::from_secs(3600));
+
+        let mut submitter =
+            UnlockSubmitter::new(MockClient::default(), UnlockJournal::open(&path).unwrap())
+                .with_election(election, ElectionJournal::open(&election_path).unwrap());
+
+        // A burn hashing onto the peer is left to them.
+        let burn_for_peer = B256::with_last_byte(5);
+        let status = submitter.submit(burn_for_peer).await.unwrap();
+        assert_eq!(
+            status,
+            UnlockStatus::NotOurTurn {
+                submitter: Some(peer),
+            }
+        );
+        assert_eq!(submitter.client.submissions.load(Ordering::SeqCst), 0);
+
+        // A burn hashing onto us proceeds through the normal submit path.
+        let burn_for_local = B256::with_last_byte(4);
+        let status = submitter.submit(burn_for_local).await.unwrap();
+        assert!(matches!(status, UnlockStatus::Submitted { .. }));
+        assert_eq!(submitter.client.submissions.load(Ordering::SeqCst), 1);
+
+        std::fs::remove_file(&path).unwrap();
+        std::fs::remove_file(&election_path).unwrap();
+    }
+}
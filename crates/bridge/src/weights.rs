@@ -0,0 +1,139 @@
+//! Stake-weighted signature thresholds.
+//!
+//! Signature collection originally treated every validator equally: a deposit
+//! completed once a fixed *count* of signatures arrived. With stake-weighted
+//! validator sets that is wrong in both directions — a few heavy validators
+//! may already represent a finalization quorum, and many light ones may not.
+//! This module provides the weighting primitives: a [`ValidatorWeights`]
+//! snapshot built from `(validator, weight)` pairs, and progress measured as
+//! summed weight against [`compute_threshold`]. Nothing in this tree builds
+//! the snapshot yet — the sidecar embedding
+//! [`StateManager`](crate::deposit_expiry::StateManager) is responsible for
+//! fetching weights from `ValidatorConfig` at each validator-set epoch
+//! refresh and installing them via
+//! [`StateManager::set_weights`](crate::deposit_expiry::StateManager::set_weights);
+//! until it does, completion stays count-based. The on-chain verifier applies
+//! the same rule at finalization, so the quorum arithmetic lives here in one
+//! pure function both sides agree on.
+
+use alloy_primitives::Address;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Weight a signature set must reach to finalize, for a set totalling
+/// `total_weight`: strictly more than two thirds.
+///
+/// This mirrors the BFT quorum rule used by consensus; with uniform weight 1
+/// it degenerates to the familiar `2f + 1` signature count.
+pub fn compute_threshold(total_weight: u128) -> u128 {
+    total_weight * 2 / 3 + 1
+}
+
+/// Per-validator signature weights for one validator-set epoch.
+///
+/// Validators absent from the snapshot contribute zero weight — a signature
+/// from an address `ValidatorConfig` does not know must never advance a
+/// deposit toward threshold.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ValidatorWeights {
+    weights: BTreeMap<Address, u64>,
+}
+
+impl ValidatorWeights {
+    /// Builds a snapshot from `(validator, weight)` pairs as fetched from
+    /// `ValidatorConfig`. Zero-weight entries are dropped: they can never
+    /// contribute and would only distort the total.
+    pub fn new(weights: impl IntoIterator<Item = (Address, u64)>) -> Self {
+        Self {
+            weights: weights
+                .into_iter()
+                .filter(|(_, weight)| *weight > 0)
+                .collect(),
+        }
+    }
+
+    /// Uniform weight 1 for every validator — the pre-weighting behavior.
+    pub fn uniform(validators: impl IntoIterator<Item = Address>) -> Self {
+        Self::new(validators.into_iter().map(|validator| (validator, 1)))
+    }
+
+    /// The weight of one validator's signature (zero if unknown).
+    pub fn weight_of(&self, validator: Address) -> u64 {
+        self.weights.get(&validator).copied().unwrap_or(0)
+    }
+
+    /// Total weight of the validator set.
+    pub fn total(&self) -> u128 {
+        self.weights
+            .values()
+            .map(|weight| u128::from(*weight))
+            .sum()
+    }
+
+    /// The summed weight this set must reach to finalize.
+    pub fn threshold(&self) -> u128 {
+        compute_threshold(self.total())
+    }
+
+    /// Summed weight of the given signers.
+    ///
+    /// Callers pass a deduplicated set (the state manager stores signatures
+    /// in a `BTreeSet`), so each signer is counted once.
+    pub fn signed_weight<'a>(&self, signers: impl IntoIterator<Item = &'a Address>) -> u128 {
+        signers
+            .into_iter()
+            .map(|signer| u128::from(self.weight_of(*signer)))
+            .sum()
+    }
+
+    /// Whether the given signers reach the weighted threshold.
+    pub fn is_quorum<'a>(&self, signers: impl IntoIterator<Item = &'a Address>) -> bool {
+        self.signed_weight(signers) >= self.threshold()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn validator(byte: u8) -> Address {
+        Address::repeat_byte(byte)
+    }
+
+    #[test]
+    fn threshold_is_two_thirds_plus_one() {
+        assert_eq!(compute_threshold(0), 1);
+        assert_eq!(compute_threshold(3), 3);
+        assert_eq!(compute_threshold(4), 3);
+        assert_eq!(compute_threshold(100), 67);
+        // Uniform weights reproduce the 2f + 1 signature count.
+        let set = ValidatorWeights::uniform((1..=4).map(validator));
+        assert_eq!(set.threshold(), 3);
+    }
+
+    #[test]
+    fn heavy_validators_finalize_early_and_light_ones_do_not() {
+        let set = ValidatorWeights::new([
+            (validator(1), 60),
+            (validator(2), 25),
+            (validator(3), 10),
+            (validator(4), 5),
+        ]);
+        assert_eq!(set.total(), 100);
+        assert_eq!(set.threshold(), 67);
+
+        // Two heavy validators are a quorum on their own.
+        assert!(set.is_quorum([&validator(1), &validator(2)]));
+        // Every light validator together is not.
+        assert!(!set.is_quorum([&validator(2), &validator(3), &validator(4)]));
+    }
+
+    #[test]
+    fn unknown_and_zero_weight_signers_contribute_nothing() {
+        let set = ValidatorWeights::new([(validator(1), 2), (validator(2), 0)]);
+        assert_eq!(set.total(), 2);
+        assert_eq!(set.weight_of(validator(2)), 0);
+        assert_eq!(set.weight_of(validator(9)), 0);
+        assert_eq!(set.signed_weight([&validator(2), &validator(9)]), 0);
+    }
+}
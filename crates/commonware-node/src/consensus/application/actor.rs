@@ -18,7 +18,7 @@ use std::{
 use alloy_consensus::BlockHeader;
 use alloy_primitives::{B256, Bytes};
 use alloy_rpc_types_engine::PayloadId;
-use commonware_codec::{Encode as _, ReadExt as _};
+use commonware_codec::Encode as _;
 use commonware_consensus::{
     Heightable as _,
     types::{Epoch, Epocher as _, FixedEpocher, Height, HeightDelta, Round, View},
@@ -28,7 +28,7 @@ use commonware_macros::select;
 use commonware_runtime::{
     ContextCell, FutureExt as _, Handle, Metrics as _, Pacer, Spawner, Storage, spawn_cell,
 };
-use prometheus_client::metrics::counter::Counter;
+use prometheus_client::metrics::{counter::Counter, gauge::Gauge, histogram::Histogram};
 
 use commonware_utils::{SystemTimeExt, channel::oneshot};
 use eyre::{OptionExt as _, WrapErr as _, bail, ensure, eyre};
@@ -670,6 +670,20 @@ impl Inner<Init> {
         // Keep waiting for the remaining return time, if there's anything left after building the block.
         context.sleep_until(payload_return_time).await;
 
+        let total_elapsed = propose_start.elapsed();
+        self.metrics
+            .propose_duration
+            .observe(total_elapsed.as_secs_f64());
+        if total_elapsed > self.payload_return_time {
+            self.metrics.propose_budget_exceeded.inc();
+        }
+        let header = payload.block().header();
+        if header.gas_limit() > 0 {
+            self.metrics
+                .built_block_fullness_bps
+                .set((header.gas_used() * 10_000 / header.gas_limit()) as i64);
+        }
+
         Ok(Block::from_execution_block(payload.block().clone()))
     }
 
@@ -954,8 +968,8 @@ async fn verify_header(
                 "failed getting public dkg ceremony outcome; cannot verify end \
                 of epoch block",
             )?;
-        let block_outcome = OnchainDkgOutcome::read(&mut block.header().extra_data().as_ref())
-            .wrap_err(
+        let block_outcome =
+            OnchainDkgOutcome::from_extra_data(block.header().extra_data().as_ref()).wrap_err(
                 "failed decoding extra data header as DKG ceremony \
                 outcome; cannot verify end of epoch block",
             )?;
@@ -1062,6 +1076,9 @@ async fn get_parent(
 #[derive(Clone)]
 struct Metrics {
     parent_ahead_of_local_time: Counter,
+    propose_duration: Histogram,
+    propose_budget_exceeded: Counter,
+    built_block_fullness_bps: Gauge,
 }
 
 impl Metrics {
@@ -1076,8 +1093,33 @@ impl Metrics {
             parent_ahead_of_local_time.clone(),
         );
 
+        let propose_duration =
+            Histogram::new([0.05, 0.1, 0.2, 0.3, 0.5, 0.75, 1.0, 1.5, 2.0, 3.0].into_iter());
+        context.register(
+            "propose_duration",
+            "seconds spent building a proposal, to compare against the configured proposal budget",
+            propose_duration.clone(),
+        );
+
+        let propose_budget_exceeded = Counter::default();
+        context.register(
+            "propose_budget_exceeded",
+            "number of proposals that took longer than the configured payload return time",
+            propose_budget_exceeded.clone(),
+        );
+
+        let built_block_fullness_bps = Gauge::default();
+        context.register(
+            "built_block_fullness_bps",
+            "gas used by the last built block as basis points of its gas limit",
+            built_block_fullness_bps.clone(),
+        );
+
         Self {
             parent_ahead_of_local_time,
+            propose_duration,
+            propose_budget_exceeded,
+            built_block_fullness_bps,
         }
     }
 }
@@ -97,6 +97,8 @@ pub struct Builder<TBlocker, TPeerManager> {
     pub with_subblocks: bool,
 
     pub feed_state: crate::feed::FeedStateHandle,
+    pub peer_state: crate::peer_manager::PeerStateHandle,
+    pub preconf_state: crate::preconfirmation::PreconfirmationHandle,
 }
 
 impl<TBlocker, TPeerManager> Builder<TBlocker, TPeerManager>
@@ -293,6 +295,7 @@ where
                 oracle: self.peer_manager.clone(),
                 epoch_strategy: epoch_strategy.clone(),
                 last_finalized_height,
+                peer_state: self.peer_state.clone(),
             },
         );
 
@@ -333,6 +336,7 @@ where
                 time_to_build_subblock: self.time_to_build_subblock,
                 subblock_broadcast_interval: self.subblock_broadcast_interval,
                 epoch_strategy: epoch_strategy.clone(),
+                preconf_state: self.preconf_state.clone(),
             })
         });
 
@@ -710,8 +710,8 @@ where
 
         info!("reached last block of epoch; reading DKG outcome from header");
 
-        let onchain_outcome = tempo_dkg_onchain_artifacts::OnchainDkgOutcome::read(
-            &mut block.header().extra_data().as_ref(),
+        let onchain_outcome = tempo_dkg_onchain_artifacts::OnchainDkgOutcome::from_extra_data(
+            block.header().extra_data().as_ref(),
         )
         .expect("the last block of an epoch must contain the DKG outcome");
 
@@ -876,8 +876,8 @@ where
 
         info!("found boundary block; reading DKG outcome from header");
 
-        let onchain_outcome = tempo_dkg_onchain_artifacts::OnchainDkgOutcome::read(
-            &mut block.header().extra_data().as_ref(),
+        let onchain_outcome = tempo_dkg_onchain_artifacts::OnchainDkgOutcome::from_extra_data(
+            block.header().extra_data().as_ref(),
         )
         .expect("the last block of an epoch must contain the DKG outcome");
 
@@ -1300,8 +1300,8 @@ where
             format!("failed to read header for latest boundary block number `{latest_boundary}`")
         })?;
 
-    let onchain_outcome = tempo_dkg_onchain_artifacts::OnchainDkgOutcome::read(
-        &mut boundary_header.extra_data().as_ref(),
+    let onchain_outcome = tempo_dkg_onchain_artifacts::OnchainDkgOutcome::from_extra_data(
+        boundary_header.extra_data().as_ref(),
     )
     .wrap_err("the boundary header did not contain the on-chain DKG outcome")?;
 
@@ -21,10 +21,11 @@ use commonware_consensus::{
 };
 use commonware_cryptography::{bls12381::primitives::variant::MinSig, ed25519::PublicKey};
 use commonware_macros::select;
-use commonware_runtime::{ContextCell, Handle, Spawner, spawn_cell};
+use commonware_runtime::{ContextCell, Handle, Metrics as _, Spawner, spawn_cell};
 use commonware_utils::channel::oneshot;
 use eyre::eyre;
 use futures::{FutureExt, StreamExt};
+use prometheus_client::metrics::histogram::Histogram;
 use std::{
     collections::BTreeMap,
     future::Future,
@@ -36,6 +37,7 @@ use tempo_node::{
     TempoFullNode,
     rpc::consensus::{CertifiedBlock, Event},
 };
+use tempo_primitives::tx_trace::{TxStage, stage_span};
 use tracing::{error, info_span, instrument, warn, warn_span};
 
 use super::state::FeedStateHandle;
@@ -97,9 +99,51 @@ pub(crate) struct Actor<TContext> {
     /// Pending block subscriptions keyed by round. Since finalizations
     /// must be delivered, pending subscriptions are bound by the marshal.
     pending: BTreeMap<Round, PendingSubscription>,
+    /// Timing metrics derived from observed certificates.
+    metrics: Metrics,
 }
 
-impl<TContext: Spawner> Actor<TContext> {
+/// Certificate timing metrics, for tuning consensus durations without log
+/// spelunking.
+struct Metrics {
+    /// Seconds from a block's timestamp to its notarization certificate being
+    /// observed locally — roughly proposal broadcast plus notarization
+    /// collection.
+    notarization_collection_duration: Histogram,
+    /// Seconds from a block's timestamp to its finalization certificate being
+    /// observed locally.
+    finalization_duration: Histogram,
+}
+
+impl Metrics {
+    fn init<TContext>(context: &TContext) -> Self
+    where
+        TContext: commonware_runtime::Metrics,
+    {
+        let buckets = [0.1, 0.25, 0.5, 0.75, 1.0, 1.5, 2.0, 3.0, 5.0, 10.0];
+
+        let notarization_collection_duration = Histogram::new(buckets.into_iter());
+        context.register(
+            "notarization_collection_duration",
+            "seconds from block timestamp to the notarization certificate being observed",
+            notarization_collection_duration.clone(),
+        );
+
+        let finalization_duration = Histogram::new(buckets.into_iter());
+        context.register(
+            "finalization_duration",
+            "seconds from block timestamp to the finalization certificate being observed",
+            finalization_duration.clone(),
+        );
+
+        Self {
+            notarization_collection_duration,
+            finalization_duration,
+        }
+    }
+}
+
+impl<TContext: Spawner + commonware_runtime::Metrics> Actor<TContext> {
     /// Create a new feed actor.
     ///
     /// The actor receives Activity messages via `receiver` and updates the shared `state`.
@@ -115,12 +159,15 @@ impl<TContext: Spawner> Actor<TContext> {
         state.set_epocher(epocher);
         state.set_execution_node(execution_node);
 
+        let metrics = Metrics::init(&context);
+
         Self {
             context: ContextCell::new(context),
             receiver,
             state,
             marshal,
             pending: BTreeMap::new(),
+            metrics,
         }
     }
 
@@ -172,6 +219,16 @@ impl<TContext: Spawner> Actor<TContext> {
             _ => return,
         };
 
+        // Record for safety telemetry before any filtering, so conflicting
+        // notarizations are detected even when superseded by a finalization.
+        self.state.record_certificate(
+            round.epoch().get(),
+            round.view().get(),
+            payload.0,
+            matches!(&activity, Activity::Notarization(_)),
+            now_millis(),
+        );
+
         // Prune & filter incoming activity.
         // - Incoming Finalization. Prune older subscriptions as we only care about latest information
         // - Incoming Notarization. Only accept if ahead of the latest Finalization.
@@ -198,6 +255,7 @@ impl<TContext: Spawner> Actor<TContext> {
 
     #[instrument(skip_all, fields(activity = ?activity))]
     fn handle_activity(&self, activity: FeedActivity, consensus_block: Block) {
+        let block_timestamp_millis = consensus_block.timestamp_millis();
         let block = consensus_block.into_inner().into_block();
         let (round, digest, certificate) = match activity.clone() {
             Activity::Notarization(notarization) => (
@@ -234,9 +292,13 @@ impl<TContext: Spawner> Actor<TContext> {
         // Update state and broadcast events
         match activity {
             Activity::Notarization(_) => {
+                let seen = now_millis();
+                self.metrics
+                    .notarization_collection_duration
+                    .observe(elapsed_secs(block_timestamp_millis, seen));
                 let _ = self.state.events_tx().send(Event::Notarized {
                     block: certified.clone(),
-                    seen: now_millis(),
+                    seen,
                 });
 
                 if latest_finalized_round.is_none_or(|r| r < round)
@@ -247,9 +309,21 @@ impl<TContext: Spawner> Actor<TContext> {
             }
 
             Activity::Finalization(_) => {
+                let seen = now_millis();
+                self.metrics
+                    .finalization_duration
+                    .observe(elapsed_secs(block_timestamp_millis, seen));
+
+                // Close the consensus stage of each transaction's timeline;
+                // correlated with the other stages by tx hash.
+                for tx in &certified.block.body.transactions {
+                    use alloy_consensus::transaction::TxHashRef as _;
+                    stage_span(TxStage::Consensus, *tx.tx_hash())
+                        .in_scope(|| tracing::debug!(?round, "transaction finalized"));
+                }
                 let _ = self.state.events_tx().send(Event::Finalized {
                     block: certified.clone(),
-                    seen: now_millis(),
+                    seen,
                 });
 
                 if latest_finalized_round.is_none_or(|r| r < round) {
@@ -265,6 +339,11 @@ impl<TContext: Spawner> Actor<TContext> {
     }
 }
 
+/// Seconds between a block's timestamp and a local observation time.
+fn elapsed_secs(block_timestamp_millis: u64, seen_millis: u64) -> f64 {
+    seen_millis.saturating_sub(block_timestamp_millis) as f64 / 1_000.0
+}
+
 /// Get current Unix timestamp in milliseconds.
 fn now_millis() -> u64 {
     SystemTime::now()
@@ -12,7 +12,7 @@ mod ingress;
 mod state;
 
 use commonware_consensus::types::FixedEpocher;
-use commonware_runtime::Spawner;
+use commonware_runtime::{Metrics, Spawner};
 use futures::channel::mpsc;
 use tempo_node::TempoFullNode;
 
@@ -22,7 +22,7 @@ pub(crate) use ingress::Mailbox;
 pub use state::FeedStateHandle;
 
 /// Initialize the feed actor and mailbox.
-pub(crate) fn init<TContext: Spawner>(
+pub(crate) fn init<TContext: Spawner + Metrics>(
     context: TContext,
     marshal: marshal::Mailbox,
     epocher: FixedEpocher,
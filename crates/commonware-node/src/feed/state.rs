@@ -2,8 +2,8 @@
 
 use crate::alias::marshal;
 use alloy_consensus::BlockHeader as _;
-use alloy_primitives::hex;
-use commonware_codec::{Encode, ReadExt as _};
+use alloy_primitives::{B256, hex, keccak256};
+use commonware_codec::Encode;
 use commonware_consensus::{
     marshal::Identifier,
     types::{Epoch, Epocher as _, FixedEpocher, Height, Round, View},
@@ -18,8 +18,9 @@ use tempo_dkg_onchain_artifacts::OnchainDkgOutcome;
 use tempo_node::{
     TempoFullNode,
     rpc::consensus::{
-        CertifiedBlock, ConsensusFeed, ConsensusState, Event, IdentityProofError,
-        IdentityTransition, IdentityTransitionResponse, Query, TransitionProofData,
+        CertifiedBlock, ConsensusFeed, ConsensusState, EpochSummary, EpochSummaryError,
+        EquivocationEvidence, Event, IdentityProofError, IdentityTransition,
+        IdentityTransitionResponse, Query, SafetyStatus, TransitionProofData,
     },
 };
 use tokio::sync::broadcast;
@@ -27,12 +28,26 @@ use tracing::instrument;
 
 const BROADCAST_CHANNEL_SIZE: usize = 1024;
 
+/// Maximum number of rounds for which the first notarized digest is retained
+/// for equivocation detection.
+const MAX_TRACKED_ROUNDS: usize = 4096;
+
+/// Maximum number of equivocation evidence entries retained.
+const MAX_EQUIVOCATION_EVIDENCE: usize = 32;
+
 /// Internal shared state for the feed.
 pub(super) struct FeedState {
     /// Latest notarized block.
     pub(super) latest_notarized: Option<CertifiedBlock>,
     /// Latest finalized block.
     pub(super) latest_finalized: Option<CertifiedBlock>,
+    /// First notarized digest observed per `(epoch, view)`, for equivocation
+    /// detection. Bounded to [`MAX_TRACKED_ROUNDS`], oldest rounds evicted.
+    pub(super) notarized_digests: std::collections::BTreeMap<(u64, u64), B256>,
+    /// Conflicting notarizations observed since startup, newest first.
+    pub(super) equivocations: Vec<EquivocationEvidence>,
+    /// Highest `(epoch, view)` observed via any certificate.
+    pub(super) highest_round: Option<(u64, u64)>,
 }
 
 /// Cached identity transition chain.
@@ -80,6 +95,9 @@ impl FeedStateHandle {
             state: Arc::new(RwLock::new(FeedState {
                 latest_notarized: None,
                 latest_finalized: None,
+                notarized_digests: std::collections::BTreeMap::new(),
+                equivocations: Vec::new(),
+                highest_round: None,
             })),
             marshal: Arc::new(OnceLock::new()),
             epocher: Arc::new(OnceLock::new()),
@@ -109,6 +127,57 @@ impl FeedStateHandle {
         &self.events_tx
     }
 
+    /// Record a certificate round for safety telemetry.
+    ///
+    /// For notarizations, the digest is checked against any previously
+    /// observed notarization of the same round; a mismatch is recorded as
+    /// equivocation evidence and logged at error level.
+    pub(super) fn record_certificate(
+        &self,
+        epoch: u64,
+        view: u64,
+        digest: B256,
+        is_notarization: bool,
+        seen: u64,
+    ) {
+        let mut state = self.state.write();
+        let round = (epoch, view);
+        if state.highest_round.is_none_or(|r| r < round) {
+            state.highest_round = Some(round);
+        }
+        if !is_notarization {
+            return;
+        }
+
+        match state.notarized_digests.get(&round) {
+            Some(first) if *first != digest => {
+                tracing::error!(
+                    epoch,
+                    view,
+                    first_digest = %first,
+                    second_digest = %digest,
+                    "conflicting notarizations observed for the same round"
+                );
+                let evidence = EquivocationEvidence {
+                    epoch,
+                    view,
+                    first_digest: *first,
+                    second_digest: digest,
+                    seen,
+                };
+                state.equivocations.insert(0, evidence);
+                state.equivocations.truncate(MAX_EQUIVOCATION_EVIDENCE);
+            }
+            Some(_) => {}
+            None => {
+                state.notarized_digests.insert(round, digest);
+                while state.notarized_digests.len() > MAX_TRACKED_ROUNDS {
+                    state.notarized_digests.pop_first();
+                }
+            }
+        }
+    }
+
     /// Get read access to the internal state.
     pub(super) fn read(&self) -> parking_lot::RwLockReadGuard<'_, FeedState> {
         self.state.read()
@@ -390,6 +459,31 @@ impl ConsensusFeed for FeedStateHandle {
         }
     }
 
+    async fn get_safety_status(&self) -> SafetyStatus {
+        let state = self.state.read();
+        let finalized = state.latest_finalized.as_ref();
+        let notarized = state.latest_notarized.as_ref();
+
+        // `latest_notarized` is only retained while ahead of the latest
+        // finalization, so it is the freshest observed block when present.
+        let leader = notarized
+            .or(finalized)
+            .and_then(|b| b.block.header.consensus_context)
+            .map(|ctx| hex::encode(ctx.proposer.get().as_bytes()));
+
+        SafetyStatus {
+            finalized_height: finalized.map(|b| b.block.header.number()),
+            finalized_view: finalized.map(|b| b.view),
+            notarized_height: notarized.map(|b| b.block.header.number()),
+            notarized_view: notarized.map(|b| b.view),
+            current_epoch: state.highest_round.map(|(epoch, _)| epoch),
+            current_view: state.highest_round.map(|(_, view)| view),
+            leader,
+            equivocation_observed: !state.equivocations.is_empty(),
+            equivocations: state.equivocations.clone(),
+        }
+    }
+
     async fn subscribe(&self) -> Option<broadcast::Receiver<Event>> {
         Some(self.events_tx.subscribe())
     }
@@ -464,6 +558,104 @@ impl ConsensusFeed for FeedStateHandle {
             transitions,
         })
     }
+
+    async fn get_epoch_summary(&self, epoch: u64) -> Result<EpochSummary, EpochSummaryError> {
+        let Some((mut marshal, epocher)) = self.marshal().zip(self.epocher()) else {
+            return Err(EpochSummaryError::NotReady);
+        };
+        let Some(execution) = self.execution_node.get() else {
+            return Err(EpochSummaryError::NotReady);
+        };
+
+        let epoch_id = Epoch::new(epoch);
+
+        // The last block of the previous epoch is the genesis of this epoch
+        // (height 0 for epoch 0), so the first block produced in the epoch
+        // comes one height later.
+        let boundary = match epoch_id.previous() {
+            None => Height::zero(),
+            Some(previous) => epocher
+                .last(previous)
+                .expect("fixed epocher is valid for all epochs"),
+        };
+        let first_height = boundary.next();
+        let epoch_end = epocher
+            .last(epoch_id)
+            .expect("fixed epocher is valid for all epochs");
+
+        let (latest_height, _) = marshal
+            .get_info(Identifier::Latest)
+            .await
+            .ok_or(EpochSummaryError::NotReady)?;
+        if first_height > latest_height {
+            return Err(EpochSummaryError::UnknownEpoch(epoch));
+        }
+        let complete = epoch_end <= latest_height;
+        let last_height = if complete { epoch_end } else { latest_height };
+
+        let first_finalization = marshal
+            .get_finalization(first_height)
+            .await
+            .ok_or(EpochSummaryError::PrunedData(first_height.get()))?;
+        let last_finalization = marshal
+            .get_finalization(last_height)
+            .await
+            .ok_or(EpochSummaryError::PrunedData(last_height.get()))?;
+
+        // Both finalizations lie in the same epoch, so the view delta counts
+        // every view consumed in between, including nullified ones.
+        let blocks = last_height.get() - first_height.get() + 1;
+        let first_view = first_finalization.proposal.round.view().get();
+        let last_view = last_finalization.proposal.round.view().get();
+        let views = last_view.saturating_sub(first_view) + 1;
+        let leader_skips = views.saturating_sub(blocks);
+        let participation_rate = blocks as f64 / views as f64;
+
+        let header = |height: Height| {
+            execution
+                .provider
+                .header_by_number(height.get())
+                .ok()
+                .flatten()
+                .ok_or(EpochSummaryError::PrunedData(height.get()))
+        };
+        let average_time_to_finalize_ms = if blocks > 1 {
+            let elapsed = header(last_height)?
+                .timestamp_millis()
+                .saturating_sub(header(first_height)?.timestamp_millis());
+            Some(elapsed / (blocks - 1))
+        } else {
+            None
+        };
+
+        // The DKG outcome is sealed into the epoch's last block, so it only
+        // exists once the epoch has fully finalized.
+        let dkg_outcome_hash = if complete {
+            let outcome = get_outcome(execution, &epocher, epoch).map_err(|err| match err {
+                IdentityProofError::NotReady => EpochSummaryError::NotReady,
+                IdentityProofError::PrunedData(height) => EpochSummaryError::PrunedData(height),
+                IdentityProofError::MalformedData(height) => {
+                    EpochSummaryError::MalformedData(height)
+                }
+            })?;
+            Some(keccak256(outcome.encode()))
+        } else {
+            None
+        };
+
+        Ok(EpochSummary {
+            epoch,
+            first_height: first_height.get(),
+            last_height: last_height.get(),
+            complete,
+            blocks,
+            views,
+            leader_skips,
+            participation_rate,
+            average_time_to_finalize_ms,
+            dkg_outcome_hash,
+        })
+    }
 }
 
 /// Fetch last block of epoch and decode DKG outcome.
@@ -483,6 +675,6 @@ fn get_outcome(
         .flatten()
         .ok_or(IdentityProofError::PrunedData(height.get()))?;
 
-    OnchainDkgOutcome::read(&mut header.extra_data().as_ref())
+    OnchainDkgOutcome::from_extra_data(header.extra_data().as_ref())
         .map_err(|_| IdentityProofError::MalformedData(height.get()))
 }
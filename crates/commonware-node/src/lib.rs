@@ -13,6 +13,7 @@ pub(crate) mod executor;
 pub mod feed;
 pub mod metrics;
 pub(crate) mod peer_manager;
+pub mod preconfirmation;
 pub(crate) mod utils;
 pub(crate) mod validators;
 
@@ -33,12 +34,16 @@ pub use crate::config::{
 };
 
 pub use args::{Args, PositiveDuration};
+pub use peer_manager::PeerStateHandle;
+pub use preconfirmation::PreconfirmationHandle;
 
 pub async fn run_consensus_stack(
     context: &commonware_runtime::tokio::Context,
     config: Args,
     execution_node: TempoFullNode,
     feed_state: feed::FeedStateHandle,
+    peer_state: PeerStateHandle,
+    preconf_state: PreconfirmationHandle,
 ) -> eyre::Result<()> {
     let share = config
         .signing_share
@@ -114,6 +119,8 @@ pub async fn run_consensus_stack(
         with_subblocks: false,
 
         feed_state,
+        peer_state,
+        preconf_state,
     }
     .try_init(context.with_label("engine"))
     .await
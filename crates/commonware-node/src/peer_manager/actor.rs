@@ -1,7 +1,6 @@
 use std::{pin::Pin, time::Duration};
 
 use alloy_consensus::{BlockHeader as _, Sealable as _};
-use commonware_codec::ReadExt as _;
 use commonware_consensus::{
     marshal::Update,
     types::{Epocher, FixedEpocher, Height},
@@ -41,6 +40,7 @@ where
     execution_node: TempoFullNode,
     epoch_strategy: FixedEpocher,
     last_finalized_height: Height,
+    peer_state: super::PeerStateHandle,
     mailbox: mpsc::UnboundedReceiver<MessageWithCause>,
 
     peers: Gauge,
@@ -62,6 +62,7 @@ where
             execution_node,
             epoch_strategy,
             last_finalized_height,
+            peer_state,
         }: super::Config<TPeerManager>,
         mailbox: mpsc::UnboundedReceiver<MessageWithCause>,
     ) -> Self {
@@ -79,6 +80,7 @@ where
             execution_node,
             epoch_strategy,
             last_finalized_height,
+            peer_state,
             mailbox,
             peers,
             last_tracked_peer_set: None,
@@ -169,11 +171,14 @@ where
             .unwrap_or(self.last_finalized_height.get())
             .max(self.last_finalized_height.get());
 
-        // Short circuit - no need to read the same state if there is no new data.
-        if self
-            .last_tracked_peer_set
-            .as_ref()
-            .is_some_and(|tracked| tracked.height >= highest_finalized)
+        // Short circuit - no need to read the same state if there is no new
+        // data, unless the blocked set changed and must be applied now.
+        let blocked_changed = self.peer_state.take_blocked_dirty();
+        if !blocked_changed
+            && self
+                .last_tracked_peer_set
+                .as_ref()
+                .is_some_and(|tracked| tracked.height >= highest_finalized)
         {
             return Ok(());
         }
@@ -208,7 +213,7 @@ where
                 .wrap_err("failed reading highest finalized header")?;
 
         let onchain_outcome =
-            OnchainDkgOutcome::read(&mut latest_boundary_header.extra_data().as_ref())
+            OnchainDkgOutcome::from_extra_data(latest_boundary_header.extra_data().as_ref())
                 .wrap_err_with(|| {
                     format!(
                         "boundary block at `{latest_boundary}` did not contain a valid DKG outcome"
@@ -229,6 +234,10 @@ where
         )
         .wrap_err("unable to read initial peer set from execution layer")?;
 
+        // Operator-blocked peers are excluded before registration, so the P2P
+        // layer never dials or accepts them.
+        let peers = self.peer_state.filter_blocked(peers);
+
         self.track_or_overwrite(highest_finalized_header.number(), peers)
             .await;
 
@@ -257,6 +266,8 @@ where
         // Always bump the last-tracked peer set. If the peers are unchanged
         // this only updates the height, but we use the height to determine if
         // state should be read or not.
+        self.peer_state
+            .record_tracked(height, &peers, self.context.current());
         self.last_tracked_peer_set
             .replace(LastTrackedPeerSet { height, peers });
 
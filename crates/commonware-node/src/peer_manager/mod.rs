@@ -35,9 +35,11 @@ use tempo_node::TempoFullNode;
 
 mod actor;
 mod ingress;
+mod state;
 
 pub(crate) use actor::Actor;
 pub(crate) use ingress::Mailbox;
+pub use state::PeerStateHandle;
 
 /// Configuration of the peer manager actor.
 pub(crate) struct Config<TOracle> {
@@ -52,6 +54,8 @@ pub(crate) struct Config<TOracle> {
     /// Used during start to determine the correct boundary block, since
     /// the execution layer may be behind.
     pub(crate) last_finalized_height: Height,
+    /// Shared state read by the admin RPC (peer snapshot, blocked set).
+    pub(crate) peer_state: PeerStateHandle,
 }
 
 /// Initializes a peer manager actor from a `config` with runtime `context`.
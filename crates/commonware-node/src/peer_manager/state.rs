@@ -0,0 +1,199 @@
+//! Shared peer state exposed to the admin RPC.
+//!
+//! The peer manager actor records every peer set it registers into a
+//! [`PeerStateHandle`]; RPC handlers read the snapshot and toggle the blocked
+//! set through the [`PeerFeed`] implementation. This mirrors how
+//! [`crate::feed::FeedStateHandle`] bridges consensus state to the execution
+//! layer's RPC server without coupling the actors to jsonrpsee.
+
+use std::{
+    collections::{BTreeMap, HashSet},
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use alloy_primitives::B256;
+use commonware_cryptography::ed25519::PublicKey;
+use commonware_utils::ordered;
+use parking_lot::RwLock;
+use tempo_node::rpc::peers::{PeerFeed, PeerInfo, PeersSnapshot};
+
+use crate::utils::public_key_to_b256;
+
+/// What the manager knows about a single peer.
+#[derive(Default)]
+struct TrackedPeer {
+    /// Last registered P2P address.
+    address: String,
+    /// Whether the peer is in the currently tracked peer set.
+    tracked: bool,
+    /// Unix timestamp (seconds) of the last peer set refresh including it.
+    last_seen: Option<u64>,
+}
+
+#[derive(Default)]
+struct PeerState {
+    /// Height the tracked peer set was derived from.
+    height: u64,
+    /// Everything the manager has ever tracked, keyed by public key.
+    peers: BTreeMap<B256, TrackedPeer>,
+    /// Peers excluded from registered peer sets.
+    blocked: HashSet<B256>,
+    /// Set when the blocked set changed since the last refresh, so the actor
+    /// re-registers the peer set even without new finalized state.
+    blocked_dirty: bool,
+}
+
+/// Cloneable handle to the peer manager's shared state.
+#[derive(Clone, Default)]
+pub struct PeerStateHandle {
+    inner: Arc<RwLock<PeerState>>,
+}
+
+impl PeerStateHandle {
+    /// Creates an empty handle.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the peer set registered at `height`, stamping `now` as the
+    /// last-seen time of every member.
+    pub(super) fn record_tracked(
+        &self,
+        height: u64,
+        peers: &ordered::Map<PublicKey, commonware_p2p::Address>,
+        now: SystemTime,
+    ) {
+        let now_secs = now.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+
+        let mut state = self.inner.write();
+        state.height = height;
+        for peer in state.peers.values_mut() {
+            peer.tracked = false;
+        }
+        for (key, address) in peers.iter() {
+            let entry = state.peers.entry(public_key_to_b256(key)).or_default();
+            entry.address = format!("{address:?}");
+            entry.tracked = true;
+            entry.last_seen = Some(now_secs);
+        }
+    }
+
+    /// Removes blocked peers from a freshly read peer set.
+    pub(super) fn filter_blocked(
+        &self,
+        peers: ordered::Map<PublicKey, commonware_p2p::Address>,
+    ) -> ordered::Map<PublicKey, commonware_p2p::Address> {
+        let state = self.inner.read();
+        if state.blocked.is_empty() {
+            return peers;
+        }
+        ordered::Map::from_iter_dedup(
+            peers
+                .iter()
+                .filter(|(key, _)| !state.blocked.contains(&public_key_to_b256(key)))
+                .map(|(key, address)| (key.clone(), address.clone())),
+        )
+    }
+
+    /// Returns true (once) if the blocked set changed since the last refresh.
+    pub(super) fn take_blocked_dirty(&self) -> bool {
+        std::mem::take(&mut self.inner.write().blocked_dirty)
+    }
+}
+
+impl PeerFeed for PeerStateHandle {
+    async fn peers(&self) -> PeersSnapshot {
+        let state = self.inner.read();
+        let peers = state
+            .peers
+            .iter()
+            .map(|(public_key, peer)| PeerInfo {
+                public_key: *public_key,
+                address: peer.address.clone(),
+                tracked: peer.tracked,
+                blocked: state.blocked.contains(public_key),
+                last_seen: peer.last_seen,
+            })
+            .collect();
+        PeersSnapshot {
+            height: state.height,
+            peers,
+        }
+    }
+
+    async fn set_blocked(&self, public_key: B256, blocked: bool) -> bool {
+        let mut state = self.inner.write();
+        let changed = if blocked {
+            state.blocked.insert(public_key)
+        } else {
+            state.blocked.remove(&public_key)
+        };
+        if changed {
+            state.blocked_dirty = true;
+        }
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use commonware_cryptography::ed25519::PrivateKey;
+
+    fn peer_set(keys: &[PublicKey]) -> ordered::Map<PublicKey, commonware_p2p::Address> {
+        let socket: std::net::SocketAddr = "127.0.0.1:9000".parse().unwrap();
+        let address = commonware_p2p::Address::Asymmetric {
+            ingress: commonware_p2p::Ingress::Socket(socket),
+            egress: socket,
+        };
+        ordered::Map::from_iter_dedup(keys.iter().map(|k| (k.clone(), address.clone())))
+    }
+
+    #[tokio::test]
+    async fn blocked_peers_are_filtered_and_reported() {
+        let keys: Vec<PublicKey> = (0..3)
+            .map(|i| PublicKey::from(PrivateKey::from_seed(i)))
+            .collect();
+        let handle = PeerStateHandle::new();
+        let peers = peer_set(&keys);
+
+        handle.record_tracked(
+            7,
+            &peers,
+            UNIX_EPOCH + std::time::Duration::from_secs(1_000),
+        );
+
+        // Nothing blocked: the set passes through untouched.
+        assert_eq!(handle.filter_blocked(peers.clone()).len(), 3);
+
+        let blocked_key = public_key_to_b256(&keys[1]);
+        assert!(handle.set_blocked(blocked_key, true).await);
+        // Idempotent: blocking again reports no change.
+        assert!(!handle.set_blocked(blocked_key, true).await);
+        assert!(handle.take_blocked_dirty());
+        assert!(!handle.take_blocked_dirty());
+
+        assert_eq!(handle.filter_blocked(peers).len(), 2);
+
+        let snapshot = handle.peers().await;
+        assert_eq!(snapshot.height, 7);
+        assert_eq!(snapshot.peers.len(), 3);
+        let blocked: Vec<_> = snapshot.peers.iter().filter(|p| p.blocked).collect();
+        assert_eq!(blocked.len(), 1);
+        assert_eq!(blocked[0].public_key, blocked_key);
+        assert_eq!(blocked[0].last_seen, Some(1_000));
+
+        assert!(handle.set_blocked(blocked_key, false).await);
+        assert_eq!(
+            handle
+                .peers()
+                .await
+                .peers
+                .iter()
+                .filter(|p| p.blocked)
+                .count(),
+            0
+        );
+    }
+}
@@ -0,0 +1,127 @@
+//! Shared pre-confirmation state exposed to the proposer RPC.
+//!
+//! The subblocks actor records the transactions of the subblock it is
+//! currently building into a [`PreconfirmationHandle`]; the RPC handler signs
+//! acknowledgements on demand through the [`PreConfirmationFeed`]
+//! implementation. This mirrors how [`crate::peer_manager::PeerStateHandle`]
+//! bridges consensus state to the execution layer's RPC server without
+//! coupling the actors to jsonrpsee.
+
+use std::{
+    collections::HashSet,
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use alloy_primitives::{B256, BlockHash, Bytes, TxHash};
+use commonware_cryptography::{Signer as _, ed25519::PrivateKey};
+use parking_lot::RwLock;
+use tempo_node::rpc::preconfirmation::PreConfirmationFeed;
+use tempo_primitives::subblock::{PreConfirmation, SignedPreConfirmation};
+
+#[derive(Default)]
+struct PreconfirmationState {
+    /// Consensus signer, installed once by the subblocks actor. `None` until
+    /// the actor starts (and forever on nodes without a subblock builder).
+    signer: Option<PrivateKey>,
+    /// Parent block of the subblock currently being built.
+    parent_hash: Option<BlockHash>,
+    /// Transactions included in that subblock.
+    included: HashSet<TxHash>,
+}
+
+/// Cloneable handle to the subblock builder's pre-confirmation state.
+#[derive(Clone, Default)]
+pub struct PreconfirmationHandle {
+    inner: Arc<RwLock<PreconfirmationState>>,
+}
+
+impl PreconfirmationHandle {
+    /// Creates an empty handle.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Installs the validator signer used to sign acknowledgements.
+    pub(crate) fn set_signer(&self, signer: PrivateKey) {
+        self.inner.write().signer = Some(signer);
+    }
+
+    /// Records the transactions of the freshly built subblock on top of
+    /// `parent_hash`, replacing whatever was recorded before.
+    pub(crate) fn record_built(
+        &self,
+        parent_hash: BlockHash,
+        transactions: impl IntoIterator<Item = TxHash>,
+    ) {
+        let mut state = self.inner.write();
+        state.parent_hash = Some(parent_hash);
+        state.included = transactions.into_iter().collect();
+    }
+
+    /// Clears the recorded subblock, e.g. when the tip moved past its parent
+    /// and a new subblock is being built.
+    pub(crate) fn clear(&self) {
+        let mut state = self.inner.write();
+        state.parent_hash = None;
+        state.included.clear();
+    }
+}
+
+impl PreConfirmationFeed for PreconfirmationHandle {
+    async fn preconfirm(&self, tx_hash: B256) -> Option<SignedPreConfirmation> {
+        let state = self.inner.read();
+        let signer = state.signer.as_ref()?;
+        let parent_hash = state.parent_hash?;
+        if !state.included.contains(&tx_hash) {
+            return None;
+        }
+
+        let preconfirmation = PreConfirmation {
+            tx_hash,
+            parent_hash,
+            validator: B256::from_slice(&signer.public_key()),
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap_or_default()
+                .as_secs(),
+        };
+        // Same empty namespace as subblock signatures, domain-separated by the
+        // pre-confirmation magic byte inside the signature hash.
+        let signature = signer.sign(&[], preconfirmation.signature_hash().as_slice());
+
+        Some(SignedPreConfirmation {
+            preconfirmation,
+            signature: Bytes::copy_from_slice(signature.as_ref()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn preconfirms_only_recorded_transactions() {
+        let handle = PreconfirmationHandle::new();
+        let tx_hash = TxHash::random();
+        let parent_hash = BlockHash::random();
+
+        // No signer installed yet.
+        assert!(futures::executor::block_on(handle.preconfirm(tx_hash)).is_none());
+
+        handle.set_signer(PrivateKey::from_seed(42));
+        handle.record_built(parent_hash, [tx_hash]);
+
+        let signed = futures::executor::block_on(handle.preconfirm(tx_hash))
+            .expect("recorded transaction should be acknowledged");
+        assert_eq!(signed.preconfirmation.tx_hash, tx_hash);
+        assert_eq!(signed.preconfirmation.parent_hash, parent_hash);
+        assert!(signed.verify(), "acknowledgement must verify offline");
+
+        // Unknown transactions and cleared state are not acknowledged.
+        assert!(futures::executor::block_on(handle.preconfirm(TxHash::random())).is_none());
+        handle.clear();
+        assert!(futures::executor::block_on(handle.preconfirm(tx_hash)).is_none());
+    }
+}
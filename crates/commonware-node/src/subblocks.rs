@@ -60,6 +60,7 @@ pub(crate) struct Config<TContext> {
     pub(crate) time_to_build_subblock: Duration,
     pub(crate) subblock_broadcast_interval: Duration,
     pub(crate) epoch_strategy: FixedEpocher,
+    pub(crate) preconf_state: crate::preconfirmation::PreconfirmationHandle,
 }
 
 /// Task managing collected subblocks.
@@ -98,6 +99,8 @@ pub(crate) struct Actor<TContext> {
     subblock_broadcast_interval: Duration,
     /// The epoch strategy used by tempo.
     epoch_strategy: FixedEpocher,
+    /// Shared pre-confirmation state read by the proposer RPC.
+    preconf_state: crate::preconfirmation::PreconfirmationHandle,
 
     /// Current consensus tip. Includes highest observed round, digest and certificate.
     consensus_tip: Option<(Round, BlockHash, Certificate<MinSig>)>,
@@ -119,8 +122,10 @@ impl<TContext: Spawner + Metrics + Pacer> Actor<TContext> {
             time_to_build_subblock,
             subblock_broadcast_interval,
             epoch_strategy,
+            preconf_state,
         }: Config<TContext>,
     ) -> Self {
+        preconf_state.set_signer(signer.clone());
         let (actions_tx, actions_rx) = mpsc::unbounded();
         Self {
             our_subblock: PendingSubblock::None,
@@ -135,6 +140,7 @@ impl<TContext: Spawner + Metrics + Pacer> Actor<TContext> {
             time_to_build_subblock,
             subblock_broadcast_interval,
             epoch_strategy,
+            preconf_state,
             consensus_tip: None,
             subblocks: Default::default(),
             subblock_transactions: Default::default(),
@@ -366,6 +372,9 @@ impl<TContext: Spawner + Metrics + Pacer> Actor<TContext> {
                 .instrument(span)
             });
 
+        // The previously built subblock is stale once we start building for a
+        // new parent or proposer, so stop acknowledging its transactions.
+        self.preconf_state.clear();
         self.our_subblock = PendingSubblock::Task(BuildSubblockTask {
             handle,
             parent_hash,
@@ -478,6 +487,11 @@ impl<TContext: Spawner + Metrics + Pacer> Actor<TContext> {
             return;
         }
 
+        self.preconf_state.record_built(
+            subblock.parent_hash,
+            subblock.transactions.iter().map(|tx| *tx.tx_hash()),
+        );
+
         self.our_subblock = PendingSubblock::Built(BuiltSubblock {
             subblock,
             proposer: next_proposer,
@@ -2,8 +2,8 @@
 
 pub use IAccountKeychain::{
     IAccountKeychainErrors as AccountKeychainError, IAccountKeychainEvents as AccountKeychainEvent,
-    authorizeKey_0Call as legacyAuthorizeKeyCall, authorizeKey_1Call as authorizeKeyCall,
-    getAllowedCallsReturn, getRemainingLimitWithPeriodCall,
+    authorizeKey_0Call as legacyAuthorizeKeyCall, authorizeKey_1Call as t3AuthorizeKeyCall,
+    authorizeKey_2Call as authorizeKeyCall, getAllowedCallsReturn, getRemainingLimitWithPeriodCall,
     getRemainingLimitWithPeriodReturn as getRemainingLimitReturn,
 };
 
@@ -56,6 +56,18 @@ crate::sol! {
             SelectorRule[] selectorRules;
         }
 
+        /// Key restrictions structure used by the T3-era `authorizeKey` overload,
+        /// before T4 added `maxUses`.
+        struct LegacyKeyRestrictions {
+            uint64 expiry;
+            bool enforceLimits;
+            TokenLimit[] limits;
+            /// `true` means the key is unrestricted and `allowedCalls` must be empty.
+            /// `false` means `allowedCalls` defines the full call scope (including deny-all with `[]`).
+            bool allowAnyCalls;
+            CallScope[] allowedCalls;
+        }
+
         /// Optional access-key restrictions configured at authorization time.
         struct KeyRestrictions {
             uint64 expiry;
@@ -65,6 +77,15 @@ crate::sol! {
             /// `false` means `allowedCalls` defines the full call scope (including deny-all with `[]`).
             bool allowAnyCalls;
             CallScope[] allowedCalls;
+            /// Maximum number of transactions this key may sign. `0` means unlimited.
+            uint64 maxUses;
+        }
+
+        /// One key to provision within a batched `authorizeKeys` call.
+        struct KeyProvision {
+            address keyId;
+            SignatureType signatureType;
+            KeyRestrictions config;
         }
 
         /// Key information structure
@@ -105,12 +126,29 @@ crate::sol! {
         /// @param keyId The key identifier (address derived from public key)
         /// @param signatureType 0: secp256k1, 1: P256, 2: WebAuthn
         /// @param config Access-key expiry and optional limits / call restrictions
+        function authorizeKey(
+            address keyId,
+            SignatureType signatureType,
+            LegacyKeyRestrictions calldata config
+        ) external;
+
+        /// Authorize a new key for the caller's account (T4+).
+        /// @dev Extends the T3 overload with transaction use limits (`config.maxUses`).
+        /// @param keyId The key identifier (address derived from public key)
+        /// @param signatureType 0: secp256k1, 1: P256, 2: WebAuthn
+        /// @param config Access-key expiry and optional limits / call / use restrictions
         function authorizeKey(
             address keyId,
             SignatureType signatureType,
             KeyRestrictions calldata config
         ) external;
 
+        /// Authorize several keys for the caller's account in one call (T4+).
+        /// @dev Each entry is validated exactly like `authorizeKey` and emits its own
+        ///      `KeyAuthorized` event; any failing entry reverts the whole batch.
+        /// @param keys Keys to provision, at most 16 per call, with unique `keyId`s
+        function authorizeKeys(KeyProvision[] calldata keys) external;
+
         /// Revoke an authorized key
         /// @param publicKey The public key to revoke
         function revokeKey(address keyId) external;
@@ -176,6 +214,16 @@ crate::sol! {
             address keyId
         ) external view returns (bool isScoped, CallScope[] memory scopes);
 
+        /// Get the remaining transaction uses for a use-limited key.
+        /// @param account The account address
+        /// @param keyId The key identifier
+        /// @return limited Whether the key is use-limited
+        /// @return remaining Remaining transaction uses (0 for unlimited keys)
+        function getRemainingUses(
+            address account,
+            address keyId
+        ) external view returns (bool limited, uint64 remaining);
+
         /// Get the key used in the current transaction
         /// @return The keyId used in the current transaction
         function getTransactionKey() external view returns (address);
@@ -194,7 +242,11 @@ crate::sol! {
         error SignatureTypeMismatch(uint8 expected, uint8 actual);
         error CallNotAllowed();
         error InvalidCallScope();
+        error InvalidMaxUses();
+        error KeyUsageExhausted();
+        error InvalidKeyBatch();
         error LegacyAuthorizeKeySelectorChanged(bytes4 newSelector);
+        error InvalidMultisigAuthorization();
     }
 }
 
@@ -266,6 +318,28 @@ impl AccountKeychainError {
         Self::InvalidCallScope(IAccountKeychain::InvalidCallScope {})
     }
 
+    /// Creates an error for use limits configured before they are active.
+    pub const fn invalid_max_uses() -> Self {
+        Self::InvalidMaxUses(IAccountKeychain::InvalidMaxUses {})
+    }
+
+    /// Creates an error for a use-limited key with no remaining transaction uses.
+    pub const fn key_usage_exhausted() -> Self {
+        Self::KeyUsageExhausted(IAccountKeychain::KeyUsageExhausted {})
+    }
+
+    /// Creates an error for a malformed `authorizeKeys` batch (empty, oversized, or
+    /// containing duplicate key ids).
+    pub const fn invalid_key_batch() -> Self {
+        Self::InvalidKeyBatch(IAccountKeychain::InvalidKeyBatch {})
+    }
+
+    /// Creates an error for a multisig root authorization that is malformed or does
+    /// not derive to the claimed account address.
+    pub const fn invalid_multisig_authorization() -> Self {
+        Self::InvalidMultisigAuthorization(IAccountKeychain::InvalidMultisigAuthorization {})
+    }
+
     /// Creates an error for the legacy authorize-key selector being unavailable on T3+.
     pub fn legacy_authorize_key_selector_changed(new_selector: [u8; 4]) -> Self {
         Self::LegacyAuthorizeKeySelectorChanged(
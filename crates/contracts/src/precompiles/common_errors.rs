@@ -2,4 +2,9 @@ crate::sol! {
     /// Error returned when a function selector is not recognized
     #[derive(Debug, PartialEq, Eq)]
     error UnknownFunctionSelector(bytes4 selector);
+
+    /// Error returned when a guarded precompile entrypoint is re-entered
+    /// within the same transaction
+    #[derive(Debug, PartialEq, Eq)]
+    error ReentrantCall();
 }
@@ -0,0 +1,15 @@
+crate::sol! {
+    /// ERC-165 standard interface detection.
+    ///
+    /// Every ABI-dispatched Tempo precompile answers `supportsInterface` from
+    /// T4 onwards, so on-chain contracts and off-chain tools can feature-detect
+    /// precompile capabilities across hardforks.
+    #[derive(Debug, PartialEq, Eq)]
+    #[sol(abi)]
+    interface IERC165 {
+        /// Query if a contract implements an interface.
+        /// @param interfaceID The interface identifier, as specified in ERC-165
+        /// @return supported True if the contract implements `interfaceID`
+        function supportsInterface(bytes4 interfaceID) external view returns (bool supported);
+    }
+}
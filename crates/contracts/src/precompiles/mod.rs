@@ -1,6 +1,7 @@
 pub mod account_keychain;
 pub mod address_registry;
 pub mod common_errors;
+pub mod erc165;
 pub mod nonce;
 pub mod signature_verifier;
 pub mod stablecoin_dex;
@@ -15,6 +16,7 @@ pub use account_keychain::*;
 pub use address_registry::*;
 use alloy_primitives::{Address, address};
 pub use common_errors::*;
+pub use erc165::*;
 pub use nonce::*;
 pub use signature_verifier::*;
 pub use stablecoin_dex::*;
@@ -43,3 +45,5 @@ pub const ADDRESS_REGISTRY_ADDRESS: Address =
     address!("0xFDC0000000000000000000000000000000000000");
 pub const SIGNATURE_VERIFIER_ADDRESS: Address =
     address!("0x5165300000000000000000000000000000000000");
+/// RIP-7212 `P256VERIFY` precompile address.
+pub const P256_VERIFY_ADDRESS: Address = address!("0x0000000000000000000000000000000000000100");
@@ -15,6 +15,17 @@ crate::sol! {
         /// @return nonce The current nonce value
         function getNonce(address account, uint256 nonceKey) external view returns (uint64 nonce);
 
+        /// Get the recorded expiry of an expiring-nonce transaction (+T4)
+        ///
+        /// Lets wallets implement safe fee-retry: a replacement with a higher
+        /// fee is only safe to broadcast once the original hash no longer
+        /// blocks replay (expiry of 0, or a timestamp in the past).
+        /// @param expiringNonceHash The expiring nonce hash
+        ///        (`keccak256(encode_for_signing || sender)`, fee-payer invariant)
+        /// @return expiry The recorded `valid_before` timestamp, or 0 if the
+        ///         hash is unseen or its entry has been evicted
+        function getExpiry(bytes32 expiringNonceHash) external view returns (uint64 expiry);
+
         // Events
         event NonceIncremented(address indexed account, uint256 indexed nonceKey, uint64 newNonce);
 
@@ -51,6 +51,40 @@ crate::sol! {
             int16 bestAskTick;
         }
 
+        /// Per-pair fee configuration (T4+).
+        ///
+        /// `isDefault` is true for pairs that have never been configured by
+        /// governance and therefore trade at the default tier.
+        struct PairConfig {
+            uint16 feeBps;
+            bool isDefault;
+        }
+
+        /// A maker's liquidity position on one book (T4+).
+        ///
+        /// `restingLiquidity` is the base-denominated sum of the maker's open
+        /// order remainders; pending fees are earned but not yet credited to
+        /// the maker's DEX balance.
+        struct MakerPosition {
+            uint128 restingLiquidity;
+            uint128 pendingBaseFees;
+            uint128 pendingQuoteFees;
+        }
+
+        /// An off-chain signed limit order (T4+), submitted by a relayer.
+        ///
+        /// Hashed per EIP-712 and signed by `maker`; the order executes
+        /// against the maker's balance regardless of who submits it.
+        struct SignedOrder {
+            address maker;
+            address token;
+            uint128 amount;
+            bool isBid;
+            int16 tick;
+            uint256 nonce;
+            uint256 deadline;
+        }
+
         // Core Trading Functions
         function createPair(address base) external returns (bytes32 key);
         function place(address token, uint128 amount, bool isBid, int16 tick) external returns (uint128 orderId);
@@ -58,12 +92,21 @@ crate::sol! {
         function cancel(uint128 orderId) external;
         function cancelStaleOrder(uint128 orderId) external;
 
+        // Order expiry (T4+)
+        function placeWithExpiry(address token, uint128 amount, bool isBid, int16 tick, uint64 expiry) external returns (uint128 orderId);
+        function expireOrders(uint128[] calldata orderIds) external;
+        function orderExpiry(uint128 orderId) external view returns (uint64);
+
         // Swap Functions
         function swapExactAmountIn(address tokenIn, address tokenOut, uint128 amountIn, uint128 minAmountOut) external returns (uint128 amountOut);
         function swapExactAmountOut(address tokenIn, address tokenOut, uint128 amountOut, uint128 maxAmountIn) external returns (uint128 amountIn);
         function quoteSwapExactAmountIn(address tokenIn, address tokenOut, uint128 amountIn) external view returns (uint128 amountOut);
         function quoteSwapExactAmountOut(address tokenIn, address tokenOut, uint128 amountOut) external view returns (uint128 amountIn);
 
+        // Quote-and-execute swap API (T4+)
+        function swapWithMinOut(address tokenIn, address tokenOut, uint128 amountIn, uint128 minAmountOut, uint64 deadline) external returns (uint128 amountOut);
+        function quote(address tokenIn, address tokenOut, uint128 amountIn) external view returns (uint128 amountOut);
+
         // Balance Management
         function balanceOf(address user, address token) external view returns (uint128);
         function withdraw(address token, uint128 amount) external;
@@ -72,10 +115,26 @@ crate::sol! {
         function getOrder(uint128 orderId) external view returns (Order memory);
 
         function getTickLevel(address base, int16 tick, bool isBid) external view returns (uint128 head, uint128 tail, uint128 totalLiquidity);
+        function consultTwap(address tokenA, address tokenB, uint32 window) external view returns (uint256 price);
         function pairKey(address tokenA, address tokenB) external pure returns (bytes32);
         function nextOrderId() external view returns (uint128);
         function books(bytes32 pairKey) external view returns (Orderbook memory);
 
+        // Fee configuration (T4+)
+        function getPairConfig(bytes32 pairKey) external view returns (PairConfig memory);
+        function setPairFee(bytes32 pairKey, uint16 feeBps) external;
+        function collectedFees(address token) external view returns (uint128);
+
+        // LP accounting (T4+)
+        function positionOf(address maker, address base) external view returns (MakerPosition memory);
+        function accruedFees(address maker, address base) external view returns (uint128 baseFees, uint128 quoteFees);
+        function collectFees(address base) external;
+
+        // Gasless orders (T4+)
+        function orderNonces(address maker) external view returns (uint256);
+        function placeWithSignature(SignedOrder calldata order, uint8 v, bytes32 r, bytes32 s) external returns (uint128 orderId);
+        function invalidateOrderNonce(uint256 newNonce) external;
+
         // Constants (exposed as view functions)
         function MIN_TICK() external pure returns (int16);
         function MAX_TICK() external pure returns (int16);
@@ -94,6 +153,10 @@ crate::sol! {
         event OrderPlaced(uint128 indexed orderId, address indexed maker, address indexed token, uint128 amount, bool isBid, int16 tick, bool isFlipOrder, int16 flipTick);
         event OrderFilled(uint128 indexed orderId, address indexed maker, address indexed taker, uint128 amountFilled, bool partialFill);
         event OrderCancelled(uint128 indexed orderId);
+        event PairFeeUpdated(bytes32 indexed key, uint16 feeBps);
+        event FeesCollected(address indexed maker, bytes32 indexed key, address indexed token, uint128 amount);
+        event OrderNonceInvalidated(address indexed maker, uint256 newNonce);
+        event OrderExpired(uint128 indexed orderId);
 
         // Errors
         error Unauthorized();
@@ -112,6 +175,15 @@ crate::sol! {
         error BelowMinimumOrderSize(uint128 amount);
         error InvalidBaseToken();
         error OrderNotStale();
+        error InvalidTwapWindow();
+        error InsufficientTwapHistory();
+        error InvalidFee();
+        error SignedOrderExpired();
+        error InvalidOrderNonce();
+        error InvalidOrderSignature();
+        error SwapDeadlineExpired();
+        error InvalidOrderExpiry();
+        error OrderNotExpired();
     }
 }
 
@@ -195,4 +267,52 @@ impl StablecoinDEXError {
     pub const fn order_not_stale() -> Self {
         Self::OrderNotStale(IStablecoinDEX::OrderNotStale {})
     }
+
+    /// Creates an error for a zero or too-long TWAP window.
+    pub const fn invalid_twap_window() -> Self {
+        Self::InvalidTwapWindow(IStablecoinDEX::InvalidTwapWindow {})
+    }
+
+    /// Creates an error when not enough observations cover the requested TWAP window.
+    pub const fn insufficient_twap_history() -> Self {
+        Self::InsufficientTwapHistory(IStablecoinDEX::InsufficientTwapHistory {})
+    }
+
+    /// Creates an error for a fee tier above the allowed maximum.
+    pub const fn invalid_fee() -> Self {
+        Self::InvalidFee(IStablecoinDEX::InvalidFee {})
+    }
+
+    /// Creates an error for a signed order submitted past its deadline.
+    pub const fn signed_order_expired() -> Self {
+        Self::SignedOrderExpired(IStablecoinDEX::SignedOrderExpired {})
+    }
+
+    /// Creates an error for a signed order whose nonce is not the maker's
+    /// current one, or a nonce invalidation that does not move forward.
+    pub const fn invalid_order_nonce() -> Self {
+        Self::InvalidOrderNonce(IStablecoinDEX::InvalidOrderNonce {})
+    }
+
+    /// Creates an error for a signed order whose signature does not recover
+    /// to the maker.
+    pub const fn invalid_order_signature() -> Self {
+        Self::InvalidOrderSignature(IStablecoinDEX::InvalidOrderSignature {})
+    }
+
+    /// Creates an error for a swap submitted past its deadline.
+    pub const fn swap_deadline_expired() -> Self {
+        Self::SwapDeadlineExpired(IStablecoinDEX::SwapDeadlineExpired {})
+    }
+
+    /// Creates an error for an order expiry that is already in the past.
+    pub const fn invalid_order_expiry() -> Self {
+        Self::InvalidOrderExpiry(IStablecoinDEX::InvalidOrderExpiry {})
+    }
+
+    /// Creates an error for an expiry sweep targeting an order that has not
+    /// yet expired.
+    pub const fn order_not_expired() -> Self {
+        Self::OrderNotExpired(IStablecoinDEX::OrderNotExpired {})
+    }
 }
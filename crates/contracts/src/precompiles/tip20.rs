@@ -91,6 +91,30 @@ crate::sol! {
         function transferWithMemo(address to, uint256 amount, bytes32 memo) external;
         function transferFromWithMemo(address from, address to, uint256 amount, bytes32 memo) external returns (bool);
 
+        /// One recipient of a batched transfer.
+        struct TransferItem {
+            address to;
+            uint256 amount;
+        }
+
+        /// T4+: transfers to each entry in order, as if by repeated `transfer` calls.
+        /// Transactions batching enough transfers through this entrypoint qualify
+        /// for a fee rebate from the fee manager. Reverts the whole batch if any
+        /// single transfer fails or the batch is empty.
+        function transferBatch(TransferItem[] calldata transfers) external returns (bool);
+
+        /// One recipient of a batched memo transfer.
+        struct MemoTransferItem {
+            address to;
+            uint256 amount;
+            bytes32 memo;
+        }
+
+        /// T4+: like `transferBatch`, but attaches a 32-byte memo to each entry,
+        /// as if by repeated `transferWithMemo` calls. Payment processors use the
+        /// memo to carry per-payment reference ids.
+        function transferBatchWithMemo(MemoTransferItem[] calldata transfers) external returns (bool);
+
         // Admin Functions
         function changeTransferPolicyId(uint64 newPolicyId) external;
         function setSupplyCap(uint256 newSupplyCap) external;
@@ -135,6 +159,57 @@ crate::sol! {
         function userRewardInfo(address account) external view returns (UserRewardInfo memory);
         function getPendingRewards(address account) external view returns (uint128);
 
+        /// A streaming (vesting) transfer: `ratePerSecond` tokens accrue to `to`
+        /// each second between `startTime` and `stopTime`. The full amount is
+        /// escrowed in the token contract at creation.
+        struct Stream {
+            address from;
+            address to;
+            uint256 ratePerSecond;
+            uint64 startTime;
+            uint64 stopTime;
+            uint256 withdrawn;
+        }
+
+        // Streaming Functions (T4+)
+        function createStream(address to, uint256 ratePerSecond, uint64 duration) external returns (uint256);
+        function withdrawFromStream(uint256 streamId) external returns (uint256);
+        function cancelStream(uint256 streamId) external;
+        function getStream(uint256 streamId) external view returns (Stream memory);
+        function streamBalance(uint256 streamId) external view returns (uint256);
+
+        // Expiring Allowances (T4+)
+
+        /// T4+: like `approve`, but the allowance stops being spendable once
+        /// `block.timestamp` exceeds `deadline` (unix seconds). `transferFrom`
+        /// and `allowance` treat an expired allowance as zero.
+        function approveWithExpiry(address spender, uint256 amount, uint64 deadline) external returns (bool);
+
+        /// T4+: returns the expiry timestamp of `spender`'s allowance from `owner`,
+        /// or 0 if the allowance never expires.
+        function allowanceExpiry(address owner, address spender) external view returns (uint64);
+
+        // Issuance Schedule (T4+)
+
+        /// An issuance schedule: at most `maxPerPeriod` tokens can be minted
+        /// within any `period`-second window. A zero `period` means no
+        /// schedule is active and minting is bounded by the supply cap alone.
+        struct IssuanceSchedule {
+            uint256 maxPerPeriod;
+            uint64 period;
+            uint256 mintedInPeriod;
+            uint64 periodStart;
+        }
+
+        /// T4+: caps minting at `maxPerPeriod` tokens per `period`-second
+        /// window, on top of the hard supply cap. Setting a zero `period`
+        /// (with a zero `maxPerPeriod`) clears the schedule.
+        function setIssuanceSchedule(uint256 maxPerPeriod, uint64 period) external;
+
+        /// T4+: returns the active issuance schedule, including how much of
+        /// the current window's budget has already been minted.
+        function issuanceSchedule() external view returns (IssuanceSchedule memory);
+
         // Events
         event Transfer(address indexed from, address indexed to, uint256 amount);
         event Approval(address indexed owner, address indexed spender, uint256 amount);
@@ -149,6 +224,11 @@ crate::sol! {
         event QuoteTokenUpdate(address indexed updater, address indexed newQuoteToken);
         event RewardDistributed(address indexed funder, uint256 amount);
         event RewardRecipientSet(address indexed holder, address indexed recipient);
+        event StreamCreated(uint256 indexed streamId, address indexed from, address indexed to, uint256 ratePerSecond, uint64 startTime, uint64 stopTime);
+        event StreamWithdrawal(uint256 indexed streamId, address indexed to, uint256 amount);
+        event StreamCancelled(uint256 indexed streamId, uint256 recipientAmount, uint256 senderAmount);
+        event AllowanceSpent(address indexed owner, address indexed spender, uint256 remaining);
+        event IssuanceScheduleSet(address indexed updater, uint256 maxPerPeriod, uint64 period);
 
         // Errors
         error InsufficientBalance(uint256 available, uint256 required, address token);
@@ -172,6 +252,13 @@ crate::sol! {
         error InvalidTransferPolicyId();
         error PermitExpired();
         error InvalidSignature();
+        error InvalidBatch();
+        error StreamDoesNotExist();
+        error NotStreamParty();
+        error InvalidStreamParameters();
+        error InvalidAllowanceExpiry();
+        error IssuanceRateExceeded();
+        error InvalidIssuanceSchedule();
     }
 }
 
@@ -322,6 +409,41 @@ impl TIP20Error {
     pub const fn invalid_signature() -> Self {
         Self::InvalidSignature(ITIP20::InvalidSignature {})
     }
+
+    /// Error when a batched transfer is empty.
+    pub const fn invalid_batch() -> Self {
+        Self::InvalidBatch(ITIP20::InvalidBatch {})
+    }
+
+    /// Error when a stream ID does not exist or the stream was cancelled.
+    pub const fn stream_does_not_exist() -> Self {
+        Self::StreamDoesNotExist(ITIP20::StreamDoesNotExist {})
+    }
+
+    /// Error when the caller is neither the stream's sender nor its recipient.
+    pub const fn not_stream_party() -> Self {
+        Self::NotStreamParty(ITIP20::NotStreamParty {})
+    }
+
+    /// Error for an allowance expiry deadline that is not in the future.
+    pub const fn invalid_allowance_expiry() -> Self {
+        Self::InvalidAllowanceExpiry(ITIP20::InvalidAllowanceExpiry {})
+    }
+
+    /// Error for invalid stream parameters (zero rate or zero duration).
+    pub const fn invalid_stream_parameters() -> Self {
+        Self::InvalidStreamParameters(ITIP20::InvalidStreamParameters {})
+    }
+
+    /// Error when a mint would exceed the issuance schedule's per-period budget.
+    pub const fn issuance_rate_exceeded() -> Self {
+        Self::IssuanceRateExceeded(ITIP20::IssuanceRateExceeded {})
+    }
+
+    /// Error for an issuance schedule whose budget and period are inconsistent.
+    pub const fn invalid_issuance_schedule() -> Self {
+        Self::InvalidIssuanceSchedule(ITIP20::InvalidIssuanceSchedule {})
+    }
 }
 
 #[cfg(test)]
@@ -13,6 +13,16 @@ crate::sol! {
         error TokenAlreadyExists(address token);
 
         event TokenCreated(address indexed token, string name, string symbol, string currency, address quoteToken, address admin, bytes32 salt);
+        event TokenURISet(address indexed token, string uri);
+
+        /// Metadata for one factory-deployed token, returned by `allTokens`.
+        struct TokenInfo {
+            address token;
+            string name;
+            string symbol;
+            uint8 decimals;
+            string uri;
+        }
 
         function createToken(
             string memory name,
@@ -26,6 +36,14 @@ crate::sol! {
         function isTIP20(address token) public view returns (bool);
 
         function getTokenAddress(address sender, bytes32 salt) public view returns (address);
+
+        function tokenURI(address token) public view returns (string memory);
+
+        function setTokenURI(address token, string memory uri) external;
+
+        function tokenCount() public view returns (uint256);
+
+        function allTokens(uint256 offset, uint256 limit) public view returns (TokenInfo[] memory);
     }
 }
 
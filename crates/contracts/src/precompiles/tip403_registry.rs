@@ -31,12 +31,21 @@ crate::sol! {
         function modifyPolicyBlacklist(uint64 policyId, address account, bool restricted) external;
         function createCompoundPolicy(uint64 senderPolicyId, uint64 recipientPolicyId, uint64 mintRecipientPolicyId) external returns (uint64);
 
+        // Attestation expiry (T4+)
+        function attestationExpiry(uint64 policyId, address account) external view returns (uint64);
+        function setAttestationExpiry(uint64 policyId, address account, uint64 expiry) external;
+        function renewAttestation(uint64 policyId, address account, uint64 newExpiry) external;
+        function markAttestationExpired(uint64 policyId, address account) external;
+        function expiringBetween(uint64 policyId, uint64 t0, uint64 t1, uint64 offset, uint64 limit) external view returns (address[] memory accounts);
+
         // Events
         event PolicyAdminUpdated(uint64 indexed policyId, address indexed updater, address indexed admin);
         event PolicyCreated(uint64 indexed policyId, address indexed updater, PolicyType policyType);
         event WhitelistUpdated(uint64 indexed policyId, address indexed updater, address indexed account, bool allowed);
         event BlacklistUpdated(uint64 indexed policyId, address indexed updater, address indexed account, bool restricted);
         event CompoundPolicyCreated(uint64 indexed policyId, address indexed creator, uint64 senderPolicyId, uint64 recipientPolicyId, uint64 mintRecipientPolicyId);
+        event AttestationExpirySet(uint64 indexed policyId, address indexed updater, address indexed account, uint64 expiry);
+        event AttestationExpired(uint64 indexed policyId, address indexed account, uint64 expiry);
 
         // Errors
         error Unauthorized();
@@ -45,6 +54,8 @@ crate::sol! {
         error InvalidPolicyType();
         error IncompatiblePolicyType();
         error VirtualAddressNotAllowed();
+        error InvalidExpiry();
+        error AttestationNotFound();
     }
 }
 
@@ -94,4 +105,15 @@ impl TIP403RegistryError {
     pub const fn virtual_address_not_allowed() -> Self {
         Self::VirtualAddressNotAllowed(ITIP403Registry::VirtualAddressNotAllowed {})
     }
+
+    /// Creates an error for an expiry in the past, a renewal that does not extend the current
+    /// expiry, or marking an attestation expired before it has lapsed.
+    pub const fn invalid_expiry() -> Self {
+        Self::InvalidExpiry(ITIP403Registry::InvalidExpiry {})
+    }
+
+    /// Creates an error for expiry operations on an account that is not in the policy set.
+    pub const fn attestation_not_found() -> Self {
+        Self::AttestationNotFound(ITIP403Registry::AttestationNotFound {})
+    }
 }
@@ -31,7 +31,13 @@ crate::sol! {
 
         // Fee functions
         function distributeFees(address validator, address token) external;
+        /// T4+: distributes every outstanding collected-fee balance, iterating pairs in
+        /// sorted `(validator, token)` order so all nodes settle them identically.
+        function distributeAllFees() external;
         function collectedFees(address validator, address token) external view returns (uint256);
+        /// T4+: total gas rebates granted to `user` in `token`, accumulated when a
+        /// transaction batches enough TIP-20 transfers through `transferBatch`.
+        function rebates(address user, address token) external view returns (uint256);
         // NOTE: collectFeePreTx is a protocol-internal function called directly by the
         // execution handler, not exposed via the dispatch interface.
 
@@ -39,6 +45,7 @@ crate::sol! {
         event UserTokenSet(address indexed user, address indexed token);
         event ValidatorTokenSet(address indexed validator, address indexed token);
         event FeesDistributed(address indexed validator, address indexed token, uint256 amount);
+        event FeeRebateGranted(address indexed feePayer, address indexed token, uint256 amount, uint256 transferCount);
 
         // Errors
         error OnlyValidator();
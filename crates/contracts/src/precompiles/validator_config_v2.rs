@@ -32,6 +32,14 @@ crate::sol! {
             uint64 deactivatedAtHeight;
         }
 
+        /// Semantic version and activation hardfork of a precompile implementation.
+        struct PrecompileVersion {
+            uint8 major;
+            uint8 minor;
+            uint8 patch;
+            string activationFork;
+        }
+
         // =====================================================================
         // View functions
         // =====================================================================
@@ -60,9 +68,27 @@ crate::sol! {
         /// Get the epoch for next network identity rotation (full DKG ceremony)
         function getNextNetworkIdentityRotationEpoch() external view returns (uint64);
 
+        /// Get the governed block gas limit target (0 = no override) (T4+)
+        function getBlockGasLimit() external view returns (uint64);
+
         /// Check if V2 has been initialized
         function isInitialized() external view returns (bool);
 
+        /// Get the Unix timestamp (seconds) of a validator's most recent heartbeat,
+        /// zero if it has never sent one (T4+).
+        ///
+        /// Delegators and the bridge sidecar use this to avoid selecting validators
+        /// that have gone quiet, e.g. as designated unlock submitters.
+        function lastActiveAt(address validator) external view returns (uint64);
+
+        /// Get the implementation version of a precompile (T4+).
+        ///
+        /// Versions are a pure function of the precompile address and the active hardfork,
+        /// so off-chain tooling can detect behavior changes deterministically by querying
+        /// this at any block. Reverts with `UnknownPrecompile` if `target` is not a
+        /// precompile, or if the precompile is not yet active at the current hardfork.
+        function getVersion(address target) external view returns (PrecompileVersion memory);
+
         // =====================================================================
         // Mutate functions
         // =====================================================================
@@ -114,6 +140,12 @@ crate::sol! {
         /// Set the epoch for next network identity rotation via full DKG ceremony (owner only)
         function setNetworkIdentityRotationEpoch(uint64 epoch) external;
 
+        /// Set the block gas limit target proposers converge to; zero clears the override (owner only) (T4+)
+        function setBlockGasLimit(uint64 gasLimit) external;
+
+        /// Record that the calling validator is alive (active validator only) (T4+)
+        function heartbeat() external;
+
         /// Migrate a single validator from V1 (owner only)
         function migrateValidator(uint64 idx) external;
 
@@ -142,6 +174,8 @@ crate::sol! {
         event OwnershipTransferred(address indexed oldOwner, address indexed newOwner);
         event ValidatorMigrated(uint64 indexed index, address indexed validatorAddress, bytes32 publicKey);
         event NetworkIdentityRotationEpochSet(uint64 indexed previousEpoch, uint64 indexed nextEpoch);
+        event BlockGasLimitUpdated(uint64 indexed previousGasLimit, uint64 indexed newGasLimit);
+        event ValidatorHeartbeat(address indexed validatorAddress, uint64 timestamp);
         event Initialized(uint64 height);
         event SkippedValidatorMigration(uint64 indexed index, address indexed validatorAddress, bytes32 publicKey);
 
@@ -164,7 +198,9 @@ crate::sol! {
         error NotIpPort(string input, string backtrace);
         error PublicKeyAlreadyExists();
         error Unauthorized();
+        error UnknownPrecompile();
         error AddressAlreadyHasValidator();
+        error BlockGasLimitOutOfBounds(uint64 gasLimit, uint64 min, uint64 max);
         error ValidatorAlreadyDeactivated();
         error ValidatorNotFound();
     }
@@ -175,6 +211,10 @@ impl ValidatorConfigV2Error {
         Self::Unauthorized(IValidatorConfigV2::Unauthorized {})
     }
 
+    pub const fn unknown_precompile() -> Self {
+        Self::UnknownPrecompile(IValidatorConfigV2::UnknownPrecompile {})
+    }
+
     pub const fn address_already_has_validator() -> Self {
         Self::AddressAlreadyHasValidator(IValidatorConfigV2::AddressAlreadyHasValidator {})
     }
@@ -242,4 +282,12 @@ impl ValidatorConfigV2Error {
     pub fn ingress_already_exists(ingress: String) -> Self {
         Self::IngressAlreadyExists(IValidatorConfigV2::IngressAlreadyExists { ingress })
     }
+
+    pub const fn block_gas_limit_out_of_bounds(gas_limit: u64, min: u64, max: u64) -> Self {
+        Self::BlockGasLimitOutOfBounds(IValidatorConfigV2::BlockGasLimitOutOfBounds {
+            gasLimit: gas_limit,
+            min,
+            max,
+        })
+    }
 }
@@ -0,0 +1,193 @@
+//! Versioned encoding of the DKG outcome into header extra data.
+//!
+//! Boundary blocks historically carried a bare [`OnchainDkgOutcome`] encoding
+//! in `extra_data`, with no version information: every reader (`xtask
+//! replay-dkg`, the bridge, the node itself) bare-decoded the field and any
+//! schema change would have broken all of them at once. This module gives the
+//! section an explicit schema version so future consensus metadata can be
+//! appended without another flag-day.
+//!
+//! A v1 section is the version byte [`EXTRA_DATA_VERSION_V1`] followed by the
+//! outcome encoding; any bytes after the outcome are unknown trailing sections
+//! and are ignored, so a v1 reader stays compatible with producers that append
+//! new sections. Decoding tries the registered versions first and falls back
+//! to the legacy bare encoding, so headers produced before the version byte
+//! existed keep parsing. A legacy encoding can only be mistaken for a
+//! versioned one if its first byte matches a registered version *and* the
+//! remainder decodes as a valid outcome (group elements and all), which the
+//! fallback order makes practically unreachable.
+//!
+//! Producers still emit the legacy encoding: flipping to
+//! [`encode_extra_data`] changes header bytes (and therefore block hashes),
+//! so it has to wait until every validator decodes v1.
+
+use bytes::{Buf as _, BufMut as _};
+use commonware_codec::{EncodeSize as _, Read as _, Write as _};
+
+use crate::OnchainDkgOutcome;
+
+/// Version byte of the first explicitly versioned extra-data schema.
+pub const EXTRA_DATA_VERSION_V1: u8 = 1;
+
+/// Registered extra-data schema versions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExtraDataVersion {
+    /// Bare [`OnchainDkgOutcome`] encoding with no version byte, as produced
+    /// by nodes predating the versioned schema.
+    Legacy,
+    /// [`EXTRA_DATA_VERSION_V1`], then the outcome, then zero or more
+    /// trailing sections that this node ignores.
+    V1,
+}
+
+impl ExtraDataVersion {
+    /// Every versioned schema this node understands, keyed by version byte.
+    ///
+    /// [`ExtraDataVersion::Legacy`] has no version byte and is deliberately
+    /// absent: it is the fallback when no registered version matches.
+    pub const REGISTRY: &[(u8, ExtraDataVersion)] =
+        &[(EXTRA_DATA_VERSION_V1, ExtraDataVersion::V1)];
+}
+
+/// An extra-data section decoded by [`decode_extra_data`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DecodedExtraData {
+    /// Schema version the section was encoded with.
+    pub version: ExtraDataVersion,
+
+    /// The DKG outcome the section carries.
+    pub outcome: OnchainDkgOutcome,
+
+    /// Number of trailing bytes belonging to sections this node does not
+    /// understand. Always zero for [`ExtraDataVersion::Legacy`].
+    pub unknown_trailing: usize,
+}
+
+/// Encodes `outcome` as a v1 extra-data section.
+pub fn encode_extra_data(outcome: &OnchainDkgOutcome) -> Vec<u8> {
+    let mut buf = Vec::with_capacity(1 + outcome.encode_size());
+    buf.put_u8(EXTRA_DATA_VERSION_V1);
+    outcome.write(&mut buf);
+    buf
+}
+
+/// Decodes the DKG outcome section from a header's extra data.
+///
+/// Accepts every schema in [`ExtraDataVersion::REGISTRY`] as well as the
+/// legacy bare encoding. Unknown trailing sections of a versioned encoding
+/// are ignored (their length is reported via
+/// [`DecodedExtraData::unknown_trailing`]); an unregistered version byte is
+/// only an error if the bytes do not decode as a legacy outcome either.
+pub fn decode_extra_data(extra_data: &[u8]) -> Result<DecodedExtraData, commonware_codec::Error> {
+    if extra_data.is_empty() {
+        return Err(commonware_codec::Error::EndOfBuffer);
+    }
+
+    for (byte, version) in ExtraDataVersion::REGISTRY {
+        if extra_data[0] != *byte {
+            continue;
+        }
+        let mut buf = &extra_data[1..];
+        if let Ok(outcome) = OnchainDkgOutcome::read(&mut buf) {
+            return Ok(DecodedExtraData {
+                version: *version,
+                outcome,
+                unknown_trailing: buf.remaining(),
+            });
+        }
+    }
+
+    let outcome = OnchainDkgOutcome::read(&mut &*extra_data)?;
+    Ok(DecodedExtraData {
+        version: ExtraDataVersion::Legacy,
+        outcome,
+        unknown_trailing: 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::iter::repeat_with;
+
+    use commonware_codec::Encode as _;
+    use commonware_consensus::types::Epoch;
+    use commonware_cryptography::{Signer as _, bls12381::dkg, ed25519::PrivateKey};
+    use commonware_math::algebra::Random as _;
+    use commonware_utils::{N3f1, TryFromIterator as _, ordered};
+    use rand_08::SeedableRng as _;
+
+    use super::{DecodedExtraData, ExtraDataVersion, decode_extra_data, encode_extra_data};
+    use crate::OnchainDkgOutcome;
+
+    fn outcome() -> OnchainDkgOutcome {
+        let mut rng = rand_08::rngs::StdRng::seed_from_u64(42);
+
+        let mut player_keys = repeat_with(|| PrivateKey::random(&mut rng))
+            .take(10)
+            .collect::<Vec<_>>();
+        player_keys.sort_by_key(|key| key.public_key());
+        let (output, _shares) = dkg::deal::<_, _, N3f1>(
+            &mut rng,
+            Default::default(),
+            ordered::Set::try_from_iter(player_keys.iter().map(|key| key.public_key())).unwrap(),
+        )
+        .unwrap();
+
+        OnchainDkgOutcome {
+            epoch: Epoch::new(42),
+            output,
+            next_players: ordered::Set::try_from_iter(
+                player_keys.iter().map(|key| key.public_key()),
+            )
+            .unwrap(),
+            is_next_full_dkg: false,
+        }
+    }
+
+    #[test]
+    fn v1_roundtrip() {
+        let outcome = outcome();
+        let bytes = encode_extra_data(&outcome);
+        assert_eq!(
+            decode_extra_data(&bytes).unwrap(),
+            DecodedExtraData {
+                version: ExtraDataVersion::V1,
+                outcome,
+                unknown_trailing: 0,
+            },
+        );
+    }
+
+    #[test]
+    fn legacy_bare_encoding_still_decodes() {
+        let outcome = outcome();
+        let bytes = outcome.encode();
+        assert_eq!(
+            decode_extra_data(&bytes).unwrap(),
+            DecodedExtraData {
+                version: ExtraDataVersion::Legacy,
+                outcome,
+                unknown_trailing: 0,
+            },
+        );
+    }
+
+    #[test]
+    fn unknown_trailing_sections_are_ignored() {
+        let outcome = outcome();
+        let mut bytes = encode_extra_data(&outcome);
+        bytes.extend_from_slice(b"future consensus metadata");
+
+        let decoded = decode_extra_data(&bytes).unwrap();
+        assert_eq!(decoded.version, ExtraDataVersion::V1);
+        assert_eq!(decoded.outcome, outcome);
+        assert_eq!(decoded.unknown_trailing, b"future consensus metadata".len());
+    }
+
+    #[test]
+    fn garbage_is_rejected() {
+        assert!(decode_extra_data(&[]).is_err());
+        assert!(decode_extra_data(&[super::EXTRA_DATA_VERSION_V1]).is_err());
+        assert!(decode_extra_data(&[0xff; 64]).is_err());
+    }
+}
@@ -17,6 +17,8 @@ use commonware_cryptography::{
 };
 use commonware_utils::{NZU32, ordered};
 
+pub mod extra_data;
+
 const MAX_VALIDATORS: NonZeroU32 = NZU32!(u16::MAX as u32);
 
 /// The outcome of a DKG ceremony as it is written to the chain.
@@ -63,6 +65,16 @@ impl OnchainDkgOutcome {
     pub fn network_identity(&self) -> &<MinSig as Variant>::Public {
         self.sharing().public()
     }
+
+    /// Decodes the outcome from a header's extra data, accepting both the
+    /// legacy bare encoding and every version registered in
+    /// [`extra_data::ExtraDataVersion::REGISTRY`].
+    ///
+    /// Unknown trailing sections of a versioned encoding are ignored; use
+    /// [`extra_data::decode_extra_data`] to inspect the detected version.
+    pub fn from_extra_data(bytes: &[u8]) -> Result<Self, commonware_codec::Error> {
+        extra_data::decode_extra_data(bytes).map(|decoded| decoded.outcome)
+    }
 }
 
 impl Write for OnchainDkgOutcome {
@@ -0,0 +1,286 @@
+//! Managed Anvil process for tests that need a real EVM origin chain.
+//!
+//! Shelling out to `anvil` with a hard-coded port and a fixed sleep is flaky
+//! on loaded CI machines and does not work at all when the binary lives in a
+//! non-standard location (the default Foundry install on Windows and macOS
+//! puts it under `~/.foundry/bin`, which is rarely on `PATH` in test
+//! environments). [`AnvilHandle`] wraps the whole lifecycle instead:
+//!
+//! - binary discovery via the `ANVIL` env var, `PATH`, and `~/.foundry/bin`
+//! - a version sanity check before spawning the chain
+//! - spawning on an OS-assigned free port, retried on bind races
+//! - readiness by polling `eth_chainId` rather than sleeping
+//! - stdout/stderr forwarded line-by-line to `tracing` under the `anvil`
+//!   target
+//! - the child process is killed when the handle is dropped
+
+use std::{
+    env,
+    path::{Path, PathBuf},
+    process::Stdio,
+    time::Duration,
+};
+
+use alloy::providers::{Provider, ProviderBuilder};
+use eyre::{Context as _, bail, eyre};
+use tokio::{
+    io::{AsyncBufReadExt as _, AsyncRead, BufReader},
+    process::{Child, Command},
+};
+
+/// Name of the anvil binary on the current platform.
+#[cfg(windows)]
+const ANVIL_BINARY: &str = "anvil.exe";
+#[cfg(not(windows))]
+const ANVIL_BINARY: &str = "anvil";
+
+/// How many times to retry spawning when the chosen port is lost to a race.
+const SPAWN_ATTEMPTS: usize = 5;
+
+/// How long to poll for readiness before giving up on one spawn attempt.
+const READY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Interval between readiness polls.
+const READY_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Configuration for a managed anvil instance.
+#[derive(Clone, Debug, Default)]
+pub struct AnvilConfig {
+    /// Explicit path to the anvil binary. When unset, the binary is
+    /// discovered via the `ANVIL` env var, `PATH`, and `~/.foundry/bin`.
+    pub binary: Option<PathBuf>,
+    /// Chain id to run with (`--chain-id`). Anvil's default when unset.
+    pub chain_id: Option<u64>,
+    /// Interval block mining in seconds (`--block-time`). Instamine when
+    /// unset.
+    pub block_time: Option<u64>,
+}
+
+/// A running anvil instance, killed on drop.
+#[derive(Debug)]
+pub struct AnvilHandle {
+    child: Child,
+    endpoint: String,
+    version: String,
+}
+
+impl AnvilHandle {
+    /// Spawn anvil with the default configuration and wait until its RPC
+    /// endpoint answers `eth_chainId`.
+    pub async fn spawn() -> eyre::Result<Self> {
+        Self::spawn_with(AnvilConfig::default()).await
+    }
+
+    /// Spawn anvil with `config` and wait until its RPC endpoint answers
+    /// `eth_chainId`.
+    ///
+    /// The port is OS-assigned; if another process grabs it between probing
+    /// and anvil binding it, the spawn is retried with a fresh port.
+    pub async fn spawn_with(config: AnvilConfig) -> eyre::Result<Self> {
+        let binary = match &config.binary {
+            Some(path) => path.clone(),
+            None => find_anvil_binary().ok_or_else(|| {
+                eyre!(
+                    "no `{ANVIL_BINARY}` binary found; install foundry or point \
+                     the ANVIL env var at the binary"
+                )
+            })?,
+        };
+        let version = check_version(&binary).await?;
+
+        let mut last_error = None;
+        for _ in 0..SPAWN_ATTEMPTS {
+            let port = free_port()?;
+            match Self::spawn_on_port(&binary, &config, port, &version).await {
+                Ok(handle) => return Ok(handle),
+                Err(error) => {
+                    tracing::debug!(target: "anvil", %error, port, "spawn attempt failed");
+                    last_error = Some(error);
+                }
+            }
+        }
+        Err(last_error.expect("at least one spawn attempt was made"))
+    }
+
+    async fn spawn_on_port(
+        binary: &Path,
+        config: &AnvilConfig,
+        port: u16,
+        version: &str,
+    ) -> eyre::Result<Self> {
+        let mut command = Command::new(binary);
+        command
+            .arg("--port")
+            .arg(port.to_string())
+            .stdin(Stdio::null())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .kill_on_drop(true);
+        if let Some(chain_id) = config.chain_id {
+            command.arg("--chain-id").arg(chain_id.to_string());
+        }
+        if let Some(block_time) = config.block_time {
+            command.arg("--block-time").arg(block_time.to_string());
+        }
+
+        let mut child = command
+            .spawn()
+            .wrap_err_with(|| format!("failed to spawn {}", binary.display()))?;
+        forward_logs(child.stdout.take(), "stdout");
+        forward_logs(child.stderr.take(), "stderr");
+
+        let endpoint = format!("http://127.0.0.1:{port}");
+        let mut handle = Self {
+            child,
+            endpoint,
+            version: version.to_owned(),
+        };
+        handle.wait_until_ready().await?;
+        Ok(handle)
+    }
+
+    /// Poll `eth_chainId` until the endpoint answers, the child exits, or
+    /// [`READY_TIMEOUT`] elapses.
+    async fn wait_until_ready(&mut self) -> eyre::Result<()> {
+        let provider = ProviderBuilder::new()
+            .connect_http(self.endpoint.parse().wrap_err("invalid anvil endpoint")?);
+
+        let deadline = tokio::time::Instant::now() + READY_TIMEOUT;
+        loop {
+            if let Some(status) = self.child.try_wait().wrap_err("failed to poll anvil")? {
+                bail!("anvil exited before becoming ready: {status}");
+            }
+            if provider.get_chain_id().await.is_ok() {
+                tracing::debug!(
+                    target: "anvil",
+                    endpoint = %self.endpoint,
+                    version = %self.version,
+                    "anvil ready"
+                );
+                return Ok(());
+            }
+            if tokio::time::Instant::now() >= deadline {
+                bail!(
+                    "anvil at {} did not become ready within {READY_TIMEOUT:?}",
+                    self.endpoint
+                );
+            }
+            tokio::time::sleep(READY_POLL_INTERVAL).await;
+        }
+    }
+
+    /// HTTP RPC endpoint of the running instance.
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+
+    /// Version string reported by `anvil --version`.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+}
+
+/// Locate the anvil binary: `ANVIL` env var first, then `PATH`, then the
+/// default Foundry install location `~/.foundry/bin`.
+pub fn find_anvil_binary() -> Option<PathBuf> {
+    if let Some(path) = env::var_os("ANVIL") {
+        let path = PathBuf::from(path);
+        return path.is_file().then_some(path);
+    }
+
+    if let Some(paths) = env::var_os("PATH") {
+        for dir in env::split_paths(&paths) {
+            let candidate = dir.join(ANVIL_BINARY);
+            if candidate.is_file() {
+                return Some(candidate);
+            }
+        }
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"))?;
+    let candidate = PathBuf::from(home)
+        .join(".foundry")
+        .join("bin")
+        .join(ANVIL_BINARY);
+    candidate.is_file().then_some(candidate)
+}
+
+/// Run `anvil --version` and return the reported version line.
+async fn check_version(binary: &Path) -> eyre::Result<String> {
+    let output = Command::new(binary)
+        .arg("--version")
+        .output()
+        .await
+        .wrap_err_with(|| format!("failed to run {} --version", binary.display()))?;
+    if !output.status.success() {
+        bail!("{} --version failed: {}", binary.display(), output.status);
+    }
+    let version = String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_owned();
+    if !version.to_ascii_lowercase().contains("anvil") {
+        bail!(
+            "{} does not look like an anvil binary (reported {version:?})",
+            binary.display()
+        );
+    }
+    Ok(version)
+}
+
+/// Ask the OS for a currently-free TCP port.
+///
+/// The port may be grabbed by someone else before anvil binds it, which is
+/// why [`AnvilHandle::spawn_with`] retries.
+fn free_port() -> eyre::Result<u16> {
+    let listener = std::net::TcpListener::bind(("127.0.0.1", 0))
+        .wrap_err("failed to probe for a free port")?;
+    Ok(listener.local_addr()?.port())
+}
+
+/// Forward a child pipe to `tracing`, line by line, under the `anvil` target.
+fn forward_logs(pipe: Option<impl AsyncRead + Unpin + Send + 'static>, stream: &'static str) {
+    let Some(pipe) = pipe else { return };
+    tokio::spawn(async move {
+        let mut lines = BufReader::new(pipe).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            tracing::debug!(target: "anvil", stream, "{line}");
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn spawns_and_answers_chain_id() {
+        if find_anvil_binary().is_none() {
+            eprintln!("skipping: no anvil binary found");
+            return;
+        }
+
+        let handle = AnvilHandle::spawn_with(AnvilConfig {
+            chain_id: Some(31_338),
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+        assert!(handle.version().to_ascii_lowercase().contains("anvil"));
+
+        let provider = ProviderBuilder::new().connect_http(handle.endpoint().parse().unwrap());
+        assert_eq!(provider.get_chain_id().await.unwrap(), 31_338);
+    }
+
+    #[test]
+    fn discovery_rejects_missing_env_override() {
+        // An ANVIL override pointing at a non-existent file must not be
+        // silently ignored in favour of a PATH lookup.
+        // SAFETY: tests in this module do not race on this variable.
+        unsafe { env::set_var("ANVIL", "/definitely/not/a/real/anvil") };
+        assert_eq!(find_anvil_binary(), None);
+        unsafe { env::remove_var("ANVIL") };
+    }
+}
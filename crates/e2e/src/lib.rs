@@ -37,6 +37,8 @@ use itertools::Itertools as _;
 use reth_node_metrics::recorder::PrometheusRecorder;
 use tempo_commonware_node::{consensus, feed::FeedStateHandle};
 
+pub mod anvil;
+pub use anvil::{AnvilConfig, AnvilHandle};
 pub mod execution_runtime;
 pub use execution_runtime::ExecutionNodeConfig;
 pub mod testing_node;
@@ -123,7 +123,7 @@ async fn consensus_subscribe_and_query_finalization() {
 }
 
 /// Wait for a validator to reach a target height by checking metrics.
-async fn wait_for_height(context: &Context, target_height: u64) {
+pub(super) async fn wait_for_height(context: &Context, target_height: u64) {
     loop {
         let metrics = context.encode();
         for line in metrics.lines() {
@@ -2,7 +2,6 @@
 
 use std::time::Duration;
 
-use commonware_codec::ReadExt as _;
 use commonware_consensus::types::{Epoch, Epocher as _, FixedEpocher, Height};
 use commonware_runtime::{Clock as _, Metrics as _, deterministic::Context};
 use commonware_utils::NZU64;
@@ -39,7 +38,7 @@ pub(crate) fn read_outcome_from_validator(
         return None;
     }
 
-    Some(OnchainDkgOutcome::read(&mut extra_data.as_ref()).expect("valid DKG outcome"))
+    Some(OnchainDkgOutcome::from_extra_data(extra_data.as_ref()).expect("valid DKG outcome"))
 }
 
 /// Parses a metric line, returning (metric_name, value) if valid.
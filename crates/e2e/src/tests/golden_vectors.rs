@@ -0,0 +1,207 @@
+//! Golden-vector tests for finalization certificate and header encoding.
+//!
+//! The committed vectors in `tempo-test-vectors` pin the exact byte encodings
+//! of finalization certificates, block headers, and the BLS message digests
+//! they commit to, as captured from a real (deterministic) network. Decoding
+//! and re-verifying them on every run catches silent drift in the certificate
+//! wire format, the header RLP encoding, or the signed digest derivation.
+//!
+//! The Solidity `FinalizationBridge` is not part of this tree yet; once it
+//! lands, the same vectors are meant to be replayed against it via Anvil so
+//! the Rust and Solidity verifiers cannot drift apart unnoticed.
+
+use std::{net::SocketAddr, time::Duration};
+
+use crate::{Setup, setup_validators, tests::consensus_rpc::wait_for_height};
+use alloy_primitives::{Sealable as _, hex};
+use commonware_codec::{Encode as _, ReadExt as _};
+use commonware_consensus::simplex::{scheme::bls12381_threshold::vrf::Scheme, types::Finalization};
+use commonware_cryptography::{
+    bls12381::primitives::variant::{MinSig, Variant},
+    ed25519::PublicKey,
+};
+use commonware_macros::test_traced;
+use commonware_runtime::{
+    Runner as _,
+    deterministic::{self, Runner},
+};
+use futures::channel::oneshot;
+use jsonrpsee::http_client::HttpClientBuilder;
+use reth_ethereum::storage::BlockReader as _;
+use tempo_commonware_node::consensus::Digest;
+use tempo_dkg_onchain_artifacts::OnchainDkgOutcome;
+use tempo_node::rpc::consensus::{Query, TempoConsensusApiClient};
+use tempo_primitives::TempoHeader;
+use tempo_test_vectors::FinalizationVector;
+
+/// Decodes, re-encodes, and re-verifies every committed finalization vector.
+///
+/// This is the drift check: the vectors were captured from a network where
+/// the certificate verified, so any change to the certificate codec, the
+/// header RLP encoding, or the digest derivation fails this test.
+#[test_traced]
+fn committed_finalization_vectors_decode_and_verify() {
+    let _ = tempo_eyre::install();
+
+    let vectors = tempo_test_vectors::load_finalization_vectors().unwrap();
+    // An empty set means the drift check is not checking anything; fail loudly
+    // instead of passing vacuously.
+    assert!(
+        !vectors.is_empty(),
+        "no committed finalization vectors found; capture one with \
+         `UPDATE_GOLDEN_VECTORS=1 cargo test -p tempo-e2e capture_finalization_vector` \
+         and commit the JSON under crates/test-vectors/vectors/finalization/",
+    );
+
+    let executor = Runner::from(deterministic::Config::default());
+    executor.start(|mut context| async move {
+        for vector in vectors {
+            // The certificate must decode and round-trip byte-for-byte.
+            let certificate_bytes = hex::decode(&vector.certificate).unwrap();
+            let finalization = Finalization::<Scheme<PublicKey, MinSig>, Digest>::read(
+                &mut certificate_bytes.as_slice(),
+            )
+            .unwrap_or_else(|err| {
+                panic!(
+                    "vector `{}`: certificate does not decode: {err}",
+                    vector.name
+                )
+            });
+            assert_eq!(
+                finalization.encode().as_ref(),
+                certificate_bytes.as_slice(),
+                "vector `{}`: certificate encoding drifted",
+                vector.name,
+            );
+
+            // The header must decode from its committed RLP and hash to the
+            // digest the certificate commits to.
+            let header_bytes = hex::decode(&vector.header_rlp).unwrap();
+            let header: TempoHeader = alloy_rlp::Decodable::decode(&mut header_bytes.as_slice())
+                .unwrap_or_else(|err| {
+                    panic!("vector `{}`: header does not decode: {err}", vector.name)
+                });
+            let digest = hex::decode(&vector.digest).unwrap();
+            assert_eq!(
+                header.hash_slow().as_slice(),
+                digest.as_slice(),
+                "vector `{}`: header hash drifted from the signed digest",
+                vector.name,
+            );
+            assert_eq!(
+                header.inner.number, vector.height,
+                "vector `{}`",
+                vector.name
+            );
+            assert_eq!(
+                finalization.proposal.payload.as_ref(),
+                digest.as_slice(),
+                "vector `{}`: certificate does not commit to the header digest",
+                vector.name,
+            );
+
+            // The BLS signature must still verify against the committed
+            // network identity under the production namespace.
+            let identity = <MinSig as Variant>::Public::read(
+                &mut hex::decode(&vector.network_identity).unwrap().as_slice(),
+            )
+            .expect("valid BLS public key");
+            assert!(
+                finalization.verify(
+                    &mut context,
+                    &Scheme::certificate_verifier(tempo_commonware_node::NAMESPACE, identity),
+                    &commonware_parallel::Sequential,
+                ),
+                "vector `{}`: BLS signature verification failed",
+                vector.name,
+            );
+        }
+    });
+}
+
+/// Captures a fresh finalization vector from a single-validator network.
+///
+/// No-op unless `UPDATE_GOLDEN_VECTORS=1` is set; the captured JSON must be
+/// committed under `crates/test-vectors/vectors/finalization/`.
+#[tokio::test]
+#[test_traced]
+async fn capture_finalization_vector() {
+    if std::env::var_os("UPDATE_GOLDEN_VECTORS").is_none() {
+        return;
+    }
+    let _ = tempo_eyre::install();
+
+    let target_height = 3;
+    let setup = Setup::new().how_many_signers(1).epoch_length(100);
+    let cfg = deterministic::Config::default().with_seed(setup.seed);
+
+    let (addr_tx, addr_rx) = oneshot::channel::<(SocketAddr, String)>();
+    let (done_tx, done_rx) = oneshot::channel::<()>();
+
+    let executor_handle = std::thread::spawn(move || {
+        let executor = Runner::from(cfg);
+        executor.start(|mut context| async move {
+            let (mut validators, _execution_runtime) = setup_validators(&mut context, setup).await;
+            validators[0].start(&context).await;
+            wait_for_height(&context, target_height).await;
+
+            // The network identity for epoch 0 is committed to the genesis
+            // header's DKG outcome.
+            let genesis = validators[0]
+                .execution_provider()
+                .block_by_number(0)
+                .unwrap()
+                .unwrap();
+            let outcome =
+                OnchainDkgOutcome::from_extra_data(genesis.header.inner.extra_data.as_ref())
+                    .expect("genesis header must contain a DKG outcome");
+            let network_identity = hex::encode(outcome.network_identity().encode());
+
+            let http_addr = validators[0]
+                .execution()
+                .rpc_server_handles
+                .rpc
+                .http_local_addr()
+                .unwrap();
+
+            addr_tx.send((http_addr, network_identity)).unwrap();
+            let _ = done_rx.await;
+        });
+    });
+
+    let (http_addr, network_identity) = addr_rx.await.unwrap();
+    let http_client = HttpClientBuilder::default()
+        .build(format!("http://{http_addr}"))
+        .unwrap();
+
+    // Poll until a finalization past genesis is available.
+    let certified = loop {
+        if let Some(certified) = http_client.get_finalization(Query::Latest).await.unwrap()
+            && certified.block.header.inner.number > 0
+        {
+            break certified;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    };
+
+    let vector = FinalizationVector {
+        name: "single_validator_epoch0".to_string(),
+        description: format!(
+            "captured by capture_finalization_vector from a deterministic \
+             single-validator network at height {}",
+            certified.block.header.inner.number,
+        ),
+        epoch: certified.epoch,
+        view: certified.view,
+        height: certified.block.header.inner.number,
+        digest: hex::encode(certified.digest),
+        header_rlp: hex::encode(alloy_rlp::encode(&certified.block.header)),
+        certificate: certified.certificate.clone(),
+        network_identity,
+    };
+    let path = tempo_test_vectors::write_finalization_vector(&vector).unwrap();
+    println!("wrote finalization vector to {}; commit it", path.display());
+
+    drop(done_tx);
+    executor_handle.join().unwrap();
+}
@@ -28,7 +28,8 @@ use reth_revm::{
 use std::collections::{HashMap, HashSet};
 use tempo_chainspec::{TempoChainSpec, hardfork::TempoHardforks};
 use tempo_contracts::precompiles::{
-    ADDRESS_REGISTRY_ADDRESS, SIGNATURE_VERIFIER_ADDRESS, VALIDATOR_CONFIG_V2_ADDRESS,
+    ADDRESS_REGISTRY_ADDRESS, P256_VERIFY_ADDRESS, SIGNATURE_VERIFIER_ADDRESS,
+    VALIDATOR_CONFIG_V2_ADDRESS,
 };
 use tempo_primitives::{
     SubBlock, SubBlockMetadata, TempoReceipt, TempoTxEnvelope, TempoTxType,
@@ -136,10 +137,17 @@ where
     I: Inspector<TempoContext<DB>>,
 {
     pub(crate) fn new(
-        evm: TempoEvm<DB, I>,
+        mut evm: TempoEvm<DB, I>,
         ctx: TempoBlockExecutionCtx<'a>,
         chain_spec: &'a TempoChainSpec,
     ) -> Self {
+        // T4+: fee escrows are still taken per transaction, but the refunds of their
+        // unused parts are netted across the block and credited once at the end of the
+        // block instead of per transaction.
+        if chain_spec.is_t4_active_at_timestamp(evm.block().timestamp.to::<u64>()) {
+            evm.inner_mut().fee_netting = Some(Default::default());
+        }
+
         Self {
             incentive_gas_used: 0,
             validator_set: ctx.validator_set,
@@ -190,6 +198,31 @@ where
         Ok(())
     }
 
+    /// Applies the end-of-block net refund settlement accumulated in the EVM's fee netting
+    /// ledger (T4+).
+    ///
+    /// Like [`Self::deploy_precompile_at_boundary`], the resulting state change is dispatched
+    /// to the system caller's state hook so that the sparse trie task is aware of it.
+    fn apply_fee_settlement(&mut self) -> Result<(), BlockExecutionError> {
+        let Some(state) = self
+            .inner
+            .evm
+            .inner_mut()
+            .take_fee_settlement()
+            .map_err(BlockExecutionError::other)?
+        else {
+            return Ok(());
+        };
+
+        self.inner.system_caller.on_state(
+            StateChangeSource::PreBlock(StateChangePreBlockSource::BlockHashesContract),
+            &state,
+        );
+        self.inner.evm.db_mut().commit(state);
+
+        Ok(())
+    }
+
     /// Validates a system transaction.
     pub(crate) fn validate_system_tx(
         &self,
@@ -431,6 +464,7 @@ where
         if self.inner.spec.is_t3_active_at_timestamp(timestamp) {
             self.deploy_precompile_at_boundary(SIGNATURE_VERIFIER_ADDRESS)?;
             self.deploy_precompile_at_boundary(ADDRESS_REGISTRY_ADDRESS)?;
+            self.deploy_precompile_at_boundary(P256_VERIFY_ADDRESS)?;
         }
 
         Ok(())
@@ -539,8 +573,9 @@ where
     }
 
     fn finish(
-        self,
+        mut self,
     ) -> Result<(Self::Evm, BlockExecutionResult<Self::Receipt>), BlockExecutionError> {
+        self.apply_fee_settlement()?;
         self.inner.finish()
     }
 
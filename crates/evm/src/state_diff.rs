@@ -0,0 +1,429 @@
+//! Deterministic per-transaction state diff export for differential testing.
+//!
+//! Two node builds executing the same block must produce identical precompile
+//! storage writes. This module captures the pre/post values of every touched
+//! precompile slot through the executor's existing [`OnStateHook`] mechanism
+//! and serializes them in a canonical form (slots sorted by `(address, slot)`,
+//! one JSON object per block) so that dumps from different builds can be
+//! compared byte-for-byte or slot-by-slot via `cargo xtask compare-state-diffs`.
+
+use alloy_evm::block::{OnStateHook, StateChangeSource};
+use alloy_primitives::{Address, B256, U256, keccak256};
+use reth_revm::state::EvmState;
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+    sync::{Arc, Mutex},
+};
+use tempo_contracts::precompiles::{
+    ACCOUNT_KEYCHAIN_ADDRESS, ADDRESS_REGISTRY_ADDRESS, NONCE_PRECOMPILE_ADDRESS,
+    STABLECOIN_DEX_ADDRESS, TIP_FEE_MANAGER_ADDRESS, TIP20_FACTORY_ADDRESS,
+    TIP403_REGISTRY_ADDRESS, VALIDATOR_CONFIG_ADDRESS, VALIDATOR_CONFIG_V2_ADDRESS,
+};
+use tempo_primitives::TempoAddressExt;
+
+/// Returns `true` if storage writes to `address` should be captured.
+///
+/// Tracks every TIP-20 token (by prefix) plus the fixed-address stateful precompiles.
+/// Pure-function precompiles (signature verifier, P256) carry no storage and are skipped.
+pub fn is_tracked_precompile(address: &Address) -> bool {
+    address.is_tip20()
+        || *address == TIP20_FACTORY_ADDRESS
+        || *address == ADDRESS_REGISTRY_ADDRESS
+        || *address == TIP403_REGISTRY_ADDRESS
+        || *address == TIP_FEE_MANAGER_ADDRESS
+        || *address == STABLECOIN_DEX_ADDRESS
+        || *address == NONCE_PRECOMPILE_ADDRESS
+        || *address == VALIDATOR_CONFIG_ADDRESS
+        || *address == ACCOUNT_KEYCHAIN_ADDRESS
+        || *address == VALIDATOR_CONFIG_V2_ADDRESS
+}
+
+/// Pre/post value of a single storage slot touched by a transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SlotDiff {
+    pub slot: U256,
+    pub pre: U256,
+    pub post: U256,
+}
+
+/// All tracked slots written by one transaction, keyed by precompile address.
+///
+/// [`BTreeMap`] keys and the per-address slot vectors are both sorted, so the
+/// serialized form is canonical for a given set of writes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TxStateDiff {
+    /// Index of the transaction within its block.
+    pub tx_index: u64,
+    pub slots: BTreeMap<Address, Vec<SlotDiff>>,
+}
+
+impl TxStateDiff {
+    /// Extracts the tracked slot writes from a committed transaction state.
+    ///
+    /// Only slots whose post value differs from the pre value are recorded;
+    /// returns `None` if the transaction touched no tracked precompile storage.
+    pub fn from_state(tx_index: u64, state: &EvmState) -> Option<Self> {
+        let mut slots: BTreeMap<Address, Vec<SlotDiff>> = BTreeMap::new();
+        for (address, account) in state {
+            if !account.is_touched() || !is_tracked_precompile(address) {
+                continue;
+            }
+            let mut changed: Vec<SlotDiff> = account
+                .storage
+                .iter()
+                .filter(|(_, slot)| slot.original_value() != slot.present_value)
+                .map(|(key, slot)| SlotDiff {
+                    slot: *key,
+                    pre: slot.original_value(),
+                    post: slot.present_value,
+                })
+                .collect();
+            if changed.is_empty() {
+                continue;
+            }
+            changed.sort_by_key(|diff| diff.slot);
+            slots.insert(*address, changed);
+        }
+        (!slots.is_empty()).then_some(Self { tx_index, slots })
+    }
+}
+
+/// Canonical per-block record of all tracked transaction state diffs.
+///
+/// Exports are JSONL files with one `BlockStateDiff` per line, in block order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockStateDiff {
+    pub block_number: u64,
+    /// Diffs of transactions that wrote tracked slots, in execution order.
+    pub txs: Vec<TxStateDiff>,
+}
+
+impl BlockStateDiff {
+    /// Creates an empty record for `block_number`.
+    pub const fn new(block_number: u64) -> Self {
+        Self {
+            block_number,
+            txs: Vec::new(),
+        }
+    }
+
+    /// Keccak digest of the canonical JSON encoding.
+    ///
+    /// Two builds diverge on this block iff their digests differ, which makes
+    /// digests a cheap first-pass comparison before drilling into slots.
+    pub fn digest(&self) -> B256 {
+        let encoded = serde_json::to_vec(self).expect("state diff serialization cannot fail");
+        keccak256(&encoded)
+    }
+
+    /// Compares two records of the same block, returning the first divergence.
+    pub fn compare(&self, other: &Self) -> Option<Divergence> {
+        if self.block_number != other.block_number {
+            return Some(Divergence::BlockNumberMismatch {
+                left: self.block_number,
+                right: other.block_number,
+            });
+        }
+        let left_txs: BTreeMap<u64, &TxStateDiff> =
+            self.txs.iter().map(|tx| (tx.tx_index, tx)).collect();
+        let right_txs: BTreeMap<u64, &TxStateDiff> =
+            other.txs.iter().map(|tx| (tx.tx_index, tx)).collect();
+
+        let tx_indices: BTreeSet<u64> = left_txs.keys().chain(right_txs.keys()).copied().collect();
+        for tx_index in &tx_indices {
+            match (left_txs.get(tx_index), right_txs.get(tx_index)) {
+                (Some(left), Some(right)) => {
+                    if let Some(divergence) = compare_tx(left, right) {
+                        return Some(divergence);
+                    }
+                }
+                (left, _) => {
+                    return Some(Divergence::TxPresence {
+                        tx_index: *tx_index,
+                        left_only: left.is_some(),
+                    });
+                }
+            }
+        }
+        None
+    }
+}
+
+fn compare_tx(left: &TxStateDiff, right: &TxStateDiff) -> Option<Divergence> {
+    let addresses: BTreeSet<Address> = left
+        .slots
+        .keys()
+        .chain(right.slots.keys())
+        .copied()
+        .collect();
+    for address in &addresses {
+        let left_slots = left.slots.get(address).map(Vec::as_slice).unwrap_or(&[]);
+        let right_slots = right.slots.get(address).map(Vec::as_slice).unwrap_or(&[]);
+        let left_by_slot: BTreeMap<U256, &SlotDiff> =
+            left_slots.iter().map(|diff| (diff.slot, diff)).collect();
+        let right_by_slot: BTreeMap<U256, &SlotDiff> =
+            right_slots.iter().map(|diff| (diff.slot, diff)).collect();
+        let slots: BTreeSet<U256> = left_by_slot
+            .keys()
+            .chain(right_by_slot.keys())
+            .copied()
+            .collect();
+        for slot in &slots {
+            let left_diff = left_by_slot.get(slot).copied();
+            let right_diff = right_by_slot.get(slot).copied();
+            if left_diff != right_diff {
+                return Some(Divergence::SlotMismatch {
+                    tx_index: left.tx_index,
+                    address: *address,
+                    slot: *slot,
+                    left: left_diff.cloned(),
+                    right: right_diff.cloned(),
+                });
+            }
+        }
+    }
+    None
+}
+
+/// First point at which two exports of the same block disagree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Divergence {
+    /// The records describe different blocks.
+    BlockNumberMismatch { left: u64, right: u64 },
+    /// One build recorded tracked writes for a transaction the other did not.
+    TxPresence { tx_index: u64, left_only: bool },
+    /// The builds wrote different values (or one did not write) to a slot.
+    SlotMismatch {
+        tx_index: u64,
+        address: Address,
+        slot: U256,
+        left: Option<SlotDiff>,
+        right: Option<SlotDiff>,
+    },
+}
+
+impl fmt::Display for Divergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BlockNumberMismatch { left, right } => {
+                write!(f, "block number mismatch: left {left}, right {right}")
+            }
+            Self::TxPresence {
+                tx_index,
+                left_only,
+            } => {
+                let side = if *left_only { "left" } else { "right" };
+                write!(
+                    f,
+                    "tx {tx_index}: only the {side} build recorded tracked writes"
+                )
+            }
+            Self::SlotMismatch {
+                tx_index,
+                address,
+                slot,
+                left,
+                right,
+            } => {
+                write!(
+                    f,
+                    "tx {tx_index}: slot {slot:#x} of {address} differs: left {left:?}, right {right:?}"
+                )
+            }
+        }
+    }
+}
+
+/// [`OnStateHook`] that accumulates tracked precompile slot diffs for one block.
+///
+/// Clone the recorder before handing it to [`BlockExecutor::set_state_hook`];
+/// all clones share the same record, so the caller can [`Self::finish`] its
+/// copy after execution. Non-transaction state changes (pre/post-block system
+/// updates) are intentionally ignored: they are deterministic per hardfork and
+/// would only add noise to per-transaction comparisons.
+///
+/// [`BlockExecutor::set_state_hook`]: alloy_evm::block::BlockExecutor::set_state_hook
+#[derive(Debug, Clone)]
+pub struct StateDiffRecorder {
+    record: Arc<Mutex<BlockStateDiff>>,
+}
+
+impl StateDiffRecorder {
+    /// Creates a recorder for `block_number`.
+    pub fn new(block_number: u64) -> Self {
+        Self {
+            record: Arc::new(Mutex::new(BlockStateDiff::new(block_number))),
+        }
+    }
+
+    /// Returns the accumulated block record.
+    pub fn finish(&self) -> BlockStateDiff {
+        self.record
+            .lock()
+            .expect("state diff lock poisoned")
+            .clone()
+    }
+}
+
+impl OnStateHook for StateDiffRecorder {
+    fn on_state(&mut self, source: StateChangeSource, state: &EvmState) {
+        let StateChangeSource::Transaction(tx_index) = source else {
+            return;
+        };
+        if let Some(diff) = TxStateDiff::from_state(tx_index as u64, state) {
+            self.record
+                .lock()
+                .expect("state diff lock poisoned")
+                .txs
+                .push(diff);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use reth_revm::state::{Account, EvmStorageSlot};
+    use tempo_contracts::precompiles::PATH_USD_ADDRESS;
+
+    fn state_with_write(address: Address, slot: u64, pre: u64, post: u64) -> EvmState {
+        let mut account = Account::default();
+        account.mark_touch();
+        account.storage.insert(
+            U256::from(slot),
+            EvmStorageSlot::new_changed(U256::from(pre), U256::from(post), 0),
+        );
+        EvmState::from_iter([(address, account)])
+    }
+
+    #[test]
+    fn test_recorder_captures_only_tracked_transaction_writes() {
+        let mut recorder = StateDiffRecorder::new(7);
+
+        // A TIP-20 token write in tx 0 is tracked.
+        recorder.on_state(
+            StateChangeSource::Transaction(0),
+            &state_with_write(PATH_USD_ADDRESS, 1, 0, 100),
+        );
+        // A plain EOA write is not.
+        recorder.on_state(
+            StateChangeSource::Transaction(1),
+            &state_with_write(Address::with_last_byte(0x42), 1, 0, 100),
+        );
+        // Unchanged slots and non-transaction sources are ignored.
+        recorder.on_state(
+            StateChangeSource::Transaction(2),
+            &state_with_write(PATH_USD_ADDRESS, 1, 100, 100),
+        );
+
+        let record = recorder.finish();
+        assert_eq!(record.block_number, 7);
+        assert_eq!(record.txs.len(), 1);
+        assert_eq!(record.txs[0].tx_index, 0);
+        let slots = &record.txs[0].slots[&PATH_USD_ADDRESS];
+        assert_eq!(
+            slots,
+            &[SlotDiff {
+                slot: U256::from(1),
+                pre: U256::ZERO,
+                post: U256::from(100),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_digest_is_order_independent_for_slots() {
+        // Two accounts written in different iteration orders must canonicalize
+        // to the same record, since exports from different builds may observe
+        // hash-map iteration differently.
+        let mut account_a = Account::default();
+        account_a.mark_touch();
+        account_a.storage.insert(
+            U256::from(2),
+            EvmStorageSlot::new_changed(U256::ZERO, U256::from(5), 0),
+        );
+        account_a.storage.insert(
+            U256::from(1),
+            EvmStorageSlot::new_changed(U256::ZERO, U256::from(9), 0),
+        );
+        let state = EvmState::from_iter([(PATH_USD_ADDRESS, account_a)]);
+
+        let diff = TxStateDiff::from_state(0, &state).unwrap();
+        let slots = &diff.slots[&PATH_USD_ADDRESS];
+        assert_eq!(slots[0].slot, U256::from(1));
+        assert_eq!(slots[1].slot, U256::from(2));
+
+        let left = BlockStateDiff {
+            block_number: 1,
+            txs: vec![diff.clone()],
+        };
+        let right = BlockStateDiff {
+            block_number: 1,
+            txs: vec![diff],
+        };
+        assert_eq!(left.digest(), right.digest());
+        assert!(left.compare(&right).is_none());
+    }
+
+    #[test]
+    fn test_compare_reports_first_divergence() {
+        let make = |post: u64| {
+            let mut recorder = StateDiffRecorder::new(3);
+            recorder.on_state(
+                StateChangeSource::Transaction(0),
+                &state_with_write(ACCOUNT_KEYCHAIN_ADDRESS, 8, 0, post),
+            );
+            recorder.finish()
+        };
+        let left = make(10);
+        let right = make(11);
+
+        assert_ne!(left.digest(), right.digest());
+        match left.compare(&right) {
+            Some(Divergence::SlotMismatch {
+                tx_index,
+                address,
+                slot,
+                left: Some(left_diff),
+                right: Some(right_diff),
+            }) => {
+                assert_eq!(tx_index, 0);
+                assert_eq!(address, ACCOUNT_KEYCHAIN_ADDRESS);
+                assert_eq!(slot, U256::from(8));
+                assert_eq!(left_diff.post, U256::from(10));
+                assert_eq!(right_diff.post, U256::from(11));
+            }
+            other => panic!("unexpected comparison result: {other:?}"),
+        }
+
+        // A tx missing on one side is a presence divergence.
+        let empty = BlockStateDiff::new(3);
+        assert!(matches!(
+            left.compare(&empty),
+            Some(Divergence::TxPresence {
+                tx_index: 0,
+                left_only: true
+            })
+        ));
+    }
+
+    #[test]
+    fn test_json_round_trip() {
+        let mut recorder = StateDiffRecorder::new(12);
+        recorder.on_state(
+            StateChangeSource::Transaction(3),
+            &state_with_write(TIP_FEE_MANAGER_ADDRESS, 0, 7, 9),
+        );
+        let record = recorder.finish();
+
+        let line = serde_json::to_string(&record).unwrap();
+        let decoded: BlockStateDiff = serde_json::from_str(&line).unwrap();
+        assert_eq!(decoded, record);
+        assert_eq!(decoded.digest(), record.digest());
+    }
+}
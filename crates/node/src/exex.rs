@@ -0,0 +1,344 @@
+//! Generic execution extension (ExEx) registry.
+//!
+//! ExExes are off-critical-path consumers of canonical chain notifications
+//! (bridge, indexer, alerting, ...). The registry decouples them from the node
+//! launch path: each ExEx gets its own bounded notification channel and task, so
+//! a slow or failing extension never affects the others or block processing.
+//!
+//! Backpressure is per-ExEx: a bounded buffer absorbs bursts and, once full,
+//! stalls only the dispatch to that extension. Per-ExEx lag relative to the
+//! canonical tip is exported as a gauge so slow consumers are visible in
+//! dashboards before their buffers fill up.
+
+use alloy::consensus::BlockHeader as _;
+use async_trait::async_trait;
+use futures::{Stream, StreamExt as _};
+use reth_metrics::{
+    Metrics,
+    metrics::{Counter, Gauge},
+};
+use reth_provider::CanonStateNotification;
+use std::sync::{
+    Arc,
+    atomic::{AtomicU64, Ordering},
+};
+use tempo_primitives::TempoPrimitives;
+use tokio::{sync::mpsc, task::JoinHandle};
+use tracing::{debug, error, warn};
+
+/// Default per-ExEx notification buffer size.
+pub const DEFAULT_EXEX_BUFFER: usize = 1024;
+
+/// A canonical chain notification consumable by an ExEx.
+///
+/// Abstracts over the concrete notification type so the registry can be
+/// exercised without a running node.
+pub trait ExExNotification: Clone + Send + 'static {
+    /// Block number of the canonical tip this notification advances to.
+    fn tip_number(&self) -> u64;
+}
+
+impl ExExNotification for CanonStateNotification<TempoPrimitives> {
+    fn tip_number(&self) -> u64 {
+        self.committed().tip().number()
+    }
+}
+
+/// An execution extension with an independent lifecycle.
+///
+/// Implementations process notifications sequentially; returning an error shuts
+/// the extension down without affecting other registered ExExes or the node.
+#[async_trait]
+pub trait ExEx<N>: Send + 'static {
+    /// Unique name, used for logging and as the `exex` metric label.
+    fn name(&self) -> &'static str;
+
+    /// Processes one canonical chain notification.
+    async fn on_notification(&mut self, notification: N) -> eyre::Result<()>;
+}
+
+/// Collects ExExes before launch. Builders register extensions based on their
+/// CLI/config args, then [`launch`](Self::launch) spawns one task per ExEx.
+pub struct ExExRegistry<N> {
+    exexes: Vec<(Box<dyn ExEx<N>>, usize)>,
+}
+
+impl<N> Default for ExExRegistry<N> {
+    fn default() -> Self {
+        Self { exexes: Vec::new() }
+    }
+}
+
+impl<N> std::fmt::Debug for ExExRegistry<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ExExRegistry")
+            .field(
+                "exexes",
+                &self
+                    .exexes
+                    .iter()
+                    .map(|(exex, _)| exex.name())
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+impl<N: ExExNotification> ExExRegistry<N> {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers an ExEx with the [default buffer size](DEFAULT_EXEX_BUFFER).
+    pub fn register(&mut self, exex: impl ExEx<N>) {
+        self.register_with_buffer(exex, DEFAULT_EXEX_BUFFER);
+    }
+
+    /// Registers an ExEx with a custom notification buffer size.
+    pub fn register_with_buffer(&mut self, exex: impl ExEx<N>, buffer: usize) {
+        self.exexes.push((Box::new(exex), buffer.max(1)));
+    }
+
+    /// Returns true if no ExExes are registered.
+    pub fn is_empty(&self) -> bool {
+        self.exexes.is_empty()
+    }
+
+    /// Returns the names of all registered ExExes.
+    pub fn names(&self) -> Vec<&'static str> {
+        self.exexes.iter().map(|(exex, _)| exex.name()).collect()
+    }
+
+    /// Spawns one task per registered ExEx and returns the dispatching manager.
+    ///
+    /// Must be called from within a tokio runtime.
+    pub fn launch(self) -> ExExManager<N> {
+        let canonical_tip = Arc::new(AtomicU64::new(0));
+        let mut handles = Vec::with_capacity(self.exexes.len());
+        let mut tasks = Vec::with_capacity(self.exexes.len());
+
+        for (exex, buffer) in self.exexes {
+            let name = exex.name();
+            let (sender, receiver) = mpsc::channel(buffer);
+            tasks.push(tokio::spawn(exex_task(
+                exex,
+                receiver,
+                canonical_tip.clone(),
+            )));
+            handles.push(ExExHandle { name, sender });
+        }
+
+        ExExManager {
+            handles,
+            tasks,
+            canonical_tip,
+        }
+    }
+}
+
+/// Dispatch side of one registered ExEx.
+struct ExExHandle<N> {
+    name: &'static str,
+    sender: mpsc::Sender<N>,
+}
+
+/// Fans canonical chain notifications out to all launched ExExes.
+pub struct ExExManager<N> {
+    handles: Vec<ExExHandle<N>>,
+    tasks: Vec<JoinHandle<()>>,
+    canonical_tip: Arc<AtomicU64>,
+}
+
+impl<N: ExExNotification> ExExManager<N> {
+    /// Delivers a notification to every live ExEx.
+    ///
+    /// A full buffer backpressures dispatch to that ExEx only insofar as this
+    /// call awaits it; an ExEx that has shut down is dropped from the fan-out.
+    pub async fn notify(&mut self, notification: N) {
+        self.canonical_tip
+            .store(notification.tip_number(), Ordering::Relaxed);
+
+        let mut dead = Vec::new();
+        for (idx, handle) in self.handles.iter().enumerate() {
+            if handle.sender.send(notification.clone()).await.is_err() {
+                warn!(target: "tempo::exex", exex = handle.name, "ExEx shut down, removing from fan-out");
+                dead.push(idx);
+            }
+        }
+        for idx in dead.into_iter().rev() {
+            self.handles.swap_remove(idx);
+        }
+    }
+
+    /// Drives the manager off a canonical state notification stream until the
+    /// stream ends, then shuts all ExExes down.
+    pub async fn run<S>(mut self, mut notifications: S)
+    where
+        S: Stream<Item = N> + Send + Unpin,
+    {
+        while let Some(notification) = notifications.next().await {
+            self.notify(notification).await;
+        }
+        self.shutdown().await;
+    }
+
+    /// Closes all notification channels and waits for the ExEx tasks to drain.
+    pub async fn shutdown(self) {
+        drop(self.handles);
+        for task in self.tasks {
+            let _ = task.await;
+        }
+    }
+}
+
+/// Per-ExEx metrics, labelled with the ExEx name.
+#[derive(Metrics, Clone)]
+#[metrics(scope = "tempo_exex")]
+struct ExExMetrics {
+    /// Block number of the last notification processed by this ExEx.
+    processed_height: Gauge,
+    /// Blocks between the canonical tip and the last processed notification.
+    lag: Gauge,
+    /// Total notifications processed by this ExEx.
+    notifications_processed_total: Counter,
+    /// Total notifications that failed processing.
+    notification_errors_total: Counter,
+}
+
+/// Drives a single ExEx until its channel closes or it returns an error.
+async fn exex_task<N: ExExNotification>(
+    mut exex: Box<dyn ExEx<N>>,
+    mut notifications: mpsc::Receiver<N>,
+    canonical_tip: Arc<AtomicU64>,
+) {
+    let name = exex.name();
+    let metrics = ExExMetrics::new_with_labels(&[("exex", name)]);
+
+    while let Some(notification) = notifications.recv().await {
+        let height = notification.tip_number();
+        match exex.on_notification(notification).await {
+            Ok(()) => {
+                metrics.notifications_processed_total.increment(1);
+                metrics.processed_height.set(height as f64);
+                let tip = canonical_tip.load(Ordering::Relaxed);
+                metrics.lag.set(tip.saturating_sub(height) as f64);
+            }
+            Err(err) => {
+                metrics.notification_errors_total.increment(1);
+                error!(target: "tempo::exex", exex = name, %err, "ExEx failed, shutting it down");
+                return;
+            }
+        }
+    }
+    debug!(target: "tempo::exex", exex = name, "notification stream closed, ExEx shutting down");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    impl ExExNotification for u64 {
+        fn tip_number(&self) -> u64 {
+            *self
+        }
+    }
+
+    struct RecordingExEx {
+        name: &'static str,
+        seen: Arc<Mutex<Vec<u64>>>,
+        fail_on: Option<u64>,
+    }
+
+    #[async_trait]
+    impl ExEx<u64> for RecordingExEx {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        async fn on_notification(&mut self, notification: u64) -> eyre::Result<()> {
+            if self.fail_on == Some(notification) {
+                eyre::bail!("induced failure at {notification}");
+            }
+            self.seen.lock().unwrap().push(notification);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_notifications_fan_out_to_all_exexes() {
+        let first = Arc::new(Mutex::new(Vec::new()));
+        let second = Arc::new(Mutex::new(Vec::new()));
+
+        let mut registry = ExExRegistry::new();
+        registry.register(RecordingExEx {
+            name: "first",
+            seen: first.clone(),
+            fail_on: None,
+        });
+        registry.register(RecordingExEx {
+            name: "second",
+            seen: second.clone(),
+            fail_on: None,
+        });
+        assert_eq!(registry.names(), vec!["first", "second"]);
+
+        let mut manager = registry.launch();
+        for height in 1..=3 {
+            manager.notify(height).await;
+        }
+        manager.shutdown().await;
+
+        assert_eq!(*first.lock().unwrap(), vec![1, 2, 3]);
+        assert_eq!(*second.lock().unwrap(), vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_failing_exex_does_not_affect_others() {
+        let flaky = Arc::new(Mutex::new(Vec::new()));
+        let steady = Arc::new(Mutex::new(Vec::new()));
+
+        let mut registry = ExExRegistry::new();
+        registry.register(RecordingExEx {
+            name: "flaky",
+            seen: flaky.clone(),
+            fail_on: Some(2),
+        });
+        registry.register(RecordingExEx {
+            name: "steady",
+            seen: steady.clone(),
+            fail_on: None,
+        });
+
+        let mut manager = registry.launch();
+        for height in 1..=3 {
+            manager.notify(height).await;
+        }
+        manager.shutdown().await;
+
+        // The flaky ExEx shut down at height 2; the steady one saw everything.
+        assert_eq!(*flaky.lock().unwrap(), vec![1]);
+        assert_eq!(*steady.lock().unwrap(), vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_run_drains_stream_and_shuts_down() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+
+        let mut registry = ExExRegistry::new();
+        registry.register_with_buffer(
+            RecordingExEx {
+                name: "stream",
+                seen: seen.clone(),
+                fail_on: None,
+            },
+            2,
+        );
+
+        let manager = registry.launch();
+        manager.run(futures::stream::iter(1u64..=5)).await;
+
+        assert_eq!(*seen.lock().unwrap(), vec![1, 2, 3, 4, 5]);
+    }
+}
@@ -13,8 +13,13 @@ use reth_node_builder::{FullNode, NodeAdapter, RethFullAdapter};
 pub use tempo_transaction_pool::validator::DEFAULT_AA_VALID_AFTER_MAX_SECS;
 
 pub mod engine;
+pub mod exex;
+pub mod light_client;
+pub mod log_index;
 pub mod node;
+pub mod prune;
 pub mod rpc;
+pub mod state_sync;
 pub mod telemetry;
 pub use tempo_consensus as consensus;
 pub use tempo_evm as evm;
@@ -0,0 +1,270 @@
+//! Light client header server.
+//!
+//! A small HTTP service, optionally enabled on the node, that serves the raw
+//! artifacts a light client needs to follow Tempo — without running both an
+//! execution RPC and a consensus RPC with custom glue:
+//!
+//! - `GET /header/{id}` — the canonical RLP-encoded header, by decimal height
+//!   or `0x`-prefixed block hash, as `application/octet-stream`.
+//! - `GET /certificate/{height}` — the finalization certificate over the
+//!   block at `height`, as `application/octet-stream`.
+//! - `GET /receipt-proof/{id}/{tx_index}` — a JSON
+//!   [`BurnProof`](tempo_bridge::proof::BurnProof): the RLP receipt, its
+//!   Merkle-Patricia proof against the header's `receiptsRoot`, the RLP
+//!   header, and the finalization certificate.
+//!
+//! Unknown blocks, unfinalized heights, and out-of-range indices return
+//! `404`; malformed identifiers return `400`.
+
+use crate::rpc::consensus::types::{ConsensusFeed, Query};
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::{B256, Bytes, hex};
+use axum::{
+    Json, Router,
+    body::Body,
+    extract::{Path, State},
+    http::{Response, StatusCode, header},
+    response::IntoResponse,
+    routing::get,
+};
+use eyre::WrapErr as _;
+use futures::Future;
+use reth_primitives_traits::AlloyBlockHeader as _;
+use reth_provider::{HeaderProvider, ReceiptProvider};
+use std::{net::SocketAddr, str::FromStr, sync::Arc};
+use tempo_bridge::proof::{BurnProof, build_receipt_proof};
+use tempo_primitives::{TempoHeader, TempoReceipt};
+use tokio::net::TcpListener;
+
+/// Block identifier in request paths: a decimal height or a `0x`-prefixed
+/// block hash.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HeaderId {
+    /// Block height.
+    Height(u64),
+    /// Block hash.
+    Hash(B256),
+}
+
+impl FromStr for HeaderId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("0x") {
+            s.parse::<B256>()
+                .map(Self::Hash)
+                .map_err(|err| format!("invalid block hash: {err}"))
+        } else {
+            s.parse::<u64>()
+                .map(Self::Height)
+                .map_err(|err| format!("invalid block height: {err}"))
+        }
+    }
+}
+
+/// Data source for the light client header server.
+///
+/// Split from the HTTP layer so handlers stay testable; the node wires in
+/// [`NodeLightClientSource`].
+pub trait LightClientSource: Send + Sync + 'static {
+    /// The canonical RLP-encoded header for `id`, if known.
+    fn header_rlp(&self, id: HeaderId) -> impl Future<Output = eyre::Result<Option<Bytes>>> + Send;
+
+    /// The finalization certificate over the block at `height`, if this node
+    /// has observed one.
+    fn finalization_certificate(&self, height: u64) -> impl Future<Output = Option<Bytes>> + Send;
+
+    /// A full burn-proof-shaped receipt proof for the receipt at `tx_index`
+    /// of block `id`. `None` when the block is unknown, the index is out of
+    /// range, or no finalization certificate is available yet.
+    fn receipt_proof(
+        &self,
+        id: HeaderId,
+        tx_index: u64,
+    ) -> impl Future<Output = eyre::Result<Option<BurnProof>>> + Send;
+}
+
+/// [`LightClientSource`] backed by the node's provider and the consensus
+/// feed.
+#[derive(Debug, Clone)]
+pub struct NodeLightClientSource<P, F> {
+    provider: P,
+    consensus: F,
+}
+
+impl<P, F> NodeLightClientSource<P, F> {
+    /// Creates a source over the given provider and consensus feed.
+    pub const fn new(provider: P, consensus: F) -> Self {
+        Self {
+            provider,
+            consensus,
+        }
+    }
+}
+
+impl<P, F> NodeLightClientSource<P, F>
+where
+    P: HeaderProvider<Header = TempoHeader> + ReceiptProvider<Receipt = TempoReceipt>,
+{
+    fn header(&self, id: HeaderId) -> eyre::Result<Option<TempoHeader>> {
+        let header = match id {
+            HeaderId::Height(height) => self.provider.header_by_number(height)?,
+            HeaderId::Hash(hash) => self.provider.header(&hash)?,
+        };
+        Ok(header)
+    }
+}
+
+impl<P, F> LightClientSource for NodeLightClientSource<P, F>
+where
+    P: HeaderProvider<Header = TempoHeader>
+        + ReceiptProvider<Receipt = TempoReceipt>
+        + Send
+        + Sync
+        + 'static,
+    F: ConsensusFeed,
+{
+    async fn header_rlp(&self, id: HeaderId) -> eyre::Result<Option<Bytes>> {
+        Ok(self
+            .header(id)?
+            .map(|header| alloy_rlp::encode(&header).into()))
+    }
+
+    async fn finalization_certificate(&self, height: u64) -> Option<Bytes> {
+        let certified = self
+            .consensus
+            .get_finalization(Query::Height(height))
+            .await?;
+        hex::decode(&certified.certificate).ok().map(Into::into)
+    }
+
+    async fn receipt_proof(&self, id: HeaderId, tx_index: u64) -> eyre::Result<Option<BurnProof>> {
+        let Some(header) = self.header(id)? else {
+            return Ok(None);
+        };
+        let height = header.number();
+        let Some(receipts) = self.provider.receipts_by_block(height.into())? else {
+            return Ok(None);
+        };
+        let encoded: Vec<Bytes> = receipts
+            .iter()
+            .map(|receipt| receipt.encoded_2718().into())
+            .collect();
+        let Some((_, proof_nodes)) = build_receipt_proof(&encoded, tx_index) else {
+            return Ok(None);
+        };
+        let Some(certificate) = self.finalization_certificate(height).await else {
+            return Ok(None);
+        };
+        Ok(Some(BurnProof {
+            header_rlp: alloy_rlp::encode(&header).into(),
+            receipt_rlp: encoded[tx_index as usize].clone(),
+            proof_nodes,
+            tx_index,
+            finalization_certificate: certificate,
+        }))
+    }
+}
+
+/// Builds the light client router over `source`.
+pub fn router<S: LightClientSource>(source: S) -> Router {
+    Router::new()
+        .route("/header/{id}", get(header_handler::<S>))
+        .route("/certificate/{height}", get(certificate_handler::<S>))
+        .route(
+            "/receipt-proof/{id}/{tx_index}",
+            get(receipt_proof_handler::<S>),
+        )
+        .with_state(Arc::new(source))
+}
+
+/// Serves the light client endpoints on `listen_addr` until the task is
+/// dropped or the server errors.
+pub async fn serve<S: LightClientSource>(listen_addr: SocketAddr, source: S) -> eyre::Result<()> {
+    let listener = TcpListener::bind(listen_addr)
+        .await
+        .wrap_err("failed to bind light client server address")?;
+    axum::serve(listener, router(source).into_make_service())
+        .await
+        .map_err(Into::into)
+}
+
+fn octet_stream(bytes: Bytes) -> Response<Body> {
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "application/octet-stream")
+        .body(Body::from(bytes))
+        .expect("static response parts are valid")
+}
+
+fn status(code: StatusCode) -> Response<Body> {
+    Response::builder()
+        .status(code)
+        .body(Body::empty())
+        .expect("static response parts are valid")
+}
+
+async fn header_handler<S: LightClientSource>(
+    State(source): State<Arc<S>>,
+    Path(id): Path<String>,
+) -> Response<Body> {
+    let Ok(id) = id.parse::<HeaderId>() else {
+        return status(StatusCode::BAD_REQUEST);
+    };
+    match source.header_rlp(id).await {
+        Ok(Some(rlp)) => octet_stream(rlp),
+        Ok(None) => status(StatusCode::NOT_FOUND),
+        Err(err) => {
+            tracing::warn!(%err, "light client header lookup failed");
+            status(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+async fn certificate_handler<S: LightClientSource>(
+    State(source): State<Arc<S>>,
+    Path(height): Path<u64>,
+) -> Response<Body> {
+    match source.finalization_certificate(height).await {
+        Some(certificate) => octet_stream(certificate),
+        None => status(StatusCode::NOT_FOUND),
+    }
+}
+
+async fn receipt_proof_handler<S: LightClientSource>(
+    State(source): State<Arc<S>>,
+    Path((id, tx_index)): Path<(String, u64)>,
+) -> Response<Body> {
+    let Ok(id) = id.parse::<HeaderId>() else {
+        return status(StatusCode::BAD_REQUEST);
+    };
+    match source.receipt_proof(id, tx_index).await {
+        Ok(Some(proof)) => Json(proof).into_response(),
+        Ok(None) => status(StatusCode::NOT_FOUND),
+        Err(err) => {
+            tracing::warn!(%err, "light client receipt proof failed");
+            status(StatusCode::INTERNAL_SERVER_ERROR)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::b256;
+
+    #[test]
+    fn parses_header_ids() {
+        assert_eq!("42".parse::<HeaderId>().unwrap(), HeaderId::Height(42));
+
+        let hash = b256!("0x00000000000000000000000000000000000000000000000000000000deadbeef");
+        assert_eq!(
+            format!("{hash}").parse::<HeaderId>().unwrap(),
+            HeaderId::Hash(hash)
+        );
+
+        assert!("".parse::<HeaderId>().is_err());
+        assert!("-1".parse::<HeaderId>().is_err());
+        assert!("0xnothex".parse::<HeaderId>().is_err());
+    }
+}
@@ -0,0 +1,323 @@
+//! Historical log index optimized for TIP-20 queries.
+//!
+//! `eth_getLogs` over large block ranges scans every header bloom in the
+//! range, which takes seconds on a chain where almost every block contains
+//! TIP-20 Transfer events. This module maintains an `(address, topic0) ->
+//! sorted block list` index as an [`ExEx`], so the RPC layer can resolve a
+//! filter over millions of blocks to the exact blocks that contain matches
+//! in milliseconds and only fetch receipts for those.
+//!
+//! The index is scoped by configuration to a set of contract addresses
+//! (typically the TIP-20 precompiles and factory-created tokens) rather than
+//! indexing every log on the chain. It is rebuilt on restart: the launcher
+//! backfills from the provider via [`backfill_from_provider`] and the ExEx keeps
+//! it current from canonical state notifications, unwinding on reorgs.
+
+use crate::exex::ExEx;
+use alloy::consensus::{BlockHeader as _, TxReceipt as _};
+use alloy_primitives::{Address, B256};
+use async_trait::async_trait;
+use reth_provider::{BlockNumReader, CanonStateNotification, ReceiptProvider};
+use std::{
+    collections::{HashMap, HashSet},
+    ops::RangeInclusive,
+    sync::{
+        Arc, RwLock,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+use tempo_primitives::{TempoPrimitives, TempoReceipt};
+use tracing::info;
+
+/// Configuration for the historical log index.
+#[derive(Debug, Clone, Default)]
+pub struct LogIndexConfig {
+    /// Contract addresses whose logs are indexed. An empty set disables the
+    /// index entirely.
+    pub addresses: Vec<Address>,
+}
+
+impl LogIndexConfig {
+    /// Returns true if at least one address is configured for indexing.
+    pub fn is_enabled(&self) -> bool {
+        !self.addresses.is_empty()
+    }
+}
+
+/// An `(emitting address, topic0)` pair the index keys block lists by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LogIndexKey {
+    /// Address that emitted the log.
+    pub address: Address,
+    /// First topic of the log (the event signature hash).
+    pub topic: B256,
+}
+
+/// Shared `(address, topic0) -> block list` index.
+///
+/// Writers ([`LogIndexExEx`] and backfill) and readers (the RPC layer) share
+/// the index through an [`Arc`]; block lists are kept sorted and deduplicated
+/// so range queries are two binary searches.
+#[derive(Debug)]
+pub struct LogIndex {
+    /// Addresses the index is scoped to.
+    addresses: HashSet<Address>,
+    /// Whether historical backfill has completed. Until it has, the index may
+    /// be missing blocks below [`Self::indexed_through`], so range queries
+    /// must fall back to a scan.
+    backfilled: AtomicBool,
+    inner: RwLock<LogIndexInner>,
+}
+
+#[derive(Debug, Default)]
+struct LogIndexInner {
+    /// Ascending, deduplicated block numbers per key.
+    blocks: HashMap<LogIndexKey, Vec<u64>>,
+    /// Highest block the index has seen, if any.
+    indexed_through: Option<u64>,
+}
+
+impl LogIndex {
+    /// Creates an empty index scoped to the configured addresses.
+    pub fn new(config: &LogIndexConfig) -> Self {
+        Self {
+            addresses: config.addresses.iter().copied().collect(),
+            backfilled: AtomicBool::new(false),
+            inner: RwLock::new(LogIndexInner::default()),
+        }
+    }
+
+    /// Marks historical backfill as complete: every block up to
+    /// [`Self::indexed_through`] is now covered.
+    pub fn mark_backfilled(&self) {
+        self.backfilled.store(true, Ordering::Release);
+    }
+
+    /// Returns true once historical backfill has completed.
+    pub fn is_backfilled(&self) -> bool {
+        self.backfilled.load(Ordering::Acquire)
+    }
+
+    /// Returns true if logs from `address` are indexed.
+    pub fn indexes(&self, address: &Address) -> bool {
+        self.addresses.contains(address)
+    }
+
+    /// Highest block number the index covers, or `None` if nothing has been
+    /// indexed yet. Queries beyond this height must fall back to a scan.
+    pub fn indexed_through(&self) -> Option<u64> {
+        self.inner.read().unwrap().indexed_through
+    }
+
+    /// Records the logs of one block, given as `(address, topic0)` pairs.
+    ///
+    /// Logs from unindexed addresses are ignored. Blocks may arrive in any
+    /// order (backfill runs alongside live notifications), so insertion keeps
+    /// each list sorted.
+    pub fn index_block(&self, number: u64, logs: impl IntoIterator<Item = (Address, B256)>) {
+        let mut inner = self.inner.write().unwrap();
+        for (address, topic) in logs {
+            if !self.addresses.contains(&address) {
+                continue;
+            }
+            let blocks = inner
+                .blocks
+                .entry(LogIndexKey { address, topic })
+                .or_default();
+            if let Err(pos) = blocks.binary_search(&number) {
+                blocks.insert(pos, number);
+            }
+        }
+        let indexed_through = &mut inner.indexed_through;
+        *indexed_through = Some(indexed_through.map_or(number, |prev| prev.max(number)));
+    }
+
+    /// Removes all entries above `number`, e.g. when a reorg reverts blocks.
+    pub fn unwind_above(&self, number: u64) {
+        let mut inner = self.inner.write().unwrap();
+        for blocks in inner.blocks.values_mut() {
+            let keep = blocks.partition_point(|block| *block <= number);
+            blocks.truncate(keep);
+        }
+        inner.blocks.retain(|_, blocks| !blocks.is_empty());
+        if let Some(indexed_through) = &mut inner.indexed_through {
+            *indexed_through = (*indexed_through).min(number);
+        }
+    }
+
+    /// Returns the blocks in `range` that contain at least one log matching
+    /// `(address, topic0)`, in ascending order.
+    pub fn blocks_in_range(
+        &self,
+        address: Address,
+        topic: B256,
+        range: RangeInclusive<u64>,
+    ) -> Vec<u64> {
+        let inner = self.inner.read().unwrap();
+        let Some(blocks) = inner.blocks.get(&LogIndexKey { address, topic }) else {
+            return Vec::new();
+        };
+        let start = blocks.partition_point(|block| block < range.start());
+        let end = blocks.partition_point(|block| block <= range.end());
+        blocks[start..end].to_vec()
+    }
+
+    /// Bulk-loads historical blocks, each given as `(number, logs)`.
+    ///
+    /// The launcher feeds this from the provider on startup before (and
+    /// concurrently with) live notifications; [`Self::index_block`] tolerates
+    /// out-of-order and duplicate blocks, so no coordination is needed.
+    pub fn backfill(&self, blocks: impl IntoIterator<Item = (u64, Vec<(Address, B256)>)>) {
+        for (number, logs) in blocks {
+            self.index_block(number, logs);
+        }
+    }
+}
+
+/// Backfills `index` from the provider's historical receipts, genesis through
+/// the current tip, then marks the index [backfilled](LogIndex::mark_backfilled).
+///
+/// Runs alongside live notifications from [`LogIndexExEx`]; [`LogIndex::index_block`]
+/// tolerates out-of-order and duplicate blocks, so no coordination is needed.
+pub fn backfill_from_provider<P>(index: &LogIndex, provider: &P) -> eyre::Result<()>
+where
+    P: ReceiptProvider<Receipt = TempoReceipt> + BlockNumReader,
+{
+    let tip = provider.best_block_number()?;
+    for number in 0..=tip {
+        let Some(receipts) = provider.receipts_by_block(number.into())? else {
+            continue;
+        };
+        let logs = receipts
+            .iter()
+            .flat_map(|receipt| receipt.logs())
+            .filter_map(|log| log.topics().first().map(|topic| (log.address(), *topic)));
+        index.index_block(number, logs);
+    }
+    index.mark_backfilled();
+    info!(target: "tempo::exex", tip, "log index backfill complete");
+    Ok(())
+}
+
+/// ExEx that keeps a [`LogIndex`] current with the canonical chain.
+#[derive(Debug)]
+pub struct LogIndexExEx {
+    index: Arc<LogIndex>,
+}
+
+impl LogIndexExEx {
+    /// Creates the ExEx writing into the given shared index.
+    pub fn new(index: Arc<LogIndex>) -> Self {
+        Self { index }
+    }
+}
+
+#[async_trait]
+impl ExEx<CanonStateNotification<TempoPrimitives>> for LogIndexExEx {
+    fn name(&self) -> &'static str {
+        "log-index"
+    }
+
+    async fn on_notification(
+        &mut self,
+        notification: CanonStateNotification<TempoPrimitives>,
+    ) -> eyre::Result<()> {
+        if let Some(reverted) = notification.reverted() {
+            let first_reverted = reverted.first().number();
+            self.index.unwind_above(first_reverted.saturating_sub(1));
+        }
+
+        for (block, receipts) in notification.committed().blocks_and_receipts() {
+            let logs = receipts
+                .iter()
+                .flat_map(|receipt| receipt.logs())
+                .filter_map(|log| log.topics().first().map(|topic| (log.address(), *topic)));
+            self.index.index_block(block.number(), logs);
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRANSFER: B256 = B256::with_last_byte(0xaa);
+    const APPROVAL: B256 = B256::with_last_byte(0xbb);
+
+    fn index_for(addresses: &[Address]) -> LogIndex {
+        LogIndex::new(&LogIndexConfig {
+            addresses: addresses.to_vec(),
+        })
+    }
+
+    #[test]
+    fn test_range_query_returns_only_matching_blocks() {
+        let token = Address::with_last_byte(1);
+        let index = index_for(&[token]);
+
+        index.index_block(10, [(token, TRANSFER)]);
+        index.index_block(20, [(token, TRANSFER), (token, APPROVAL)]);
+        index.index_block(30, [(token, TRANSFER)]);
+
+        assert_eq!(
+            index.blocks_in_range(token, TRANSFER, 0..=100),
+            vec![10, 20, 30]
+        );
+        assert_eq!(index.blocks_in_range(token, TRANSFER, 11..=29), vec![20]);
+        assert_eq!(index.blocks_in_range(token, APPROVAL, 0..=100), vec![20]);
+        assert!(index.blocks_in_range(token, TRANSFER, 31..=100).is_empty());
+    }
+
+    #[test]
+    fn test_unindexed_addresses_are_ignored() {
+        let token = Address::with_last_byte(1);
+        let other = Address::with_last_byte(2);
+        let index = index_for(&[token]);
+
+        index.index_block(5, [(other, TRANSFER)]);
+
+        assert!(!index.indexes(&other));
+        assert!(index.blocks_in_range(other, TRANSFER, 0..=10).is_empty());
+        // The block still advances the coverage watermark.
+        assert_eq!(index.indexed_through(), Some(5));
+    }
+
+    #[test]
+    fn test_backfill_and_duplicates_keep_lists_sorted() {
+        let token = Address::with_last_byte(1);
+        let index = index_for(&[token]);
+
+        // Live notification lands first, backfill fills in history after.
+        index.index_block(50, [(token, TRANSFER)]);
+        index.backfill([
+            (30, vec![(token, TRANSFER)]),
+            (10, vec![(token, TRANSFER)]),
+            // Duplicate of the live block must not double-count.
+            (50, vec![(token, TRANSFER)]),
+        ]);
+
+        assert_eq!(
+            index.blocks_in_range(token, TRANSFER, 0..=100),
+            vec![10, 30, 50]
+        );
+        assert_eq!(index.indexed_through(), Some(50));
+    }
+
+    #[test]
+    fn test_unwind_removes_reverted_blocks() {
+        let token = Address::with_last_byte(1);
+        let index = index_for(&[token]);
+
+        index.index_block(10, [(token, TRANSFER)]);
+        index.index_block(20, [(token, TRANSFER), (token, APPROVAL)]);
+        index.index_block(30, [(token, APPROVAL)]);
+
+        index.unwind_above(15);
+
+        assert_eq!(index.blocks_in_range(token, TRANSFER, 0..=100), vec![10]);
+        assert!(index.blocks_in_range(token, APPROVAL, 0..=100).is_empty());
+        assert_eq!(index.indexed_through(), Some(15));
+    }
+}
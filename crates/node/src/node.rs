@@ -1,11 +1,18 @@
 use crate::{
     TempoPayloadTypes,
     engine::TempoEngineValidator,
+    exex::ExExRegistry,
+    log_index::{LogIndex, LogIndexConfig, LogIndexExEx, backfill_from_provider},
+    prune::{DatabaseReceiptsPruner, ReceiptsPruneConfig, ReceiptsPruneExEx, RetentionHints},
     rpc::{
-        TempoAdminApi, TempoAdminApiServer, TempoEthApi, TempoEthApiBuilder, TempoEthExt,
-        TempoEthExtApiServer, TempoForkScheduleApiServer, TempoForkScheduleRpc,
-        TempoOperatorApiServer, TempoOperatorRpc, TempoSimulate, TempoSimulateApiServer,
-        TempoToken, TempoTokenApiServer,
+        MethodQuota, RpcRateLimitConfig, RpcRateLimitLayer, RpcRateLimiter, TempoAdminApi,
+        TempoAdminApiServer, TempoBlockFees, TempoBlockFeesApiServer, TempoCall,
+        TempoCallApiServer, TempoCompressedTxApiServer, TempoCompressedTxRpc, TempoEthApi,
+        TempoEthApiBuilder, TempoEthExt, TempoEthExtApiServer, TempoForkScheduleApiServer,
+        TempoForkScheduleRpc, TempoKeychain, TempoKeychainApiServer, TempoLogsApiServer,
+        TempoLogsRpc, TempoOperatorApiServer, TempoOperatorRpc, TempoRetentionApiServer,
+        TempoRetentionRpc, TempoSimulate, TempoSimulateApiServer, TempoToken, TempoTokenApiServer,
+        TempoWitnessApiServer, TempoWitnessRpc,
     },
 };
 use alloy_primitives::B256;
@@ -27,19 +34,22 @@ use reth_node_builder::{
 };
 use reth_node_ethereum::EthereumNetworkBuilder;
 use reth_primitives_traits::SealedHeader;
-use reth_provider::{EthStorage, providers::ProviderFactoryBuilder};
-use reth_rpc_builder::{Identity, RethRpcModule};
+use reth_provider::{
+    CanonStateNotification, CanonStateSubscriptions as _, EthStorage,
+    providers::ProviderFactoryBuilder,
+};
+use reth_rpc_builder::RethRpcModule;
 use reth_rpc_eth_api::{
     RpcNodeCore,
     helpers::config::{EthConfigApiServer, EthConfigHandler},
 };
-use reth_tracing::tracing::{debug, info};
+use reth_tracing::tracing::{debug, error, info};
 use reth_transaction_pool::{TransactionValidationTaskExecutor, blobstore::InMemoryBlobStore};
-use std::default::Default;
+use std::{default::Default, net::SocketAddr, sync::Arc};
 use tempo_chainspec::spec::TempoChainSpec;
 use tempo_consensus::TempoConsensus;
 use tempo_evm::TempoEvmConfig;
-use tempo_payload_builder::TempoPayloadBuilder;
+use tempo_payload_builder::{GasReservation, GasReservationPolicy, TempoPayloadBuilder};
 use tempo_payload_types::TempoPayloadAttributes;
 use tempo_primitives::{TempoHeader, TempoPrimitives, TempoTxEnvelope, TempoTxType};
 use tempo_transaction_pool::{
@@ -52,7 +62,7 @@ use tempo_transaction_pool::{
 };
 
 /// Tempo node CLI arguments.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::Args)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, clap::Args)]
 pub struct TempoNodeArgs {
     /// Maximum allowed `valid_after` offset for AA txs.
     #[arg(long = "txpool.aa-valid-after-max-secs", default_value_t = DEFAULT_AA_VALID_AFTER_MAX_SECS)]
@@ -69,6 +79,40 @@ pub struct TempoNodeArgs {
     /// Disable state cache for the payload builder.
     #[arg(long = "builder.disable-state-cache", default_value_t = false)]
     pub builder_disable_state_cache: bool,
+
+    /// Gas reservations for validator-critical system transactions, as
+    /// `ADDRESS:GAS` pairs (e.g. for bridge finalization and fee-manager
+    /// settlement). Reserved gas is withheld from regular pool transactions.
+    #[arg(long = "builder.system-gas-reservation", value_name = "ADDRESS:GAS")]
+    pub builder_system_gas_reservations: Vec<GasReservation>,
+
+    /// Per-method RPC rate limits as `METHOD=REQUESTS_PER_SECOND` pairs
+    /// (e.g. `eth_call=50`). Methods without a quota are never limited.
+    #[arg(long = "rpc.method-quota", value_name = "METHOD=QPS")]
+    pub rpc_method_quotas: Vec<MethodQuota>,
+
+    /// Maximum number of requests allowed in a single RPC batch.
+    #[arg(long = "rpc.max-batch-size", value_name = "COUNT")]
+    pub rpc_max_batch_size: Option<usize>,
+
+    /// Contract addresses to maintain a historical log index for (may be
+    /// repeated). Speeds up `eth_getLogs` over large ranges for the given
+    /// addresses, e.g. TIP-20 tokens.
+    #[arg(long = "logindex.address", value_name = "ADDRESS")]
+    pub log_index_addresses: Vec<Address>,
+
+    /// Prune historical receipts and logs, keeping at least this many most
+    /// recent blocks. Retention pins (`tempo_pinReceiptRetention`) hold
+    /// pruning back further, e.g. for in-flight bridge unlocks. Disabled
+    /// unless set.
+    #[arg(long = "prune.receipts-distance", value_name = "BLOCKS")]
+    pub prune_receipts_distance: Option<u64>,
+
+    /// Listen address for the light client header server, which serves
+    /// canonical RLP headers, finalization certificates, and receipt proofs
+    /// over HTTP. Disabled unless set.
+    #[arg(long = "lightclient.addr", value_name = "ADDR")]
+    pub light_client_addr: Option<SocketAddr>,
 }
 
 impl TempoNodeArgs {
@@ -85,8 +129,33 @@ impl TempoNodeArgs {
         TempoPayloadBuilderBuilder {
             state_provider_metrics: self.builder_state_provider_metrics,
             disable_state_cache: self.builder_disable_state_cache,
+            gas_reservations: GasReservationPolicy::new(
+                self.builder_system_gas_reservations.clone(),
+            ),
         }
     }
+
+    /// Returns the [`RpcRateLimitConfig`] configured from these args.
+    pub fn rpc_rate_limit_config(&self) -> RpcRateLimitConfig {
+        RpcRateLimitConfig::new(self.rpc_method_quotas.clone(), self.rpc_max_batch_size)
+    }
+
+    /// Returns the [`LogIndexConfig`] configured from these args.
+    pub fn log_index_config(&self) -> LogIndexConfig {
+        LogIndexConfig {
+            addresses: self.log_index_addresses.clone(),
+        }
+    }
+
+    /// Returns the [`ReceiptsPruneConfig`] configured from these args, if
+    /// receipt pruning is enabled.
+    pub fn receipts_prune_config(&self) -> Option<ReceiptsPruneConfig> {
+        self.prune_receipts_distance
+            .map(|distance| ReceiptsPruneConfig {
+                distance,
+                ..Default::default()
+            })
+    }
 }
 
 /// Type configuration for a regular Ethereum node.
@@ -99,6 +168,12 @@ pub struct TempoNode {
     payload_builder_builder: TempoPayloadBuilderBuilder,
     /// Validator public key for `admin_validatorKey` RPC method.
     validator_key: Option<B256>,
+    /// RPC rate limit configuration.
+    rpc_rate_limit: RpcRateLimitConfig,
+    /// Historical log index configuration.
+    log_index: LogIndexConfig,
+    /// Receipts pruning configuration, if enabled.
+    receipts_prune: Option<ReceiptsPruneConfig>,
 }
 
 impl TempoNode {
@@ -108,6 +183,9 @@ impl TempoNode {
             pool_builder: args.pool_builder(),
             payload_builder_builder: args.payload_builder_builder(),
             validator_key,
+            rpc_rate_limit: args.rpc_rate_limit_config(),
+            log_index: args.log_index_config(),
+            receipts_prune: args.receipts_prune_config(),
         }
     }
 
@@ -161,9 +239,14 @@ pub struct TempoAddOns<N: FullNodeTypes<Types = TempoNode>> {
         TempoEngineValidatorBuilder,
         NoopEngineApiBuilder,
         BasicEngineValidatorBuilder<TempoEngineValidatorBuilder>,
-        Identity,
+        RpcRateLimitLayer,
     >,
     validator_key: Option<B256>,
+    rate_limiter: Option<Arc<RpcRateLimiter>>,
+    retention_hints: RetentionHints,
+    exex_registry: ExExRegistry<CanonStateNotification<TempoPrimitives>>,
+    log_index: Option<Arc<LogIndex>>,
+    receipts_prune: Option<ReceiptsPruneConfig>,
 }
 
 impl<N> TempoAddOns<N>
@@ -171,18 +254,57 @@ where
     N: FullNodeTypes<Types = TempoNode>,
 {
     /// Creates a new instance from the inner `RpcAddOns`.
-    pub fn new(validator_key: Option<B256>) -> Self {
+    pub fn new(
+        validator_key: Option<B256>,
+        rate_limit: RpcRateLimitConfig,
+        log_index_config: LogIndexConfig,
+        receipts_prune: Option<ReceiptsPruneConfig>,
+    ) -> Self {
+        let rate_limiter = rate_limit
+            .is_enabled()
+            .then(|| Arc::new(RpcRateLimiter::new(rate_limit)));
+        // The log index is kept current by an ExEx; the launcher backfills
+        // history and the RPC layer routes `eth_getLogs` through it.
+        let log_index = log_index_config
+            .is_enabled()
+            .then(|| Arc::new(LogIndex::new(&log_index_config)));
+        let mut exex_registry = ExExRegistry::new();
+        if let Some(index) = &log_index {
+            exex_registry.register(LogIndexExEx::new(index.clone()));
+        }
         Self {
             inner: RpcAddOns::new(
                 TempoEthApiBuilder::new(validator_key),
                 TempoEngineValidatorBuilder,
                 NoopEngineApiBuilder::default(),
                 BasicEngineValidatorBuilder::default(),
-                Identity::default(),
+                RpcRateLimitLayer::new(rate_limiter.clone()),
             ),
             validator_key,
+            rate_limiter,
+            retention_hints: RetentionHints::new(),
+            exex_registry,
+            log_index,
+            receipts_prune,
         }
     }
+
+    /// The receipt retention hint registry shared between the retention RPC
+    /// and the receipts pruner; launchers enabling pruning pass this handle to
+    /// [`crate::prune::ReceiptsPruneExEx`].
+    pub fn retention_hints(&self) -> RetentionHints {
+        self.retention_hints.clone()
+    }
+
+    /// Mutable access to the ExEx registry so builders and launchers can
+    /// register execution extensions before the node starts. Registered ExExes
+    /// are launched alongside the RPC add-ons and fed canonical state
+    /// notifications until the node shuts down.
+    pub fn exex_registry_mut(
+        &mut self,
+    ) -> &mut ExExRegistry<CanonStateNotification<TempoPrimitives>> {
+        &mut self.exex_registry
+    }
 }
 
 impl<N> NodeAddOns<NodeAdapter<N>> for TempoAddOns<N>
@@ -192,13 +314,53 @@ where
     type Handle = RpcHandle<NodeAdapter<N>, TempoEthApi<N>>;
 
     async fn launch_add_ons(
-        self,
+        mut self,
         ctx: AddOnsContext<'_, NodeAdapter<N>>,
     ) -> eyre::Result<Self::Handle> {
         let eth_config = EthConfigHandler::new(
             ctx.node.provider.clone(),
             ctx.node.components.evm_config.clone(),
         );
+        let witness_evm_config = ctx.node.components.evm_config.clone();
+
+        // Backfill the log index from historical receipts while the ExEx keeps
+        // it current from live notifications; `eth_getLogs` falls back to the
+        // stock scan until backfill completes.
+        if let Some(index) = self.log_index.clone() {
+            let provider = ctx.node.provider.clone();
+            ctx.node.task_executor.spawn_blocking(async move {
+                if let Err(err) = backfill_from_provider(&index, &provider) {
+                    error!(target: "tempo::exex", %err, "log index backfill failed");
+                }
+            });
+        }
+
+        // Receipts pruning needs the node's database, so the ExEx is only
+        // registered here; retention pins from the sidecar (shared through
+        // `retention_hints`) clamp the prune target.
+        if let Some(config) = self.receipts_prune {
+            self.exex_registry.register(ReceiptsPruneExEx::new(
+                DatabaseReceiptsPruner::new(ctx.node.provider.clone()),
+                config,
+                self.retention_hints.clone(),
+            ));
+        }
+
+        // Fan canonical state notifications out to registered ExExes. Each one
+        // runs on its own task with an independent buffer, so a slow or failing
+        // extension never affects the others or block processing.
+        if !self.exex_registry.is_empty() {
+            info!(
+                target: "tempo::exex",
+                exexes = ?self.exex_registry.names(),
+                "launching execution extensions"
+            );
+            let notifications = ctx.node.provider.canonical_state_stream();
+            let manager = self.exex_registry.launch();
+            ctx.node
+                .task_executor
+                .spawn_critical("tempo exex manager", manager.run(notifications));
+        }
 
         self.inner
             .launch_add_ons_with(ctx, move |container| {
@@ -209,15 +371,26 @@ where
                 let eth_api = registry.eth_api().clone();
                 let token = TempoToken::new(eth_api.clone());
                 let eth_ext = TempoEthExt::new(eth_api.clone());
+                let call = TempoCall::new(eth_api.clone());
+                let keychain = TempoKeychain::new(eth_api.clone());
+                let block_fees = TempoBlockFees::new(eth_api.clone());
+                let compressed_tx = TempoCompressedTxRpc::new(eth_api.clone());
                 let simulate = TempoSimulate::new(eth_api);
-                let admin = TempoAdminApi::new(self.validator_key);
+                let admin = TempoAdminApi::new(self.validator_key, self.rate_limiter.clone());
                 let operator = TempoOperatorRpc::new(registry.admin_api());
                 let fork_schedule =
                     TempoForkScheduleRpc::new(registry.eth_api().provider().clone());
+                let witness =
+                    TempoWitnessRpc::new(registry.eth_api().provider().clone(), witness_evm_config);
+                let retention = TempoRetentionRpc::new(self.retention_hints.clone());
 
                 modules.merge_configured(token.into_rpc())?;
                 modules.merge_configured(eth_ext.into_rpc())?;
                 modules.merge_if_module_configured(RethRpcModule::Eth, simulate.into_rpc())?;
+                modules.merge_if_module_configured(RethRpcModule::Eth, call.into_rpc())?;
+                modules.merge_if_module_configured(RethRpcModule::Eth, keychain.into_rpc())?;
+                modules.merge_if_module_configured(RethRpcModule::Eth, block_fees.into_rpc())?;
+                modules.merge_if_module_configured(RethRpcModule::Eth, compressed_tx.into_rpc())?;
                 modules.merge_configured(fork_schedule.into_rpc())?;
                 modules.merge_if_module_configured(
                     RethRpcModule::Other("operator".to_string()),
@@ -225,6 +398,19 @@ where
                 )?;
                 modules.merge_if_module_configured(RethRpcModule::Admin, admin.into_rpc())?;
                 modules.merge_if_module_configured(RethRpcModule::Eth, eth_config.into_rpc())?;
+                // Witness generation re-executes blocks; opt in via `debug`.
+                modules.merge_if_module_configured(RethRpcModule::Debug, witness.into_rpc())?;
+                // Retention pins gate pruning; operator/sidecar surface only.
+                modules.merge_if_module_configured(RethRpcModule::Admin, retention.into_rpc())?;
+
+                // With the log index enabled, replace the stock `eth_getLogs`
+                // with the index-accelerated handler; unindexable filters fall
+                // through to the stock scan inside the handler.
+                if let Some(index) = &self.log_index {
+                    let logs =
+                        TempoLogsRpc::new(index.clone(), registry.eth_handlers().filter.clone());
+                    modules.replace_configured(logs.into_rpc())?;
+                }
 
                 Ok(())
             })
@@ -274,7 +460,12 @@ where
     }
 
     fn add_ons(&self) -> Self::AddOns {
-        TempoAddOns::new(self.validator_key)
+        TempoAddOns::new(
+            self.validator_key,
+            self.rpc_rate_limit.clone(),
+            self.log_index.clone(),
+            self.receipts_prune,
+        )
     }
 }
 
@@ -485,13 +676,15 @@ where
     }
 }
 
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone)]
 #[non_exhaustive]
 pub struct TempoPayloadBuilderBuilder {
     /// Enable state provider metrics for the payload builder.
     pub state_provider_metrics: bool,
     /// Disable state cache for the payload builder.
     pub disable_state_cache: bool,
+    /// Per-precompile gas reservations for validator-critical transactions.
+    pub gas_reservations: GasReservationPolicy,
 }
 
 impl<Node> PayloadBuilderBuilder<Node, TempoTransactionPool<Node::Provider>, TempoEvmConfig>
@@ -514,6 +707,7 @@ where
             ctx.is_dev(),
             self.state_provider_metrics,
             self.disable_state_cache,
+            self.gas_reservations,
         ))
     }
 }
@@ -0,0 +1,323 @@
+//! Receipts/logs pruning with bridge-aware retention.
+//!
+//! Full nodes do not need receipts and logs back to genesis, but the bridge
+//! sidecar does need them for every block whose burn events back an unlock
+//! that has not finalized on the origin chain yet: burn proofs are built from
+//! the receipt trie, so pruning such a block would make the unlock
+//! unprovable. This module therefore splits pruning into a distance-based
+//! policy and a set of [`RetentionHints`] — named floors pinned by the
+//! sidecar (via the `tempo_pinReceiptRetention` RPC in
+//! [`crate::rpc::retention`]) while unlocks are in flight and released once
+//! origin finalization is confirmed. The prune target never crosses the
+//! lowest pinned floor.
+//!
+//! The pruner itself runs as an [`ExEx`]: on each canonical tip it computes
+//! the target from the policy and the hints and asks the node's
+//! [`ReceiptsPruner`] to drop receipts below it, throttled so it does not run
+//! on every block.
+
+use crate::exex::{ExEx, ExExNotification};
+use async_trait::async_trait;
+use reth_db_api::{
+    cursor::{DbCursorRO as _, DbCursorRW as _},
+    tables,
+    transaction::{DbTx as _, DbTxMut as _},
+};
+use reth_provider::{DBProvider, DatabaseProviderFactory};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+use tempo_primitives::TempoReceipt;
+use tracing::{debug, info};
+
+/// One named retention floor, as reported by the hint API.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionHint {
+    /// Who pinned the floor (e.g. `bridge-sidecar`).
+    pub source: String,
+    /// Lowest block whose receipts must be kept.
+    pub min_block: u64,
+}
+
+/// Shared registry of retention floors. Cloning is cheap; all clones observe
+/// the same set.
+#[derive(Debug, Clone, Default)]
+pub struct RetentionHints {
+    hints: Arc<RwLock<HashMap<String, u64>>>,
+}
+
+impl RetentionHints {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pins (or moves) `source`'s floor: receipts from `min_block` on must be
+    /// kept until the pin is released.
+    pub fn pin(&self, source: impl Into<String>, min_block: u64) {
+        self.hints
+            .write()
+            .expect("retention hints lock not poisoned")
+            .insert(source.into(), min_block);
+    }
+
+    /// Releases `source`'s floor. Returns true if a pin existed.
+    pub fn release(&self, source: &str) -> bool {
+        self.hints
+            .write()
+            .expect("retention hints lock not poisoned")
+            .remove(source)
+            .is_some()
+    }
+
+    /// The lowest pinned floor, or `None` when nothing is pinned.
+    pub fn floor(&self) -> Option<u64> {
+        self.hints
+            .read()
+            .expect("retention hints lock not poisoned")
+            .values()
+            .min()
+            .copied()
+    }
+
+    /// All current pins, sorted by source for stable output.
+    pub fn snapshot(&self) -> Vec<RetentionHint> {
+        let mut hints = self
+            .hints
+            .read()
+            .expect("retention hints lock not poisoned")
+            .iter()
+            .map(|(source, min_block)| RetentionHint {
+                source: source.clone(),
+                min_block: *min_block,
+            })
+            .collect::<Vec<_>>();
+        hints.sort_by(|a, b| a.source.cmp(&b.source));
+        hints
+    }
+}
+
+/// Configuration for the receipts pruning ExEx.
+#[derive(Debug, Clone, Copy)]
+pub struct ReceiptsPruneConfig {
+    /// Number of most recent blocks whose receipts are always kept.
+    pub distance: u64,
+    /// Minimum number of newly prunable blocks before the pruner runs again,
+    /// so it does not issue a deletion per block.
+    pub batch: u64,
+}
+
+impl Default for ReceiptsPruneConfig {
+    fn default() -> Self {
+        Self {
+            // Roughly a week of blocks at one block per second.
+            distance: 604_800,
+            batch: 1_000,
+        }
+    }
+}
+
+impl ReceiptsPruneConfig {
+    /// The block below which receipts may be dropped at `tip`, after clamping
+    /// to the lowest retention floor. `None` when nothing is prunable.
+    pub fn prune_target(&self, tip: u64, hints: &RetentionHints) -> Option<u64> {
+        let mut target = tip.checked_sub(self.distance)?;
+        if let Some(floor) = hints.floor() {
+            target = target.min(floor);
+        }
+        (target > 0).then_some(target)
+    }
+}
+
+/// Deletes historical receipts and logs below a block boundary.
+///
+/// Implemented over the node's database; abstracted so the pruning policy can
+/// be exercised without one.
+pub trait ReceiptsPruner: Send + 'static {
+    /// Drops receipts and logs of all blocks strictly below `block`. Returns
+    /// the number of receipt records removed.
+    fn prune_receipts_below(&mut self, block: u64) -> eyre::Result<usize>;
+}
+
+/// [`ReceiptsPruner`] over the node's database.
+///
+/// Deletes rows from the receipts table up to the first transaction of the
+/// boundary block, the same table walk reth's own receipts prune segment
+/// performs.
+#[derive(Debug)]
+pub struct DatabaseReceiptsPruner<F> {
+    factory: F,
+}
+
+impl<F> DatabaseReceiptsPruner<F> {
+    /// Creates a pruner issuing deletions through the given provider factory.
+    pub fn new(factory: F) -> Self {
+        Self { factory }
+    }
+}
+
+impl<F> ReceiptsPruner for DatabaseReceiptsPruner<F>
+where
+    F: DatabaseProviderFactory + Send + 'static,
+{
+    fn prune_receipts_below(&mut self, block: u64) -> eyre::Result<usize> {
+        let provider = self.factory.database_provider_rw()?;
+        let tx = provider.tx_ref();
+        let Some(indices) = tx.get::<tables::BlockBodyIndices>(block)? else {
+            return Ok(0);
+        };
+
+        let mut removed = 0;
+        {
+            let mut cursor = tx.cursor_write::<tables::Receipts<TempoReceipt>>()?;
+            let mut walker = cursor.walk_range(..indices.first_tx_num())?;
+            while let Some(entry) = walker.next() {
+                entry?;
+                walker.delete_current()?;
+                removed += 1;
+            }
+        }
+        provider.commit()?;
+        Ok(removed)
+    }
+}
+
+/// ExEx applying [`ReceiptsPruneConfig`] against a [`ReceiptsPruner`].
+pub struct ReceiptsPruneExEx<P> {
+    pruner: P,
+    config: ReceiptsPruneConfig,
+    hints: RetentionHints,
+    /// Boundary below which receipts are already gone.
+    pruned_below: u64,
+}
+
+impl<P: ReceiptsPruner> ReceiptsPruneExEx<P> {
+    /// Creates the pruning ExEx. `hints` is the same handle served by the
+    /// retention RPC so sidecar pins take effect immediately.
+    pub fn new(pruner: P, config: ReceiptsPruneConfig, hints: RetentionHints) -> Self {
+        Self {
+            pruner,
+            config,
+            hints,
+            pruned_below: 0,
+        }
+    }
+}
+
+#[async_trait]
+impl<P: ReceiptsPruner, N: ExExNotification> ExEx<N> for ReceiptsPruneExEx<P> {
+    fn name(&self) -> &'static str {
+        "receipts-prune"
+    }
+
+    async fn on_notification(&mut self, notification: N) -> eyre::Result<()> {
+        let tip = notification.tip_number();
+        let Some(target) = self.config.prune_target(tip, &self.hints) else {
+            return Ok(());
+        };
+        if target < self.pruned_below.saturating_add(self.config.batch) {
+            // Not enough new history (or a retention pin is holding the
+            // target back); try again once the backlog is worth a batch.
+            debug!(target, pruned_below = self.pruned_below, "prune deferred");
+            return Ok(());
+        }
+
+        let pruned = self.pruner.prune_receipts_below(target)?;
+        info!(target, pruned, tip, "pruned historical receipts");
+        self.pruned_below = target;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Default)]
+    struct MockPruner {
+        calls: Arc<RwLock<Vec<u64>>>,
+    }
+
+    impl ReceiptsPruner for MockPruner {
+        fn prune_receipts_below(&mut self, block: u64) -> eyre::Result<usize> {
+            self.calls.write().unwrap().push(block);
+            Ok(1)
+        }
+    }
+
+    impl ExExNotification for u64 {
+        fn tip_number(&self) -> u64 {
+            *self
+        }
+    }
+
+    fn config() -> ReceiptsPruneConfig {
+        ReceiptsPruneConfig {
+            distance: 100,
+            batch: 10,
+        }
+    }
+
+    #[test]
+    fn floor_is_the_minimum_across_sources() {
+        let hints = RetentionHints::new();
+        assert_eq!(hints.floor(), None);
+
+        hints.pin("bridge-sidecar", 500);
+        hints.pin("snapshotter", 300);
+        assert_eq!(hints.floor(), Some(300));
+        assert_eq!(hints.snapshot().len(), 2);
+
+        assert!(hints.release("snapshotter"));
+        assert!(!hints.release("snapshotter"));
+        assert_eq!(hints.floor(), Some(500));
+    }
+
+    #[test]
+    fn target_is_clamped_by_pins() {
+        let hints = RetentionHints::new();
+        let config = config();
+
+        assert_eq!(config.prune_target(50, &hints), None);
+        assert_eq!(config.prune_target(1_000, &hints), Some(900));
+
+        // A bridge pin at 400 holds the target back even as the tip advances.
+        hints.pin("bridge-sidecar", 400);
+        assert_eq!(config.prune_target(1_000, &hints), Some(400));
+        assert_eq!(config.prune_target(10_000, &hints), Some(400));
+
+        hints.release("bridge-sidecar");
+        assert_eq!(config.prune_target(10_000, &hints), Some(9_900));
+    }
+
+    #[tokio::test]
+    async fn exex_prunes_in_batches_and_respects_pins() -> eyre::Result<()> {
+        let pruner = MockPruner::default();
+        let calls = pruner.calls.clone();
+        let hints = RetentionHints::new();
+        let mut exex = ReceiptsPruneExEx::new(pruner, config(), hints.clone());
+
+        // First eligible tip prunes; the next few are deferred until a full
+        // batch of new history exists.
+        exex.on_notification(200u64).await?;
+        exex.on_notification(205u64).await?;
+        exex.on_notification(210u64).await?;
+        assert_eq!(*calls.read().unwrap(), vec![100, 110]);
+
+        // An in-flight unlock pins block 105: the tip keeps advancing but the
+        // already-pruned boundary stays put, so nothing below the pin is lost.
+        hints.pin("bridge-sidecar", 105);
+        exex.on_notification(400u64).await?;
+        assert_eq!(*calls.read().unwrap(), vec![100, 110]);
+
+        // Finalization confirmed: the pin is released and pruning catches up.
+        hints.release("bridge-sidecar");
+        exex.on_notification(400u64).await?;
+        assert_eq!(*calls.read().unwrap(), vec![100, 110, 300]);
+
+        Ok(())
+    }
+}
@@ -1,5 +1,7 @@
+use crate::rpc::rate_limit::{RpcRateLimitHealth, RpcRateLimiter};
 use alloy_primitives::B256;
 use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use std::sync::Arc;
 
 #[rpc(server, namespace = "admin")]
 pub trait TempoAdminApi {
@@ -11,18 +13,28 @@ pub trait TempoAdminApi {
     /// Returns `null` if the node is not configured as a validator.
     #[method(name = "validatorKey")]
     async fn validator_key(&self) -> RpcResult<Option<B256>>;
+
+    /// Returns the RPC rate limit configuration and rejection counts.
+    ///
+    /// Returns `null` if no rate limits are configured.
+    #[method(name = "rpcRateLimitHealth")]
+    async fn rpc_rate_limit_health(&self) -> RpcResult<Option<RpcRateLimitHealth>>;
 }
 
 /// Tempo-specific `admin_` namespace extensions.
 #[derive(Debug, Clone)]
 pub struct TempoAdminApi {
     validator_key: Option<B256>,
+    rate_limiter: Option<Arc<RpcRateLimiter>>,
 }
 
 impl TempoAdminApi {
     /// Create a new admin API handler.
-    pub fn new(validator_key: Option<B256>) -> Self {
-        Self { validator_key }
+    pub fn new(validator_key: Option<B256>, rate_limiter: Option<Arc<RpcRateLimiter>>) -> Self {
+        Self {
+            validator_key,
+            rate_limiter,
+        }
     }
 }
 
@@ -31,4 +43,8 @@ impl TempoAdminApiServer for TempoAdminApi {
     async fn validator_key(&self) -> RpcResult<Option<B256>> {
         Ok(self.validator_key)
     }
+
+    async fn rpc_rate_limit_health(&self) -> RpcResult<Option<RpcRateLimitHealth>> {
+        Ok(self.rate_limiter.as_ref().map(|limiter| limiter.health()))
+    }
 }
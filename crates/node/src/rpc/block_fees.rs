@@ -0,0 +1,106 @@
+//! `tempo_blockFeeTotals`: per-block fee-token revenue aggregation.
+//!
+//! Receipts already expose the fee token and fee amount per transaction, but
+//! explorers showing block-level revenue would have to fetch every receipt and
+//! aggregate client-side. This endpoint sums fees collected per TIP-20 token
+//! across a block's receipts, along with the amounts burned from each token's
+//! supply, so stablecoin-denominated block revenue is a single call.
+
+use crate::{node::TempoNode, rpc::TempoEthApi};
+use alloy_eips::BlockId;
+use alloy_primitives::{Address, U256};
+use alloy_sol_types::SolEvent;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::ErrorObject};
+use reth_node_api::FullNodeTypes;
+use reth_rpc_eth_api::helpers::EthBlocks;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tempo_precompiles::tip20::ITIP20;
+use tempo_primitives::TempoAddressExt;
+
+/// Aggregated fee and burn totals for one TIP-20 token within a block.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TokenFeeTotal {
+    /// TIP-20 token address.
+    pub token: Address,
+    /// Total transaction fees collected in this token, in the token's base
+    /// units.
+    pub fees_collected: U256,
+    /// Total amount burned from this token's supply (`Burn` and `BurnBlocked`
+    /// events).
+    pub burned: U256,
+}
+
+#[rpc(server, namespace = "tempo")]
+pub trait TempoBlockFeesApi {
+    /// Returns per-token fee and burn totals for the given block, aggregated
+    /// from its receipts and sorted by token address. `None` if the block is
+    /// unknown.
+    #[method(name = "blockFeeTotals")]
+    async fn block_fee_totals(&self, block: BlockId) -> RpcResult<Option<Vec<TokenFeeTotal>>>;
+}
+
+/// Implementation of `tempo_blockFeeTotals`.
+#[derive(Debug, Clone)]
+pub struct TempoBlockFees<N: FullNodeTypes<Types = TempoNode>> {
+    eth_api: TempoEthApi<N>,
+}
+
+impl<N: FullNodeTypes<Types = TempoNode>> TempoBlockFees<N> {
+    pub fn new(eth_api: TempoEthApi<N>) -> Self {
+        Self { eth_api }
+    }
+}
+
+#[async_trait::async_trait]
+impl<N: FullNodeTypes<Types = TempoNode>> TempoBlockFeesApiServer for TempoBlockFees<N> {
+    async fn block_fee_totals(&self, block: BlockId) -> RpcResult<Option<Vec<TokenFeeTotal>>> {
+        let receipts = EthBlocks::block_receipts(&self.eth_api, block)
+            .await
+            .map_err(|e| {
+                let err: ErrorObject<'static> = e.into();
+                err
+            })?;
+        let Some(receipts) = receipts else {
+            return Ok(None);
+        };
+
+        let mut totals: BTreeMap<Address, (U256, U256)> = BTreeMap::new();
+
+        for receipt in receipts {
+            // The receipt converter already resolved the fee token and amount
+            // for each transaction; free transactions carry neither.
+            if let (Some(token), Some(amount)) =
+                (receipt.tempo.fee_token, receipt.tempo.fee_amount_in_token)
+            {
+                totals.entry(token).or_default().0 += amount;
+            }
+
+            for log in receipt.logs() {
+                if !log.address().is_tip20() {
+                    continue;
+                }
+                let amount = if let Ok(ev) = ITIP20::Burn::decode_log(&log.inner) {
+                    ev.amount
+                } else if let Ok(ev) = ITIP20::BurnBlocked::decode_log(&log.inner) {
+                    ev.amount
+                } else {
+                    continue;
+                };
+                totals.entry(log.address()).or_default().1 += amount;
+            }
+        }
+
+        Ok(Some(
+            totals
+                .into_iter()
+                .map(|(token, (fees_collected, burned))| TokenFeeTotal {
+                    token,
+                    fees_collected,
+                    burned,
+                })
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,312 @@
+//! Precompile-aware state overrides for `eth_call`-style requests.
+//!
+//! `eth_call` accepts raw `stateDiff` overrides, but precompile state lives in
+//! hashed mapping slots (TIP-20 balances, keychain entries), which integrators
+//! would otherwise have to derive by hand. This module translates a
+//! human-friendly override description into the precompile slot layout and
+//! exposes `tempo_call`, which behaves like `eth_call` with the translated
+//! overrides applied — so "what-if" scenarios can be tested without funding
+//! accounts or authorizing keys on chain.
+
+use crate::{node::TempoNode, rpc::TempoEthApi};
+use alloy_primitives::{Address, B256, Bytes, U256};
+use alloy_rpc_types_eth::state::{AccountOverride, StateOverride};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::ErrorObject};
+use reth_node_api::FullNodeTypes;
+use reth_rpc_eth_api::helpers::EthCall;
+use reth_rpc_eth_types::EvmOverrides;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use tempo_precompiles::{
+    account_keychain::{AccountKeychain, AuthorizedKey, SpendingLimitState},
+    error::TempoPrecompileError,
+    storage::{StorageCtx, hashmap::HashMapStorageProvider},
+    tip20::TIP20Token,
+};
+
+/// Overrides a holder's TIP-20 token balance.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Tip20BalanceOverride {
+    /// TIP-20 token address.
+    pub token: Address,
+    /// Account whose balance is overridden.
+    pub holder: Address,
+    /// New balance.
+    pub amount: U256,
+}
+
+/// Overrides an authorized key in the account keychain.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeychainKeyOverride {
+    /// Account the key is authorized for.
+    pub account: Address,
+    /// Key identifier.
+    pub key_id: Address,
+    /// Signature type: 0 = secp256k1, 1 = P256, 2 = WebAuthn.
+    #[serde(default)]
+    pub signature_type: u8,
+    /// Expiry timestamp of the key.
+    pub expiry: u64,
+    /// Whether spending limits are enforced for this key.
+    #[serde(default)]
+    pub enforce_limits: bool,
+}
+
+/// Overrides a per-token spending limit for an authorized key.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpendingLimitOverride {
+    /// Account the limit applies to.
+    pub account: Address,
+    /// Key the limit applies to.
+    pub key_id: Address,
+    /// Token the limit is denominated in.
+    pub token: Address,
+    /// Remaining spendable amount.
+    pub remaining: U256,
+}
+
+/// Human-friendly description of precompile state to override.
+///
+/// Translated into raw storage slot diffs on the corresponding precompile
+/// accounts via [`Self::to_state_override`].
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PrecompileStateOverride {
+    /// TIP-20 balance overrides.
+    #[serde(default)]
+    pub tip20_balances: Vec<Tip20BalanceOverride>,
+    /// Keychain key overrides.
+    #[serde(default)]
+    pub keychain_keys: Vec<KeychainKeyOverride>,
+    /// Keychain spending-limit overrides.
+    #[serde(default)]
+    pub spending_limits: Vec<SpendingLimitOverride>,
+}
+
+impl PrecompileStateOverride {
+    /// Returns `true` if no overrides are requested.
+    pub fn is_empty(&self) -> bool {
+        self.tip20_balances.is_empty()
+            && self.keychain_keys.is_empty()
+            && self.spending_limits.is_empty()
+    }
+
+    /// Translates the overrides into raw `stateDiff` entries.
+    ///
+    /// The writes are replayed through the precompile storage handlers against
+    /// a scratch provider, so the emitted slots always match the precompiles'
+    /// actual layout (including packing and mapping-slot hashing).
+    pub fn to_state_override(&self) -> Result<StateOverride, TempoPrecompileError> {
+        let mut scratch = HashMapStorageProvider::new(1);
+        StorageCtx::enter(&mut scratch, || {
+            for balance in &self.tip20_balances {
+                let mut token = TIP20Token::from_address(balance.token)?;
+                token.balances[balance.holder].write(balance.amount)?;
+            }
+
+            let mut keychain = AccountKeychain::new();
+            for key in &self.keychain_keys {
+                keychain.keys[key.account][key.key_id].write(AuthorizedKey {
+                    signature_type: key.signature_type,
+                    expiry: key.expiry,
+                    enforce_limits: key.enforce_limits,
+                    is_revoked: false,
+                    use_limited: false,
+                    remaining_uses: 0,
+                })?;
+            }
+            for limit in &self.spending_limits {
+                let limit_key = AccountKeychain::spending_limit_key(limit.account, limit.key_id);
+                keychain.spending_limits[limit_key][limit.token].write(SpendingLimitState {
+                    remaining: limit.remaining,
+                    max: limit.remaining.saturating_to(),
+                    period: 0,
+                    period_end: 0,
+                })?;
+            }
+            Ok::<(), TempoPrecompileError>(())
+        })?;
+
+        let mut diffs: HashMap<Address, Vec<(B256, B256)>> = HashMap::new();
+        for (address, slot, value) in scratch.into_storage() {
+            diffs
+                .entry(address)
+                .or_default()
+                .push((B256::from(slot), B256::from(value)));
+        }
+
+        Ok(diffs
+            .into_iter()
+            .map(|(address, diff)| (address, AccountOverride::default().with_state_diff(diff)))
+            .collect())
+    }
+
+    /// Merges the translated slot diffs into an existing [`StateOverride`].
+    ///
+    /// Explicit raw `stateDiff` entries provided by the caller win over the
+    /// translated ones on slot collisions.
+    pub fn merge_into(&self, target: &mut StateOverride) -> Result<(), TempoPrecompileError> {
+        for (address, translated) in self.to_state_override()? {
+            let account = target.entry(address).or_default();
+            let mut diff = translated.state_diff.unwrap_or_default();
+            if let Some(existing) = account.state_diff.take() {
+                diff.extend(existing);
+            }
+            account.state_diff = Some(diff);
+        }
+        Ok(())
+    }
+}
+
+#[rpc(server, namespace = "tempo")]
+pub trait TempoCallApi {
+    /// Executes a call like `eth_call`, additionally accepting precompile-aware
+    /// state overrides (TIP-20 balances, keychain entries) that are translated
+    /// into the precompiles' storage slot layout before execution.
+    #[method(name = "call")]
+    async fn call(
+        &self,
+        request: tempo_alloy::rpc::TempoTransactionRequest,
+        block: Option<alloy_eips::BlockId>,
+        state_overrides: Option<StateOverride>,
+        precompile_overrides: Option<PrecompileStateOverride>,
+    ) -> RpcResult<Bytes>;
+}
+
+/// Implementation of `tempo_call`.
+#[derive(Debug, Clone)]
+pub struct TempoCall<N: FullNodeTypes<Types = TempoNode>> {
+    eth_api: TempoEthApi<N>,
+}
+
+impl<N: FullNodeTypes<Types = TempoNode>> TempoCall<N> {
+    pub fn new(eth_api: TempoEthApi<N>) -> Self {
+        Self { eth_api }
+    }
+}
+
+#[async_trait::async_trait]
+impl<N: FullNodeTypes<Types = TempoNode>> TempoCallApiServer for TempoCall<N> {
+    async fn call(
+        &self,
+        request: tempo_alloy::rpc::TempoTransactionRequest,
+        block: Option<alloy_eips::BlockId>,
+        state_overrides: Option<StateOverride>,
+        precompile_overrides: Option<PrecompileStateOverride>,
+    ) -> RpcResult<Bytes> {
+        let mut overrides = state_overrides.unwrap_or_default();
+        if let Some(precompile) = precompile_overrides {
+            precompile.merge_into(&mut overrides).map_err(|e| {
+                ErrorObject::owned(
+                    jsonrpsee::types::error::INVALID_PARAMS_CODE,
+                    e.to_string(),
+                    None::<()>,
+                )
+            })?;
+        }
+
+        let overrides = (!overrides.is_empty()).then_some(overrides);
+        self.eth_api
+            .call(request, block, EvmOverrides::new(overrides, None))
+            .await
+            .map_err(|e| {
+                let err: ErrorObject<'static> = e.into();
+                err
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempo_precompiles::{ACCOUNT_KEYCHAIN_ADDRESS, PATH_USD_ADDRESS};
+
+    #[test]
+    fn translates_balance_overrides_to_layout_slots() {
+        let holder = Address::random();
+        let amount = U256::from(1_000_000u64);
+        let overrides = PrecompileStateOverride {
+            tip20_balances: vec![Tip20BalanceOverride {
+                token: PATH_USD_ADDRESS,
+                holder,
+                amount,
+            }],
+            ..Default::default()
+        };
+
+        let translated = overrides.to_state_override().unwrap();
+        let diff = translated[&PATH_USD_ADDRESS]
+            .state_diff
+            .as_ref()
+            .unwrap()
+            .clone();
+
+        let expected_slot =
+            B256::from(TIP20Token::from_address(PATH_USD_ADDRESS).unwrap().balances[holder].slot());
+        assert_eq!(diff.get(&expected_slot), Some(&B256::from(amount)));
+    }
+
+    #[test]
+    fn translates_keychain_overrides_to_keychain_account() {
+        let overrides = PrecompileStateOverride {
+            keychain_keys: vec![KeychainKeyOverride {
+                account: Address::random(),
+                key_id: Address::random(),
+                signature_type: 1,
+                expiry: u64::MAX,
+                enforce_limits: false,
+            }],
+            ..Default::default()
+        };
+
+        let translated = overrides.to_state_override().unwrap();
+        let diff = translated[&ACCOUNT_KEYCHAIN_ADDRESS]
+            .state_diff
+            .as_ref()
+            .unwrap();
+        assert_eq!(diff.len(), 1);
+    }
+
+    #[test]
+    fn rejects_invalid_tip20_address() {
+        let overrides = PrecompileStateOverride {
+            tip20_balances: vec![Tip20BalanceOverride {
+                token: Address::random(),
+                holder: Address::random(),
+                amount: U256::ONE,
+            }],
+            ..Default::default()
+        };
+        assert!(overrides.to_state_override().is_err());
+    }
+
+    #[test]
+    fn explicit_state_diff_wins_on_collision() {
+        let holder = Address::random();
+        let overrides = PrecompileStateOverride {
+            tip20_balances: vec![Tip20BalanceOverride {
+                token: PATH_USD_ADDRESS,
+                holder,
+                amount: U256::from(1u64),
+            }],
+            ..Default::default()
+        };
+
+        let slot =
+            B256::from(TIP20Token::from_address(PATH_USD_ADDRESS).unwrap().balances[holder].slot());
+        let explicit = B256::from(U256::from(42u64));
+        let mut target: StateOverride = [(
+            PATH_USD_ADDRESS,
+            AccountOverride::default().with_state_diff([(slot, explicit)]),
+        )]
+        .into_iter()
+        .collect();
+
+        overrides.merge_into(&mut target).unwrap();
+        let diff = target[&PATH_USD_ADDRESS].state_diff.as_ref().unwrap();
+        assert_eq!(diff.get(&slot), Some(&explicit));
+    }
+}
@@ -0,0 +1,95 @@
+//! `tempo_sendRawTransactionCompressed`: raw-transaction submission using the
+//! compressed transport encoding.
+//!
+//! Payment batches carry highly repetitive calldata, so wallets can shrink
+//! their submissions by wrapping the whole signed payload in a compressed
+//! envelope (see `AASigned::eip2718_encode_compressed`). The envelope is
+//! transport-only: this endpoint restores the canonical bytes and forwards
+//! them through the stock raw-transaction path, so the pool, propagation, and
+//! consensus only ever see the canonical encoding. Active from T4.
+
+use alloy_eips::eip2718::Encodable2718;
+use alloy_primitives::{B256, Bytes};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::ErrorObject};
+use reth_node_api::FullNodeTypes;
+use reth_primitives_traits::AlloyBlockHeader as _;
+use reth_provider::{BlockNumReader, ChainSpecProvider, HeaderProvider};
+use reth_rpc_eth_api::{RpcNodeCore, helpers::EthTransactions};
+use tempo_chainspec::hardfork::TempoHardforks;
+use tempo_primitives::AASigned;
+
+use crate::{node::TempoNode, rpc::TempoEthApi};
+
+#[rpc(server, namespace = "tempo")]
+pub trait TempoCompressedTxApi {
+    /// Submits a signed transaction in the compressed transport encoding
+    /// (type byte followed by a compressed envelope of the canonical RLP
+    /// payload, see `AASigned::eip2718_encode_compressed`).
+    ///
+    /// The node restores the canonical bytes and hands them to the ordinary
+    /// raw-transaction path, so the returned hash is the canonical
+    /// transaction hash. Only available once T4 is active.
+    #[method(name = "sendRawTransactionCompressed")]
+    async fn send_raw_transaction_compressed(&self, tx: Bytes) -> RpcResult<B256>;
+}
+
+/// Implementation of `tempo_sendRawTransactionCompressed`.
+#[derive(Debug, Clone)]
+pub struct TempoCompressedTxRpc<N: FullNodeTypes<Types = TempoNode>> {
+    eth_api: TempoEthApi<N>,
+}
+
+impl<N: FullNodeTypes<Types = TempoNode>> TempoCompressedTxRpc<N> {
+    /// Create a new compressed transaction RPC handler.
+    pub fn new(eth_api: TempoEthApi<N>) -> Self {
+        Self { eth_api }
+    }
+}
+
+fn invalid_params(msg: impl ToString) -> ErrorObject<'static> {
+    ErrorObject::owned(
+        jsonrpsee::types::error::INVALID_PARAMS_CODE,
+        msg.to_string(),
+        None::<()>,
+    )
+}
+
+fn internal_err(msg: impl ToString) -> ErrorObject<'static> {
+    ErrorObject::owned(-32000, msg.to_string(), None::<()>)
+}
+
+#[async_trait::async_trait]
+impl<N: FullNodeTypes<Types = TempoNode>> TempoCompressedTxApiServer for TempoCompressedTxRpc<N> {
+    async fn send_raw_transaction_compressed(&self, tx: Bytes) -> RpcResult<B256> {
+        // The compressed transport encoding is a T4 feature; before that the
+        // canonical `eth_sendRawTransaction` is the only ingress.
+        let provider = self.eth_api.provider();
+        let best_number = provider.best_block_number().map_err(internal_err)?;
+        let header = provider
+            .header_by_number(best_number)
+            .map_err(internal_err)?
+            .ok_or_else(|| internal_err("head header not found"))?;
+        if !provider
+            .chain_spec()
+            .is_t4_active_at_timestamp(header.timestamp())
+        {
+            return Err(internal_err(
+                "tempo_sendRawTransactionCompressed is not available before T4",
+            ));
+        }
+
+        let signed = AASigned::eip2718_decode_compressed(&tx)
+            .map_err(|err| invalid_params(format!("invalid compressed transaction: {err}")))?;
+
+        // Forward the canonical bytes through the stock raw-transaction path,
+        // so pool validation and propagation see exactly what consensus will.
+        let canonical: Bytes = signed.encoded_2718().into();
+        self.eth_api
+            .send_raw_transaction(canonical)
+            .await
+            .map_err(|err| {
+                let err: ErrorObject<'static> = err.into();
+                err
+            })
+    }
+}
@@ -14,8 +14,9 @@ use jsonrpsee::{
 };
 
 pub use types::{
-    CertifiedBlock, ConsensusFeed, ConsensusState, Event, IdentityProofError, IdentityTransition,
-    IdentityTransitionResponse, Query, TransitionProofData,
+    CertifiedBlock, ConsensusFeed, ConsensusState, EpochSummary, EpochSummaryError,
+    EquivocationEvidence, Event, IdentityProofError, IdentityTransition,
+    IdentityTransitionResponse, Query, SafetyStatus, TransitionProofData,
 };
 
 /// Consensus namespace RPC trait.
@@ -33,6 +34,14 @@ pub trait TempoConsensusApi {
     #[method(name = "getLatest")]
     async fn get_latest(&self) -> RpcResult<ConsensusState>;
 
+    /// Get the safety telemetry snapshot for external watchdogs.
+    ///
+    /// Reports the latest notarized/finalized heights, the highest observed
+    /// round and its leader, and whether this node has observed conflicting
+    /// notarizations for any round (potential equivocation).
+    #[method(name = "getSafetyStatus")]
+    async fn get_safety_status(&self) -> RpcResult<SafetyStatus>;
+
     /// Subscribe to all consensus events (Notarized, Finalized, Nullified).
     #[subscription(name = "subscribe" => "event", unsubscribe = "unsubscribe", item = Event)]
     async fn subscribe_events(&self) -> jsonrpsee::core::SubscriptionResult;
@@ -51,6 +60,15 @@ pub trait TempoConsensusApi {
         from_epoch: Option<u64>,
         full: Option<bool>,
     ) -> RpcResult<IdentityTransitionResponse>;
+
+    /// Get aggregated consensus statistics for one epoch.
+    ///
+    /// Returns participation rate, views consumed, leader skips, average
+    /// time-to-finalize, and the DKG outcome hash, computed from stored
+    /// consensus artifacts. For the in-progress epoch the summary covers
+    /// heights up to the latest finalization.
+    #[method(name = "getEpochSummary")]
+    async fn get_epoch_summary(&self, epoch: u64) -> RpcResult<EpochSummary>;
 }
 
 /// Tempo consensus RPC implementation.
@@ -76,6 +94,10 @@ impl<I: ConsensusFeed> TempoConsensusApiServer for TempoConsensusRpc<I> {
         Ok(self.consensus_feed.get_latest().await)
     }
 
+    async fn get_safety_status(&self) -> RpcResult<SafetyStatus> {
+        Ok(self.consensus_feed.get_safety_status().await)
+    }
+
     async fn subscribe_events(
         &self,
         pending: jsonrpsee::PendingSubscriptionSink,
@@ -118,4 +140,11 @@ impl<I: ConsensusFeed> TempoConsensusApiServer for TempoConsensusRpc<I> {
             .await
             .map_err(|e| ErrorObject::owned(INTERNAL_ERROR_CODE, e.to_string(), None::<()>))
     }
+
+    async fn get_epoch_summary(&self, epoch: u64) -> RpcResult<EpochSummary> {
+        self.consensus_feed
+            .get_epoch_summary(epoch)
+            .await
+            .map_err(|e| ErrorObject::owned(INTERNAL_ERROR_CODE, e.to_string(), None::<()>))
+    }
 }
@@ -69,6 +69,54 @@ pub struct ConsensusState {
     pub notarized: Option<CertifiedBlock>,
 }
 
+/// A pair of conflicting notarizations observed for the same round.
+///
+/// Under the protocol's safety assumptions this must never happen; observing
+/// one means more than the tolerated share of validators signed two different
+/// blocks for the same view.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EquivocationEvidence {
+    /// Epoch of the conflicting notarizations.
+    pub epoch: u64,
+    /// View of the conflicting notarizations.
+    pub view: u64,
+    /// Digest of the first notarized block observed for the round.
+    pub first_digest: B256,
+    /// Digest of the conflicting notarized block.
+    pub second_digest: B256,
+    /// Unix timestamp in milliseconds when the conflict was observed.
+    pub seen: u64,
+}
+
+/// Safety-relevant consensus snapshot for external watchdogs.
+///
+/// All fields are best-effort views of what this node has observed locally;
+/// a watchdog should compare them across nodes to detect partitions or stalls.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SafetyStatus {
+    /// Height of the latest finalized block, if any.
+    pub finalized_height: Option<u64>,
+    /// View of the latest finalized block, if any.
+    pub finalized_view: Option<u64>,
+    /// Height of the latest notarized-but-not-finalized block, if any.
+    pub notarized_height: Option<u64>,
+    /// View of the latest notarized-but-not-finalized block, if any.
+    pub notarized_view: Option<u64>,
+    /// Epoch of the highest round observed via any certificate.
+    pub current_epoch: Option<u64>,
+    /// View of the highest round observed via any certificate.
+    pub current_view: Option<u64>,
+    /// Hex-encoded Ed25519 public key of the proposer of the latest observed
+    /// block, if its header carries a consensus context.
+    pub leader: Option<String>,
+    /// Whether conflicting notarizations have been observed since startup.
+    pub equivocation_observed: bool,
+    /// Evidence of observed conflicts, newest first, bounded in size.
+    pub equivocations: Vec<EquivocationEvidence>,
+}
+
 /// Error type for identity transition proof requests.
 #[derive(Clone, Debug, thiserror::Error)]
 pub enum IdentityProofError {
@@ -120,6 +168,60 @@ pub struct IdentityTransition {
     pub proof: Option<TransitionProofData>,
 }
 
+/// Error type for epoch summary requests.
+#[derive(Clone, Debug, thiserror::Error)]
+pub enum EpochSummaryError {
+    /// Node is not ready - consensus state not yet initialized.
+    #[error("node not ready")]
+    NotReady,
+    /// The epoch starts beyond the latest finalized height.
+    #[error("epoch {0} has not started")]
+    UnknownEpoch(u64),
+    /// Consensus artifacts for the epoch have been pruned.
+    #[error("consensus data pruned at height {0}")]
+    PrunedData(u64),
+    /// Failed to decode DKG outcome from block.
+    #[error("malformed DKG outcome at height {0}")]
+    MalformedData(u64),
+}
+
+/// Aggregated consensus statistics for one epoch.
+///
+/// Computed from stored finalization certificates and block headers, for
+/// monitoring dashboards and the staking UI.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpochSummary {
+    /// The summarized epoch.
+    pub epoch: u64,
+    /// First finalized height covered by the summary.
+    pub first_height: u64,
+    /// Last finalized height covered by the summary.
+    pub last_height: u64,
+    /// Whether the epoch has fully finalized. For the in-progress epoch the
+    /// summary covers heights up to the latest finalization.
+    pub complete: bool,
+    /// Finalized blocks in the covered range.
+    pub blocks: u64,
+    /// Views consumed over the covered range, including nullified ones.
+    pub views: u64,
+    /// Views that did not produce a finalized block (nullifications / leader
+    /// skips) within the covered range.
+    pub leader_skips: u64,
+    /// Share of views that produced a finalized block, in `[0, 1]`. Threshold
+    /// certificates do not expose per-validator attendance, so this is the
+    /// per-view success rate rather than a per-validator breakdown.
+    pub participation_rate: f64,
+    /// Average wall-clock milliseconds between consecutive finalized blocks.
+    /// `None` when the range contains fewer than two blocks.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub average_time_to_finalize_ms: Option<u64>,
+    /// Keccak hash of the DKG outcome sealed in the epoch's last block.
+    /// `None` while the epoch is still in progress.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dkg_outcome_hash: Option<B256>,
+}
+
 /// Cryptographic proof data for an identity transition.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -139,6 +241,10 @@ pub trait ConsensusFeed: Send + Sync + 'static {
     /// Get the current consensus state (latest finalized + latest notarized).
     fn get_latest(&self) -> impl Future<Output = ConsensusState> + Send;
 
+    /// Get the safety telemetry snapshot (heights, current round, leader,
+    /// observed equivocations).
+    fn get_safety_status(&self) -> impl Future<Output = SafetyStatus> + Send;
+
     /// Subscribe to consensus events.
     fn subscribe(&self) -> impl Future<Output = Option<broadcast::Receiver<Event>>> + Send;
 
@@ -151,4 +257,10 @@ pub trait ConsensusFeed: Send + Sync + 'static {
         from_epoch: Option<u64>,
         full: bool,
     ) -> impl Future<Output = Result<IdentityTransitionResponse, IdentityProofError>> + Send;
+
+    /// Get aggregated consensus statistics for one epoch.
+    fn get_epoch_summary(
+        &self,
+        epoch: u64,
+    ) -> impl Future<Output = Result<EpochSummary, EpochSummaryError>> + Send;
 }
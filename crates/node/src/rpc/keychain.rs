@@ -0,0 +1,346 @@
+//! `tempo_getKeychain`: decoded AccountKeychain state for an account.
+//!
+//! Wallets otherwise need to know the precompile storage layout or issue one
+//! `eth_call` per getter to reconstruct a key's configuration. This endpoint
+//! decodes everything about the requested keys — authorization status, expiry,
+//! remaining uses, per-token spending limits with period rollover applied, and
+//! call scopes — from state in a single roundtrip.
+//!
+//! Key ids are not enumerable on chain (the keychain stores them in a plain
+//! mapping), so callers pass the ids they care about, typically collected from
+//! `KeyAuthorized` events. The same applies to spending-limit tokens.
+
+use crate::{node::TempoNode, rpc::TempoEthApi};
+use alloy_primitives::{Address, FixedBytes, U256};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::ErrorObject};
+use reth_errors::RethError;
+use reth_ethereum::evm::revm::database::StateProviderDatabase;
+use reth_node_api::FullNodeTypes;
+use reth_primitives_traits::AlloyBlockHeader as _;
+use reth_provider::{BlockIdReader, ChainSpecProvider, HeaderProvider};
+use reth_rpc_eth_api::{
+    RpcNodeCore,
+    helpers::{LoadState, SpawnBlocking},
+};
+use reth_rpc_eth_types::EthApiError;
+use serde::{Deserialize, Serialize};
+use tempo_chainspec::hardfork::TempoHardforks;
+use tempo_evm::TempoStateAccess;
+use tempo_precompiles::{
+    account_keychain::{AccountKeychain, getAllowedCallsCall},
+    error::TempoPrecompileError,
+};
+
+/// Response for `tempo_getKeychain`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeychainSnapshot {
+    /// Account the keys belong to.
+    pub account: Address,
+    /// Block the snapshot was taken at.
+    pub block_number: u64,
+    /// Timestamp of that block; expiry and period rollovers are evaluated
+    /// against it.
+    pub timestamp: u64,
+    /// One entry per requested key id, in request order.
+    pub keys: Vec<KeychainKeySnapshot>,
+}
+
+/// Decoded state of one account key.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeychainKeySnapshot {
+    /// Key identifier.
+    pub key_id: Address,
+    /// Whether the key can currently authorize transactions: it exists, has
+    /// not been revoked, and has not expired.
+    pub authorized: bool,
+    /// Whether the key has been permanently revoked.
+    pub is_revoked: bool,
+    /// Signature type: 0 = secp256k1, 1 = P256, 2 = WebAuthn.
+    pub signature_type: u8,
+    /// Expiry timestamp; `0` means the key was never authorized.
+    pub expiry: u64,
+    /// Whether spending limits are enforced for this key.
+    pub enforce_limits: bool,
+    /// Remaining transaction uses for use-limited keys; absent means
+    /// unlimited.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remaining_uses: Option<u64>,
+    /// Effective spending limits for the requested tokens, with period
+    /// rollover applied.
+    pub spending_limits: Vec<KeychainSpendingLimit>,
+    /// Call scopes configured for the key.
+    pub allowed_calls: KeychainAllowedCalls,
+}
+
+/// Effective spending limit for one key-token pair.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeychainSpendingLimit {
+    /// TIP-20 token the limit applies to.
+    pub token: Address,
+    /// Remaining amount available to spend in the current period.
+    pub remaining: U256,
+    /// End timestamp of the current period window; `0` for non-periodic
+    /// limits.
+    pub period_end: u64,
+}
+
+/// Call scoping configuration for one key.
+///
+/// `is_scoped = false` means unrestricted; `is_scoped = true` with empty
+/// `scopes` means the key currently allows no calls (including missing,
+/// revoked, and expired keys).
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeychainAllowedCalls {
+    pub is_scoped: bool,
+    pub scopes: Vec<KeychainCallScope>,
+}
+
+/// Allowed calls for one target contract.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeychainCallScope {
+    /// Target contract address.
+    pub target: Address,
+    /// Per-selector rules; empty means any selector on the target is allowed.
+    pub selector_rules: Vec<KeychainSelectorRule>,
+}
+
+/// Allowed recipients for one selector under a target.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KeychainSelectorRule {
+    /// Function selector.
+    pub selector: FixedBytes<4>,
+    /// Allowed recipients; empty means any recipient is allowed.
+    pub recipients: Vec<Address>,
+}
+
+#[rpc(server, namespace = "tempo")]
+pub trait TempoKeychainApi {
+    /// Returns the decoded keychain configuration for `account` at the given
+    /// block (latest by default).
+    ///
+    /// `key_ids` selects which keys to decode (key ids are not enumerable on
+    /// chain; wallets track them from `KeyAuthorized` events). `tokens`
+    /// selects which per-token spending limits to report.
+    #[method(name = "getKeychain")]
+    async fn get_keychain(
+        &self,
+        account: Address,
+        key_ids: Vec<Address>,
+        tokens: Option<Vec<Address>>,
+        block: Option<alloy_eips::BlockId>,
+    ) -> RpcResult<KeychainSnapshot>;
+}
+
+/// Implementation of `tempo_getKeychain`.
+#[derive(Debug, Clone)]
+pub struct TempoKeychain<N: FullNodeTypes<Types = TempoNode>> {
+    eth_api: TempoEthApi<N>,
+}
+
+impl<N: FullNodeTypes<Types = TempoNode>> TempoKeychain<N> {
+    pub fn new(eth_api: TempoEthApi<N>) -> Self {
+        Self { eth_api }
+    }
+}
+
+#[async_trait::async_trait]
+impl<N: FullNodeTypes<Types = TempoNode>> TempoKeychainApiServer for TempoKeychain<N> {
+    async fn get_keychain(
+        &self,
+        account: Address,
+        key_ids: Vec<Address>,
+        tokens: Option<Vec<Address>>,
+        block: Option<alloy_eips::BlockId>,
+    ) -> RpcResult<KeychainSnapshot> {
+        self.eth_api
+            .spawn_blocking_io_fut(async move |this| {
+                let state = this.state_at_block_id_or_latest(block).await?;
+
+                // Resolve the block header so expiry and period rollovers are
+                // evaluated at the snapshot's own timestamp, not wall time.
+                let block_id = block.unwrap_or_default();
+                let number = this
+                    .provider()
+                    .block_number_for_id(block_id)?
+                    .ok_or(EthApiError::HeaderNotFound(block_id))?;
+                let header = this
+                    .provider()
+                    .header_by_number(number)?
+                    .ok_or(EthApiError::HeaderNotFound(block_id))?;
+                let timestamp = header.timestamp();
+
+                let spec = this.provider().chain_spec().tempo_hardfork_at(timestamp);
+                let mut db = StateProviderDatabase::new(state);
+
+                let keys = db
+                    .with_read_only_storage_ctx(spec, || {
+                        let keychain = AccountKeychain::new();
+                        let tokens = tokens.as_deref().unwrap_or_default();
+
+                        let mut keys = Vec::with_capacity(key_ids.len());
+                        for key_id in &key_ids {
+                            keys.push(decode_key(&keychain, account, *key_id, tokens, timestamp)?);
+                        }
+                        Ok::<_, TempoPrecompileError>(keys)
+                    })
+                    .map_err(|e| {
+                        EthApiError::Internal(RethError::msg(format!(
+                            "failed to decode keychain state: {e}"
+                        )))
+                    })?;
+
+                Ok(KeychainSnapshot {
+                    account,
+                    block_number: number,
+                    timestamp,
+                    keys,
+                })
+            })
+            .await
+            .map_err(|e| {
+                let err: ErrorObject<'static> = e.into();
+                err
+            })
+    }
+}
+
+/// Decodes one key's full configuration at `timestamp`.
+///
+/// Missing keys are reported rather than erroring (`expiry = 0`,
+/// `authorized = false`) so wallets can probe candidate ids.
+fn decode_key(
+    keychain: &AccountKeychain,
+    account: Address,
+    key_id: Address,
+    tokens: &[Address],
+    timestamp: u64,
+) -> Result<KeychainKeySnapshot, TempoPrecompileError> {
+    // Read the raw entry instead of `getKey`, which blanks out revoked keys;
+    // the snapshot should report revocation explicitly.
+    let key = keychain.keys[account][key_id].read()?;
+    let authorized = key.expiry != 0 && !key.is_revoked && timestamp < key.expiry;
+
+    let mut spending_limits = Vec::with_capacity(tokens.len());
+    for token in tokens {
+        let (remaining, period_end) =
+            keychain.effective_limit_with_period(account, key_id, *token, timestamp)?;
+        spending_limits.push(KeychainSpendingLimit {
+            token: *token,
+            remaining,
+            period_end,
+        });
+    }
+
+    let allowed = keychain.get_allowed_calls_at(
+        getAllowedCallsCall {
+            account,
+            keyId: key_id,
+        },
+        timestamp,
+    )?;
+
+    Ok(KeychainKeySnapshot {
+        key_id,
+        authorized,
+        is_revoked: key.is_revoked,
+        signature_type: key.signature_type,
+        expiry: key.expiry,
+        enforce_limits: key.enforce_limits,
+        remaining_uses: key.use_limited.then_some(key.remaining_uses),
+        spending_limits,
+        allowed_calls: KeychainAllowedCalls {
+            is_scoped: allowed.isScoped,
+            scopes: allowed
+                .scopes
+                .into_iter()
+                .map(|scope| KeychainCallScope {
+                    target: scope.target,
+                    selector_rules: scope
+                        .selectorRules
+                        .into_iter()
+                        .map(|rule| KeychainSelectorRule {
+                            selector: rule.selector,
+                            recipients: rule.recipients,
+                        })
+                        .collect(),
+                })
+                .collect(),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempo_precompiles::{
+        account_keychain::{AuthorizedKey, SpendingLimitState},
+        storage::{StorageCtx, hashmap::HashMapStorageProvider},
+    };
+
+    #[test]
+    fn decodes_active_revoked_and_missing_keys() {
+        let account = Address::random();
+        let active = Address::random();
+        let revoked = Address::random();
+        let missing = Address::random();
+        let token = Address::random();
+
+        let mut storage = HashMapStorageProvider::new(1);
+        let snapshots = StorageCtx::enter(&mut storage, || {
+            let mut keychain = AccountKeychain::new();
+            keychain.keys[account][active]
+                .write(AuthorizedKey {
+                    signature_type: 1,
+                    expiry: 1_000,
+                    enforce_limits: true,
+                    is_revoked: false,
+                    use_limited: true,
+                    remaining_uses: 7,
+                })
+                .unwrap();
+            let limit_key = AccountKeychain::spending_limit_key(account, active);
+            keychain.spending_limits[limit_key][token]
+                .write(SpendingLimitState {
+                    remaining: U256::from(500),
+                    max: 500,
+                    period: 0,
+                    period_end: 0,
+                })
+                .unwrap();
+            keychain.keys[account][revoked]
+                .write(AuthorizedKey {
+                    signature_type: 0,
+                    expiry: 1_000,
+                    is_revoked: true,
+                    ..Default::default()
+                })
+                .unwrap();
+
+            [active, revoked, missing]
+                .map(|key_id| decode_key(&keychain, account, key_id, &[token], 100).unwrap())
+        });
+
+        let [active, revoked, missing] = snapshots;
+        assert!(active.authorized);
+        assert_eq!(active.remaining_uses, Some(7));
+        assert_eq!(active.spending_limits[0].remaining, U256::from(500));
+        assert!(!active.allowed_calls.is_scoped);
+
+        assert!(!revoked.authorized);
+        assert!(revoked.is_revoked);
+        assert_eq!(revoked.spending_limits[0].remaining, U256::ZERO);
+        // Revoked keys report scoped deny-all rather than stale scope state.
+        assert!(revoked.allowed_calls.is_scoped);
+        assert!(revoked.allowed_calls.scopes.is_empty());
+
+        assert!(!missing.authorized);
+        assert_eq!(missing.expiry, 0);
+        assert_eq!(missing.remaining_uses, None);
+    }
+}
@@ -0,0 +1,147 @@
+//! `eth_getLogs` override backed by the historical log index.
+//!
+//! When the node is started with `--logindex.address`, this handler replaces
+//! the stock `eth_getLogs`: for range filters fully covered by the
+//! [`LogIndex`] it resolves the filter to the exact blocks containing matches
+//! and delegates only those (coalesced into contiguous runs) to the stock
+//! [`EthFilter`] for log materialization, instead of scanning every header
+//! bloom in the range. Filters the index cannot answer — unindexed addresses,
+//! no `topic0` constraint, tag-based or unbackfilled ranges — fall through to
+//! the stock handler unchanged.
+
+use crate::{log_index::LogIndex, node::TempoNode, rpc::TempoEthApi};
+use alloy_eips::BlockNumberOrTag;
+use alloy_rpc_types_eth::{Filter, FilterBlockOption, Log, ValueOrArray};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_node_api::FullNodeTypes;
+use reth_rpc::eth::EthFilter;
+use reth_rpc_eth_api::{EthFilterApiServer, RpcTransaction};
+use std::{ops::RangeInclusive, sync::Arc};
+use tempo_alloy::TempoNetwork;
+
+/// `eth_getLogs` served through the historical log index.
+#[rpc(server, namespace = "eth")]
+pub trait TempoLogsApi {
+    /// Returns logs matching the given filter, using the log index to skip
+    /// blocks without matches where possible.
+    #[method(name = "getLogs")]
+    async fn logs(&self, filter: Filter) -> RpcResult<Vec<Log>>;
+}
+
+/// Index-accelerated `eth_getLogs` handler wrapping the stock filter API.
+#[derive(Debug)]
+pub struct TempoLogsRpc<N: FullNodeTypes<Types = TempoNode>> {
+    index: Arc<LogIndex>,
+    inner: EthFilter<TempoEthApi<N>>,
+}
+
+impl<N: FullNodeTypes<Types = TempoNode>> TempoLogsRpc<N> {
+    /// Creates the handler over the shared index and the stock filter API it
+    /// delegates log materialization to.
+    pub fn new(index: Arc<LogIndex>, inner: EthFilter<TempoEthApi<N>>) -> Self {
+        Self { index, inner }
+    }
+
+    /// Resolves `filter` through the index to the contiguous block ranges that
+    /// can contain matches, or `None` if the index cannot answer this filter
+    /// and the stock scan must run.
+    fn narrowed_ranges(&self, filter: &Filter) -> Option<Vec<RangeInclusive<u64>>> {
+        // Only explicit numeric ranges benefit; tag bounds and block hashes go
+        // through the stock path, which resolves them against the chain.
+        let FilterBlockOption::Range {
+            from_block: Some(BlockNumberOrTag::Number(from)),
+            to_block: Some(BlockNumberOrTag::Number(to)),
+        } = filter.block_option
+        else {
+            return None;
+        };
+        if from > to {
+            // Let the stock handler produce its usual error for this.
+            return None;
+        }
+        // The index must fully cover the range: backfill complete and the live
+        // ExEx caught up past the upper bound.
+        if !self.index.is_backfilled() || self.index.indexed_through().is_none_or(|tip| tip < to) {
+            return None;
+        }
+
+        // An empty address set matches every address; the index only covers
+        // the configured ones.
+        let addresses = match filter.address.to_value_or_array()? {
+            ValueOrArray::Value(address) => vec![address],
+            ValueOrArray::Array(addresses) => addresses,
+        };
+        if addresses.iter().any(|address| !self.index.indexes(address)) {
+            return None;
+        }
+        // Without a topic0 constraint the index cannot prove a block empty.
+        let topics = match filter.topics[0].to_value_or_array()? {
+            ValueOrArray::Value(topic) => vec![topic],
+            ValueOrArray::Array(topics) => topics,
+        };
+
+        let mut blocks: Vec<u64> = addresses
+            .iter()
+            .flat_map(|address| {
+                topics
+                    .iter()
+                    .flat_map(|topic| self.index.blocks_in_range(*address, *topic, from..=to))
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        blocks.sort_unstable();
+        blocks.dedup();
+
+        Some(coalesce(&blocks))
+    }
+}
+
+/// Coalesces an ascending block list into contiguous inclusive ranges.
+fn coalesce(blocks: &[u64]) -> Vec<RangeInclusive<u64>> {
+    let mut ranges: Vec<RangeInclusive<u64>> = Vec::new();
+    for &block in blocks {
+        match ranges.last_mut() {
+            Some(last) if block <= last.end().saturating_add(1) => {
+                *last = *last.start()..=block;
+            }
+            _ => ranges.push(block..=block),
+        }
+    }
+    ranges
+}
+
+#[async_trait::async_trait]
+impl<N: FullNodeTypes<Types = TempoNode>> TempoLogsApiServer for TempoLogsRpc<N> {
+    async fn logs(&self, filter: Filter) -> RpcResult<Vec<Log>> {
+        let Some(ranges) = self.narrowed_ranges(&filter) else {
+            return EthFilterApiServer::<RpcTransaction<TempoNetwork>>::logs(&self.inner, filter)
+                .await;
+        };
+
+        let mut logs = Vec::new();
+        for range in ranges {
+            let mut narrowed = filter.clone();
+            narrowed.block_option = FilterBlockOption::Range {
+                from_block: Some(BlockNumberOrTag::Number(*range.start())),
+                to_block: Some(BlockNumberOrTag::Number(*range.end())),
+            };
+            logs.extend(
+                EthFilterApiServer::<RpcTransaction<TempoNetwork>>::logs(&self.inner, narrowed)
+                    .await?,
+            );
+        }
+        Ok(logs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coalesce_merges_contiguous_runs() {
+        assert!(coalesce(&[]).is_empty());
+        assert_eq!(coalesce(&[5]), vec![5..=5]);
+        assert_eq!(coalesce(&[1, 2, 3, 7, 8, 12]), vec![1..=3, 7..=8, 12..=12]);
+    }
+}
@@ -1,25 +1,54 @@
 pub mod admin;
+pub mod block_fees;
+pub mod call_overrides;
+pub mod compressed_tx;
 pub mod consensus;
 pub mod error;
 pub mod eth_ext;
 pub mod fork_schedule;
+pub mod keychain;
+pub mod logs;
 pub mod operator;
+pub mod peers;
+pub mod preconfirmation;
+pub mod rate_limit;
+pub mod retention;
 pub mod simulate;
 pub mod token;
+pub mod witness;
 
 pub use admin::{TempoAdminApi, TempoAdminApiServer};
 use alloy_primitives::B256;
 use alloy_rpc_types_eth::{Log, ReceiptWithBloom};
+pub use block_fees::{TempoBlockFees, TempoBlockFeesApiServer, TokenFeeTotal};
+pub use call_overrides::{
+    KeychainKeyOverride, PrecompileStateOverride, SpendingLimitOverride, TempoCall,
+    TempoCallApiServer, Tip20BalanceOverride,
+};
+pub use compressed_tx::{TempoCompressedTxApiServer, TempoCompressedTxRpc};
 pub use consensus::{TempoConsensusApiServer, TempoConsensusRpc};
 pub use eth_ext::{TempoEthExt, TempoEthExtApiServer};
 pub use fork_schedule::{TempoForkScheduleApiServer, TempoForkScheduleRpc};
 use futures::{TryFutureExt, future::Either};
+pub use keychain::{KeychainKeySnapshot, KeychainSnapshot, TempoKeychain, TempoKeychainApiServer};
+pub use logs::{TempoLogsApiServer, TempoLogsRpc};
 pub use operator::{TempoOperatorApiServer, TempoOperatorRpc};
+pub use peers::{PeerFeed, PeerInfo, PeersSnapshot, TempoPeersApiServer, TempoPeersRpc};
+pub use preconfirmation::{
+    PreConfirmationFeed, TempoPreConfirmationApiServer, TempoPreConfirmationRpc,
+};
+pub use rate_limit::{
+    MethodQuota, RpcRateLimitConfig, RpcRateLimitHealth, RpcRateLimitLayer, RpcRateLimiter,
+};
+pub use retention::{RetentionSnapshot, TempoRetentionApiServer, TempoRetentionRpc};
 use reth_errors::RethError;
 use reth_primitives_traits::{Recovered, TransactionMeta, WithEncoded, transaction::TxHashRef};
 use reth_rpc_eth_api::{FromEthApiError, IntoEthApiError, RpcTxReq};
 use reth_transaction_pool::{PoolPooledTx, TransactionOrigin};
-pub use simulate::{TempoSimulate, TempoSimulateApiServer, TempoSimulateV1Response};
+pub use simulate::{
+    KeychainChange, TempoSimulate, TempoSimulateApiServer, TempoSimulateTransactionResponse,
+    TempoSimulateV1Response,
+};
 use std::sync::Arc;
 pub use tempo_alloy::rpc::TempoTransactionRequest;
 use tempo_chainspec::TempoChainSpec;
@@ -27,6 +56,7 @@ use tempo_evm::TempoStateAccess;
 use tempo_precompiles::{NONCE_PRECOMPILE_ADDRESS, nonce::NonceManager};
 use tempo_primitives::transaction::TEMPO_EXPIRING_NONCE_KEY;
 pub use token::{TempoToken, TempoTokenApiServer};
+pub use witness::{TempoExecutionWitness, TempoWitnessApiServer, TempoWitnessRpc};
 
 use crate::{node::TempoNode, rpc::error::TempoEthApiError};
 use alloy::primitives::{U256, uint};
@@ -62,8 +92,9 @@ use reth_rpc_eth_types::{
 use tempo_alloy::{TempoNetwork, rpc::TempoTransactionReceipt};
 use tempo_evm::TempoEvmConfig;
 use tempo_primitives::{
-    TEMPO_GAS_PRICE_SCALING_FACTOR, TempoPrimitives, TempoReceipt, TempoTxEnvelope,
-    subblock::PartialValidatorKey,
+    KeyAuthorizationResult, TEMPO_GAS_PRICE_SCALING_FACTOR, TempoPrimitives, TempoReceipt,
+    TempoReceiptFields, TempoTxEnvelope, subblock::PartialValidatorKey,
+    transaction::calc_gas_balance_spending,
 };
 use tokio::sync::{Mutex, broadcast};
 
@@ -463,13 +494,26 @@ impl ReceiptConverter<TempoPrimitives> for TempoReceiptConverter {
             .into_iter()
             .zip(txs)
             .map(|(inner, tx)| {
+                let status = inner.status();
                 let mut receipt = TempoTransactionReceipt {
                     inner,
-                    fee_token: None,
-                    // should never fail, we only deal with valid transactions here
-                    fee_payer: tx
-                        .fee_payer(tx.signer())
-                        .map_err(|_| EthApiError::InvalidTransactionSignature)?,
+                    tempo: TempoReceiptFields {
+                        fee_token: None,
+                        // should never fail, we only deal with valid transactions here
+                        fee_payer: tx
+                            .fee_payer(tx.signer())
+                            .map_err(|_| EthApiError::InvalidTransactionSignature)?,
+                        fee_amount_in_token: None,
+                        // Authorizations only take effect when the transaction succeeds.
+                        key_authorization: tx
+                            .as_aa()
+                            .and_then(|aa| aa.tx().key_authorization.as_ref())
+                            .map(|auth| KeyAuthorizationResult {
+                                key_id: auth.key_id,
+                                key_type: auth.key_type,
+                                authorized: status,
+                            }),
+                    },
                 };
                 if receipt.effective_gas_price == 0 || receipt.gas_used == 0 {
                     return Ok(receipt);
@@ -479,7 +523,11 @@ impl ReceiptConverter<TempoPrimitives> for TempoReceiptConverter {
                 //
                 // Assumption is that every non-free transaction will end with a
                 // fee token transfer to TIPFeeManager.
-                receipt.fee_token = receipt.logs().last().map(|log| log.address());
+                receipt.tempo.fee_token = receipt.logs().last().map(|log| log.address());
+                receipt.tempo.fee_amount_in_token = Some(calc_gas_balance_spending(
+                    receipt.gas_used,
+                    receipt.effective_gas_price,
+                ));
                 Ok(receipt)
             })
             .collect()
@@ -0,0 +1,104 @@
+//! Admin RPC surface for the consensus P2P peer set.
+//!
+//! The consensus layer maintains its own peer set, separate from the devp2p
+//! peers reported by `admin_peers`. This module exposes that state (tracked
+//! validators, their addresses, block status) and lets operators block or
+//! unblock a peer at runtime. The data is provided by the consensus layer via
+//! the [`PeerFeed`] trait, mirroring how [`super::consensus::ConsensusFeed`]
+//! bridges the two layers.
+
+use alloy_primitives::B256;
+use futures::Future;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{Deserialize, Serialize};
+
+/// One consensus peer as known to the peer manager.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeerInfo {
+    /// Ed25519 public key of the peer.
+    pub public_key: B256,
+    /// Last registered P2P address of the peer.
+    pub address: String,
+    /// Whether the peer is part of the currently tracked peer set.
+    pub tracked: bool,
+    /// Whether the peer is blocked. Blocked peers are excluded from the peer
+    /// sets registered with the P2P layer.
+    pub blocked: bool,
+    /// Unix timestamp (seconds) at which the peer last appeared in a peer set
+    /// refresh.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_seen: Option<u64>,
+}
+
+/// Snapshot of the consensus peer set.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PeersSnapshot {
+    /// Block height the tracked peer set was derived from.
+    pub height: u64,
+    /// All peers the manager knows about, tracked or blocked.
+    pub peers: Vec<PeerInfo>,
+}
+
+/// Trait for accessing and mutating the consensus peer set.
+///
+/// Implemented by the consensus layer's peer manager state handle.
+pub trait PeerFeed: Send + Sync + 'static {
+    /// Returns the current peer set snapshot.
+    fn peers(&self) -> impl Future<Output = PeersSnapshot> + Send;
+
+    /// Blocks or unblocks a peer. Returns true if the block status changed.
+    ///
+    /// Takes effect on the next peer set refresh.
+    fn set_blocked(&self, public_key: B256, blocked: bool) -> impl Future<Output = bool> + Send;
+}
+
+/// Admin namespace RPC for the consensus peer set.
+#[rpc(server, client, namespace = "admin")]
+pub trait TempoPeersApi {
+    /// Returns the consensus peer set: tracked validators, their addresses,
+    /// block status, and when each was last seen in a peer set refresh.
+    #[method(name = "validatorPeers")]
+    async fn validator_peers(&self) -> RpcResult<PeersSnapshot>;
+
+    /// Blocks a consensus peer, excluding it from registered peer sets.
+    ///
+    /// Returns true if the peer was not already blocked.
+    #[method(name = "blockValidatorPeer")]
+    async fn block_validator_peer(&self, public_key: B256) -> RpcResult<bool>;
+
+    /// Unblocks a previously blocked consensus peer.
+    ///
+    /// Returns true if the peer was blocked.
+    #[method(name = "unblockValidatorPeer")]
+    async fn unblock_validator_peer(&self, public_key: B256) -> RpcResult<bool>;
+}
+
+/// Implementation of the consensus peer set admin RPC.
+#[derive(Debug, Clone)]
+pub struct TempoPeersRpc<F> {
+    feed: F,
+}
+
+impl<F: PeerFeed> TempoPeersRpc<F> {
+    /// Create a new peers RPC handler.
+    pub fn new(feed: F) -> Self {
+        Self { feed }
+    }
+}
+
+#[async_trait::async_trait]
+impl<F: PeerFeed> TempoPeersApiServer for TempoPeersRpc<F> {
+    async fn validator_peers(&self) -> RpcResult<PeersSnapshot> {
+        Ok(self.feed.peers().await)
+    }
+
+    async fn block_validator_peer(&self, public_key: B256) -> RpcResult<bool> {
+        Ok(self.feed.set_blocked(public_key, true).await)
+    }
+
+    async fn unblock_validator_peer(&self, public_key: B256) -> RpcResult<bool> {
+        Ok(self.feed.set_blocked(public_key, false).await)
+    }
+}
@@ -0,0 +1,66 @@
+//! Soft pre-confirmation acknowledgements from the current leader.
+//!
+//! When this node is building a subblock, it can acknowledge that a
+//! transaction is included in that pending subblock by returning a signed
+//! [`SignedPreConfirmation`]. Payment apps can display the acknowledgement
+//! before full finalization and verify it offline against the validator's
+//! ed25519 key via [`SignedPreConfirmation::verify`]. The data is provided by
+//! the subblock builder via the [`PreConfirmationFeed`] trait, mirroring how
+//! [`super::peers::PeerFeed`] bridges the consensus layer.
+
+use alloy_primitives::B256;
+use futures::Future;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use tempo_primitives::subblock::SignedPreConfirmation;
+
+/// Trait for requesting pre-confirmations from the subblock builder.
+///
+/// Implemented by the consensus layer's subblock service handle.
+pub trait PreConfirmationFeed: Send + Sync + 'static {
+    /// Returns a signed acknowledgement if `tx_hash` is included in the
+    /// subblock this node is currently building, `None` otherwise.
+    fn preconfirm(
+        &self,
+        tx_hash: B256,
+    ) -> impl Future<Output = Option<SignedPreConfirmation>> + Send;
+}
+
+/// Tempo namespace RPC for proposer pre-confirmations.
+#[rpc(server, client, namespace = "tempo")]
+pub trait TempoPreConfirmationApi {
+    /// Returns a signed acknowledgement that the transaction is included in
+    /// the subblock this node is currently building, or `null` if the node is
+    /// not building one or the transaction is not part of it.
+    ///
+    /// The acknowledgement is a soft promise, not an inclusion proof; verify
+    /// it against the validator's ed25519 key and treat it as superseded once
+    /// the transaction is finalized (or the parent block is reorged away).
+    #[method(name = "preconfirmTransaction")]
+    async fn preconfirm_transaction(
+        &self,
+        tx_hash: B256,
+    ) -> RpcResult<Option<SignedPreConfirmation>>;
+}
+
+/// Implementation of the proposer pre-confirmation RPC.
+#[derive(Debug, Clone)]
+pub struct TempoPreConfirmationRpc<F> {
+    feed: F,
+}
+
+impl<F: PreConfirmationFeed> TempoPreConfirmationRpc<F> {
+    /// Create a new pre-confirmation RPC handler.
+    pub fn new(feed: F) -> Self {
+        Self { feed }
+    }
+}
+
+#[async_trait::async_trait]
+impl<F: PreConfirmationFeed> TempoPreConfirmationApiServer for TempoPreConfirmationRpc<F> {
+    async fn preconfirm_transaction(
+        &self,
+        tx_hash: B256,
+    ) -> RpcResult<Option<SignedPreConfirmation>> {
+        Ok(self.feed.preconfirm(tx_hash).await)
+    }
+}
@@ -0,0 +1,424 @@
+//! Per-method RPC rate limiting and batch-size enforcement.
+//!
+//! Validators that expose public RPC are easy to overload with precompile-heavy
+//! `eth_call` batches. This middleware lets operators cap individual methods
+//! with token-bucket quotas (`--rpc.method-quota eth_call=50`) and bound batch
+//! sizes (`--rpc.max-batch-size`), rejecting excess requests with error code
+//! [`RATE_LIMITED_CODE`] before they reach the handlers. Rejections are counted
+//! in metrics and surfaced through `admin_rpcRateLimitHealth`.
+
+use jsonrpsee::{
+    MethodResponse,
+    core::middleware::{Batch, BatchEntry, Notification, RpcServiceT},
+    types::{ErrorObject, Id, Request},
+};
+use reth_metrics::{Metrics, metrics::Counter};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::Instant,
+};
+
+/// JSON-RPC error code returned for rate-limited requests and oversized batches.
+pub const RATE_LIMITED_CODE: i32 = -32005;
+
+/// A per-second request quota for a single RPC method.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MethodQuota {
+    /// Fully qualified method name, e.g. `eth_call`.
+    pub method: String,
+    /// Maximum sustained requests per second (also the burst capacity).
+    pub per_second: u32,
+}
+
+impl FromStr for MethodQuota {
+    type Err = String;
+
+    /// Parses `METHOD=REQUESTS_PER_SECOND`, e.g. `eth_call=50`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (method, quota) = s
+            .split_once('=')
+            .ok_or_else(|| format!("invalid method quota '{s}', expected METHOD=QPS"))?;
+        let per_second: u32 = quota
+            .parse()
+            .map_err(|err| format!("invalid quota for '{method}': {err}"))?;
+        if method.is_empty() {
+            return Err(format!("invalid method quota '{s}', empty method name"));
+        }
+        if per_second == 0 {
+            return Err(format!("invalid quota for '{method}': must be non-zero"));
+        }
+        Ok(Self {
+            method: method.to_string(),
+            per_second,
+        })
+    }
+}
+
+/// Operator-facing rate limit configuration.
+///
+/// An empty config (the default) disables the middleware entirely; methods
+/// without a quota are never limited.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RpcRateLimitConfig {
+    /// Per-method quotas; a later entry for the same method overrides earlier ones.
+    pub quotas: Vec<MethodQuota>,
+    /// Maximum number of requests in a single batch.
+    pub max_batch_size: Option<usize>,
+}
+
+impl RpcRateLimitConfig {
+    /// Creates a config from CLI values.
+    pub fn new(quotas: Vec<MethodQuota>, max_batch_size: Option<usize>) -> Self {
+        Self {
+            quotas,
+            max_batch_size,
+        }
+    }
+
+    /// Returns `true` if any limit is configured.
+    pub fn is_enabled(&self) -> bool {
+        !self.quotas.is_empty() || self.max_batch_size.is_some()
+    }
+}
+
+/// Token bucket refilled at `per_second` with burst capacity `per_second`.
+#[derive(Debug)]
+struct TokenBucket {
+    per_second: u32,
+    tokens: f64,
+    last_refill: Instant,
+    rejected: u64,
+}
+
+impl TokenBucket {
+    fn new(per_second: u32, now: Instant) -> Self {
+        Self {
+            per_second,
+            tokens: per_second as f64,
+            last_refill: now,
+            rejected: 0,
+        }
+    }
+
+    fn try_acquire(&mut self, now: Instant) -> bool {
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.per_second as f64).min(self.per_second as f64);
+        self.last_refill = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            self.rejected += 1;
+            false
+        }
+    }
+}
+
+#[derive(Metrics)]
+#[metrics(scope = "tempo_rpc_rate_limit")]
+struct RateLimitMetrics {
+    /// Calls rejected by per-method quotas.
+    rejected_calls: Counter,
+    /// Batches rejected for exceeding the maximum batch size.
+    rejected_batches: Counter,
+}
+
+/// Shared rate limiter state behind the middleware and the health readout.
+#[derive(Debug)]
+pub struct RpcRateLimiter {
+    max_batch_size: Option<usize>,
+    buckets: Mutex<HashMap<String, TokenBucket>>,
+    rejected_batches: Mutex<u64>,
+    metrics: RateLimitMetrics,
+}
+
+impl RpcRateLimiter {
+    /// Creates a limiter from the given config.
+    pub fn new(config: RpcRateLimitConfig) -> Self {
+        let now = Instant::now();
+        let mut buckets = HashMap::new();
+        for quota in config.quotas {
+            // Later CLI entries override earlier ones for the same method.
+            buckets.insert(quota.method, TokenBucket::new(quota.per_second, now));
+        }
+        Self {
+            max_batch_size: config.max_batch_size,
+            buckets: Mutex::new(buckets),
+            rejected_batches: Mutex::new(0),
+            metrics: RateLimitMetrics::default(),
+        }
+    }
+
+    /// Returns `true` if a call to `method` is within its quota.
+    /// Methods without a configured quota are never limited.
+    pub fn allow_call(&self, method: &str) -> bool {
+        self.allow_call_at(method, Instant::now())
+    }
+
+    fn allow_call_at(&self, method: &str, now: Instant) -> bool {
+        let mut buckets = self.buckets.lock().expect("rate limit lock poisoned");
+        let Some(bucket) = buckets.get_mut(method) else {
+            return true;
+        };
+        let allowed = bucket.try_acquire(now);
+        if !allowed {
+            self.metrics.rejected_calls.increment(1);
+        }
+        allowed
+    }
+
+    /// Returns `true` if a batch of `len` requests is within the size bound.
+    pub fn allow_batch(&self, len: usize) -> bool {
+        let allowed = self.max_batch_size.is_none_or(|max| len <= max);
+        if !allowed {
+            *self
+                .rejected_batches
+                .lock()
+                .expect("rate limit lock poisoned") += 1;
+            self.metrics.rejected_batches.increment(1);
+        }
+        allowed
+    }
+
+    /// Snapshot of the configured limits and rejection counts.
+    pub fn health(&self) -> RpcRateLimitHealth {
+        let buckets = self.buckets.lock().expect("rate limit lock poisoned");
+        let mut methods: Vec<MethodQuotaHealth> = buckets
+            .iter()
+            .map(|(method, bucket)| MethodQuotaHealth {
+                method: method.clone(),
+                per_second: bucket.per_second,
+                rejected: bucket.rejected,
+            })
+            .collect();
+        methods.sort_by(|a, b| a.method.cmp(&b.method));
+        let rejected_calls = methods.iter().map(|m| m.rejected).sum();
+        RpcRateLimitHealth {
+            max_batch_size: self.max_batch_size,
+            rejected_calls,
+            rejected_batches: *self
+                .rejected_batches
+                .lock()
+                .expect("rate limit lock poisoned"),
+            methods,
+        }
+    }
+}
+
+/// Per-method health entry of [`RpcRateLimitHealth`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MethodQuotaHealth {
+    pub method: String,
+    pub per_second: u32,
+    /// Calls rejected for this method since startup.
+    pub rejected: u64,
+}
+
+/// Health readout returned by `admin_rpcRateLimitHealth`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcRateLimitHealth {
+    pub max_batch_size: Option<usize>,
+    /// Total calls rejected by per-method quotas since startup.
+    pub rejected_calls: u64,
+    /// Total batches rejected for exceeding the size bound since startup.
+    pub rejected_batches: u64,
+    pub methods: Vec<MethodQuotaHealth>,
+}
+
+fn rate_limited_error() -> ErrorObject<'static> {
+    ErrorObject::owned(RATE_LIMITED_CODE, "rate limit exceeded", None::<()>)
+}
+
+fn batch_too_large_error() -> ErrorObject<'static> {
+    ErrorObject::owned(RATE_LIMITED_CODE, "batch exceeds maximum size", None::<()>)
+}
+
+/// RPC middleware layer installing the rate limiter on every connection.
+///
+/// A layer without a limiter (the default) passes all requests through.
+#[derive(Debug, Clone, Default)]
+pub struct RpcRateLimitLayer {
+    limiter: Option<Arc<RpcRateLimiter>>,
+}
+
+impl RpcRateLimitLayer {
+    /// Creates a layer sharing the given limiter across all connections.
+    pub fn new(limiter: Option<Arc<RpcRateLimiter>>) -> Self {
+        Self { limiter }
+    }
+}
+
+impl<S> tower::Layer<S> for RpcRateLimitLayer {
+    type Service = RpcRateLimitService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        RpcRateLimitService {
+            inner,
+            limiter: self.limiter.clone(),
+        }
+    }
+}
+
+/// Middleware service enforcing quotas and batch bounds before the inner service.
+#[derive(Debug, Clone)]
+pub struct RpcRateLimitService<S> {
+    inner: S,
+    limiter: Option<Arc<RpcRateLimiter>>,
+}
+
+impl<S> RpcServiceT for RpcRateLimitService<S>
+where
+    S: RpcServiceT<
+            MethodResponse = MethodResponse,
+            BatchResponse = MethodResponse,
+            NotificationResponse = MethodResponse,
+        > + Send
+        + Sync
+        + Clone
+        + 'static,
+{
+    type MethodResponse = MethodResponse;
+    type BatchResponse = MethodResponse;
+    type NotificationResponse = MethodResponse;
+
+    fn call<'a>(
+        &self,
+        request: Request<'a>,
+    ) -> impl Future<Output = Self::MethodResponse> + Send + 'a {
+        let limiter = self.limiter.clone();
+        let inner = self.inner.clone();
+        async move {
+            if let Some(limiter) = &limiter
+                && !limiter.allow_call(request.method_name())
+            {
+                return MethodResponse::error(request.id(), rate_limited_error());
+            }
+            inner.call(request).await
+        }
+    }
+
+    fn batch<'a>(&self, batch: Batch<'a>) -> impl Future<Output = Self::BatchResponse> + Send + 'a {
+        let limiter = self.limiter.clone();
+        let inner = self.inner.clone();
+        async move {
+            if let Some(limiter) = &limiter {
+                if !limiter.allow_batch(batch.len()) {
+                    return MethodResponse::error(Id::Null, batch_too_large_error());
+                }
+                // Any over-quota entry rejects the whole batch, so abusive
+                // batches cost one round trip instead of a partial execution.
+                for entry in batch.iter() {
+                    if let Ok(BatchEntry::Call(request)) = entry
+                        && !limiter.allow_call(request.method_name())
+                    {
+                        return MethodResponse::error(request.id(), rate_limited_error());
+                    }
+                }
+            }
+            inner.batch(batch).await
+        }
+    }
+
+    fn notification<'a>(
+        &self,
+        notification: Notification<'a>,
+    ) -> impl Future<Output = Self::NotificationResponse> + Send + 'a {
+        self.inner.notification(notification)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_method_quota_parsing() {
+        let quota: MethodQuota = "eth_call=50".parse().unwrap();
+        assert_eq!(quota.method, "eth_call");
+        assert_eq!(quota.per_second, 50);
+
+        assert!("eth_call".parse::<MethodQuota>().is_err());
+        assert!("eth_call=abc".parse::<MethodQuota>().is_err());
+        assert!("eth_call=0".parse::<MethodQuota>().is_err());
+        assert!("=50".parse::<MethodQuota>().is_err());
+    }
+
+    #[test]
+    fn test_quota_enforced_and_refilled() {
+        let limiter = RpcRateLimiter::new(RpcRateLimitConfig::new(
+            vec!["eth_call=2".parse().unwrap()],
+            None,
+        ));
+        let start = Instant::now();
+
+        // Burst capacity equals the per-second quota.
+        assert!(limiter.allow_call_at("eth_call", start));
+        assert!(limiter.allow_call_at("eth_call", start));
+        assert!(!limiter.allow_call_at("eth_call", start));
+
+        // Unlimited methods are unaffected.
+        assert!(limiter.allow_call_at("eth_blockNumber", start));
+
+        // Tokens refill at the configured rate.
+        let later = start + Duration::from_millis(600);
+        assert!(limiter.allow_call_at("eth_call", later));
+        assert!(!limiter.allow_call_at("eth_call", later));
+    }
+
+    #[test]
+    fn test_batch_size_bound() {
+        let limiter = RpcRateLimiter::new(RpcRateLimitConfig::new(vec![], Some(3)));
+        assert!(limiter.allow_batch(0));
+        assert!(limiter.allow_batch(3));
+        assert!(!limiter.allow_batch(4));
+
+        // No bound configured: any size passes.
+        let unbounded = RpcRateLimiter::new(RpcRateLimitConfig::default());
+        assert!(unbounded.allow_batch(10_000));
+    }
+
+    #[test]
+    fn test_health_reports_rejections() {
+        let limiter = RpcRateLimiter::new(RpcRateLimitConfig::new(
+            vec![
+                "eth_call=1".parse().unwrap(),
+                "eth_getLogs=1".parse().unwrap(),
+            ],
+            Some(2),
+        ));
+        let now = Instant::now();
+        assert!(limiter.allow_call_at("eth_call", now));
+        assert!(!limiter.allow_call_at("eth_call", now));
+        assert!(!limiter.allow_call_at("eth_call", now));
+        assert!(!limiter.allow_batch(3));
+
+        let health = limiter.health();
+        assert_eq!(health.max_batch_size, Some(2));
+        assert_eq!(health.rejected_calls, 2);
+        assert_eq!(health.rejected_batches, 1);
+        assert_eq!(
+            health.methods,
+            vec![
+                MethodQuotaHealth {
+                    method: "eth_call".to_string(),
+                    per_second: 1,
+                    rejected: 2,
+                },
+                MethodQuotaHealth {
+                    method: "eth_getLogs".to_string(),
+                    per_second: 1,
+                    rejected: 0,
+                },
+            ]
+        );
+    }
+}
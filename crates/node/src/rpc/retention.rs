@@ -0,0 +1,74 @@
+//! Receipt retention hint RPC for the bridge sidecar.
+//!
+//! The bridge sidecar pins a retention floor while unlocks backed by burn
+//! receipts are still unfinalized on the origin chain, so the receipts
+//! pruner (see [`crate::prune`]) never drops a block a burn proof may still
+//! be built from. Pins are keyed by source, so several consumers (sidecar,
+//! snapshotter, ...) can hold independent floors; the pruner honors the
+//! lowest one.
+
+use crate::prune::{RetentionHint, RetentionHints};
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use serde::{Deserialize, Serialize};
+
+/// Response for `tempo_receiptRetention`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RetentionSnapshot {
+    /// Lowest pinned floor across all sources, if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub floor: Option<u64>,
+    /// Every current pin, sorted by source.
+    pub hints: Vec<RetentionHint>,
+}
+
+/// Receipt retention hint API.
+#[rpc(server, namespace = "tempo")]
+pub trait TempoRetentionApi {
+    /// Pins (or moves) a retention floor: receipts from `min_block` on are
+    /// kept until the same `source` releases the pin.
+    #[method(name = "pinReceiptRetention")]
+    async fn pin_receipt_retention(&self, source: String, min_block: u64) -> RpcResult<()>;
+
+    /// Releases a previously pinned floor. Returns true if a pin existed for
+    /// `source`.
+    #[method(name = "releaseReceiptRetention")]
+    async fn release_receipt_retention(&self, source: String) -> RpcResult<bool>;
+
+    /// Returns all current retention pins and the effective floor.
+    #[method(name = "receiptRetention")]
+    async fn receipt_retention(&self) -> RpcResult<RetentionSnapshot>;
+}
+
+/// Implementation of the receipt retention hint API over the shared
+/// [`RetentionHints`] handle the pruner consumes.
+#[derive(Debug, Clone)]
+pub struct TempoRetentionRpc {
+    hints: RetentionHints,
+}
+
+impl TempoRetentionRpc {
+    /// Creates the handler over the node's retention hint registry.
+    pub fn new(hints: RetentionHints) -> Self {
+        Self { hints }
+    }
+}
+
+#[async_trait::async_trait]
+impl TempoRetentionApiServer for TempoRetentionRpc {
+    async fn pin_receipt_retention(&self, source: String, min_block: u64) -> RpcResult<()> {
+        self.hints.pin(source, min_block);
+        Ok(())
+    }
+
+    async fn release_receipt_retention(&self, source: String) -> RpcResult<bool> {
+        Ok(self.hints.release(&source))
+    }
+
+    async fn receipt_retention(&self) -> RpcResult<RetentionSnapshot> {
+        Ok(RetentionSnapshot {
+            floor: self.hints.floor(),
+            hints: self.hints.snapshot(),
+        })
+    }
+}
@@ -1,7 +1,9 @@
 use crate::{node::TempoNode, rpc::TempoEthApi};
-use alloy_primitives::{Address, B256, keccak256};
-use alloy_rpc_types_eth::simulate::SimulatedBlock;
-use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use alloy_primitives::{Address, B256, Bytes, U256, keccak256};
+use alloy_rpc_types_eth::simulate::{SimBlock, SimulatePayload, SimulatedBlock};
+use alloy_sol_types::SolEvent;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc, types::ErrorObject};
+use reth_chainspec::EthChainSpec;
 use reth_ethereum::evm::revm::database::StateProviderDatabase;
 use reth_node_api::FullNodeTypes;
 use reth_primitives_traits::AlloyBlockHeader as _;
@@ -17,8 +19,11 @@ use std::{
     sync::LazyLock,
 };
 use tempo_chainspec::hardfork::TempoHardforks;
-use tempo_evm::TempoStateAccess;
-use tempo_precompiles::{error::TempoPrecompileError, tip20::TIP20Token};
+use tempo_evm::{SimulationBlockEnv, TempoStateAccess};
+use tempo_precompiles::{
+    ACCOUNT_KEYCHAIN_ADDRESS, account_keychain::IAccountKeychain, error::TempoPrecompileError,
+    tip20::TIP20Token,
+};
 use tempo_primitives::TempoAddressExt;
 
 /// keccak256("Transfer(address,address,uint256)")
@@ -49,6 +54,96 @@ pub struct TempoSimulateV1Response<B> {
     pub token_metadata: BTreeMap<Address, Tip20TokenMetadata>,
 }
 
+/// A keychain or spending-limit change observed during `tempo_simulateTransaction`.
+///
+/// Decoded from AccountKeychain precompile events emitted by the simulated call,
+/// covering both explicit keychain operations and the effects of applying the
+/// transaction's own key authorization and spending limits.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(
+    tag = "kind",
+    rename_all = "camelCase",
+    rename_all_fields = "camelCase"
+)]
+pub enum KeychainChange {
+    /// A key was authorized for the account.
+    KeyAuthorized {
+        account: Address,
+        key_id: Address,
+        signature_type: u8,
+        expiry: u64,
+    },
+    /// A key was revoked.
+    KeyRevoked { account: Address, key_id: Address },
+    /// A per-token spending limit was set or updated.
+    SpendingLimitUpdated {
+        account: Address,
+        key_id: Address,
+        token: Address,
+        new_limit: U256,
+    },
+    /// An access key spent against its limit.
+    AccessKeySpend {
+        account: Address,
+        key_id: Address,
+        token: Address,
+        amount: U256,
+        remaining_limit: U256,
+    },
+}
+
+/// One storage slot a simulated precompile call would change.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimulatedSlotChange {
+    /// Contract whose storage would change.
+    pub address: Address,
+    /// Storage slot.
+    pub slot: B256,
+    /// Value in the base state.
+    pub previous: B256,
+    /// Value the call would have written.
+    pub value: B256,
+}
+
+/// Response for `tempo_simulatePrecompileCall`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TempoSimulatePrecompileCallResponse {
+    /// Whether the simulated call succeeded.
+    pub status: bool,
+    /// Gas the precompile metered, including calldata decoding costs.
+    pub gas_used: u64,
+    /// Return data of the call (revert data on failure).
+    pub return_data: Bytes,
+    /// Events the call would have emitted, in emission order.
+    pub logs: Vec<alloy_rpc_types_eth::Log>,
+    /// Net storage changes the call would have made, sorted by (address, slot).
+    pub state_diff: Vec<SimulatedSlotChange>,
+    /// Error message when dispatch failed fatally (e.g. out of gas).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Response for `tempo_simulateTransaction`.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TempoSimulateTransactionResponse {
+    /// Whether the simulated call succeeded.
+    pub status: bool,
+    /// Gas consumed by the call.
+    pub gas_used: u64,
+    /// Return data of the call (revert data on failure).
+    pub return_data: Bytes,
+    /// Logs emitted by the call.
+    pub logs: Vec<alloy_rpc_types_eth::Log>,
+    /// Keychain and spending-limit changes decoded from AccountKeychain events.
+    pub keychain_changes: Vec<KeychainChange>,
+    /// Error message when the call failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
 #[rpc(server, namespace = "tempo")]
 pub trait TempoSimulateApi {
     /// Simulates transactions like `eth_simulateV1` but enriches the response with
@@ -64,6 +159,75 @@ pub trait TempoSimulateApi {
         >,
         block: Option<alloy_eips::BlockId>,
     ) -> RpcResult<TempoSimulateV1Response<RpcBlock<tempo_alloy::TempoNetwork>>>;
+
+    /// Executes a single Tempo transaction against the given block's state (latest by
+    /// default) without submitting it, applying any key authorization and spending
+    /// limits it carries exactly as execution would.
+    ///
+    /// Returns gas used, emitted logs, and the keychain/limit changes the transaction
+    /// would cause, so wallets can pre-flight complex transactions.
+    #[method(name = "simulateTransaction")]
+    async fn simulate_transaction(
+        &self,
+        request: tempo_alloy::rpc::TempoTransactionRequest,
+        block: Option<alloy_eips::BlockId>,
+    ) -> RpcResult<TempoSimulateTransactionResponse>;
+
+    /// Executes a single precompile call against a copy-on-write overlay over the given
+    /// block's state (latest by default), without spinning up an EVM frame.
+    ///
+    /// State-mutating entrypoints (TIP-20 transfers, keychain operations, ...) run exactly
+    /// as they would during execution, but every write lands in the overlay: the response
+    /// carries the would-be events and net state diff together with the gas the precompile
+    /// metered, giving `eth_call`/`eth_estimateGas` answers with precompile-accurate gas
+    /// for direct precompile calls.
+    #[method(name = "simulatePrecompileCall")]
+    async fn simulate_precompile_call(
+        &self,
+        to: Address,
+        data: Bytes,
+        from: Option<Address>,
+        block: Option<alloy_eips::BlockId>,
+    ) -> RpcResult<TempoSimulatePrecompileCallResponse>;
+}
+
+/// Decodes AccountKeychain precompile events from simulation logs.
+fn extract_keychain_changes(logs: &[alloy_rpc_types_eth::Log]) -> Vec<KeychainChange> {
+    logs.iter()
+        .filter(|log| log.address() == ACCOUNT_KEYCHAIN_ADDRESS)
+        .filter_map(|log| {
+            if let Ok(ev) = IAccountKeychain::KeyAuthorized::decode_log(&log.inner) {
+                Some(KeychainChange::KeyAuthorized {
+                    account: ev.account,
+                    key_id: ev.publicKey,
+                    signature_type: ev.signatureType,
+                    expiry: ev.expiry,
+                })
+            } else if let Ok(ev) = IAccountKeychain::KeyRevoked::decode_log(&log.inner) {
+                Some(KeychainChange::KeyRevoked {
+                    account: ev.account,
+                    key_id: ev.publicKey,
+                })
+            } else if let Ok(ev) = IAccountKeychain::SpendingLimitUpdated::decode_log(&log.inner) {
+                Some(KeychainChange::SpendingLimitUpdated {
+                    account: ev.account,
+                    key_id: ev.publicKey,
+                    token: ev.token,
+                    new_limit: ev.newLimit,
+                })
+            } else if let Ok(ev) = IAccountKeychain::AccessKeySpend::decode_log(&log.inner) {
+                Some(KeychainChange::AccessKeySpend {
+                    account: ev.account,
+                    key_id: ev.publicKey,
+                    token: ev.token,
+                    amount: ev.amount,
+                    remaining_limit: ev.remainingLimit,
+                })
+            } else {
+                None
+            }
+        })
+        .collect()
 }
 
 /// Implementation of `tempo_simulateV1`.
@@ -166,6 +330,158 @@ impl<N: FullNodeTypes<Types = TempoNode>> TempoSimulateApiServer for TempoSimula
             token_metadata,
         })
     }
+
+    async fn simulate_transaction(
+        &self,
+        request: tempo_alloy::rpc::TempoTransactionRequest,
+        block: Option<alloy_eips::BlockId>,
+    ) -> RpcResult<TempoSimulateTransactionResponse> {
+        // Wrap the request in a single-block, single-call simulation. Validation is
+        // disabled so missing fields (nonce, fees) are filled in, while the tempo
+        // handler still applies key authorizations and spending limits.
+        let payload = SimulatePayload {
+            block_state_calls: vec![SimBlock {
+                block_overrides: None,
+                state_overrides: None,
+                calls: vec![request],
+            }],
+            trace_transfers: false,
+            validation: false,
+            return_full_transactions: false,
+        };
+
+        let blocks = self
+            .eth_api
+            .simulate_v1(payload, block)
+            .await
+            .map_err(|e| {
+                let err: ErrorObject<'static> = e.into();
+                err
+            })?;
+
+        let call = blocks
+            .into_iter()
+            .next()
+            .and_then(|b| b.calls.into_iter().next())
+            .ok_or_else(|| {
+                ErrorObject::owned(
+                    jsonrpsee::types::error::INTERNAL_ERROR_CODE,
+                    "simulation returned no call results",
+                    None::<()>,
+                )
+            })?;
+
+        let keychain_changes = extract_keychain_changes(&call.logs);
+
+        Ok(TempoSimulateTransactionResponse {
+            status: call.status,
+            gas_used: call.gas_used,
+            return_data: call.return_data,
+            logs: call.logs,
+            keychain_changes,
+            error: call.error.map(|e| e.message),
+        })
+    }
+
+    async fn simulate_precompile_call(
+        &self,
+        to: Address,
+        data: Bytes,
+        from: Option<Address>,
+        block: Option<alloy_eips::BlockId>,
+    ) -> RpcResult<TempoSimulatePrecompileCallResponse> {
+        let simulated = self
+            .eth_api
+            .spawn_blocking_io_fut(async move |this| {
+                let state = this.state_at_block_id_or_latest(block).await?;
+
+                // Derive the block env from the target block's header so timestamp-gated
+                // precompile logic (key expiries, hardfork checks) behaves as it would at
+                // that block.
+                let header = this
+                    .provider()
+                    .block_number_for_id(block.unwrap_or_default())
+                    .ok()
+                    .flatten()
+                    .and_then(|num| this.provider().header_by_number(num).ok().flatten());
+                let (timestamp, number, beneficiary) = header
+                    .map(|h| (h.timestamp(), h.number(), h.beneficiary()))
+                    .unwrap_or((u64::MAX, 0, Address::ZERO));
+
+                let spec = this.provider().chain_spec().tempo_hardfork_at(timestamp);
+                let env = SimulationBlockEnv {
+                    chain_id: this.provider().chain_spec().chain().id(),
+                    timestamp,
+                    number,
+                    beneficiary,
+                };
+
+                let mut db = StateProviderDatabase::new(state);
+                Ok(db.simulate_precompile_call(spec, env, to, &data, from.unwrap_or_default()))
+            })
+            .await
+            .map_err(|e| {
+                let err: ErrorObject<'static> = e.into();
+                err
+            })?;
+
+        let Some((dispatch, outcome)) = simulated else {
+            return Err(ErrorObject::owned(
+                jsonrpsee::types::error::INVALID_PARAMS_CODE,
+                format!("{to} is not an active Tempo precompile"),
+                None::<()>,
+            ));
+        };
+
+        Ok(match dispatch {
+            Ok(output) => {
+                let failed = output.is_revert() || output.is_halt();
+                // A reverted call rolls everything back, so its overlay contents are
+                // meaningless; only the revert data and metered gas survive.
+                let (logs, state_diff) = if failed {
+                    (Vec::new(), Vec::new())
+                } else {
+                    (
+                        outcome
+                            .events
+                            .into_iter()
+                            .map(|(address, data)| alloy_rpc_types_eth::Log {
+                                inner: alloy_primitives::Log { address, data },
+                                ..Default::default()
+                            })
+                            .collect(),
+                        outcome
+                            .state_diff
+                            .into_iter()
+                            .map(|diff| SimulatedSlotChange {
+                                address: diff.address,
+                                slot: diff.slot.into(),
+                                previous: diff.previous.into(),
+                                value: diff.value.into(),
+                            })
+                            .collect(),
+                    )
+                };
+
+                TempoSimulatePrecompileCallResponse {
+                    status: !failed,
+                    gas_used: outcome.gas_used,
+                    return_data: output.bytes,
+                    logs,
+                    state_diff,
+                    error: None,
+                }
+            }
+            Err(e) => TempoSimulatePrecompileCallResponse {
+                status: false,
+                gas_used: outcome.gas_used,
+                return_data: Bytes::new(),
+                logs: Vec::new(),
+                state_diff: Vec::new(),
+                error: Some(e.to_string()),
+            },
+        })
+    }
 }
 
 impl<N: FullNodeTypes<Types = TempoNode>> TempoSimulate<N> {
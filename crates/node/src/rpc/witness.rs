@@ -0,0 +1,155 @@
+//! `tempo_executionWitness`: per-block execution witnesses.
+//!
+//! Re-executes a finalized block against its parent state and reports every
+//! account and storage slot the execution touched. Tempo precompile state
+//! lives in plain account storage under the precompile addresses (including
+//! `DirectAddressMap` slots), so it is captured by the same mechanism as
+//! contract storage — a stateless verifier needs no precompile-specific
+//! handling. This is groundwork for stateless validation and for light
+//! clients proving Tempo state on origin chains.
+//!
+//! Witness generation re-executes the block, so the method is only merged
+//! when the `debug` RPC module is enabled.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use alloy_eips::BlockId;
+use alloy_primitives::{Address, B256};
+use eyre::OptionExt as _;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use reth_ethereum::evm::revm::database::StateProviderDatabase;
+use reth_evm::{ConfigureEvm, execute::Executor as _};
+use reth_primitives_traits::AlloyBlockHeader as _;
+use reth_provider::{BlockIdReader, BlockReader, StateProviderFactory, TransactionVariant};
+use serde::{Deserialize, Serialize};
+use tempo_evm::TempoEvmConfig;
+
+/// Response for `tempo_executionWitness`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TempoExecutionWitness {
+    /// Hash of the witnessed block.
+    pub block_hash: B256,
+    /// Number of the witnessed block.
+    pub block_number: u64,
+    /// Every account touched by the block's execution, sorted by address.
+    pub accounts: Vec<AccountAccess>,
+}
+
+/// One account touched during execution.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountAccess {
+    /// Account address.
+    pub address: Address,
+    /// Storage slots read or written under this account, as 32-byte keys,
+    /// sorted.
+    pub slots: Vec<B256>,
+}
+
+#[rpc(server, namespace = "tempo")]
+pub trait TempoWitnessApi {
+    /// Re-executes the given block and returns the accounts and storage slots
+    /// its execution accessed.
+    #[method(name = "executionWitness")]
+    async fn execution_witness(&self, block: BlockId) -> RpcResult<TempoExecutionWitness>;
+}
+
+/// Implementation of `tempo_executionWitness`.
+#[derive(Debug, Clone)]
+pub struct TempoWitnessRpc<P> {
+    provider: P,
+    evm_config: TempoEvmConfig,
+}
+
+impl<P> TempoWitnessRpc<P> {
+    /// Create a new witness RPC handler.
+    pub fn new(provider: P, evm_config: TempoEvmConfig) -> Self {
+        Self {
+            provider,
+            evm_config,
+        }
+    }
+}
+
+fn internal_err(msg: impl ToString) -> jsonrpsee::types::ErrorObject<'static> {
+    jsonrpsee::types::ErrorObject::owned(-32000, msg.to_string(), None::<()>)
+}
+
+impl<P> TempoWitnessRpc<P>
+where
+    P: BlockIdReader
+        + BlockReader<Block = tempo_primitives::Block>
+        + StateProviderFactory
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+{
+    /// Re-executes `block_id` over its parent state, recording every account
+    /// and slot the executor's state cache loaded.
+    fn witness(&self, block_id: BlockId) -> eyre::Result<TempoExecutionWitness> {
+        let number = self
+            .provider
+            .block_number_for_id(block_id)?
+            .ok_or_eyre("block not found")?;
+        let block = self
+            .provider
+            .recovered_block(number.into(), TransactionVariant::WithHash)?
+            .ok_or_eyre("block not found")?;
+        eyre::ensure!(
+            block.header().number() > 0,
+            "the genesis block has no execution witness"
+        );
+
+        let state = self
+            .provider
+            .history_by_block_hash(block.header().parent_hash())?;
+        let executor = self.evm_config.executor(StateProviderDatabase::new(state));
+
+        // The executor's cache holds exactly the accounts and slots execution
+        // loaded, whether it mutated them or not — which is the access set a
+        // stateless verifier must be furnished with.
+        let mut accesses: BTreeMap<Address, BTreeSet<B256>> = BTreeMap::new();
+        executor.execute_with_state_closure(&block, |state| {
+            for (address, account) in &state.cache.accounts {
+                let slots = accesses.entry(*address).or_default();
+                if let Some(plain) = account.account.as_ref() {
+                    slots.extend(plain.storage.keys().map(|slot| B256::from(*slot)));
+                }
+            }
+        })?;
+
+        Ok(TempoExecutionWitness {
+            block_hash: block.hash(),
+            block_number: number,
+            accounts: accesses
+                .into_iter()
+                .map(|(address, slots)| AccountAccess {
+                    address,
+                    slots: slots.into_iter().collect(),
+                })
+                .collect(),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl<P> TempoWitnessApiServer for TempoWitnessRpc<P>
+where
+    P: BlockIdReader
+        + BlockReader<Block = tempo_primitives::Block>
+        + StateProviderFactory
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+{
+    async fn execution_witness(&self, block: BlockId) -> RpcResult<TempoExecutionWitness> {
+        let this = self.clone();
+        tokio::task::spawn_blocking(move || this.witness(block))
+            .await
+            .map_err(internal_err)?
+            .map_err(internal_err)
+    }
+}
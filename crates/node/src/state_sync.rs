@@ -0,0 +1,502 @@
+//! Precompile space-aware state sync.
+//!
+//! A syncing node spends most of its initial download on precompile storage,
+//! and most of that on a handful of huge mappings — TIP-20 balances above
+//! all. Downloading that state as one sequential stream serializes the whole
+//! sync behind the largest space. Instead, the storage space registry
+//! ([`tempo_precompiles::storage::registry`]) already describes which spaces
+//! each precompile reserves, so the download is planned per space: every
+//! space is split into fixed-size chunks, chunks are fetched in parallel, and
+//! each space is validated against its own root before any of it is applied.
+//! A corrupted or malicious chunk therefore invalidates one space, not the
+//! whole download.
+
+use alloy_primitives::{Address, B256, keccak256};
+use futures::{StreamExt as _, stream};
+use tempo_precompiles::storage::registry::PRECOMPILE_SPACES;
+
+/// Default number of storage entries per download chunk.
+pub const DEFAULT_CHUNK_SIZE: u64 = 50_000;
+
+/// Default number of chunk downloads in flight at once.
+pub const DEFAULT_MAX_PARALLEL: usize = 8;
+
+/// One precompile storage space, the unit of independent validation.
+///
+/// A space is a single reserved field of one precompile — e.g. the TIP-20
+/// `balances` mapping — identified by the address it lives at and the field
+/// name from the registry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct SpaceId {
+    /// Type name of the owning precompile.
+    pub precompile: &'static str,
+    /// Address the space lives at.
+    pub address: Address,
+    /// Field name of the space within the precompile.
+    pub field: &'static str,
+}
+
+impl std::fmt::Display for SpaceId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{} at {}", self.precompile, self.field, self.address)
+    }
+}
+
+/// Every storage space the registry knows about, one [`SpaceId`] per
+/// reserved field.
+pub fn known_spaces() -> Vec<SpaceId> {
+    PRECOMPILE_SPACES
+        .iter()
+        .flat_map(|entry| {
+            entry.spaces.iter().map(|space| SpaceId {
+                precompile: entry.precompile,
+                address: entry.address,
+                field: space.field,
+            })
+        })
+        .collect()
+}
+
+/// What a snapshot peer advertises for one space: how many entries it holds
+/// and the root committing to all of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpaceManifest {
+    /// Number of storage entries in the space.
+    pub entries: u64,
+    /// Commitment over all entries in slot order, per [`space_root`].
+    pub root: B256,
+}
+
+/// One storage slot/value pair of a space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageEntry {
+    /// Storage slot key.
+    pub slot: B256,
+    /// Stored value.
+    pub value: B256,
+}
+
+/// One chunk of a space's entries: `count` entries starting at offset
+/// `start`, in slot order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ChunkSpec {
+    /// Space the chunk belongs to.
+    pub space: SpaceId,
+    /// Offset of the chunk's first entry within the space, in slot order.
+    pub start: u64,
+    /// Number of entries in the chunk.
+    pub count: u64,
+}
+
+/// Splits a space of `entries` entries into chunks of at most `chunk_size`.
+/// An empty space yields no chunks.
+pub fn chunk_space(space: SpaceId, entries: u64, chunk_size: u64) -> Vec<ChunkSpec> {
+    assert!(chunk_size > 0, "chunk size must be positive");
+    (0..entries)
+        .step_by(chunk_size as usize)
+        .map(|start| ChunkSpec {
+            space,
+            start,
+            count: chunk_size.min(entries - start),
+        })
+        .collect()
+}
+
+/// Commitment over a space's entries: a hash chain over `(slot, value)`
+/// pairs in slot order, seeded with the space identity so equal contents in
+/// different spaces never share a root. Serving peers compute the same value
+/// when building their [`SpaceManifest`].
+pub fn space_root(space: &SpaceId, entries: &[StorageEntry]) -> B256 {
+    let mut root = keccak256(space.to_string().as_bytes());
+    for entry in entries {
+        let mut preimage = [0u8; 96];
+        preimage[..32].copy_from_slice(root.as_slice());
+        preimage[32..64].copy_from_slice(entry.slot.as_slice());
+        preimage[64..].copy_from_slice(entry.value.as_slice());
+        root = keccak256(preimage);
+    }
+    root
+}
+
+/// Source of snapshot data, implemented over the peer/RPC layer by the node
+/// and by in-memory mocks in tests.
+#[async_trait::async_trait]
+pub trait SpaceSyncSource: Send + Sync {
+    /// The manifest for `space`: entry count and validation root.
+    async fn space_manifest(&self, space: &SpaceId) -> eyre::Result<SpaceManifest>;
+
+    /// The entries of `chunk`, in slot order.
+    async fn fetch_chunk(&self, chunk: &ChunkSpec) -> eyre::Result<Vec<StorageEntry>>;
+}
+
+/// State sync failure.
+#[derive(Debug, thiserror::Error)]
+pub enum StateSyncError {
+    /// A chunk returned a different number of entries than requested.
+    #[error("chunk at offset {start} of {space} returned {actual} entries, expected {expected}")]
+    ChunkShortfall {
+        space: SpaceId,
+        start: u64,
+        expected: u64,
+        actual: u64,
+    },
+
+    /// The assembled space does not hash to the advertised root.
+    #[error("validation root mismatch for {space}: expected {expected}, got {actual}")]
+    RootMismatch {
+        space: SpaceId,
+        expected: B256,
+        actual: B256,
+    },
+
+    /// The source failed to serve a manifest or chunk.
+    #[error(transparent)]
+    Source(#[from] eyre::Error),
+}
+
+/// Tuning knobs for the chunked download.
+#[derive(Debug, Clone, Copy)]
+pub struct StateSyncConfig {
+    /// Entries per chunk.
+    pub chunk_size: u64,
+    /// Chunk downloads in flight at once.
+    pub max_parallel: usize,
+}
+
+impl Default for StateSyncConfig {
+    fn default() -> Self {
+        Self {
+            chunk_size: DEFAULT_CHUNK_SIZE,
+            max_parallel: DEFAULT_MAX_PARALLEL,
+        }
+    }
+}
+
+/// A fully downloaded and validated space.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SyncedSpace {
+    /// The space that was synced.
+    pub space: SpaceId,
+    /// Number of chunks the space was downloaded in.
+    pub chunks: usize,
+    /// All entries of the space, in slot order, validated against the
+    /// manifest root.
+    pub entries: Vec<StorageEntry>,
+}
+
+/// Downloads precompile storage spaces in validated parallel chunks.
+pub struct StateSyncer<S> {
+    source: S,
+    config: StateSyncConfig,
+}
+
+impl<S: SpaceSyncSource> StateSyncer<S> {
+    /// Creates a syncer over the given source.
+    pub fn new(source: S, config: StateSyncConfig) -> Self {
+        Self { source, config }
+    }
+
+    /// Downloads and validates one space: plans its chunks from the
+    /// manifest, fetches them in parallel, reassembles them in order, and
+    /// checks the result against the manifest root.
+    pub async fn sync_space(&self, space: SpaceId) -> Result<SyncedSpace, StateSyncError> {
+        let manifest = self.source.space_manifest(&space).await?;
+        let chunks = chunk_space(space, manifest.entries, self.config.chunk_size);
+
+        // `buffered` preserves chunk order, so the reassembled entries stay
+        // in slot order no matter how downloads interleave.
+        let results: Vec<(ChunkSpec, Vec<StorageEntry>)> = stream::iter(chunks.iter().copied())
+            .map(|chunk| async move {
+                let entries = self.source.fetch_chunk(&chunk).await?;
+                Ok::<_, eyre::Error>((chunk, entries))
+            })
+            .buffered(self.config.max_parallel)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<_, _>>()?;
+
+        let mut entries = Vec::with_capacity(manifest.entries as usize);
+        for (chunk, chunk_entries) in results {
+            if chunk_entries.len() as u64 != chunk.count {
+                return Err(StateSyncError::ChunkShortfall {
+                    space,
+                    start: chunk.start,
+                    expected: chunk.count,
+                    actual: chunk_entries.len() as u64,
+                });
+            }
+            entries.extend(chunk_entries);
+        }
+
+        let actual = space_root(&space, &entries);
+        if actual != manifest.root {
+            return Err(StateSyncError::RootMismatch {
+                space,
+                expected: manifest.root,
+                actual,
+            });
+        }
+
+        tracing::debug!(
+            target: "tempo::state_sync",
+            %space,
+            entries = entries.len(),
+            chunks = chunks.len(),
+            "space synced and validated"
+        );
+        Ok(SyncedSpace {
+            space,
+            chunks: chunks.len(),
+            entries,
+        })
+    }
+
+    /// Downloads every registry-known space, largest manifests first so the
+    /// dominant spaces (TIP-20 balances) start immediately instead of
+    /// trailing the long tail of small ones.
+    pub async fn sync_all(&self) -> Result<Vec<SyncedSpace>, StateSyncError> {
+        let mut spaces = Vec::new();
+        for space in known_spaces() {
+            let manifest = self.source.space_manifest(&space).await?;
+            spaces.push((manifest.entries, space));
+        }
+        spaces.sort_by(|a, b| b.0.cmp(&a.0));
+
+        let mut synced = Vec::with_capacity(spaces.len());
+        for (_, space) in spaces {
+            synced.push(self.sync_space(space).await?);
+        }
+        Ok(synced)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{
+        collections::BTreeMap,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    /// In-memory snapshot source over generated per-space entries, tracking
+    /// the peak number of concurrent chunk fetches.
+    struct MockSource {
+        spaces: BTreeMap<SpaceId, Vec<StorageEntry>>,
+        /// Root override to simulate a lying peer.
+        bad_root: Option<SpaceId>,
+        in_flight: AtomicUsize,
+        peak_in_flight: AtomicUsize,
+    }
+
+    impl MockSource {
+        fn new(spaces: BTreeMap<SpaceId, Vec<StorageEntry>>) -> Self {
+            Self {
+                spaces,
+                bad_root: None,
+                in_flight: AtomicUsize::new(0),
+                peak_in_flight: AtomicUsize::new(0),
+            }
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl SpaceSyncSource for MockSource {
+        async fn space_manifest(&self, space: &SpaceId) -> eyre::Result<SpaceManifest> {
+            let entries = self.spaces.get(space).map(Vec::as_slice).unwrap_or(&[]);
+            let root = if self.bad_root.as_ref() == Some(space) {
+                B256::with_last_byte(0xFF)
+            } else {
+                space_root(space, entries)
+            };
+            Ok(SpaceManifest {
+                entries: entries.len() as u64,
+                root,
+            })
+        }
+
+        async fn fetch_chunk(&self, chunk: &ChunkSpec) -> eyre::Result<Vec<StorageEntry>> {
+            let concurrent = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.peak_in_flight.fetch_max(concurrent, Ordering::SeqCst);
+            // Yield so neighbouring chunk futures get polled while this one
+            // is "downloading".
+            tokio::task::yield_now().await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            let entries = self
+                .spaces
+                .get(&chunk.space)
+                .map(Vec::as_slice)
+                .unwrap_or(&[]);
+            let start = chunk.start as usize;
+            let end = (chunk.start + chunk.count).min(entries.len() as u64) as usize;
+            Ok(entries[start.min(entries.len())..end].to_vec())
+        }
+    }
+
+    fn space(field: &'static str) -> SpaceId {
+        SpaceId {
+            precompile: "TIP20Token",
+            address: Address::with_last_byte(0x20),
+            field,
+        }
+    }
+
+    fn entries(count: u64) -> Vec<StorageEntry> {
+        (0..count)
+            .map(|i| StorageEntry {
+                slot: B256::from(alloy_primitives::U256::from(i)),
+                value: B256::with_last_byte((i % 251) as u8),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn chunk_planning_covers_the_space_exactly() {
+        let space = space("balances");
+        let chunks = chunk_space(space, 25, 10);
+        assert_eq!(
+            chunks
+                .iter()
+                .map(|c| (c.start, c.count))
+                .collect::<Vec<_>>(),
+            vec![(0, 10), (10, 10), (20, 5)]
+        );
+        assert!(chunk_space(space, 0, 10).is_empty());
+        // A space smaller than one chunk is a single partial chunk.
+        assert_eq!(
+            chunk_space(space, 3, 10),
+            vec![ChunkSpec {
+                space,
+                start: 0,
+                count: 3
+            }]
+        );
+    }
+
+    #[test]
+    fn space_roots_are_bound_to_the_space_identity() {
+        let entries = entries(4);
+        // Same contents under different spaces must commit differently.
+        assert_ne!(
+            space_root(&space("balances"), &entries),
+            space_root(&space("allowances"), &entries)
+        );
+        // And the root is order-sensitive.
+        let mut reversed = entries.clone();
+        reversed.reverse();
+        assert_ne!(
+            space_root(&space("balances"), &entries),
+            space_root(&space("balances"), &reversed)
+        );
+    }
+
+    #[test]
+    fn known_spaces_cover_the_registry() {
+        let spaces = known_spaces();
+        let expected: usize = PRECOMPILE_SPACES
+            .iter()
+            .map(|entry| entry.spaces.len())
+            .sum();
+        assert_eq!(spaces.len(), expected);
+    }
+
+    #[tokio::test]
+    async fn syncs_and_validates_a_chunked_space() {
+        let balances = space("balances");
+        let all = entries(25);
+        let source = MockSource::new(BTreeMap::from([(balances, all.clone())]));
+        let syncer = StateSyncer::new(
+            source,
+            StateSyncConfig {
+                chunk_size: 10,
+                max_parallel: 4,
+            },
+        );
+
+        let synced = syncer.sync_space(balances).await.unwrap();
+        assert_eq!(synced.chunks, 3);
+        assert_eq!(synced.entries, all);
+        // The downloads actually overlapped.
+        assert!(syncer.source.peak_in_flight.load(Ordering::SeqCst) > 1);
+    }
+
+    #[tokio::test]
+    async fn parallelism_is_bounded_by_the_config() {
+        let balances = space("balances");
+        let source = MockSource::new(BTreeMap::from([(balances, entries(100))]));
+        let syncer = StateSyncer::new(
+            source,
+            StateSyncConfig {
+                chunk_size: 5,
+                max_parallel: 3,
+            },
+        );
+
+        syncer.sync_space(balances).await.unwrap();
+        assert!(syncer.source.peak_in_flight.load(Ordering::SeqCst) <= 3);
+    }
+
+    #[tokio::test]
+    async fn detects_a_root_mismatch() {
+        let balances = space("balances");
+        let mut source = MockSource::new(BTreeMap::from([(balances, entries(8))]));
+        source.bad_root = Some(balances);
+        let syncer = StateSyncer::new(
+            source,
+            StateSyncConfig {
+                chunk_size: 4,
+                max_parallel: 2,
+            },
+        );
+
+        let err = syncer.sync_space(balances).await.unwrap_err();
+        assert!(matches!(err, StateSyncError::RootMismatch { space, .. } if space == balances));
+    }
+
+    #[tokio::test]
+    async fn detects_a_short_chunk() {
+        let balances = space("balances");
+        // The manifest advertises more entries than the source can serve, so
+        // the final chunk comes back short.
+        struct ShortSource(MockSource);
+
+        #[async_trait::async_trait]
+        impl SpaceSyncSource for ShortSource {
+            async fn space_manifest(&self, space: &SpaceId) -> eyre::Result<SpaceManifest> {
+                let manifest = self.0.space_manifest(space).await?;
+                Ok(SpaceManifest {
+                    entries: manifest.entries + 3,
+                    root: manifest.root,
+                })
+            }
+
+            async fn fetch_chunk(&self, chunk: &ChunkSpec) -> eyre::Result<Vec<StorageEntry>> {
+                self.0.fetch_chunk(chunk).await
+            }
+        }
+
+        let source = ShortSource(MockSource::new(BTreeMap::from([(balances, entries(10))])));
+        let syncer = StateSyncer::new(
+            source,
+            StateSyncConfig {
+                chunk_size: 8,
+                max_parallel: 2,
+            },
+        );
+
+        let err = syncer.sync_space(balances).await.unwrap_err();
+        assert!(matches!(err, StateSyncError::ChunkShortfall { .. }));
+    }
+
+    #[tokio::test]
+    async fn sync_all_covers_every_known_space() {
+        // Registry spaces with no mock data sync as empty but still validate.
+        let source = MockSource::new(BTreeMap::new());
+        let syncer = StateSyncer::new(source, StateSyncConfig::default());
+
+        let synced = syncer.sync_all().await.unwrap();
+        assert_eq!(synced.len(), known_spaces().len());
+        assert!(synced.iter().all(|space| space.entries.is_empty()));
+    }
+}
@@ -37,5 +37,9 @@ pub fn version_metadata() -> RethCliVersionConsts {
 }
 
 fn extra_data() -> String {
-    format!("otterevm/v{}/{}", env!("CARGO_PKG_VERSION"), env::consts::OS)
+    format!(
+        "otterevm/v{}/{}",
+        env!("CARGO_PKG_VERSION"),
+        env::consts::OS
+    )
 }
@@ -359,7 +359,8 @@ pub(crate) fn create_signed_key_authorization(
         Address::random(), // Random key being authorized
     );
     authorization.limits = limits;
-    authorization.allowed_calls = create_allowed_call_scopes(allowed_calls, allowed_recipient);
+    authorization.allowed_calls = create_allowed_call_scopes(allowed_calls, allowed_recipient)
+        .map(|scopes| scopes.into_iter().map(Into::into).collect());
 
     // Sign the key authorization
     let sig_hash = authorization.signature_hash();
@@ -757,12 +758,14 @@ pub(crate) fn create_basic_aa_tx(
         nonce,
         // Use AlphaUSD to match fund_address_with
         fee_token: Some(DEFAULT_FEE_TOKEN),
+        fee_token_preferences: vec![],
         fee_payer_signature: None,
         valid_before: None,
         valid_after: None,
         access_list: Default::default(),
         key_authorization: None,
         tempo_authorization_list: vec![],
+        unknown_fields: Default::default(),
     }
 }
 
@@ -62,7 +62,7 @@ async fn test_fee_in_stable() -> eyre::Result<()> {
     assert_eq!(transfer.from, caller);
     assert_eq!(transfer.to, TIP_FEE_MANAGER_ADDRESS);
     assert_eq!(transfer.amount, U256::from(cost));
-    assert_eq!(receipt.fee_token, Some(fee_token_address));
+    assert_eq!(receipt.tempo.fee_token, Some(fee_token_address));
 
     Ok(())
 }
@@ -129,7 +129,7 @@ async fn test_default_fee_token() -> eyre::Result<()> {
     assert_eq!(transfer.from, new_address);
     assert_eq!(transfer.to, TIP_FEE_MANAGER_ADDRESS);
     assert_eq!(transfer.amount, U256::from(cost));
-    assert_eq!(receipt.fee_token, Some(PATH_USD_ADDRESS));
+    assert_eq!(receipt.tempo.fee_token, Some(PATH_USD_ADDRESS));
 
     Ok(())
 }
@@ -182,7 +182,7 @@ async fn test_fee_transfer_logs() -> eyre::Result<()> {
     assert_eq!(transfer.from, caller);
     assert_eq!(transfer.to, TIP_FEE_MANAGER_ADDRESS);
     assert_eq!(transfer.amount, U256::from(cost));
-    assert_eq!(receipt.fee_token, Some(fee_token_address));
+    assert_eq!(receipt.tempo.fee_token, Some(fee_token_address));
 
     Ok(())
 }
@@ -4,6 +4,9 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 mod metrics;
+mod reservations;
+
+pub use reservations::{GasReservation, GasReservationPolicy};
 
 use crate::metrics::{InstrumentedFinishProvider, TempoPayloadBuilderMetrics};
 use alloy_consensus::{BlockHeader as _, Signed, Transaction, TxLegacy};
@@ -58,6 +61,7 @@ use tempo_primitives::{
         calc_gas_balance_spending,
         envelope::{TEMPO_SYSTEM_TX_SENDER, TEMPO_SYSTEM_TX_SIGNATURE},
     },
+    tx_trace::{TxStage, stage_span},
 };
 use tempo_transaction_pool::{
     TempoTransactionPool,
@@ -98,6 +102,8 @@ pub struct TempoPayloadBuilder<Provider> {
     state_provider_metrics: bool,
     /// Whether to disable state cache.
     disable_state_cache: bool,
+    /// Per-precompile gas reservations for validator-critical transactions.
+    gas_reservations: GasReservationPolicy,
 }
 
 impl<Provider> TempoPayloadBuilder<Provider> {
@@ -108,6 +114,7 @@ impl<Provider> TempoPayloadBuilder<Provider> {
         is_dev: bool,
         state_provider_metrics: bool,
         disable_state_cache: bool,
+        gas_reservations: GasReservationPolicy,
     ) -> Self {
         Self {
             pool,
@@ -118,6 +125,7 @@ impl<Provider> TempoPayloadBuilder<Provider> {
             is_dev,
             state_provider_metrics,
             disable_state_cache,
+            gas_reservations,
         }
     }
 }
@@ -300,7 +308,8 @@ where
             .chain_spec()
             .is_osaka_active_at_timestamp(attributes.timestamp);
 
-        let block_gas_limit: u64 = parent_header.gas_limit();
+        let block_gas_limit: u64 =
+            next_block_gas_limit(&self.evm_config, &state_provider, parent_header.header());
         let shared_gas_limit = block_gas_limit / TEMPO_SHARED_GAS_DIVISOR;
         // Non-shared gas limit is the maximum gas available for proposer's pool transactions.
         // The remaining `shared_gas_limit` is reserved for validator subblocks.
@@ -311,6 +320,12 @@ where
             shared_gas_limit,
         );
 
+        // Gas set aside for transactions targeting reserved precompiles (bridge
+        // finalization, fee-manager settlement). Regular pool transactions are
+        // capped below this slice so reserved-target transactions always fit.
+        let reserved_gas = self.gas_reservations.total().min(non_shared_gas_limit);
+        let unreserved_gas_limit = non_shared_gas_limit - reserved_gas;
+
         let mut cumulative_gas_used = 0;
         let mut non_payment_gas_used = 0;
         // initial block size usage - size of withdrawals plus 1Kb of overhead for the block header
@@ -453,15 +468,25 @@ where
 
             // Ensure we still have capacity for this transaction within the non-shared gas limit.
             // The remaining `shared_gas_limit` is reserved for validator subblocks and must not
-            // be consumed by proposer's pool transactions.
-            if cumulative_gas_used + pool_tx.gas_limit() > non_shared_gas_limit {
+            // be consumed by proposer's pool transactions. Transactions targeting reserved
+            // precompiles may additionally consume the reserved slice.
+            let tx_gas_cap = if reserved_gas != 0
+                && self
+                    .gas_reservations
+                    .covers(pool_tx.transaction.inner().inner())
+            {
+                non_shared_gas_limit
+            } else {
+                unreserved_gas_limit
+            };
+            if cumulative_gas_used + pool_tx.gas_limit() > tx_gas_cap {
                 // Mark this transaction as invalid since it doesn't fit
                 // The iterator will handle lane switching internally when appropriate
                 best_txs.mark_invalid(
                     &pool_tx,
                     &InvalidPoolTransactionError::ExceedsGasLimit(
                         pool_tx.gas_limit(),
-                        non_shared_gas_limit - cumulative_gas_used,
+                        tx_gas_cap.saturating_sub(cumulative_gas_used),
                     ),
                 );
                 self.metrics
@@ -519,6 +544,7 @@ where
 
             let tx_with_env = pool_tx.transaction.clone().into_with_tx_env();
             let tx_execution_start = Instant::now();
+            let _tx_stage_span = stage_span(TxStage::Execution, *pool_tx.hash()).entered();
             let gas_used = match builder.execute_transaction(tx_with_env) {
                 Ok(gas_used) => gas_used,
                 Err(BlockExecutionError::Validation(BlockValidationError::InvalidTx {
@@ -612,6 +638,8 @@ where
             let mut subblock_tx_count = 0f64;
 
             for tx in subblock.transactions_recovered() {
+                let _tx_stage_span =
+                    stage_span(TxStage::SubblockInclusion, *tx.tx_hash()).entered();
                 if let Err(err) = builder.execute_transaction(tx.cloned()) {
                     if let BlockExecutionError::Validation(BlockValidationError::InvalidTx {
                         ..
@@ -849,6 +877,71 @@ pub fn is_more_subblocks(
     subblocks.len() > best_metadata.len()
 }
 
+/// Returns the gas limit for the next block, honoring the governance-set target.
+///
+/// Reads the block gas limit target from the V2 validator config contract at the
+/// parent state and steps the parent's gas limit toward it, clamped to the
+/// per-block delta consensus accepts (`validate_against_parent_gas_limit`), so a
+/// governance update rolls out over successive blocks without a coordinated
+/// binary release. Inherits the parent's gas limit when no target is set or the
+/// read fails — a broken read must not stall block production.
+fn next_block_gas_limit(
+    evm_config: &TempoEvmConfig,
+    state_provider: impl StateProvider,
+    parent_header: &TempoHeader,
+) -> u64 {
+    let parent_gas_limit = parent_header.gas_limit();
+    let db = State::builder()
+        .with_database(StateProviderDatabase::new(state_provider))
+        .build();
+    let target = match evm_config.evm_for_block(db, parent_header) {
+        Ok(mut evm) => {
+            let ctx = evm.ctx_mut();
+            if !ctx.cfg.spec.is_t4() {
+                return parent_gas_limit;
+            }
+            StorageCtx::enter_evm(
+                &mut ctx.journaled_state,
+                &ctx.block,
+                &ctx.cfg,
+                &ctx.tx,
+                || -> Result<u64, PayloadBuilderError> {
+                    let config = ValidatorConfigV2::default();
+                    if !config
+                        .is_initialized()
+                        .map_err(PayloadBuilderError::other)?
+                    {
+                        return Ok(0);
+                    }
+                    config
+                        .get_block_gas_limit()
+                        .map_err(PayloadBuilderError::other)
+                },
+            )
+        }
+        Err(err) => Err(PayloadBuilderError::other(err)),
+    };
+
+    let target = match target {
+        // Zero means governance has not set a target; keep inheriting.
+        Ok(0) => return parent_gas_limit,
+        Ok(target) => target,
+        Err(err) => {
+            warn!(%err, "failed reading governed block gas limit; inheriting parent's");
+            return parent_gas_limit;
+        }
+    };
+
+    let max_delta = (parent_gas_limit / 1024).saturating_sub(1);
+    match target.cmp(&parent_gas_limit) {
+        std::cmp::Ordering::Equal => parent_gas_limit,
+        std::cmp::Ordering::Greater => {
+            parent_gas_limit + (target - parent_gas_limit).min(max_delta)
+        }
+        std::cmp::Ordering::Less => parent_gas_limit - (parent_gas_limit - target).min(max_delta),
+    }
+}
+
 /// Overrides the block's fee recipient (beneficiary) with the value from the
 /// V2 validator config contract, if the contract is active and returns a
 /// non-zero address for the given `public_key`.
@@ -0,0 +1,155 @@
+//! Per-precompile gas reservations for validator-critical transactions.
+//!
+//! A reservation sets aside a slice of the proposer's (non-shared) block gas
+//! for transactions targeting a specific precompile — e.g. bridge finalization
+//! or fee-manager settlement — so they still fit when blocks run full. Regular
+//! pool transactions cannot consume reserved gas; transactions whose calls all
+//! target reserved precompiles may use both the general budget and the
+//! reservation.
+
+use alloy_primitives::Address;
+use std::str::FromStr;
+use tempo_primitives::TempoTxEnvelope;
+
+/// A single gas reservation for transactions targeting `target`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GasReservation {
+    /// Precompile address the reservation applies to.
+    pub target: Address,
+    /// Amount of block gas set aside.
+    pub gas: u64,
+}
+
+impl FromStr for GasReservation {
+    type Err = String;
+
+    /// Parses `ADDRESS:GAS`, e.g.
+    /// `0xfeec000000000000000000000000000000000000:500000`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (addr, gas) = s
+            .split_once(':')
+            .ok_or_else(|| format!("invalid gas reservation '{s}', expected ADDRESS:GAS"))?;
+        Ok(Self {
+            target: addr
+                .parse()
+                .map_err(|err| format!("invalid reservation address '{addr}': {err}"))?,
+            gas: gas
+                .parse()
+                .map_err(|err| format!("invalid reservation gas '{gas}': {err}"))?,
+        })
+    }
+}
+
+/// Builder policy reserving slices of block gas per target precompile.
+///
+/// An empty policy (the default) changes nothing about block building.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GasReservationPolicy {
+    reservations: Vec<GasReservation>,
+}
+
+impl GasReservationPolicy {
+    /// Creates a policy from the given reservations, merging duplicate targets.
+    pub fn new(reservations: Vec<GasReservation>) -> Self {
+        let mut merged: Vec<GasReservation> = Vec::with_capacity(reservations.len());
+        for reservation in reservations {
+            match merged.iter_mut().find(|r| r.target == reservation.target) {
+                Some(existing) => existing.gas = existing.gas.saturating_add(reservation.gas),
+                None => merged.push(reservation),
+            }
+        }
+        Self {
+            reservations: merged,
+        }
+    }
+
+    /// Returns true if no gas is reserved.
+    pub fn is_empty(&self) -> bool {
+        self.reservations.is_empty()
+    }
+
+    /// Total gas set aside across all reservations.
+    pub fn total(&self) -> u64 {
+        self.reservations
+            .iter()
+            .fold(0u64, |acc, r| acc.saturating_add(r.gas))
+    }
+
+    /// Returns true if every call of the transaction targets a reserved
+    /// precompile, i.e. the transaction is allowed to consume reserved gas.
+    pub fn covers(&self, tx: &TempoTxEnvelope) -> bool {
+        let mut any = false;
+        for (kind, _) in tx.calls() {
+            match kind.to() {
+                Some(to) if self.reservations.iter().any(|r| &r.target == to) => any = true,
+                _ => return false,
+            }
+        }
+        any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy_primitives::{Bytes, U256, address};
+    use tempo_primitives::{AASigned, TempoSignature, TempoTransaction, transaction::Call};
+
+    const FEE_MANAGER: Address = address!("fee0000000000000000000000000000000000000");
+    const BRIDGE: Address = address!("b000000000000000000000000000000000000001");
+
+    fn aa_tx(targets: &[Address]) -> TempoTxEnvelope {
+        let tx = TempoTransaction {
+            calls: targets
+                .iter()
+                .map(|&to| Call {
+                    to: to.into(),
+                    value: U256::ZERO,
+                    input: Bytes::new(),
+                })
+                .collect(),
+            ..Default::default()
+        };
+        AASigned::new_unhashed(tx, TempoSignature::default()).into()
+    }
+
+    #[test]
+    fn parse_reservation() {
+        let r: GasReservation = "0xfee0000000000000000000000000000000000000:500000"
+            .parse()
+            .unwrap();
+        assert_eq!(r.target, FEE_MANAGER);
+        assert_eq!(r.gas, 500_000);
+        assert!("nonsense".parse::<GasReservation>().is_err());
+    }
+
+    #[test]
+    fn duplicate_targets_are_merged() {
+        let policy = GasReservationPolicy::new(vec![
+            GasReservation {
+                target: FEE_MANAGER,
+                gas: 100_000,
+            },
+            GasReservation {
+                target: FEE_MANAGER,
+                gas: 50_000,
+            },
+        ]);
+        assert_eq!(policy.total(), 150_000);
+    }
+
+    #[test]
+    fn covers_requires_all_calls_reserved() {
+        let policy = GasReservationPolicy::new(vec![GasReservation {
+            target: BRIDGE,
+            gas: 200_000,
+        }]);
+        assert!(policy.covers(&aa_tx(&[BRIDGE])));
+        assert!(policy.covers(&aa_tx(&[BRIDGE, BRIDGE])));
+        // Mixed batches cannot dip into the reservation.
+        assert!(!policy.covers(&aa_tx(&[BRIDGE, FEE_MANAGER])));
+        assert!(!policy.covers(&aa_tx(&[FEE_MANAGER])));
+        // The empty policy covers nothing.
+        assert!(!GasReservationPolicy::default().covers(&aa_tx(&[BRIDGE])));
+    }
+}
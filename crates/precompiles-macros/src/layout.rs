@@ -228,6 +228,7 @@ pub(crate) fn gen_slots_module(allocated_fields: &[LayoutField<'_>]) -> proc_mac
     // Generate constants and collision check functions
     let constants = packing::gen_constants_from_ir(allocated_fields, false);
     let collision_checks = gen_collision_checks(allocated_fields);
+    let reserved_spaces = gen_reserved_spaces_const(allocated_fields);
 
     quote! {
         pub mod slots {
@@ -235,10 +236,39 @@ pub(crate) fn gen_slots_module(allocated_fields: &[LayoutField<'_>]) -> proc_mac
 
             #constants
             #collision_checks
+            #reserved_spaces
         }
     }
 }
 
+/// Generate the `RESERVED_SPACES` constant declaring the header slots the contract reserves.
+///
+/// One span per storage field. Mappings (and other hashed containers) reserve only their
+/// base slot — their entries live in hashed subtrees keyed off it — which their
+/// `StorableType::SLOTS` already reflects. The storage space registry consumes these
+/// tables to detect collisions between precompiles that share an address.
+fn gen_reserved_spaces_const(allocated_fields: &[LayoutField<'_>]) -> proc_macro2::TokenStream {
+    let entries = allocated_fields.iter().map(|field| {
+        let name_str = field.name.to_string();
+        let ty = field.ty;
+        let slot_const = PackingConstants::new(field.name).slot();
+        quote! {
+            crate::storage::ReservedSpace {
+                field: #name_str,
+                slot: #slot_const,
+                slots: <#ty as crate::storage::StorableType>::SLOTS as u64,
+            }
+        }
+    });
+
+    quote! {
+        /// Header slot spans this contract reserves at its address, one per field.
+        pub const RESERVED_SPACES: &[crate::storage::ReservedSpace] = &[
+            #(#entries,)*
+        ];
+    }
+}
+
 /// Generate collision check functions for all fields
 fn gen_collision_checks(allocated_fields: &[LayoutField<'_>]) -> proc_macro2::TokenStream {
     let mut generated = proc_macro2::TokenStream::new();
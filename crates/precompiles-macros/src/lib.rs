@@ -3,6 +3,7 @@
 //! This crate provides:
 //! - `#[contract]` macro that transforms a storage schema into a fully-functional contract
 //! - `#[derive(Storable)]` macro for storage structs and `#[repr(u8)]` unit enums
+//! - `#[when_not_paused]` attribute macro guarding contract methods behind the pause switch
 //! - `storable_alloy_ints!` macro for generating alloy integer storage implementations
 //! - `storable_alloy_bytes!` macro for generating alloy FixedBytes storage implementations
 //! - `storable_rust_ints!` macro for generating standard Rust integer storage implementations
@@ -245,7 +246,7 @@ fn gen_contract_storage(
 ///     pub amount_total: U256,           // rel slot: 3 (32 bytes)
 /// }
 /// ```
-#[proc_macro_derive(Storable, attributes(storable_arrays))]
+#[proc_macro_derive(Storable)]
 pub fn derive_storage_block(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
@@ -307,6 +308,37 @@ pub fn storable_alloy_bytes(_input: TokenStream) -> TokenStream {
     storable_primitives::gen_storable_alloy_bytes().into()
 }
 
+/// Guards a contract method behind the token's pause switch, mirroring
+/// Solidity's `whenNotPaused` modifier.
+///
+/// Expands to a `self.check_not_paused()?;` statement prepended to the method
+/// body, so the annotated method must be an inherent method of a contract
+/// that provides `check_not_paused(&self)` and must return a `Result`.
+///
+/// # Usage
+/// ```ignore
+/// #[when_not_paused]
+/// pub fn transfer(&mut self, msg_sender: Address, call: ITIP20::transferCall) -> Result<bool> {
+///     // runs only while the token is not paused
+/// }
+/// ```
+#[proc_macro_attribute]
+pub fn when_not_paused(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if !attr.is_empty() {
+        return syn::Error::new(
+            proc_macro2::Span::call_site(),
+            "`when_not_paused` takes no arguments",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let mut method = parse_macro_input!(item as syn::ImplItemFn);
+    let guard: syn::Stmt = syn::parse_quote! { self.check_not_paused()?; };
+    method.block.stmts.insert(0, guard);
+    quote!(#method).into()
+}
+
 /// Generate comprehensive property tests for all storage types.
 ///
 /// This macro generates:
@@ -318,32 +350,6 @@ pub fn gen_storable_tests(_input: TokenStream) -> TokenStream {
     storable_tests::gen_storable_tests().into()
 }
 
-/// Generate `Storable` implementations for fixed-size arrays of primitive types.
-///
-/// Generates implementations for arrays of sizes 1-32 for the following element types:
-/// - Rust integers: u8-u128, i8-i128
-/// - Alloy integers: U8-U256, I8-I256
-/// - Address
-/// - FixedBytes<20>, FixedBytes<32>
-///
-/// Each array gets:
-/// - `StorableType` impl with `LAYOUT = Layout::Slot`
-/// - `Storable`
-#[proc_macro]
-pub fn storable_arrays(_input: TokenStream) -> TokenStream {
-    storable_primitives::gen_storable_arrays().into()
-}
-
-/// Generate `Storable` implementations for nested arrays of small primitive types.
-///
-/// Generates implementations for nested arrays like `[[u8; 4]; 8]` where:
-/// - Inner arrays are small (2, 4, 8, 16 for u8; 2, 4, 8 for u16)
-/// - Total slot count ≤ 32
-#[proc_macro]
-pub fn storable_nested_arrays(_input: TokenStream) -> TokenStream {
-    storable_primitives::gen_nested_arrays().into()
-}
-
 // -- TEST HELPERS -------------------------------------------------------------
 
 /// Test helper macro for validating slots
@@ -8,8 +8,7 @@ use crate::{
     FieldInfo,
     layout::{gen_handler_field_decl, gen_handler_field_init},
     packing::{self, LayoutField, PackingConstants},
-    storable_primitives::gen_struct_arrays,
-    utils::{extract_mapping_types, extract_storable_array_sizes, to_snake_case},
+    utils::{extract_mapping_types, to_snake_case},
 };
 
 /// Implements the `Storable` derive macro for structs and `#[repr(u8)]` unit enums.
@@ -166,32 +165,10 @@ fn derive_struct_impl(input: &DeriveInput, data_struct: &DataStruct) -> syn::Res
         }
     };
 
-    // Generate array implementations if requested
-    let array_impls = if let Some(sizes) = extract_storable_array_sizes(&input.attrs)? {
-        // Generate the struct type path for array generation
-        let struct_type = quote! { #strukt #ty_generics };
-        gen_struct_arrays(struct_type, &sizes)
-    } else {
-        quote! {}
-    };
-
-    // Combine struct implementation with array implementations
-    let combined = quote! {
-        #expanded
-        #array_impls
-    };
-
-    Ok(combined)
+    Ok(expanded)
 }
 
 fn derive_unit_enum_impl(input: &DeriveInput, data_enum: &DataEnum) -> syn::Result<TokenStream> {
-    if extract_storable_array_sizes(&input.attrs)?.is_some() {
-        return Err(syn::Error::new_spanned(
-            &input.ident,
-            "`storable_arrays` is only supported for structs",
-        ));
-    }
-
     if !has_repr_u8(&input.attrs)? {
         return Err(syn::Error::new_spanned(
             &input.ident,
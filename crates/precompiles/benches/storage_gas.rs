@@ -0,0 +1,434 @@
+//! Storage gas regression harness for precompile entrypoints.
+//!
+//! Runs each entrypoint once against [`HashMapStorageProvider`] and records the
+//! number of SLOAD/SSTORE operations it performs, together with a modeled gas
+//! cost assuming worst-case (cold) slot access. Results are compared against
+//! the thresholds committed in `benches/snapshots/storage_gas.snap`, so layout
+//! or logic changes that blow up storage traffic fail loudly in local runs.
+//!
+//! Run with `cargo bench --bench storage_gas --features test-utils`. To accept
+//! new thresholds after an intentional change, re-run with
+//! `UPDATE_STORAGE_GAS_SNAPSHOTS=1` and commit the updated snapshot file.
+
+use alloy::primitives::{Address, U256};
+use std::{cell::Cell, collections::BTreeMap, path::PathBuf};
+use tempo_precompiles::{
+    account_keychain::{
+        AccountKeychain, IAccountKeychain, KeyRestrictions, SignatureType, authorizeKeyCall,
+    },
+    storage::{StorageCtx, hashmap::HashMapStorageProvider},
+    test_util::TIP20Setup,
+    tip20::{ISSUER_ROLE, ITIP20, TIP20Token},
+    tip403_registry::{AuthRole, ITIP403Registry, TIP403Registry},
+};
+
+/// Worst-case (cold) SLOAD cost per EIP-2929.
+const SLOAD_GAS: u64 = 2100;
+/// Worst-case SSTORE cost: zero-to-nonzero write (20000) plus cold surcharge.
+const SSTORE_GAS: u64 = 22100;
+
+/// Slot-access counts measured for one entrypoint.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Measurement {
+    sloads: u64,
+    sstores: u64,
+}
+
+impl Measurement {
+    fn modeled_gas(&self) -> u64 {
+        self.sloads * SLOAD_GAS + self.sstores * SSTORE_GAS
+    }
+}
+
+/// Runs `setup` and then `op` against a fresh provider, counting only the slot
+/// accesses performed by `op`.
+fn measure(setup: impl FnOnce(), op: impl FnOnce()) -> Measurement {
+    let mut storage = HashMapStorageProvider::new(1);
+    StorageCtx::enter(&mut storage, setup);
+    storage.reset_access_counters();
+    StorageCtx::enter(&mut storage, op);
+    Measurement {
+        sloads: storage.counter_sload(),
+        sstores: storage.counter_sstore(),
+    }
+}
+
+fn restrictions() -> KeyRestrictions {
+    KeyRestrictions {
+        expiry: u64::MAX,
+        enforceLimits: false,
+        limits: vec![],
+        allowAnyCalls: true,
+        allowedCalls: vec![],
+        maxUses: 0,
+    }
+}
+
+fn scenarios() -> Vec<(&'static str, Measurement)> {
+    let admin = Address::from([0u8; 20]);
+    let user = Address::from([1u8; 20]);
+    let spender = Address::from([2u8; 20]);
+    let key_id = Address::from([3u8; 20]);
+
+    // Token address created during setup, reopened by the measured closure.
+    let token_addr = Cell::new(Address::ZERO);
+    let create_token = |setup: TIP20Setup| {
+        let token = setup.apply().unwrap();
+        token_addr.set(token.address());
+    };
+    let open_token = || TIP20Token::from_address(token_addr.get()).unwrap();
+
+    let mut results = Vec::new();
+
+    results.push((
+        "tip20_name",
+        measure(
+            || create_token(TIP20Setup::create("TestToken", "TEST", admin)),
+            || {
+                open_token().name().unwrap();
+            },
+        ),
+    ));
+
+    results.push((
+        "tip20_balance_of",
+        measure(
+            || {
+                create_token(
+                    TIP20Setup::create("TestToken", "TEST", admin)
+                        .with_issuer(admin)
+                        .with_mint(user, U256::from(1000)),
+                )
+            },
+            || {
+                open_token()
+                    .balance_of(ITIP20::balanceOfCall { account: user })
+                    .unwrap();
+            },
+        ),
+    ));
+
+    results.push((
+        "tip20_mint",
+        measure(
+            || create_token(TIP20Setup::create("TestToken", "TEST", admin).with_issuer(admin)),
+            || {
+                open_token()
+                    .mint(
+                        admin,
+                        ITIP20::mintCall {
+                            to: user,
+                            amount: U256::from(1000),
+                        },
+                    )
+                    .unwrap();
+            },
+        ),
+    ));
+
+    results.push((
+        "tip20_transfer",
+        measure(
+            || {
+                create_token(
+                    TIP20Setup::create("TestToken", "TEST", admin)
+                        .with_issuer(admin)
+                        .with_mint(user, U256::from(1000)),
+                )
+            },
+            || {
+                open_token()
+                    .transfer(
+                        user,
+                        ITIP20::transferCall {
+                            to: spender,
+                            amount: U256::ONE,
+                        },
+                    )
+                    .unwrap();
+            },
+        ),
+    ));
+
+    results.push((
+        "tip20_approve",
+        measure(
+            || create_token(TIP20Setup::create("TestToken", "TEST", admin)),
+            || {
+                open_token()
+                    .approve(
+                        user,
+                        ITIP20::approveCall {
+                            spender,
+                            amount: U256::from(500),
+                        },
+                    )
+                    .unwrap();
+            },
+        ),
+    ));
+
+    results.push((
+        "tip20_transfer_from",
+        measure(
+            || {
+                create_token(
+                    TIP20Setup::create("TestToken", "TEST", admin)
+                        .with_issuer(admin)
+                        .with_mint(user, U256::from(1000))
+                        .with_approval(user, spender, U256::from(500)),
+                )
+            },
+            || {
+                open_token()
+                    .transfer_from(
+                        spender,
+                        ITIP20::transferFromCall {
+                            from: user,
+                            to: spender,
+                            amount: U256::ONE,
+                        },
+                    )
+                    .unwrap();
+            },
+        ),
+    ));
+
+    results.push((
+        "tip20_factory_create_token",
+        measure(
+            || {
+                TIP20Setup::path_usd(admin).apply().unwrap();
+            },
+            || {
+                TIP20Setup::create("Test", "TEST", admin).apply().unwrap();
+            },
+        ),
+    ));
+
+    let policy_id = Cell::new(0u64);
+    results.push((
+        "tip403_registry_create_policy",
+        measure(
+            || {},
+            || {
+                let mut registry = TIP403Registry::new();
+                registry
+                    .create_policy(
+                        admin,
+                        ITIP403Registry::createPolicyCall {
+                            admin,
+                            policyType: ITIP403Registry::PolicyType::WHITELIST,
+                        },
+                    )
+                    .unwrap();
+            },
+        ),
+    ));
+
+    results.push((
+        "tip403_registry_is_authorized",
+        measure(
+            || {
+                let mut registry = TIP403Registry::new();
+                let id = registry
+                    .create_policy(
+                        admin,
+                        ITIP403Registry::createPolicyCall {
+                            admin,
+                            policyType: ITIP403Registry::PolicyType::WHITELIST,
+                        },
+                    )
+                    .unwrap();
+                policy_id.set(id);
+            },
+            || {
+                let mut registry = TIP403Registry::new();
+                registry
+                    .is_authorized_as(policy_id.get(), user, AuthRole::Transfer)
+                    .unwrap();
+            },
+        ),
+    ));
+
+    results.push((
+        "account_keychain_authorize_key",
+        measure(
+            || {},
+            || {
+                let mut keychain = AccountKeychain::new();
+                keychain
+                    .authorize_key(
+                        admin,
+                        authorizeKeyCall {
+                            keyId: key_id,
+                            signatureType: SignatureType::P256,
+                            config: restrictions(),
+                        },
+                    )
+                    .unwrap();
+            },
+        ),
+    ));
+
+    results.push((
+        "account_keychain_revoke_key",
+        measure(
+            || {
+                let mut keychain = AccountKeychain::new();
+                keychain
+                    .authorize_key(
+                        admin,
+                        authorizeKeyCall {
+                            keyId: key_id,
+                            signatureType: SignatureType::P256,
+                            config: restrictions(),
+                        },
+                    )
+                    .unwrap();
+            },
+            || {
+                let mut keychain = AccountKeychain::new();
+                keychain
+                    .revoke_key(admin, IAccountKeychain::revokeKeyCall { keyId: key_id })
+                    .unwrap();
+            },
+        ),
+    ));
+
+    results.push((
+        "account_keychain_get_key",
+        measure(
+            || {
+                let mut keychain = AccountKeychain::new();
+                keychain
+                    .authorize_key(
+                        admin,
+                        authorizeKeyCall {
+                            keyId: key_id,
+                            signatureType: SignatureType::P256,
+                            config: restrictions(),
+                        },
+                    )
+                    .unwrap();
+            },
+            || {
+                let keychain = AccountKeychain::new();
+                keychain
+                    .get_key(IAccountKeychain::getKeyCall {
+                        account: admin,
+                        keyId: key_id,
+                    })
+                    .unwrap();
+            },
+        ),
+    ));
+
+    results
+}
+
+fn snapshot_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("benches/snapshots/storage_gas.snap")
+}
+
+fn load_snapshot() -> Option<BTreeMap<String, Measurement>> {
+    let contents = std::fs::read_to_string(snapshot_path()).ok()?;
+    let mut thresholds = BTreeMap::new();
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let mut parts = line.split_whitespace();
+        let (Some(name), Some(sloads), Some(sstores)) = (parts.next(), parts.next(), parts.next())
+        else {
+            panic!("malformed snapshot line: {line}");
+        };
+        thresholds.insert(
+            name.to_string(),
+            Measurement {
+                sloads: sloads.parse().expect("invalid sload count"),
+                sstores: sstores.parse().expect("invalid sstore count"),
+            },
+        );
+    }
+    Some(thresholds)
+}
+
+fn write_snapshot(results: &[(&str, Measurement)]) {
+    let path = snapshot_path();
+    std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+    let mut out = String::from(
+        "# Storage access thresholds per precompile entrypoint.\n\
+         # Columns: name, SLOADs, SSTOREs (modeled gas is derived).\n\
+         # Regenerate with UPDATE_STORAGE_GAS_SNAPSHOTS=1 cargo bench --bench storage_gas.\n",
+    );
+    for (name, m) in results {
+        out.push_str(&format!("{name} {} {}\n", m.sloads, m.sstores));
+    }
+    std::fs::write(&path, out).unwrap();
+    println!("wrote snapshot to {}", path.display());
+}
+
+fn main() {
+    let results = scenarios();
+
+    println!(
+        "{:<40} {:>7} {:>8} {:>12}",
+        "entrypoint", "SLOADs", "SSTOREs", "modeled gas"
+    );
+    for (name, m) in &results {
+        println!(
+            "{name:<40} {:>7} {:>8} {:>12}",
+            m.sloads,
+            m.sstores,
+            m.modeled_gas()
+        );
+    }
+
+    if std::env::var_os("UPDATE_STORAGE_GAS_SNAPSHOTS").is_some() {
+        write_snapshot(&results);
+        return;
+    }
+
+    let Some(thresholds) = load_snapshot() else {
+        // First run: record the baseline rather than failing.
+        write_snapshot(&results);
+        println!("no snapshot found; baseline recorded, commit it to enable regression checks");
+        return;
+    };
+
+    let mut regressions = Vec::new();
+    for (name, measured) in &results {
+        match thresholds.get(*name) {
+            Some(threshold)
+                if measured.sloads > threshold.sloads || measured.sstores > threshold.sstores =>
+            {
+                regressions.push(format!(
+                    "{name}: {}/{} SLOADs, {}/{} SSTOREs (measured/threshold), \
+                     modeled gas {} -> {}",
+                    measured.sloads,
+                    threshold.sloads,
+                    measured.sstores,
+                    threshold.sstores,
+                    threshold.modeled_gas(),
+                    measured.modeled_gas(),
+                ));
+            }
+            Some(_) => {}
+            None => regressions.push(format!(
+                "{name}: no threshold recorded, re-run with UPDATE_STORAGE_GAS_SNAPSHOTS=1"
+            )),
+        }
+    }
+
+    if !regressions.is_empty() {
+        eprintln!("\nstorage gas regressions detected:");
+        for r in &regressions {
+            eprintln!("  {r}");
+        }
+        std::process::exit(1);
+    }
+
+    println!("\nall entrypoints within committed thresholds");
+}
@@ -0,0 +1,118 @@
+//! Benchmarks for TIP-20 calldata decoding in the dispatch hot path.
+//!
+//! Compares the generic [`SolInterface`] decoder against the zero-copy
+//! [`CalldataWords`] reader used for the hot fixed-argument selectors, and
+//! counts heap allocations per decode to show the zero-copy path allocates
+//! nothing for a TIP-20 transfer.
+
+use alloy::{
+    primitives::{Address, U256},
+    sol_types::{SolCall, SolInterface},
+};
+use criterion::{Criterion, criterion_group, criterion_main};
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    hint::black_box,
+    sync::atomic::{AtomicU64, Ordering},
+};
+use tempo_precompiles::{
+    Precompile,
+    calldata::CalldataWords,
+    storage::{StorageCtx, hashmap::HashMapStorageProvider},
+    test_util::TIP20Setup,
+    tip20::ITIP20,
+};
+
+/// Wraps the system allocator to count allocations made between two snapshots.
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Returns the number of heap allocations performed by `f`.
+fn count_allocations(f: impl FnOnce()) -> u64 {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn decode_transfer(c: &mut Criterion) {
+    let calldata = ITIP20::transferCall {
+        to: Address::from([1u8; 20]),
+        amount: U256::from(1_000_000u64),
+    }
+    .abi_encode();
+
+    c.bench_function("tip20_decode_transfer_generic", |b| {
+        b.iter(|| {
+            let call = ITIP20::ITIP20Calls::abi_decode(black_box(&calldata)).unwrap();
+            black_box(call);
+        });
+    });
+
+    c.bench_function("tip20_decode_transfer_zero_copy", |b| {
+        b.iter(|| {
+            let words = CalldataWords::split_exact(black_box(&calldata), 2).unwrap();
+            black_box((words.address(0), words.u256(1)));
+        });
+    });
+
+    let generic_allocs = count_allocations(|| {
+        let call = ITIP20::ITIP20Calls::abi_decode(black_box(&calldata)).unwrap();
+        black_box(call);
+    });
+    let zero_copy_allocs = count_allocations(|| {
+        let words = CalldataWords::split_exact(black_box(&calldata), 2).unwrap();
+        black_box((words.address(0), words.u256(1)));
+    });
+    println!(
+        "allocations per transfer decode: generic = {generic_allocs}, zero-copy = {zero_copy_allocs}"
+    );
+    assert_eq!(
+        zero_copy_allocs, 0,
+        "zero-copy transfer decode must not allocate"
+    );
+}
+
+fn dispatch_transfer(c: &mut Criterion) {
+    c.bench_function("tip20_dispatch_transfer", |b| {
+        let admin = Address::from([0u8; 20]);
+        let sender = Address::from([1u8; 20]);
+        let recipient = Address::from([2u8; 20]);
+        let mut storage = HashMapStorageProvider::new(1);
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("TestToken", "TEST", admin)
+                .with_issuer(admin)
+                .with_mint(sender, U256::from(u128::MAX))
+                .apply()
+                .unwrap();
+
+            let calldata = ITIP20::transferCall {
+                to: recipient,
+                amount: U256::ONE,
+            }
+            .abi_encode();
+
+            b.iter(|| {
+                let result = token.call(black_box(&calldata), sender).unwrap();
+                black_box(result);
+            });
+        });
+    });
+}
+
+criterion_group!(benches, decode_transfer, dispatch_transfer);
+criterion_main!(benches);
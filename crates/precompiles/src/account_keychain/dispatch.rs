@@ -1,7 +1,9 @@
 //! ABI dispatch for the [`AccountKeychain`] precompile.
 
-use super::{AccountKeychain, KeyRestrictions, TokenLimit, authorizeKeyCall};
-use crate::{Precompile, SelectorSchedule, charge_input_cost, dispatch_call, mutate_void, view};
+use super::{AccountKeychain, KeyRestrictions, TokenLimit, authorizeKeyCall, t3AuthorizeKeyCall};
+use crate::{
+    Precompile, SelectorSchedule, charge_input_cost, dispatch_call, interface_id, mutate_void, view,
+};
 use alloy::{
     primitives::Address,
     sol_types::{SolCall, SolInterface},
@@ -14,13 +16,21 @@ use tempo_contracts::precompiles::{
 };
 
 const T3_ADDED: &[[u8; 4]] = &[
-    authorizeKeyCall::SELECTOR,
+    t3AuthorizeKeyCall::SELECTOR,
     IAccountKeychain::setAllowedCallsCall::SELECTOR,
     IAccountKeychain::removeAllowedCallsCall::SELECTOR,
     IAccountKeychain::getRemainingLimitWithPeriodCall::SELECTOR,
     IAccountKeychain::getAllowedCallsCall::SELECTOR,
 ];
 const T3_DROPPED: &[[u8; 4]] = &[IAccountKeychain::getRemainingLimitCall::SELECTOR];
+const T4_ADDED: &[[u8; 4]] = &[
+    authorizeKeyCall::SELECTOR,
+    IAccountKeychain::authorizeKeysCall::SELECTOR,
+    IAccountKeychain::getRemainingUsesCall::SELECTOR,
+];
+
+/// ERC-165 interface ids served by `supportsInterface` (T4+).
+const INTERFACE_IDS: &[[u8; 4]] = &[interface_id(IAccountKeychainCalls::SELECTORS)];
 
 impl Precompile for AccountKeychain {
     fn call(&mut self, calldata: &[u8], msg_sender: Address) -> PrecompileResult {
@@ -30,16 +40,28 @@ impl Precompile for AccountKeychain {
 
         dispatch_call(
             calldata,
-            &[SelectorSchedule::new(TempoHardfork::T3)
-                .with_added(T3_ADDED)
-                .with_dropped(T3_DROPPED)],
+            &[
+                SelectorSchedule::new(TempoHardfork::T3)
+                    .with_added(T3_ADDED)
+                    .with_dropped(T3_DROPPED),
+                SelectorSchedule::new(TempoHardfork::T4).with_added(T4_ADDED),
+            ],
+            INTERFACE_IDS,
             IAccountKeychainCalls::abi_decode,
             |call| match call {
                 IAccountKeychainCalls::authorizeKey_0(call) => {
                     if self.storage.spec().is_t3() {
+                        // Point callers at the overload active for the current
+                        // fork; at T3 this must stay the T3-era selector so
+                        // historical replays produce identical revert data.
+                        let new_selector = if self.storage.spec().is_t4() {
+                            authorizeKeyCall::SELECTOR
+                        } else {
+                            t3AuthorizeKeyCall::SELECTOR
+                        };
                         return self.storage.error_result(
                             AccountKeychainError::legacy_authorize_key_selector_changed(
-                                authorizeKeyCall::SELECTOR,
+                                new_selector,
                             ),
                         );
                     }
@@ -61,14 +83,36 @@ impl Precompile for AccountKeychain {
                                 .collect(),
                             allowAnyCalls: true,
                             allowedCalls: vec![],
+                            maxUses: 0,
                         },
                     };
 
                     mutate_void(call, msg_sender, |sender, c| self.authorize_key(sender, c))
                 }
                 IAccountKeychainCalls::authorizeKey_1(call) => {
+                    // T3-era overload: same semantics as the T4 one, minus use
+                    // limits, which its `LegacyKeyRestrictions` cannot encode.
+                    let call = authorizeKeyCall {
+                        keyId: call.keyId,
+                        signatureType: call.signatureType,
+                        config: KeyRestrictions {
+                            expiry: call.config.expiry,
+                            enforceLimits: call.config.enforceLimits,
+                            limits: call.config.limits,
+                            allowAnyCalls: call.config.allowAnyCalls,
+                            allowedCalls: call.config.allowedCalls,
+                            maxUses: 0,
+                        },
+                    };
+
                     mutate_void(call, msg_sender, |sender, c| self.authorize_key(sender, c))
                 }
+                IAccountKeychainCalls::authorizeKey_2(call) => {
+                    mutate_void(call, msg_sender, |sender, c| self.authorize_key(sender, c))
+                }
+                IAccountKeychainCalls::authorizeKeys(call) => {
+                    mutate_void(call, msg_sender, |sender, c| self.authorize_keys(sender, c))
+                }
                 IAccountKeychainCalls::revokeKey(call) => {
                     mutate_void(call, msg_sender, |sender, c| self.revoke_key(sender, c))
                 }
@@ -97,6 +141,9 @@ impl Precompile for AccountKeychain {
                 IAccountKeychainCalls::getAllowedCalls(call) => {
                     view(call, |c| self.get_allowed_calls(c))
                 }
+                IAccountKeychainCalls::getRemainingUses(call) => {
+                    view(call, |c| self.get_remaining_uses(c))
+                }
                 IAccountKeychainCalls::getTransactionKey(call) => {
                     view(call, |c| self.get_transaction_key(c, msg_sender))
                 }
@@ -123,7 +170,8 @@ mod tests {
 
     #[test]
     fn test_account_keychain_selector_coverage() -> eyre::Result<()> {
-        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T3);
+        // Use T4 hardfork so the T4-gated authorizeKeys selector is active.
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
         StorageCtx::enter(&mut storage, || {
             let mut fee_manager = AccountKeychain::new();
             let selectors: Vec<_> = IAccountKeychainCalls::SELECTORS
@@ -206,6 +254,7 @@ mod tests {
                     }],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             }
             .abi_encode();
@@ -239,7 +288,100 @@ mod tests {
             assert!(result.is_revert());
             let decoded =
                 IAccountKeychain::LegacyAuthorizeKeySelectorChanged::abi_decode(&result.bytes)?;
-            assert_eq!(decoded.newSelector, authorizeKeyCall::SELECTOR);
+            assert_eq!(decoded.newSelector, t3AuthorizeKeyCall::SELECTOR);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_t3_authorize_key_selector_active_at_t3() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T3);
+        let account = Address::random();
+        let key_id = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut keychain = AccountKeychain::new();
+            keychain.initialize()?;
+            keychain.set_transaction_key(Address::ZERO)?;
+            keychain.set_tx_origin(account)?;
+
+            let calldata = t3AuthorizeKeyCall {
+                keyId: key_id,
+                signatureType: IAccountKeychain::SignatureType::Secp256k1,
+                config: IAccountKeychain::LegacyKeyRestrictions {
+                    expiry: u64::MAX,
+                    enforceLimits: false,
+                    limits: vec![],
+                    allowAnyCalls: true,
+                    allowedCalls: vec![],
+                },
+            }
+            .abi_encode();
+
+            let result = keychain.call(&calldata, account)?;
+            assert!(!result.is_revert());
+
+            let key = keychain.keys[account][key_id].read()?;
+            assert_eq!(key.expiry, u64::MAX);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_max_uses_authorize_key_selector_rejected_pre_t4() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T3);
+        let account = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut keychain = AccountKeychain::new();
+            keychain.initialize()?;
+            keychain.set_transaction_key(Address::ZERO)?;
+            keychain.set_tx_origin(account)?;
+
+            let calldata = authorizeKeyCall {
+                keyId: Address::random(),
+                signatureType: IAccountKeychain::SignatureType::Secp256k1,
+                config: KeyRestrictions {
+                    expiry: u64::MAX,
+                    enforceLimits: false,
+                    limits: vec![],
+                    allowAnyCalls: true,
+                    allowedCalls: vec![],
+                    maxUses: 1,
+                },
+            }
+            .abi_encode();
+
+            // The `maxUses`-carrying overload is a T4 selector; at T3 it must
+            // stay unknown so historical replays are unaffected.
+            let result = keychain.call(&calldata, account)?;
+            assert!(result.is_revert());
+            assert!(UnknownFunctionSelector::abi_decode(&result.bytes).is_ok());
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_get_remaining_uses_selector_rejected_pre_t4() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T3);
+        let account = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut keychain = AccountKeychain::new();
+            keychain.initialize()?;
+
+            let calldata = IAccountKeychain::getRemainingUsesCall {
+                account,
+                keyId: Address::random(),
+            }
+            .abi_encode();
+
+            let result = keychain.call(&calldata, account)?;
+            assert!(result.is_revert());
+            assert!(UnknownFunctionSelector::abi_decode(&result.bytes).is_ok());
 
             Ok(())
         })
@@ -348,6 +490,64 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_authorize_keys_batch_dispatch() -> eyre::Result<()> {
+        let account = Address::random();
+        let first_key = Address::random();
+        let second_key = Address::random();
+
+        let provision = |key_id| IAccountKeychain::KeyProvision {
+            keyId: key_id,
+            signatureType: IAccountKeychain::SignatureType::Secp256k1,
+            config: KeyRestrictions {
+                expiry: u64::MAX,
+                enforceLimits: false,
+                limits: vec![],
+                allowAnyCalls: true,
+                allowedCalls: vec![],
+                maxUses: 0,
+            },
+        };
+        let calldata = IAccountKeychain::authorizeKeysCall {
+            keys: vec![provision(first_key), provision(second_key)],
+        }
+        .abi_encode();
+
+        // Pre-T4 the selector is inactive.
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T3);
+        StorageCtx::enter(&mut storage, || {
+            let mut keychain = AccountKeychain::new();
+            keychain.initialize()?;
+
+            let result = keychain.call(&calldata, account)?;
+            assert!(result.is_revert(), "expected revert for T4 selector pre-T4");
+
+            Ok(())
+        })?;
+
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        StorageCtx::enter(&mut storage, || {
+            let mut keychain = AccountKeychain::new();
+            keychain.initialize()?;
+
+            let result = keychain.call(&calldata, account)?;
+            assert!(!result.is_revert());
+            assert_eq!(keychain.keys[account][first_key].read()?.expiry, u64::MAX);
+            assert_eq!(keychain.keys[account][second_key].read()?.expiry, u64::MAX);
+
+            // Duplicate key ids reject the whole batch.
+            let duplicate = IAccountKeychain::authorizeKeysCall {
+                keys: vec![provision(Address::random()); 2],
+            }
+            .abi_encode();
+            let result = keychain.call(&duplicate, account)?;
+            assert!(result.is_revert());
+            let _ = IAccountKeychain::InvalidKeyBatch::abi_decode(&result.bytes)?;
+
+            Ok(())
+        })
+    }
+
     #[test]
     fn test_t3_selector_with_malformed_data_returns_unknown_selector_error() -> eyre::Result<()> {
         let selector = getRemainingLimitWithPeriodCall::SELECTOR;
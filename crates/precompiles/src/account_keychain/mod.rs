@@ -8,29 +8,34 @@
 
 pub mod dispatch;
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 use alloy::sol_types::SolCall;
 use tempo_contracts::precompiles::{AccountKeychainError, AccountKeychainEvent, ITIP20};
 pub use tempo_contracts::precompiles::{
     IAccountKeychain,
     IAccountKeychain::{
-        CallScope, KeyInfo, KeyRestrictions, SelectorRule, SignatureType, TokenLimit,
-        getAllowedCallsCall, getKeyCall, getRemainingLimitCall, getRemainingLimitWithPeriodCall,
+        CallScope, KeyInfo, KeyProvision, KeyRestrictions, SelectorRule, SignatureType, TokenLimit,
+        authorizeKeysCall, getAllowedCallsCall, getKeyCall, getRemainingLimitCall,
+        getRemainingLimitWithPeriodCall, getRemainingUsesCall, getRemainingUsesReturn,
         getTransactionKeyCall, removeAllowedCallsCall, revokeKeyCall, setAllowedCallsCall,
         updateSpendingLimitCall,
     },
-    authorizeKeyCall, getAllowedCallsReturn, getRemainingLimitReturn,
+    authorizeKeyCall, getAllowedCallsReturn, getRemainingLimitReturn, t3AuthorizeKeyCall,
 };
 
 use crate::{
     ACCOUNT_KEYCHAIN_ADDRESS,
-    error::Result,
+    error::{Result, TempoPrecompileError},
     storage::{Handler, Mapping, Set},
     tip20_factory::TIP20Factory,
 };
 use alloy::primitives::{Address, B256, FixedBytes, TxKind, U256, keccak256};
 use tempo_precompiles_macros::{Storable, contract};
+use tempo_primitives::transaction::{Call, MultisigAuthorization};
+
+/// Maximum number of keys one `authorizeKeys` batch may provision.
+pub const MAX_PROVISIONED_KEYS: usize = 16;
 
 /// Allowed TIP-20 selectors for recipient-constrained rules.
 const TIP20_TRANSFER_SELECTOR: [u8; 4] = ITIP20::transferCall::SELECTOR;
@@ -52,6 +57,8 @@ pub fn is_constrained_tip20_selector(selector: [u8; 4]) -> bool {
 /// - bytes 1-8: expiry (u64, little-endian)
 /// - byte 9: enforce_limits (bool)
 /// - byte 10: is_revoked (bool)
+/// - byte 11: use_limited (bool)
+/// - bytes 12-19: remaining_uses (u64, little-endian)
 #[derive(Debug, Clone, Default, PartialEq, Eq, Storable)]
 pub struct AuthorizedKey {
     /// Signature type: 0 = secp256k1, 1 = P256, 2 = WebAuthn
@@ -63,6 +70,14 @@ pub struct AuthorizedKey {
     /// Whether this key has been revoked. Once revoked, a key cannot be re-authorized
     /// with the same key_id. This prevents replay attacks.
     pub is_revoked: bool,
+    /// Whether this key is limited to a maximum number of transactions (T3+).
+    ///
+    /// Kept separate from `remaining_uses` so an exhausted counter (0 remaining) stays
+    /// distinguishable from an unlimited key. Pre-T3 keys decode with `false`.
+    pub use_limited: bool,
+    /// Remaining transaction uses for use-limited keys. Decremented once per transaction
+    /// that authenticates with this key; ignored when `use_limited` is false.
+    pub remaining_uses: u64,
 }
 
 /// Account Keychain contract for managing authorized keys (session keys, spending limits).
@@ -87,6 +102,10 @@ pub struct AccountKeychain {
     // The transaction origin (tx.origin) - the EOA that signed the transaction.
     // Used to ensure spending limits only apply when msg_sender == tx_origin.
     tx_origin: Address,
+    // batch_spend_credit[token] -> TIP-20 amount already deducted for this transaction
+    // by `authorize_batch`. Execution-time authorization consumes the credit instead
+    // of deducting the same spend a second time.
+    batch_spend_credit: Mapping<Address, U256>,
 }
 
 /// Key-level call scope.
@@ -235,6 +254,11 @@ impl AccountKeychain {
         };
 
         // TIP-1011 fields are hardfork-gated at T3, so reject them before mutating state.
+        // Transaction use limits are a T4 extension on top of that.
+        if !self.storage.spec().is_t4() && config.maxUses != 0 {
+            return Err(AccountKeychainError::invalid_max_uses().into());
+        }
+
         let allowed_call_configs = if is_t3 {
             if config.enforceLimits {
                 let mut seen_tokens = HashSet::with_capacity(config.limits.len());
@@ -268,6 +292,8 @@ impl AccountKeychain {
             expiry: config.expiry,
             enforce_limits: config.enforceLimits,
             is_revoked: false,
+            use_limited: config.maxUses != 0,
+            remaining_uses: config.maxUses,
         };
 
         self.keys[msg_sender][call.keyId].write(new_key)?;
@@ -296,6 +322,42 @@ impl AccountKeychain {
         ))
     }
 
+    /// Registers up to [`MAX_PROVISIONED_KEYS`] access keys in one call (T4+).
+    ///
+    /// Each entry goes through the same validation as [`Self::authorize_key`] and emits its own
+    /// `KeyAuthorized` event; any failing entry reverts the whole dispatch, so either every key
+    /// in the batch is provisioned or none are.
+    ///
+    /// # Errors
+    /// - `InvalidKeyBatch` — empty batch, more than [`MAX_PROVISIONED_KEYS`] entries, or
+    ///   duplicate `keyId`s within the batch
+    /// - any error `authorizeKey` can produce, for the first failing entry
+    pub fn authorize_keys(&mut self, msg_sender: Address, call: authorizeKeysCall) -> Result<()> {
+        if call.keys.is_empty() || call.keys.len() > MAX_PROVISIONED_KEYS {
+            return Err(AccountKeychainError::invalid_key_batch().into());
+        }
+
+        let mut seen_keys = HashSet::with_capacity(call.keys.len());
+        for key in &call.keys {
+            if !seen_keys.insert(key.keyId) {
+                return Err(AccountKeychainError::invalid_key_batch().into());
+            }
+        }
+
+        for key in call.keys {
+            self.authorize_key(
+                msg_sender,
+                authorizeKeyCall {
+                    keyId: key.keyId,
+                    signatureType: key.signatureType,
+                    config: key.config,
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+
     /// Permanently revokes an access key. Once revoked, a key ID can never be re-authorized for
     /// this account, preventing replay of old `KeyAuthorization` signatures.
     ///
@@ -416,6 +478,26 @@ impl AccountKeychain {
         })
     }
 
+    /// Returns whether a key is use-limited and, if so, how many transaction uses remain.
+    ///
+    /// Missing or revoked keys report unlimited (`limited = false`), matching how `getKey`
+    /// blanks out inexistent entries.
+    pub fn get_remaining_uses(&self, call: getRemainingUsesCall) -> Result<getRemainingUsesReturn> {
+        let key = self.keys[call.account][call.keyId].read()?;
+
+        if key.expiry == 0 || key.is_revoked {
+            return Ok(getRemainingUsesReturn {
+                limited: false,
+                remaining: 0,
+            });
+        }
+
+        Ok(getRemainingUsesReturn {
+            limited: key.use_limited,
+            remaining: key.remaining_uses,
+        })
+    }
+
     /// Returns the remaining spending limit for a key-token pair.
     ///
     /// T2+ returns zero for missing, revoked, or expired keys. Pre-T2 preserves the historical
@@ -518,6 +600,18 @@ impl AccountKeychain {
     /// the key is scoped but currently allows no targets. Missing, revoked, or expired access
     /// keys also report scoped deny-all so this getter never exposes stale persisted scope state.
     pub fn get_allowed_calls(&self, call: getAllowedCallsCall) -> Result<getAllowedCallsReturn> {
+        let current_timestamp = self.storage.timestamp().saturating_to::<u64>();
+        self.get_allowed_calls_at(call, current_timestamp)
+    }
+
+    /// Like [`Self::get_allowed_calls`], but takes an explicit `current_timestamp` so
+    /// read-only callers without a block environment (e.g. RPC handlers) can evaluate
+    /// key expiry.
+    pub fn get_allowed_calls_at(
+        &self,
+        call: getAllowedCallsCall,
+        current_timestamp: u64,
+    ) -> Result<getAllowedCallsReturn> {
         if call.keyId.is_zero() {
             return Ok(getAllowedCallsReturn {
                 isScoped: false,
@@ -525,7 +619,6 @@ impl AccountKeychain {
             });
         }
 
-        let current_timestamp = self.storage.timestamp().saturating_to::<u64>();
         let key = self.keys[call.account][call.keyId].read()?;
         if key.expiry == 0 || key.is_revoked || current_timestamp >= key.expiry {
             return Ok(getAllowedCallsReturn {
@@ -1010,6 +1103,30 @@ impl AccountKeychain {
         Ok(key)
     }
 
+    /// Consumes one transaction use for a use-limited key (T3+).
+    ///
+    /// Unlimited keys (`use_limited == false`, the only pre-T3 shape) are a no-op, so this is
+    /// safe to call for every keychain-signed transaction. The check and decrement happen in a
+    /// single read-modify-write of the key slot, so a bounded session key can never exceed its
+    /// authorized use count.
+    ///
+    /// # Errors
+    /// - `KeyUsageExhausted` — the key is use-limited and has no remaining uses
+    pub fn consume_key_use(&mut self, account: Address, key_id: Address) -> Result<()> {
+        let mut key = self.keys[account][key_id].read()?;
+
+        if !key.use_limited {
+            return Ok(());
+        }
+
+        if key.remaining_uses == 0 {
+            return Err(AccountKeychainError::key_usage_exhausted().into());
+        }
+
+        key.remaining_uses -= 1;
+        self.keys[account][key_id].write(key)
+    }
+
     /// Computes the effective remaining limit at `current_timestamp` without mutating storage.
     pub fn effective_remaining_limit(
         &self,
@@ -1022,6 +1139,20 @@ impl AccountKeychain {
             .map(|(remaining, _)| remaining)
     }
 
+    /// Like [`Self::effective_remaining_limit`], but also returns the active period end.
+    ///
+    /// Takes an explicit `current_timestamp` so read-only callers without a block
+    /// environment (e.g. RPC handlers) can evaluate period rollovers.
+    pub fn effective_limit_with_period(
+        &self,
+        account: Address,
+        key_id: Address,
+        token: Address,
+        current_timestamp: u64,
+    ) -> Result<(U256, u64)> {
+        self.effective_limit_state(account, key_id, token, current_timestamp)
+    }
+
     /// Computes the effective remaining limit and period end at `current_timestamp`
     /// without mutating storage.
     fn effective_limit_state(
@@ -1245,10 +1376,34 @@ impl AccountKeychain {
             return Ok(());
         }
 
+        // Spend already charged by `authorize_batch` is covered by its credit.
+        let amount = self.consume_batch_credit(token, amount)?;
+        if amount.is_zero() {
+            return Ok(());
+        }
+
         // Verify and update spending limits for this access key
         self.verify_and_update_spending(account, transaction_key, token, amount)
     }
 
+    /// Consumes batch pre-authorization credit for `token` and returns the part of
+    /// `amount` that still has to be deducted from the key's spending limit.
+    ///
+    /// The credit is written by [`Self::authorize_batch`] and is zero outside batch
+    /// transactions, so this is a single transient read on the common path. Whether a
+    /// declared or an undeclared spend drains the credit first does not matter: per
+    /// token, the total deducted is the pre-charged aggregate plus whatever exceeds it.
+    fn consume_batch_credit(&mut self, token: Address, amount: U256) -> Result<U256> {
+        let credit = self.batch_spend_credit[token].t_read()?;
+        if credit.is_zero() {
+            return Ok(amount);
+        }
+
+        let covered = credit.min(amount);
+        self.batch_spend_credit[token].t_write(credit - covered)?;
+        Ok(amount - covered)
+    }
+
     /// Authorize a token approval with access key spending limits.
     ///
     /// This method checks if the transaction is using an access key, and if so,
@@ -1290,9 +1445,138 @@ impl AccountKeychain {
             return Ok(());
         }
 
+        // Batches pre-charge approvals at face value, so consume the credit here too.
+        let approval_increase = self.consume_batch_credit(token, approval_increase)?;
+        if approval_increase.is_zero() {
+            return Ok(());
+        }
+
         // Verify and update spending limits for this access key
         self.verify_and_update_spending(account, transaction_key, token, approval_increase)
     }
+
+    /// Authorizes an access-key batch of calls in a single pass (T4+).
+    ///
+    /// Every call is validated against the key's call scope before any limit is touched, then
+    /// the TIP-20 amounts the batch moves are aggregated per token and each total is deducted
+    /// from the key's remaining limit exactly once. Checking aggregates instead of per-call
+    /// amounts means the whole batch sees one consistent limit snapshot, so splitting one
+    /// large spend into many small calls cannot stretch a per-period limit, and a failing
+    /// call leaves no partial deduction behind.
+    ///
+    /// Each pre-charged total is also recorded as a transient per-token credit that
+    /// [`Self::authorize_transfer`] and [`Self::authorize_approve`] consume during execution,
+    /// so declared spends are not deducted a second time while undeclared spends (e.g. a
+    /// `transferFrom` issued by a called contract) are still charged in full. Approvals count
+    /// at face value: the pre-pass cannot observe allowance deltas, so the aggregate is
+    /// conservative for approval-heavy batches.
+    ///
+    /// # Errors
+    /// - `InvalidKeyBatch` — the batch contains no calls
+    /// - `KeyAlreadyRevoked` / `KeyNotFound` / `KeyExpired` — the key is not active
+    /// - `CallNotAllowed` — a call falls outside the key's call scope
+    /// - `SpendingLimitExceeded` — a token's aggregate spend exceeds the remaining limit
+    pub fn authorize_batch(
+        &mut self,
+        account: Address,
+        key_id: Address,
+        calls: &[Call],
+    ) -> Result<()> {
+        // Main-key batches are unrestricted, like every other keychain check.
+        if key_id == Address::ZERO {
+            return Ok(());
+        }
+
+        if calls.is_empty() {
+            return Err(AccountKeychainError::invalid_key_batch().into());
+        }
+
+        let current_timestamp = self.storage.timestamp().saturating_to::<u64>();
+        let key = self.load_active_key(account, key_id, current_timestamp)?;
+
+        for call in calls {
+            self.validate_call_scope_for_transaction(
+                account,
+                key_id,
+                &call.to,
+                call.input.as_ref(),
+            )?;
+        }
+
+        if !key.enforce_limits {
+            return Ok(());
+        }
+
+        // Aggregate the declared TIP-20 spends per token across the whole batch.
+        let mut totals: BTreeMap<Address, U256> = BTreeMap::new();
+        for call in calls {
+            let Some((token, amount)) = self.batch_call_spend(call)? else {
+                continue;
+            };
+
+            let total = totals.entry(token).or_default();
+            *total = total
+                .checked_add(amount)
+                .ok_or(TempoPrecompileError::under_overflow())?;
+        }
+
+        // Single deduction pass: one read-modify-write per token for the whole batch.
+        for (token, total) in totals {
+            self.verify_and_update_spending(account, key_id, token, total)?;
+
+            // Record the pre-charged amount so [`Self::authorize_transfer`] and
+            // [`Self::authorize_approve`] consume the credit during execution instead
+            // of deducting the same spend a second time.
+            self.batch_spend_credit[token].t_write(total)?;
+        }
+
+        Ok(())
+    }
+
+    /// Decodes the TIP-20 spend declared by one batch call, if any.
+    ///
+    /// Only the recipient-constrained TIP-20 selectors move value out of the account, and all
+    /// three carry the amount as the second ABI word.
+    fn batch_call_spend(&self, call: &Call) -> Result<Option<(Address, U256)>> {
+        let TxKind::Call(target) = call.to else {
+            return Ok(None);
+        };
+
+        let input = call.input.as_ref();
+        if input.len() < 68 {
+            return Ok(None);
+        }
+
+        let selector = <[u8; 4]>::try_from(&input[..4]).expect("input len checked above");
+        if !is_constrained_tip20_selector(selector) || !TIP20Factory::new().is_tip20(target)? {
+            return Ok(None);
+        }
+
+        Ok(Some((target, U256::from_be_slice(&input[36..68]))))
+    }
+
+    /// Enforces that `account` is the multisig root account derived from `authorization`.
+    ///
+    /// Multisig root accounts are counterfactual: the weighted signer set and threshold
+    /// derive the account address, so enforcement here is a structural check — the
+    /// configuration must be valid and must hash to the claimed account. Handlers call
+    /// this before honoring a multisig signature's root authority over the keychain
+    /// (key authorization, revocation), mirroring how access keys are checked through
+    /// [`Self::load_active_key`].
+    ///
+    /// # Errors
+    /// - `InvalidMultisigAuthorization` — the configuration is malformed or derives a
+    ///   different account address
+    pub fn validate_multisig_root(
+        &self,
+        account: Address,
+        authorization: &MultisigAuthorization,
+    ) -> Result<()> {
+        if authorization.validate().is_err() || authorization.account_address() != account {
+            return Err(AccountKeychainError::invalid_multisig_authorization().into());
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -1412,6 +1696,7 @@ mod tests {
                     limits: vec![],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             keychain.authorize_key(msg_sender, setup_call)?;
@@ -1429,6 +1714,7 @@ mod tests {
                     limits: vec![],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             let auth_result = keychain.authorize_key(msg_sender, auth_call);
@@ -1498,6 +1784,7 @@ mod tests {
                         limits: vec![],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             )?;
@@ -1516,6 +1803,7 @@ mod tests {
                         limits: vec![],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             );
@@ -1581,6 +1869,7 @@ mod tests {
                         }],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             )?;
@@ -1646,6 +1935,7 @@ mod tests {
                         }],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             )?;
@@ -1701,6 +1991,7 @@ mod tests {
                         }],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             )?;
@@ -1755,6 +2046,7 @@ mod tests {
                         }],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             )?;
@@ -1775,6 +2067,7 @@ mod tests {
                         limits: vec![],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             );
@@ -1839,6 +2132,7 @@ mod tests {
                     }],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             keychain.authorize_key(account, auth_call.clone())?;
@@ -1924,6 +2218,7 @@ mod tests {
                     limits: vec![],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             let result = keychain.authorize_key(account, auth_call);
@@ -1953,6 +2248,7 @@ mod tests {
                     limits: vec![],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             let result_past = keychain.authorize_key(account, auth_call_past);
@@ -1997,6 +2293,7 @@ mod tests {
                         }],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             );
@@ -2051,6 +2348,7 @@ mod tests {
                     limits: vec![],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             keychain.authorize_key(account, auth_call_1)?;
@@ -2068,6 +2366,7 @@ mod tests {
                     limits: vec![],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             keychain.authorize_key(account, auth_call_2)?;
@@ -2114,6 +2413,7 @@ mod tests {
                     }],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             keychain.authorize_key(eoa, auth_call)?;
@@ -2231,6 +2531,7 @@ mod tests {
                     }],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             keychain.authorize_key(eoa_alice, auth_call)?;
@@ -2335,6 +2636,7 @@ mod tests {
                     limits: vec![],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             keychain.authorize_key(account, auth_call.clone())?;
@@ -2424,6 +2726,7 @@ mod tests {
                     limits: vec![],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             // This would fail if initialize didn't set up storage properly
@@ -2460,6 +2763,7 @@ mod tests {
                     limits: vec![],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             keychain.authorize_key(account, auth_call)?;
@@ -2515,6 +2819,7 @@ mod tests {
                     }],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             keychain.authorize_key(account, auth_call)?;
@@ -2564,6 +2869,7 @@ mod tests {
                     limits: vec![],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             keychain.authorize_key(account, auth_call)?;
@@ -2630,6 +2936,7 @@ mod tests {
                     limits: vec![],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             keychain.authorize_key(account, auth_call)?;
@@ -2650,6 +2957,7 @@ mod tests {
                     limits: vec![],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             keychain.authorize_key(account, auth_valid)?;
@@ -2728,6 +3036,7 @@ mod tests {
                         limits: vec![],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             )?;
@@ -2743,6 +3052,7 @@ mod tests {
                         limits: vec![],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             )?;
@@ -2758,6 +3068,7 @@ mod tests {
                         limits: vec![],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             )?;
@@ -2824,6 +3135,7 @@ mod tests {
                     limits: vec![],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             keychain.authorize_key(account, auth_call)?;
@@ -2897,6 +3209,7 @@ mod tests {
                     }],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             keychain.authorize_key(eoa, auth_call)?;
@@ -2972,6 +3285,7 @@ mod tests {
                     }],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             keychain.authorize_key(eoa, auth_call)?;
@@ -3038,6 +3352,7 @@ mod tests {
                     }],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             keychain.authorize_key(eoa, auth_call)?;
@@ -3099,6 +3414,7 @@ mod tests {
                     }],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             keychain.authorize_key(eoa, auth_call)?;
@@ -3154,6 +3470,7 @@ mod tests {
                     }],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             keychain.authorize_key(eoa, auth_call)?;
@@ -3215,6 +3532,7 @@ mod tests {
                     }],
                     allowAnyCalls: true,
                     allowedCalls: vec![],
+                    maxUses: 0,
                 },
             };
             keychain.authorize_key(eoa, auth_call)?;
@@ -3269,6 +3587,8 @@ mod tests {
                 expiry: u64::MAX,
                 enforce_limits: true,
                 is_revoked: false,
+                use_limited: false,
+                remaining_uses: 0,
             })?;
             keychain.spending_limits[limit_key][token].write(SpendingLimitState {
                 remaining: U256::from(90),
@@ -3324,6 +3644,7 @@ mod tests {
                         }],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             )?;
@@ -3377,6 +3698,7 @@ mod tests {
                         }],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             );
@@ -3406,6 +3728,7 @@ mod tests {
                         }],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             )?;
@@ -3468,6 +3791,7 @@ mod tests {
                         ],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             );
@@ -3551,6 +3875,7 @@ mod tests {
                         limits: vec![],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             )?;
@@ -3612,6 +3937,7 @@ mod tests {
                         }],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             )?;
@@ -3681,6 +4007,7 @@ mod tests {
                         limits: vec![],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             )?;
@@ -3735,6 +4062,7 @@ mod tests {
                                 target,
                                 selectorRules: vec![],
                             }],
+                            maxUses: 0,
                         },
                     },
                 )?;
@@ -3804,6 +4132,7 @@ mod tests {
                             }],
                             allowAnyCalls: true,
                             allowedCalls: vec![],
+                            maxUses: 0,
                         },
                     },
                 )?;
@@ -3882,6 +4211,7 @@ mod tests {
                             }],
                             allowAnyCalls: true,
                             allowedCalls: vec![],
+                            maxUses: 0,
                         },
                     },
                 )?;
@@ -3980,6 +4310,7 @@ mod tests {
                         limits: vec![],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             )?;
@@ -4025,6 +4356,7 @@ mod tests {
                         limits: vec![],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             )?;
@@ -4075,6 +4407,7 @@ mod tests {
                         limits: vec![],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             )?;
@@ -4168,6 +4501,7 @@ mod tests {
                         limits: vec![],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             )?;
@@ -4231,6 +4565,7 @@ mod tests {
                         limits: vec![],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             )?;
@@ -4312,6 +4647,7 @@ mod tests {
                         limits: vec![],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             )?;
@@ -4324,4 +4660,515 @@ mod tests {
             Ok(())
         })
     }
+
+    #[test]
+    fn test_t4_max_uses_consumed_atomically_until_exhausted() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        let account = Address::random();
+        let key_id = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut keychain = AccountKeychain::new();
+            keychain.initialize()?;
+            keychain.set_transaction_key(Address::ZERO)?;
+            keychain.set_tx_origin(account)?;
+
+            keychain.authorize_key(
+                account,
+                authorizeKeyCall {
+                    keyId: key_id,
+                    signatureType: SignatureType::Secp256k1,
+                    config: KeyRestrictions {
+                        expiry: u64::MAX,
+                        enforceLimits: false,
+                        limits: vec![],
+                        allowAnyCalls: true,
+                        allowedCalls: vec![],
+                        maxUses: 2,
+                    },
+                },
+            )?;
+
+            let uses = keychain.get_remaining_uses(getRemainingUsesCall {
+                account,
+                keyId: key_id,
+            })?;
+            assert!(uses.limited);
+            assert_eq!(uses.remaining, 2);
+
+            keychain.consume_key_use(account, key_id)?;
+            keychain.consume_key_use(account, key_id)?;
+
+            let uses = keychain.get_remaining_uses(getRemainingUsesCall {
+                account,
+                keyId: key_id,
+            })?;
+            assert!(uses.limited);
+            assert_eq!(uses.remaining, 0);
+
+            let exhausted = keychain.consume_key_use(account, key_id).unwrap_err();
+            match exhausted {
+                TempoPrecompileError::AccountKeychainError(e) => assert!(
+                    matches!(e, AccountKeychainError::KeyUsageExhausted(_)),
+                    "Expected KeyUsageExhausted error, got: {e:?}"
+                ),
+                _ => panic!("Expected AccountKeychainError, got: {exhausted:?}"),
+            }
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_consume_key_use_is_noop_for_unlimited_keys() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T3);
+        let account = Address::random();
+        let key_id = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut keychain = AccountKeychain::new();
+            keychain.initialize()?;
+            keychain.set_transaction_key(Address::ZERO)?;
+            keychain.set_tx_origin(account)?;
+
+            keychain.authorize_key(
+                account,
+                authorizeKeyCall {
+                    keyId: key_id,
+                    signatureType: SignatureType::Secp256k1,
+                    config: KeyRestrictions {
+                        expiry: u64::MAX,
+                        enforceLimits: false,
+                        limits: vec![],
+                        allowAnyCalls: true,
+                        allowedCalls: vec![],
+                        maxUses: 0,
+                    },
+                },
+            )?;
+
+            // Unlimited keys never exhaust, and missing keys are also a no-op so the
+            // handler can call this unconditionally.
+            for _ in 0..10 {
+                keychain.consume_key_use(account, key_id)?;
+            }
+            keychain.consume_key_use(account, Address::random())?;
+
+            let uses = keychain.get_remaining_uses(getRemainingUsesCall {
+                account,
+                keyId: key_id,
+            })?;
+            assert!(!uses.limited);
+            assert_eq!(uses.remaining, 0);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_max_uses_rejected_pre_t4() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T3);
+        let account = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut keychain = AccountKeychain::new();
+            keychain.initialize()?;
+            keychain.set_transaction_key(Address::ZERO)?;
+            keychain.set_tx_origin(account)?;
+
+            let result = keychain.authorize_key(
+                account,
+                authorizeKeyCall {
+                    keyId: Address::random(),
+                    signatureType: SignatureType::Secp256k1,
+                    config: KeyRestrictions {
+                        expiry: u64::MAX,
+                        enforceLimits: false,
+                        limits: vec![],
+                        allowAnyCalls: true,
+                        allowedCalls: vec![],
+                        maxUses: 1,
+                    },
+                },
+            );
+
+            match result.unwrap_err() {
+                TempoPrecompileError::AccountKeychainError(e) => assert!(
+                    matches!(e, AccountKeychainError::InvalidMaxUses(_)),
+                    "Expected InvalidMaxUses error, got: {e:?}"
+                ),
+                other => panic!("Expected AccountKeychainError, got: {other:?}"),
+            }
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_get_remaining_uses_blanks_revoked_keys() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        let account = Address::random();
+        let key_id = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut keychain = AccountKeychain::new();
+            keychain.initialize()?;
+            keychain.set_transaction_key(Address::ZERO)?;
+            keychain.set_tx_origin(account)?;
+
+            keychain.authorize_key(
+                account,
+                authorizeKeyCall {
+                    keyId: key_id,
+                    signatureType: SignatureType::Secp256k1,
+                    config: KeyRestrictions {
+                        expiry: u64::MAX,
+                        enforceLimits: false,
+                        limits: vec![],
+                        allowAnyCalls: true,
+                        allowedCalls: vec![],
+                        maxUses: 3,
+                    },
+                },
+            )?;
+            keychain.revoke_key(account, revokeKeyCall { keyId: key_id })?;
+
+            let uses = keychain.get_remaining_uses(getRemainingUsesCall {
+                account,
+                keyId: key_id,
+            })?;
+            assert!(!uses.limited);
+            assert_eq!(uses.remaining, 0);
+
+            Ok(())
+        })
+    }
+
+    fn transfer_call(token: Address, to: Address, amount: u64) -> Call {
+        Call {
+            to: TxKind::Call(token),
+            value: U256::ZERO,
+            input: ITIP20::transferCall {
+                to,
+                amount: U256::from(amount),
+            }
+            .abi_encode()
+            .into(),
+        }
+    }
+
+    #[test]
+    fn test_authorize_batch_aggregates_spending_across_calls() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        let account = Address::random();
+        let key_id = Address::random();
+        let recipient = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut keychain = AccountKeychain::new();
+            keychain.initialize()?;
+            keychain.set_transaction_key(Address::ZERO)?;
+            keychain.set_tx_origin(account)?;
+            let token = TIP20Setup::path_usd(account).apply()?.address();
+
+            keychain.authorize_key(
+                account,
+                authorizeKeyCall {
+                    keyId: key_id,
+                    signatureType: SignatureType::Secp256k1,
+                    config: KeyRestrictions {
+                        expiry: u64::MAX,
+                        enforceLimits: true,
+                        limits: vec![TokenLimit {
+                            token,
+                            amount: U256::from(100),
+                            period: 0,
+                        }],
+                        allowAnyCalls: true,
+                        allowedCalls: vec![],
+                        maxUses: 0,
+                    },
+                },
+            )?;
+
+            // An empty batch is malformed, mirroring `authorizeKeys`.
+            let empty = keychain.authorize_batch(account, key_id, &[]);
+            assert!(matches!(
+                empty,
+                Err(TempoPrecompileError::AccountKeychainError(
+                    AccountKeychainError::InvalidKeyBatch(_)
+                ))
+            ));
+
+            // Three calls summing to 110 exceed the 100 limit even though each
+            // individual call would pass; nothing is deducted.
+            let over = keychain.authorize_batch(
+                account,
+                key_id,
+                &[
+                    transfer_call(token, recipient, 40),
+                    transfer_call(token, recipient, 40),
+                    transfer_call(token, recipient, 30),
+                ],
+            );
+            assert!(matches!(
+                over,
+                Err(TempoPrecompileError::AccountKeychainError(
+                    AccountKeychainError::SpendingLimitExceeded(_)
+                ))
+            ));
+            let remaining = keychain.get_remaining_limit(getRemainingLimitCall {
+                account,
+                keyId: key_id,
+                token,
+            })?;
+            assert_eq!(remaining, U256::from(100), "failed batch must not deduct");
+
+            // A batch summing to exactly the limit passes with one deduction.
+            keychain.authorize_batch(
+                account,
+                key_id,
+                &[
+                    transfer_call(token, recipient, 40),
+                    transfer_call(token, recipient, 40),
+                    transfer_call(token, recipient, 20),
+                ],
+            )?;
+            let depleted = keychain.get_remaining_limit(getRemainingLimitCall {
+                account,
+                keyId: key_id,
+                token,
+            })?;
+            assert_eq!(depleted, U256::ZERO);
+
+            // Replaying the batch fails against the depleted limit, while the
+            // main key stays unrestricted.
+            let replay =
+                keychain.authorize_batch(account, key_id, &[transfer_call(token, recipient, 1)]);
+            assert!(matches!(
+                replay,
+                Err(TempoPrecompileError::AccountKeychainError(
+                    AccountKeychainError::SpendingLimitExceeded(_)
+                ))
+            ));
+            keychain.authorize_batch(
+                account,
+                Address::ZERO,
+                &[transfer_call(token, recipient, 1_000)],
+            )?;
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_authorize_batch_enforces_call_scope_for_every_call() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        let account = Address::random();
+        let key_id = Address::random();
+        let recipient = Address::random();
+        let other_target = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut keychain = AccountKeychain::new();
+            keychain.initialize()?;
+            keychain.set_transaction_key(Address::ZERO)?;
+            keychain.set_tx_origin(account)?;
+            let token = TIP20Setup::path_usd(account).apply()?.address();
+
+            keychain.authorize_key(
+                account,
+                authorizeKeyCall {
+                    keyId: key_id,
+                    signatureType: SignatureType::Secp256k1,
+                    config: KeyRestrictions {
+                        expiry: u64::MAX,
+                        enforceLimits: true,
+                        limits: vec![TokenLimit {
+                            token,
+                            amount: U256::from(100),
+                            period: 0,
+                        }],
+                        allowAnyCalls: false,
+                        allowedCalls: vec![CallScope {
+                            target: token,
+                            selectorRules: vec![],
+                        }],
+                        maxUses: 0,
+                    },
+                },
+            )?;
+
+            // One out-of-scope call rejects the whole batch before any limit
+            // is touched.
+            let out_of_scope = keychain.authorize_batch(
+                account,
+                key_id,
+                &[
+                    transfer_call(token, recipient, 30),
+                    Call {
+                        to: TxKind::Call(other_target),
+                        value: U256::ZERO,
+                        input: Default::default(),
+                    },
+                ],
+            );
+            assert_call_not_allowed(out_of_scope.unwrap_err());
+            let remaining = keychain.get_remaining_limit(getRemainingLimitCall {
+                account,
+                keyId: key_id,
+                token,
+            })?;
+            assert_eq!(remaining, U256::from(100));
+
+            // In-scope calls without a decodable TIP-20 spend pass the scope
+            // check and deduct nothing.
+            keychain.authorize_batch(
+                account,
+                key_id,
+                &[
+                    transfer_call(token, recipient, 30),
+                    Call {
+                        to: TxKind::Call(token),
+                        value: U256::ZERO,
+                        input: Default::default(),
+                    },
+                ],
+            )?;
+            let after = keychain.get_remaining_limit(getRemainingLimitCall {
+                account,
+                keyId: key_id,
+                token,
+            })?;
+            assert_eq!(after, U256::from(70));
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_authorize_batch_credit_covers_execution_time_deduction() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        let account = Address::random();
+        let key_id = Address::random();
+        let recipient = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut keychain = AccountKeychain::new();
+            keychain.initialize()?;
+            keychain.set_transaction_key(key_id)?;
+            keychain.set_tx_origin(account)?;
+            let token = TIP20Setup::path_usd(account).apply()?.address();
+
+            keychain.authorize_key(
+                account,
+                authorizeKeyCall {
+                    keyId: key_id,
+                    signatureType: SignatureType::Secp256k1,
+                    config: KeyRestrictions {
+                        expiry: u64::MAX,
+                        enforceLimits: true,
+                        limits: vec![TokenLimit {
+                            token,
+                            amount: U256::from(100),
+                            period: 0,
+                        }],
+                        allowAnyCalls: true,
+                        allowedCalls: vec![],
+                        maxUses: 0,
+                    },
+                },
+            )?;
+
+            keychain.authorize_batch(
+                account,
+                key_id,
+                &[
+                    transfer_call(token, recipient, 40),
+                    transfer_call(token, recipient, 20),
+                ],
+            )?;
+            let after_batch = keychain.get_remaining_limit(getRemainingLimitCall {
+                account,
+                keyId: key_id,
+                token,
+            })?;
+            assert_eq!(after_batch, U256::from(40));
+
+            // The declared transfers run during execution: they consume the
+            // recorded credit instead of deducting a second time.
+            keychain.authorize_transfer(account, token, U256::from(40))?;
+            keychain.authorize_transfer(account, token, U256::from(20))?;
+            let after_execution = keychain.get_remaining_limit(getRemainingLimitCall {
+                account,
+                keyId: key_id,
+                token,
+            })?;
+            assert_eq!(
+                after_execution,
+                U256::from(40),
+                "declared spend must not be deducted twice"
+            );
+
+            // An undeclared spend beyond the credit is still charged in full.
+            keychain.authorize_transfer(account, token, U256::from(15))?;
+            let after_undeclared = keychain.get_remaining_limit(getRemainingLimitCall {
+                account,
+                keyId: key_id,
+                token,
+            })?;
+            assert_eq!(after_undeclared, U256::from(25));
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_validate_multisig_root() -> eyre::Result<()> {
+        use tempo_primitives::transaction::multisig::WeightedSigner;
+
+        let mut storage = HashMapStorageProvider::new(1);
+
+        StorageCtx::enter(&mut storage, || {
+            let keychain = AccountKeychain::new();
+
+            let mut signers = vec![
+                WeightedSigner {
+                    address: Address::random(),
+                    weight: 1,
+                },
+                WeightedSigner {
+                    address: Address::random(),
+                    weight: 1,
+                },
+            ];
+            signers.sort_by_key(|s| s.address);
+            let authorization = MultisigAuthorization {
+                threshold: 2,
+                signers,
+            };
+            let account = authorization.account_address();
+
+            // The derived account passes; any other account is rejected.
+            keychain.validate_multisig_root(account, &authorization)?;
+            assert!(matches!(
+                keychain.validate_multisig_root(Address::random(), &authorization),
+                Err(TempoPrecompileError::AccountKeychainError(
+                    AccountKeychainError::InvalidMultisigAuthorization(_)
+                ))
+            ));
+
+            // A malformed configuration is rejected even for its own derived address.
+            let mut unreachable = authorization.clone();
+            unreachable.threshold = 10;
+            assert!(matches!(
+                keychain.validate_multisig_root(unreachable.account_address(), &unreachable),
+                Err(TempoPrecompileError::AccountKeychainError(
+                    AccountKeychainError::InvalidMultisigAuthorization(_)
+                ))
+            ));
+
+            Ok(())
+        })
+    }
 }
@@ -0,0 +1,166 @@
+//! Chainspec-driven activation schedule for Tempo precompiles.
+//!
+//! Each precompile declares the hardfork window in which it is routable; the
+//! lookup installed by [`extend_tempo_precompiles`](crate::extend_tempo_precompiles)
+//! consults this table with the configured spec instead of hardcoding per-fork
+//! checks in the routing code. Trialing a new precompile on a testnet (or
+//! retiring an old one) is then a schedule edit, not a routing change.
+//!
+//! Note that activation here means "the address resolves to a precompile at
+//! all" — selector-level gating within an active precompile stays with
+//! [`SelectorSchedule`](crate::SelectorSchedule), and contract-level behavior
+//! gates (e.g. ValidatorConfigV2's pre-T2 empty success) stay in the
+//! precompile's dispatcher.
+
+use alloy::primitives::Address;
+use tempo_chainspec::hardfork::TempoHardfork;
+use tempo_contracts::precompiles::{
+    ACCOUNT_KEYCHAIN_ADDRESS, ADDRESS_REGISTRY_ADDRESS, NONCE_PRECOMPILE_ADDRESS,
+    P256_VERIFY_ADDRESS, SIGNATURE_VERIFIER_ADDRESS, STABLECOIN_DEX_ADDRESS,
+    TIP_FEE_MANAGER_ADDRESS, TIP20_FACTORY_ADDRESS, TIP403_REGISTRY_ADDRESS,
+    VALIDATOR_CONFIG_ADDRESS, VALIDATOR_CONFIG_V2_ADDRESS,
+};
+use tempo_primitives::TempoAddressExt;
+
+/// The hardfork window in which a precompile is routable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PrecompileActivation {
+    /// Hardfork at which the address starts resolving to the precompile.
+    pub activated_at: TempoHardfork,
+    /// Hardfork at which the address stops resolving, if retirement is scheduled.
+    pub deactivated_at: Option<TempoHardfork>,
+}
+
+impl PrecompileActivation {
+    /// An activation starting at `fork` with no scheduled retirement.
+    pub const fn at(fork: TempoHardfork) -> Self {
+        Self {
+            activated_at: fork,
+            deactivated_at: None,
+        }
+    }
+
+    /// Schedules retirement: the precompile stops resolving once `fork` activates.
+    pub const fn until(mut self, fork: TempoHardfork) -> Self {
+        self.deactivated_at = Some(fork);
+        self
+    }
+
+    /// Returns `true` if the precompile is routable under `spec`.
+    pub fn is_active(&self, spec: TempoHardfork) -> bool {
+        self.activated_at <= spec && self.deactivated_at.is_none_or(|fork| spec < fork)
+    }
+}
+
+/// TIP-20 tokens (matched by address prefix in [`activation_of`]).
+const TIP20_ACTIVATION: PrecompileActivation = PrecompileActivation::at(TempoHardfork::Genesis);
+
+/// Activation windows of all fixed-address precompiles.
+const SCHEDULE: &[(Address, PrecompileActivation)] = &[
+    (
+        TIP20_FACTORY_ADDRESS,
+        PrecompileActivation::at(TempoHardfork::Genesis),
+    ),
+    (
+        ADDRESS_REGISTRY_ADDRESS,
+        PrecompileActivation::at(TempoHardfork::T3),
+    ),
+    (
+        TIP403_REGISTRY_ADDRESS,
+        PrecompileActivation::at(TempoHardfork::Genesis),
+    ),
+    (
+        TIP_FEE_MANAGER_ADDRESS,
+        PrecompileActivation::at(TempoHardfork::Genesis),
+    ),
+    (
+        STABLECOIN_DEX_ADDRESS,
+        PrecompileActivation::at(TempoHardfork::Genesis),
+    ),
+    (
+        NONCE_PRECOMPILE_ADDRESS,
+        PrecompileActivation::at(TempoHardfork::Genesis),
+    ),
+    (
+        VALIDATOR_CONFIG_ADDRESS,
+        PrecompileActivation::at(TempoHardfork::Genesis),
+    ),
+    (
+        ACCOUNT_KEYCHAIN_ADDRESS,
+        PrecompileActivation::at(TempoHardfork::Genesis),
+    ),
+    // Routable from genesis; dispatch returns empty success until T2 unlocks it.
+    (
+        VALIDATOR_CONFIG_V2_ADDRESS,
+        PrecompileActivation::at(TempoHardfork::Genesis),
+    ),
+    (
+        SIGNATURE_VERIFIER_ADDRESS,
+        PrecompileActivation::at(TempoHardfork::T3),
+    ),
+    (
+        P256_VERIFY_ADDRESS,
+        PrecompileActivation::at(TempoHardfork::T3),
+    ),
+];
+
+/// Returns the activation window of the precompile at `address`, or `None` if the
+/// address is not a Tempo precompile.
+pub fn activation_of(address: Address) -> Option<PrecompileActivation> {
+    if address.is_tip20() {
+        return Some(TIP20_ACTIVATION);
+    }
+    SCHEDULE
+        .iter()
+        .find(|(addr, _)| *addr == address)
+        .map(|(_, activation)| *activation)
+}
+
+/// Returns `true` if `address` resolves to a Tempo precompile under `spec`.
+pub fn is_active(address: Address, spec: TempoHardfork) -> bool {
+    activation_of(address).is_some_and(|activation| activation.is_active(spec))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempo_contracts::precompiles::PATH_USD_ADDRESS;
+
+    #[test]
+    fn t3_precompiles_activate_at_t3() {
+        for addr in [
+            ADDRESS_REGISTRY_ADDRESS,
+            SIGNATURE_VERIFIER_ADDRESS,
+            P256_VERIFY_ADDRESS,
+        ] {
+            assert!(!is_active(addr, TempoHardfork::T2));
+            assert!(is_active(addr, TempoHardfork::T3));
+            assert!(is_active(addr, TempoHardfork::T4));
+        }
+    }
+
+    #[test]
+    fn genesis_precompiles_are_always_active() {
+        assert!(is_active(TIP20_FACTORY_ADDRESS, TempoHardfork::Genesis));
+        assert!(is_active(PATH_USD_ADDRESS, TempoHardfork::Genesis));
+        assert!(is_active(
+            VALIDATOR_CONFIG_V2_ADDRESS,
+            TempoHardfork::Genesis
+        ));
+    }
+
+    #[test]
+    fn unknown_addresses_are_never_active() {
+        assert_eq!(activation_of(Address::random()), None);
+        assert!(!is_active(Address::random(), TempoHardfork::T4));
+    }
+
+    #[test]
+    fn retirement_window_excludes_the_deactivation_fork() {
+        let activation = PrecompileActivation::at(TempoHardfork::T1).until(TempoHardfork::T3);
+        assert!(!activation.is_active(TempoHardfork::T0));
+        assert!(activation.is_active(TempoHardfork::T1));
+        assert!(activation.is_active(TempoHardfork::T2));
+        assert!(!activation.is_active(TempoHardfork::T3));
+    }
+}
@@ -1,11 +1,15 @@
 use crate::{
-    Precompile, address_registry::AddressRegistry, charge_input_cost, dispatch_call, mutate, view,
+    Precompile, address_registry::AddressRegistry, charge_input_cost, dispatch_call, interface_id,
+    mutate, view,
 };
 use alloy::{primitives::Address, sol_types::SolInterface};
 use revm::precompile::PrecompileResult;
 use tempo_contracts::precompiles::IAddressRegistry::IAddressRegistryCalls;
 use tempo_primitives::{MasterId, TempoAddressExt, UserTag};
 
+/// ERC-165 interface ids served by `supportsInterface` (T4+).
+const INTERFACE_IDS: &[[u8; 4]] = &[interface_id(IAddressRegistryCalls::SELECTORS)];
+
 impl Precompile for AddressRegistry {
     fn call(&mut self, calldata: &[u8], msg_sender: Address) -> PrecompileResult {
         if let Some(err) = charge_input_cost(&mut self.storage, calldata) {
@@ -15,6 +19,7 @@ impl Precompile for AddressRegistry {
         dispatch_call(
             calldata,
             &[],
+            INTERFACE_IDS,
             IAddressRegistryCalls::abi_decode,
             |call| match call {
                 // Registration
@@ -0,0 +1,102 @@
+//! Zero-copy readers for statically-sized calldata arguments.
+//!
+//! The generic [`SolInterface`](alloy::sol_types::SolInterface) decoder walks calldata through
+//! alloy's token machinery on every call. For hot, fixed-argument selectors (e.g. TIP-20
+//! `transfer`) dispatchers can instead read the argument words straight out of the input buffer
+//! with [`CalldataWords`], skipping the generic decoder entirely and allocating nothing.
+//!
+//! Readers here are intentionally lenient in the same way as the non-validating alloy decoder:
+//! address padding bytes are ignored rather than rejected. Callers must fall back to the generic
+//! decoder whenever [`CalldataWords::split_exact`] returns `None` so that malformed input keeps
+//! producing the exact same errors as before.
+
+use alloy::primitives::{Address, U256};
+
+/// The calldata argument section split into 32-byte ABI words, borrowed from the input buffer.
+#[derive(Clone, Copy, Debug)]
+pub struct CalldataWords<'a> {
+    words: &'a [u8],
+}
+
+impl<'a> CalldataWords<'a> {
+    /// Splits `calldata` after the 4-byte selector into exactly `expected` 32-byte words.
+    ///
+    /// Returns `None` when the length does not match, so callers can fall back to the generic
+    /// ABI decoder and reproduce its error behavior for malformed input.
+    #[inline]
+    pub fn split_exact(calldata: &'a [u8], expected: usize) -> Option<Self> {
+        let words = calldata.get(4..)?;
+        (words.len() == expected * 32).then_some(Self { words })
+    }
+
+    /// Reads the argument word at `index` as an [`Address`] (last 20 bytes of the word).
+    ///
+    /// Like the non-validating alloy decoder, the 12 padding bytes are ignored.
+    #[inline]
+    pub fn address(&self, index: usize) -> Address {
+        Address::from_slice(&self.word(index)[12..])
+    }
+
+    /// Reads the argument word at `index` as a big-endian [`U256`].
+    #[inline]
+    pub fn u256(&self, index: usize) -> U256 {
+        U256::from_be_bytes(*self.word(index))
+    }
+
+    /// Returns the raw 32-byte word at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is outside the word count passed to [`Self::split_exact`].
+    #[inline]
+    fn word(&self, index: usize) -> &'a [u8; 32] {
+        self.words[index * 32..(index + 1) * 32]
+            .try_into()
+            .expect("word slice is exactly 32 bytes")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::sol_types::SolCall;
+    use tempo_contracts::precompiles::ITIP20;
+
+    #[test]
+    fn test_split_exact_rejects_length_mismatch() {
+        let calldata = ITIP20::transferCall {
+            to: Address::random(),
+            amount: U256::from(1),
+        }
+        .abi_encode();
+
+        assert!(CalldataWords::split_exact(&calldata, 2).is_some());
+        // Wrong word count, truncated args, and selector-only input all bail out.
+        assert!(CalldataWords::split_exact(&calldata, 3).is_none());
+        assert!(CalldataWords::split_exact(&calldata[..calldata.len() - 1], 2).is_none());
+        assert!(CalldataWords::split_exact(&calldata[..4], 2).is_none());
+        assert!(CalldataWords::split_exact(&[], 0).is_none());
+    }
+
+    #[test]
+    fn test_reads_match_abi_encoding() {
+        let to = Address::random();
+        let amount = U256::MAX - U256::from(42);
+        let calldata = ITIP20::transferCall { to, amount }.abi_encode();
+
+        let words = CalldataWords::split_exact(&calldata, 2).unwrap();
+        assert_eq!(words.address(0), to);
+        assert_eq!(words.u256(1), amount);
+    }
+
+    #[test]
+    fn test_address_ignores_dirty_padding() {
+        let to = Address::random();
+        let mut calldata = ITIP20::balanceOfCall { account: to }.abi_encode();
+        // The non-validating alloy decoder ignores padding bytes; so do we.
+        calldata[4..16].fill(0xFF);
+
+        let words = CalldataWords::split_exact(&calldata, 1).unwrap();
+        assert_eq!(words.address(0), to);
+    }
+}
@@ -0,0 +1,343 @@
+//! Differential testing harness against reference Solidity implementations.
+//!
+//! Validates precompile behavior against the spec by running the same
+//! operation sequence on both sides:
+//!
+//! - the Rust precompile, over a [`HashMapStorageProvider`], and
+//! - a compiled reference Solidity contract, executed on a plain revm
+//!   instance.
+//!
+//! Each [`DifferentialHarness::step`] asserts identical return data and
+//! revert status; [`DifferentialHarness::assert_converged`] then compares
+//! the full storage and event stream of every tracked contract address.
+//! Reference artifacts are compiled outside the workspace (e.g. `solc
+//! --bin-runtime`) and passed in as runtime bytecode, deployed at the same
+//! address as the precompile under test so storage slots and event
+//! addresses line up.
+
+use crate::{
+    Precompile,
+    storage::{StorageCtx, hashmap::HashMapStorageProvider},
+};
+use alloy::primitives::{Address, Bytes, LogData, TxKind, U256, hex};
+use revm::{
+    Context, ExecuteCommitEvm, MainBuilder, MainContext, MainnetContext, MainnetEvm,
+    context::{ContextTr, TxEnv, result::ExecutionResult},
+    database::{CacheDB, EmptyDB},
+    state::{AccountInfo, Bytecode},
+};
+use std::collections::HashMap;
+use tempo_chainspec::hardfork::TempoHardfork;
+
+/// Gas limit for every reference call; generous, since the Rust side is not
+/// metered by the same schedule.
+const REFERENCE_CALL_GAS_LIMIT: u64 = 30_000_000;
+
+/// Balance given to callers on first use, so reference transactions always
+/// pass balance checks.
+const REFERENCE_CALLER_BALANCE: u128 = 1_000_000_000_000_000_000;
+
+type ReferenceEvm = MainnetEvm<MainnetContext<CacheDB<EmptyDB>>>;
+
+/// Compiled runtime bytecode of a reference implementation, deployed at the
+/// same address as the precompile it mirrors.
+#[derive(Debug, Clone)]
+pub struct ReferenceContract {
+    /// Address the runtime code is installed at.
+    pub address: Address,
+    /// Deployed (runtime) bytecode.
+    pub runtime_code: Bytes,
+}
+
+impl ReferenceContract {
+    /// Parses a `solc --bin-runtime` artifact (hex, with or without a `0x`
+    /// prefix).
+    ///
+    /// # Panics
+    ///
+    /// Panics on malformed hex; artifacts are checked-in test fixtures.
+    pub fn from_runtime_hex(address: Address, artifact: &str) -> Self {
+        let runtime_code = hex::decode(artifact.trim())
+            .expect("invalid reference artifact hex")
+            .into();
+        Self {
+            address,
+            runtime_code,
+        }
+    }
+}
+
+/// Outcome of one operation, identical on both sides by construction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StepOutcome {
+    /// Whether the call reverted.
+    pub reverted: bool,
+    /// Return (or revert) data.
+    pub output: Bytes,
+}
+
+/// Drives the same operation sequence against a Rust precompile and its
+/// Solidity reference, asserting equivalence.
+pub struct DifferentialHarness {
+    contracts: Vec<ReferenceContract>,
+    storage: HashMapStorageProvider,
+    evm: ReferenceEvm,
+    nonces: HashMap<Address, u64>,
+    reference_logs: HashMap<Address, Vec<LogData>>,
+    steps: usize,
+}
+
+impl DifferentialHarness {
+    /// Creates a harness with the given reference contracts installed on the
+    /// revm side and an empty [`HashMapStorageProvider`] on the precompile
+    /// side.
+    pub fn new(chain_id: u64, spec: TempoHardfork, contracts: Vec<ReferenceContract>) -> Self {
+        let mut db = CacheDB::new(EmptyDB::new());
+        for contract in &contracts {
+            let code = Bytecode::new_raw(contract.runtime_code.clone());
+            db.insert_account_info(
+                contract.address,
+                AccountInfo {
+                    code_hash: code.hash_slow(),
+                    code: Some(code),
+                    ..Default::default()
+                },
+            );
+        }
+        let evm = Context::mainnet().with_db(db).build_mainnet();
+
+        Self {
+            contracts,
+            storage: HashMapStorageProvider::new_with_spec(chain_id, spec),
+            evm,
+            nonces: HashMap::new(),
+            reference_logs: HashMap::new(),
+            steps: 0,
+        }
+    }
+
+    /// Runs precompile-side setup (e.g. [`TIP20Setup`](crate::test_util::TIP20Setup))
+    /// inside the harness storage context. The reference side must be brought
+    /// to the equivalent state via [`Self::db_mut`] or reference calls.
+    pub fn setup_rust<R>(&mut self, f: impl FnOnce() -> R) -> R {
+        StorageCtx::enter(&mut self.storage, f)
+    }
+
+    /// Mutable access to the precompile-side storage provider.
+    pub fn storage_mut(&mut self) -> &mut HashMapStorageProvider {
+        &mut self.storage
+    }
+
+    /// Mutable access to the reference-side database, for pre-state setup.
+    pub fn db_mut(&mut self) -> &mut CacheDB<EmptyDB> {
+        self.evm.ctx.db_mut()
+    }
+
+    /// Runs `calldata` from `sender` against both sides and asserts they
+    /// return the same data with the same revert status.
+    pub fn step(
+        &mut self,
+        precompile: &mut impl Precompile,
+        calldata: &[u8],
+        sender: Address,
+    ) -> StepOutcome {
+        let step = self.steps;
+        let rust = self.call_rust(precompile, calldata, sender);
+        let reference = self.call_reference(calldata, sender);
+        assert_eq!(
+            rust, reference,
+            "step {step}: precompile and reference outcomes diverged \
+             (left: precompile, right: reference)"
+        );
+        self.steps += 1;
+        rust
+    }
+
+    /// Asserts that storage and event streams match for every tracked
+    /// contract address, consuming the harness.
+    pub fn assert_converged(mut self) {
+        // Event streams, per tracked address, in emission order.
+        for contract in &self.contracts {
+            let rust_events = self
+                .storage
+                .events
+                .get(&contract.address)
+                .cloned()
+                .unwrap_or_default();
+            let reference_events = self
+                .reference_logs
+                .get(&contract.address)
+                .cloned()
+                .unwrap_or_default();
+            assert_eq!(
+                rust_events, reference_events,
+                "event streams diverged for {} (left: precompile, right: reference)",
+                contract.address
+            );
+        }
+
+        // Storage, compared over the union of slots either side touched;
+        // zero-valued slots are equivalent to absent ones.
+        let addresses: Vec<Address> = self.contracts.iter().map(|c| c.address).collect();
+        let mut rust_slots: HashMap<Address, HashMap<U256, U256>> = HashMap::new();
+        for (address, slot, value) in self.storage.into_storage() {
+            if addresses.contains(&address) && !value.is_zero() {
+                rust_slots.entry(address).or_default().insert(slot, value);
+            }
+        }
+        for address in addresses {
+            let reference_slots: HashMap<U256, U256> = self
+                .evm
+                .ctx
+                .db_mut()
+                .cache
+                .accounts
+                .get(&address)
+                .map(|account| {
+                    account
+                        .storage
+                        .iter()
+                        .filter(|(_, value)| !value.is_zero())
+                        .map(|(slot, value)| (*slot, *value))
+                        .collect()
+                })
+                .unwrap_or_default();
+            assert_eq!(
+                rust_slots.remove(&address).unwrap_or_default(),
+                reference_slots,
+                "storage diverged for {address} (left: precompile, right: reference)"
+            );
+        }
+    }
+
+    fn call_rust(
+        &mut self,
+        precompile: &mut impl Precompile,
+        calldata: &[u8],
+        sender: Address,
+    ) -> StepOutcome {
+        let step = self.steps;
+        let result = StorageCtx::enter(&mut self.storage, || precompile.call(calldata, sender));
+        match result {
+            Ok(output) => StepOutcome {
+                reverted: output.is_revert(),
+                output: output.bytes,
+            },
+            Err(err) => panic!("step {step}: precompile returned a fatal error: {err}"),
+        }
+    }
+
+    fn call_reference(&mut self, calldata: &[u8], sender: Address) -> StepOutcome {
+        let step = self.steps;
+        let target = self.contracts[0].address;
+        if !self.nonces.contains_key(&sender) {
+            self.evm.ctx.db_mut().insert_account_info(
+                sender,
+                AccountInfo {
+                    balance: U256::from(REFERENCE_CALLER_BALANCE),
+                    ..Default::default()
+                },
+            );
+        }
+        let nonce = self.nonces.entry(sender).or_insert(0);
+        let tx = TxEnv {
+            caller: sender,
+            gas_limit: REFERENCE_CALL_GAS_LIMIT,
+            kind: TxKind::Call(target),
+            data: calldata.to_vec().into(),
+            nonce: *nonce,
+            chain_id: None,
+            ..Default::default()
+        };
+        *nonce += 1;
+
+        let result = self
+            .evm
+            .transact_commit(tx)
+            .unwrap_or_else(|err| panic!("step {step}: reference execution failed: {err}"));
+        match result {
+            ExecutionResult::Success { output, logs, .. } => {
+                for log in logs {
+                    self.reference_logs
+                        .entry(log.address)
+                        .or_default()
+                        .push(log.data);
+                }
+                StepOutcome {
+                    reverted: false,
+                    output: output.into_data(),
+                }
+            }
+            ExecutionResult::Revert { output, .. } => StepOutcome {
+                reverted: true,
+                output,
+            },
+            ExecutionResult::Halt { reason, .. } => {
+                panic!("step {step}: reference halted: {reason:?}")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloy::primitives::{B256, address, bytes};
+    use revm::precompile::PrecompileResult;
+
+    const ECHO_ADDRESS: Address = address!("00000000000000000000000000000000000e0101");
+
+    /// Hand-assembled runtime mirroring [`StoreEcho`]: stores the first
+    /// calldata word at slot 0, logs it (LOG0), and returns it.
+    ///
+    /// `PUSH0 CALLDATALOAD DUP1 PUSH0 SSTORE PUSH0 MSTORE
+    ///  PUSH1 0x20 PUSH0 LOG0 PUSH1 0x20 PUSH0 RETURN`
+    const ECHO_RUNTIME: Bytes = bytes!("5f35805f555f5260205fa060205ff3");
+
+    /// Minimal Rust twin of [`ECHO_RUNTIME`], for exercising the harness
+    /// itself without a compiled artifact.
+    struct StoreEcho;
+
+    impl Precompile for StoreEcho {
+        fn call(&mut self, calldata: &[u8], _msg_sender: Address) -> PrecompileResult {
+            let mut word = [0u8; 32];
+            let len = calldata.len().min(32);
+            word[..len].copy_from_slice(&calldata[..len]);
+
+            if let Err(err) = StorageCtx.sstore(ECHO_ADDRESS, U256::ZERO, U256::from_be_bytes(word))
+            {
+                return StorageCtx.error_result(err);
+            }
+            if let Err(err) = StorageCtx.emit_event(
+                ECHO_ADDRESS,
+                LogData::new_unchecked(vec![], word.to_vec().into()),
+            ) {
+                return StorageCtx.error_result(err);
+            }
+            Ok(StorageCtx.success_output(word.to_vec().into()))
+        }
+    }
+
+    #[test]
+    fn echo_contract_matches_its_precompile_twin() {
+        let reference = ReferenceContract {
+            address: ECHO_ADDRESS,
+            runtime_code: ECHO_RUNTIME,
+        };
+        let mut harness = DifferentialHarness::new(1, TempoHardfork::default(), vec![reference]);
+
+        let alice = Address::repeat_byte(0xa1);
+        let bob = Address::repeat_byte(0xb0);
+        let mut precompile = StoreEcho;
+
+        let word = B256::repeat_byte(0x42);
+        let outcome = harness.step(&mut precompile, word.as_slice(), alice);
+        assert_eq!(outcome.output.as_ref(), word.as_slice());
+        assert!(!outcome.reverted);
+
+        let word = B256::repeat_byte(0x07);
+        harness.step(&mut precompile, word.as_slice(), bob);
+
+        harness.assert_converged();
+    }
+}
@@ -11,7 +11,7 @@ use std::{
 
 use crate::tip20::TIP20Error;
 use alloy::{
-    primitives::{Selector, U256},
+    primitives::{Bytes, Selector, U256},
     sol_types::{Panic, PanicKind, SolError, SolInterface},
 };
 use alloy_evm::EvmInternalsError;
@@ -20,9 +20,10 @@ use revm::{
     precompile::{PrecompileError, PrecompileHalt, PrecompileOutput, PrecompileResult},
 };
 use tempo_contracts::precompiles::{
-    AccountKeychainError, AddrRegistryError, FeeManagerError, NonceError, RolesAuthError,
-    SignatureVerifierError, StablecoinDEXError, TIP20FactoryError, TIP403RegistryError,
-    TIPFeeAMMError, UnknownFunctionSelector, ValidatorConfigError, ValidatorConfigV2Error,
+    AccountKeychainError, AddrRegistryError, FeeManagerError, NonceError, ReentrantCall,
+    RolesAuthError, SignatureVerifierError, StablecoinDEXError, TIP20FactoryError,
+    TIP403RegistryError, TIPFeeAMMError, UnknownFunctionSelector, ValidatorConfigError,
+    ValidatorConfigV2Error,
 };
 
 /// Top-level error type for all Tempo precompile operations
@@ -94,6 +95,10 @@ pub enum TempoPrecompileError {
     #[error("Unknown function selector: {0:?}")]
     UnknownFunctionSelector([u8; 4]),
 
+    /// A reentrancy-guarded precompile entrypoint was re-entered.
+    #[error("Reentrant call")]
+    ReentrantCall,
+
     /// Unrecoverable internal error (e.g. database failure).
     #[error("Fatal precompile error: {0:?}")]
     #[from(skip)]
@@ -148,7 +153,8 @@ impl TempoPrecompileError {
             | Self::ValidatorConfigV2Error(_)
             | Self::AccountKeychainError(_)
             | Self::SignatureVerifierError(_)
-            | Self::UnknownFunctionSelector(_) => false,
+            | Self::UnknownFunctionSelector(_)
+            | Self::ReentrantCall => false,
         }
     }
 
@@ -167,12 +173,13 @@ impl TempoPrecompileError {
         Self::Panic(PanicKind::ArrayOutOfBounds)
     }
 
-    /// ABI-encodes this error and wraps it as a reverted [`PrecompileResult`].
+    /// ABI-encodes this error as Solidity custom-error revert data (4-byte selector plus
+    /// typed params), so contracts can `catch` specific precompile errors.
     ///
-    /// # Errors
-    /// - `PrecompileOutput::halt(PrecompileHalt::OutOfGas, ..)` — if the variant is [`OutOfGas`](Self::OutOfGas)
-    /// - `PrecompileError::Fatal` — if the variant is [`Fatal`](Self::Fatal)
-    pub fn into_precompile_result(self, gas: u64, reservoir: u64) -> PrecompileResult {
+    /// Every encoding produced here decodes back to the same variant via [`decode_error`].
+    /// Returns `None` for the variants that do not surface as reverts:
+    /// [`OutOfGas`](Self::OutOfGas) halts and [`Fatal`](Self::Fatal) aborts execution.
+    pub fn abi_encode(&self) -> Option<Bytes> {
         let bytes = match self {
             Self::StablecoinDEX(e) => e.abi_encode().into(),
             Self::TIP20(e) => e.abi_encode().into(),
@@ -185,7 +192,7 @@ impl TempoPrecompileError {
             Self::NonceError(e) => e.abi_encode().into(),
             Self::Panic(kind) => {
                 let panic = Panic {
-                    code: U256::from(kind as u32),
+                    code: U256::from(*kind as u32),
                 };
 
                 panic.abi_encode().into()
@@ -194,19 +201,31 @@ impl TempoPrecompileError {
             Self::ValidatorConfigV2Error(e) => e.abi_encode().into(),
             Self::AccountKeychainError(e) => e.abi_encode().into(),
             Self::SignatureVerifierError(e) => e.abi_encode().into(),
-            Self::OutOfGas => {
-                return Ok(PrecompileOutput::halt(PrecompileHalt::OutOfGas, reservoir));
-            }
             Self::UnknownFunctionSelector(selector) => UnknownFunctionSelector {
-                selector: selector.into(),
+                selector: (*selector).into(),
             }
             .abi_encode()
             .into(),
-            Self::Fatal(msg) => {
-                return Err(PrecompileError::Fatal(msg));
-            }
+            Self::ReentrantCall => ReentrantCall {}.abi_encode().into(),
+            Self::OutOfGas | Self::Fatal(_) => return None,
         };
-        Ok(PrecompileOutput::revert(gas, bytes, reservoir))
+        Some(bytes)
+    }
+
+    /// ABI-encodes this error and wraps it as a reverted [`PrecompileResult`].
+    ///
+    /// # Errors
+    /// - `PrecompileOutput::halt(PrecompileHalt::OutOfGas, ..)` — if the variant is [`OutOfGas`](Self::OutOfGas)
+    /// - `PrecompileError::Fatal` — if the variant is [`Fatal`](Self::Fatal)
+    pub fn into_precompile_result(self, gas: u64, reservoir: u64) -> PrecompileResult {
+        match self.abi_encode() {
+            Some(bytes) => Ok(PrecompileOutput::revert(gas, bytes, reservoir)),
+            None => match self {
+                Self::OutOfGas => Ok(PrecompileOutput::halt(PrecompileHalt::OutOfGas, reservoir)),
+                Self::Fatal(msg) => Err(PrecompileError::Fatal(msg)),
+                _ => unreachable!("only OutOfGas and Fatal have no revert encoding"),
+            },
+        }
     }
 }
 
@@ -232,6 +251,28 @@ pub fn add_errors_to_registry<T: SolInterface>(
     }
 }
 
+/// Registers a single standalone [`SolError`] selector into the decoder registry.
+///
+/// Unlike [`add_errors_to_registry`] the converter is fallible, so decoders can reject
+/// well-formed ABI data that is semantically invalid (e.g. an unknown `Panic` code).
+pub fn add_error_to_registry<T: SolError>(
+    registry: &mut TempoPrecompileErrorRegistry,
+    converter: impl Fn(T) -> Option<TempoPrecompileError> + 'static + Send + Sync,
+) {
+    registry.insert(
+        T::SELECTOR.into(),
+        Box::new(move |data: &[u8]| {
+            T::abi_decode(data)
+                .ok()
+                .and_then(&converter)
+                .map(|error| DecodedTempoPrecompileError {
+                    error,
+                    revert_bytes: data,
+                })
+        }),
+    );
+}
+
 /// A decoded precompile error together with the raw revert bytes.
 pub struct DecodedTempoPrecompileError<'a> {
     pub error: TempoPrecompileError,
@@ -262,6 +303,17 @@ pub fn error_decoder_registry() -> TempoPrecompileErrorRegistry {
     add_errors_to_registry(&mut registry, TempoPrecompileError::AccountKeychainError);
     add_errors_to_registry(&mut registry, TempoPrecompileError::SignatureVerifierError);
 
+    // Standalone errors emitted by dispatch rather than a per-precompile interface.
+    add_error_to_registry(&mut registry, |e: UnknownFunctionSelector| {
+        Some(TempoPrecompileError::UnknownFunctionSelector(e.selector.0))
+    });
+    add_error_to_registry(&mut registry, |_: ReentrantCall| {
+        Some(TempoPrecompileError::ReentrantCall)
+    });
+    add_error_to_registry(&mut registry, |e: Panic| {
+        e.kind().map(TempoPrecompileError::Panic)
+    });
+
     registry
 }
 
@@ -396,6 +448,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_dispatch_errors_round_trip() {
+        for error in [
+            TempoPrecompileError::UnknownFunctionSelector([0xde, 0xad, 0xbe, 0xef]),
+            TempoPrecompileError::ReentrantCall,
+            TempoPrecompileError::under_overflow(),
+        ] {
+            let encoded = error.abi_encode().expect("variant encodes as revert data");
+            let decoded = decode_error(&encoded).expect("encoded error should decode");
+            assert_eq!(decoded.error, error);
+            assert_eq!(decoded.revert_bytes, &encoded[..]);
+        }
+    }
+
+    #[test]
+    fn test_abi_encode_is_none_for_non_revert_variants() {
+        assert!(TempoPrecompileError::OutOfGas.abi_encode().is_none());
+        assert!(
+            TempoPrecompileError::Fatal("db failure".to_string())
+                .abi_encode()
+                .is_none()
+        );
+    }
+
     #[test]
     fn test_decode_error_with_tip20_error() {
         // Use insufficient_allowance which has a unique selector (no collision with other errors)
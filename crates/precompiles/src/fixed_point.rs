@@ -0,0 +1,322 @@
+//! Shared fixed-point arithmetic for stablecoin amounts.
+//!
+//! The DEX, the fee manager, and bridge minting all multiply an amount by a
+//! ratio and divide by a scale, each with its own overflow handling and
+//! rounding convention. This module centralizes that arithmetic: full-width
+//! `mul_div` with an explicit [`Rounding`] mode, WAD (18-decimals) helpers,
+//! and decimal rescaling between token precisions (e.g. 6-decimals USDC and
+//! 18-decimals TIP-20 amounts). Intermediates are computed in 512 bits so a
+//! product of two `U256` values never overflows before the division.
+
+use crate::error::{Result, TempoPrecompileError};
+use alloy::primitives::{U256, U512, uint};
+
+/// One whole unit in 18-decimals fixed point (1e18).
+pub const WAD: U256 = uint!(1000000000000000000_U256);
+
+/// Decimal precision of WAD-scaled amounts.
+pub const WAD_DECIMALS: u8 = 18;
+
+/// Rounding mode for fixed-point division.
+///
+/// Rounding prevents dust-level insolvency in settlement:
+/// - When escrowing funds from a user → round [`Up`](Rounding::Up) (user pays more)
+/// - When releasing funds to a user → round [`Down`](Rounding::Down) (user receives less)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round down (floor division) - favors protocol when user receives funds
+    Down,
+    /// Round up (ceiling division) - favors protocol when user deposits funds
+    Up,
+}
+
+/// Computes `a * b / denominator` with the product taken in 512 bits, so the
+/// multiplication itself cannot overflow.
+///
+/// # Errors
+/// - `UnderOverflow` — `denominator` is zero or the quotient exceeds `U256`
+pub fn mul_div(a: U256, b: U256, denominator: U256, rounding: Rounding) -> Result<U256> {
+    if denominator.is_zero() {
+        return Err(TempoPrecompileError::under_overflow());
+    }
+
+    let numerator = U512::from(a) * U512::from(b);
+    let denominator = U512::from(denominator);
+    let quotient = match rounding {
+        Rounding::Down => numerator / denominator,
+        Rounding::Up => numerator.div_ceil(denominator),
+    };
+
+    if quotient > U512::from(U256::MAX) {
+        return Err(TempoPrecompileError::under_overflow());
+    }
+    Ok(quotient.to::<U256>())
+}
+
+/// `u128` variant of [`mul_div`], returning `None` when the denominator is
+/// zero or the result does not fit. Amount arithmetic in the DEX is
+/// `u128`-denominated, so the widened product already fits in `U256`.
+pub fn mul_div_u128(a: u128, b: u128, denominator: u128, rounding: Rounding) -> Option<u128> {
+    if denominator == 0 {
+        return None;
+    }
+
+    let numerator = U256::from(a) * U256::from(b);
+    let denominator = U256::from(denominator);
+    let quotient = match rounding {
+        Rounding::Down => numerator / denominator,
+        Rounding::Up => numerator.div_ceil(denominator),
+    };
+
+    quotient.try_into().ok()
+}
+
+/// Multiplies two WAD-scaled values: `a * b / 1e18`.
+///
+/// # Errors
+/// - `UnderOverflow` — the quotient exceeds `U256`
+pub fn wad_mul(a: U256, b: U256, rounding: Rounding) -> Result<U256> {
+    mul_div(a, b, WAD, rounding)
+}
+
+/// Divides two WAD-scaled values: `a * 1e18 / b`.
+///
+/// # Errors
+/// - `UnderOverflow` — `b` is zero or the quotient exceeds `U256`
+pub fn wad_div(a: U256, b: U256, rounding: Rounding) -> Result<U256> {
+    mul_div(a, WAD, b, rounding)
+}
+
+/// Rescales `amount` from `from_decimals` to `to_decimals` precision, e.g.
+/// between 6-decimals and 18-decimals token amounts.
+///
+/// Scaling up multiplies by a power of ten and is exact; scaling down divides
+/// with the given rounding, so precision below the target scale is either
+/// dropped ([`Rounding::Down`]) or bumped ([`Rounding::Up`]).
+///
+/// # Errors
+/// - `UnderOverflow` — the scaled amount exceeds `U256`
+pub fn rescale(
+    amount: U256,
+    from_decimals: u8,
+    to_decimals: u8,
+    rounding: Rounding,
+) -> Result<U256> {
+    match from_decimals.cmp(&to_decimals) {
+        std::cmp::Ordering::Equal => Ok(amount),
+        std::cmp::Ordering::Less => {
+            let factor = pow10(to_decimals - from_decimals)?;
+            amount
+                .checked_mul(factor)
+                .ok_or(TempoPrecompileError::under_overflow())
+        }
+        std::cmp::Ordering::Greater => {
+            let factor = pow10(from_decimals - to_decimals)?;
+            Ok(match rounding {
+                Rounding::Down => amount / factor,
+                Rounding::Up => amount.div_ceil(factor),
+            })
+        }
+    }
+}
+
+/// `10^exp` as a `U256`.
+///
+/// # Errors
+/// - `UnderOverflow` — `10^exp` exceeds `U256` (exp > 77)
+fn pow10(exp: u8) -> Result<U256> {
+    U256::from(10u64)
+        .checked_pow(U256::from(exp))
+        .ok_or(TempoPrecompileError::under_overflow())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mul_div_basics() {
+        // 7 * 3 / 2 = 10.5: floor 10, ceil 11.
+        let a = U256::from(7u64);
+        let b = U256::from(3u64);
+        let d = U256::from(2u64);
+        assert_eq!(mul_div(a, b, d, Rounding::Down).unwrap(), U256::from(10u64));
+        assert_eq!(mul_div(a, b, d, Rounding::Up).unwrap(), U256::from(11u64));
+
+        // Exact division rounds identically in both modes.
+        let exact = mul_div(U256::from(6u64), b, d, Rounding::Down).unwrap();
+        assert_eq!(
+            exact,
+            mul_div(U256::from(6u64), b, d, Rounding::Up).unwrap()
+        );
+        assert_eq!(exact, U256::from(9u64));
+    }
+
+    #[test]
+    fn test_mul_div_full_width_intermediate() {
+        // MAX * MAX / MAX = MAX: the product overflows 256 bits but the
+        // quotient fits, which the 512-bit intermediate must handle.
+        assert_eq!(
+            mul_div(U256::MAX, U256::MAX, U256::MAX, Rounding::Down).unwrap(),
+            U256::MAX
+        );
+
+        // MAX * 2 / 1 does not fit.
+        assert!(
+            mul_div(
+                U256::MAX,
+                U256::from(2u64),
+                U256::from(1u64),
+                Rounding::Down
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_mul_div_zero_denominator() {
+        assert!(
+            mul_div(
+                U256::from(1u64),
+                U256::from(1u64),
+                U256::ZERO,
+                Rounding::Down
+            )
+            .is_err()
+        );
+        assert!(mul_div_u128(1, 1, 0, Rounding::Down).is_none());
+    }
+
+    #[test]
+    fn test_wad_helpers() {
+        let two = U256::from(2u64) * WAD;
+        let three = U256::from(3u64) * WAD;
+        assert_eq!(
+            wad_mul(two, three, Rounding::Down).unwrap(),
+            U256::from(6u64) * WAD
+        );
+        assert_eq!(
+            wad_div(three, two, Rounding::Down).unwrap(),
+            WAD * U256::from(3u64) / U256::from(2u64)
+        );
+        assert!(wad_div(WAD, U256::ZERO, Rounding::Down).is_err());
+    }
+
+    #[test]
+    fn test_rescale_between_6_and_18() {
+        // 1.5 tokens at 6 decimals.
+        let six = U256::from(1_500_000u64);
+        let eighteen = rescale(six, 6, 18, Rounding::Down).unwrap();
+        assert_eq!(eighteen, U256::from(1_500_000u64) * pow10(12).unwrap());
+
+        // Scaling back down is exact for amounts that came from 6 decimals.
+        assert_eq!(rescale(eighteen, 18, 6, Rounding::Down).unwrap(), six);
+
+        // Sub-precision dust is dropped or bumped by the rounding mode.
+        let dusty = eighteen + U256::from(1u64);
+        assert_eq!(rescale(dusty, 18, 6, Rounding::Down).unwrap(), six);
+        assert_eq!(
+            rescale(dusty, 18, 6, Rounding::Up).unwrap(),
+            six + U256::from(1u64)
+        );
+    }
+
+    #[test]
+    fn test_rescale_overflow() {
+        assert!(rescale(U256::MAX, 6, 18, Rounding::Down).is_err());
+        assert_eq!(
+            rescale(U256::MAX, 18, 18, Rounding::Down).unwrap(),
+            U256::MAX
+        );
+    }
+
+    // -- PROPERTY TESTS -----------------------------------------------------------
+
+    use proptest::prelude::*;
+
+    /// Strategy for generating random U256 values
+    fn arb_u256() -> impl Strategy<Value = U256> {
+        any::<[u64; 4]>().prop_map(U256::from_limbs)
+    }
+
+    /// Strategy for generating non-zero U256 values
+    fn arb_nonzero_u256() -> impl Strategy<Value = U256> {
+        arb_u256().prop_map(|v| v.max(U256::from(1u64)))
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(500))]
+
+        #[test]
+        fn proptest_mul_div_floor_bounds(a in arb_u256(), b in arb_u256(), d in arb_nonzero_u256()) {
+            // Floor division: q * d <= a * b < (q + 1) * d, checked in 512 bits.
+            if let Ok(q) = mul_div(a, b, d, Rounding::Down) {
+                let product = U512::from(a) * U512::from(b);
+                let q = U512::from(q);
+                let d = U512::from(d);
+                prop_assert!(q * d <= product);
+                prop_assert!((q + U512::from(1u64)) * d > product);
+            }
+        }
+
+        #[test]
+        fn proptest_mul_div_rounding_differs_by_at_most_one(
+            a in arb_u256(),
+            b in arb_u256(),
+            d in arb_nonzero_u256(),
+        ) {
+            if let (Ok(down), Ok(up)) = (
+                mul_div(a, b, d, Rounding::Down),
+                mul_div(a, b, d, Rounding::Up),
+            ) {
+                prop_assert!(up - down <= U256::from(1u64));
+                // The modes agree exactly when the division is exact.
+                let exact = (U512::from(a) * U512::from(b)) % U512::from(d) == U512::ZERO;
+                prop_assert_eq!(up == down, exact);
+            }
+        }
+
+        #[test]
+        fn proptest_mul_div_u128_matches_u256(a: u128, b: u128, d in 1u128..) {
+            let wide = mul_div(U256::from(a), U256::from(b), U256::from(d), Rounding::Up).unwrap();
+            match mul_div_u128(a, b, d, Rounding::Up) {
+                Some(narrow) => prop_assert_eq!(U256::from(narrow), wide),
+                None => prop_assert!(wide > U256::from(u128::MAX)),
+            }
+        }
+
+        #[test]
+        fn proptest_wad_mul_div_roundtrip(a in arb_u256(), b in arb_nonzero_u256()) {
+            // (a / b) * b recovers a up to one unit of WAD precision.
+            if let Ok(ratio) = wad_div(a, b, Rounding::Down)
+                && let Ok(back) = wad_mul(ratio, b, Rounding::Down)
+            {
+                prop_assert!(back <= a);
+                // The loss is bounded by b / WAD rounded up, plus the final floor.
+                let max_loss = b.div_ceil(WAD) + U256::from(1u64);
+                prop_assert!(a - back <= max_loss);
+            }
+        }
+
+        #[test]
+        fn proptest_rescale_up_is_exact(amount: u128, from in 0u8..=18, to in 0u8..=18) {
+            prop_assume!(from <= to);
+            // Scaling up never fails for u128-sized amounts and divides back exactly.
+            let up = rescale(U256::from(amount), from, to, Rounding::Down).unwrap();
+            let back = rescale(up, to, from, Rounding::Down).unwrap();
+            prop_assert_eq!(back, U256::from(amount));
+        }
+
+        #[test]
+        fn proptest_rescale_down_bounds(amount in arb_u256(), from in 0u8..=18, to in 0u8..=18) {
+            prop_assume!(from >= to);
+            let down = rescale(amount, from, to, Rounding::Down).unwrap();
+            let up = rescale(amount, from, to, Rounding::Up).unwrap();
+            prop_assert!(up - down <= U256::from(1u64));
+            // Round-tripping back up never exceeds the original amount.
+            if let Ok(restored) = rescale(down, to, from, Rounding::Down) {
+                prop_assert!(restored <= amount);
+            }
+        }
+    }
+}
@@ -5,6 +5,10 @@
 pub mod error;
 pub use error::{IntoPrecompileResult, Result};
 
+pub mod activation;
+pub mod calldata;
+pub mod fixed_point;
+pub mod runtime;
 pub mod storage;
 
 pub(crate) mod ip_validation;
@@ -12,6 +16,7 @@ pub(crate) mod ip_validation;
 pub mod account_keychain;
 pub mod address_registry;
 pub mod nonce;
+pub mod p256_verify;
 pub mod signature_verifier;
 pub mod stablecoin_dex;
 pub mod tip20;
@@ -20,16 +25,19 @@ pub mod tip403_registry;
 pub mod tip_fee_manager;
 pub mod validator_config;
 pub mod validator_config_v2;
+pub mod version_registry;
 
+#[cfg(any(test, feature = "test-utils"))]
+pub mod differential;
 #[cfg(any(test, feature = "test-utils"))]
 pub mod test_util;
 
 use crate::{
     account_keychain::AccountKeychain, address_registry::AddressRegistry, nonce::NonceManager,
-    signature_verifier::SignatureVerifier, stablecoin_dex::StablecoinDEX, storage::StorageCtx,
-    tip_fee_manager::TipFeeManager, tip20::TIP20Token, tip20_factory::TIP20Factory,
-    tip403_registry::TIP403Registry, validator_config::ValidatorConfig,
-    validator_config_v2::ValidatorConfigV2,
+    p256_verify::P256Verify, signature_verifier::SignatureVerifier, stablecoin_dex::StablecoinDEX,
+    storage::StorageCtx, tip_fee_manager::TipFeeManager, tip20::TIP20Token,
+    tip20_factory::TIP20Factory, tip403_registry::TIP403Registry,
+    validator_config::ValidatorConfig, validator_config_v2::ValidatorConfigV2,
 };
 use tempo_chainspec::hardfork::TempoHardfork;
 use tempo_primitives::TempoAddressExt;
@@ -49,11 +57,13 @@ use revm::{
     primitives::hardfork::SpecId,
 };
 
+use tempo_contracts::precompiles::IERC165;
+
 pub use tempo_contracts::precompiles::{
     ACCOUNT_KEYCHAIN_ADDRESS, ADDRESS_REGISTRY_ADDRESS, DEFAULT_FEE_TOKEN,
-    NONCE_PRECOMPILE_ADDRESS, PATH_USD_ADDRESS, SIGNATURE_VERIFIER_ADDRESS, STABLECOIN_DEX_ADDRESS,
-    TIP_FEE_MANAGER_ADDRESS, TIP20_FACTORY_ADDRESS, TIP403_REGISTRY_ADDRESS,
-    VALIDATOR_CONFIG_ADDRESS, VALIDATOR_CONFIG_V2_ADDRESS,
+    NONCE_PRECOMPILE_ADDRESS, P256_VERIFY_ADDRESS, PATH_USD_ADDRESS, SIGNATURE_VERIFIER_ADDRESS,
+    STABLECOIN_DEX_ADDRESS, TIP_FEE_MANAGER_ADDRESS, TIP20_FACTORY_ADDRESS,
+    TIP403_REGISTRY_ADDRESS, VALIDATOR_CONFIG_ADDRESS, VALIDATOR_CONFIG_V2_ADDRESS,
 };
 
 // Re-export storage layout helpers for read-only contexts (e.g., pool validation)
@@ -109,17 +119,24 @@ pub fn tempo_precompiles(cfg: &CfgEnv<TempoHardfork>) -> PrecompilesMap {
 /// Registers Tempo-specific precompiles into an existing [`PrecompilesMap`] by installing a
 /// lookup function that matches addresses to their precompile: TIP-20 tokens (by prefix),
 /// TIP20Factory, TIP403Registry, TipFeeManager, StablecoinDEX, NonceManager, ValidatorConfig,
-/// AccountKeychain, and ValidatorConfigV2. Each precompile is wrapped via the `tempo_precompile!`
-/// macro which enforces direct-call-only (no delegatecall) and sets up the storage context.
+/// AccountKeychain, ValidatorConfigV2, SignatureVerifier, AddressRegistry, and the RIP-7212
+/// `P256VERIFY`. Whether an address resolves at all under the configured spec is driven by the
+/// [`activation`] schedule, so hardfork gating lives in data rather than in this routing code.
+/// Each precompile is wrapped via the `tempo_precompile!` macro which enforces
+/// direct-call-only (no delegatecall) and sets up the storage context.
 pub fn extend_tempo_precompiles(precompiles: &mut PrecompilesMap, cfg: &CfgEnv<TempoHardfork>) {
     let cfg = cfg.clone();
 
     precompiles.set_precompile_lookup(move |address: &Address| {
+        if !activation::is_active(*address, cfg.spec) {
+            return None;
+        }
+
         if address.is_tip20() {
             Some(TIP20Token::create_precompile(*address, &cfg))
         } else if *address == TIP20_FACTORY_ADDRESS {
             Some(TIP20Factory::create_precompile(&cfg))
-        } else if *address == ADDRESS_REGISTRY_ADDRESS && cfg.spec.is_t3() {
+        } else if *address == ADDRESS_REGISTRY_ADDRESS {
             Some(AddressRegistry::create_precompile(&cfg))
         } else if *address == TIP403_REGISTRY_ADDRESS {
             Some(TIP403Registry::create_precompile(&cfg))
@@ -135,14 +152,70 @@ pub fn extend_tempo_precompiles(precompiles: &mut PrecompilesMap, cfg: &CfgEnv<T
             Some(AccountKeychain::create_precompile(&cfg))
         } else if *address == VALIDATOR_CONFIG_V2_ADDRESS {
             Some(ValidatorConfigV2::create_precompile(&cfg))
-        } else if *address == SIGNATURE_VERIFIER_ADDRESS && cfg.spec.is_t3() {
+        } else if *address == SIGNATURE_VERIFIER_ADDRESS {
             Some(SignatureVerifier::create_precompile(&cfg))
+        } else if *address == P256_VERIFY_ADDRESS {
+            Some(P256Verify::create_precompile(&cfg))
         } else {
             None
         }
     });
 }
 
+/// Simulates a single precompile call against a copy-on-write overlay over `base`.
+///
+/// Routing and hardfork activation mirror [`extend_tempo_precompiles`], but the call runs
+/// through [`storage::simulate`] instead of an EVM frame: every write, emitted event, and
+/// metered gas unit lands in the returned [`storage::SimulationOutcome`] while `base` is
+/// left untouched. This is the dispatch mode `eth_call`/`eth_estimateGas`-style handlers
+/// use to preview state-mutating entrypoints (TIP-20 transfers, keychain authorizations,
+/// ...) without journaling into real state.
+///
+/// Returns `None` when `address` does not resolve to an active Tempo precompile under
+/// `base`'s spec.
+pub fn simulate_precompile_call<S: storage::PrecompileStorageProvider>(
+    base: &mut S,
+    address: Address,
+    calldata: &[u8],
+    msg_sender: Address,
+) -> Option<(PrecompileResult, storage::SimulationOutcome)> {
+    if !activation::is_active(address, base.spec()) {
+        return None;
+    }
+
+    let mut precompile: Box<dyn Precompile> = if address.is_tip20() {
+        Box::new(TIP20Token::from_address(address).ok()?)
+    } else if address == TIP20_FACTORY_ADDRESS {
+        Box::new(TIP20Factory::new())
+    } else if address == ADDRESS_REGISTRY_ADDRESS {
+        Box::new(AddressRegistry::new())
+    } else if address == TIP403_REGISTRY_ADDRESS {
+        Box::new(TIP403Registry::new())
+    } else if address == TIP_FEE_MANAGER_ADDRESS {
+        Box::new(TipFeeManager::new())
+    } else if address == STABLECOIN_DEX_ADDRESS {
+        Box::new(StablecoinDEX::new())
+    } else if address == NONCE_PRECOMPILE_ADDRESS {
+        Box::new(NonceManager::new())
+    } else if address == VALIDATOR_CONFIG_ADDRESS {
+        Box::new(ValidatorConfig::new())
+    } else if address == ACCOUNT_KEYCHAIN_ADDRESS {
+        Box::new(AccountKeychain::new())
+    } else if address == VALIDATOR_CONFIG_V2_ADDRESS {
+        Box::new(ValidatorConfigV2::new())
+    } else if address == SIGNATURE_VERIFIER_ADDRESS {
+        Box::new(SignatureVerifier::new())
+    } else if address == P256_VERIFY_ADDRESS {
+        Box::new(P256Verify::new())
+    } else {
+        return None;
+    };
+
+    Some(storage::simulate(base, || {
+        precompile.call(calldata, msg_sender)
+    }))
+}
+
 sol! {
     error DelegateCallNotAllowed();
     error StaticCallNotAllowed();
@@ -254,6 +327,13 @@ impl SignatureVerifier {
     }
 }
 
+impl P256Verify {
+    /// Creates the EVM precompile for this type.
+    pub fn create_precompile(cfg: &CfgEnv<TempoHardfork>) -> DynPrecompile {
+        tempo_precompile!("P256Verify", cfg, |input| { Self::new() })
+    }
+}
+
 /// Dispatches a parameterless view call, encoding the return via `T`.
 #[inline]
 fn metadata<T: SolCall>(f: impl FnOnce() -> Result<T::Return>) -> PrecompileResult {
@@ -365,15 +445,41 @@ impl<'a> SelectorSchedule<'a> {
     }
 }
 
+/// Computes an ERC-165 interface id as the XOR of all function selectors.
+///
+/// Matches Solidity's `type(I).interfaceId` for the interface the selectors were generated
+/// from, so callers can feature-detect precompiles the same way they would any contract.
+pub(crate) const fn interface_id(selectors: &[[u8; 4]]) -> [u8; 4] {
+    let mut id = [0u8; 4];
+    let mut i = 0;
+    while i < selectors.len() {
+        let mut j = 0;
+        while j < 4 {
+            id[j] ^= selectors[i][j];
+            j += 1;
+        }
+        i += 1;
+    }
+    id
+}
+
+/// The ERC-165 interface id of `IERC165` itself (`0x01ffc9a7`).
+pub(crate) const ERC165_INTERFACE_ID: [u8; 4] = IERC165::supportsInterfaceCall::SELECTOR;
+
 /// Applies hardfork selector schedules, decodes calldata via `decode`, then dispatches to `f`.
 ///
 /// Handles missing selectors (revert on T1+, error on earlier forks), hardfork-gated selectors,
 /// unknown selectors (ABI-encoded `UnknownFunctionSelector`), and malformed ABI data (empty
 /// revert).
+///
+/// From T4, `supportsInterface(bytes4)` is answered here for every precompile: it reports
+/// `true` for the ERC-165 id itself and for any id in `interfaces` (one per implemented
+/// interface, via [`interface_id`]). Earlier forks treat the selector as unknown.
 #[inline]
 pub(crate) fn dispatch_call<T>(
     calldata: &[u8],
     hardforks: &[SelectorSchedule<'_>],
+    interfaces: &[[u8; 4]],
     decode: impl FnOnce(&[u8]) -> core::result::Result<T, alloy::sol_types::Error>,
     f: impl FnOnce(T) -> PrecompileResult,
 ) -> PrecompileResult {
@@ -390,6 +496,22 @@ pub(crate) fn dispatch_call<T>(
     }
 
     let selector: [u8; 4] = calldata[..4].try_into().expect("calldata len >= 4");
+
+    if selector == IERC165::supportsInterfaceCall::SELECTOR && storage.spec().is_t4() {
+        return match IERC165::supportsInterfaceCall::abi_decode(calldata) {
+            Ok(call) => {
+                let queried: [u8; 4] = call.interfaceID.0;
+                let supported = queried == ERC165_INTERFACE_ID || interfaces.contains(&queried);
+                view(call, |_| Ok(supported)).map(|mut res| {
+                    res.gas_used = storage.gas_used();
+                    res.reservoir = storage.reservoir();
+                    res
+                })
+            }
+            Err(_) => Ok(storage.revert_output(Bytes::new())),
+        };
+    }
+
     if hardforks
         .iter()
         .any(|schedule| schedule.rejects(selector, storage.spec()))
@@ -657,6 +779,7 @@ mod tests {
                 dispatch_call(
                     calldata,
                     SELECTOR_SCHEDULE,
+                    &[],
                     ISelectorGatedTest::ISelectorGatedTestCalls::abi_decode,
                     |call| match call {
                         ISelectorGatedTest::ISelectorGatedTestCalls::stable(_) => {
@@ -719,6 +842,110 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_supports_interface_feature_detection() -> eyre::Result<()> {
+        use crate::storage::hashmap::HashMapStorageProvider;
+        use tempo_contracts::precompiles::INonce::INonceCalls;
+
+        let supports = |spec: TempoHardfork, queried: [u8; 4]| {
+            let mut storage = HashMapStorageProvider::new_with_spec(1, spec);
+            StorageCtx::enter(&mut storage, || {
+                let calldata = IERC165::supportsInterfaceCall {
+                    interfaceID: queried.into(),
+                }
+                .abi_encode();
+                NonceManager::new().call(&calldata, Address::ZERO)
+            })
+        };
+
+        let nonce_id = interface_id(INonceCalls::SELECTORS);
+
+        // T4: the precompile reports its own interface and the ERC-165 id.
+        for queried in [nonce_id, ERC165_INTERFACE_ID] {
+            let output = supports(TempoHardfork::T4, queried)?;
+            assert!(!output.is_revert());
+            let supported = IERC165::supportsInterfaceCall::abi_decode_returns(&output.bytes)?;
+            assert!(supported, "expected {queried:x?} to be supported");
+        }
+
+        // T4: unknown ids (including the ERC-165 sentinel 0xffffffff) report false.
+        let output = supports(TempoHardfork::T4, [0xFF; 4])?;
+        assert!(!output.is_revert());
+        assert!(!IERC165::supportsInterfaceCall::abi_decode_returns(
+            &output.bytes
+        )?);
+
+        // pre-T4: the selector is unknown like any other pre-activation function.
+        let output = supports(TempoHardfork::T3, nonce_id)?;
+        assert!(output.is_revert());
+        let decoded = UnknownFunctionSelector::abi_decode(&output.bytes)?;
+        assert_eq!(
+            decoded.selector.as_slice(),
+            &IERC165::supportsInterfaceCall::SELECTOR
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_simulate_precompile_call_routes_without_touching_base() -> eyre::Result<()> {
+        use crate::{error::TempoPrecompileError, test_util::TIP20Setup};
+
+        let mut storage = HashMapStorageProvider::new(1);
+        let admin = Address::random();
+        let from = Address::random();
+        let to = Address::random();
+
+        let token = StorageCtx::enter(&mut storage, || {
+            TIP20Setup::create("Test", "TST", admin)
+                .with_issuer(admin)
+                .with_mint(from, U256::from(500))
+                .apply()
+        })?;
+
+        // Addresses that are not Tempo precompiles do not route anywhere.
+        assert!(simulate_precompile_call(&mut storage, Address::random(), &[], from).is_none());
+
+        let calldata = ITIP20::transferCall {
+            to,
+            amount: U256::from(100),
+        }
+        .abi_encode();
+        let (result, outcome) =
+            simulate_precompile_call(&mut storage, token.address(), &calldata, from)
+                .expect("TIP-20 address must route to the token precompile");
+        let output = result.expect("simulated transfer must dispatch");
+        assert!(!output.is_revert());
+        assert!(!outcome.state_diff.is_empty(), "balances must diff");
+        assert!(!outcome.events.is_empty(), "transfer must emit");
+        assert!(outcome.gas_used > 0, "dispatch must meter calldata gas");
+
+        // The simulated transfer never reached the base provider.
+        StorageCtx::enter(&mut storage, || {
+            let token = TIP20Token::from_address(token.address())?;
+            assert_eq!(
+                token.balance_of(ITIP20::balanceOfCall { account: from })?,
+                U256::from(500)
+            );
+            Ok::<_, TempoPrecompileError>(())
+        })?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_interface_id_xors_selectors() {
+        // XOR of a single selector is the selector itself, and each selector
+        // cancels out when folded in twice.
+        let a = [0x01, 0x02, 0x03, 0x04];
+        let b = [0xF0, 0x0F, 0xAA, 0x55];
+        assert_eq!(interface_id(&[a]), a);
+        assert_eq!(interface_id(&[a, b, a]), b);
+        assert_eq!(
+            interface_id(&[IERC165::supportsInterfaceCall::SELECTOR]),
+            ERC165_INTERFACE_ID
+        );
+    }
+
     #[test]
     fn test_input_cost_returns_non_zero_for_input() {
         // Empty input should cost 0
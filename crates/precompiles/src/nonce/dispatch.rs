@@ -1,9 +1,21 @@
 //! ABI dispatch for the [`NonceManager`] precompile.
 
-use crate::{Precompile, charge_input_cost, dispatch_call, nonce::NonceManager, view};
-use alloy::{primitives::Address, sol_types::SolInterface};
+use crate::{
+    Precompile, SelectorSchedule, charge_input_cost, dispatch_call, interface_id,
+    nonce::NonceManager, view,
+};
+use alloy::{
+    primitives::Address,
+    sol_types::{SolCall, SolInterface},
+};
 use revm::precompile::PrecompileResult;
-use tempo_contracts::precompiles::INonce::INonceCalls;
+use tempo_chainspec::hardfork::TempoHardfork;
+use tempo_contracts::precompiles::INonce::{self, INonceCalls};
+
+const T4_ADDED: &[[u8; 4]] = &[INonce::getExpiryCall::SELECTOR];
+
+/// ERC-165 interface ids served by `supportsInterface` (T4+).
+const INTERFACE_IDS: &[[u8; 4]] = &[interface_id(INonceCalls::SELECTORS)];
 
 impl Precompile for NonceManager {
     fn call(&mut self, calldata: &[u8], _msg_sender: Address) -> PrecompileResult {
@@ -11,9 +23,16 @@ impl Precompile for NonceManager {
             return err;
         }
 
-        dispatch_call(calldata, &[], INonceCalls::abi_decode, |call| match call {
-            INonceCalls::getNonce(call) => view(call, |c| self.get_nonce(c)),
-        })
+        dispatch_call(
+            calldata,
+            &[SelectorSchedule::new(TempoHardfork::T4).with_added(T4_ADDED)],
+            INTERFACE_IDS,
+            INonceCalls::abi_decode,
+            |call| match call {
+                INonceCalls::getNonce(call) => view(call, |c| self.get_nonce(c)),
+                INonceCalls::getExpiry(call) => view(call, |c| self.get_expiry(c)),
+            },
+        )
     }
 }
 
@@ -24,7 +43,8 @@ mod tests {
         storage::{StorageCtx, hashmap::HashMapStorageProvider},
         test_util::{assert_full_coverage, check_selector_coverage},
     };
-    use tempo_contracts::precompiles::INonce::INonceCalls;
+    use alloy::primitives::B256;
+    use tempo_contracts::precompiles::{INonce::INonceCalls, UnknownFunctionSelector};
 
     #[test]
     fn test_nonce_selector_coverage() -> eyre::Result<()> {
@@ -43,4 +63,26 @@ mod tests {
             Ok(())
         })
     }
+
+    #[test]
+    fn test_get_expiry_gated_behind_t4() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T3);
+        StorageCtx::enter(&mut storage, || {
+            let mut nonce_manager = NonceManager::new();
+
+            let calldata = INonce::getExpiryCall {
+                expiringNonceHash: B256::ZERO,
+            }
+            .abi_encode();
+            let output = nonce_manager.call(&calldata, Address::ZERO)?;
+
+            assert!(output.is_revert());
+            let decoded = UnknownFunctionSelector::abi_decode(&output.bytes)?;
+            assert_eq!(
+                decoded.selector.as_slice(),
+                &INonce::getExpiryCall::SELECTOR
+            );
+            Ok(())
+        })
+    }
 }
@@ -104,6 +104,15 @@ impl NonceManager {
         Ok(new_nonce)
     }
 
+    /// Returns the recorded `valid_before` of an expiring-nonce hash, or 0 when the hash is
+    /// unseen or its ring entry has been evicted.
+    ///
+    /// Wallets use this for safe fee-retry: a replacement transaction is only safe to send
+    /// once the original hash no longer blocks replay.
+    pub fn get_expiry(&self, call: INonce::getExpiryCall) -> Result<u64> {
+        self.expiring_nonce_seen[call.expiringNonceHash].read()
+    }
+
     /// Checks if a hash has been seen and is still valid (not expired).
     /// NOTE: internally used by the transaction pool.
     pub fn is_expiring_nonce_seen(&self, hash: B256, now: u64) -> Result<bool> {
@@ -322,6 +331,37 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_get_expiry_reports_recorded_valid_before() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1);
+        let now = 1000u64;
+        storage.set_timestamp(U256::from(now));
+        StorageCtx::enter(&mut storage, || {
+            let mut mgr = NonceManager::new();
+
+            let tx_hash = B256::repeat_byte(0x55);
+            let valid_before = now + 20;
+
+            // Unseen hash reports 0.
+            assert_eq!(
+                mgr.get_expiry(INonce::getExpiryCall {
+                    expiringNonceHash: tx_hash,
+                })?,
+                0
+            );
+
+            mgr.check_and_mark_expiring_nonce(tx_hash, valid_before)?;
+            assert_eq!(
+                mgr.get_expiry(INonce::getExpiryCall {
+                    expiringNonceHash: tx_hash,
+                })?,
+                valid_before
+            );
+
+            Ok(())
+        })
+    }
+
     #[test]
     fn test_expiring_nonce_expiry_validation() -> eyre::Result<()> {
         let mut storage = HashMapStorageProvider::new(1);
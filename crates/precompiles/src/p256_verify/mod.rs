@@ -0,0 +1,149 @@
+//! RIP-7212 `P256VERIFY` precompile.
+//!
+//! Verifies a P-256 (secp256r1) ECDSA signature over a 32-byte message hash for
+//! a flat gas cost, so contracts (passkey wallets, session-key validators, ...)
+//! can check WebAuthn-style signatures without the ~1M gas a Solidity
+//! implementation costs. Input and output follow [RIP-7212]: there is no
+//! function selector and malformed or invalid inputs return empty output
+//! rather than reverting.
+//!
+//! [RIP-7212]: <https://github.com/ethereum/RIPs/blob/master/RIPS/rip-7212.md>
+
+use crate::{P256_VERIFY_ADDRESS, Precompile, error::Result};
+use alloy::primitives::{Address, B256, Bytes, U256};
+use revm::precompile::{PrecompileHalt, PrecompileResult};
+use tempo_precompiles_macros::contract;
+use tempo_primitives::transaction::verify_p256_signature;
+
+/// Flat gas cost of a `P256VERIFY` call, per RIP-7212.
+pub const P256_VERIFY_GAS: u64 = 3_450;
+
+/// Exact input length: `hash(32) || r(32) || s(32) || x(32) || y(32)`.
+pub const P256_VERIFY_INPUT_LENGTH: usize = 160;
+
+#[contract(addr = P256_VERIFY_ADDRESS)]
+pub struct P256Verify {}
+
+impl P256Verify {
+    /// Initializes the `P256VERIFY` precompile account.
+    pub fn initialize(&mut self) -> Result<()> {
+        self.__initialize()
+    }
+}
+
+impl Precompile for P256Verify {
+    fn call(&mut self, calldata: &[u8], _msg_sender: Address) -> PrecompileResult {
+        if self.storage.deduct_gas(P256_VERIFY_GAS).is_err() {
+            return Ok(self.storage.halt_output(PrecompileHalt::OutOfGas));
+        }
+
+        // Per RIP-7212, anything other than a valid 160-byte input yields empty
+        // output with success status; the precompile never reverts.
+        if calldata.len() != P256_VERIFY_INPUT_LENGTH {
+            return Ok(self.storage.success_output(Bytes::new()));
+        }
+
+        let hash = B256::from_slice(&calldata[0..32]);
+        let valid = verify_p256_signature(
+            &calldata[32..64],
+            &calldata[64..96],
+            &calldata[96..128],
+            &calldata[128..160],
+            &hash,
+        )
+        .is_ok();
+
+        let output = if valid {
+            Bytes::from(U256::from(1).to_be_bytes::<32>())
+        } else {
+            Bytes::new()
+        };
+        Ok(self.storage.success_output(output))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::{StorageCtx, hashmap::HashMapStorageProvider};
+    use p256::ecdsa::{SigningKey, signature::hazmat::PrehashSigner};
+    use tempo_primitives::transaction::tt_signature::normalize_p256_s;
+
+    /// Builds a valid 160-byte RIP-7212 input for a fresh key over `hash`.
+    fn valid_input(hash: B256) -> Vec<u8> {
+        let signing_key = SigningKey::random(&mut p256::elliptic_curve::rand_core::OsRng);
+        let signature: p256::ecdsa::Signature = signing_key.sign_prehash(hash.as_slice()).unwrap();
+        let sig_bytes = signature.to_bytes();
+        let point = signing_key.verifying_key().to_encoded_point(false);
+
+        let mut input = Vec::with_capacity(P256_VERIFY_INPUT_LENGTH);
+        input.extend_from_slice(hash.as_slice());
+        input.extend_from_slice(&sig_bytes[0..32]);
+        input.extend_from_slice(normalize_p256_s(&sig_bytes[32..64]).unwrap().as_slice());
+        input.extend_from_slice(point.x().unwrap());
+        input.extend_from_slice(point.y().unwrap());
+        input
+    }
+
+    #[test]
+    fn test_valid_signature_returns_one() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1);
+        StorageCtx::enter(&mut storage, || {
+            let hash = B256::from([0x42; 32]);
+            let input = valid_input(hash);
+
+            let output = P256Verify::new().call(&input, Address::ZERO)?;
+            assert_eq!(output.bytes, Bytes::from(U256::from(1).to_be_bytes::<32>()));
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_high_s_signature_is_accepted() -> eyre::Result<()> {
+        use tempo_primitives::transaction::tt_signature::P256_ORDER;
+
+        let mut storage = HashMapStorageProvider::new(1);
+        StorageCtx::enter(&mut storage, || {
+            let hash = B256::from([0x42; 32]);
+            let mut input = valid_input(hash);
+
+            // Flip s to its high form; RIP-7212 does not require canonical s.
+            let s = U256::from_be_slice(&input[64..96]);
+            input[64..96].copy_from_slice(&(P256_ORDER - s).to_be_bytes::<32>());
+
+            let output = P256Verify::new().call(&input, Address::ZERO)?;
+            assert_eq!(output.bytes, Bytes::from(U256::from(1).to_be_bytes::<32>()));
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_invalid_signature_returns_empty() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1);
+        StorageCtx::enter(&mut storage, || {
+            let hash = B256::from([0x42; 32]);
+            let mut input = valid_input(hash);
+            // Corrupt r.
+            input[32] ^= 0x01;
+
+            let output = P256Verify::new().call(&input, Address::ZERO)?;
+            assert!(output.bytes.is_empty());
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_malformed_input_returns_empty() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1);
+        StorageCtx::enter(&mut storage, || {
+            for len in [0usize, 159, 161] {
+                let output = P256Verify::new().call(&vec![0u8; len], Address::ZERO)?;
+                assert!(
+                    output.bytes.is_empty(),
+                    "input of length {len} must be empty"
+                );
+            }
+            Ok(())
+        })
+    }
+}
@@ -0,0 +1,121 @@
+//! Shared runtime helpers for precompile entrypoints.
+
+use alloy::primitives::{Address, U256};
+
+use crate::{
+    error::{Result, TempoPrecompileError},
+    storage::StorageCtx,
+};
+
+/// Transient storage slot used by [`with_reentrancy_guard`].
+///
+/// Contract layouts assign persistent and transient fields sequential slots
+/// starting at zero (with mapping entries at keccak-derived slots), so the
+/// top of the slot space is free for runtime bookkeeping.
+pub const REENTRANCY_GUARD_SLOT: U256 = U256::MAX;
+
+/// Runs `f` under a transient-storage reentrancy guard scoped to `address`.
+///
+/// If a guarded entrypoint of the same precompile is already executing in the
+/// current transaction, returns [`TempoPrecompileError::ReentrantCall`] without
+/// invoking `f`. The guard is released when `f` returns, so sequential calls
+/// within one transaction are unaffected.
+///
+/// # SPEC
+/// Only active T4+; on earlier hardforks this is a pass-through so historical
+/// gas usage and state roots are preserved.
+pub fn with_reentrancy_guard<R>(address: Address, f: impl FnOnce() -> Result<R>) -> Result<R> {
+    let mut storage = StorageCtx;
+    if !storage.spec().is_t4() {
+        return f();
+    }
+
+    if storage.tload(address, REENTRANCY_GUARD_SLOT)? != U256::ZERO {
+        return Err(TempoPrecompileError::ReentrantCall);
+    }
+    storage.tstore(address, REENTRANCY_GUARD_SLOT, U256::from(1))?;
+
+    let result = f();
+
+    // Always release so a reverted-and-caught inner call cannot poison later
+    // calls in the same transaction.
+    storage.tstore(address, REENTRANCY_GUARD_SLOT, U256::ZERO)?;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::hashmap::HashMapStorageProvider;
+    use tempo_chainspec::hardfork::TempoHardfork;
+
+    #[test]
+    fn test_nested_guarded_call_is_blocked() {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        let address = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let result = with_reentrancy_guard(address, || {
+                // Simulates a nested precompile call re-entering the same
+                // guarded entrypoint (e.g. via a token hook).
+                with_reentrancy_guard(address, || Ok(()))
+            });
+            assert_eq!(result, Err(TempoPrecompileError::ReentrantCall));
+        });
+    }
+
+    #[test]
+    fn test_sequential_calls_are_allowed() {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        let address = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            assert!(with_reentrancy_guard(address, || Ok(())).is_ok());
+            // The guard must be released after the first call returns.
+            assert!(with_reentrancy_guard(address, || Ok(())).is_ok());
+        });
+    }
+
+    #[test]
+    fn test_guard_is_released_after_inner_error() {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        let address = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let result: Result<()> = with_reentrancy_guard(address, || {
+                Err(TempoPrecompileError::Fatal("boom".to_string()))
+            });
+            assert!(result.is_err());
+            // A failed call must not leave the guard set.
+            assert!(with_reentrancy_guard(address, || Ok(())).is_ok());
+        });
+    }
+
+    #[test]
+    fn test_guards_are_scoped_per_address() {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        let dex = Address::random();
+        let fee_manager = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let result = with_reentrancy_guard(dex, || {
+                // Entering a different precompile's guard is not reentrancy.
+                with_reentrancy_guard(fee_manager, || Ok(()))
+            });
+            assert!(result.is_ok());
+        });
+    }
+
+    #[test]
+    fn test_guard_is_inactive_before_t4() {
+        let mut storage = HashMapStorageProvider::new(1); // default = T0
+        let address = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            // Pre-T4 the guard is a pass-through, even when nested.
+            let result =
+                with_reentrancy_guard(address, || with_reentrancy_guard(address, || Ok(())));
+            assert!(result.is_ok());
+        });
+    }
+}
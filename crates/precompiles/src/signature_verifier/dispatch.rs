@@ -1,5 +1,5 @@
 use super::SignatureVerifier;
-use crate::{Precompile, charge_input_cost, dispatch_call, view};
+use crate::{Precompile, charge_input_cost, dispatch_call, interface_id, view};
 use alloy::{primitives::Address, sol_types::SolInterface};
 use revm::precompile::PrecompileResult;
 use tempo_contracts::precompiles::{
@@ -13,6 +13,9 @@ use tempo_primitives::MAX_WEBAUTHN_SIGNATURE_LENGTH;
 const MAX_CALLDATA_LEN: usize =
     4 + 32 * 4 + (MAX_WEBAUTHN_SIGNATURE_LENGTH + 1).next_multiple_of(32);
 
+/// ERC-165 interface ids served by `supportsInterface` (T4+).
+const INTERFACE_IDS: &[[u8; 4]] = &[interface_id(ISVCalls::SELECTORS)];
+
 impl Precompile for SignatureVerifier {
     fn call(&mut self, calldata: &[u8], _msg_sender: Address) -> PrecompileResult {
         if let Some(err) = charge_input_cost(&mut self.storage, calldata) {
@@ -25,12 +28,18 @@ impl Precompile for SignatureVerifier {
                 .abi_revert(SignatureVerifierError::invalid_format()));
         }
 
-        dispatch_call(calldata, &[], ISVCalls::abi_decode, |call| match call {
-            ISVCalls::recover(call) => view(call, |c| self.recover(c.hash, c.signature)),
-            ISVCalls::verify(call) => view(call, |c| {
-                self.recover(c.hash, c.signature).map(|sig| sig == c.signer)
-            }),
-        })
+        dispatch_call(
+            calldata,
+            &[],
+            INTERFACE_IDS,
+            ISVCalls::abi_decode,
+            |call| match call {
+                ISVCalls::recover(call) => view(call, |c| self.recover(c.hash, c.signature)),
+                ISVCalls::verify(call) => view(call, |c| {
+                    self.recover(c.hash, c.signature).map(|sig| sig == c.signer)
+                }),
+            },
+        )
     }
 }
 
@@ -1,15 +1,42 @@
 //! ABI dispatch for the [`StablecoinDEX`] precompile.
 
-use alloy::{primitives::Address, sol_types::SolInterface};
+use alloy::{
+    primitives::Address,
+    sol_types::{SolCall, SolInterface},
+};
 use revm::precompile::PrecompileResult;
-use tempo_contracts::precompiles::IStablecoinDEX::IStablecoinDEXCalls;
+use tempo_chainspec::hardfork::TempoHardfork;
+use tempo_contracts::precompiles::IStablecoinDEX::{self, IStablecoinDEXCalls};
 
 use crate::{
-    Precompile, charge_input_cost, dispatch_call, mutate, mutate_void,
+    Precompile, SelectorSchedule, charge_input_cost, dispatch_call, interface_id, mutate,
+    mutate_void,
+    runtime::with_reentrancy_guard,
     stablecoin_dex::{StablecoinDEX, orderbook::compute_book_key},
     view,
 };
 
+const T4_ADDED: &[[u8; 4]] = &[
+    IStablecoinDEX::getPairConfigCall::SELECTOR,
+    IStablecoinDEX::setPairFeeCall::SELECTOR,
+    IStablecoinDEX::collectedFeesCall::SELECTOR,
+    IStablecoinDEX::positionOfCall::SELECTOR,
+    IStablecoinDEX::accruedFeesCall::SELECTOR,
+    IStablecoinDEX::collectFeesCall::SELECTOR,
+    IStablecoinDEX::orderNoncesCall::SELECTOR,
+    IStablecoinDEX::placeWithSignatureCall::SELECTOR,
+    IStablecoinDEX::invalidateOrderNonceCall::SELECTOR,
+    IStablecoinDEX::swapWithMinOutCall::SELECTOR,
+    IStablecoinDEX::quoteCall::SELECTOR,
+    IStablecoinDEX::placeWithExpiryCall::SELECTOR,
+    IStablecoinDEX::expireOrdersCall::SELECTOR,
+    IStablecoinDEX::orderExpiryCall::SELECTOR,
+    IStablecoinDEX::consultTwapCall::SELECTOR,
+];
+
+/// ERC-165 interface ids served by `supportsInterface` (T4+).
+const INTERFACE_IDS: &[[u8; 4]] = &[interface_id(IStablecoinDEXCalls::SELECTORS)];
+
 impl Precompile for StablecoinDEX {
     fn call(&mut self, calldata: &[u8], msg_sender: Address) -> PrecompileResult {
         if let Some(err) = charge_input_cost(&mut self.storage, calldata) {
@@ -18,15 +45,31 @@ impl Precompile for StablecoinDEX {
 
         dispatch_call(
             calldata,
-            &[],
+            &[SelectorSchedule::new(TempoHardfork::T4).with_added(T4_ADDED)],
+            INTERFACE_IDS,
             IStablecoinDEXCalls::abi_decode,
             |call| match call {
                 IStablecoinDEXCalls::place(call) => mutate(call, msg_sender, |s, c| {
-                    self.place(s, c.token, c.amount, c.isBid, c.tick)
+                    with_reentrancy_guard(self.address, || {
+                        self.place(s, c.token, c.amount, c.isBid, c.tick)
+                    })
                 }),
                 IStablecoinDEXCalls::placeFlip(call) => mutate(call, msg_sender, |s, c| {
-                    self.place_flip(s, c.token, c.amount, c.isBid, c.tick, c.flipTick, false)
+                    with_reentrancy_guard(self.address, || {
+                        self.place_flip(s, c.token, c.amount, c.isBid, c.tick, c.flipTick, false)
+                    })
+                }),
+                IStablecoinDEXCalls::placeWithExpiry(call) => mutate(call, msg_sender, |s, c| {
+                    with_reentrancy_guard(self.address, || {
+                        self.place_with_expiry(s, c.token, c.amount, c.isBid, c.tick, c.expiry)
+                    })
                 }),
+                IStablecoinDEXCalls::expireOrders(call) => {
+                    mutate_void(call, msg_sender, |_, c| self.expire_orders(&c.orderIds))
+                }
+                IStablecoinDEXCalls::orderExpiry(call) => {
+                    view(call, |c| self.order_expiry(c.orderId))
+                }
                 IStablecoinDEXCalls::balanceOf(call) => {
                     view(call, |c| self.balance_of(c.user, c.token))
                 }
@@ -37,19 +80,55 @@ impl Precompile for StablecoinDEX {
                     let level = self.get_price_level(c.base, c.tick, c.isBid)?;
                     Ok((level.head, level.tail, level.total_liquidity).into())
                 }),
+                IStablecoinDEXCalls::consultTwap(call) => {
+                    view(call, |c| self.consult_twap(c.tokenA, c.tokenB, c.window))
+                }
                 IStablecoinDEXCalls::pairKey(call) => {
                     view(call, |c| Ok(compute_book_key(c.tokenA, c.tokenB)))
                 }
                 IStablecoinDEXCalls::books(call) => {
                     view(call, |c| self.books(c.pairKey).map(Into::into))
                 }
+                IStablecoinDEXCalls::getPairConfig(call) => {
+                    view(call, |c| self.get_pair_config(c.pairKey))
+                }
+                IStablecoinDEXCalls::setPairFee(call) => mutate_void(call, msg_sender, |s, c| {
+                    self.set_pair_fee(s, c.pairKey, c.feeBps)
+                }),
+                IStablecoinDEXCalls::collectedFees(call) => {
+                    view(call, |c| self.collected_fees(c.token))
+                }
+                IStablecoinDEXCalls::positionOf(call) => {
+                    view(call, |c| self.position_of(c.maker, c.base))
+                }
+                IStablecoinDEXCalls::accruedFees(call) => {
+                    view(call, |c| self.accrued_fees(c.maker, c.base).map(Into::into))
+                }
+                IStablecoinDEXCalls::collectFees(call) => {
+                    mutate_void(call, msg_sender, |s, c| self.collect_fees(s, c.base))
+                }
+                IStablecoinDEXCalls::orderNonces(call) => {
+                    view(call, |c| self.order_nonces(c.maker))
+                }
+                IStablecoinDEXCalls::placeWithSignature(call) => {
+                    mutate(call, msg_sender, |_, c| {
+                        with_reentrancy_guard(self.address, || {
+                            self.place_with_signature(&c.order, c.v, c.r, c.s)
+                        })
+                    })
+                }
+                IStablecoinDEXCalls::invalidateOrderNonce(call) => {
+                    mutate_void(call, msg_sender, |s, c| {
+                        self.invalidate_order_nonce(s, c.newNonce)
+                    })
+                }
                 IStablecoinDEXCalls::nextOrderId(call) => view(call, |_| self.next_order_id()),
                 IStablecoinDEXCalls::createPair(call) => {
                     mutate(call, msg_sender, |_, c| self.create_pair(c.base))
                 }
-                IStablecoinDEXCalls::withdraw(call) => {
-                    mutate_void(call, msg_sender, |s, c| self.withdraw(s, c.token, c.amount))
-                }
+                IStablecoinDEXCalls::withdraw(call) => mutate_void(call, msg_sender, |s, c| {
+                    with_reentrancy_guard(self.address, || self.withdraw(s, c.token, c.amount))
+                }),
                 IStablecoinDEXCalls::cancel(call) => {
                     mutate_void(call, msg_sender, |s, c| self.cancel(s, c.orderId))
                 }
@@ -57,22 +136,47 @@ impl Precompile for StablecoinDEX {
                     mutate_void(call, msg_sender, |_, c| self.cancel_stale_order(c.orderId))
                 }
                 IStablecoinDEXCalls::swapExactAmountIn(call) => mutate(call, msg_sender, |s, c| {
-                    self.swap_exact_amount_in(s, c.tokenIn, c.tokenOut, c.amountIn, c.minAmountOut)
+                    with_reentrancy_guard(self.address, || {
+                        self.swap_exact_amount_in(
+                            s,
+                            c.tokenIn,
+                            c.tokenOut,
+                            c.amountIn,
+                            c.minAmountOut,
+                        )
+                    })
                 }),
                 IStablecoinDEXCalls::swapExactAmountOut(call) => {
                     mutate(call, msg_sender, |s, c| {
-                        self.swap_exact_amount_out(
+                        with_reentrancy_guard(self.address, || {
+                            self.swap_exact_amount_out(
+                                s,
+                                c.tokenIn,
+                                c.tokenOut,
+                                c.amountOut,
+                                c.maxAmountIn,
+                            )
+                        })
+                    })
+                }
+                IStablecoinDEXCalls::swapWithMinOut(call) => mutate(call, msg_sender, |s, c| {
+                    with_reentrancy_guard(self.address, || {
+                        self.swap_with_min_out(
                             s,
                             c.tokenIn,
                             c.tokenOut,
-                            c.amountOut,
-                            c.maxAmountIn,
+                            c.amountIn,
+                            c.minAmountOut,
+                            c.deadline,
                         )
                     })
-                }
+                }),
                 IStablecoinDEXCalls::quoteSwapExactAmountIn(call) => view(call, |c| {
                     self.quote_swap_exact_amount_in(c.tokenIn, c.tokenOut, c.amountIn)
                 }),
+                IStablecoinDEXCalls::quote(call) => {
+                    view(call, |c| self.quote(c.tokenIn, c.tokenOut, c.amountIn))
+                }
                 IStablecoinDEXCalls::quoteSwapExactAmountOut(call) => view(call, |c| {
                     self.quote_swap_exact_amount_out(c.tokenIn, c.tokenOut, c.amountOut)
                 }),
@@ -109,15 +213,19 @@ mod tests {
 
     use crate::{
         Precompile,
+        runtime::with_reentrancy_guard,
         stablecoin_dex::{IStablecoinDEX, MIN_ORDER_AMOUNT, StablecoinDEX},
         storage::{ContractStorage, StorageCtx, hashmap::HashMapStorageProvider},
         test_util::{TIP20Setup, assert_full_coverage, check_selector_coverage},
     };
     use alloy::{
         primitives::{Address, U256},
-        sol_types::{SolCall, SolValue},
+        sol_types::{SolCall, SolError, SolValue},
+    };
+    use tempo_chainspec::hardfork::TempoHardfork;
+    use tempo_contracts::precompiles::{
+        IStablecoinDEX::IStablecoinDEXCalls, ReentrantCall, UnknownFunctionSelector,
     };
-    use tempo_contracts::precompiles::IStablecoinDEX::IStablecoinDEXCalls;
 
     /// Setup a basic exchange with tokens and liquidity for swap tests
     fn setup_exchange_with_liquidity() -> eyre::Result<(StablecoinDEX, Address, Address, Address)> {
@@ -465,9 +573,103 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_guarded_entrypoint_rejects_reentrant_dispatch() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinDEX::new();
+            exchange.initialize()?;
+
+            let sender = Address::random();
+            let calldata = IStablecoinDEX::withdrawCall {
+                token: Address::random(),
+                amount: 100u128,
+            }
+            .abi_encode();
+
+            // Simulates a nested precompile call re-entering the DEX while a
+            // guarded entrypoint is still executing.
+            let output =
+                with_reentrancy_guard(exchange.address, || Ok(exchange.call(&calldata, sender)))??;
+            assert!(output.is_revert());
+            assert!(ReentrantCall::abi_decode(&output.bytes).is_ok());
+
+            // Once the guard is released, the same call dispatches normally
+            // and fails only on business logic, not on reentrancy.
+            let output = exchange.call(&calldata, sender)?;
+            assert!(ReentrantCall::abi_decode(&output.bytes).is_err());
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_fee_config_selectors_gated_behind_t4() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T3);
+        StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinDEX::new();
+            exchange.initialize()?;
+
+            let sender = Address::random();
+            let calldata = IStablecoinDEX::getPairConfigCall {
+                pairKey: Default::default(),
+            }
+            .abi_encode();
+
+            let result = exchange.call(&calldata, sender)?;
+            assert!(result.is_revert());
+            assert!(UnknownFunctionSelector::abi_decode(&result.bytes).is_ok());
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_consult_twap_selector_gated_behind_t4() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T3);
+        StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinDEX::new();
+            exchange.initialize()?;
+
+            let calldata = IStablecoinDEX::consultTwapCall {
+                tokenA: Address::random(),
+                tokenB: Address::random(),
+                window: 600,
+            }
+            .abi_encode();
+
+            let result = exchange.call(&calldata, Address::random())?;
+            assert!(result.is_revert());
+            assert!(UnknownFunctionSelector::abi_decode(&result.bytes).is_ok());
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_get_pair_config_call() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        StorageCtx::enter(&mut storage, || {
+            let (mut exchange, base_token, quote_token, _user) = setup_exchange_with_liquidity()?;
+            let pair_key =
+                crate::stablecoin_dex::orderbook::compute_book_key(base_token, quote_token);
+
+            let result = exchange.call(
+                &IStablecoinDEX::getPairConfigCall { pairKey: pair_key }.abi_encode(),
+                Address::random(),
+            )?;
+            let config = IStablecoinDEX::PairConfig::abi_decode(&result.bytes)?;
+            assert_eq!(config.feeBps, crate::stablecoin_dex::DEFAULT_FEE_BPS);
+            assert!(config.isDefault);
+
+            Ok(())
+        })
+    }
+
     #[test]
     fn stablecoin_dex_test_selector_coverage() -> eyre::Result<()> {
-        let mut storage = HashMapStorageProvider::new(1);
+        // Run at T4 so the fee-configuration and gasless-order selectors are active.
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
         StorageCtx::enter(&mut storage, || {
             let mut exchange = StablecoinDEX::new();
 
@@ -9,6 +9,7 @@ pub mod dispatch;
 pub mod error;
 pub mod order;
 pub mod orderbook;
+pub mod twap;
 
 pub use order::Order;
 pub use orderbook::{
@@ -17,17 +18,24 @@ pub use orderbook::{
 };
 use tempo_contracts::precompiles::PATH_USD_ADDRESS;
 pub use tempo_contracts::precompiles::{IStablecoinDEX, StablecoinDEXError, StablecoinDEXEvents};
+pub use twap::{
+    MAX_TWAP_WINDOW, TWAP_GRANULARITY, TWAP_OBSERVATION_PERIOD, TwapCheckpoint, TwapState,
+};
 
 use crate::{
     STABLECOIN_DEX_ADDRESS,
     error::{Result, TempoPrecompileError},
     stablecoin_dex::orderbook::{MAX_PRICE, MIN_PRICE, compute_book_key},
     storage::{Handler, Mapping},
-    tip20::{ITIP20, TIP20Token, validate_usd_currency},
+    tip20::{ITIP20, TIP20Token, roles::DEFAULT_ADMIN_ROLE, validate_usd_currency},
     tip20_factory::TIP20Factory,
     tip403_registry::{AuthRole, TIP403Registry, is_policy_lookup_error},
 };
-use alloy::primitives::{Address, B256, U256};
+use alloy::{
+    primitives::{Address, B256, U256, keccak256},
+    sol_types::SolValue,
+};
+use std::sync::LazyLock;
 use tempo_precompiles_macros::contract;
 use tempo_primitives::TempoAddressExt;
 
@@ -37,6 +45,36 @@ pub const MIN_ORDER_AMOUNT: u128 = 100_000_000;
 /// Allowed tick spacing for order placement
 pub const TICK_SPACING: i16 = 10;
 
+/// Default taker fee tier for pairs without an explicit configuration (0.05%).
+pub const DEFAULT_FEE_BPS: u16 = 5;
+
+/// Maximum taker fee tier governance may configure (1%).
+pub const MAX_FEE_BPS: u16 = 100;
+
+/// Basis-point denominator for fee math.
+const BPS_DENOMINATOR: u128 = 10_000;
+
+/// Fixed-point scale for the per-book maker fee-growth accumulators.
+const FEE_GROWTH_SCALE: u128 = 1 << 64;
+
+/// EIP-712 typehash for off-chain signed orders (T4+).
+pub static SIGNED_ORDER_TYPEHASH: LazyLock<B256> = LazyLock::new(|| {
+    keccak256(
+        b"SignedOrder(address maker,address token,uint128 amount,bool isBid,int16 tick,uint256 nonce,uint256 deadline)",
+    )
+});
+
+/// EIP-712 domain separator typehash.
+pub static EIP712_DOMAIN_TYPEHASH: LazyLock<B256> = LazyLock::new(|| {
+    keccak256(b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)")
+});
+
+/// EIP-712 domain name hash: keccak256("StablecoinDEX")
+pub static DOMAIN_NAME_HASH: LazyLock<B256> = LazyLock::new(|| keccak256(b"StablecoinDEX"));
+
+/// EIP-712 version hash: keccak256("1")
+pub static VERSION_HASH: LazyLock<B256> = LazyLock::new(|| keccak256(b"1"));
+
 /// On-chain CLOB (Central Limit Order Book) for stablecoin trading.
 ///
 /// Supports limit orders, market swaps, and flip orders across USD-denominated TIP-20 token pairs.
@@ -51,6 +89,30 @@ pub struct StablecoinDEX {
     balances: Mapping<Address, Mapping<Address, u128>>,
     next_order_id: u128,
     book_keys: Vec<B256>,
+    twap_states: Mapping<B256, TwapState>,
+    twap_checkpoints: Mapping<B256, Mapping<u64, TwapCheckpoint>>,
+    /// Per-pair fee tier, stored shifted by one so zero keeps meaning
+    /// "unconfigured" (default tier). Pairs created before fee tiers existed
+    /// migrate lazily through that sentinel.
+    pair_fee_bps: Mapping<B256, u16>,
+    /// Taker fees withheld by the DEX, per token.
+    collected_fees: Mapping<Address, u128>,
+    /// Next valid nonce per maker for off-chain signed orders.
+    order_nonces: Mapping<Address, U256>,
+    /// Base-denominated resting liquidity per book per maker (T4+).
+    maker_liquidity: Mapping<B256, Mapping<Address, u128>>,
+    /// Total resting maker liquidity per book (T4+).
+    total_maker_liquidity: Mapping<B256, u128>,
+    /// Cumulative fees per unit of resting liquidity, per book per fee
+    /// token, scaled by [`FEE_GROWTH_SCALE`] (T4+).
+    fee_growth: Mapping<B256, Mapping<Address, U256>>,
+    /// Per-maker snapshot of [`Self::fee_growth`] taken at the last
+    /// settlement, per book per fee token (T4+).
+    fee_growth_snapshots: Mapping<B256, Mapping<Address, Mapping<Address, U256>>>,
+    /// Expiry timestamp (Unix seconds) per order ID, zero meaning the order
+    /// never expires. Kept outside [`Order`] so pre-existing orders keep
+    /// their storage layout (T4+).
+    order_expiries: Mapping<u128, u64>,
 }
 
 impl StablecoinDEX {
@@ -234,6 +296,9 @@ impl StablecoinDEX {
         // Execute quotes backwards from output to input
         let mut current_amount = amount_out;
         for (book_key, base_for_quote) in route.iter().rev() {
+            // Mirror the execution path: the book must fill the pre-fee gross.
+            current_amount =
+                Self::gross_up_output(current_amount, self.effective_fee_bps(*book_key)?)?;
             current_amount = self.quote_exact_out(*book_key, current_amount, *base_for_quote)?;
         }
 
@@ -261,6 +326,9 @@ impl StablecoinDEX {
         let mut current_amount = amount_in;
         for (book_key, base_for_quote) in route {
             current_amount = self.quote_exact_in(book_key, current_amount, base_for_quote)?;
+            // Mirror the execution path: the taker's fee comes out of the gross output.
+            current_amount -=
+                Self::fee_on_output(current_amount, self.effective_fee_bps(book_key)?);
         }
 
         Ok(current_amount)
@@ -294,6 +362,19 @@ impl StablecoinDEX {
         for (book_key, base_for_quote) in route {
             // Fill orders for this hop - no min check on intermediate hops
             amount = self.fill_orders_exact_in(book_key, base_for_quote, amount, sender)?;
+            // Withhold the hop's taker fee from the gross output (T4+).
+            let fee_bps = self.effective_fee_bps(book_key)?;
+            if fee_bps != 0 {
+                let book = self.books[book_key].read()?;
+                let hop_token_out = if base_for_quote {
+                    book.quote
+                } else {
+                    book.base
+                };
+                let fee = Self::fee_on_output(amount, fee_bps);
+                self.accrue_fee(book_key, hop_token_out, fee)?;
+                amount -= fee;
+            }
         }
 
         // Check final output meets minimum requirement
@@ -306,6 +387,51 @@ impl StablecoinDEX {
         Ok(amount)
     }
 
+    /// Quotes the output of [`Self::swap_with_min_out`] without executing it.
+    ///
+    /// Thin alias of [`Self::quote_swap_exact_amount_in`] so integrators get a
+    /// `quote`/`swapWithMinOut` pair backed by the same routing and fee math:
+    /// a quote followed by a swap in the same block returns the quoted amount
+    /// exactly (liquidity permitting), with no off-chain reimplementation of
+    /// the pricing formula.
+    ///
+    /// # Errors
+    /// - `IdenticalTokens` — `token_in` and `token_out` are the same address
+    /// - `InvalidToken` — a token address does not have a valid TIP-20 prefix
+    /// - `PairDoesNotExist` — no orderbook exists for one of the hops in the route
+    /// - `InsufficientLiquidity` — not enough resting orders to fill `amount_in`
+    pub fn quote(&self, token_in: Address, token_out: Address, amount_in: u128) -> Result<u128> {
+        self.quote_swap_exact_amount_in(token_in, token_out, amount_in)
+    }
+
+    /// Swaps `amount_in` of `token_in` for `token_out` like
+    /// [`Self::swap_exact_amount_in`], additionally enforcing a `deadline`
+    /// (Unix seconds): the swap reverts once the block timestamp passes it, so
+    /// a transaction stuck in the mempool cannot execute against a stale
+    /// quote with only slippage protecting it.
+    ///
+    /// # Errors
+    /// - `SwapDeadlineExpired` — current timestamp exceeds `deadline`
+    /// - `InvalidBaseToken` — token address does not have a valid TIP-20 prefix
+    /// - `PairNotFound` — no orderbook exists for the token pair
+    /// - `InsufficientOutput` — final output amount falls below `min_amount_out`
+    /// - `InsufficientBalance` — sender balance lower than required input
+    pub fn swap_with_min_out(
+        &mut self,
+        sender: Address,
+        token_in: Address,
+        token_out: Address,
+        amount_in: u128,
+        min_amount_out: u128,
+        deadline: u64,
+    ) -> Result<u128> {
+        if self.storage.timestamp() > U256::from(deadline) {
+            return Err(StablecoinDEXError::swap_deadline_expired().into());
+        }
+
+        self.swap_exact_amount_in(sender, token_in, token_out, amount_in, min_amount_out)
+    }
+
     /// Swaps to receive exactly `amount_out` of `token_out`, routing
     /// through one or more orderbooks. Works backwards from output to
     /// compute input, then deducts via [`TIP20Token`] or DEX balance.
@@ -329,6 +455,20 @@ impl StablecoinDEX {
         // Work backwards from output to calculate input needed - intermediate amounts are TRANSITORY
         let mut amount = amount_out;
         for (book_key, base_for_quote) in route.iter().rev() {
+            // Fill enough gross output that the hop still nets `amount` after
+            // the taker fee is withheld (T4+).
+            let fee_bps = self.effective_fee_bps(*book_key)?;
+            if fee_bps != 0 {
+                let gross = Self::gross_up_output(amount, fee_bps)?;
+                let book = self.books[*book_key].read()?;
+                let hop_token_out = if *base_for_quote {
+                    book.quote
+                } else {
+                    book.base
+                };
+                self.accrue_fee(*book_key, hop_token_out, gross - amount)?;
+                amount = gross;
+            }
             amount = self.fill_orders_exact_out(*book_key, *base_for_quote, amount, sender)?;
         }
 
@@ -365,11 +505,531 @@ impl StablecoinDEX {
         self.books[pair_key].read()
     }
 
+    /// Returns the fee configuration of an existing pair.
+    ///
+    /// Pairs that governance never configured — including every pair created
+    /// before fee tiers existed — report the default tier with `isDefault`
+    /// set, so activation did not require rewriting each pair's storage.
+    ///
+    /// # Errors
+    /// - `PairDoesNotExist` — no orderbook exists for the pair key
+    pub fn get_pair_config(&self, pair_key: B256) -> Result<IStablecoinDEX::PairConfig> {
+        if !self.books[pair_key].read()?.is_initialized() {
+            return Err(StablecoinDEXError::pair_does_not_exist().into());
+        }
+        Ok(match self.stored_fee_bps(pair_key)? {
+            Some(fee_bps) => IStablecoinDEX::PairConfig {
+                feeBps: fee_bps,
+                isDefault: false,
+            },
+            None => IStablecoinDEX::PairConfig {
+                feeBps: DEFAULT_FEE_BPS,
+                isDefault: true,
+            },
+        })
+    }
+
+    /// Sets the taker fee tier of an existing pair. Only the quote token's
+    /// `DEFAULT_ADMIN_ROLE` holder may call this, e.g. to move a
+    /// stable-stable pair from the default tier down to 1bp.
+    ///
+    /// # Errors
+    /// - `InvalidFee` — `fee_bps` exceeds [`MAX_FEE_BPS`]
+    /// - `PairDoesNotExist` — no orderbook exists for the pair key
+    /// - `Unauthorized` — caller does not hold the quote token's admin role
+    pub fn set_pair_fee(&mut self, sender: Address, pair_key: B256, fee_bps: u16) -> Result<()> {
+        if fee_bps > MAX_FEE_BPS {
+            return Err(StablecoinDEXError::invalid_fee().into());
+        }
+        let book = self.books[pair_key].read()?;
+        if !book.is_initialized() {
+            return Err(StablecoinDEXError::pair_does_not_exist().into());
+        }
+        TIP20Token::from_address(book.quote)?.check_role(sender, DEFAULT_ADMIN_ROLE)?;
+
+        self.pair_fee_bps[pair_key].write(fee_bps + 1)?;
+        self.emit_event(StablecoinDEXEvents::PairFeeUpdated(
+            IStablecoinDEX::PairFeeUpdated {
+                key: pair_key,
+                feeBps: fee_bps,
+            },
+        ))
+    }
+
+    /// Total taker fees withheld by the DEX in `token`.
+    pub fn collected_fees(&self, token: Address) -> Result<u128> {
+        self.collected_fees[token].read()
+    }
+
+    /// Returns the maker's next valid signed-order nonce.
+    pub fn order_nonces(&self, maker: Address) -> Result<U256> {
+        self.order_nonces[maker].read()
+    }
+
+    /// Returns the expiry timestamp of an order, zero if it never expires.
+    pub fn order_expiry(&self, order_id: u128) -> Result<u64> {
+        self.order_expiries[order_id].read()
+    }
+
+    /// Returns `true` if the order carries an expiry and the block timestamp
+    /// has passed it.
+    fn is_order_expired(&self, order_id: u128) -> Result<bool> {
+        let expiry = self.order_expiries[order_id].read()?;
+        Ok(expiry != 0 && self.storage.timestamp() > U256::from(expiry))
+    }
+
+    /// Returns the EIP-712 domain separator for signed orders, computed
+    /// dynamically from the chain ID.
+    pub fn domain_separator(&self) -> Result<B256> {
+        let encoded = (
+            *EIP712_DOMAIN_TYPEHASH,
+            *DOMAIN_NAME_HASH,
+            *VERSION_HASH,
+            U256::from(self.storage.chain_id()),
+            self.address,
+        )
+            .abi_encode();
+        self.storage.keccak256(&encoded)
+    }
+
+    /// Places a limit order signed off-chain by its maker (EIP-712) and
+    /// submitted by a relayer. Validates the deadline, the maker's nonce,
+    /// and the ECDSA signature, then escrows from the maker's balance
+    /// exactly as if the maker had called [`Self::place`] directly.
+    ///
+    /// # Errors
+    /// - `SignedOrderExpired` — current timestamp exceeds the order deadline
+    /// - `InvalidOrderNonce` — order nonce is not the maker's current one
+    /// - `InvalidOrderSignature` — ECDSA recovery failed or recovered signer ≠ maker
+    /// - plus everything [`Self::place`] can return
+    ///
+    /// # Returns
+    /// The assigned order ID
+    pub fn place_with_signature(
+        &mut self,
+        order: &IStablecoinDEX::SignedOrder,
+        v: u8,
+        r: B256,
+        s: B256,
+    ) -> Result<u128> {
+        // 1. Check deadline
+        if self.storage.timestamp() > order.deadline {
+            return Err(StablecoinDEXError::signed_order_expired().into());
+        }
+
+        // 2. Check nonce. Sequential per maker, so a signed order can only
+        // execute once and stale orders die with their nonce.
+        let nonce = self.order_nonces[order.maker].read()?;
+        if order.nonce != nonce {
+            return Err(StablecoinDEXError::invalid_order_nonce().into());
+        }
+
+        // 3. Construct the EIP-712 digest
+        let struct_hash = self.storage.keccak256(
+            &(
+                *SIGNED_ORDER_TYPEHASH,
+                order.maker,
+                order.token,
+                order.amount,
+                order.isBid,
+                order.tick,
+                order.nonce,
+                order.deadline,
+            )
+                .abi_encode(),
+        )?;
+        let domain_separator = self.domain_separator()?;
+        let digest = self.storage.keccak256(
+            &[
+                &[0x19, 0x01],
+                domain_separator.as_slice(),
+                struct_hash.as_slice(),
+            ]
+            .concat(),
+        )?;
+
+        // 4. Validate ECDSA signature
+        // Only v=27/28 is accepted; v=0/1 is intentionally NOT normalized (see TIP-1004 spec).
+        let recovered = self
+            .storage
+            .recover_signer(digest, v, r, s)?
+            .ok_or(StablecoinDEXError::invalid_order_signature())?;
+        if recovered != order.maker {
+            return Err(StablecoinDEXError::invalid_order_signature().into());
+        }
+
+        // 5. Increment nonce
+        self.order_nonces[order.maker].write(
+            nonce
+                .checked_add(U256::from(1))
+                .ok_or(TempoPrecompileError::under_overflow())?,
+        )?;
+
+        // 6. Place the order on the maker's behalf
+        self.place(
+            order.maker,
+            order.token,
+            order.amount,
+            order.isBid,
+            order.tick,
+        )
+    }
+
+    /// Advances the caller's signed-order nonce to `new_nonce`, cancelling
+    /// every outstanding signed order with a nonce below it. This is how a
+    /// maker revokes an order a relayer has not yet submitted.
+    ///
+    /// # Errors
+    /// - `InvalidOrderNonce` — `new_nonce` does not move the nonce forward
+    pub fn invalidate_order_nonce(&mut self, sender: Address, new_nonce: U256) -> Result<()> {
+        if new_nonce <= self.order_nonces[sender].read()? {
+            return Err(StablecoinDEXError::invalid_order_nonce().into());
+        }
+        self.order_nonces[sender].write(new_nonce)?;
+        self.emit_event(StablecoinDEXEvents::OrderNonceInvalidated(
+            IStablecoinDEX::OrderNonceInvalidated {
+                maker: sender,
+                newNonce: new_nonce,
+            },
+        ))
+    }
+
+    /// Reads the explicitly configured fee tier, undoing the shift-by-one
+    /// sentinel encoding. `None` means the pair trades at the default tier.
+    fn stored_fee_bps(&self, pair_key: B256) -> Result<Option<u16>> {
+        Ok(self.pair_fee_bps[pair_key].read()?.checked_sub(1))
+    }
+
+    /// Effective taker fee for a hop: zero before T4 (fees activate with the
+    /// hardfork), otherwise the configured tier or the default.
+    fn effective_fee_bps(&self, pair_key: B256) -> Result<u16> {
+        if !self.storage.spec().is_t4() {
+            return Ok(0);
+        }
+        Ok(self.stored_fee_bps(pair_key)?.unwrap_or(DEFAULT_FEE_BPS))
+    }
+
+    /// Fee withheld from a gross output amount (rounds down, favoring the taker).
+    fn fee_on_output(amount_out: u128, fee_bps: u16) -> u128 {
+        ((U256::from(amount_out) * U256::from(fee_bps)) / U256::from(BPS_DENOMINATOR))
+            .saturating_to()
+    }
+
+    /// Gross output the book must fill so the taker nets `amount_out` after
+    /// the fee (rounds up, zero-sum with the fee ledger).
+    fn gross_up_output(amount_out: u128, fee_bps: u16) -> Result<u128> {
+        if fee_bps == 0 {
+            return Ok(amount_out);
+        }
+        let gross = (U256::from(amount_out) * U256::from(BPS_DENOMINATOR))
+            .div_ceil(U256::from(BPS_DENOMINATOR - u128::from(fee_bps)));
+        u128::try_from(gross).map_err(|_| TempoPrecompileError::under_overflow())
+    }
+
+    /// Accrues a withheld taker fee on a book.
+    ///
+    /// The fee is distributed pro rata to the maker liquidity resting on the
+    /// book at accrual time. Rounding dust, and the whole fee when the book
+    /// is empty, stays in the DEX-retained [`Self::collected_fees`] ledger.
+    fn accrue_fee(&mut self, book_key: B256, token: Address, amount: u128) -> Result<()> {
+        if amount == 0 {
+            return Ok(());
+        }
+
+        let mut retained = amount;
+        let total = self.total_maker_liquidity[book_key].read()?;
+        if total != 0 {
+            let growth_delta =
+                (U256::from(amount) * U256::from(FEE_GROWTH_SCALE)) / U256::from(total);
+            let distributed = ((growth_delta * U256::from(total)) / U256::from(FEE_GROWTH_SCALE))
+                .saturating_to::<u128>();
+
+            let growth = self.fee_growth[book_key][token].read()?;
+            self.fee_growth[book_key][token].write(
+                growth
+                    .checked_add(growth_delta)
+                    .ok_or(TempoPrecompileError::under_overflow())?,
+            )?;
+            retained = amount - distributed;
+        }
+
+        if retained == 0 {
+            return Ok(());
+        }
+        let current = self.collected_fees[token].read()?;
+        self.collected_fees[token].write(
+            current
+                .checked_add(retained)
+                .ok_or(TempoPrecompileError::under_overflow())?,
+        )
+    }
+
+    /// Fees earned by `maker` on `token` for the book since their last
+    /// settlement, computed from the growth accumulator.
+    fn pending_maker_fees(&self, book_key: B256, maker: Address, token: Address) -> Result<u128> {
+        let liquidity = self.maker_liquidity[book_key][maker].read()?;
+        if liquidity == 0 {
+            return Ok(0);
+        }
+        let growth = self.fee_growth[book_key][token].read()?;
+        let snapshot = self.fee_growth_snapshots[book_key][maker][token].read()?;
+        Ok(((growth.saturating_sub(snapshot)) * U256::from(liquidity)
+            / U256::from(FEE_GROWTH_SCALE))
+        .saturating_to())
+    }
+
+    /// Credits `maker`'s earned fees on the book to their DEX balance and
+    /// advances their growth snapshots.
+    ///
+    /// Runs before every change to the maker's resting liquidity so the
+    /// pro-rata weight never drifts from what the fees accrued against.
+    fn settle_maker_fees(&mut self, book_key: B256, maker: Address) -> Result<()> {
+        if !self.storage.spec().is_t4() {
+            return Ok(());
+        }
+
+        let orderbook = self.books[book_key].read()?;
+        for token in [orderbook.base, orderbook.quote] {
+            let owed = self.pending_maker_fees(book_key, maker, token)?;
+            if owed != 0 {
+                self.increment_balance(maker, token, owed)?;
+                self.emit_event(StablecoinDEXEvents::FeesCollected(
+                    IStablecoinDEX::FeesCollected {
+                        maker,
+                        key: book_key,
+                        token,
+                        amount: owed,
+                    },
+                ))?;
+            }
+            let growth = self.fee_growth[book_key][token].read()?;
+            if self.fee_growth_snapshots[book_key][maker][token].read()? != growth {
+                self.fee_growth_snapshots[book_key][maker][token].write(growth)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Settles `maker` and adds `amount` to their resting liquidity on the
+    /// book. No-op before T4.
+    fn add_maker_liquidity(&mut self, book_key: B256, maker: Address, amount: u128) -> Result<()> {
+        if !self.storage.spec().is_t4() {
+            return Ok(());
+        }
+        self.settle_maker_fees(book_key, maker)?;
+
+        let liquidity = self.maker_liquidity[book_key][maker].read()?;
+        self.maker_liquidity[book_key][maker].write(
+            liquidity
+                .checked_add(amount)
+                .ok_or(TempoPrecompileError::under_overflow())?,
+        )?;
+        let total = self.total_maker_liquidity[book_key].read()?;
+        self.total_maker_liquidity[book_key].write(
+            total
+                .checked_add(amount)
+                .ok_or(TempoPrecompileError::under_overflow())?,
+        )
+    }
+
+    /// Settles `maker` and removes `amount` from their resting liquidity on
+    /// the book. No-op before T4.
+    ///
+    /// Subtraction saturates: orders resting across the T4 activation were
+    /// never added to the position ledger, so fills and cancels may remove
+    /// more than was tracked.
+    fn remove_maker_liquidity(
+        &mut self,
+        book_key: B256,
+        maker: Address,
+        amount: u128,
+    ) -> Result<()> {
+        if !self.storage.spec().is_t4() {
+            return Ok(());
+        }
+        self.settle_maker_fees(book_key, maker)?;
+
+        let liquidity = self.maker_liquidity[book_key][maker].read()?;
+        let removed = liquidity.min(amount);
+        self.maker_liquidity[book_key][maker].write(liquidity - removed)?;
+        let total = self.total_maker_liquidity[book_key].read()?;
+        self.total_maker_liquidity[book_key].write(total.saturating_sub(removed))
+    }
+
+    /// Returns `maker`'s position on the book of `base`: resting liquidity
+    /// plus fees earned but not yet credited to their DEX balance.
+    ///
+    /// # Errors
+    /// - `InvalidBaseToken` — `base` address does not resolve to a valid [`TIP20Token`]
+    pub fn position_of(
+        &self,
+        maker: Address,
+        base: Address,
+    ) -> Result<IStablecoinDEX::MakerPosition> {
+        let quote = TIP20Token::from_address(base)?.quote_token()?;
+        let book_key = compute_book_key(base, quote);
+        Ok(IStablecoinDEX::MakerPosition {
+            restingLiquidity: self.maker_liquidity[book_key][maker].read()?,
+            pendingBaseFees: self.pending_maker_fees(book_key, maker, base)?,
+            pendingQuoteFees: self.pending_maker_fees(book_key, maker, quote)?,
+        })
+    }
+
+    /// Returns `maker`'s pending fee earnings on the book of `base`, per
+    /// payout token.
+    ///
+    /// # Errors
+    /// - `InvalidBaseToken` — `base` address does not resolve to a valid [`TIP20Token`]
+    pub fn accrued_fees(&self, maker: Address, base: Address) -> Result<(u128, u128)> {
+        let quote = TIP20Token::from_address(base)?.quote_token()?;
+        let book_key = compute_book_key(base, quote);
+        Ok((
+            self.pending_maker_fees(book_key, maker, base)?,
+            self.pending_maker_fees(book_key, maker, quote)?,
+        ))
+    }
+
+    /// Credits the caller's earned fees on the book of `base` to their DEX
+    /// balance, from where [`withdraw`](Self::withdraw) moves them out.
+    ///
+    /// Settlement also happens automatically whenever the caller's resting
+    /// liquidity changes; this entrypoint lets a passive maker realize
+    /// earnings without touching their orders.
+    ///
+    /// # Errors
+    /// - `InvalidBaseToken` — `base` address does not resolve to a valid [`TIP20Token`]
+    pub fn collect_fees(&mut self, sender: Address, base: Address) -> Result<()> {
+        let quote = TIP20Token::from_address(base)?.quote_token()?;
+        let book_key = compute_book_key(base, quote);
+        self.settle_maker_fees(book_key, sender)
+    }
+
     /// Returns all registered orderbook keys.
     pub fn get_book_keys(&self) -> Result<Vec<B256>> {
         self.book_keys.read()
     }
 
+    /// Advances the TWAP accumulator for `book_key` with a trade executed at `tick`.
+    ///
+    /// Called on every fill from T4 onward (writing the accumulator earlier would
+    /// change pre-T4 state roots on replay). The first trade of each
+    /// [`TWAP_OBSERVATION_PERIOD`] also snapshots the cumulative into the checkpoint
+    /// ring so [`consult_twap`](Self::consult_twap) can anchor a window start.
+    fn record_twap_observation(&mut self, book_key: B256, tick: i16) -> Result<()> {
+        if !self.storage.spec().is_t4() {
+            return Ok(());
+        }
+        let now = self.storage.timestamp().saturating_to::<u64>();
+        let state = self.twap_states[book_key].read()?;
+
+        let price_cumulative = if state.is_initialized() {
+            state
+                .cumulative_at(now)
+                .ok_or(TempoPrecompileError::under_overflow())?
+        } else {
+            U256::ZERO
+        };
+
+        self.twap_states[book_key].write(TwapState {
+            price_cumulative,
+            last_price: orderbook::tick_to_price(tick),
+            last_timestamp: now,
+        })?;
+
+        // Only the first trade of an observation period writes the checkpoint, so the
+        // snapshot is as close as possible to the period boundary.
+        let slot = twap::observation_slot(now);
+        let checkpoint = self.twap_checkpoints[book_key][slot].read()?;
+        if twap::observation_epoch(checkpoint.timestamp) != twap::observation_epoch(now) {
+            self.twap_checkpoints[book_key][slot].write(TwapCheckpoint {
+                timestamp: now,
+                price_cumulative,
+            })?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the time-weighted average execution price of `token_a` denominated in
+    /// `token_b` over the trailing `window` seconds, scaled by [`PRICE_SCALE`].
+    ///
+    /// The pair is resolved in either direction; a reversed pair returns the
+    /// reciprocal price. If no trade occurred inside the window, the last trade
+    /// price is returned (the book traded at that price for the whole window).
+    ///
+    /// # Errors
+    /// - `IdenticalTokens` — `token_a` and `token_b` are the same address
+    /// - `InvalidTwapWindow` — `window` is zero or exceeds [`MAX_TWAP_WINDOW`]
+    /// - `PairDoesNotExist` — no orderbook exists for the pair in either direction
+    /// - `InsufficientTwapHistory` — no checkpoint old enough to cover the window
+    pub fn consult_twap(&self, token_a: Address, token_b: Address, window: u32) -> Result<U256> {
+        if token_a == token_b {
+            return Err(StablecoinDEXError::identical_tokens().into());
+        }
+        if window == 0 || u64::from(window) > MAX_TWAP_WINDOW {
+            return Err(StablecoinDEXError::invalid_twap_window().into());
+        }
+
+        // Resolve the book in either direction; reversed pairs report the reciprocal.
+        let direct_key = compute_book_key(token_a, token_b);
+        let (book_key, inverted) = if self.books[direct_key].read()?.is_initialized() {
+            (direct_key, false)
+        } else {
+            let reverse_key = compute_book_key(token_b, token_a);
+            if !self.books[reverse_key].read()?.is_initialized() {
+                return Err(StablecoinDEXError::pair_does_not_exist().into());
+            }
+            (reverse_key, true)
+        };
+
+        let state = self.twap_states[book_key].read()?;
+        if !state.is_initialized() {
+            return Err(StablecoinDEXError::insufficient_twap_history().into());
+        }
+
+        let now = self.storage.timestamp().saturating_to::<u64>();
+        let window_start = now.saturating_sub(u64::from(window));
+
+        let twap = if state.last_timestamp <= window_start {
+            // No trade inside the window: the book traded at `last_price` throughout.
+            U256::from(state.last_price)
+        } else {
+            // Anchor on the oldest checkpoint inside the window.
+            let mut anchor: Option<TwapCheckpoint> = None;
+            for slot in 0..TWAP_GRANULARITY {
+                let checkpoint = self.twap_checkpoints[book_key][slot].read()?;
+                if checkpoint.timestamp == 0
+                    || checkpoint.timestamp < window_start
+                    || checkpoint.timestamp >= now
+                {
+                    continue;
+                }
+                if anchor.is_none_or(|best| checkpoint.timestamp < best.timestamp) {
+                    anchor = Some(checkpoint);
+                }
+            }
+            let anchor = anchor.ok_or_else(StablecoinDEXError::insufficient_twap_history)?;
+
+            let cumulative_now = state
+                .cumulative_at(now)
+                .ok_or(TempoPrecompileError::under_overflow())?;
+            let elapsed = U256::from(now - anchor.timestamp);
+            cumulative_now
+                .checked_sub(anchor.price_cumulative)
+                .ok_or(TempoPrecompileError::under_overflow())?
+                / elapsed
+        };
+
+        if inverted {
+            let scale_squared = U256::from(PRICE_SCALE) * U256::from(PRICE_SCALE);
+            scale_squared
+                .checked_div(twap)
+                .ok_or(TempoPrecompileError::under_overflow())
+        } else {
+            Ok(twap)
+        }
+    }
+
     /// Converts a relative tick to a scaled price. On T2+ validates [`TICK_SPACING`] alignment.
     ///
     /// # Errors
@@ -528,6 +1188,37 @@ impl StablecoinDEX {
         Ok(order_id)
     }
 
+    /// Places a limit order like [`Self::place`], additionally recording an
+    /// `expiry` timestamp (Unix seconds). Once the block timestamp passes it,
+    /// the order no longer trades: matching sweeps it out of the book and
+    /// refunds the escrow, and anyone may remove it via
+    /// [`Self::expire_orders`].
+    ///
+    /// # Errors
+    /// - `InvalidOrderExpiry` — `expiry` is zero or not in the future
+    /// - plus everything [`Self::place`] can return
+    ///
+    /// # Returns
+    /// The assigned order ID
+    pub fn place_with_expiry(
+        &mut self,
+        sender: Address,
+        token: Address,
+        amount: u128,
+        is_bid: bool,
+        tick: i16,
+        expiry: u64,
+    ) -> Result<u128> {
+        if expiry == 0 || self.storage.timestamp() >= U256::from(expiry) {
+            return Err(StablecoinDEXError::invalid_order_expiry().into());
+        }
+
+        let order_id = self.place(sender, token, amount, is_bid, tick)?;
+        self.order_expiries[order_id].write(expiry)?;
+
+        Ok(order_id)
+    }
+
     /// Commits an order to the specified orderbook, updating tick bits, best bid/ask, and total liquidity
     fn commit_order_to_book(&mut self, mut order: Order) -> Result<()> {
         let orderbook = self.books[order.book_key()].read()?;
@@ -574,6 +1265,8 @@ impl StablecoinDEX {
             .tick_level_handler_mut(order.tick(), order.is_bid())
             .write(level)?;
 
+        self.add_maker_liquidity(order.book_key(), order.maker(), order.remaining())?;
+
         self.orders[order.order_id()].write(order)
     }
 
@@ -761,6 +1454,10 @@ impl StablecoinDEX {
             .tick_level_handler_mut(order.tick(), order.is_bid())
             .write(*level)?;
 
+        self.remove_maker_liquidity(order.book_key(), order.maker(), fill_amount)?;
+
+        self.record_twap_observation(order.book_key(), order.tick())?;
+
         // Emit OrderFilled event for partial fill
         self.emit_order_filled(order.order_id(), order.maker(), taker, fill_amount, true)?;
 
@@ -802,6 +1499,10 @@ impl StablecoinDEX {
             fill_amount
         };
 
+        self.remove_maker_liquidity(book_key, order.maker(), fill_amount)?;
+
+        self.record_twap_observation(book_key, order.tick())?;
+
         // Emit OrderFilled event for complete fill
         self.emit_order_filled(order.order_id(), order.maker(), taker, fill_amount, false)?;
 
@@ -897,6 +1598,16 @@ impl StablecoinDEX {
         let mut total_amount_in: u128 = 0;
 
         while amount_out > 0 {
+            // Expired resting orders are swept out of the book (escrow
+            // refunded, storage cleared) instead of trading, so stale
+            // liquidity cannot distort pricing.
+            if self.is_order_expired(order.order_id())? {
+                self.expire_active_order(order)?;
+                level = self.get_best_price_level(book_key, bid)?;
+                order = self.orders[level.head].read()?;
+                continue;
+            }
+
             let tick = order.tick();
 
             let (fill_amount, amount_in) = if bid {
@@ -977,6 +1688,16 @@ impl StablecoinDEX {
         let mut total_amount_out: u128 = 0;
 
         while amount_in > 0 {
+            // Expired resting orders are swept out of the book (escrow
+            // refunded, storage cleared) instead of trading, so stale
+            // liquidity cannot distort pricing.
+            if self.is_order_expired(order.order_id())? {
+                self.expire_active_order(order)?;
+                level = self.get_best_price_level(book_key, bid)?;
+                order = self.orders[level.head].read()?;
+                continue;
+            }
+
             let tick = order.tick();
 
             let fill_amount = if bid {
@@ -1092,6 +1813,19 @@ impl StablecoinDEX {
 
     /// Cancel an active order (already in the orderbook)
     fn cancel_active_order(&mut self, order: Order) -> Result<()> {
+        let order_id = order.order_id();
+        self.remove_active_order(order)?;
+
+        // Emit OrderCancelled event
+        self.emit_event(StablecoinDEXEvents::OrderCancelled(
+            IStablecoinDEX::OrderCancelled { orderId: order_id },
+        ))
+    }
+
+    /// Removes an active order from the book, refunding the escrow to the
+    /// maker and clearing its storage. Shared by cancellation and expiry,
+    /// which emit their own events.
+    fn remove_active_order(&mut self, order: Order) -> Result<()> {
         let mut level = self.books[order.book_key()]
             .tick_level_handler(order.tick(), order.is_bid())
             .read()?;
@@ -1161,15 +1895,11 @@ impl StablecoinDEX {
             self.increment_balance(order.maker(), orderbook.base, order.remaining())?;
         }
 
-        // Clear the order from storage
-        self.orders[order.order_id()].delete()?;
+        self.remove_maker_liquidity(order.book_key(), order.maker(), order.remaining())?;
 
-        // Emit OrderCancelled event
-        self.emit_event(StablecoinDEXEvents::OrderCancelled(
-            IStablecoinDEX::OrderCancelled {
-                orderId: order.order_id(),
-            },
-        ))
+        // Clear the order and its expiry from storage
+        self.orders[order.order_id()].delete()?;
+        self.order_expiries[order.order_id()].delete()
     }
 
     /// Cancels an order whose maker is blocked by [`TIP403Registry`] policy, allowing anyone to
@@ -1197,6 +1927,43 @@ impl StablecoinDEX {
         }
     }
 
+    /// Removes expired orders from the book, refunding each maker's escrow.
+    /// Anyone may call this; keepers batch IDs to reclaim storage so stale
+    /// liquidity does not accumulate. IDs that no longer resolve to an active
+    /// order are skipped, so racing keepers (or a fill sweeping the order
+    /// first) do not fail the whole batch.
+    ///
+    /// # Errors
+    /// - `OrderNotExpired` — an order in the batch has no expiry or its
+    ///   expiry has not passed
+    pub fn expire_orders(&mut self, order_ids: &[u128]) -> Result<()> {
+        for &order_id in order_ids {
+            let order = self.orders[order_id].read()?;
+            if order.maker().is_zero() {
+                continue;
+            }
+
+            if !self.is_order_expired(order_id)? {
+                return Err(StablecoinDEXError::order_not_expired().into());
+            }
+
+            self.expire_active_order(order)?;
+        }
+
+        Ok(())
+    }
+
+    /// Removes an expired order from the book, refunding the escrow to the
+    /// maker and emitting `OrderExpired`.
+    fn expire_active_order(&mut self, order: Order) -> Result<()> {
+        let order_id = order.order_id();
+        self.remove_active_order(order)?;
+
+        self.emit_event(StablecoinDEXEvents::OrderExpired(
+            IStablecoinDEX::OrderExpired { orderId: order_id },
+        ))
+    }
+
     /// Returns `true` if the maker is authorized to keep the order open.
     ///
     /// Checks sender authorization on the escrow token (bid=quote, ask=base).
@@ -2483,6 +3250,52 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_swap_with_min_out_enforces_deadline_and_matches_quote() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1);
+        storage.set_timestamp(U256::from(1_000u64));
+        StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinDEX::new();
+            exchange.initialize()?;
+
+            let alice = Address::random();
+            let bob = Address::random();
+            let admin = Address::random();
+            let amount_in = 500_000u128;
+            let tick = 10;
+
+            let (base_token, quote_token) =
+                setup_test_tokens(admin, alice, exchange.address, 200_000_000u128)?;
+            exchange.create_pair(base_token)?;
+            exchange.place(alice, base_token, MIN_ORDER_AMOUNT, true, tick)?;
+            exchange.set_balance(bob, base_token, 200_000_000u128)?;
+
+            // A past deadline rejects the swap before any balance moves.
+            assert!(matches!(
+                exchange.swap_with_min_out(bob, base_token, quote_token, amount_in, 0, 999),
+                Err(TempoPrecompileError::StablecoinDEX(
+                    StablecoinDEXError::SwapDeadlineExpired(_)
+                ))
+            ));
+            assert_eq!(exchange.balance_of(bob, base_token)?, 200_000_000u128);
+
+            // The quote matches execution exactly, so it can double as the
+            // slippage bound.
+            let quoted = exchange.quote(base_token, quote_token, amount_in)?;
+            let amount_out = exchange.swap_with_min_out(
+                bob,
+                base_token,
+                quote_token,
+                amount_in,
+                quoted,
+                1_000,
+            )?;
+            assert_eq!(amount_out, quoted);
+
+            Ok(())
+        })
+    }
+
     #[test]
     fn test_flip_order_execution() -> eyre::Result<()> {
         let mut storage = HashMapStorageProvider::new(1);
@@ -5225,4 +6038,702 @@ mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_consult_twap_validation() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1);
+        StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinDEX::new();
+            exchange.initialize()?;
+
+            let admin = Address::random();
+            let alice = Address::random();
+            let (base_token, quote_token) =
+                setup_test_tokens(admin, alice, exchange.address, 200_000_000u128)?;
+
+            // Identical tokens
+            assert_eq!(
+                exchange
+                    .consult_twap(base_token, base_token, 600)
+                    .unwrap_err(),
+                StablecoinDEXError::identical_tokens().into()
+            );
+
+            // Zero and oversized windows
+            assert_eq!(
+                exchange
+                    .consult_twap(base_token, quote_token, 0)
+                    .unwrap_err(),
+                StablecoinDEXError::invalid_twap_window().into()
+            );
+            assert_eq!(
+                exchange
+                    .consult_twap(base_token, quote_token, MAX_TWAP_WINDOW as u32 + 1)
+                    .unwrap_err(),
+                StablecoinDEXError::invalid_twap_window().into()
+            );
+
+            // Pair not yet created
+            assert_eq!(
+                exchange
+                    .consult_twap(base_token, quote_token, 600)
+                    .unwrap_err(),
+                StablecoinDEXError::pair_does_not_exist().into()
+            );
+
+            // Pair created but never traded
+            exchange.create_pair(base_token)?;
+            assert_eq!(
+                exchange
+                    .consult_twap(base_token, quote_token, 600)
+                    .unwrap_err(),
+                StablecoinDEXError::insufficient_twap_history().into()
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_consult_twap_after_trades() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        let admin = Address::random();
+        let alice = Address::random();
+        let bob = Address::random();
+        let tick = 10i16;
+        let price = orderbook::tick_to_price(tick);
+
+        // First trade at t=1_000: partial fill against alice's resting bid.
+        storage.set_timestamp(U256::from(1_000u64));
+        let (base_token, quote_token) = StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinDEX::new();
+            exchange.initialize()?;
+
+            let (base_token, quote_token) =
+                setup_test_tokens(admin, alice, exchange.address, 200_000_000u128)?;
+            exchange.create_pair(base_token)?;
+            exchange.place(alice, base_token, MIN_ORDER_AMOUNT, true, tick)?;
+            exchange.set_balance(bob, base_token, 10_000_000u128)?;
+
+            exchange.swap_exact_amount_in(bob, base_token, quote_token, 500_000, 0)?;
+            Ok::<_, eyre::Report>((base_token, quote_token))
+        })?;
+
+        // Second trade at t=1_400, one observation period later.
+        storage.set_timestamp(U256::from(1_400u64));
+        StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinDEX::new();
+            exchange.swap_exact_amount_in(bob, base_token, quote_token, 500_000, 0)?;
+            Ok::<_, eyre::Report>(())
+        })?;
+
+        // Consult at t=1_600: both fills executed at the same tick, so the
+        // average must be exactly that price in both directions.
+        storage.set_timestamp(U256::from(1_600u64));
+        StorageCtx::enter(&mut storage, || {
+            let exchange = StablecoinDEX::new();
+
+            let twap = exchange.consult_twap(base_token, quote_token, 400)?;
+            assert_eq!(twap, U256::from(price));
+
+            // Reversed pair reports the reciprocal price.
+            let inverted = exchange.consult_twap(quote_token, base_token, 400)?;
+            let scale_squared = U256::from(PRICE_SCALE) * U256::from(PRICE_SCALE);
+            assert_eq!(inverted, scale_squared / U256::from(price));
+
+            Ok::<_, eyre::Report>(())
+        })?;
+
+        // Consult long after the last trade: the window contains no fills, so
+        // the last trade price carries through.
+        storage.set_timestamp(U256::from(3_000u64));
+        StorageCtx::enter(&mut storage, || {
+            let exchange = StablecoinDEX::new();
+            let twap = exchange.consult_twap(base_token, quote_token, 400)?;
+            assert_eq!(twap, U256::from(price));
+            Ok::<_, eyre::Report>(())
+        })
+    }
+    #[test]
+    fn test_swap_fee_uses_default_tier_at_t4() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinDEX::new();
+            exchange.initialize()?;
+
+            let admin = Address::random();
+            let user = Address::random();
+            let (base, quote) = setup_test_tokens(admin, user, exchange.address, 200_000_000)?;
+            exchange.create_pair(base)?;
+
+            // Resting bid at tick 0 (price 1.0) so base sells into it 1:1.
+            exchange.place(user, base, MIN_ORDER_AMOUNT, true, 0)?;
+            exchange.set_balance(user, base, 1_000_000)?;
+
+            let quoted = exchange.quote_swap_exact_amount_in(base, quote, 100_000)?;
+            let out = exchange.swap_exact_amount_in(user, base, quote, 100_000, 0)?;
+
+            // Quote and execution agree; the default 5bp tier is withheld and
+            // distributed to the liquidity left resting, modulo rounding dust
+            // retained by the DEX.
+            assert_eq!(out, quoted);
+            assert_eq!(out, 100_000 - 50);
+            let (_, pending_quote) = exchange.accrued_fees(user, base)?;
+            assert_eq!(pending_quote + exchange.collected_fees(quote)?, 50);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_swap_fee_inactive_before_t4() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T3);
+        StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinDEX::new();
+            exchange.initialize()?;
+
+            let admin = Address::random();
+            let user = Address::random();
+            let (base, quote) = setup_test_tokens(admin, user, exchange.address, 200_000_000)?;
+            exchange.create_pair(base)?;
+            exchange.place(user, base, MIN_ORDER_AMOUNT, true, 0)?;
+            exchange.set_balance(user, base, 1_000_000)?;
+
+            let out = exchange.swap_exact_amount_in(user, base, quote, 100_000, 0)?;
+            assert_eq!(out, 100_000);
+            assert_eq!(exchange.collected_fees(quote)?, 0);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_swap_exact_out_grosses_up_the_fee() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinDEX::new();
+            exchange.initialize()?;
+
+            let admin = Address::random();
+            let user = Address::random();
+            let (base, quote) = setup_test_tokens(admin, user, exchange.address, 200_000_000)?;
+            exchange.create_pair(base)?;
+            exchange.place(user, base, MIN_ORDER_AMOUNT, true, 0)?;
+            exchange.set_balance(user, base, 1_000_000)?;
+
+            // Netting 9_995 quote after 5bp requires a gross fill of 10_000.
+            let quoted = exchange.quote_swap_exact_amount_out(base, quote, 9_995)?;
+            let amount_in = exchange.swap_exact_amount_out(user, base, quote, 9_995, 20_000)?;
+
+            assert_eq!(amount_in, quoted);
+            assert_eq!(amount_in, 10_000);
+            // Exact-out accrues the fee before the fill, so the fill's maker
+            // settlement already credited the maker's share to their balance.
+            assert_eq!(
+                exchange.balance_of(user, quote)? + exchange.collected_fees(quote)?,
+                5
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_set_pair_fee_governance_and_bounds() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinDEX::new();
+            exchange.initialize()?;
+
+            let admin = Address::random();
+            let user = Address::random();
+            let (base, quote) = setup_test_tokens(admin, user, exchange.address, 200_000_000)?;
+            let pair_key = exchange.create_pair(base)?;
+
+            // Unconfigured pairs (including those created before fee tiers
+            // existed) report the default tier.
+            let config = exchange.get_pair_config(pair_key)?;
+            assert_eq!(config.feeBps, DEFAULT_FEE_BPS);
+            assert!(config.isDefault);
+
+            // Only the quote token's admin may configure the tier.
+            assert!(exchange.set_pair_fee(user, pair_key, 1).is_err());
+
+            // Stable-stable tier of 1bp set by governance.
+            exchange.set_pair_fee(admin, pair_key, 1)?;
+            let config = exchange.get_pair_config(pair_key)?;
+            assert_eq!(config.feeBps, 1);
+            assert!(!config.isDefault);
+
+            // The configured tier drives the swap math.
+            exchange.place(user, base, MIN_ORDER_AMOUNT, true, 0)?;
+            exchange.set_balance(user, base, 1_000_000)?;
+            let out = exchange.swap_exact_amount_in(user, base, quote, 100_000, 0)?;
+            assert_eq!(out, 100_000 - 10);
+            let (_, pending_quote) = exchange.accrued_fees(user, base)?;
+            assert_eq!(pending_quote + exchange.collected_fees(quote)?, 10);
+
+            // Tiers above the cap and unknown pairs are rejected.
+            assert!(matches!(
+                exchange.set_pair_fee(admin, pair_key, MAX_FEE_BPS + 1),
+                Err(TempoPrecompileError::StablecoinDEX(
+                    StablecoinDEXError::InvalidFee(_)
+                ))
+            ));
+            assert!(matches!(
+                exchange.get_pair_config(B256::ZERO),
+                Err(TempoPrecompileError::StablecoinDEX(
+                    StablecoinDEXError::PairDoesNotExist(_)
+                ))
+            ));
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_maker_position_and_fee_collection() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinDEX::new();
+            exchange.initialize()?;
+
+            let admin = Address::random();
+            let user = Address::random();
+            let (base, quote) = setup_test_tokens(admin, user, exchange.address, 200_000_000)?;
+            exchange.create_pair(base)?;
+
+            // A resting bid shows up as the maker's position, in base units.
+            exchange.place(user, base, MIN_ORDER_AMOUNT, true, 0)?;
+            let position = exchange.position_of(user, base)?;
+            assert_eq!(position.restingLiquidity, MIN_ORDER_AMOUNT);
+            assert_eq!(position.pendingBaseFees, 0);
+            assert_eq!(position.pendingQuoteFees, 0);
+
+            // A taker swap withholds the default 5bp fee and accrues it to
+            // the liquidity left resting.
+            exchange.set_balance(user, base, 1_000_000)?;
+            exchange.swap_exact_amount_in(user, base, quote, 100_000, 0)?;
+
+            let position = exchange.position_of(user, base)?;
+            assert_eq!(position.restingLiquidity, MIN_ORDER_AMOUNT - 100_000);
+            let (pending_base, pending_quote) = exchange.accrued_fees(user, base)?;
+            assert_eq!(pending_base, 0);
+            assert!(pending_quote > 0);
+            assert_eq!(pending_quote + exchange.collected_fees(quote)?, 50);
+
+            // collectFees credits the earnings to the tradable DEX balance
+            // and clears the pending amount.
+            let balance_before = exchange.balance_of(user, quote)?;
+            exchange.collect_fees(user, base)?;
+            assert_eq!(
+                exchange.balance_of(user, quote)?,
+                balance_before + pending_quote
+            );
+            assert_eq!(exchange.accrued_fees(user, base)?, (0, 0));
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_cancel_clears_maker_position() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinDEX::new();
+            exchange.initialize()?;
+
+            let admin = Address::random();
+            let user = Address::random();
+            let (base, _quote) = setup_test_tokens(admin, user, exchange.address, 200_000_000)?;
+            exchange.create_pair(base)?;
+
+            let order_id = exchange.place(user, base, MIN_ORDER_AMOUNT, true, 0)?;
+            assert_eq!(
+                exchange.position_of(user, base)?.restingLiquidity,
+                MIN_ORDER_AMOUNT
+            );
+
+            exchange.cancel(user, order_id)?;
+            assert_eq!(exchange.position_of(user, base)?.restingLiquidity, 0);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_maker_fees_distributed_pro_rata() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        StorageCtx::enter(&mut storage, || {
+            let mut exchange = StablecoinDEX::new();
+            exchange.initialize()?;
+
+            let admin = Address::random();
+            let alice = Address::random();
+            let bob = Address::random();
+            let amount = U256::from(MIN_ORDER_AMOUNT * 10);
+
+            let quote = TIP20Setup::path_usd(admin)
+                .with_issuer(admin)
+                .with_mint(alice, amount)
+                .with_approval(alice, exchange.address, amount)
+                .with_mint(bob, amount)
+                .with_approval(bob, exchange.address, amount)
+                .apply()?;
+            let base = TIP20Setup::create("USDC", "USDC", admin)
+                .with_issuer(admin)
+                .with_mint(alice, amount)
+                .with_approval(alice, exchange.address, amount)
+                .apply()?;
+            exchange.create_pair(base.address())?;
+
+            // Bob rests three times Alice's liquidity at the same tick.
+            exchange.place(alice, base.address(), MIN_ORDER_AMOUNT, true, 0)?;
+            exchange.place(bob, base.address(), MIN_ORDER_AMOUNT * 3, true, 0)?;
+
+            // A taker sells base into the book; the whole fill comes out of
+            // Alice's order (price-time priority), so the fee accrues against
+            // Alice's remainder plus Bob's untouched order.
+            exchange.set_balance(alice, base.address(), 1_000_000)?;
+            exchange.swap_exact_amount_in(alice, base.address(), quote.address(), 100_000, 0)?;
+
+            let (_, alice_quote) = exchange.accrued_fees(alice, base.address())?;
+            let (_, bob_quote) = exchange.accrued_fees(bob, base.address())?;
+            assert!(bob_quote > alice_quote);
+            assert_eq!(
+                alice_quote + bob_quote + exchange.collected_fees(quote.address())?,
+                50
+            );
+
+            Ok(())
+        })
+    }
+
+    mod signed_order_tests {
+        use super::*;
+        use alloy_signer::SignerSync;
+        use alloy_signer_local::PrivateKeySigner;
+
+        const CHAIN_ID: u64 = 1;
+
+        /// Helper to sign an order with the DEX's EIP-712 domain.
+        fn sign_order(
+            signer: &PrivateKeySigner,
+            order: &IStablecoinDEX::SignedOrder,
+        ) -> (u8, B256, B256) {
+            let domain_separator = keccak256(
+                (
+                    *EIP712_DOMAIN_TYPEHASH,
+                    *DOMAIN_NAME_HASH,
+                    *VERSION_HASH,
+                    U256::from(CHAIN_ID),
+                    STABLECOIN_DEX_ADDRESS,
+                )
+                    .abi_encode(),
+            );
+            let struct_hash = keccak256(
+                (
+                    *SIGNED_ORDER_TYPEHASH,
+                    order.maker,
+                    order.token,
+                    order.amount,
+                    order.isBid,
+                    order.tick,
+                    order.nonce,
+                    order.deadline,
+                )
+                    .abi_encode(),
+            );
+            let digest = keccak256(
+                [
+                    &[0x19, 0x01],
+                    domain_separator.as_slice(),
+                    struct_hash.as_slice(),
+                ]
+                .concat(),
+            );
+
+            let sig = signer.sign_hash_sync(&digest).unwrap();
+            (sig.v() as u8 + 27, sig.r().into(), sig.s().into())
+        }
+
+        fn make_order(maker: Address, token: Address, nonce: u64) -> IStablecoinDEX::SignedOrder {
+            IStablecoinDEX::SignedOrder {
+                maker,
+                token,
+                amount: MIN_ORDER_AMOUNT,
+                isBid: true,
+                tick: 0,
+                nonce: U256::from(nonce),
+                deadline: U256::MAX,
+            }
+        }
+
+        #[test]
+        fn test_place_with_signature_happy_path() -> eyre::Result<()> {
+            let mut storage = HashMapStorageProvider::new_with_spec(CHAIN_ID, TempoHardfork::T4);
+            StorageCtx::enter(&mut storage, || {
+                let mut exchange = StablecoinDEX::new();
+                exchange.initialize()?;
+
+                let admin = Address::random();
+                let signer = PrivateKeySigner::random();
+                let maker = signer.address();
+                let (base, _) = setup_test_tokens(admin, maker, exchange.address, 200_000_000)?;
+                exchange.create_pair(base)?;
+
+                let order = make_order(maker, base, 0);
+                let (v, r, s) = sign_order(&signer, &order);
+
+                // Submitted by an arbitrary relayer, executed as the maker.
+                let order_id = exchange.place_with_signature(&order, v, r, s)?;
+                assert_eq!(exchange.get_order(order_id)?.maker, maker);
+                assert_eq!(exchange.order_nonces(maker)?, U256::from(1));
+
+                // Replaying the same signed order dies on the consumed nonce.
+                assert!(matches!(
+                    exchange.place_with_signature(&order, v, r, s),
+                    Err(TempoPrecompileError::StablecoinDEX(
+                        StablecoinDEXError::InvalidOrderNonce(_)
+                    ))
+                ));
+
+                Ok(())
+            })
+        }
+
+        #[test]
+        fn test_place_with_signature_rejects_expiry_and_bad_signatures() -> eyre::Result<()> {
+            let mut storage = HashMapStorageProvider::new_with_spec(CHAIN_ID, TempoHardfork::T4);
+            storage.set_timestamp(U256::from(1_000u64));
+            StorageCtx::enter(&mut storage, || {
+                let mut exchange = StablecoinDEX::new();
+                exchange.initialize()?;
+
+                let admin = Address::random();
+                let signer = PrivateKeySigner::random();
+                let maker = signer.address();
+                let (base, _) = setup_test_tokens(admin, maker, exchange.address, 200_000_000)?;
+                exchange.create_pair(base)?;
+
+                // Past-deadline orders are rejected before signature checks.
+                let mut order = make_order(maker, base, 0);
+                order.deadline = U256::from(500u64);
+                let (v, r, s) = sign_order(&signer, &order);
+                assert!(matches!(
+                    exchange.place_with_signature(&order, v, r, s),
+                    Err(TempoPrecompileError::StablecoinDEX(
+                        StablecoinDEXError::SignedOrderExpired(_)
+                    ))
+                ));
+
+                // A signature from someone other than the maker is rejected.
+                let order = make_order(maker, base, 0);
+                let (v, r, s) = sign_order(&PrivateKeySigner::random(), &order);
+                assert!(matches!(
+                    exchange.place_with_signature(&order, v, r, s),
+                    Err(TempoPrecompileError::StablecoinDEX(
+                        StablecoinDEXError::InvalidOrderSignature(_)
+                    ))
+                ));
+
+                // Tampering with a signed field invalidates the signature.
+                let mut order = make_order(maker, base, 0);
+                let (v, r, s) = sign_order(&signer, &order);
+                order.amount = MIN_ORDER_AMOUNT * 2;
+                assert!(matches!(
+                    exchange.place_with_signature(&order, v, r, s),
+                    Err(TempoPrecompileError::StablecoinDEX(
+                        StablecoinDEXError::InvalidOrderSignature(_)
+                    ))
+                ));
+
+                Ok(())
+            })
+        }
+
+        #[test]
+        fn test_invalidate_order_nonce_cancels_outstanding_orders() -> eyre::Result<()> {
+            let mut storage = HashMapStorageProvider::new_with_spec(CHAIN_ID, TempoHardfork::T4);
+            StorageCtx::enter(&mut storage, || {
+                let mut exchange = StablecoinDEX::new();
+                exchange.initialize()?;
+
+                let admin = Address::random();
+                let signer = PrivateKeySigner::random();
+                let maker = signer.address();
+                let (base, _) = setup_test_tokens(admin, maker, exchange.address, 200_000_000)?;
+                exchange.create_pair(base)?;
+
+                // Maker signed an order at nonce 0, then revoked it.
+                let revoked = make_order(maker, base, 0);
+                let (v, r, s) = sign_order(&signer, &revoked);
+                exchange.invalidate_order_nonce(maker, U256::from(5))?;
+
+                assert!(matches!(
+                    exchange.place_with_signature(&revoked, v, r, s),
+                    Err(TempoPrecompileError::StablecoinDEX(
+                        StablecoinDEXError::InvalidOrderNonce(_)
+                    ))
+                ));
+
+                // A fresh order at the advanced nonce still executes.
+                let order = make_order(maker, base, 5);
+                let (v, r, s) = sign_order(&signer, &order);
+                exchange.place_with_signature(&order, v, r, s)?;
+
+                // The nonce can only move forward.
+                assert!(matches!(
+                    exchange.invalidate_order_nonce(maker, U256::from(6)),
+                    Err(TempoPrecompileError::StablecoinDEX(
+                        StablecoinDEXError::InvalidOrderNonce(_)
+                    ))
+                ));
+
+                Ok(())
+            })
+        }
+    }
+
+    mod order_expiry_tests {
+        use super::*;
+
+        #[test]
+        fn test_place_with_expiry_validates_and_records() -> eyre::Result<()> {
+            let mut storage = HashMapStorageProvider::new(1);
+            storage.set_timestamp(U256::from(1_000u64));
+            StorageCtx::enter(&mut storage, || {
+                let mut exchange = StablecoinDEX::new();
+                exchange.initialize()?;
+
+                let admin = Address::random();
+                let user = Address::random();
+                let (base, _) = setup_test_tokens(admin, user, exchange.address, 400_000_000)?;
+                exchange.create_pair(base)?;
+
+                // Zero and non-future expiries are rejected before escrow moves.
+                for expiry in [0u64, 1_000] {
+                    assert!(matches!(
+                        exchange.place_with_expiry(user, base, MIN_ORDER_AMOUNT, true, 0, expiry),
+                        Err(TempoPrecompileError::StablecoinDEX(
+                            StablecoinDEXError::InvalidOrderExpiry(_)
+                        ))
+                    ));
+                }
+
+                let expiring =
+                    exchange.place_with_expiry(user, base, MIN_ORDER_AMOUNT, true, 0, 2_000)?;
+                assert_eq!(exchange.order_expiry(expiring)?, 2_000);
+
+                // Plain placement never expires.
+                let plain = exchange.place(user, base, MIN_ORDER_AMOUNT, true, 0)?;
+                assert_eq!(exchange.order_expiry(plain)?, 0);
+
+                // Cancellation clears the expiry slot along with the order.
+                exchange.cancel(user, expiring)?;
+                assert_eq!(exchange.order_expiry(expiring)?, 0);
+
+                Ok(())
+            })
+        }
+
+        #[test]
+        fn test_expire_orders_refunds_escrow_and_cleans_up() -> eyre::Result<()> {
+            let mut storage = HashMapStorageProvider::new(1);
+            let admin = Address::random();
+            let user = Address::random();
+
+            storage.set_timestamp(U256::from(1_000u64));
+            let (order_id, quote) = StorageCtx::enter(&mut storage, || {
+                let mut exchange = StablecoinDEX::new();
+                exchange.initialize()?;
+
+                let (base, quote) = setup_test_tokens(admin, user, exchange.address, 200_000_000)?;
+                exchange.create_pair(base)?;
+
+                let order_id =
+                    exchange.place_with_expiry(user, base, MIN_ORDER_AMOUNT, true, 0, 1_500)?;
+
+                // The order is still live, so the sweep rejects it.
+                assert!(matches!(
+                    exchange.expire_orders(&[order_id]),
+                    Err(TempoPrecompileError::StablecoinDEX(
+                        StablecoinDEXError::OrderNotExpired(_)
+                    ))
+                ));
+
+                Ok::<_, eyre::Report>((order_id, quote))
+            })?;
+
+            // Past the expiry anyone may sweep; unknown IDs are skipped so a
+            // racing keeper does not fail the batch.
+            storage.set_timestamp(U256::from(2_000u64));
+            StorageCtx::enter(&mut storage, || {
+                let mut exchange = StablecoinDEX::new();
+                exchange.expire_orders(&[order_id, 999])?;
+
+                // Bid at tick 0 escrows quote 1:1; the full escrow comes back.
+                assert_eq!(exchange.balance_of(user, quote)?, MIN_ORDER_AMOUNT);
+                assert!(matches!(
+                    exchange.get_order(order_id),
+                    Err(TempoPrecompileError::StablecoinDEX(
+                        StablecoinDEXError::OrderDoesNotExist(_)
+                    ))
+                ));
+                assert_eq!(exchange.order_expiry(order_id)?, 0);
+
+                // Re-sweeping the cleared ID is a no-op.
+                exchange.expire_orders(&[order_id])?;
+
+                Ok::<_, eyre::Report>(())
+            })
+        }
+
+        #[test]
+        fn test_matching_sweeps_expired_orders() -> eyre::Result<()> {
+            let mut storage = HashMapStorageProvider::new(1);
+            let admin = Address::random();
+            let maker = Address::random();
+            let taker = Address::random();
+
+            storage.set_timestamp(U256::from(1_000u64));
+            let (expired_id, base, quote) = StorageCtx::enter(&mut storage, || {
+                let mut exchange = StablecoinDEX::new();
+                exchange.initialize()?;
+
+                let (base, quote) = setup_test_tokens(admin, maker, exchange.address, 400_000_000)?;
+                exchange.create_pair(base)?;
+
+                // Best bid at tick 10 expires; the bid at tick 0 rests forever.
+                let expired_id =
+                    exchange.place_with_expiry(maker, base, MIN_ORDER_AMOUNT, true, 10, 1_500)?;
+                exchange.place(maker, base, MIN_ORDER_AMOUNT, true, 0)?;
+                exchange.set_balance(taker, base, 10_000_000)?;
+
+                Ok::<_, eyre::Report>((expired_id, base, quote))
+            })?;
+
+            storage.set_timestamp(U256::from(2_000u64));
+            StorageCtx::enter(&mut storage, || {
+                let mut exchange = StablecoinDEX::new();
+
+                // The swap skips the expired tick-10 bid and fills at tick 0
+                // (price 1.0), so the expired order no longer sets the price.
+                let out = exchange.swap_exact_amount_in(taker, base, quote, 100_000, 0)?;
+                assert_eq!(out, 100_000);
+
+                // The sweep refunded the expired maker's full escrow.
+                let escrow = base_to_quote(MIN_ORDER_AMOUNT, 10, RoundingDirection::Up).unwrap();
+                assert_eq!(exchange.balance_of(maker, quote)?, escrow);
+                assert!(matches!(
+                    exchange.get_order(expired_id),
+                    Err(TempoPrecompileError::StablecoinDEX(
+                        StablecoinDEXError::OrderDoesNotExist(_)
+                    ))
+                ));
+
+                Ok::<_, eyre::Report>(())
+            })
+        }
+    }
 }
@@ -2,6 +2,7 @@
 
 use crate::{
     error::Result,
+    fixed_point,
     stablecoin_dex::{IStablecoinDEX, TICK_SPACING},
     storage::{Handler, Mapping},
 };
@@ -16,25 +17,18 @@ pub const MAX_TICK: i16 = 2000;
 /// Scaling factor for tick-to-price conversion. A tick of 0 maps to `PRICE_SCALE` (peg).
 pub const PRICE_SCALE: u32 = 100_000;
 
-/// Rounding direction for price conversions.
+/// Rounding direction for price conversions, shared with the other
+/// fixed-point math in [`crate::fixed_point`].
 ///
 /// Rounding prevents dust-level insolvency in maker/taker settlement:
 /// - When escrowing funds from a user → round UP (user pays more)
 /// - When releasing funds to a user → round DOWN (user receives less)
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum RoundingDirection {
-    /// Round down (floor division) - favors protocol when user receives funds
-    Down,
-    /// Round up (ceiling division) - favors protocol when user deposits funds
-    Up,
-}
+pub use crate::fixed_point::Rounding as RoundingDirection;
 
 /// Convert base token amount to quote token amount at a given tick.
 ///
 /// Formula: quote_amount = (base_amount * price) / PRICE_SCALE
 ///
-/// Uses U256 for intermediate multiplication to prevent overflow.
-///
 /// # Arguments
 /// * `base_amount` - Amount of base tokens
 /// * `tick` - Price tick
@@ -43,26 +37,18 @@ pub enum RoundingDirection {
 /// # Returns
 /// Quote token amount, or None if result exceeds u128
 pub fn base_to_quote(base_amount: u128, tick: i16, rounding: RoundingDirection) -> Option<u128> {
-    let price = U256::from(tick_to_price(tick));
-    let base = U256::from(base_amount);
-    let scale = U256::from(PRICE_SCALE);
-
-    let numerator = base * price;
-
-    let result = match rounding {
-        RoundingDirection::Down => numerator / scale,
-        RoundingDirection::Up => numerator.div_ceil(scale),
-    };
-
-    result.try_into().ok()
+    fixed_point::mul_div_u128(
+        base_amount,
+        tick_to_price(tick) as u128,
+        PRICE_SCALE as u128,
+        rounding,
+    )
 }
 
 /// Convert quote token amount to base token amount at a given tick.
 ///
 /// Formula: base_amount = (quote_amount * PRICE_SCALE) / price
 ///
-/// Uses U256 for intermediate multiplication to prevent overflow.
-///
 /// # Arguments
 /// * `quote_amount` - Amount of quote tokens
 /// * `tick` - Price tick
@@ -71,18 +57,12 @@ pub fn base_to_quote(base_amount: u128, tick: i16, rounding: RoundingDirection)
 /// # Returns
 /// Base token amount, or None if result exceeds u128
 pub fn quote_to_base(quote_amount: u128, tick: i16, rounding: RoundingDirection) -> Option<u128> {
-    let price = U256::from(tick_to_price(tick));
-    let quote = U256::from(quote_amount);
-    let scale = U256::from(PRICE_SCALE);
-
-    let numerator = quote * scale;
-
-    let result = match rounding {
-        RoundingDirection::Down => numerator / price,
-        RoundingDirection::Up => numerator.div_ceil(price),
-    };
-
-    result.try_into().ok()
+    fixed_point::mul_div_u128(
+        quote_amount,
+        PRICE_SCALE as u128,
+        tick_to_price(tick) as u128,
+        rounding,
+    )
 }
 
 /// Lowest representable scaled price (`PRICE_SCALE + MIN_TICK`).
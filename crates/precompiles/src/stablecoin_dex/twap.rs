@@ -0,0 +1,104 @@
+//! TWAP (time-weighted average price) accumulators for the stablecoin DEX.
+//!
+//! Each orderbook maintains a running cumulative of `price * seconds` that is
+//! advanced on every trade, plus a fixed-size ring of checkpoints snapshotted
+//! once per [`TWAP_OBSERVATION_PERIOD`]. `consultTwap` reads the checkpoint
+//! closest to the window start and divides the cumulative delta by the elapsed
+//! time, yielding a manipulation-resistant average execution price.
+
+use alloy::primitives::U256;
+use tempo_precompiles_macros::Storable;
+
+/// Seconds covered by one checkpoint slot in the observation ring.
+pub const TWAP_OBSERVATION_PERIOD: u64 = 300;
+
+/// Number of checkpoint slots retained per orderbook.
+pub const TWAP_GRANULARITY: u64 = 24;
+
+/// Maximum TWAP window that can be consulted. One slot is always being
+/// overwritten, so only `TWAP_GRANULARITY - 1` periods are reliably retained.
+pub const MAX_TWAP_WINDOW: u64 = TWAP_OBSERVATION_PERIOD * (TWAP_GRANULARITY - 1);
+
+/// Running price accumulator for a single orderbook.
+///
+/// `last_timestamp == 0` means no trade has ever been recorded for the book.
+#[derive(Debug, Storable, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TwapState {
+    /// Cumulative sum of `last_price * elapsed_seconds` over the book's lifetime.
+    pub price_cumulative: U256,
+    /// Execution price (scaled by `PRICE_SCALE`) of the most recent trade.
+    pub last_price: u32,
+    /// Timestamp at which the accumulator was last advanced.
+    pub last_timestamp: u64,
+}
+
+impl TwapState {
+    /// Returns true if at least one trade has been recorded.
+    pub fn is_initialized(&self) -> bool {
+        self.last_timestamp != 0
+    }
+
+    /// Returns the cumulative advanced from `last_timestamp` to `now` at
+    /// `last_price`, without mutating the stored state. `None` on overflow.
+    pub fn cumulative_at(&self, now: u64) -> Option<U256> {
+        let elapsed = now.checked_sub(self.last_timestamp)?;
+        self.price_cumulative
+            .checked_add(U256::from(self.last_price).checked_mul(U256::from(elapsed))?)
+    }
+}
+
+/// A snapshot of the accumulator taken at the first trade of an observation period.
+///
+/// `timestamp == 0` means the slot has never been written.
+#[derive(Debug, Storable, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TwapCheckpoint {
+    /// Timestamp at which the snapshot was taken.
+    pub timestamp: u64,
+    /// Value of the book's price cumulative at `timestamp`.
+    pub price_cumulative: U256,
+}
+
+/// Returns the ring slot index for a timestamp.
+pub fn observation_slot(timestamp: u64) -> u64 {
+    (timestamp / TWAP_OBSERVATION_PERIOD) % TWAP_GRANULARITY
+}
+
+/// Returns the observation period (epoch) a timestamp falls into. Two
+/// timestamps in the same epoch share a checkpoint slot and only the first
+/// trade of the epoch writes it.
+pub fn observation_epoch(timestamp: u64) -> u64 {
+    timestamp / TWAP_OBSERVATION_PERIOD
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_observation_slot_wraps() {
+        assert_eq!(observation_slot(0), 0);
+        assert_eq!(observation_slot(TWAP_OBSERVATION_PERIOD - 1), 0);
+        assert_eq!(observation_slot(TWAP_OBSERVATION_PERIOD), 1);
+        assert_eq!(
+            observation_slot(TWAP_OBSERVATION_PERIOD * TWAP_GRANULARITY),
+            0
+        );
+    }
+
+    #[test]
+    fn test_cumulative_at() {
+        let state = TwapState {
+            price_cumulative: U256::from(1_000u64),
+            last_price: 100_000,
+            last_timestamp: 50,
+        };
+
+        // 10 seconds at price 100_000
+        assert_eq!(
+            state.cumulative_at(60),
+            Some(U256::from(1_000u64 + 100_000 * 10))
+        );
+        // Timestamps never go backwards, but the helper must not panic if they do
+        assert_eq!(state.cumulative_at(40), None);
+    }
+}
@@ -23,6 +23,7 @@ pub struct HashMapStorageProvider {
     spec: TempoHardfork,
     is_static: bool,
     counter_sload: u64,
+    counter_sstore: u64,
     snapshots: Vec<Snapshot>,
 
     /// Emitted events keyed by contract address.
@@ -65,6 +66,7 @@ impl HashMapStorageProvider {
             spec,
             is_static: false,
             counter_sload: 0,
+            counter_sstore: 0,
         }
     }
 
@@ -115,6 +117,7 @@ impl PrecompileStorageProvider for HashMapStorageProvider {
         key: U256,
         value: U256,
     ) -> Result<(), TempoPrecompileError> {
+        self.counter_sstore += 1;
         self.internals.insert((address, key), value);
         Ok(())
     }
@@ -279,6 +282,16 @@ impl HashMapStorageProvider {
         self.counter_sload
     }
 
+    pub fn counter_sstore(&self) -> u64 {
+        self.counter_sstore
+    }
+
+    /// Resets the SLOAD/SSTORE access counters to zero.
+    pub fn reset_access_counters(&mut self) {
+        self.counter_sload = 0;
+        self.counter_sstore = 0;
+    }
+
     /// Returns all storage entries as `(address, slot, value)`.
     pub fn into_storage(self) -> impl Iterator<Item = (Address, U256, U256)> {
         self.internals
@@ -5,6 +5,8 @@
 
 pub mod evm;
 pub mod hashmap;
+pub mod overlay;
+pub use overlay::{OverlayStorageProvider, SimulationOutcome, SlotDiff, simulate};
 
 pub mod thread_local;
 use alloy::primitives::keccak256;
@@ -17,6 +19,9 @@ pub mod packing;
 pub use packing::FieldLocation;
 pub use types::mapping as slots;
 
+pub mod registry;
+pub use registry::ReservedSpace;
+
 use alloy::primitives::{Address, B256, LogData, Signature, U256};
 use revm::{
     context::journaled_state::JournalCheckpoint,
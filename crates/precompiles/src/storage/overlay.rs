@@ -0,0 +1,350 @@
+//! Copy-on-write overlay for simulate-only precompile execution.
+//!
+//! `eth_call` and `eth_estimateGas` want to run state-mutating precompile
+//! entrypoints (TIP-20 transfers, keychain authorizations, ...) without
+//! journaling anything into the real state. [`OverlayStorageProvider`] wraps a
+//! base [`PrecompileStorageProvider`]: reads fall through to the base until a
+//! slot is written, writes and emitted events land only in the overlay, and
+//! [`OverlayStorageProvider::finish`] returns the would-be events and state
+//! diff alongside the gas the entrypoint metered. The base provider is never
+//! touched, so the same context can serve many simulations.
+//!
+//! [`simulate`] is the entrypoint: it scopes a closure over the overlay via
+//! [`StorageCtx::enter`] exactly like real dispatch does, so precompile code
+//! cannot tell it is being simulated.
+
+use crate::{
+    error::{Result, TempoPrecompileError},
+    storage::{PrecompileStorageProvider, StorageCtx},
+};
+use alloy::primitives::{Address, LogData, U256};
+use revm::{
+    context::journaled_state::JournalCheckpoint,
+    state::{AccountInfo, Bytecode},
+};
+use std::collections::HashMap;
+use tempo_chainspec::hardfork::TempoHardfork;
+
+/// One slot changed by a simulated execution.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SlotDiff {
+    /// Contract whose storage changed.
+    pub address: Address,
+    /// Storage slot.
+    pub slot: U256,
+    /// Value in the base state.
+    pub previous: U256,
+    /// Value the execution would have written.
+    pub value: U256,
+}
+
+/// Everything a simulated execution would have done to the real state.
+#[derive(Debug, Clone, Default)]
+pub struct SimulationOutcome {
+    /// Net slot changes, sorted by (address, slot). Writes that restored the
+    /// base value are omitted.
+    pub state_diff: Vec<SlotDiff>,
+    /// Events the execution would have emitted, in emission order.
+    pub events: Vec<(Address, LogData)>,
+    /// Gas the execution metered through `deduct_gas`.
+    pub gas_used: u64,
+    /// Gas refunds the execution accumulated.
+    pub gas_refunded: i64,
+}
+
+/// Copy-on-write snapshot for overlay checkpoints.
+///
+/// PERF: naive cloning, mirroring `HashMapStorageProvider` — simulations are
+/// off the hot path.
+struct OverlaySnapshot {
+    writes: HashMap<(Address, U256), U256>,
+    transient: HashMap<(Address, U256), U256>,
+    events_len: usize,
+}
+
+/// [`PrecompileStorageProvider`] that shadows a base provider copy-on-write.
+pub struct OverlayStorageProvider<'a, S> {
+    base: &'a mut S,
+    writes: HashMap<(Address, U256), U256>,
+    /// Base value of every written slot, captured on first write for the diff.
+    originals: HashMap<(Address, U256), U256>,
+    transient: HashMap<(Address, U256), U256>,
+    codes: HashMap<Address, Bytecode>,
+    events: Vec<(Address, LogData)>,
+    snapshots: Vec<OverlaySnapshot>,
+    gas_used: u64,
+    gas_refunded: i64,
+}
+
+impl<'a, S: PrecompileStorageProvider> OverlayStorageProvider<'a, S> {
+    /// Wraps `base`; nothing is written through until the overlay is dropped,
+    /// which discards everything.
+    pub fn new(base: &'a mut S) -> Self {
+        Self {
+            base,
+            writes: HashMap::new(),
+            originals: HashMap::new(),
+            transient: HashMap::new(),
+            codes: HashMap::new(),
+            events: Vec::new(),
+            snapshots: Vec::new(),
+            gas_used: 0,
+            gas_refunded: 0,
+        }
+    }
+
+    /// Consumes the overlay into the simulation outcome.
+    pub fn finish(self) -> SimulationOutcome {
+        let mut state_diff = self
+            .writes
+            .iter()
+            .filter_map(|((address, slot), value)| {
+                let previous = self.originals[&(*address, *slot)];
+                (*value != previous).then_some(SlotDiff {
+                    address: *address,
+                    slot: *slot,
+                    previous,
+                    value: *value,
+                })
+            })
+            .collect::<Vec<_>>();
+        state_diff.sort_by_key(|diff| (diff.address, diff.slot));
+
+        SimulationOutcome {
+            state_diff,
+            events: self.events,
+            gas_used: self.gas_used,
+            gas_refunded: self.gas_refunded,
+        }
+    }
+}
+
+impl<S: PrecompileStorageProvider> PrecompileStorageProvider for OverlayStorageProvider<'_, S> {
+    fn chain_id(&self) -> u64 {
+        self.base.chain_id()
+    }
+
+    fn timestamp(&self) -> U256 {
+        self.base.timestamp()
+    }
+
+    fn beneficiary(&self) -> Address {
+        self.base.beneficiary()
+    }
+
+    fn block_number(&self) -> u64 {
+        self.base.block_number()
+    }
+
+    fn set_code(&mut self, address: Address, code: Bytecode) -> Result<()> {
+        self.codes.insert(address, code);
+        Ok(())
+    }
+
+    fn with_account_info(
+        &mut self,
+        address: Address,
+        f: &mut dyn FnMut(&AccountInfo),
+    ) -> Result<()> {
+        if let Some(code) = self.codes.get(&address) {
+            let info = AccountInfo {
+                code_hash: code.hash_slow(),
+                code: Some(code.clone()),
+                ..Default::default()
+            };
+            f(&info);
+            return Ok(());
+        }
+        self.base.with_account_info(address, f)
+    }
+
+    fn sload(&mut self, address: Address, key: U256) -> Result<U256> {
+        if let Some(value) = self.writes.get(&(address, key)) {
+            return Ok(*value);
+        }
+        self.base.sload(address, key)
+    }
+
+    fn tload(&mut self, address: Address, key: U256) -> Result<U256> {
+        if let Some(value) = self.transient.get(&(address, key)) {
+            return Ok(*value);
+        }
+        self.base.tload(address, key)
+    }
+
+    fn sstore(&mut self, address: Address, key: U256, value: U256) -> Result<()> {
+        if !self.originals.contains_key(&(address, key)) {
+            let original = self.base.sload(address, key)?;
+            self.originals.insert((address, key), original);
+        }
+        self.writes.insert((address, key), value);
+        Ok(())
+    }
+
+    fn tstore(&mut self, address: Address, key: U256, value: U256) -> Result<()> {
+        self.transient.insert((address, key), value);
+        Ok(())
+    }
+
+    fn emit_event(&mut self, address: Address, event: LogData) -> Result<()> {
+        self.events.push((address, event));
+        Ok(())
+    }
+
+    fn deduct_gas(&mut self, gas: u64) -> Result<()> {
+        self.gas_used = self
+            .gas_used
+            .checked_add(gas)
+            .ok_or_else(|| TempoPrecompileError::Fatal("simulated gas overflow".into()))?;
+        Ok(())
+    }
+
+    fn refund_gas(&mut self, gas: i64) {
+        self.gas_refunded += gas;
+    }
+
+    fn gas_used(&self) -> u64 {
+        self.gas_used
+    }
+
+    fn gas_refunded(&self) -> i64 {
+        self.gas_refunded
+    }
+
+    fn reservoir(&self) -> u64 {
+        self.base.reservoir()
+    }
+
+    fn spec(&self) -> TempoHardfork {
+        self.base.spec()
+    }
+
+    fn is_static(&self) -> bool {
+        // Simulation exists precisely to run mutating entrypoints.
+        false
+    }
+
+    fn checkpoint(&mut self) -> JournalCheckpoint {
+        let idx = self.snapshots.len();
+        self.snapshots.push(OverlaySnapshot {
+            writes: self.writes.clone(),
+            transient: self.transient.clone(),
+            events_len: self.events.len(),
+        });
+        JournalCheckpoint {
+            log_i: 0,
+            journal_i: idx,
+            selfdestructed_i: 0,
+        }
+    }
+
+    fn checkpoint_commit(&mut self, checkpoint: JournalCheckpoint) {
+        self.snapshots.truncate(checkpoint.journal_i);
+    }
+
+    fn checkpoint_revert(&mut self, checkpoint: JournalCheckpoint) {
+        if let Some(snapshot) = self.snapshots.drain(checkpoint.journal_i..).next() {
+            self.writes = snapshot.writes;
+            self.transient = snapshot.transient;
+            self.events.truncate(snapshot.events_len);
+        }
+    }
+}
+
+/// Runs `f` against a copy-on-write overlay of `base`, returning its result
+/// and everything it would have changed. The base state is left untouched.
+pub fn simulate<S, R>(base: &mut S, f: impl FnOnce() -> R) -> (R, SimulationOutcome)
+where
+    S: PrecompileStorageProvider,
+{
+    let mut overlay = OverlayStorageProvider::new(base);
+    let result = StorageCtx::enter(&mut overlay, f);
+    (result, overlay.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        storage::hashmap::HashMapStorageProvider,
+        test_util::TIP20Setup,
+        tip20::{ITIP20, TIP20Token},
+    };
+    use alloy::primitives::address;
+
+    #[test]
+    fn overlay_shadows_reads_and_drops_noop_writes() -> eyre::Result<()> {
+        let mut base = HashMapStorageProvider::new(1);
+        let contract = address!("00000000000000000000000000000000000000aa");
+        base.sstore(contract, U256::from(1), U256::from(10))?;
+
+        let mut overlay = OverlayStorageProvider::new(&mut base);
+        assert_eq!(overlay.sload(contract, U256::from(1))?, U256::from(10));
+
+        overlay.sstore(contract, U256::from(1), U256::from(20))?;
+        overlay.sstore(contract, U256::from(2), U256::from(7))?;
+        // Restoring the base value nets out of the diff.
+        overlay.sstore(contract, U256::from(1), U256::from(10))?;
+        assert_eq!(overlay.sload(contract, U256::from(1))?, U256::from(10));
+
+        let outcome = overlay.finish();
+        assert_eq!(
+            outcome.state_diff,
+            vec![SlotDiff {
+                address: contract,
+                slot: U256::from(2),
+                previous: U256::ZERO,
+                value: U256::from(7),
+            }]
+        );
+
+        // The base never saw the overlay writes.
+        assert_eq!(base.sload(contract, U256::from(2))?, U256::ZERO);
+        Ok(())
+    }
+
+    #[test]
+    fn simulated_transfer_reports_events_without_touching_state() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1);
+        let admin = Address::random();
+        let from = Address::random();
+        let to = Address::random();
+
+        let token = StorageCtx::enter(&mut storage, || {
+            TIP20Setup::create("Test", "TST", admin)
+                .with_issuer(admin)
+                .with_mint(from, U256::from(1_000))
+                .apply()
+        })?;
+
+        let (result, outcome) = simulate(&mut storage, || {
+            let mut tip20 = TIP20Token::from_address(token.address())?;
+            tip20.transfer(
+                from,
+                ITIP20::transferCall {
+                    to,
+                    amount: U256::from(250),
+                },
+            )
+        });
+        result?;
+
+        assert!(!outcome.events.is_empty(), "transfer must emit");
+        assert!(!outcome.state_diff.is_empty(), "balances must diff");
+
+        // The real balances are untouched by the simulation.
+        StorageCtx::enter(&mut storage, || {
+            let tip20 = TIP20Token::from_address(token.address())?;
+            assert_eq!(
+                tip20.balance_of(ITIP20::balanceOfCall { account: from })?,
+                U256::from(1_000)
+            );
+            assert_eq!(
+                tip20.balance_of(ITIP20::balanceOfCall { account: to })?,
+                U256::ZERO
+            );
+            Ok::<_, TempoPrecompileError>(())
+        })?;
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+//! Registry of the storage spaces reserved by each precompile.
+//!
+//! Every `#[contract]` struct generates a `slots::RESERVED_SPACES` table describing the
+//! header slots its fields occupy. This registry collects those tables together with the
+//! address each precompile lives at, so [`storage_collisions`] can prove that no two
+//! precompiles cohabiting an address overlap their reserved spaces — the kind of silent
+//! state corruption that is otherwise easy to introduce when a new precompile is pointed
+//! at an existing address.
+
+use alloy::primitives::{Address, U256};
+use tempo_contracts::precompiles::{
+    ACCOUNT_KEYCHAIN_ADDRESS, ADDRESS_REGISTRY_ADDRESS, NONCE_PRECOMPILE_ADDRESS,
+    P256_VERIFY_ADDRESS, PATH_USD_ADDRESS, SIGNATURE_VERIFIER_ADDRESS, STABLECOIN_DEX_ADDRESS,
+    TIP_FEE_MANAGER_ADDRESS, TIP20_FACTORY_ADDRESS, TIP403_REGISTRY_ADDRESS,
+    VALIDATOR_CONFIG_ADDRESS, VALIDATOR_CONFIG_V2_ADDRESS,
+};
+
+/// A contiguous span of header slots reserved by one contract field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReservedSpace {
+    /// Name of the field the span belongs to.
+    pub field: &'static str,
+    /// First header slot of the span.
+    pub slot: U256,
+    /// Number of consecutive header slots reserved. Mappings and vecs reserve only their
+    /// base slot; their entries live in hashed subtrees keyed off it.
+    pub slots: u64,
+}
+
+impl ReservedSpace {
+    /// Returns true if the two spans share at least one header slot.
+    pub fn overlaps(&self, other: &Self) -> bool {
+        let self_end = self.slot.saturating_add(U256::from(self.slots));
+        let other_end = other.slot.saturating_add(U256::from(other.slots));
+        self.slot < other_end && other.slot < self_end
+    }
+}
+
+/// The storage spaces one precompile reserves at its address.
+#[derive(Debug, Clone, Copy)]
+pub struct PrecompileSpaces {
+    /// Type name of the precompile.
+    pub precompile: &'static str,
+    /// Address the precompile lives at.
+    pub address: Address,
+    /// Reserved header slot spans, one per storage field.
+    pub spaces: &'static [ReservedSpace],
+}
+
+/// Every `#[contract]` precompile and the storage spaces it reserves.
+///
+/// TIP-20 tokens live at every address carrying the TIP-20 prefix; the canonical PathUSD
+/// instance stands in for the whole family. New precompiles must be registered here so
+/// [`storage_collisions`] covers them.
+pub const PRECOMPILE_SPACES: &[PrecompileSpaces] = &[
+    PrecompileSpaces {
+        precompile: "AccountKeychain",
+        address: ACCOUNT_KEYCHAIN_ADDRESS,
+        spaces: crate::account_keychain::slots::RESERVED_SPACES,
+    },
+    PrecompileSpaces {
+        precompile: "AddressRegistry",
+        address: ADDRESS_REGISTRY_ADDRESS,
+        spaces: crate::address_registry::slots::RESERVED_SPACES,
+    },
+    PrecompileSpaces {
+        precompile: "NonceManager",
+        address: NONCE_PRECOMPILE_ADDRESS,
+        spaces: crate::nonce::slots::RESERVED_SPACES,
+    },
+    PrecompileSpaces {
+        precompile: "P256Verify",
+        address: P256_VERIFY_ADDRESS,
+        spaces: crate::p256_verify::slots::RESERVED_SPACES,
+    },
+    PrecompileSpaces {
+        precompile: "SignatureVerifier",
+        address: SIGNATURE_VERIFIER_ADDRESS,
+        spaces: crate::signature_verifier::slots::RESERVED_SPACES,
+    },
+    PrecompileSpaces {
+        precompile: "StablecoinDEX",
+        address: STABLECOIN_DEX_ADDRESS,
+        spaces: crate::stablecoin_dex::slots::RESERVED_SPACES,
+    },
+    PrecompileSpaces {
+        precompile: "TIP20Token",
+        address: PATH_USD_ADDRESS,
+        spaces: crate::tip20::slots::RESERVED_SPACES,
+    },
+    PrecompileSpaces {
+        precompile: "TIP20Factory",
+        address: TIP20_FACTORY_ADDRESS,
+        spaces: crate::tip20_factory::slots::RESERVED_SPACES,
+    },
+    PrecompileSpaces {
+        precompile: "TIP403Registry",
+        address: TIP403_REGISTRY_ADDRESS,
+        spaces: crate::tip403_registry::slots::RESERVED_SPACES,
+    },
+    PrecompileSpaces {
+        precompile: "TipFeeManager",
+        address: TIP_FEE_MANAGER_ADDRESS,
+        spaces: crate::tip_fee_manager::slots::RESERVED_SPACES,
+    },
+    PrecompileSpaces {
+        precompile: "ValidatorConfig",
+        address: VALIDATOR_CONFIG_ADDRESS,
+        spaces: crate::validator_config::slots::RESERVED_SPACES,
+    },
+    PrecompileSpaces {
+        precompile: "ValidatorConfigV2",
+        address: VALIDATOR_CONFIG_V2_ADDRESS,
+        spaces: crate::validator_config_v2::slots::RESERVED_SPACES,
+    },
+];
+
+/// Returns a description of every reserved space overlap between two distinct precompiles
+/// registered at the same address. Empty when the layouts are sound.
+pub fn storage_collisions() -> Vec<String> {
+    let mut collisions = Vec::new();
+    for (i, a) in PRECOMPILE_SPACES.iter().enumerate() {
+        for b in &PRECOMPILE_SPACES[i + 1..] {
+            if a.address != b.address {
+                continue;
+            }
+            for space_a in a.spaces {
+                for space_b in b.spaces {
+                    if space_a.overlaps(space_b) {
+                        collisions.push(format!(
+                            "{}.{} (slot {}, {} slot(s)) overlaps {}.{} (slot {}, {} slot(s)) at {}",
+                            a.precompile,
+                            space_a.field,
+                            space_a.slot,
+                            space_a.slots,
+                            b.precompile,
+                            space_b.field,
+                            space_b.slot,
+                            space_b.slots,
+                            a.address,
+                        ));
+                    }
+                }
+            }
+        }
+    }
+    collisions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn space(slot: u64, slots: u64) -> ReservedSpace {
+        ReservedSpace {
+            field: "test",
+            slot: U256::from(slot),
+            slots,
+        }
+    }
+
+    #[test]
+    fn test_overlap_detection() {
+        // Adjacent spans don't overlap; intersecting ones do.
+        assert!(!space(0, 2).overlaps(&space(2, 1)));
+        assert!(!space(5, 1).overlaps(&space(4, 1)));
+        assert!(space(0, 3).overlaps(&space(2, 1)));
+        assert!(space(2, 1).overlaps(&space(0, 3)));
+        assert!(space(7, 1).overlaps(&space(7, 1)));
+        // Zero-width spans never overlap anything.
+        assert!(!space(3, 0).overlaps(&space(3, 1)));
+    }
+
+    #[test]
+    fn test_no_collisions_between_cohabiting_precompiles() {
+        let collisions = storage_collisions();
+        assert!(
+            collisions.is_empty(),
+            "precompile storage spaces overlap:\n{}",
+            collisions.join("\n")
+        );
+    }
+
+    #[test]
+    fn test_every_precompile_reserves_distinct_spaces_against_a_clone() {
+        // Sanity-check the detector end-to-end: a precompile compared against itself at
+        // the same address must collide on every non-empty field.
+        for entry in PRECOMPILE_SPACES {
+            for space in entry.spaces {
+                assert!(
+                    space.slots == 0 || space.overlaps(space),
+                    "{}.{} does not overlap itself",
+                    entry.precompile,
+                    space.field
+                );
+            }
+        }
+    }
+}
@@ -13,20 +13,97 @@
 
 use alloy::primitives::{Address, U256};
 use std::ops::{Index, IndexMut};
-use tempo_precompiles_macros;
 
 use crate::{
     error::Result,
     storage::{
-        Handler, LayoutCtx, Storable, StorableType, packing,
+        Handler, Layout, LayoutCtx, Storable, StorableType, StorageOps, packing,
         types::{HandlerCache, Slot},
     },
 };
 
-// fixed-size arrays: [T; N] for primitive types T and sizes 1-32
-tempo_precompiles_macros::storable_arrays!();
-// nested arrays: [[T; M]; N] for small primitive types
-tempo_precompiles_macros::storable_nested_arrays!();
+/// Number of storage slots occupied by `[T; N]`, computed at compile time from
+/// the element layout: packable elements (≤ 16 bytes) share slots, larger
+/// elements each take their own full slot(s).
+const fn array_slot_count(elem_layout: Layout, len: usize) -> usize {
+    let bytes = elem_layout.bytes();
+    if bytes <= 16 {
+        (len * bytes).div_ceil(32)
+    } else {
+        len * elem_layout.slots()
+    }
+}
+
+/// Slot address and layout context of element `index`, mirroring the packing
+/// strategy of [`ArrayHandler`] element handlers.
+fn element_location<T: StorableType>(base_slot: U256, index: usize) -> (U256, LayoutCtx) {
+    if T::BYTES <= 16 {
+        let location = packing::calc_element_loc(index, T::BYTES);
+        (
+            base_slot + U256::from(location.offset_slots),
+            LayoutCtx::packed(location.offset_bytes),
+        )
+    } else {
+        (base_slot + U256::from(index * T::SLOTS), LayoutCtx::FULL)
+    }
+}
+
+// Generic fixed-size array support: any storable element type works at any
+// length, with the slot count computed at compile time. This covers (and
+// replaces) the per-size implementations the `storable_arrays!` macro used to
+// enumerate, including nested arrays like `[[u8; 4]; 8]`.
+impl<T, const N: usize> StorableType for [T; N]
+where
+    T: Storable + Copy + Default,
+{
+    // Arrays cannot be packed, so they must take full slots
+    const LAYOUT: Layout = Layout::Slots(array_slot_count(T::LAYOUT, N));
+
+    type Handler = ArrayHandler<T, N>;
+
+    fn handle(slot: U256, ctx: LayoutCtx, address: Address) -> Self::Handler {
+        debug_assert_eq!(ctx, LayoutCtx::FULL, "Arrays cannot be packed");
+        Self::Handler::new(slot, address)
+    }
+}
+
+impl<T, const N: usize> Storable for [T; N]
+where
+    T: Storable + Copy + Default,
+{
+    #[inline]
+    fn load<S: StorageOps>(storage: &S, slot: U256, ctx: LayoutCtx) -> Result<Self> {
+        debug_assert_eq!(
+            ctx,
+            LayoutCtx::FULL,
+            "Arrays can only be loaded with LayoutCtx::FULL"
+        );
+
+        let mut result = [T::default(); N];
+        for (i, elem) in result.iter_mut().enumerate() {
+            let (elem_slot, elem_ctx) = element_location::<T>(slot, i);
+            *elem = T::load(storage, elem_slot, elem_ctx)?;
+        }
+        Ok(result)
+    }
+
+    #[inline]
+    fn store<S: StorageOps>(&self, storage: &mut S, slot: U256, ctx: LayoutCtx) -> Result<()> {
+        debug_assert_eq!(
+            ctx,
+            LayoutCtx::FULL,
+            "Arrays can only be stored with LayoutCtx::FULL"
+        );
+
+        for (i, elem) in self.iter().enumerate() {
+            let (elem_slot, elem_ctx) = element_location::<T>(slot, i);
+            elem.store(storage, elem_slot, elem_ctx)?;
+        }
+        Ok(())
+    }
+
+    // delete uses the default implementation from the trait
+}
 
 /// Type-safe handler for accessing fixed-size arrays `[T; N]` in storage.
 ///
@@ -529,4 +606,43 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_array_size_beyond_former_attribute_list() {
+        let (mut storage, address) = setup_storage();
+        let base_slot = U256::from(500);
+
+        // Sizes beyond the formerly pre-declared 1-32 range work through the
+        // generic impl: [u64; 40] packs 4 elements per slot into 10 slots.
+        assert_eq!(<[u64; 40] as StorableType>::LAYOUT, Layout::Slots(10));
+
+        let mut data = [0u64; 40];
+        for (i, value) in data.iter_mut().enumerate() {
+            *value = i as u64 + 1;
+        }
+
+        StorageCtx::enter(&mut storage, || {
+            let mut slot = <[u64; 40]>::handle(base_slot, LayoutCtx::FULL, address);
+            slot.write(data).unwrap();
+            assert_eq!(slot.read().unwrap(), data, "[u64; 40] roundtrip failed");
+        });
+    }
+
+    #[test]
+    fn test_nested_array_via_generic_impl() {
+        let (mut storage, address) = setup_storage();
+        let base_slot = U256::from(600);
+
+        // Each [u8; 4] inner array takes one full slot, so the outer array
+        // spans one slot per element.
+        assert_eq!(<[[u8; 4]; 3] as StorableType>::LAYOUT, Layout::Slots(3));
+
+        let data: [[u8; 4]; 3] = [[1, 2, 3, 4], [5, 6, 7, 8], [9, 10, 11, 12]];
+
+        StorageCtx::enter(&mut storage, || {
+            let mut slot = <[[u8; 4]; 3]>::handle(base_slot, LayoutCtx::FULL, address);
+            slot.write(data).unwrap();
+            assert_eq!(slot.read().unwrap(), data, "[[u8; 4]; 3] roundtrip failed");
+        });
+    }
 }
@@ -1,7 +1,9 @@
 //! ABI dispatch for the [`TIP20Token`] precompile.
 
 use crate::{
-    Precompile, SelectorSchedule, charge_input_cost, dispatch_call, metadata, mutate, mutate_void,
+    Precompile, SelectorSchedule,
+    calldata::CalldataWords,
+    charge_input_cost, dispatch_call, interface_id, metadata, mutate, mutate_void,
     storage::ContractStorage,
     tip20::{ITIP20, TIP20Token},
     view,
@@ -20,6 +22,20 @@ const T2_ADDED: &[[u8; 4]] = &[
     ITIP20::DOMAIN_SEPARATORCall::SELECTOR,
 ];
 
+const T4_ADDED: &[[u8; 4]] = &[
+    ITIP20::transferBatchCall::SELECTOR,
+    ITIP20::transferBatchWithMemoCall::SELECTOR,
+    ITIP20::createStreamCall::SELECTOR,
+    ITIP20::withdrawFromStreamCall::SELECTOR,
+    ITIP20::cancelStreamCall::SELECTOR,
+    ITIP20::getStreamCall::SELECTOR,
+    ITIP20::streamBalanceCall::SELECTOR,
+    ITIP20::approveWithExpiryCall::SELECTOR,
+    ITIP20::allowanceExpiryCall::SELECTOR,
+    ITIP20::setIssuanceScheduleCall::SELECTOR,
+    ITIP20::issuanceScheduleCall::SELECTOR,
+];
+
 /// Decoded call variant — either a TIP-20 token call or a role-management call.
 enum TIP20Call {
     TIP20(ITIP20Calls),
@@ -31,14 +47,70 @@ impl TIP20Call {
         // safe to expect as `dispatch_call` pre-validates calldata len
         let selector: [u8; 4] = calldata[..4].try_into().expect("calldata len >= 4");
 
+        if let Some(call) = Self::decode_hot_path(selector, calldata) {
+            return Ok(call);
+        }
+
         if IRolesAuthCalls::valid_selector(selector) {
             IRolesAuthCalls::abi_decode(calldata).map(Self::RolesAuth)
         } else {
             ITIP20Calls::abi_decode(calldata).map(Self::TIP20)
         }
     }
+
+    /// Decodes the hottest fixed-argument selectors straight from the calldata words, skipping
+    /// the generic [`SolInterface`] decoder and its per-call overhead.
+    ///
+    /// Returns `None` for any other selector or on a length mismatch so that the generic path
+    /// keeps producing the existing errors for malformed input.
+    fn decode_hot_path(selector: [u8; 4], calldata: &[u8]) -> Option<Self> {
+        Some(Self::TIP20(match selector {
+            ITIP20::transferCall::SELECTOR => {
+                let words = CalldataWords::split_exact(calldata, 2)?;
+                ITIP20Calls::transfer(ITIP20::transferCall {
+                    to: words.address(0),
+                    amount: words.u256(1),
+                })
+            }
+            ITIP20::transferFromCall::SELECTOR => {
+                let words = CalldataWords::split_exact(calldata, 3)?;
+                ITIP20Calls::transferFrom(ITIP20::transferFromCall {
+                    from: words.address(0),
+                    to: words.address(1),
+                    amount: words.u256(2),
+                })
+            }
+            ITIP20::approveCall::SELECTOR => {
+                let words = CalldataWords::split_exact(calldata, 2)?;
+                ITIP20Calls::approve(ITIP20::approveCall {
+                    spender: words.address(0),
+                    amount: words.u256(1),
+                })
+            }
+            ITIP20::balanceOfCall::SELECTOR => {
+                let words = CalldataWords::split_exact(calldata, 1)?;
+                ITIP20Calls::balanceOf(ITIP20::balanceOfCall {
+                    account: words.address(0),
+                })
+            }
+            ITIP20::allowanceCall::SELECTOR => {
+                let words = CalldataWords::split_exact(calldata, 2)?;
+                ITIP20Calls::allowance(ITIP20::allowanceCall {
+                    owner: words.address(0),
+                    spender: words.address(1),
+                })
+            }
+            _ => return None,
+        }))
+    }
 }
 
+/// ERC-165 interface ids served by `supportsInterface` (T4+).
+const INTERFACE_IDS: &[[u8; 4]] = &[
+    interface_id(ITIP20Calls::SELECTORS),
+    interface_id(IRolesAuthCalls::SELECTORS),
+];
+
 impl Precompile for TIP20Token {
     fn call(&mut self, calldata: &[u8], msg_sender: Address) -> PrecompileResult {
         if let Some(err) = charge_input_cost(&mut self.storage, calldata) {
@@ -57,7 +129,11 @@ impl Precompile for TIP20Token {
 
         dispatch_call(
             calldata,
-            &[SelectorSchedule::new(TempoHardfork::T2).with_added(T2_ADDED)],
+            &[
+                SelectorSchedule::new(TempoHardfork::T2).with_added(T2_ADDED),
+                SelectorSchedule::new(TempoHardfork::T4).with_added(T4_ADDED),
+            ],
+            INTERFACE_IDS,
             TIP20Call::decode,
             |call| match call {
                 // Metadata functions (no calldata decoding needed)
@@ -117,9 +193,22 @@ impl Precompile for TIP20Token {
                 TIP20Call::TIP20(ITIP20Calls::transfer(call)) => {
                     mutate(call, msg_sender, |s, c| self.transfer(s, c))
                 }
+                TIP20Call::TIP20(ITIP20Calls::transferBatch(call)) => {
+                    mutate(call, msg_sender, |s, c| self.transfer_batch(s, c))
+                }
+                TIP20Call::TIP20(ITIP20Calls::transferBatchWithMemo(call)) => {
+                    mutate(call, msg_sender, |s, c| self.transfer_batch_with_memo(s, c))
+                }
                 TIP20Call::TIP20(ITIP20Calls::approve(call)) => {
                     mutate(call, msg_sender, |s, c| self.approve(s, c))
                 }
+                // Expiring allowances (T4+)
+                TIP20Call::TIP20(ITIP20Calls::approveWithExpiry(call)) => {
+                    mutate(call, msg_sender, |s, c| self.approve_with_expiry(s, c))
+                }
+                TIP20Call::TIP20(ITIP20Calls::allowanceExpiry(call)) => {
+                    view(call, |c| self.allowance_expiry(c))
+                }
                 TIP20Call::TIP20(ITIP20Calls::changeTransferPolicyId(call)) => {
                     mutate_void(call, msg_sender, |s, c| {
                         self.change_transfer_policy_id(s, c)
@@ -128,6 +217,13 @@ impl Precompile for TIP20Token {
                 TIP20Call::TIP20(ITIP20Calls::setSupplyCap(call)) => {
                     mutate_void(call, msg_sender, |s, c| self.set_supply_cap(s, c))
                 }
+                // Issuance schedule (T4+)
+                TIP20Call::TIP20(ITIP20Calls::setIssuanceSchedule(call)) => {
+                    mutate_void(call, msg_sender, |s, c| self.set_issuance_schedule(s, c))
+                }
+                TIP20Call::TIP20(ITIP20Calls::issuanceSchedule(call)) => {
+                    view(call, |_| self.issuance_schedule())
+                }
                 TIP20Call::TIP20(ITIP20Calls::pause(call)) => {
                     mutate_void(call, msg_sender, |s, c| self.pause(s, c))
                 }
@@ -187,6 +283,23 @@ impl Precompile for TIP20Token {
                     view(call, |c| self.get_pending_rewards(c.account))
                 }
 
+                // Streaming functions (T4+)
+                TIP20Call::TIP20(ITIP20Calls::createStream(call)) => {
+                    mutate(call, msg_sender, |s, c| self.create_stream(s, c))
+                }
+                TIP20Call::TIP20(ITIP20Calls::withdrawFromStream(call)) => {
+                    mutate(call, msg_sender, |s, c| self.withdraw_from_stream(s, c))
+                }
+                TIP20Call::TIP20(ITIP20Calls::cancelStream(call)) => {
+                    mutate_void(call, msg_sender, |s, c| self.cancel_stream(s, c))
+                }
+                TIP20Call::TIP20(ITIP20Calls::getStream(call)) => view(call, |c| {
+                    self.get_stream(c.streamId).map(|stream| stream.into())
+                }),
+                TIP20Call::TIP20(ITIP20Calls::streamBalance(call)) => {
+                    view(call, |c| self.stream_balance(c.streamId))
+                }
+
                 TIP20Call::TIP20(ITIP20Calls::permit(call)) => {
                     mutate_void(call, msg_sender, |_s, c| self.permit(c))
                 }
@@ -229,13 +342,13 @@ mod tests {
         tip403_registry::{ITIP403Registry, TIP403Registry},
     };
     use alloy::{
-        primitives::{Bytes, U256, address},
+        primitives::{B256, Bytes, U256, address},
         sol_types::{SolCall, SolError, SolInterface, SolValue},
     };
 
     use tempo_chainspec::hardfork::TempoHardfork;
     use tempo_contracts::precompiles::{
-        IRolesAuth, RolesAuthError, TIP20Error, UnknownFunctionSelector,
+        IRolesAuth, RolesAuthError, TIP20Error, TIP20Event, UnknownFunctionSelector,
     };
 
     #[test]
@@ -434,6 +547,49 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_hot_path_malformed_calldata_matches_generic_decoder() -> eyre::Result<()> {
+        let (mut storage, admin) = setup_storage();
+        let sender = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Test", "TST", admin)
+                .with_issuer(admin)
+                .with_mint(sender, U256::from(1000))
+                .apply()?;
+
+            // Truncated and over-long transfer calldata must fall back to the generic decoder
+            // and produce the same empty revert it always has.
+            let calldata = ITIP20::transferCall {
+                to: Address::random(),
+                amount: U256::from(100),
+            }
+            .abi_encode();
+
+            let result = token.call(&calldata[..calldata.len() - 1], sender)?;
+            assert!(result.is_revert());
+            assert!(result.bytes.is_empty());
+
+            // Over-long calldata also bypasses the hot path; whether it dispatches is decided
+            // by the generic decoder, exactly as before.
+            let mut padded = calldata.clone();
+            padded.extend_from_slice(&[0u8; 32]);
+            let result = token.call(&padded, sender)?;
+            assert_eq!(
+                result.is_revert(),
+                ITIP20Calls::abi_decode(&padded).is_err()
+            );
+
+            // Well-formed calldata still dispatches through the hot path.
+            let result = token.call(&calldata, sender)?;
+            assert!(!result.is_revert());
+            let success = bool::abi_decode(&result.bytes)?;
+            assert!(success);
+
+            Ok(())
+        })
+    }
+
     #[test]
     fn test_pause_and_unpause() -> eyre::Result<()> {
         let (mut storage, admin) = setup_storage();
@@ -756,8 +912,9 @@ mod tests {
         use crate::test_util::{assert_full_coverage, check_selector_coverage};
         use tempo_contracts::precompiles::{IRolesAuth::IRolesAuthCalls, ITIP20::ITIP20Calls};
 
-        // Use T2 hardfork so T2-gated selectors (permit, nonces, DOMAIN_SEPARATOR) are active
-        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T2);
+        // Use T4 hardfork so the T2-gated selectors (permit, nonces, DOMAIN_SEPARATOR)
+        // and the T4-gated transferBatch selector are all active
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
         let admin = Address::random();
 
         StorageCtx::enter(&mut storage, || {
@@ -822,4 +979,245 @@ mod tests {
             Ok(())
         })
     }
+
+    #[test]
+    fn test_transfer_batch_gated_behind_t4() -> eyre::Result<()> {
+        // Pre-T4: transferBatch should return unknown selector
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T3);
+        let admin = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Test", "TST", admin).apply()?;
+
+            let calldata = ITIP20::transferBatchCall { transfers: vec![] }.abi_encode();
+            let result = token.call(&calldata, admin)?;
+            assert!(result.is_revert());
+            assert!(UnknownFunctionSelector::abi_decode(&result.bytes).is_ok());
+
+            let calldata = ITIP20::transferBatchWithMemoCall { transfers: vec![] }.abi_encode();
+            let result = token.call(&calldata, admin)?;
+            assert!(result.is_revert());
+            assert!(UnknownFunctionSelector::abi_decode(&result.bytes).is_ok());
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_transfer_batch_dispatch() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        let admin = Address::random();
+        let (first, second) = (Address::random(), Address::random());
+
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Test", "TST", admin)
+                .with_issuer(admin)
+                .with_mint(admin, U256::from(1000))
+                .apply()?;
+
+            let calldata = ITIP20::transferBatchCall {
+                transfers: vec![
+                    ITIP20::TransferItem {
+                        to: first,
+                        amount: U256::from(10),
+                    },
+                    ITIP20::TransferItem {
+                        to: second,
+                        amount: U256::from(20),
+                    },
+                ],
+            }
+            .abi_encode();
+            let result = token.call(&calldata, admin)?;
+            assert!(!result.is_revert());
+
+            let balance = token.balance_of(ITIP20::balanceOfCall { account: first })?;
+            assert_eq!(balance, U256::from(10));
+            let balance = token.balance_of(ITIP20::balanceOfCall { account: second })?;
+            assert_eq!(balance, U256::from(20));
+
+            // An empty batch reverts without transferring anything.
+            let calldata = ITIP20::transferBatchCall { transfers: vec![] }.abi_encode();
+            let result = token.call(&calldata, admin)?;
+            assert!(result.is_revert());
+            assert!(ITIP20::InvalidBatch::abi_decode(&result.bytes).is_ok());
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_transfer_batch_with_memo_dispatch() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        let admin = Address::random();
+        let (first, second) = (Address::random(), Address::random());
+        let (first_memo, second_memo) = (B256::random(), B256::random());
+
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Test", "TST", admin)
+                .with_issuer(admin)
+                .with_mint(admin, U256::from(1000))
+                .clear_events()
+                .apply()?;
+
+            let calldata = ITIP20::transferBatchWithMemoCall {
+                transfers: vec![
+                    ITIP20::MemoTransferItem {
+                        to: first,
+                        amount: U256::from(10),
+                        memo: first_memo,
+                    },
+                    ITIP20::MemoTransferItem {
+                        to: second,
+                        amount: U256::from(20),
+                        memo: second_memo,
+                    },
+                ],
+            }
+            .abi_encode();
+            let result = token.call(&calldata, admin)?;
+            assert!(!result.is_revert());
+
+            let balance = token.balance_of(ITIP20::balanceOfCall { account: first })?;
+            assert_eq!(balance, U256::from(10));
+            let balance = token.balance_of(ITIP20::balanceOfCall { account: second })?;
+            assert_eq!(balance, U256::from(20));
+
+            // Each entry carries its own memo in the extended event.
+            token.assert_emitted_events(vec![
+                TIP20Event::Transfer(ITIP20::Transfer {
+                    from: admin,
+                    to: first,
+                    amount: U256::from(10),
+                }),
+                TIP20Event::TransferWithMemo(ITIP20::TransferWithMemo {
+                    from: admin,
+                    to: first,
+                    amount: U256::from(10),
+                    memo: first_memo,
+                }),
+                TIP20Event::Transfer(ITIP20::Transfer {
+                    from: admin,
+                    to: second,
+                    amount: U256::from(20),
+                }),
+                TIP20Event::TransferWithMemo(ITIP20::TransferWithMemo {
+                    from: admin,
+                    to: second,
+                    amount: U256::from(20),
+                    memo: second_memo,
+                }),
+            ]);
+
+            // An empty batch reverts without transferring anything.
+            let calldata = ITIP20::transferBatchWithMemoCall { transfers: vec![] }.abi_encode();
+            let result = token.call(&calldata, admin)?;
+            assert!(result.is_revert());
+            assert!(ITIP20::InvalidBatch::abi_decode(&result.bytes).is_ok());
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_stream_selectors_gated_behind_t4() -> eyre::Result<()> {
+        // Pre-T4: streaming selectors should return unknown selector
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T3);
+        let admin = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Test", "TST", admin).apply()?;
+
+            let calls = [
+                ITIP20::createStreamCall {
+                    to: Address::random(),
+                    ratePerSecond: U256::ONE,
+                    duration: 100,
+                }
+                .abi_encode(),
+                ITIP20::withdrawFromStreamCall {
+                    streamId: U256::ONE,
+                }
+                .abi_encode(),
+                ITIP20::cancelStreamCall {
+                    streamId: U256::ONE,
+                }
+                .abi_encode(),
+                ITIP20::getStreamCall {
+                    streamId: U256::ONE,
+                }
+                .abi_encode(),
+                ITIP20::streamBalanceCall {
+                    streamId: U256::ONE,
+                }
+                .abi_encode(),
+            ];
+            for calldata in calls {
+                let result = token.call(&calldata, admin)?;
+                assert!(result.is_revert());
+                assert!(UnknownFunctionSelector::abi_decode(&result.bytes).is_ok());
+            }
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_issuance_schedule_selectors_gated_behind_t4() -> eyre::Result<()> {
+        // Pre-T4: issuance schedule selectors should return unknown selector
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T3);
+        let admin = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Test", "TST", admin).apply()?;
+
+            let calls = [
+                ITIP20::setIssuanceScheduleCall {
+                    maxPerPeriod: U256::ONE,
+                    period: 60,
+                }
+                .abi_encode(),
+                ITIP20::issuanceScheduleCall {}.abi_encode(),
+            ];
+            for calldata in calls {
+                let result = token.call(&calldata, admin)?;
+                assert!(result.is_revert());
+                assert!(UnknownFunctionSelector::abi_decode(&result.bytes).is_ok());
+            }
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_allowance_expiry_selectors_gated_behind_t4() -> eyre::Result<()> {
+        // Pre-T4: expiring-allowance selectors should return unknown selector
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T3);
+        let admin = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Test", "TST", admin).apply()?;
+
+            let calls = [
+                ITIP20::approveWithExpiryCall {
+                    spender: Address::random(),
+                    amount: U256::ONE,
+                    deadline: u64::MAX,
+                }
+                .abi_encode(),
+                ITIP20::allowanceExpiryCall {
+                    owner: Address::random(),
+                    spender: Address::random(),
+                }
+                .abi_encode(),
+            ];
+            for calldata in calls {
+                let result = token.call(&calldata, admin)?;
+                assert!(result.is_revert());
+                assert!(UnknownFunctionSelector::abi_decode(&result.bytes).is_ok());
+            }
+
+            Ok(())
+        })
+    }
 }
@@ -11,6 +11,7 @@
 pub mod dispatch;
 pub mod rewards;
 pub mod roles;
+pub mod streams;
 
 use tempo_contracts::precompiles::STABLECOIN_DEX_ADDRESS;
 pub use tempo_contracts::precompiles::{
@@ -26,7 +27,7 @@ use crate::{
     address_registry::AddressRegistry,
     error::{Result, TempoPrecompileError},
     storage::{Handler, Mapping},
-    tip20::{rewards::UserRewardInfo, roles::DEFAULT_ADMIN_ROLE},
+    tip20::{rewards::UserRewardInfo, roles::DEFAULT_ADMIN_ROLE, streams::Stream},
     tip20_factory::TIP20Factory,
     tip403_registry::{AuthRole, ITIP403Registry, TIP403Registry},
 };
@@ -35,7 +36,7 @@ use alloy::{
     sol_types::SolValue,
 };
 use std::sync::LazyLock;
-use tempo_precompiles_macros::contract;
+use tempo_precompiles_macros::{contract, when_not_paused};
 use tempo_primitives::TempoAddressExt;
 pub use tempo_primitives::is_tip20_prefix;
 use tracing::trace;
@@ -100,6 +101,19 @@ pub struct TIP20Token {
     global_reward_per_token: U256,
     opted_in_supply: u128,
     user_reward_info: Mapping<Address, UserRewardInfo>,
+
+    // TIP20 Streams (T4+)
+    streams: Mapping<U256, Stream>,
+    next_stream_id: U256,
+
+    // TIP20 Expiring allowances (T4+)
+    allowance_expiries: Mapping<Address, Mapping<Address, u64>>,
+
+    // TIP20 Issuance schedule (T4+)
+    issuance_max_per_period: U256,
+    issuance_period: u64,
+    issuance_minted_in_period: U256,
+    issuance_period_start: u64,
 }
 
 /// EIP-712 Permit typehash: keccak256("Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)")
@@ -213,8 +227,18 @@ impl TIP20Token {
     }
 
     /// Returns the remaining allowance that `spender` can transfer on behalf of `owner`.
+    /// On T4+, an allowance whose expiry deadline has passed reads as zero.
     pub fn allowance(&self, call: ITIP20::allowanceCall) -> Result<U256> {
-        self.allowances[call.owner][call.spender].read()
+        self.get_allowance(call.owner, call.spender)
+    }
+
+    /// Returns the expiry timestamp of `spender`'s allowance from `owner`
+    /// (0 = never expires).
+    ///
+    /// # SPEC
+    /// T4+ only; the selector is inactive on earlier hardforks.
+    pub fn allowance_expiry(&self, call: ITIP20::allowanceExpiryCall) -> Result<u64> {
+        self.allowance_expiries[call.owner][call.spender].read()
     }
 
     /// Updates the [`TIP403Registry`] transfer policy governing this token's transfers.
@@ -274,6 +298,84 @@ impl TIP20Token {
         }))
     }
 
+    /// Sets or clears the issuance schedule capping how much can be minted per period.
+    ///
+    /// A schedule is active when `period` is non-zero; setting a zero `period` (with a zero
+    /// `maxPerPeriod`) clears it. Setting a schedule resets the current window, so the full
+    /// budget is available from the block timestamp onward.
+    ///
+    /// # Errors
+    /// - `Unauthorized` — caller does not hold `DEFAULT_ADMIN_ROLE`
+    /// - `InvalidIssuanceSchedule` — exactly one of `maxPerPeriod` and `period` is zero, or
+    ///   `maxPerPeriod` exceeds [`U128_MAX`]
+    pub fn set_issuance_schedule(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::setIssuanceScheduleCall,
+    ) -> Result<()> {
+        self.check_role(msg_sender, DEFAULT_ADMIN_ROLE)?;
+
+        if (call.period == 0) != (call.maxPerPeriod == U256::ZERO) || call.maxPerPeriod > U128_MAX {
+            return Err(TIP20Error::invalid_issuance_schedule().into());
+        }
+
+        self.issuance_max_per_period.write(call.maxPerPeriod)?;
+        self.issuance_period.write(call.period)?;
+        self.issuance_minted_in_period.write(U256::ZERO)?;
+        self.issuance_period_start
+            .write(self.storage.timestamp().saturating_to::<u64>())?;
+
+        self.emit_event(TIP20Event::IssuanceScheduleSet(
+            ITIP20::IssuanceScheduleSet {
+                updater: msg_sender,
+                maxPerPeriod: call.maxPerPeriod,
+                period: call.period,
+            },
+        ))
+    }
+
+    /// Returns the issuance schedule, including the usage of the current window.
+    pub fn issuance_schedule(&self) -> Result<ITIP20::IssuanceSchedule> {
+        Ok(ITIP20::IssuanceSchedule {
+            maxPerPeriod: self.issuance_max_per_period.read()?,
+            period: self.issuance_period.read()?,
+            mintedInPeriod: self.issuance_minted_in_period.read()?,
+            periodStart: self.issuance_period_start.read()?,
+        })
+    }
+
+    /// Charges `amount` against the issuance schedule's current window, rolling the window
+    /// forward first if it has elapsed. No-op when no schedule is active.
+    ///
+    /// # Errors
+    /// - `IssuanceRateExceeded` — the mint would exceed the window's remaining budget
+    fn check_issuance_schedule(&mut self, amount: U256) -> Result<()> {
+        let period = self.issuance_period.read()?;
+        if period == 0 {
+            return Ok(());
+        }
+
+        let now = self.storage.timestamp().saturating_to::<u64>();
+        let start = self.issuance_period_start.read()?;
+        let minted = if now >= start.saturating_add(period) {
+            // The window has elapsed: align the start to the window containing `now`.
+            let elapsed_periods = (now - start) / period;
+            self.issuance_period_start
+                .write(start.saturating_add(elapsed_periods.saturating_mul(period)))?;
+            U256::ZERO
+        } else {
+            self.issuance_minted_in_period.read()?
+        };
+
+        let new_minted = minted
+            .checked_add(amount)
+            .ok_or(TempoPrecompileError::under_overflow())?;
+        if new_minted > self.issuance_max_per_period.read()? {
+            return Err(TIP20Error::issuance_rate_exceeded().into());
+        }
+        self.issuance_minted_in_period.write(new_minted)
+    }
+
     /// Pauses all token transfers.
     ///
     /// # Errors
@@ -392,6 +494,7 @@ impl TIP20Token {
     /// - `InvalidRecipient` — (+T3) recipient is zero or a TIP-20 prefix address
     /// - `PolicyForbids` — TIP-403 policy rejects the mint recipient
     /// - `SupplyCapExceeded` — minting would push total supply above the cap
+    /// - `IssuanceRateExceeded` — (+T4) mint exceeds the issuance schedule's per-period budget
     pub fn mint(&mut self, msg_sender: Address, call: ITIP20::mintCall) -> Result<()> {
         let to = Recipient::resolve(call.to)?;
         self._mint(msg_sender, &to, call.amount)?;
@@ -449,6 +552,10 @@ impl TIP20Token {
             return Err(TIP20Error::supply_cap_exceeded().into());
         }
 
+        if self.storage.spec().is_t4() {
+            self.check_issuance_schedule(amount)?;
+        }
+
         self.handle_rewards_on_mint(to.target, amount)?;
 
         self.set_total_supply(new_supply)?;
@@ -583,6 +690,50 @@ impl TIP20Token {
         // Set the new allowance
         self.set_allowance(msg_sender, call.spender, call.amount)?;
 
+        // A plain approve resets any deadline set by a previous `approveWithExpiry`.
+        if self.storage.spec().is_t4() {
+            self.allowance_expiries[msg_sender][call.spender].write(0)?;
+        }
+
+        self.emit_event(TIP20Event::Approval(ITIP20::Approval {
+            owner: msg_sender,
+            spender: call.spender,
+            amount: call.amount,
+        }))?;
+
+        Ok(true)
+    }
+
+    /// Like [`Self::approve`], but the allowance stops being spendable once the
+    /// block timestamp exceeds `deadline`. [`Self::transfer_from`] and
+    /// [`Self::allowance`] treat an expired allowance as zero.
+    ///
+    /// # SPEC
+    /// T4+ only; the selector is inactive on earlier hardforks.
+    ///
+    /// # Errors
+    /// - `InvalidAllowanceExpiry` — deadline is not in the future
+    /// - `SpendingLimitExceeded` — new allowance exceeds access key spending limit
+    pub fn approve_with_expiry(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::approveWithExpiryCall,
+    ) -> Result<bool> {
+        if U256::from(call.deadline) <= self.storage.timestamp() {
+            return Err(TIP20Error::invalid_allowance_expiry().into());
+        }
+
+        // Check and update spending limits for access keys
+        AccountKeychain::new().authorize_approve(
+            msg_sender,
+            self.address,
+            self.get_allowance(msg_sender, call.spender)?,
+            call.amount,
+        )?;
+
+        self.set_allowance(msg_sender, call.spender, call.amount)?;
+        self.allowance_expiries[msg_sender][call.spender].write(call.deadline)?;
+
         self.emit_event(TIP20Event::Approval(ITIP20::Approval {
             owner: msg_sender,
             spender: call.spender,
@@ -673,8 +824,11 @@ impl TIP20Token {
                 .ok_or(TempoPrecompileError::under_overflow())?,
         )?;
 
-        // 6. Set allowance
+        // 6. Set allowance; a permit resets any deadline set by `approveWithExpiry`
         self.set_allowance(call.owner, call.spender, call.value)?;
+        if self.storage.spec().is_t4() {
+            self.allowance_expiries[call.owner][call.spender].write(0)?;
+        }
 
         // 7. Emit Approval event
         self.emit_event(TIP20Event::Approval(ITIP20::Approval {
@@ -796,6 +950,16 @@ impl TIP20Token {
                 .checked_sub(amount)
                 .ok_or(TIP20Error::insufficient_allowance())?;
             self.set_allowance(from, msg_sender, new_allowance)?;
+
+            // (+T4) surface the remaining approval so wallets and compliance
+            // tooling can track outstanding allowances without re-querying.
+            if self.storage.spec().is_t4() {
+                self.emit_event(TIP20Event::AllowanceSpent(ITIP20::AllowanceSpent {
+                    owner: from,
+                    spender: msg_sender,
+                    remaining: new_allowance,
+                }))?;
+            }
         }
 
         self._transfer(from, to, amount)?;
@@ -803,6 +967,76 @@ impl TIP20Token {
         Ok(true)
     }
 
+    /// Transfers to each entry of the batch in order, as if by repeated
+    /// [`Self::transfer`] calls, and reports the batch size to the fee manager
+    /// so qualifying transactions earn a gas rebate at transaction end.
+    ///
+    /// Any single failing transfer reverts the whole batch.
+    ///
+    /// # SPEC
+    /// T4+ only; the selector is inactive on earlier hardforks.
+    ///
+    /// # Errors
+    /// - `InvalidBatch` — the batch is empty
+    /// - any error [`Self::transfer`] can raise, for any entry
+    pub fn transfer_batch(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::transferBatchCall,
+    ) -> Result<bool> {
+        if call.transfers.is_empty() {
+            return Err(TIP20Error::invalid_batch().into());
+        }
+
+        let count = call.transfers.len() as u64;
+        for item in call.transfers {
+            self.transfer(
+                msg_sender,
+                ITIP20::transferCall {
+                    to: item.to,
+                    amount: item.amount,
+                },
+            )?;
+        }
+
+        crate::tip_fee_manager::TipFeeManager::new().note_batch_transfers(count)?;
+        Ok(true)
+    }
+
+    /// Like [`Self::transfer_batch`], but attaches a 32-byte memo to each entry,
+    /// as if by repeated [`Self::transfer_with_memo`] calls.
+    ///
+    /// # SPEC
+    /// T4+ only; the selector is inactive on earlier hardforks.
+    ///
+    /// # Errors
+    /// - `InvalidBatch` — the batch is empty
+    /// - any error [`Self::transfer_with_memo`] can raise, for any entry
+    pub fn transfer_batch_with_memo(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::transferBatchWithMemoCall,
+    ) -> Result<bool> {
+        if call.transfers.is_empty() {
+            return Err(TIP20Error::invalid_batch().into());
+        }
+
+        let count = call.transfers.len() as u64;
+        for item in call.transfers {
+            self.transfer_with_memo(
+                msg_sender,
+                ITIP20::transferWithMemoCall {
+                    to: item.to,
+                    amount: item.amount,
+                    memo: item.memo,
+                },
+            )?;
+        }
+
+        crate::tip_fee_manager::TipFeeManager::new().note_batch_transfers(count)?;
+        Ok(true)
+    }
+
     /// Like [`Self::transfer`], but attaches a 32-byte memo.
     pub fn transfer_with_memo(
         &mut self,
@@ -893,6 +1127,13 @@ impl TIP20Token {
     }
 
     fn get_allowance(&self, owner: Address, spender: Address) -> Result<U256> {
+        // (+T4) an allowance past its `approveWithExpiry` deadline reads as zero
+        if self.storage.spec().is_t4() {
+            let expiry = self.allowance_expiries[owner][spender].read()?;
+            if expiry != 0 && self.storage.timestamp() > U256::from(expiry) {
+                return Ok(U256::ZERO);
+            }
+        }
         self.allowances[owner][spender].read()
     }
 
@@ -913,8 +1154,8 @@ impl TIP20Token {
 
     /// Checks pause state, validates the effective recipient, and ensures the transfer
     /// is authorized. Shared by public entrypoints that resolve a [`Recipient`] up front.
+    #[when_not_paused]
     fn validate_transfer(&self, from: Address, to: &Recipient) -> Result<()> {
-        self.check_not_paused()?;
         to.validate()?;
         self.ensure_transfer_authorized(from, to.target)
     }
@@ -1122,6 +1363,83 @@ impl TIP20Token {
             .ok_or(TIP20Error::supply_cap_exceeded())?;
         self.set_balance(to, new_to_balance)
     }
+
+    /// Emits the per-transaction fee [`Transfer`](ITIP20::Transfer) event and, on T1C+, restores
+    /// the access-key spending limit by the unused reservation. The refund itself is left in the
+    /// fee manager's escrow and credited back once per block in [`Self::settle_net_refund`]. Like
+    /// [`Self::transfer_fee_post_tx`], this is intentionally allowed while paused.
+    pub fn note_fee_spent(
+        &mut self,
+        to: Address,
+        refund: U256,
+        actual_spending: U256,
+    ) -> Result<()> {
+        self.emit_event(TIP20Event::Transfer(ITIP20::Transfer {
+            from: to,
+            to: TIP_FEE_MANAGER_ADDRESS,
+            amount: actual_spending,
+        }))?;
+
+        if refund.is_zero() {
+            return Ok(());
+        }
+
+        if self.storage.spec().is_t1c() {
+            AccountKeychain::new().refund_spending_limit(to, self.address, refund)?;
+        }
+
+        Ok(())
+    }
+
+    /// Applies the net fee refund accumulated for `to` over the whole block: debits the fee
+    /// manager's escrow, credits the payer, and adjusts reward accounting once for the net
+    /// amount.
+    ///
+    /// Runs at the block boundary, so it is allowed while paused and emits no events — the
+    /// per-transaction `Transfer` events were already emitted by [`Self::note_fee_spent`].
+    /// Cannot fail on the payer's balance: the pre-tx escrows taken by
+    /// [`Self::transfer_fee_pre_tx`] always cover the refunds they produce.
+    ///
+    /// # Errors
+    /// - `InsufficientBalance` — fee manager balance lower than the net refund
+    pub fn settle_net_refund(&mut self, to: Address, amount: U256) -> Result<()> {
+        if amount.is_zero() {
+            return Ok(());
+        }
+
+        let from_balance = self.get_balance(TIP_FEE_MANAGER_ADDRESS)?;
+        let new_from_balance =
+            from_balance
+                .checked_sub(amount)
+                .ok_or(TIP20Error::insufficient_balance(
+                    from_balance,
+                    amount,
+                    self.address,
+                ))?;
+
+        // Update rewards for the recipient and get their reward recipient
+        let to_reward_recipient = self.update_rewards(to)?;
+
+        // If user is opted into rewards, increase opted-in supply by the refund amount
+        if to_reward_recipient != Address::ZERO {
+            let opted_in_supply = U256::from(self.get_opted_in_supply()?)
+                .checked_add(amount)
+                .ok_or(TempoPrecompileError::under_overflow())?;
+            self.set_opted_in_supply(
+                opted_in_supply
+                    .try_into()
+                    .map_err(|_| TempoPrecompileError::under_overflow())?,
+            )?;
+        }
+
+        self.set_balance(TIP_FEE_MANAGER_ADDRESS, new_from_balance)?;
+
+        let to_balance = self.get_balance(to)?;
+        let new_to_balance = to_balance
+            .checked_add(amount)
+            .ok_or(TIP20Error::supply_cap_exceeded())?;
+        self.set_balance(to, new_to_balance)
+    }
 }
 
 /// Resolved transfer recipient for [TIP-1022] virtual address support.
@@ -1684,6 +2002,7 @@ pub(crate) mod tests {
                         }],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             )?;
@@ -1757,6 +2076,7 @@ pub(crate) mod tests {
                         }],
                         allowAnyCalls: true,
                         allowedCalls: vec![],
+                        maxUses: 0,
                     },
                 },
             )?;
@@ -2289,6 +2609,150 @@ pub(crate) mod tests {
         })
     }
 
+    #[test]
+    fn test_issuance_schedule_enforced_in_mint() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        storage.set_timestamp(U256::from(1_000u64));
+        let admin = Address::random();
+        let recipient = Address::random();
+
+        let token_address = StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Token", "TKN", admin)
+                .with_issuer(admin)
+                .apply()?;
+
+            token.set_issuance_schedule(
+                admin,
+                ITIP20::setIssuanceScheduleCall {
+                    maxPerPeriod: U256::from(100),
+                    period: 60,
+                },
+            )?;
+
+            let mint = |token: &mut TIP20Token, amount: u64| {
+                token.mint(
+                    admin,
+                    ITIP20::mintCall {
+                        to: recipient,
+                        amount: U256::from(amount),
+                    },
+                )
+            };
+
+            // The window budget is consumed across mints...
+            mint(&mut token, 60)?;
+            mint(&mut token, 40)?;
+            let schedule = token.issuance_schedule()?;
+            assert_eq!(schedule.mintedInPeriod, U256::from(100));
+            assert_eq!(schedule.periodStart, 1_000);
+
+            // ...and the mint pushing past it fails without touching supply.
+            let result = mint(&mut token, 1);
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::TIP20(
+                    TIP20Error::IssuanceRateExceeded(_)
+                ))
+            ));
+            assert_eq!(token.total_supply()?, U256::from(100));
+
+            Ok::<_, eyre::Report>(token.address())
+        })?;
+
+        // Two windows later the budget is fresh and the start realigns.
+        storage.set_timestamp(U256::from(1_130u64));
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Token::from_address(token_address)?;
+            token.mint(
+                admin,
+                ITIP20::mintCall {
+                    to: recipient,
+                    amount: U256::from(100),
+                },
+            )?;
+
+            let schedule = token.issuance_schedule()?;
+            assert_eq!(schedule.mintedInPeriod, U256::from(100));
+            assert_eq!(schedule.periodStart, 1_120);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_set_issuance_schedule_validation() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        let admin = Address::random();
+        let non_admin = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Token", "TKN", admin)
+                .with_issuer(admin)
+                .apply()?;
+
+            // Only the admin may set a schedule.
+            let result = token.set_issuance_schedule(
+                non_admin,
+                ITIP20::setIssuanceScheduleCall {
+                    maxPerPeriod: U256::from(100),
+                    period: 60,
+                },
+            );
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::RolesAuth(
+                    RolesAuthError::Unauthorized(_)
+                ))
+            ));
+
+            // A budget without a period (and vice versa) is inconsistent.
+            for (max_per_period, period) in [
+                (U256::from(100), 0),
+                (U256::ZERO, 60),
+                (U128_MAX + U256::ONE, 60),
+            ] {
+                let result = token.set_issuance_schedule(
+                    admin,
+                    ITIP20::setIssuanceScheduleCall {
+                        maxPerPeriod: max_per_period,
+                        period,
+                    },
+                );
+                assert!(matches!(
+                    result,
+                    Err(TempoPrecompileError::TIP20(
+                        TIP20Error::InvalidIssuanceSchedule(_)
+                    ))
+                ));
+            }
+
+            // Clearing the schedule lifts the per-period bound again.
+            token.set_issuance_schedule(
+                admin,
+                ITIP20::setIssuanceScheduleCall {
+                    maxPerPeriod: U256::ONE,
+                    period: 60,
+                },
+            )?;
+            token.set_issuance_schedule(
+                admin,
+                ITIP20::setIssuanceScheduleCall {
+                    maxPerPeriod: U256::ZERO,
+                    period: 0,
+                },
+            )?;
+            token.mint(
+                admin,
+                ITIP20::mintCall {
+                    to: Address::random(),
+                    amount: U256::from(1_000),
+                },
+            )?;
+
+            Ok(())
+        })
+    }
+
     #[test]
     fn test_unable_to_burn_blocked_from_protected_address() -> eyre::Result<()> {
         let mut storage = HashMapStorageProvider::new(1);
@@ -3601,4 +4065,136 @@ pub(crate) mod tests {
         }
         Ok(())
     }
+
+    #[test]
+    fn test_approve_with_expiry_lifecycle() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1);
+        let admin = Address::random();
+        let owner = Address::random();
+        let spender = Address::random();
+        let recipient = Address::random();
+
+        storage.set_timestamp(U256::from(1_000u64));
+        let token_address = StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Test", "TST", admin)
+                .with_issuer(admin)
+                .with_mint(owner, U256::from(1_000))
+                .apply()?;
+
+            token.approve_with_expiry(
+                owner,
+                ITIP20::approveWithExpiryCall {
+                    spender,
+                    amount: U256::from(500),
+                    deadline: 2_000,
+                },
+            )?;
+            assert_eq!(token.get_allowance(owner, spender)?, U256::from(500));
+            assert_eq!(
+                token.allowance_expiry(ITIP20::allowanceExpiryCall { owner, spender })?,
+                2_000
+            );
+
+            // Spending before the deadline works and surfaces the remainder.
+            token.clear_emitted_events();
+            token.transfer_from(
+                spender,
+                ITIP20::transferFromCall {
+                    from: owner,
+                    to: recipient,
+                    amount: U256::from(200),
+                },
+            )?;
+            token.assert_emitted_events(vec![
+                TIP20Event::AllowanceSpent(ITIP20::AllowanceSpent {
+                    owner,
+                    spender,
+                    remaining: U256::from(300),
+                }),
+                TIP20Event::Transfer(ITIP20::Transfer {
+                    from: owner,
+                    to: recipient,
+                    amount: U256::from(200),
+                }),
+            ]);
+
+            Ok::<_, eyre::Report>(token.address)
+        })?;
+
+        // Past the deadline: the allowance reads as zero and cannot be spent.
+        storage.set_timestamp(U256::from(2_001u64));
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Token::from_address(token_address)?;
+            assert_eq!(
+                token.allowance(ITIP20::allowanceCall { owner, spender })?,
+                U256::ZERO
+            );
+
+            let result = token.transfer_from(
+                spender,
+                ITIP20::transferFromCall {
+                    from: owner,
+                    to: recipient,
+                    amount: U256::ONE,
+                },
+            );
+            assert_eq!(
+                result,
+                Err(TempoPrecompileError::TIP20(
+                    TIP20Error::insufficient_allowance()
+                ))
+            );
+
+            // A plain approve resets the deadline.
+            token.approve(
+                owner,
+                ITIP20::approveCall {
+                    spender,
+                    amount: U256::from(100),
+                },
+            )?;
+            assert_eq!(
+                token.allowance_expiry(ITIP20::allowanceExpiryCall { owner, spender })?,
+                0
+            );
+            assert_eq!(
+                token.allowance(ITIP20::allowanceCall { owner, spender })?,
+                U256::from(100)
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_approve_with_expiry_requires_future_deadline() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1);
+        let admin = Address::random();
+        let owner = Address::random();
+        let spender = Address::random();
+
+        storage.set_timestamp(U256::from(1_000u64));
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Test", "TST", admin).apply()?;
+
+            for deadline in [0, 999, 1_000] {
+                let result = token.approve_with_expiry(
+                    owner,
+                    ITIP20::approveWithExpiryCall {
+                        spender,
+                        amount: U256::ONE,
+                        deadline,
+                    },
+                );
+                assert_eq!(
+                    result,
+                    Err(TempoPrecompileError::TIP20(
+                        TIP20Error::invalid_allowance_expiry()
+                    ))
+                );
+            }
+
+            Ok(())
+        })
+    }
 }
@@ -13,7 +13,7 @@ use crate::{
 };
 use alloy::primitives::{Address, U256, uint};
 use tempo_contracts::precompiles::{ITIP20, TIP20Error, TIP20Event};
-use tempo_precompiles_macros::Storable;
+use tempo_precompiles_macros::{Storable, when_not_paused};
 use tempo_primitives::TempoAddressExt;
 
 /// Precision multiplier for reward-per-token accumulator (1e18).
@@ -31,12 +31,12 @@ impl TIP20Token {
     /// - `SpendingLimitExceeded` — access key spending limit exceeded
     /// - `InsufficientBalance` — caller balance lower than `amount`
     /// - `NoOptedInSupply` — no tokens are currently opted into rewards
+    #[when_not_paused]
     pub fn distribute_reward(
         &mut self,
         msg_sender: Address,
         call: ITIP20::distributeRewardCall,
     ) -> Result<()> {
-        self.check_not_paused()?;
         let token_address = self.address;
 
         if call.amount == U256::ZERO {
@@ -128,13 +128,12 @@ impl TIP20Token {
     /// - `Paused` — token transfers are currently paused
     /// - `PolicyForbids` — TIP-403 policy rejects the sender→recipient transfer authorization
     /// - `InvalidRecipient` — TIP-1022 virtual addresses are rejected
+    #[when_not_paused]
     pub fn set_reward_recipient(
         &mut self,
         msg_sender: Address,
         call: ITIP20::setRewardRecipientCall,
     ) -> Result<()> {
-        self.check_not_paused()?;
-
         // TIP-1022: reject virtual addresses as reward recipients
         if self.storage.spec().is_t3() && call.recipient.is_virtual() {
             return Err(TIP20Error::invalid_recipient().into());
@@ -191,8 +190,8 @@ impl TIP20Token {
     /// # Errors
     /// - `Paused` — token transfers are currently paused
     /// - `PolicyForbids` — TIP-403 policy rejects the contract→caller transfer authorization
+    #[when_not_paused]
     pub fn claim_rewards(&mut self, msg_sender: Address) -> Result<U256> {
-        self.check_not_paused()?;
         self.ensure_transfer_authorized(self.address, msg_sender)?;
 
         self.update_rewards(msg_sender)?;
@@ -0,0 +1,595 @@
+//! Streaming (vesting) transfers for TIP-20 tokens.
+//!
+//! A stream locks `rate_per_second * duration` tokens in the token contract at
+//! creation and vests them linearly to the recipient between `start_time` and
+//! `stop_time`. The recipient withdraws accrued tokens at any time; either
+//! party can cancel, which pays out the accrued portion to the recipient and
+//! returns the unvested remainder to the sender. Designed for payroll-style
+//! recurring payments.
+//!
+//! Available from T4.
+
+use crate::{
+    error::{Result, TempoPrecompileError},
+    storage::Handler,
+    tip20::{Recipient, TIP20Token},
+};
+use alloy::primitives::{Address, U256};
+use tempo_contracts::precompiles::{ITIP20, TIP20Error, TIP20Event};
+use tempo_precompiles_macros::{Storable, when_not_paused};
+use tempo_primitives::TempoAddressExt;
+
+impl TIP20Token {
+    /// Creates a stream paying `ratePerSecond` tokens to `to` for `duration` seconds,
+    /// starting now. Escrows the full stream amount (`ratePerSecond * duration`) in
+    /// the token contract and returns the new stream ID.
+    ///
+    /// # Errors
+    /// - `Paused` — token transfers are currently paused
+    /// - `InvalidStreamParameters` — `ratePerSecond` or `duration` is zero
+    /// - `InvalidRecipient` — recipient is the zero address, the sender, or a virtual address
+    /// - `PolicyForbids` — TIP-403 policy rejects the sender→recipient transfer
+    /// - `SpendingLimitExceeded` — access key spending limit exceeded
+    /// - `InsufficientBalance` — sender balance lower than the escrowed amount
+    #[when_not_paused]
+    pub fn create_stream(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::createStreamCall,
+    ) -> Result<U256> {
+        let token_address = self.address;
+
+        if call.ratePerSecond == U256::ZERO || call.duration == 0 {
+            return Err(TIP20Error::invalid_stream_parameters().into());
+        }
+        // TIP-1022: virtual addresses cannot receive streamed payouts
+        if call.to == Address::ZERO || call.to == msg_sender || call.to.is_virtual() {
+            return Err(TIP20Error::invalid_recipient().into());
+        }
+
+        // Authorize the sender→recipient leg up front: the escrow below and the
+        // later payouts both derive from this transfer intent.
+        self.ensure_transfer_authorized(msg_sender, call.to)?;
+
+        let total = call
+            .ratePerSecond
+            .checked_mul(U256::from(call.duration))
+            .ok_or(TempoPrecompileError::under_overflow())?;
+
+        self.check_and_update_spending_limit(msg_sender, total)?;
+
+        // Escrow the full stream amount in the token contract.
+        self._transfer(msg_sender, &Recipient::direct(token_address), total)?;
+
+        let start_time = self.storage.timestamp().saturating_to::<u64>();
+        let stop_time = start_time
+            .checked_add(call.duration)
+            .ok_or(TempoPrecompileError::under_overflow())?;
+
+        // Stream IDs start at 1 so an all-zero storage slot never aliases a live stream.
+        let stream_id = self
+            .next_stream_id
+            .read()?
+            .checked_add(U256::ONE)
+            .ok_or(TempoPrecompileError::under_overflow())?;
+        self.next_stream_id.write(stream_id)?;
+
+        self.streams[stream_id].write(Stream {
+            from: msg_sender,
+            to: call.to,
+            rate_per_second: call.ratePerSecond,
+            start_time,
+            stop_time,
+            withdrawn: U256::ZERO,
+        })?;
+
+        self.emit_event(TIP20Event::StreamCreated(ITIP20::StreamCreated {
+            streamId: stream_id,
+            from: msg_sender,
+            to: call.to,
+            ratePerSecond: call.ratePerSecond,
+            startTime: start_time,
+            stopTime: stop_time,
+        }))?;
+
+        Ok(stream_id)
+    }
+
+    /// Withdraws all currently accrued tokens from a stream to its recipient.
+    /// Callable by either stream party; the payout always goes to the recipient.
+    /// Returns the withdrawn amount (zero if nothing has accrued yet).
+    ///
+    /// # Errors
+    /// - `Paused` — token transfers are currently paused
+    /// - `StreamDoesNotExist` — unknown or cancelled stream ID
+    /// - `NotStreamParty` — caller is neither the stream's sender nor its recipient
+    /// - `PolicyForbids` — TIP-403 policy rejects the contract→recipient transfer
+    #[when_not_paused]
+    pub fn withdraw_from_stream(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::withdrawFromStreamCall,
+    ) -> Result<U256> {
+        let mut stream = self.streams[call.streamId].read()?;
+        if stream.from == Address::ZERO {
+            return Err(TIP20Error::stream_does_not_exist().into());
+        }
+        if msg_sender != stream.from && msg_sender != stream.to {
+            return Err(TIP20Error::not_stream_party().into());
+        }
+
+        let amount = self.stream_accrued(&stream)?;
+        if amount.is_zero() {
+            return Ok(U256::ZERO);
+        }
+
+        self.ensure_transfer_authorized(self.address, stream.to)?;
+
+        stream.withdrawn = stream
+            .withdrawn
+            .checked_add(amount)
+            .ok_or(TempoPrecompileError::under_overflow())?;
+        let recipient = stream.to;
+        self.streams[call.streamId].write(stream)?;
+
+        let contract_address = self.address;
+        self._transfer(contract_address, &Recipient::direct(recipient), amount)?;
+
+        self.emit_event(TIP20Event::StreamWithdrawal(ITIP20::StreamWithdrawal {
+            streamId: call.streamId,
+            to: recipient,
+            amount,
+        }))?;
+
+        Ok(amount)
+    }
+
+    /// Cancels a stream: pays the accrued portion to the recipient, returns the
+    /// unvested remainder to the sender, and deletes the stream.
+    ///
+    /// # Errors
+    /// - `Paused` — token transfers are currently paused
+    /// - `StreamDoesNotExist` — unknown or cancelled stream ID
+    /// - `NotStreamParty` — caller is neither the stream's sender nor its recipient
+    /// - `PolicyForbids` — TIP-403 policy rejects a payout transfer
+    #[when_not_paused]
+    pub fn cancel_stream(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP20::cancelStreamCall,
+    ) -> Result<()> {
+        let stream = self.streams[call.streamId].read()?;
+        if stream.from == Address::ZERO {
+            return Err(TIP20Error::stream_does_not_exist().into());
+        }
+        if msg_sender != stream.from && msg_sender != stream.to {
+            return Err(TIP20Error::not_stream_party().into());
+        }
+
+        let recipient_amount = self.stream_accrued(&stream)?;
+        let total = stream
+            .rate_per_second
+            .checked_mul(U256::from(
+                stream
+                    .stop_time
+                    .checked_sub(stream.start_time)
+                    .ok_or(TempoPrecompileError::under_overflow())?,
+            ))
+            .ok_or(TempoPrecompileError::under_overflow())?;
+        let sender_amount = total
+            .checked_sub(stream.withdrawn)
+            .and_then(|v| v.checked_sub(recipient_amount))
+            .ok_or(TempoPrecompileError::under_overflow())?;
+
+        // Delete before paying out so the stream cannot be double-cancelled.
+        self.streams[call.streamId].write(Stream::default())?;
+
+        let contract_address = self.address;
+        if recipient_amount > U256::ZERO {
+            self.ensure_transfer_authorized(contract_address, stream.to)?;
+            self._transfer(
+                contract_address,
+                &Recipient::direct(stream.to),
+                recipient_amount,
+            )?;
+        }
+        if sender_amount > U256::ZERO {
+            self.ensure_transfer_authorized(contract_address, stream.from)?;
+            self._transfer(
+                contract_address,
+                &Recipient::direct(stream.from),
+                sender_amount,
+            )?;
+        }
+
+        self.emit_event(TIP20Event::StreamCancelled(ITIP20::StreamCancelled {
+            streamId: call.streamId,
+            recipientAmount: recipient_amount,
+            senderAmount: sender_amount,
+        }))?;
+
+        Ok(())
+    }
+
+    /// Returns the stored stream for `streamId`.
+    ///
+    /// # Errors
+    /// - `StreamDoesNotExist` — unknown or cancelled stream ID
+    pub fn get_stream(&self, stream_id: U256) -> Result<Stream> {
+        let stream = self.streams[stream_id].read()?;
+        if stream.from == Address::ZERO {
+            return Err(TIP20Error::stream_does_not_exist().into());
+        }
+        Ok(stream)
+    }
+
+    /// Returns the amount currently withdrawable from a stream.
+    ///
+    /// # Errors
+    /// - `StreamDoesNotExist` — unknown or cancelled stream ID
+    pub fn stream_balance(&self, stream_id: U256) -> Result<U256> {
+        let stream = self.get_stream(stream_id)?;
+        self.stream_accrued(&stream)
+    }
+
+    /// Vested-but-unwithdrawn amount: `rate * (min(now, stop) - start) - withdrawn`.
+    fn stream_accrued(&self, stream: &Stream) -> Result<U256> {
+        let now = self.storage.timestamp().saturating_to::<u64>();
+        let elapsed = now.min(stream.stop_time).saturating_sub(stream.start_time);
+
+        stream
+            .rate_per_second
+            .checked_mul(U256::from(elapsed))
+            .and_then(|vested| vested.checked_sub(stream.withdrawn))
+            .ok_or(TempoPrecompileError::under_overflow())
+    }
+}
+
+/// Storage layout of a single stream (`from == Address::ZERO` means the slot is empty).
+#[derive(Debug, Clone, Default, Storable)]
+pub struct Stream {
+    /// Stream creator who funded the escrow and receives the unvested remainder on cancel.
+    pub from: Address,
+    /// Recipient the stream vests to.
+    pub to: Address,
+    /// Tokens vested per second.
+    pub rate_per_second: U256,
+    /// Unix timestamp at which vesting starts.
+    pub start_time: u64,
+    /// Unix timestamp at which vesting stops.
+    pub stop_time: u64,
+    /// Total amount already withdrawn by the recipient.
+    pub withdrawn: U256,
+}
+
+impl From<Stream> for ITIP20::Stream {
+    fn from(value: Stream) -> Self {
+        Self {
+            from: value.from,
+            to: value.to,
+            ratePerSecond: value.rate_per_second,
+            startTime: value.start_time,
+            stopTime: value.stop_time,
+            withdrawn: value.withdrawn,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        error::TempoPrecompileError,
+        storage::{StorageCtx, hashmap::HashMapStorageProvider},
+        test_util::TIP20Setup,
+    };
+    use alloy::primitives::{Address, U256};
+    use tempo_contracts::precompiles::TIP20Error;
+
+    #[test]
+    fn test_create_stream_escrows_and_vests_linearly() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1);
+        let admin = Address::random();
+        let alice = Address::random();
+        let bob = Address::random();
+        let rate = U256::from(10);
+        let duration = 100u64;
+        let total = rate * U256::from(duration);
+
+        storage.set_timestamp(U256::from(1_000u64));
+        let token_address = StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Test", "TST", admin)
+                .with_issuer(admin)
+                .with_mint(alice, total)
+                .apply()?;
+
+            let stream_id = token.create_stream(
+                alice,
+                ITIP20::createStreamCall {
+                    to: bob,
+                    ratePerSecond: rate,
+                    duration,
+                },
+            )?;
+            assert_eq!(stream_id, U256::ONE);
+
+            // Full amount escrowed in the token contract, nothing vested yet.
+            assert_eq!(token.get_balance(alice)?, U256::ZERO);
+            assert_eq!(token.get_balance(token.address)?, total);
+            assert_eq!(token.stream_balance(stream_id)?, U256::ZERO);
+
+            let stream = token.get_stream(stream_id)?;
+            assert_eq!(stream.from, alice);
+            assert_eq!(stream.to, bob);
+            assert_eq!(stream.start_time, 1_000);
+            assert_eq!(stream.stop_time, 1_100);
+
+            Ok::<_, eyre::Report>(token.address)
+        })?;
+
+        // Halfway through: half the total is withdrawable.
+        storage.set_timestamp(U256::from(1_050u64));
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Token::from_address(token_address)?;
+            assert_eq!(token.stream_balance(U256::ONE)?, total / U256::from(2));
+
+            let withdrawn = token.withdraw_from_stream(
+                bob,
+                ITIP20::withdrawFromStreamCall {
+                    streamId: U256::ONE,
+                },
+            )?;
+            assert_eq!(withdrawn, total / U256::from(2));
+            assert_eq!(token.get_balance(bob)?, total / U256::from(2));
+            assert_eq!(token.stream_balance(U256::ONE)?, U256::ZERO);
+            Ok::<_, eyre::Report>(())
+        })?;
+
+        // Past the stop time: only the remaining half accrues, never more.
+        storage.set_timestamp(U256::from(2_000u64));
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Token::from_address(token_address)?;
+            assert_eq!(token.stream_balance(U256::ONE)?, total / U256::from(2));
+
+            let withdrawn = token.withdraw_from_stream(
+                bob,
+                ITIP20::withdrawFromStreamCall {
+                    streamId: U256::ONE,
+                },
+            )?;
+            assert_eq!(withdrawn, total / U256::from(2));
+            assert_eq!(token.get_balance(bob)?, total);
+            assert_eq!(token.get_balance(token.address)?, U256::ZERO);
+            Ok::<_, eyre::Report>(())
+        })
+    }
+
+    #[test]
+    fn test_cancel_stream_splits_between_parties() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1);
+        let admin = Address::random();
+        let alice = Address::random();
+        let bob = Address::random();
+        let rate = U256::from(5);
+        let duration = 200u64;
+        let total = rate * U256::from(duration);
+
+        storage.set_timestamp(U256::from(0u64));
+        let token_address = StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Test", "TST", admin)
+                .with_issuer(admin)
+                .with_mint(alice, total)
+                .apply()?;
+
+            token.create_stream(
+                alice,
+                ITIP20::createStreamCall {
+                    to: bob,
+                    ratePerSecond: rate,
+                    duration,
+                },
+            )?;
+            Ok::<_, eyre::Report>(token.address)
+        })?;
+
+        // Cancel a quarter of the way in: bob gets 1/4, alice gets 3/4 back.
+        storage.set_timestamp(U256::from(50u64));
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Token::from_address(token_address)?;
+            token.cancel_stream(
+                alice,
+                ITIP20::cancelStreamCall {
+                    streamId: U256::ONE,
+                },
+            )?;
+
+            assert_eq!(token.get_balance(bob)?, total / U256::from(4));
+            assert_eq!(token.get_balance(alice)?, total - total / U256::from(4));
+            assert_eq!(token.get_balance(token.address)?, U256::ZERO);
+
+            // The stream is gone: further operations report it does not exist.
+            assert!(matches!(
+                token.cancel_stream(
+                    alice,
+                    ITIP20::cancelStreamCall {
+                        streamId: U256::ONE
+                    }
+                ),
+                Err(TempoPrecompileError::TIP20(TIP20Error::StreamDoesNotExist(
+                    _
+                )))
+            ));
+            assert!(matches!(
+                token.stream_balance(U256::ONE),
+                Err(TempoPrecompileError::TIP20(TIP20Error::StreamDoesNotExist(
+                    _
+                )))
+            ));
+            Ok::<_, eyre::Report>(())
+        })
+    }
+
+    #[test]
+    fn test_stream_validation() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1);
+        let admin = Address::random();
+        let alice = Address::random();
+        let bob = Address::random();
+        let outsider = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Test", "TST", admin)
+                .with_issuer(admin)
+                .with_mint(alice, U256::from(1_000))
+                .apply()?;
+
+            // Zero rate and zero duration are rejected.
+            assert!(matches!(
+                token.create_stream(
+                    alice,
+                    ITIP20::createStreamCall {
+                        to: bob,
+                        ratePerSecond: U256::ZERO,
+                        duration: 100,
+                    },
+                ),
+                Err(TempoPrecompileError::TIP20(
+                    TIP20Error::InvalidStreamParameters(_)
+                ))
+            ));
+            assert!(matches!(
+                token.create_stream(
+                    alice,
+                    ITIP20::createStreamCall {
+                        to: bob,
+                        ratePerSecond: U256::ONE,
+                        duration: 0,
+                    },
+                ),
+                Err(TempoPrecompileError::TIP20(
+                    TIP20Error::InvalidStreamParameters(_)
+                ))
+            ));
+
+            // Streaming to self or to the zero address is rejected.
+            assert!(matches!(
+                token.create_stream(
+                    alice,
+                    ITIP20::createStreamCall {
+                        to: alice,
+                        ratePerSecond: U256::ONE,
+                        duration: 100,
+                    },
+                ),
+                Err(TempoPrecompileError::TIP20(TIP20Error::InvalidRecipient(_)))
+            ));
+
+            // Escrow requires the full amount up front.
+            assert!(matches!(
+                token.create_stream(
+                    alice,
+                    ITIP20::createStreamCall {
+                        to: bob,
+                        ratePerSecond: U256::from(100),
+                        duration: 100,
+                    },
+                ),
+                Err(TempoPrecompileError::TIP20(
+                    TIP20Error::InsufficientBalance(_)
+                ))
+            ));
+
+            // Only stream parties may withdraw or cancel.
+            let stream_id = token.create_stream(
+                alice,
+                ITIP20::createStreamCall {
+                    to: bob,
+                    ratePerSecond: U256::ONE,
+                    duration: 100,
+                },
+            )?;
+            assert!(matches!(
+                token.withdraw_from_stream(
+                    outsider,
+                    ITIP20::withdrawFromStreamCall {
+                        streamId: stream_id
+                    },
+                ),
+                Err(TempoPrecompileError::TIP20(TIP20Error::NotStreamParty(_)))
+            ));
+            assert!(matches!(
+                token.cancel_stream(
+                    outsider,
+                    ITIP20::cancelStreamCall {
+                        streamId: stream_id
+                    }
+                ),
+                Err(TempoPrecompileError::TIP20(TIP20Error::NotStreamParty(_)))
+            ));
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_stream_calls_reject_while_paused() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1);
+        let admin = Address::random();
+        let alice = Address::random();
+        let bob = Address::random();
+
+        storage.set_timestamp(U256::from(1_000u64));
+        StorageCtx::enter(&mut storage, || {
+            let mut token = TIP20Setup::create("Test", "TST", admin)
+                .with_issuer(admin)
+                .with_mint(alice, U256::from(2_000u64))
+                .with_role(admin, *crate::tip20::PAUSE_ROLE)
+                .apply()?;
+
+            let stream_id = token.create_stream(
+                alice,
+                ITIP20::createStreamCall {
+                    to: bob,
+                    ratePerSecond: U256::from(10),
+                    duration: 100,
+                },
+            )?;
+
+            token.pause(admin, ITIP20::pauseCall {})?;
+
+            // Every stream entrypoint sits behind `#[when_not_paused]`.
+            let expected = TempoPrecompileError::TIP20(TIP20Error::contract_paused());
+            assert_eq!(
+                token.create_stream(
+                    alice,
+                    ITIP20::createStreamCall {
+                        to: bob,
+                        ratePerSecond: U256::ONE,
+                        duration: 100,
+                    },
+                ),
+                Err(expected.clone())
+            );
+            assert_eq!(
+                token.withdraw_from_stream(
+                    bob,
+                    ITIP20::withdrawFromStreamCall {
+                        streamId: stream_id
+                    },
+                ),
+                Err(expected.clone())
+            );
+            assert_eq!(
+                token.cancel_stream(
+                    alice,
+                    ITIP20::cancelStreamCall {
+                        streamId: stream_id
+                    },
+                ),
+                Err(expected)
+            );
+
+            Ok(())
+        })
+    }
+}
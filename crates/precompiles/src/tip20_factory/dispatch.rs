@@ -1,11 +1,26 @@
 //! ABI dispatch for the [`TIP20Factory`] precompile.
 
 use crate::{
-    Precompile, charge_input_cost, dispatch_call, mutate, tip20_factory::TIP20Factory, view,
+    Precompile, SelectorSchedule, charge_input_cost, dispatch_call, interface_id, mutate,
+    mutate_void, tip20_factory::TIP20Factory, view,
+};
+use alloy::{
+    primitives::{Address, U256},
+    sol_types::{SolCall, SolInterface},
 };
-use alloy::{primitives::Address, sol_types::SolInterface};
 use revm::precompile::PrecompileResult;
-use tempo_contracts::precompiles::ITIP20Factory::ITIP20FactoryCalls;
+use tempo_chainspec::hardfork::TempoHardfork;
+use tempo_contracts::precompiles::ITIP20Factory::{self, ITIP20FactoryCalls};
+
+const T4_ADDED: &[[u8; 4]] = &[
+    ITIP20Factory::tokenURICall::SELECTOR,
+    ITIP20Factory::setTokenURICall::SELECTOR,
+    ITIP20Factory::tokenCountCall::SELECTOR,
+    ITIP20Factory::allTokensCall::SELECTOR,
+];
+
+/// ERC-165 interface ids served by `supportsInterface` (T4+).
+const INTERFACE_IDS: &[[u8; 4]] = &[interface_id(ITIP20FactoryCalls::SELECTORS)];
 
 impl Precompile for TIP20Factory {
     fn call(&mut self, calldata: &[u8], msg_sender: Address) -> PrecompileResult {
@@ -15,7 +30,8 @@ impl Precompile for TIP20Factory {
 
         dispatch_call(
             calldata,
-            &[],
+            &[SelectorSchedule::new(TempoHardfork::T4).with_added(T4_ADDED)],
+            INTERFACE_IDS,
             ITIP20FactoryCalls::abi_decode,
             |call| match call {
                 ITIP20FactoryCalls::createToken(call) => {
@@ -25,6 +41,14 @@ impl Precompile for TIP20Factory {
                 ITIP20FactoryCalls::getTokenAddress(call) => {
                     view(call, |c| self.get_token_address(c))
                 }
+                ITIP20FactoryCalls::tokenURI(call) => view(call, |c| self.token_uri(c.token)),
+                ITIP20FactoryCalls::setTokenURI(call) => {
+                    mutate_void(call, msg_sender, |s, c| self.set_token_uri(s, c))
+                }
+                ITIP20FactoryCalls::tokenCount(call) => {
+                    view(call, |_| self.token_count().map(U256::from))
+                }
+                ITIP20FactoryCalls::allTokens(call) => view(call, |c| self.all_tokens(c)),
             },
         )
     }
@@ -41,7 +65,9 @@ mod tests {
 
     #[test]
     fn tip20_factory_test_selector_coverage() {
-        let mut storage = HashMapStorageProvider::new(1);
+        // Use T4 hardfork so T4-gated selectors (tokenURI, setTokenURI,
+        // tokenCount, allTokens) are active.
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
 
         StorageCtx::enter(&mut storage, || {
             let mut factory = TIP20Factory::new();
@@ -10,7 +10,8 @@ use tempo_precompiles_macros::contract;
 use crate::{
     PATH_USD_ADDRESS, TIP20_FACTORY_ADDRESS,
     error::{Result, TempoPrecompileError},
-    tip20::{TIP20Error, TIP20Token, USD_CURRENCY},
+    storage::Mapping,
+    tip20::{TIP20Error, TIP20Token, USD_CURRENCY, roles::DEFAULT_ADMIN_ROLE},
 };
 use alloy::{
     primitives::{Address, B256, keccak256},
@@ -35,7 +36,15 @@ const TIP20_PREFIX_BYTES: [u8; 12] = [
 /// The struct fields define the on-chain storage layout; the `#[contract]` macro generates the
 /// storage handlers which provide an ergonomic way to interact with the EVM state.
 #[contract(addr = TIP20_FACTORY_ADDRESS)]
-pub struct TIP20Factory {}
+pub struct TIP20Factory {
+    /// Number of tokens registered for enumeration. Only counts tokens deployed
+    /// after T4 activates; earlier deployments predate the registry slots.
+    token_count: u64,
+    /// Maps a registration index (`< token_count`) to the deployed token address.
+    tokens: Mapping<u64, Address>,
+    /// Optional metadata URI per token, set by the token admin via `setTokenURI`.
+    token_uris: Mapping<Address, String>,
+}
 
 /// Computes the deterministic TIP20 address from sender and salt.
 /// Returns the address and the lower bytes used for derivation.
@@ -145,6 +154,8 @@ impl TIP20Factory {
             call.admin,
         )?;
 
+        self.register_token(token_address)?;
+
         self.emit_event(TIP20FactoryEvent::TokenCreated(
             ITIP20Factory::TokenCreated {
                 token: token_address,
@@ -219,6 +230,8 @@ impl TIP20Factory {
         let mut token = TIP20Token::from_address(address)?;
         token.initialize(admin, name, symbol, currency, quote_token, admin)?;
 
+        self.register_token(address)?;
+
         self.emit_event(TIP20FactoryEvent::TokenCreated(
             ITIP20Factory::TokenCreated {
                 token: address,
@@ -233,6 +246,86 @@ impl TIP20Factory {
 
         Ok(address)
     }
+
+    /// Appends `token` to the enumeration registry.
+    ///
+    /// The registry slots only exist from T4; earlier blocks must not write
+    /// them so that historical state roots are preserved.
+    fn register_token(&mut self, token: Address) -> Result<()> {
+        if !self.storage.spec().is_t4() {
+            return Ok(());
+        }
+        let index = self.token_count.read()?;
+        self.tokens[index].write(token)?;
+        self.token_count.write(index + 1)
+    }
+
+    /// Returns the number of tokens registered for enumeration.
+    pub fn token_count(&self) -> Result<u64> {
+        self.token_count.read()
+    }
+
+    /// Returns the metadata URI for `token`, or an empty string if unset.
+    ///
+    /// # Errors
+    /// - `InvalidToken` — `token` is not a deployed TIP-20
+    pub fn token_uri(&self, token: Address) -> Result<String> {
+        if !self.is_tip20(token)? {
+            return Err(TIP20Error::invalid_token().into());
+        }
+        self.token_uris[token].read()
+    }
+
+    /// Sets the metadata URI for `token`. Only the token's admin
+    /// (`DEFAULT_ADMIN_ROLE` holder) may call this.
+    ///
+    /// # Errors
+    /// - `InvalidToken` — `token` is not a deployed TIP-20
+    /// - `Unauthorized` — caller does not hold the token's `DEFAULT_ADMIN_ROLE`
+    pub fn set_token_uri(
+        &mut self,
+        sender: Address,
+        call: ITIP20Factory::setTokenURICall,
+    ) -> Result<()> {
+        if !self.is_tip20(call.token)? {
+            return Err(TIP20Error::invalid_token().into());
+        }
+        TIP20Token::from_address(call.token)?.check_role(sender, DEFAULT_ADMIN_ROLE)?;
+
+        self.token_uris[call.token].write(call.uri.clone())?;
+
+        self.emit_event(TIP20FactoryEvent::TokenURISet(ITIP20Factory::TokenURISet {
+            token: call.token,
+            uri: call.uri,
+        }))
+    }
+
+    /// Returns metadata for registered tokens in creation order, starting at
+    /// `offset` and returning at most `limit` entries. Out-of-range offsets
+    /// yield an empty list, so wallets can page until exhaustion.
+    pub fn all_tokens(
+        &self,
+        call: ITIP20Factory::allTokensCall,
+    ) -> Result<Vec<ITIP20Factory::TokenInfo>> {
+        let total = self.token_count.read()?;
+        let offset = u64::try_from(call.offset).unwrap_or(u64::MAX);
+        let limit = u64::try_from(call.limit).unwrap_or(u64::MAX);
+        let end = offset.saturating_add(limit).min(total);
+
+        let mut infos = Vec::new();
+        for index in offset..end {
+            let address = self.tokens[index].read()?;
+            let token = TIP20Token::from_address(address)?;
+            infos.push(ITIP20Factory::TokenInfo {
+                token: address,
+                name: token.name()?,
+                symbol: token.symbol()?,
+                decimals: token.decimals()?,
+                uri: self.token_uris[address].read()?,
+            });
+        }
+        Ok(infos)
+    }
 }
 
 #[cfg(test)]
@@ -838,6 +931,128 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_all_tokens_enumeration() -> eyre::Result<()> {
+        use tempo_chainspec::hardfork::TempoHardfork;
+
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        let admin = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut factory = TIP20Setup::factory()?;
+            let path_usd = TIP20Setup::path_usd(admin).apply()?;
+            let token_a = TIP20Setup::create("Token A", "TKA", admin).apply()?;
+            let token_b = TIP20Setup::create("Token B", "TKB", admin).apply()?;
+
+            assert_eq!(factory.token_count()?, 3);
+
+            // Full enumeration in creation order.
+            let infos = factory.all_tokens(ITIP20Factory::allTokensCall {
+                offset: alloy::primitives::U256::ZERO,
+                limit: alloy::primitives::U256::from(10),
+            })?;
+            assert_eq!(infos.len(), 3);
+            assert_eq!(infos[0].token, path_usd.address());
+            assert_eq!(infos[1].token, token_a.address());
+            assert_eq!(infos[1].name, "Token A");
+            assert_eq!(infos[1].symbol, "TKA");
+            assert_eq!(infos[1].decimals, 6);
+            assert_eq!(infos[1].uri, "");
+            assert_eq!(infos[2].token, token_b.address());
+
+            // Paging: offset past the end yields an empty list.
+            let page = factory.all_tokens(ITIP20Factory::allTokensCall {
+                offset: alloy::primitives::U256::from(2),
+                limit: alloy::primitives::U256::from(10),
+            })?;
+            assert_eq!(page.len(), 1);
+            assert_eq!(page[0].token, token_b.address());
+
+            let empty = factory.all_tokens(ITIP20Factory::allTokensCall {
+                offset: alloy::primitives::U256::from(3),
+                limit: alloy::primitives::U256::from(10),
+            })?;
+            assert!(empty.is_empty());
+
+            // URIs set via setTokenURI show up in the enumeration.
+            factory.set_token_uri(
+                admin,
+                ITIP20Factory::setTokenURICall {
+                    token: token_a.address(),
+                    uri: "https://tokens.tempo.xyz/tka.json".to_string(),
+                },
+            )?;
+            assert_eq!(
+                factory.token_uri(token_a.address())?,
+                "https://tokens.tempo.xyz/tka.json"
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_set_token_uri_requires_token_admin() -> eyre::Result<()> {
+        use crate::tip20::RolesAuthError;
+        use tempo_chainspec::hardfork::TempoHardfork;
+
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        let admin = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let mut factory = TIP20Setup::factory()?;
+            TIP20Setup::path_usd(admin).apply()?;
+            let token = TIP20Setup::create("Token", "TKN", admin).apply()?;
+
+            // Non-admin cannot set the URI.
+            let result = factory.set_token_uri(
+                Address::random(),
+                ITIP20Factory::setTokenURICall {
+                    token: token.address(),
+                    uri: "https://example.com".to_string(),
+                },
+            );
+            assert!(matches!(
+                result,
+                Err(TempoPrecompileError::RolesAuthError(
+                    RolesAuthError::Unauthorized(_)
+                ))
+            ));
+
+            // Setting a URI on a non-TIP20 address is rejected.
+            let result = factory.set_token_uri(
+                admin,
+                ITIP20Factory::setTokenURICall {
+                    token: Address::random(),
+                    uri: "https://example.com".to_string(),
+                },
+            );
+            assert_eq!(
+                result.unwrap_err(),
+                TempoPrecompileError::TIP20(TIP20Error::invalid_token())
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_registration_is_inactive_before_t4() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1);
+        let admin = Address::random();
+
+        StorageCtx::enter(&mut storage, || {
+            let factory = TIP20Setup::factory()?;
+            TIP20Setup::path_usd(admin).apply()?;
+            TIP20Setup::create("Token", "TKN", admin).apply()?;
+
+            // Pre-T4 deployments must not touch the registry slots.
+            assert_eq!(factory.token_count()?, 0);
+
+            Ok(())
+        })
+    }
+
     #[test]
     fn test_get_token_address_reserved_boundary() {
         let sender = Address::ZERO;
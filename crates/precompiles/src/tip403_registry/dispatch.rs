@@ -1,7 +1,8 @@
 //! ABI dispatch for the [`TIP403Registry`] precompile.
 
 use crate::{
-    Precompile, SelectorSchedule, charge_input_cost, dispatch_call, mutate, mutate_void,
+    Precompile, SelectorSchedule, charge_input_cost, dispatch_call, interface_id, mutate,
+    mutate_void,
     tip403_registry::{AuthRole, TIP403Registry},
     view,
 };
@@ -21,6 +22,17 @@ const T2_ADDED: &[[u8; 4]] = &[
     ITIP403Registry::createCompoundPolicyCall::SELECTOR,
 ];
 
+const T4_ADDED: &[[u8; 4]] = &[
+    ITIP403Registry::attestationExpiryCall::SELECTOR,
+    ITIP403Registry::setAttestationExpiryCall::SELECTOR,
+    ITIP403Registry::renewAttestationCall::SELECTOR,
+    ITIP403Registry::markAttestationExpiredCall::SELECTOR,
+    ITIP403Registry::expiringBetweenCall::SELECTOR,
+];
+
+/// ERC-165 interface ids served by `supportsInterface` (T4+).
+const INTERFACE_IDS: &[[u8; 4]] = &[interface_id(ITIP403RegistryCalls::SELECTORS)];
+
 impl Precompile for TIP403Registry {
     fn call(&mut self, calldata: &[u8], msg_sender: Address) -> PrecompileResult {
         if let Some(err) = charge_input_cost(&mut self.storage, calldata) {
@@ -29,7 +41,11 @@ impl Precompile for TIP403Registry {
 
         dispatch_call(
             calldata,
-            &[SelectorSchedule::new(TempoHardfork::T2).with_added(T2_ADDED)],
+            &[
+                SelectorSchedule::new(TempoHardfork::T2).with_added(T2_ADDED),
+                SelectorSchedule::new(TempoHardfork::T4).with_added(T4_ADDED),
+            ],
+            INTERFACE_IDS,
             ITIP403RegistryCalls::abi_decode,
             |call| match call {
                 ITIP403RegistryCalls::policyIdCounter(call) => {
@@ -74,6 +90,22 @@ impl Precompile for TIP403Registry {
                 ITIP403RegistryCalls::createCompoundPolicy(call) => {
                     mutate(call, msg_sender, |s, c| self.create_compound_policy(s, c))
                 }
+                // Attestation expiry: T4+ only (gated via T4_ADDED)
+                ITIP403RegistryCalls::attestationExpiry(call) => {
+                    view(call, |c| self.attestation_expiry(c))
+                }
+                ITIP403RegistryCalls::setAttestationExpiry(call) => {
+                    mutate_void(call, msg_sender, |s, c| self.set_attestation_expiry(s, c))
+                }
+                ITIP403RegistryCalls::renewAttestation(call) => {
+                    mutate_void(call, msg_sender, |s, c| self.renew_attestation(s, c))
+                }
+                ITIP403RegistryCalls::markAttestationExpired(call) => {
+                    mutate_void(call, msg_sender, |_, c| self.mark_attestation_expired(c))
+                }
+                ITIP403RegistryCalls::expiringBetween(call) => {
+                    view(call, |c| self.expiring_between(c))
+                }
             },
         )
     }
@@ -87,9 +119,11 @@ mod tests {
         test_util::{assert_full_coverage, check_selector_coverage},
         tip403_registry::ITIP403Registry,
     };
-    use alloy::sol_types::{SolCall, SolValue};
+    use alloy::sol_types::{SolCall, SolError, SolValue};
     use tempo_chainspec::hardfork::TempoHardfork;
-    use tempo_contracts::precompiles::ITIP403Registry::ITIP403RegistryCalls;
+    use tempo_contracts::precompiles::{
+        ITIP403Registry::ITIP403RegistryCalls, UnknownFunctionSelector,
+    };
 
     #[test]
     fn test_is_authorized_precompile() -> eyre::Result<()> {
@@ -531,10 +565,30 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_attestation_expiry_selectors_gated_behind_t4() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T3);
+        StorageCtx::enter(&mut storage, || {
+            let mut registry = TIP403Registry::new();
+
+            let calldata = ITIP403Registry::attestationExpiryCall {
+                policyId: 2,
+                account: Address::random(),
+            }
+            .abi_encode();
+            let result = registry.call(&calldata, Address::random())?;
+            assert!(result.is_revert());
+            assert!(UnknownFunctionSelector::abi_decode(&result.bytes).is_ok());
+
+            Ok(())
+        })
+    }
+
     #[test]
     fn test_selector_coverage() -> eyre::Result<()> {
-        // Use T2 to test all selectors including TIP-1015 compound policy functions
-        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T2);
+        // Run at T4 so the attestation-expiry selectors are active alongside the
+        // TIP-1015 compound policy functions.
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
         StorageCtx::enter(&mut storage, || {
             let mut registry = TIP403Registry::new();
 
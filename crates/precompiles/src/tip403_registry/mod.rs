@@ -17,9 +17,9 @@ use tempo_precompiles_macros::{Storable, contract};
 use crate::{
     TIP403_REGISTRY_ADDRESS,
     error::{Result, TempoPrecompileError},
-    storage::{Handler, Mapping},
+    storage::{Handler, Mapping, Set},
 };
-use alloy::primitives::Address;
+use alloy::primitives::{Address, U256};
 use tempo_primitives::TempoAddressExt;
 
 /// Built-in policy ID that always rejects authorization.
@@ -48,6 +48,12 @@ pub struct TIP403Registry {
     /// value is `true` when the address is allowed; for blacklists it is `true` when the
     /// address is restricted.
     policy_set: Mapping<u64, Mapping<Address, bool>>,
+    /// Expiry timestamp (Unix seconds) for a policy-set entry; zero means the attestation
+    /// never expires. Lapsed entries are treated as absent by authorization checks (T4+).
+    attestation_expiry: Mapping<u64, Mapping<Address, u64>>,
+    /// Per-policy index of accounts carrying a non-zero expiry, backing the paginated
+    /// `expiringBetween` view.
+    expiring_accounts: Mapping<u64, Set<Address>>,
 }
 
 /// Policy record containing base data and optional data for compound policies ([TIP-1015])
@@ -536,6 +542,157 @@ impl TIP403Registry {
         Ok(new_policy_id)
     }
 
+    /// Returns the expiry timestamp of a policy-set entry (`0` = never expires).
+    pub fn attestation_expiry(&self, call: ITIP403Registry::attestationExpiryCall) -> Result<u64> {
+        self.attestation_expiry[call.policyId][call.account].read()
+    }
+
+    /// Sets (or clears, with `expiry == 0`) the expiry of an existing policy-set entry.
+    /// Admin-only.
+    ///
+    /// # Errors
+    /// - `Unauthorized` — `msg_sender` is not the policy admin
+    /// - `IncompatiblePolicyType` — the policy is not simple
+    /// - `AttestationNotFound` — the account is not in the policy set
+    /// - `InvalidExpiry` — the expiry is non-zero but not in the future
+    /// - `PolicyNotFound` — the policy ID does not exist
+    pub fn set_attestation_expiry(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP403Registry::setAttestationExpiryCall,
+    ) -> Result<()> {
+        let data = self.get_policy_data(call.policyId)?;
+        if data.admin != msg_sender {
+            return Err(TIP403RegistryError::unauthorized().into());
+        }
+        if !data.is_simple() {
+            return Err(TIP403RegistryError::incompatible_policy_type().into());
+        }
+        if !self.policy_set[call.policyId][call.account].read()? {
+            return Err(TIP403RegistryError::attestation_not_found().into());
+        }
+        if call.expiry != 0 && U256::from(call.expiry) <= self.storage.timestamp() {
+            return Err(TIP403RegistryError::invalid_expiry().into());
+        }
+
+        let previous = self.attestation_expiry[call.policyId][call.account].read()?;
+        self.attestation_expiry[call.policyId][call.account].write(call.expiry)?;
+        if call.expiry == 0 {
+            self.expiring_accounts[call.policyId].remove(&call.account)?;
+        } else if previous == 0 {
+            self.expiring_accounts[call.policyId].insert(call.account)?;
+        }
+
+        self.emit_event(TIP403RegistryEvent::AttestationExpirySet(
+            ITIP403Registry::AttestationExpirySet {
+                policyId: call.policyId,
+                updater: msg_sender,
+                account: call.account,
+                expiry: call.expiry,
+            },
+        ))
+    }
+
+    /// Extends the expiry of an attestation. Admin-only. Renewing a lapsed attestation
+    /// reinstates it, since the underlying policy-set entry is kept until it is explicitly
+    /// removed or swept via `markAttestationExpired`.
+    ///
+    /// # Errors
+    /// - `Unauthorized` — `msg_sender` is not the policy admin
+    /// - `AttestationNotFound` — the entry carries no expiry (nothing to renew)
+    /// - `InvalidExpiry` — the new expiry does not extend the current one, or is not in the
+    ///   future
+    /// - `PolicyNotFound` — the policy ID does not exist
+    pub fn renew_attestation(
+        &mut self,
+        msg_sender: Address,
+        call: ITIP403Registry::renewAttestationCall,
+    ) -> Result<()> {
+        let data = self.get_policy_data(call.policyId)?;
+        if data.admin != msg_sender {
+            return Err(TIP403RegistryError::unauthorized().into());
+        }
+
+        let current = self.attestation_expiry[call.policyId][call.account].read()?;
+        if current == 0 {
+            return Err(TIP403RegistryError::attestation_not_found().into());
+        }
+        if call.newExpiry <= current || U256::from(call.newExpiry) <= self.storage.timestamp() {
+            return Err(TIP403RegistryError::invalid_expiry().into());
+        }
+
+        self.attestation_expiry[call.policyId][call.account].write(call.newExpiry)?;
+
+        self.emit_event(TIP403RegistryEvent::AttestationExpirySet(
+            ITIP403Registry::AttestationExpirySet {
+                policyId: call.policyId,
+                updater: msg_sender,
+                account: call.account,
+                expiry: call.newExpiry,
+            },
+        ))
+    }
+
+    /// Removes a lapsed attestation from the policy set and emits `AttestationExpired`.
+    /// Permissionless housekeeping: authorization checks already treat the entry as absent,
+    /// so anyone may surface the denial on-chain and reclaim the storage.
+    ///
+    /// # Errors
+    /// - `InvalidExpiry` — the entry has no expiry or has not lapsed yet
+    pub fn mark_attestation_expired(
+        &mut self,
+        call: ITIP403Registry::markAttestationExpiredCall,
+    ) -> Result<()> {
+        let expiry = self.attestation_expiry[call.policyId][call.account].read()?;
+        if expiry == 0 || U256::from(expiry) > self.storage.timestamp() {
+            return Err(TIP403RegistryError::invalid_expiry().into());
+        }
+
+        self.policy_set[call.policyId][call.account].write(false)?;
+        self.attestation_expiry[call.policyId][call.account].write(0)?;
+        self.expiring_accounts[call.policyId].remove(&call.account)?;
+
+        self.emit_event(TIP403RegistryEvent::AttestationExpired(
+            ITIP403Registry::AttestationExpired {
+                policyId: call.policyId,
+                account: call.account,
+                expiry,
+            },
+        ))
+    }
+
+    /// Returns up to `limit` accounts (starting at `offset` in the per-policy expiry index)
+    /// whose attestations expire within `[t0, t1]`. Pagination runs over the index, so pages
+    /// may return fewer than `limit` matches; callers advance `offset` by `limit` until an
+    /// empty page.
+    ///
+    /// # Errors
+    /// - `PolicyNotFound` — the policy ID does not exist
+    pub fn expiring_between(
+        &self,
+        call: ITIP403Registry::expiringBetweenCall,
+    ) -> Result<Vec<Address>> {
+        if !self.policy_exists(ITIP403Registry::policyExistsCall {
+            policyId: call.policyId,
+        })? {
+            return Err(TIP403RegistryError::policy_not_found().into());
+        }
+
+        let index = &self.expiring_accounts[call.policyId];
+        let len = index.len()?;
+        let start = (call.offset as usize).min(len);
+        let end = start.saturating_add(call.limit as usize).min(len);
+
+        let mut accounts = Vec::new();
+        for account in index.read_range(start, end)? {
+            let expiry = self.attestation_expiry[call.policyId][account].read()?;
+            if expiry >= call.t0 && expiry <= call.t1 {
+                accounts.push(account);
+            }
+        }
+        Ok(accounts)
+    }
+
     /// Core role-based authorization check ([TIP-1015]). Resolves built-in policies (0 = reject,
     /// 1 = allow) immediately, delegates compound policies to their sub-policies, and evaluates
     /// simple policies via `is_simple`.
@@ -608,6 +765,10 @@ impl TIP403Registry {
         // This order must be preserved for block re-execution compatibility.
         let is_in_set = self.policy_set[policy_id][user].read()?;
 
+        // T4+: a lapsed attestation is treated as absent from the set, for whitelists
+        // (authorization withdrawn) and blacklists (restriction lapsed) alike.
+        let is_in_set = is_in_set && !self.is_attestation_expired(policy_id, user)?;
+
         match data.policy_type()? {
             PolicyType::WHITELIST => Ok(is_in_set),
             PolicyType::BLACKLIST => Ok(!is_in_set),
@@ -661,8 +822,27 @@ impl TIP403Registry {
     }
 
     fn set_policy_set(&mut self, policy_id: u64, account: Address, value: bool) -> Result<()> {
+        // T4+: dropping an entry also drops its expiry metadata, so a later re-add starts
+        // from a clean, non-expiring state.
+        if !value
+            && self.storage.spec().is_t4()
+            && self.attestation_expiry[policy_id][account].read()? != 0
+        {
+            self.attestation_expiry[policy_id][account].write(0)?;
+            self.expiring_accounts[policy_id].remove(&account)?;
+        }
         self.policy_set[policy_id][account].write(value)
     }
+
+    /// Returns `true` if the entry carries an expiry that has passed. Always `false` before
+    /// T4, where expiry metadata is inaccessible and therefore ignored.
+    fn is_attestation_expired(&self, policy_id: u64, account: Address) -> Result<bool> {
+        if !self.storage.spec().is_t4() {
+            return Ok(false);
+        }
+        let expiry = self.attestation_expiry[policy_id][account].read()?;
+        Ok(expiry != 0 && U256::from(expiry) <= self.storage.timestamp())
+    }
 }
 
 impl AuthRole {
@@ -2371,4 +2551,281 @@ mod tests {
             Ok(())
         })
     }
+
+    #[test]
+    fn test_attestation_expiry_gates_whitelist_authorization() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        storage.set_timestamp(U256::from(1_000));
+        let admin = Address::random();
+        let user = Address::random();
+        let policy_id = StorageCtx::enter(&mut storage, || {
+            let mut registry = TIP403Registry::new();
+
+            let policy_id = registry.create_policy(
+                admin,
+                ITIP403Registry::createPolicyCall {
+                    admin,
+                    policyType: ITIP403Registry::PolicyType::WHITELIST,
+                },
+            )?;
+            registry.modify_policy_whitelist(
+                admin,
+                ITIP403Registry::modifyPolicyWhitelistCall {
+                    policyId: policy_id,
+                    account: user,
+                    allowed: true,
+                },
+            )?;
+
+            // Non-admins cannot set expiries.
+            assert!(matches!(
+                registry.set_attestation_expiry(
+                    user,
+                    ITIP403Registry::setAttestationExpiryCall {
+                        policyId: policy_id,
+                        account: user,
+                        expiry: 2_000,
+                    },
+                ),
+                Err(TempoPrecompileError::TIP403RegistryError(
+                    TIP403RegistryError::Unauthorized(_)
+                ))
+            ));
+
+            // Expiries must lie in the future.
+            assert!(matches!(
+                registry.set_attestation_expiry(
+                    admin,
+                    ITIP403Registry::setAttestationExpiryCall {
+                        policyId: policy_id,
+                        account: user,
+                        expiry: 1_000,
+                    },
+                ),
+                Err(TempoPrecompileError::TIP403RegistryError(
+                    TIP403RegistryError::InvalidExpiry(_)
+                ))
+            ));
+
+            // Accounts outside the set cannot carry an expiry.
+            assert!(matches!(
+                registry.set_attestation_expiry(
+                    admin,
+                    ITIP403Registry::setAttestationExpiryCall {
+                        policyId: policy_id,
+                        account: Address::random(),
+                        expiry: 2_000,
+                    },
+                ),
+                Err(TempoPrecompileError::TIP403RegistryError(
+                    TIP403RegistryError::AttestationNotFound(_)
+                ))
+            ));
+
+            registry.set_attestation_expiry(
+                admin,
+                ITIP403Registry::setAttestationExpiryCall {
+                    policyId: policy_id,
+                    account: user,
+                    expiry: 2_000,
+                },
+            )?;
+
+            // Still authorized before the expiry.
+            assert!(registry.is_authorized_as(policy_id, user, AuthRole::Transfer)?);
+
+            Ok::<_, eyre::Report>(policy_id)
+        })?;
+
+        // Warp block time past the expiry.
+        storage.set_timestamp(U256::from(2_000));
+
+        StorageCtx::enter(&mut storage, || {
+            let mut registry = TIP403Registry::new();
+
+            // Once lapsed, the attestation is treated as absent.
+            assert!(!registry.is_authorized_as(policy_id, user, AuthRole::Transfer)?);
+
+            // Renewal past the current expiry reinstates the attestation.
+            registry.renew_attestation(
+                admin,
+                ITIP403Registry::renewAttestationCall {
+                    policyId: policy_id,
+                    account: user,
+                    newExpiry: 3_000,
+                },
+            )?;
+            assert!(registry.is_authorized_as(policy_id, user, AuthRole::Transfer)?);
+
+            // A renewal that does not extend the expiry is rejected.
+            assert!(matches!(
+                registry.renew_attestation(
+                    admin,
+                    ITIP403Registry::renewAttestationCall {
+                        policyId: policy_id,
+                        account: user,
+                        newExpiry: 2_500,
+                    },
+                ),
+                Err(TempoPrecompileError::TIP403RegistryError(
+                    TIP403RegistryError::InvalidExpiry(_)
+                ))
+            ));
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_expired_blacklist_restriction_lapses() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        storage.set_timestamp(U256::from(1_000));
+        let admin = Address::random();
+        let user = Address::random();
+        let policy_id = StorageCtx::enter(&mut storage, || {
+            let mut registry = TIP403Registry::new();
+
+            let policy_id = registry.create_policy(
+                admin,
+                ITIP403Registry::createPolicyCall {
+                    admin,
+                    policyType: ITIP403Registry::PolicyType::BLACKLIST,
+                },
+            )?;
+            registry.modify_policy_blacklist(
+                admin,
+                ITIP403Registry::modifyPolicyBlacklistCall {
+                    policyId: policy_id,
+                    account: user,
+                    restricted: true,
+                },
+            )?;
+            registry.set_attestation_expiry(
+                admin,
+                ITIP403Registry::setAttestationExpiryCall {
+                    policyId: policy_id,
+                    account: user,
+                    expiry: 2_000,
+                },
+            )?;
+
+            // Restricted while the entry is live.
+            assert!(!registry.is_authorized_as(policy_id, user, AuthRole::Transfer)?);
+
+            Ok::<_, eyre::Report>(policy_id)
+        })?;
+
+        // Warp block time past the expiry: the restriction lapses.
+        storage.set_timestamp(U256::from(2_000));
+
+        StorageCtx::enter(&mut storage, || {
+            let registry = TIP403Registry::new();
+            assert!(registry.is_authorized_as(policy_id, user, AuthRole::Transfer)?);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_mark_expired_and_expiring_between_pagination() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        storage.set_timestamp(U256::from(1_000));
+        let admin = Address::random();
+        let early = Address::random();
+        let late = Address::random();
+        let policy_id = StorageCtx::enter(&mut storage, || {
+            let mut registry = TIP403Registry::new();
+
+            let policy_id = registry.create_policy(
+                admin,
+                ITIP403Registry::createPolicyCall {
+                    admin,
+                    policyType: ITIP403Registry::PolicyType::WHITELIST,
+                },
+            )?;
+            for (account, expiry) in [(early, 2_000), (late, 5_000)] {
+                registry.modify_policy_whitelist(
+                    admin,
+                    ITIP403Registry::modifyPolicyWhitelistCall {
+                        policyId: policy_id,
+                        account,
+                        allowed: true,
+                    },
+                )?;
+                registry.set_attestation_expiry(
+                    admin,
+                    ITIP403Registry::setAttestationExpiryCall {
+                        policyId: policy_id,
+                        account,
+                        expiry,
+                    },
+                )?;
+            }
+
+            // Window selects only the earlier expiry; pagination walks the index.
+            let expiring = registry.expiring_between(ITIP403Registry::expiringBetweenCall {
+                policyId: policy_id,
+                t0: 1_500,
+                t1: 3_000,
+                offset: 0,
+                limit: 10,
+            })?;
+            assert_eq!(expiring, vec![early]);
+
+            let page = registry.expiring_between(ITIP403Registry::expiringBetweenCall {
+                policyId: policy_id,
+                t0: 0,
+                t1: u64::MAX,
+                offset: 1,
+                limit: 10,
+            })?;
+            assert_eq!(page, vec![late]);
+
+            // Sweeping before the expiry has lapsed is rejected.
+            assert!(matches!(
+                registry.mark_attestation_expired(ITIP403Registry::markAttestationExpiredCall {
+                    policyId: policy_id,
+                    account: early,
+                }),
+                Err(TempoPrecompileError::TIP403RegistryError(
+                    TIP403RegistryError::InvalidExpiry(_)
+                ))
+            ));
+
+            Ok::<_, eyre::Report>(policy_id)
+        })?;
+
+        // Warp block time past the earlier expiry.
+        storage.set_timestamp(U256::from(2_500));
+
+        StorageCtx::enter(&mut storage, || {
+            let mut registry = TIP403Registry::new();
+
+            // Once lapsed, anyone may sweep the entry and surface the denial.
+            registry.mark_attestation_expired(ITIP403Registry::markAttestationExpiredCall {
+                policyId: policy_id,
+                account: early,
+            })?;
+            assert!(!registry.is_authorized_as(policy_id, early, AuthRole::Transfer)?);
+            assert_eq!(
+                registry.attestation_expiry(ITIP403Registry::attestationExpiryCall {
+                    policyId: policy_id,
+                    account: early,
+                })?,
+                0
+            );
+
+            // The swept entry left the index.
+            let remaining = registry.expiring_between(ITIP403Registry::expiringBetweenCall {
+                policyId: policy_id,
+                t0: 0,
+                t1: u64::MAX,
+                offset: 0,
+                limit: 10,
+            })?;
+            assert_eq!(remaining, vec![late]);
+
+            Ok(())
+        })
+    }
 }
@@ -21,6 +21,11 @@ pub const MIN_LIQUIDITY: U256 = uint!(1000_U256);
 
 /// Computes the output amount for a fee swap: `amount_in * M / SCALE`.
 ///
+/// Deliberately keeps the checked 256-bit multiplication rather than going
+/// through [`crate::fixed_point::mul_div`]: the 512-bit intermediate would accept
+/// inputs above `U256::MAX / M` that have always reverted here, and this is
+/// consensus-critical fee-swap code.
+///
 /// # Errors
 /// - `UnderOverflow` — multiplication of `amount_in * M` overflows
 #[inline]
@@ -1,7 +1,9 @@
 //! ABI dispatch for the [`TipFeeManager`] precompile.
 
 use crate::{
-    Precompile, charge_input_cost, dispatch_call, metadata, mutate, mutate_void,
+    Precompile, SelectorSchedule, charge_input_cost, dispatch_call, interface_id, metadata, mutate,
+    mutate_void,
+    runtime::with_reentrancy_guard,
     storage::Handler,
     tip_fee_manager::{
         ITIPFeeAMM, TipFeeManager,
@@ -9,9 +11,20 @@ use crate::{
     },
     view,
 };
-use alloy::{primitives::Address, sol_types::SolInterface};
+use alloy::{
+    primitives::Address,
+    sol_types::{SolCall, SolInterface},
+};
 use revm::precompile::PrecompileResult;
-use tempo_contracts::precompiles::{IFeeManager::IFeeManagerCalls, ITIPFeeAMM::ITIPFeeAMMCalls};
+use tempo_chainspec::hardfork::TempoHardfork;
+use tempo_contracts::precompiles::{
+    IFeeManager, IFeeManager::IFeeManagerCalls, ITIPFeeAMM::ITIPFeeAMMCalls,
+};
+
+const T4_ADDED: &[[u8; 4]] = &[
+    IFeeManager::rebatesCall::SELECTOR,
+    IFeeManager::distributeAllFeesCall::SELECTOR,
+];
 
 /// Unified calldata discriminant for both `IFeeManager` and `ITIPFeeAMM` selectors.
 enum TipFeeManagerCall {
@@ -32,6 +45,12 @@ impl TipFeeManagerCall {
     }
 }
 
+/// ERC-165 interface ids served by `supportsInterface` (T4+).
+const INTERFACE_IDS: &[[u8; 4]] = &[
+    interface_id(IFeeManagerCalls::SELECTORS),
+    interface_id(ITIPFeeAMMCalls::SELECTORS),
+];
+
 impl Precompile for TipFeeManager {
     fn call(&mut self, calldata: &[u8], msg_sender: Address) -> PrecompileResult {
         if let Some(err) = charge_input_cost(&mut self.storage, calldata) {
@@ -40,7 +59,8 @@ impl Precompile for TipFeeManager {
 
         dispatch_call(
             calldata,
-            &[],
+            &[SelectorSchedule::new(TempoHardfork::T4).with_added(T4_ADDED)],
+            INTERFACE_IDS,
             TipFeeManagerCall::decode,
             |call| match call {
                 // IFeeManager view functions
@@ -53,6 +73,9 @@ impl Precompile for TipFeeManager {
                 TipFeeManagerCall::FeeManager(IFeeManagerCalls::collectedFees(call)) => {
                     view(call, |c| self.collected_fees[c.validator][c.token].read())
                 }
+                TipFeeManagerCall::FeeManager(IFeeManagerCalls::rebates(call)) => {
+                    view(call, |c| self.rebates[c.user][c.token].read())
+                }
 
                 // IFeeManager mutate functions
                 TipFeeManagerCall::FeeManager(IFeeManagerCalls::setValidatorToken(call)) => {
@@ -66,7 +89,14 @@ impl Precompile for TipFeeManager {
                 }
                 TipFeeManagerCall::FeeManager(IFeeManagerCalls::distributeFees(call)) => {
                     mutate_void(call, msg_sender, |_, c| {
-                        self.distribute_fees(c.validator, c.token)
+                        with_reentrancy_guard(self.address, || {
+                            self.distribute_fees(c.validator, c.token)
+                        })
+                    })
+                }
+                TipFeeManagerCall::FeeManager(IFeeManagerCalls::distributeAllFees(call)) => {
+                    mutate_void(call, msg_sender, |_, _| {
+                        with_reentrancy_guard(self.address, || self.distribute_all_fees())
                     })
                 }
 
@@ -104,28 +134,34 @@ impl Precompile for TipFeeManager {
                 // ITIPFeeAMM mutate functions
                 TipFeeManagerCall::Amm(ITIPFeeAMMCalls::mint(call)) => {
                     mutate(call, msg_sender, |s, c| {
-                        self.mint(
-                            s,
-                            c.userToken,
-                            c.validatorToken,
-                            c.amountValidatorToken,
-                            c.to,
-                        )
+                        with_reentrancy_guard(self.address, || {
+                            self.mint(
+                                s,
+                                c.userToken,
+                                c.validatorToken,
+                                c.amountValidatorToken,
+                                c.to,
+                            )
+                        })
                     })
                 }
                 TipFeeManagerCall::Amm(ITIPFeeAMMCalls::burn(call)) => {
                     mutate(call, msg_sender, |s, c| {
-                        let (amount_user_token, amount_validator_token) =
-                            self.burn(s, c.userToken, c.validatorToken, c.liquidity, c.to)?;
-                        Ok(ITIPFeeAMM::burnReturn {
-                            amountUserToken: amount_user_token,
-                            amountValidatorToken: amount_validator_token,
+                        with_reentrancy_guard(self.address, || {
+                            let (amount_user_token, amount_validator_token) =
+                                self.burn(s, c.userToken, c.validatorToken, c.liquidity, c.to)?;
+                            Ok(ITIPFeeAMM::burnReturn {
+                                amountUserToken: amount_user_token,
+                                amountValidatorToken: amount_validator_token,
+                            })
                         })
                     })
                 }
                 TipFeeManagerCall::Amm(ITIPFeeAMMCalls::rebalanceSwap(call)) => {
                     mutate(call, msg_sender, |s, c| {
-                        self.rebalance_swap(s, c.userToken, c.validatorToken, c.amountOut, c.to)
+                        with_reentrancy_guard(self.address, || {
+                            self.rebalance_swap(s, c.userToken, c.validatorToken, c.amountOut, c.to)
+                        })
                     })
                 }
             },
@@ -382,7 +418,8 @@ mod tests {
 
     #[test]
     fn test_tip_fee_manager_selector_coverage() -> eyre::Result<()> {
-        let mut storage = HashMapStorageProvider::new(1);
+        // Use T4 so the T4-gated `rebates` selector is active.
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
         StorageCtx::enter(&mut storage, || {
             let mut fee_manager = TipFeeManager::new();
 
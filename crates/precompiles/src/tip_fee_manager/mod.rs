@@ -17,7 +17,19 @@ pub use tempo_contracts::precompiles::{
     DEFAULT_FEE_TOKEN, FeeManagerError, FeeManagerEvent, IFeeManager, ITIPFeeAMM,
     TIP_FEE_MANAGER_ADDRESS, TIPFeeAMMError, TIPFeeAMMEvent,
 };
-use tempo_precompiles_macros::contract;
+use tempo_precompiles_macros::{Storable, contract};
+
+/// One (validator, token) pair with an outstanding collected-fee balance.
+///
+/// Kept in the `pending_distributions` index so [`TipFeeManager::distribute_all_fees`] can
+/// enumerate outstanding balances without relying on undefined mapping iteration order.
+#[derive(Debug, Storable)]
+struct PendingDistribution {
+    /// The validator the fees were collected for.
+    validator: Address,
+    /// The TIP-20 token the balance is denominated in.
+    token: Address,
+}
 
 /// Fee manager precompile that handles transaction fee collection and distribution.
 ///
@@ -34,6 +46,16 @@ pub struct TipFeeManager {
     pools: Mapping<B256, Pool>,
     total_supply: Mapping<B256, U256>,
     liquidity_balances: Mapping<B256, Mapping<Address, U256>>,
+    /// T4+: total gas rebates granted per fee payer and fee token.
+    rebates: Mapping<Address, Mapping<Address, U256>>,
+    /// T4+: every (validator, token) pair with an outstanding collected-fee balance.
+    /// Entries are appended on the first increment for a pair and removed (swap-and-pop)
+    /// once the pair is distributed; [`Self::distribute_all_fees`] sorts them in memory so
+    /// distribution order never depends on how fees happened to accrue.
+    pending_distributions: Vec<PendingDistribution>,
+    /// T4+: validator → token → 1-indexed position in `pending_distributions`
+    /// (0 = no outstanding balance).
+    pending_distribution_positions: Mapping<Address, Mapping<Address, u64>>,
 
     // WARNING(rusowsky): transient storage slots must always be placed at the very end until the `contract`
     // macro is refactored and has 2 independent layouts (persistent and transient).
@@ -41,6 +63,27 @@ pub struct TipFeeManager {
     /// T1C+: Tracks liquidity reserved for a pending fee swap during `collect_fee_pre_tx`.
     /// Checked by `burn` and `rebalance_swap` to prevent withdrawals that would violate the reservation.
     pending_fee_swap_reservation: Mapping<B256, u128>,
+    /// T4+: number of TIP-20 transfers the current transaction performed through
+    /// `transferBatch`, reported by the TIP-20 precompile and read back when the
+    /// fee is settled in `collect_fee_post_tx`.
+    batch_transfer_count: u64,
+    /// T4+: scratch space for [`Self::settle_block_fees`] to net repeated
+    /// (payer, token) refunds before applying them as a single balance update.
+    pending_fee_refunds: Mapping<Address, Mapping<Address, U256>>,
+}
+
+/// A single fee refund recorded by the block executor for end-of-block net settlement.
+///
+/// Produced per transaction when fee settlement netting is active (T4+) and consumed in
+/// bulk by [`TipFeeManager::settle_block_fees`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeSettlement {
+    /// The fee payer owed the refund.
+    pub payer: Address,
+    /// The fee token the refund is denominated in.
+    pub token: Address,
+    /// The unused part of the pre-tx escrow owed back to the payer, including rebates.
+    pub amount: U256,
 }
 
 impl TipFeeManager {
@@ -50,6 +93,11 @@ impl TipFeeManager {
     pub const BASIS_POINTS: u64 = 10000;
     /// Minimum TIP-20 balance required for fee operations (1e9).
     pub const MINIMUM_BALANCE: U256 = uint!(1_000_000_000_U256);
+    /// T4+: minimum TIP-20 transfers via `transferBatch` in one transaction to
+    /// qualify for the gas rebate.
+    pub const REBATE_MIN_TRANSFERS: u64 = 10;
+    /// T4+: gas rebate for qualifying batched transfers, in basis points (5%).
+    pub const REBATE_BPS: u64 = 500;
 
     /// Initializes the fee manager precompile.
     pub fn initialize(&mut self) -> Result<()> {
@@ -189,10 +237,15 @@ impl TipFeeManager {
     /// Refunds unused `user_token` to `fee_payer` via [`TIP20Token`], executes the fee swap
     /// through the AMM pool if tokens differ, and accumulates fees for the validator.
     ///
+    /// On T4+, a transaction that performed at least [`Self::REBATE_MIN_TRANSFERS`] TIP-20
+    /// transfers through `transferBatch` earns back [`Self::REBATE_BPS`] of the fee: the
+    /// rebate enlarges the refund and shrinks the spending before the swap, so the
+    /// validator only ever receives the post-rebate fee.
+    ///
     /// # Errors
     /// - `InvalidToken` — `fee_token` does not have a valid TIP-20 prefix
     /// - `InsufficientLiquidity` — AMM pool lacks liquidity for the fee swap
-    /// - `UnderOverflow` — collected-fee accumulator overflows
+    /// - `UnderOverflow` — collected-fee or rebate accumulator overflows
     pub fn collect_fee_post_tx(
         &mut self,
         fee_payer: Address,
@@ -201,7 +254,13 @@ impl TipFeeManager {
         fee_token: Address,
         beneficiary: Address,
     ) -> Result<()> {
-        // Refund unused tokens to user
+        let rebate = self.pending_batch_rebate(actual_spending)?;
+        let actual_spending = actual_spending - rebate;
+        let refund_amount = refund_amount
+            .checked_add(rebate)
+            .ok_or(TempoPrecompileError::under_overflow())?;
+
+        // Refund unused tokens (plus any rebate) to user
         let mut tip20_token = TIP20Token::from_address(fee_token)?;
         tip20_token.transfer_fee_post_tx(fee_payer, refund_amount, actual_spending)?;
 
@@ -221,9 +280,156 @@ impl TipFeeManager {
 
         self.increment_collected_fees(beneficiary, validator_token, amount)?;
 
+        if !rebate.is_zero() {
+            self.grant_rebate(fee_payer, fee_token, rebate)?;
+        }
+
         Ok(())
     }
 
+    /// Variant of [`Self::collect_fee_post_tx`] used when fee settlement netting is active (T4+).
+    ///
+    /// The pre-tx escrow was taken by [`Self::collect_fee_pre_tx`] exactly as on the baseline
+    /// path, so the payer's balance already carries the debit. This applies the same rebate,
+    /// swap, and collected-fee accounting, but leaves the unused escrow with the fee manager:
+    /// the per-transaction `Transfer` event is emitted by [`TIP20Token::note_fee_spent`] while
+    /// the refund credit is deferred to [`Self::settle_block_fees`]. Returns the refund owed
+    /// (including rebates) for the block executor to record in its settlement ledger.
+    ///
+    /// # Errors
+    /// - `InvalidToken` — `fee_token` does not have a valid TIP-20 prefix
+    /// - `InsufficientLiquidity` — AMM pool lacks liquidity for the fee swap
+    /// - `UnderOverflow` — collected-fee or rebate accumulator overflows
+    pub fn collect_fee_post_tx_netted(
+        &mut self,
+        fee_payer: Address,
+        actual_spending: U256,
+        refund_amount: U256,
+        fee_token: Address,
+        beneficiary: Address,
+    ) -> Result<U256> {
+        let rebate = self.pending_batch_rebate(actual_spending)?;
+        let actual_spending = actual_spending - rebate;
+        let refund_amount = refund_amount
+            .checked_add(rebate)
+            .ok_or(TempoPrecompileError::under_overflow())?;
+
+        // Emit the per-transaction transfer event and release the unused spending limit
+        let mut tip20_token = TIP20Token::from_address(fee_token)?;
+        tip20_token.note_fee_spent(fee_payer, refund_amount, actual_spending)?;
+
+        // Execute fee swap and track collected fees
+        let validator_token = self.get_validator_token(beneficiary)?;
+
+        if fee_token != validator_token && !actual_spending.is_zero() {
+            // Execute fee swap immediately and accumulate fees
+            self.execute_fee_swap(fee_token, validator_token, actual_spending)?;
+        }
+
+        let amount = if fee_token == validator_token {
+            actual_spending
+        } else {
+            compute_amount_out(actual_spending)?
+        };
+
+        self.increment_collected_fees(beneficiary, validator_token, amount)?;
+
+        if !rebate.is_zero() {
+            self.grant_rebate(fee_payer, fee_token, rebate)?;
+        }
+
+        Ok(refund_amount)
+    }
+
+    /// Settles the fee refunds accumulated by the block executor over the whole block.
+    ///
+    /// Repeated (payer, token) pairs are first netted in transient storage so that each pair
+    /// is settled with a single balance update via [`TIP20Token::settle_net_refund`], regardless
+    /// of how many transactions the payer submitted. Runs once at the block boundary. Because
+    /// the refunds only release escrow already held by the fee manager, a payer cannot make
+    /// this fail by moving their balance mid-block.
+    ///
+    /// # Errors
+    /// - `InvalidToken` — a settlement token does not have a valid TIP-20 prefix
+    /// - `InsufficientBalance` — the fee manager cannot cover a net refund (the pre-tx
+    ///   escrows always cover the refunds they produce)
+    /// - `UnderOverflow` — a netted refund overflows
+    pub fn settle_block_fees(&mut self, settlements: &[FeeSettlement]) -> Result<()> {
+        // Net repeated (payer, token) pairs in transient storage, remembering first-seen order.
+        let mut order = Vec::new();
+        for settlement in settlements {
+            if settlement.amount.is_zero() {
+                continue;
+            }
+
+            let pending = self.pending_fee_refunds[settlement.payer][settlement.token].t_read()?;
+            if pending.is_zero() {
+                order.push((settlement.payer, settlement.token));
+            }
+            self.pending_fee_refunds[settlement.payer][settlement.token].t_write(
+                pending
+                    .checked_add(settlement.amount)
+                    .ok_or(TempoPrecompileError::under_overflow())?,
+            )?;
+        }
+
+        // Apply one balance update per unique pair and clear the scratch slot.
+        for (payer, token) in order {
+            let net = self.pending_fee_refunds[payer][token].t_read()?;
+            self.pending_fee_refunds[payer][token].t_write(U256::ZERO)?;
+
+            TIP20Token::from_address(token)?.settle_net_refund(payer, net)?;
+        }
+
+        Ok(())
+    }
+
+    /// Records `count` TIP-20 transfers performed through `transferBatch` in the
+    /// current transaction.
+    ///
+    /// Called by the TIP-20 precompile; the counter lives in transient storage
+    /// and is read back when the fee is settled in [`Self::collect_fee_post_tx`].
+    /// No-op before T4 so the rebate cannot activate early.
+    pub fn note_batch_transfers(&mut self, count: u64) -> Result<()> {
+        if !self.storage.spec().is_t4() {
+            return Ok(());
+        }
+
+        let total = self.batch_transfer_count.t_read()?.saturating_add(count);
+        self.batch_transfer_count.t_write(total)
+    }
+
+    /// Rebate owed for the current transaction, or zero if it does not qualify.
+    fn pending_batch_rebate(&self, actual_spending: U256) -> Result<U256> {
+        if !self.storage.spec().is_t4() {
+            return Ok(U256::ZERO);
+        }
+
+        if self.batch_transfer_count.t_read()? < Self::REBATE_MIN_TRANSFERS {
+            return Ok(U256::ZERO);
+        }
+
+        Ok(actual_spending * U256::from(Self::REBATE_BPS) / U256::from(Self::BASIS_POINTS))
+    }
+
+    /// Accumulates a granted rebate in storage and emits the event.
+    fn grant_rebate(&mut self, fee_payer: Address, token: Address, amount: U256) -> Result<()> {
+        let total = self.rebates[fee_payer][token]
+            .read()?
+            .checked_add(amount)
+            .ok_or(TempoPrecompileError::under_overflow())?;
+        self.rebates[fee_payer][token].write(total)?;
+
+        self.emit_event(FeeManagerEvent::FeeRebateGranted(
+            IFeeManager::FeeRebateGranted {
+                feePayer: fee_payer,
+                token,
+                amount,
+                transferCount: U256::from(self.batch_transfer_count.t_read()?),
+            },
+        ))
+    }
+
     /// Increment collected fees for a specific validator and token combination.
     fn increment_collected_fees(
         &mut self,
@@ -242,6 +448,44 @@ impl TipFeeManager {
                 .ok_or(TempoPrecompileError::under_overflow())?,
         )?;
 
+        // Index the pair on its first outstanding balance so distribution loops can
+        // enumerate it deterministically. T4+ only: the index slots did not exist before.
+        if self.storage.spec().is_t4() {
+            let position = self.pending_distribution_positions[validator][token].read()?;
+            if position == 0 {
+                self.pending_distributions
+                    .push(PendingDistribution { validator, token })?;
+                self.pending_distribution_positions[validator][token]
+                    .write(self.pending_distributions.len()? as u64)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes a settled (validator, token) pair from the distribution index via
+    /// swap-and-pop. No-op pre-T4 or when the pair was never indexed (balances that
+    /// accrued before T4 activated).
+    fn remove_pending_distribution(&mut self, validator: Address, token: Address) -> Result<()> {
+        if !self.storage.spec().is_t4() {
+            return Ok(());
+        }
+
+        let position = self.pending_distribution_positions[validator][token].read()?;
+        if position == 0 {
+            return Ok(());
+        }
+
+        let index = position as usize - 1;
+        let last = self.pending_distributions.len()? - 1;
+        if index != last {
+            let moved = self.pending_distributions[last].read()?;
+            self.pending_distribution_positions[moved.validator][moved.token].write(position)?;
+            self.pending_distributions[index].write(moved)?;
+        }
+        self.pending_distributions.pop()?;
+        self.pending_distribution_positions[validator][token].write(0)?;
+
         Ok(())
     }
 
@@ -256,6 +500,7 @@ impl TipFeeManager {
             return Ok(());
         }
         self.collected_fees[validator][token].write(U256::ZERO)?;
+        self.remove_pending_distribution(validator, token)?;
 
         // Transfer fees to validator
         let mut tip20_token = TIP20Token::from_address(token)?;
@@ -279,6 +524,32 @@ impl TipFeeManager {
         Ok(())
     }
 
+    /// Distributes every outstanding collected-fee balance in a single call (T4+).
+    ///
+    /// Loads the `pending_distributions` index and settles pairs sorted by
+    /// `(validator, token)` so every node applies the transfers and events in an
+    /// identical sequence regardless of the order fees accrued in.
+    ///
+    /// # Errors
+    /// - `InvalidToken` — an indexed token does not have a valid TIP-20 prefix
+    pub fn distribute_all_fees(&mut self) -> Result<()> {
+        let count = self.pending_distributions.len()?;
+        let mut pending = Vec::with_capacity(count);
+        for i in 0..count {
+            let entry = self.pending_distributions[i].read()?;
+            pending.push((entry.validator, entry.token));
+        }
+        // The index is append-ordered; sort so distribution order is defined by the
+        // pair itself, not by insertion history.
+        pending.sort_unstable();
+
+        for (validator, token) in pending {
+            self.distribute_fees(validator, token)?;
+        }
+
+        Ok(())
+    }
+
     /// Reads the stored fee token preference for a user.
     pub fn user_tokens(&self, call: IFeeManager::userTokensCall) -> Result<Address> {
         self.user_tokens[call.user].read()
@@ -541,6 +812,254 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_collect_fee_netted_defers_refund() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1);
+        let user = Address::random();
+        let validator = Address::random();
+        let beneficiary = Address::random();
+        StorageCtx::enter(&mut storage, || {
+            let initial_balance = U256::from(100000);
+            let max_amount = U256::from(10000);
+            let actual_spending = U256::from(6000);
+            let refund_amount = U256::from(4000);
+
+            let token = TIP20Setup::create("Test", "TST", user)
+                .with_issuer(user)
+                .with_mint(user, initial_balance)
+                .apply()?;
+
+            let mut fee_manager = TipFeeManager::new();
+
+            fee_manager.set_validator_token(
+                validator,
+                IFeeManager::setValidatorTokenCall {
+                    token: token.address(),
+                },
+                beneficiary,
+            )?;
+
+            // Pre-tx escrows the full reservation exactly as on the baseline path, so
+            // the payer cannot spend it mid-block.
+            fee_manager.collect_fee_pre_tx(user, token.address(), max_amount, validator, false)?;
+            let balance = token.balance_of(ITIP20::balanceOfCall { account: user })?;
+            assert_eq!(balance, initial_balance - max_amount);
+
+            // Post-tx settles accounting and returns the refund owed, but leaves the
+            // unused escrow with the fee manager.
+            let refund = fee_manager.collect_fee_post_tx_netted(
+                user,
+                actual_spending,
+                refund_amount,
+                token.address(),
+                validator,
+            )?;
+            assert_eq!(refund, refund_amount);
+            let balance = token.balance_of(ITIP20::balanceOfCall { account: user })?;
+            assert_eq!(balance, initial_balance - max_amount);
+
+            let tracked_amount = fee_manager.collected_fees[validator][token.address()].read()?;
+            assert_eq!(tracked_amount, actual_spending);
+
+            // End-of-block settlement credits the refund back.
+            fee_manager.settle_block_fees(&[FeeSettlement {
+                payer: user,
+                token: token.address(),
+                amount: refund,
+            }])?;
+            let balance = token.balance_of(ITIP20::balanceOfCall { account: user })?;
+            assert_eq!(balance, initial_balance - actual_spending);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_settle_block_fees_nets_repeated_payers() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1);
+        let admin = Address::random();
+        let payer_a = Address::random();
+        let payer_b = Address::random();
+        StorageCtx::enter(&mut storage, || {
+            let token = TIP20Setup::create("Test", "TST", admin)
+                .with_issuer(admin)
+                .with_mint(payer_a, U256::from(10000))
+                .with_mint(payer_b, U256::from(5000))
+                .with_mint(TIP_FEE_MANAGER_ADDRESS, U256::from(6000))
+                .apply()?;
+
+            let mut fee_manager = TipFeeManager::new();
+
+            // Two refunds for payer A and one for payer B; zero amounts are ignored
+            fee_manager.settle_block_fees(&[
+                FeeSettlement {
+                    payer: payer_a,
+                    token: token.address(),
+                    amount: U256::from(1500),
+                },
+                FeeSettlement {
+                    payer: payer_b,
+                    token: token.address(),
+                    amount: U256::ZERO,
+                },
+                FeeSettlement {
+                    payer: payer_a,
+                    token: token.address(),
+                    amount: U256::from(500),
+                },
+                FeeSettlement {
+                    payer: payer_b,
+                    token: token.address(),
+                    amount: U256::from(1000),
+                },
+            ])?;
+
+            let balance_a = token.balance_of(ITIP20::balanceOfCall { account: payer_a })?;
+            assert_eq!(balance_a, U256::from(12000));
+            let balance_b = token.balance_of(ITIP20::balanceOfCall { account: payer_b })?;
+            assert_eq!(balance_b, U256::from(6000));
+
+            let manager_balance = token.balance_of(ITIP20::balanceOfCall {
+                account: TIP_FEE_MANAGER_ADDRESS,
+            })?;
+            assert_eq!(manager_balance, U256::from(3000));
+
+            // Scratch slots are cleared so a later settlement starts from zero
+            assert_eq!(
+                fee_manager.pending_fee_refunds[payer_a][token.address()].t_read()?,
+                U256::ZERO
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_collect_fee_post_tx_batch_rebate() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        let user = Address::random();
+        let admin = Address::random();
+        let validator = Address::random();
+        let beneficiary = Address::random();
+        StorageCtx::enter(&mut storage, || {
+            let actual_used = U256::from(6000);
+            let refund_amount = U256::from(4000);
+
+            // Mint to FeeManager (simulating collect_fee_pre_tx already happened)
+            // and to the user so it can pay the batched transfers.
+            let mut token = TIP20Setup::create("Test", "TST", admin)
+                .with_issuer(admin)
+                .with_mint(TIP_FEE_MANAGER_ADDRESS, U256::from(100000000000000_u64))
+                .with_mint(user, U256::from(1000))
+                .apply()?;
+
+            let mut fee_manager = TipFeeManager::new();
+            fee_manager.set_validator_token(
+                validator,
+                IFeeManager::setValidatorTokenCall {
+                    token: token.address(),
+                },
+                beneficiary,
+            )?;
+
+            // Ten batched transfers reach the rebate threshold.
+            token.transfer_batch(
+                user,
+                ITIP20::transferBatchCall {
+                    transfers: (0..TipFeeManager::REBATE_MIN_TRANSFERS)
+                        .map(|_| ITIP20::TransferItem {
+                            to: Address::random(),
+                            amount: U256::from(1),
+                        })
+                        .collect(),
+                },
+            )?;
+
+            fee_manager.collect_fee_post_tx(
+                user,
+                actual_used,
+                refund_amount,
+                token.address(),
+                validator,
+            )?;
+
+            // 5% of the 6000 fee is rebated: the validator only accrues the
+            // post-rebate fee and the user gets the rebate on top of the refund.
+            let rebate = actual_used * U256::from(TipFeeManager::REBATE_BPS)
+                / U256::from(TipFeeManager::BASIS_POINTS);
+            let tracked_amount = fee_manager.collected_fees[validator][token.address()].read()?;
+            assert_eq!(tracked_amount, actual_used - rebate);
+            assert_eq!(fee_manager.rebates[user][token.address()].read()?, rebate);
+
+            let balance = token.balance_of(ITIP20::balanceOfCall { account: user })?;
+            assert_eq!(
+                balance,
+                U256::from(1000) - U256::from(TipFeeManager::REBATE_MIN_TRANSFERS)
+                    + refund_amount
+                    + rebate
+            );
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_collect_fee_post_tx_no_rebate_below_threshold() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        let user = Address::random();
+        let admin = Address::random();
+        let validator = Address::random();
+        let beneficiary = Address::random();
+        StorageCtx::enter(&mut storage, || {
+            let actual_used = U256::from(6000);
+
+            let mut token = TIP20Setup::create("Test", "TST", admin)
+                .with_issuer(admin)
+                .with_mint(TIP_FEE_MANAGER_ADDRESS, U256::from(100000000000000_u64))
+                .with_mint(user, U256::from(1000))
+                .apply()?;
+
+            let mut fee_manager = TipFeeManager::new();
+            fee_manager.set_validator_token(
+                validator,
+                IFeeManager::setValidatorTokenCall {
+                    token: token.address(),
+                },
+                beneficiary,
+            )?;
+
+            // One transfer short of the threshold: no rebate.
+            token.transfer_batch(
+                user,
+                ITIP20::transferBatchCall {
+                    transfers: (0..TipFeeManager::REBATE_MIN_TRANSFERS - 1)
+                        .map(|_| ITIP20::TransferItem {
+                            to: Address::random(),
+                            amount: U256::from(1),
+                        })
+                        .collect(),
+                },
+            )?;
+
+            fee_manager.collect_fee_post_tx(
+                user,
+                actual_used,
+                U256::ZERO,
+                token.address(),
+                validator,
+            )?;
+
+            let tracked_amount = fee_manager.collected_fees[validator][token.address()].read()?;
+            assert_eq!(tracked_amount, actual_used);
+            assert_eq!(
+                fee_manager.rebates[user][token.address()].read()?,
+                U256::ZERO
+            );
+
+            Ok(())
+        })
+    }
+
     #[test]
     fn test_rejects_non_usd() -> eyre::Result<()> {
         let mut storage = HashMapStorageProvider::new(1);
@@ -941,6 +1460,94 @@ mod tests {
         })
     }
 
+    /// Distribution must not depend on the order fees happened to accrue: every
+    /// insertion order settles the same pairs to the same balances, and
+    /// `distribute_all_fees` leaves the index fully drained.
+    #[test]
+    fn test_distribute_all_fees_is_insertion_order_independent() -> eyre::Result<()> {
+        let admin = Address::random();
+        let validators = [Address::with_last_byte(0x11), Address::with_last_byte(0x22)];
+
+        // (validator idx, token idx, amount) accruals, replayed in shuffled orders.
+        // The last entry repeats a pair to exercise index dedupe.
+        let accruals = [
+            (0usize, 0usize, 100u64),
+            (1, 0, 250),
+            (0, 1, 75),
+            (1, 1, 40),
+            (0, 0, 1),
+        ];
+        let orders = [[0usize, 1, 2, 3, 4], [4, 3, 2, 1, 0], [2, 4, 0, 3, 1]];
+
+        let mut outcomes = Vec::new();
+        for order in orders {
+            let mut storage = HashMapStorageProvider::new(1);
+            let outcome = StorageCtx::enter(&mut storage, || {
+                let tokens = [
+                    TIP20Setup::create("TokenA", "TKA", admin)
+                        .with_issuer(admin)
+                        .with_mint(TIP_FEE_MANAGER_ADDRESS, U256::from(1000))
+                        .apply()?,
+                    TIP20Setup::create("TokenB", "TKB", admin)
+                        .with_issuer(admin)
+                        .with_mint(TIP_FEE_MANAGER_ADDRESS, U256::from(1000))
+                        .apply()?,
+                ];
+
+                let mut fee_manager = TipFeeManager::new();
+                for i in order {
+                    let (validator, token, amount) = accruals[i];
+                    fee_manager.increment_collected_fees(
+                        validators[validator],
+                        tokens[token].address(),
+                        U256::from(amount),
+                    )?;
+                }
+                // Four unique pairs, regardless of the repeated accrual.
+                assert_eq!(fee_manager.pending_distributions.len()?, 4);
+
+                fee_manager.distribute_all_fees()?;
+
+                // The index is drained and every pair's ledger is cleared.
+                assert_eq!(fee_manager.pending_distributions.len()?, 0);
+                let mut balances = Vec::new();
+                for validator in validators {
+                    for token in &tokens {
+                        assert_eq!(
+                            fee_manager.collected_fees[validator][token.address()].read()?,
+                            U256::ZERO
+                        );
+                        assert_eq!(
+                            fee_manager.pending_distribution_positions[validator][token.address()]
+                                .read()?,
+                            0
+                        );
+                        balances.push(
+                            TIP20Token::from_address(token.address())?
+                                .balance_of(ITIP20::balanceOfCall { account: validator })?,
+                        );
+                    }
+                }
+                Ok::<_, eyre::Report>(balances)
+            })?;
+            outcomes.push(outcome);
+        }
+
+        assert_eq!(outcomes[0], outcomes[1]);
+        assert_eq!(outcomes[0], outcomes[2]);
+        assert_eq!(
+            outcomes[0],
+            vec![
+                U256::from(101),
+                U256::from(75),
+                U256::from(250),
+                U256::from(40)
+            ]
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn test_initialize_sets_storage_state() -> eyre::Result<()> {
         let mut storage = HashMapStorageProvider::new(1);
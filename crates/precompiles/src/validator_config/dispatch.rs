@@ -3,7 +3,7 @@
 use super::ValidatorConfig;
 use crate::{
     Precompile, SelectorSchedule, charge_input_cost, dispatch_call, error::TempoPrecompileError,
-    mutate_void, view,
+    interface_id, mutate_void, view,
 };
 use alloy::{
     primitives::Address,
@@ -15,6 +15,9 @@ use tempo_contracts::precompiles::IValidatorConfig::{self, IValidatorConfigCalls
 
 const T1_ADDED: &[[u8; 4]] = &[IValidatorConfig::changeValidatorStatusByIndexCall::SELECTOR];
 
+/// ERC-165 interface ids served by `supportsInterface` (T4+).
+const INTERFACE_IDS: &[[u8; 4]] = &[interface_id(IValidatorConfigCalls::SELECTORS)];
+
 impl Precompile for ValidatorConfig {
     fn call(&mut self, calldata: &[u8], msg_sender: Address) -> PrecompileResult {
         if let Some(err) = charge_input_cost(&mut self.storage, calldata) {
@@ -24,6 +27,7 @@ impl Precompile for ValidatorConfig {
         dispatch_call(
             calldata,
             &[SelectorSchedule::new(TempoHardfork::T1).with_added(T1_ADDED)],
+            INTERFACE_IDS,
             IValidatorConfigCalls::abi_decode,
             |call| match call {
                 // View functions
@@ -1,11 +1,29 @@
 //! ABI dispatch for the [`ValidatorConfigV2`] precompile (T2+).
 
 use super::*;
-use crate::{Precompile, charge_input_cost, dispatch_call, mutate, mutate_void, view};
-use alloy::{primitives::Address, sol_types::SolInterface};
+use crate::{
+    Precompile, SelectorSchedule, charge_input_cost, dispatch_call, interface_id, mutate,
+    mutate_void, view,
+};
+use alloy::{
+    primitives::Address,
+    sol_types::{SolCall, SolInterface},
+};
 use revm::precompile::PrecompileResult;
+use tempo_chainspec::hardfork::TempoHardfork;
 use tempo_contracts::precompiles::IValidatorConfigV2::IValidatorConfigV2Calls;
 
+const T4_ADDED: &[[u8; 4]] = &[
+    IValidatorConfigV2::getVersionCall::SELECTOR,
+    IValidatorConfigV2::getBlockGasLimitCall::SELECTOR,
+    IValidatorConfigV2::setBlockGasLimitCall::SELECTOR,
+    IValidatorConfigV2::heartbeatCall::SELECTOR,
+    IValidatorConfigV2::lastActiveAtCall::SELECTOR,
+];
+
+/// ERC-165 interface ids served by `supportsInterface` (T4+).
+const INTERFACE_IDS: &[[u8; 4]] = &[interface_id(IValidatorConfigV2Calls::SELECTORS)];
+
 impl Precompile for ValidatorConfigV2 {
     fn call(&mut self, calldata: &[u8], msg_sender: Address) -> PrecompileResult {
         if let Some(err) = charge_input_cost(&mut self.storage, calldata) {
@@ -19,7 +37,8 @@ impl Precompile for ValidatorConfigV2 {
 
         dispatch_call(
             calldata,
-            &[],
+            &[SelectorSchedule::new(TempoHardfork::T4).with_added(T4_ADDED)],
+            INTERFACE_IDS,
             IValidatorConfigV2Calls::abi_decode,
             |call| match call {
                 IValidatorConfigV2Calls::owner(call) => view(call, |_| self.owner()),
@@ -44,9 +63,18 @@ impl Precompile for ValidatorConfigV2 {
                 IValidatorConfigV2Calls::getNextNetworkIdentityRotationEpoch(call) => {
                     view(call, |_| self.get_next_network_identity_rotation_epoch())
                 }
+                IValidatorConfigV2Calls::getBlockGasLimit(call) => {
+                    view(call, |_| self.get_block_gas_limit())
+                }
                 IValidatorConfigV2Calls::isInitialized(call) => {
                     view(call, |_| self.is_initialized())
                 }
+                IValidatorConfigV2Calls::getVersion(call) => {
+                    view(call, |c| self.get_version(c.target))
+                }
+                IValidatorConfigV2Calls::lastActiveAt(call) => {
+                    view(call, |c| self.last_active_at(c.validator))
+                }
 
                 IValidatorConfigV2Calls::addValidator(call) => {
                     mutate(call, msg_sender, |s, c| self.add_validator(s, c))
@@ -76,6 +104,12 @@ impl Precompile for ValidatorConfigV2 {
                         self.set_network_identity_rotation_epoch(s, c)
                     })
                 }
+                IValidatorConfigV2Calls::setBlockGasLimit(call) => {
+                    mutate_void(call, msg_sender, |s, c| self.set_block_gas_limit(s, c))
+                }
+                IValidatorConfigV2Calls::heartbeat(call) => {
+                    mutate_void(call, msg_sender, |s, _| self.heartbeat(s))
+                }
                 IValidatorConfigV2Calls::migrateValidator(call) => {
                     mutate_void(call, msg_sender, |s, c| self.migrate_validator(s, c))
                 }
@@ -283,9 +317,94 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_get_version_dispatch() -> eyre::Result<()> {
+        let owner = Address::random();
+        let calldata = IValidatorConfigV2::getVersionCall {
+            target: tempo_contracts::precompiles::VALIDATOR_CONFIG_V2_ADDRESS,
+        }
+        .abi_encode();
+
+        // Pre-T4: the selector schedule rejects getVersion.
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T3);
+        StorageCtx::enter(&mut storage, || -> eyre::Result<()> {
+            let mut vc = ValidatorConfigV2::new();
+            vc.initialize(owner)?;
+
+            let result = vc.call(&calldata, owner)?;
+            assert!(result.is_revert());
+
+            Ok(())
+        })?;
+
+        // T4: returns the registry entry for the queried precompile.
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        StorageCtx::enter(&mut storage, || -> eyre::Result<()> {
+            let mut vc = ValidatorConfigV2::new();
+            vc.initialize(owner)?;
+
+            let result = vc.call(&calldata, owner)?;
+            assert!(!result.is_revert());
+            let version = IValidatorConfigV2::getVersionCall::abi_decode_returns(&result.bytes)?;
+            assert_eq!((version.major, version.minor, version.patch), (1, 1, 0));
+            assert_eq!(version.activationFork, "T4");
+
+            // Non-precompile addresses revert with UnknownPrecompile.
+            let calldata = IValidatorConfigV2::getVersionCall {
+                target: Address::random(),
+            }
+            .abi_encode();
+            let result = vc.call(&calldata, owner);
+            expect_precompile_revert(&result, ValidatorConfigV2Error::unknown_precompile());
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_heartbeat_dispatch_is_t4_gated() -> eyre::Result<()> {
+        let owner = Address::random();
+        let calldata = IValidatorConfigV2::heartbeatCall {}.abi_encode();
+
+        // Pre-T4: the selector schedule rejects heartbeat.
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T3);
+        StorageCtx::enter(&mut storage, || -> eyre::Result<()> {
+            let mut vc = ValidatorConfigV2::new();
+            vc.initialize(owner)?;
+
+            let result = vc.call(&calldata, owner)?;
+            assert!(result.is_revert());
+
+            Ok(())
+        })?;
+
+        // T4: dispatches through to the heartbeat logic, which rejects
+        // unregistered senders.
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
+        StorageCtx::enter(&mut storage, || -> eyre::Result<()> {
+            let mut vc = ValidatorConfigV2::new();
+            vc.initialize(owner)?;
+
+            let result = vc.call(&calldata, owner);
+            expect_precompile_revert(&result, ValidatorConfigV2Error::validator_not_found());
+
+            let result = vc.call(
+                &IValidatorConfigV2::lastActiveAtCall {
+                    validator: Address::random(),
+                }
+                .abi_encode(),
+                owner,
+            );
+            expect_precompile_revert(&result, ValidatorConfigV2Error::validator_not_found());
+
+            Ok(())
+        })
+    }
+
     #[test]
     fn test_selector_coverage() -> eyre::Result<()> {
-        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T2);
+        // Use T4 hardfork so the T4-gated getVersion selector is active.
+        let mut storage = HashMapStorageProvider::new_with_spec(1, TempoHardfork::T4);
         StorageCtx::enter(&mut storage, || {
             let mut vc = ValidatorConfigV2::new();
 
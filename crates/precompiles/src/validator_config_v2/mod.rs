@@ -28,6 +28,12 @@ pub const VALIDATOR_NS_ADD: &[u8] = b"TEMPO_VALIDATOR_CONFIG_V2_ADD_VALIDATOR";
 /// Signature namespace for `rotateValidator` operations.
 pub const VALIDATOR_NS_ROTATE: &[u8] = b"TEMPO_VALIDATOR_CONFIG_V2_ROTATE_VALIDATOR";
 
+/// Smallest block gas limit target governance may set. Guards against a fat-fingered
+/// value throttling the chain below what validator-critical transactions need.
+pub const MIN_BLOCK_GAS_LIMIT: u64 = 30_000_000;
+/// Largest block gas limit target governance may set.
+pub const MAX_BLOCK_GAS_LIMIT: u64 = 10_000_000_000;
+
 /// Distinguishes `addValidator` from `rotateValidator` signatures at the type level.
 enum SignatureKind {
     Add { fee_recipient: Address },
@@ -172,6 +178,13 @@ pub struct ValidatorConfigV2 {
     /// Compact list of 1-indexed global positions of currently active validators.
     /// Order is NOT stable (swap-and-pop on deactivation).
     active_indices: Vec<u64>,
+    /// Governance-set block gas limit target (0 = no override; blocks inherit the
+    /// parent's gas limit).
+    block_gas_limit: u64,
+    /// Unix timestamp (seconds) of each validator's most recent heartbeat
+    /// (0 = never). Keyed by address rather than stored on the record so
+    /// heartbeats survive rotations and ownership transfers (T4+).
+    last_active_at: Mapping<Address, u64>,
 }
 
 impl ValidatorConfigV2 {
@@ -221,6 +234,18 @@ impl ValidatorConfigV2 {
         Ok(self.validators.len()? as u64)
     }
 
+    /// Returns the implementation version of the precompile at `target` under the
+    /// currently active hardfork, from the static [version registry].
+    ///
+    /// Errors with `UnknownPrecompile` if `target` is not a precompile or is not
+    /// yet active.
+    ///
+    /// [version registry]: crate::version_registry
+    pub fn get_version(&self, target: Address) -> Result<IValidatorConfigV2::PrecompileVersion> {
+        crate::version_registry::version_of(target, self.storage.spec())
+            .ok_or_else(|| ValidatorConfigV2Error::unknown_precompile().into())
+    }
+
     /// Returns the validator at the given global index, or errors if the index
     /// is out of bounds or the validator has been deactivated.
     fn get_active_validator(&self, idx: u64) -> Result<ValidatorRecord> {
@@ -310,6 +335,27 @@ impl ValidatorConfigV2 {
         self.next_network_identity_rotation_epoch.read()
     }
 
+    /// Returns the governance-set block gas limit target (0 = no override).
+    ///
+    /// See [`set_block_gas_limit`](Self::set_block_gas_limit).
+    pub fn get_block_gas_limit(&self) -> Result<u64> {
+        self.block_gas_limit.read()
+    }
+
+    /// Returns the Unix timestamp (seconds) of `validator`'s most recent
+    /// heartbeat, or zero if it has never sent one.
+    ///
+    /// See [`heartbeat`](Self::heartbeat).
+    ///
+    /// # Errors
+    /// - `ValidatorNotFound` — the address has never been registered
+    pub fn last_active_at(&self, validator: Address) -> Result<u64> {
+        if self.address_to_index[validator].read()? == 0 {
+            Err(ValidatorConfigV2Error::validator_not_found())?
+        }
+        self.last_active_at[validator].read()
+    }
+
     fn validate_endpoints(ingress: &str, egress: &str) -> Result<()> {
         ensure_address_is_ip_port(ingress).map_err(|err| {
             TempoPrecompileError::from(ValidatorConfigV2Error::not_ip_port(
@@ -671,6 +717,86 @@ impl ValidatorConfigV2 {
         ))
     }
 
+    /// Sets the block gas limit target that proposers converge to (owner only).
+    ///
+    /// The target is read by the payload builder when preparing each block and approached
+    /// within the per-block gas limit delta consensus accepts, so a throughput change rolls
+    /// out over successive blocks without a coordinated binary release. A value of zero
+    /// clears the override and blocks keep inheriting the parent's gas limit.
+    ///
+    /// # Errors
+    /// - `Unauthorized` — `sender` is not the owner
+    /// - `NotInitialized` — the contract has not been initialized
+    /// - `BlockGasLimitOutOfBounds` — non-zero value outside
+    ///   [`MIN_BLOCK_GAS_LIMIT`]..=[`MAX_BLOCK_GAS_LIMIT`]
+    pub fn set_block_gas_limit(
+        &mut self,
+        sender: Address,
+        call: IValidatorConfigV2::setBlockGasLimitCall,
+    ) -> Result<()> {
+        self.config.read()?.require_init()?.require_owner(sender)?;
+        if call.gasLimit != 0
+            && !(MIN_BLOCK_GAS_LIMIT..=MAX_BLOCK_GAS_LIMIT).contains(&call.gasLimit)
+        {
+            Err(ValidatorConfigV2Error::block_gas_limit_out_of_bounds(
+                call.gasLimit,
+                MIN_BLOCK_GAS_LIMIT,
+                MAX_BLOCK_GAS_LIMIT,
+            ))?
+        }
+        let previous = self.block_gas_limit.read()?;
+        self.block_gas_limit.write(call.gasLimit)?;
+        self.emit_event(ValidatorConfigV2Event::BlockGasLimitUpdated(
+            IValidatorConfigV2::BlockGasLimitUpdated {
+                previousGasLimit: previous,
+                newGasLimit: call.gasLimit,
+            },
+        ))
+    }
+
+    // =========================================================================
+    // Validator-only functions
+    // =========================================================================
+
+    /// Records that the calling validator is alive, stamping the current block
+    /// timestamp into [`last_active_at`](Self::last_active_at).
+    ///
+    /// Deliberately cheap — one mapping lookup, one record read, one write —
+    /// so validators can call it every few minutes without meaningful cost.
+    /// Delegators and the bridge sidecar read the timestamps to avoid routing
+    /// work (e.g. designated unlock submissions) to validators that have gone
+    /// quiet.
+    ///
+    /// # Errors
+    /// - `NotInitialized` — the contract has not been initialized
+    /// - `ValidatorNotFound` — `sender` has never been registered
+    /// - `ValidatorAlreadyDeactivated` — `sender`'s entry is deactivated
+    pub fn heartbeat(&mut self, sender: Address) -> Result<()> {
+        self.config.read()?.require_init()?;
+
+        let idx1 = self.address_to_index[sender].read()?;
+        if idx1 == 0 {
+            Err(ValidatorConfigV2Error::validator_not_found())?
+        }
+        if self.validators[(idx1 - 1) as usize]
+            .deactivated_at_height
+            .read()?
+            != 0
+        {
+            Err(ValidatorConfigV2Error::validator_already_deactivated())?
+        }
+
+        let timestamp = self.storage.timestamp().saturating_to::<u64>();
+        self.last_active_at[sender].write(timestamp)?;
+
+        self.emit_event(ValidatorConfigV2Event::ValidatorHeartbeat(
+            IValidatorConfigV2::ValidatorHeartbeat {
+                validatorAddress: sender,
+                timestamp,
+            },
+        ))
+    }
+
     // =========================================================================
     // Dual-auth functions (owner or validator)
     // =========================================================================
@@ -1062,7 +1188,7 @@ fn v1() -> ValidatorConfig {
 mod tests {
     use super::*;
     use crate::storage::{StorageCtx, hashmap::HashMapStorageProvider};
-    use alloy::primitives::Address;
+    use alloy::primitives::{Address, U256};
     use alloy_primitives::FixedBytes;
     use commonware_codec::Encode;
     use commonware_cryptography::{Signer, ed25519::PrivateKey};
@@ -1829,6 +1955,63 @@ mod tests {
         })
     }
 
+    #[test]
+    fn test_set_block_gas_limit() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1);
+        let owner = Address::random();
+        StorageCtx::enter(&mut storage, || {
+            let mut vc = ValidatorConfigV2::new();
+            vc.initialize(owner)?;
+
+            assert_eq!(vc.get_block_gas_limit()?, 0);
+
+            vc.set_block_gas_limit(
+                owner,
+                IValidatorConfigV2::setBlockGasLimitCall {
+                    gasLimit: 100_000_000,
+                },
+            )?;
+            assert_eq!(vc.get_block_gas_limit()?, 100_000_000);
+
+            // Non-zero values outside the safety bounds are rejected.
+            for out_of_bounds in [MIN_BLOCK_GAS_LIMIT - 1, MAX_BLOCK_GAS_LIMIT + 1] {
+                let result = vc.set_block_gas_limit(
+                    owner,
+                    IValidatorConfigV2::setBlockGasLimitCall {
+                        gasLimit: out_of_bounds,
+                    },
+                );
+                assert_eq!(
+                    result,
+                    Err(ValidatorConfigV2Error::block_gas_limit_out_of_bounds(
+                        out_of_bounds,
+                        MIN_BLOCK_GAS_LIMIT,
+                        MAX_BLOCK_GAS_LIMIT,
+                    )
+                    .into())
+                );
+            }
+
+            let non_owner = Address::random();
+            let result = vc.set_block_gas_limit(
+                non_owner,
+                IValidatorConfigV2::setBlockGasLimitCall {
+                    gasLimit: 200_000_000,
+                },
+            );
+            assert_eq!(result, Err(ValidatorConfigV2Error::unauthorized().into()));
+
+            // Zero clears the override.
+            vc.set_block_gas_limit(
+                owner,
+                IValidatorConfigV2::setBlockGasLimitCall { gasLimit: 0 },
+            )?;
+            assert_eq!(vc.get_block_gas_limit()?, 0);
+
+            Ok(())
+        })
+    }
+
     #[test]
     fn test_not_initialized_errors() -> eyre::Result<()> {
         let mut storage = HashMapStorageProvider::new(1);
@@ -3616,4 +3799,133 @@ mod tests {
             Ok(())
         })
     }
+
+    #[test]
+    fn test_heartbeat_records_last_active_at() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1);
+        let owner = Address::random();
+        let validator = Address::random();
+        StorageCtx::enter(&mut storage, || {
+            let mut vc = ValidatorConfigV2::new();
+            vc.initialize(owner)?;
+
+            vc.storage.set_block_number(200);
+            vc.add_validator(
+                owner,
+                make_valid_add_call(validator, "192.168.1.1:8000", "192.168.1.1", validator),
+            )?;
+
+            // A registered validator that has never heartbeated reads as zero.
+            assert_eq!(vc.last_active_at(validator)?, 0);
+
+            vc.clear_emitted_events();
+            vc.storage.set_timestamp(U256::from(5_000u64));
+            vc.heartbeat(validator)?;
+            assert_eq!(vc.last_active_at(validator)?, 5_000);
+            vc.assert_emitted_events(vec![ValidatorConfigV2Event::ValidatorHeartbeat(
+                IValidatorConfigV2::ValidatorHeartbeat {
+                    validatorAddress: validator,
+                    timestamp: 5_000,
+                },
+            )]);
+
+            // A later heartbeat overwrites the previous timestamp.
+            vc.storage.set_timestamp(U256::from(5_600u64));
+            vc.heartbeat(validator)?;
+            assert_eq!(vc.last_active_at(validator)?, 5_600);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_heartbeat_rejects_unknown_and_deactivated() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1);
+        let owner = Address::random();
+        let validator = Address::random();
+        StorageCtx::enter(&mut storage, || {
+            let mut vc = ValidatorConfigV2::new();
+            vc.initialize(owner)?;
+
+            // Unregistered addresses can neither heartbeat nor be queried.
+            assert_eq!(
+                vc.heartbeat(validator),
+                Err(ValidatorConfigV2Error::validator_not_found().into())
+            );
+            assert_eq!(
+                vc.last_active_at(validator),
+                Err(ValidatorConfigV2Error::validator_not_found().into())
+            );
+
+            vc.storage.set_block_number(200);
+            vc.add_validator(
+                owner,
+                make_valid_add_call(validator, "192.168.1.1:8000", "192.168.1.1", validator),
+            )?;
+            vc.storage.set_timestamp(U256::from(5_000u64));
+            vc.heartbeat(validator)?;
+
+            // Deactivation blocks further heartbeats but keeps the last
+            // timestamp readable for downtime views.
+            vc.storage.set_block_number(300);
+            vc.deactivate_validator(
+                owner,
+                IValidatorConfigV2::deactivateValidatorCall { idx: 0 },
+            )?;
+            vc.storage.set_timestamp(U256::from(6_000u64));
+            assert_eq!(
+                vc.heartbeat(validator),
+                Err(ValidatorConfigV2Error::validator_already_deactivated().into())
+            );
+            assert_eq!(vc.last_active_at(validator)?, 5_000);
+
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn test_heartbeat_survives_rotation() -> eyre::Result<()> {
+        let mut storage = HashMapStorageProvider::new(1);
+        let owner = Address::random();
+        let validator = Address::random();
+        StorageCtx::enter(&mut storage, || {
+            let mut vc = ValidatorConfigV2::new();
+            vc.initialize(owner)?;
+
+            vc.storage.set_block_number(200);
+            vc.add_validator(
+                owner,
+                make_valid_add_call(validator, "192.168.1.1:8000", "192.168.1.1", validator),
+            )?;
+            vc.storage.set_timestamp(U256::from(5_000u64));
+            vc.heartbeat(validator)?;
+
+            // Rotating to a new identity keeps the address's heartbeat
+            // history, since the mapping is keyed by address.
+            let (new_pubkey, new_sig) = make_test_keypair_and_signature(
+                validator,
+                "10.0.0.1:8000",
+                "10.0.0.1",
+                SignatureKind::Rotate,
+            );
+            vc.storage.set_block_number(300);
+            vc.rotate_validator(
+                owner,
+                IValidatorConfigV2::rotateValidatorCall {
+                    idx: 0,
+                    publicKey: new_pubkey,
+                    ingress: "10.0.0.1:8000".to_string(),
+                    egress: "10.0.0.1".to_string(),
+                    signature: new_sig.into(),
+                },
+            )?;
+            assert_eq!(vc.last_active_at(validator)?, 5_000);
+
+            vc.storage.set_timestamp(U256::from(7_000u64));
+            vc.heartbeat(validator)?;
+            assert_eq!(vc.last_active_at(validator)?, 7_000);
+
+            Ok(())
+        })
+    }
 }
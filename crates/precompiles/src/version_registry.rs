@@ -0,0 +1,210 @@
+//! Implementation version registry for Tempo precompiles.
+//!
+//! Every behavior-changing hardfork bumps the semantic version of the precompiles it
+//! touches. The history lives in a static table here — versions are a pure function of
+//! `(address, active hardfork)`, so there is nothing to write to state at activation and
+//! off-chain tooling can reconstruct the full version timeline deterministically from the
+//! chain spec alone. The table is exposed on-chain through
+//! `IValidatorConfigV2::getVersion` (T4+); see
+//! [`version_of`] for the lookup semantics and [`changed_in`] for enumerating the bumps a
+//! given fork ships.
+//!
+//! ## Maintaining the table
+//!
+//! When a hardfork changes a precompile's observable behavior (new selectors, changed
+//! semantics, gas changes), append an entry to that precompile's history with the
+//! activating fork and the bumped version. Histories must stay in ascending activation
+//! order — the tests enforce this.
+
+use alloy::primitives::Address;
+use tempo_chainspec::hardfork::TempoHardfork;
+use tempo_contracts::precompiles::{
+    ACCOUNT_KEYCHAIN_ADDRESS, ADDRESS_REGISTRY_ADDRESS, IValidatorConfigV2::PrecompileVersion,
+    NONCE_PRECOMPILE_ADDRESS, P256_VERIFY_ADDRESS, SIGNATURE_VERIFIER_ADDRESS,
+    STABLECOIN_DEX_ADDRESS, TIP_FEE_MANAGER_ADDRESS, TIP20_FACTORY_ADDRESS,
+    TIP403_REGISTRY_ADDRESS, VALIDATOR_CONFIG_ADDRESS, VALIDATOR_CONFIG_V2_ADDRESS,
+};
+use tempo_primitives::TempoAddressExt;
+
+/// One version bump: the hardfork that activated it and the resulting semver.
+type VersionEntry = (TempoHardfork, (u8, u8, u8));
+
+/// Version history of a single precompile, in ascending activation order.
+type VersionHistory = &'static [VersionEntry];
+
+/// TIP-20 tokens: ERC-20 surface at genesis, T2 added the selector schedule gating.
+const TIP20_HISTORY: VersionHistory = &[
+    (TempoHardfork::T0, (1, 0, 0)),
+    (TempoHardfork::T2, (1, 1, 0)),
+];
+
+/// Factory: T4 added `tokenURI`/`setTokenURI`/`tokenCount`/`allTokens`.
+const TIP20_FACTORY_HISTORY: VersionHistory = &[
+    (TempoHardfork::T0, (1, 0, 0)),
+    (TempoHardfork::T4, (1, 1, 0)),
+];
+
+const ADDRESS_REGISTRY_HISTORY: VersionHistory = &[(TempoHardfork::T3, (1, 0, 0))];
+
+const TIP403_REGISTRY_HISTORY: VersionHistory = &[
+    (TempoHardfork::T0, (1, 0, 0)),
+    (TempoHardfork::T2, (1, 1, 0)),
+];
+
+/// Fee manager: T4 added the cross-precompile reentrancy guard.
+const TIP_FEE_MANAGER_HISTORY: VersionHistory = &[
+    (TempoHardfork::T0, (1, 0, 0)),
+    (TempoHardfork::T4, (1, 1, 0)),
+];
+
+/// DEX: T4 added the cross-precompile reentrancy guard.
+const STABLECOIN_DEX_HISTORY: VersionHistory = &[
+    (TempoHardfork::T0, (1, 0, 0)),
+    (TempoHardfork::T4, (1, 1, 0)),
+];
+
+const NONCE_MANAGER_HISTORY: VersionHistory = &[(TempoHardfork::T0, (1, 0, 0))];
+
+/// V1 config: T1 switched consensus reads to height-based activation semantics.
+const VALIDATOR_CONFIG_HISTORY: VersionHistory = &[
+    (TempoHardfork::T0, (1, 0, 0)),
+    (TempoHardfork::T1, (1, 1, 0)),
+];
+
+const ACCOUNT_KEYCHAIN_HISTORY: VersionHistory = &[
+    (TempoHardfork::T0, (1, 0, 0)),
+    (TempoHardfork::T3, (1, 1, 0)),
+];
+
+/// V2 config: dispatch unlocked at T2, `getVersion` itself added at T4.
+const VALIDATOR_CONFIG_V2_HISTORY: VersionHistory = &[
+    (TempoHardfork::T2, (1, 0, 0)),
+    (TempoHardfork::T4, (1, 1, 0)),
+];
+
+const SIGNATURE_VERIFIER_HISTORY: VersionHistory = &[(TempoHardfork::T3, (1, 0, 0))];
+
+const P256_VERIFY_HISTORY: VersionHistory = &[(TempoHardfork::T3, (1, 0, 0))];
+
+/// All tracked `(address, history)` pairs. TIP-20 tokens are matched by address
+/// prefix in [`history_of`] instead, since they are not at a fixed address.
+const REGISTRY: &[(Address, VersionHistory)] = &[
+    (TIP20_FACTORY_ADDRESS, TIP20_FACTORY_HISTORY),
+    (ADDRESS_REGISTRY_ADDRESS, ADDRESS_REGISTRY_HISTORY),
+    (TIP403_REGISTRY_ADDRESS, TIP403_REGISTRY_HISTORY),
+    (TIP_FEE_MANAGER_ADDRESS, TIP_FEE_MANAGER_HISTORY),
+    (STABLECOIN_DEX_ADDRESS, STABLECOIN_DEX_HISTORY),
+    (NONCE_PRECOMPILE_ADDRESS, NONCE_MANAGER_HISTORY),
+    (VALIDATOR_CONFIG_ADDRESS, VALIDATOR_CONFIG_HISTORY),
+    (ACCOUNT_KEYCHAIN_ADDRESS, ACCOUNT_KEYCHAIN_HISTORY),
+    (VALIDATOR_CONFIG_V2_ADDRESS, VALIDATOR_CONFIG_V2_HISTORY),
+    (SIGNATURE_VERIFIER_ADDRESS, SIGNATURE_VERIFIER_HISTORY),
+    (P256_VERIFY_ADDRESS, P256_VERIFY_HISTORY),
+];
+
+/// Returns the version history of `address`, or `None` if it is not a precompile.
+fn history_of(address: Address) -> Option<VersionHistory> {
+    if address.is_tip20() {
+        return Some(TIP20_HISTORY);
+    }
+    REGISTRY
+        .iter()
+        .find(|(addr, _)| *addr == address)
+        .map(|(_, history)| *history)
+}
+
+/// Returns the implementation version of the precompile at `address` under `spec`:
+/// the latest history entry whose activation fork is at or before `spec`.
+///
+/// Returns `None` if `address` is not a precompile, or if the precompile only
+/// activates at a later hardfork than `spec`.
+pub fn version_of(address: Address, spec: TempoHardfork) -> Option<PrecompileVersion> {
+    let (activation, (major, minor, patch)) = history_of(address)?
+        .iter()
+        .rev()
+        .find(|(activation, _)| spec as u64 >= *activation as u64)?;
+    Some(PrecompileVersion {
+        major: *major,
+        minor: *minor,
+        patch: *patch,
+        activationFork: activation.to_string(),
+    })
+}
+
+/// Returns the fixed-address precompiles whose version changes when `fork` activates,
+/// with the version they change to. TIP-20 tokens are not enumerated (their history is
+/// shared; query any token address via [`version_of`]).
+///
+/// Off-chain tooling uses this to synthesize per-fork "version changed" notifications,
+/// since activation does not write anything to state.
+pub fn changed_in(fork: TempoHardfork) -> Vec<(Address, PrecompileVersion)> {
+    REGISTRY
+        .iter()
+        .filter(|(_, history)| history.iter().any(|(activation, _)| *activation == fork))
+        .filter_map(|(addr, _)| Some((*addr, version_of(*addr, fork)?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempo_contracts::precompiles::PATH_USD_ADDRESS;
+
+    #[test]
+    fn histories_are_in_ascending_activation_order() {
+        let all = REGISTRY
+            .iter()
+            .map(|(_, history)| *history)
+            .chain([TIP20_HISTORY]);
+        for history in all {
+            assert!(!history.is_empty());
+            for pair in history.windows(2) {
+                assert!(
+                    (pair[0].0 as u64) < (pair[1].0 as u64),
+                    "history entries out of order: {pair:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn version_tracks_the_active_spec() {
+        // Factory is 1.0.0 until T4 bumps it.
+        let v = version_of(TIP20_FACTORY_ADDRESS, TempoHardfork::T3).unwrap();
+        assert_eq!((v.major, v.minor, v.patch), (1, 0, 0));
+        assert_eq!(v.activationFork, "T0");
+
+        let v = version_of(TIP20_FACTORY_ADDRESS, TempoHardfork::T4).unwrap();
+        assert_eq!((v.major, v.minor, v.patch), (1, 1, 0));
+        assert_eq!(v.activationFork, "T4");
+    }
+
+    #[test]
+    fn inactive_precompile_has_no_version() {
+        // AddressRegistry only activates at T3.
+        assert_eq!(
+            version_of(ADDRESS_REGISTRY_ADDRESS, TempoHardfork::T2),
+            None
+        );
+        assert!(version_of(ADDRESS_REGISTRY_ADDRESS, TempoHardfork::T3).is_some());
+    }
+
+    #[test]
+    fn tip20_tokens_match_by_prefix() {
+        let v = version_of(PATH_USD_ADDRESS, TempoHardfork::T2).unwrap();
+        assert_eq!((v.major, v.minor, v.patch), (1, 1, 0));
+        assert_eq!(version_of(Address::random(), TempoHardfork::T4), None);
+    }
+
+    #[test]
+    fn changed_in_lists_the_fork_bumps() {
+        let changed = changed_in(TempoHardfork::T4);
+        let addrs: Vec<Address> = changed.iter().map(|(addr, _)| *addr).collect();
+        assert!(addrs.contains(&TIP20_FACTORY_ADDRESS));
+        assert!(addrs.contains(&VALIDATOR_CONFIG_V2_ADDRESS));
+        assert!(!addrs.contains(&NONCE_PRECOMPILE_ADDRESS));
+        for (_, v) in &changed {
+            assert_eq!(v.activationFork, "T4");
+        }
+    }
+}
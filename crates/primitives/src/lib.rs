@@ -20,11 +20,17 @@ pub use transaction::{
 mod header;
 pub use header::{TempoConsensusContext, TempoHeader};
 
+mod receipt;
+pub use receipt::{KeyAuthorizationResult, TempoReceiptFields};
+
 pub mod subblock;
 pub use subblock::{
     RecoveredSubBlock, SignedSubBlock, SubBlock, SubBlockMetadata, SubBlockVersion,
 };
 
+#[cfg(feature = "std")]
+pub mod tx_trace;
+
 extern crate alloc;
 
 use once_cell as _;
@@ -0,0 +1,60 @@
+//! Tempo-specific receipt fields exposed over JSON-RPC.
+
+use crate::SignatureType;
+use alloy_primitives::{Address, U256};
+
+/// Tempo-specific fields attached to transaction receipt RPC responses.
+///
+/// These are flattened into the standard Ethereum receipt so that explorers
+/// can display stablecoin-denominated fees and key provisioning outcomes
+/// without decoding logs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct TempoReceiptFields {
+    /// Token that was used to pay fees for the transaction.
+    ///
+    /// None if the transaction was free.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub fee_token: Option<Address>,
+
+    /// Address that paid the fees for the transaction.
+    pub fee_payer: Address,
+
+    /// Fee charged for the transaction, denominated in the fee token's base
+    /// units (microdollars for 6-decimal TIP-20 tokens).
+    ///
+    /// None if the transaction was free.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub fee_amount_in_token: Option<U256>,
+
+    /// Outcome of the key authorization carried by this transaction, if any.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub key_authorization: Option<KeyAuthorizationResult>,
+}
+
+/// Result of processing a [`KeyAuthorization`](crate::transaction::KeyAuthorization)
+/// included in a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct KeyAuthorizationResult {
+    /// Identifier of the authorized access key.
+    pub key_id: Address,
+
+    /// Type of the authorized key.
+    pub key_type: SignatureType,
+
+    /// Whether the key was provisioned. Authorizations only take effect when
+    /// the carrying transaction succeeds.
+    pub authorized: bool,
+}
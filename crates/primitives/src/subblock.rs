@@ -254,6 +254,78 @@ impl RecoveredSubBlock {
     }
 }
 
+/// Magic byte for the pre-confirmation signature hash.
+const PRECONFIRMATION_SIGNATURE_HASH_MAGIC_BYTE: u8 = 0x79;
+
+/// A proposer's soft acknowledgement that a transaction is included in its
+/// pending subblock.
+///
+/// Pre-confirmations are not a consensus artifact: they promise only that the
+/// acknowledging validator has placed the transaction in the subblock it is
+/// building on top of `parent_hash`. Payment apps can display them before full
+/// finalization, but they carry no inclusion guarantee beyond the validator's
+/// accountability for its signature.
+#[derive(Debug, Clone, PartialEq, Eq, RlpEncodable, RlpDecodable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct PreConfirmation {
+    /// Hash of the acknowledged transaction.
+    pub tx_hash: B256,
+    /// Parent block the acknowledging subblock is being built on.
+    pub parent_hash: B256,
+    /// Ed25519 public key of the acknowledging validator.
+    pub validator: B256,
+    /// Unix timestamp (seconds) at which the acknowledgement was produced.
+    pub timestamp: u64,
+}
+
+impl PreConfirmation {
+    /// Returns the hash for the signature, domain-separated from subblock
+    /// signatures by its own magic byte.
+    pub fn signature_hash(&self) -> B256 {
+        let mut buf = Vec::with_capacity(self.length() + 1);
+        buf.put_u8(PRECONFIRMATION_SIGNATURE_HASH_MAGIC_BYTE);
+        self.encode(&mut buf);
+        keccak256(&buf)
+    }
+}
+
+/// A [`PreConfirmation`] together with the validator's ed25519 signature.
+#[derive(Debug, Clone, PartialEq, Eq, RlpEncodable, RlpDecodable, derive_more::Deref)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct SignedPreConfirmation {
+    /// The acknowledged pre-confirmation.
+    #[deref]
+    pub preconfirmation: PreConfirmation,
+    /// Ed25519 signature over [`PreConfirmation::signature_hash`].
+    pub signature: Bytes,
+}
+
+impl SignedPreConfirmation {
+    /// Verifies the signature against the embedded validator public key.
+    ///
+    /// Returns `false` for malformed keys or signatures as well as for
+    /// signatures that do not verify.
+    pub fn verify(&self) -> bool {
+        let Ok(validator) = crate::ed25519::PublicKey::try_from(self.preconfirmation.validator)
+        else {
+            return false;
+        };
+        let Ok(signature) = <[u8; 64]>::try_from(self.signature.as_ref()) else {
+            return false;
+        };
+
+        validator
+            .get()
+            .verify(
+                &ed25519_consensus::Signature::from(signature),
+                self.preconfirmation.signature_hash().as_slice(),
+            )
+            .is_ok()
+    }
+}
+
 /// Metadata for an included subblock.
 #[derive(Debug, Clone, RlpEncodable, RlpDecodable)]
 pub struct SubBlockMetadata {
@@ -409,6 +481,48 @@ mod tests {
         assert_eq!(meta.signature, Bytes::from(vec![1, 2, 3, 4]));
     }
 
+    #[test]
+    fn test_preconfirmation_sign_and_verify() {
+        let signing_key = ed25519_consensus::SigningKey::from([7u8; 32]);
+        let preconfirmation = PreConfirmation {
+            tx_hash: B256::random(),
+            parent_hash: B256::random(),
+            validator: crate::ed25519::PublicKey::from(signing_key.verification_key()).into(),
+            timestamp: 1_700_000_000,
+        };
+
+        let signature = signing_key.sign(preconfirmation.signature_hash().as_slice());
+        let signed = SignedPreConfirmation {
+            preconfirmation: preconfirmation.clone(),
+            signature: Bytes::from(signature.to_bytes().to_vec()),
+        };
+        assert!(signed.verify());
+
+        // RLP roundtrip
+        let mut buf = Vec::new();
+        signed.encode(&mut buf);
+        let decoded = SignedPreConfirmation::decode(&mut buf.as_slice()).unwrap();
+        assert_eq!(decoded, signed);
+
+        // Tampering with any acknowledged field invalidates the signature.
+        let mut tampered = signed.clone();
+        tampered.preconfirmation.tx_hash = B256::random();
+        assert!(!tampered.verify());
+
+        // A signature from a different key does not verify.
+        let other_key = ed25519_consensus::SigningKey::from([8u8; 32]);
+        let forged = SignedPreConfirmation {
+            preconfirmation,
+            signature: Bytes::from(
+                other_key
+                    .sign(signed.preconfirmation.signature_hash().as_slice())
+                    .to_bytes()
+                    .to_vec(),
+            ),
+        };
+        assert!(!forged.verify());
+    }
+
     #[test]
     fn test_subblock_version_conversion() {
         // Valid V1
@@ -0,0 +1,380 @@
+//! Compressed transport envelope for signed Tempo transactions.
+//!
+//! High-volume stablecoin payment batches carry highly repetitive calldata
+//! (the same selectors, token addresses, and amount layouts over and over), so
+//! the wire representation can opt into carrying the whole signed payload
+//! zstd-compressed. The envelope is a transport encoding only and is never
+//! part of the canonical EIP-2718 encoding: consensus, hashing, gas
+//! accounting, and the transactions root always run over the canonical bytes,
+//! and the canonical decoder ([`AASigned::rlp_decode`](super::AASigned))
+//! rejects the envelope outright (it is an RLP string where the canonical
+//! payload is always an RLP list).
+//!
+//! Producers build the envelope with
+//! [`AASigned::eip2718_encode_compressed`](super::AASigned::eip2718_encode_compressed)
+//! (which only emits it when it actually shrinks the payload) and submit it
+//! through the explicit `tempo_sendRawTransactionCompressed` RPC, active at
+//! T4. The node restores the canonical bytes with
+//! [`AASigned::eip2718_decode_compressed`](super::AASigned::eip2718_decode_compressed)
+//! before the transaction enters the ordinary raw-transaction path; nothing
+//! downstream ever sees the envelope.
+//!
+//! Frame layout (following the EIP-2718 type byte):
+//!
+//! ```text
+//! magic "TCZ" (3 bytes) || dictionary id (1 byte) ||
+//! decompressed length (4 bytes, big-endian) || zstd frame
+//! ```
+//!
+//! Dictionary id `0` means no dictionary. Non-zero ids are reserved for
+//! dictionaries trained on mainnet payment traffic and pinned by the hardfork
+//! that introduces them; decoding rejects ids this node does not know, so a
+//! dictionary can only be used once every node ships it.
+
+#[cfg(feature = "std")]
+use alloc::{vec, vec::Vec};
+
+/// Magic prefix identifying a compressed transport envelope.
+pub const COMPRESSED_ENVELOPE_MAGIC: [u8; 3] = *b"TCZ";
+
+/// Dictionary id meaning "no dictionary" (a plain zstd frame).
+pub const DICTIONARY_ID_NONE: u8 = 0;
+
+/// Envelope header length: magic, dictionary id, decompressed length.
+pub const COMPRESSED_ENVELOPE_HEADER_LEN: usize = 8;
+
+/// Upper bound on the decompressed size accepted from an envelope (8 MiB).
+///
+/// Guards against decompression bombs: the bound is checked against the
+/// header's declared length before any decompression work happens, and the
+/// frame must then decompress to exactly the declared length.
+pub const MAX_DECOMPRESSED_PAYLOAD: usize = 8 * 1024 * 1024;
+
+/// zstd compression level used by producers. Level 3 is the zstd default and
+/// keeps compression cheap enough for the hot path.
+#[cfg(feature = "std")]
+const COMPRESSION_LEVEL: i32 = 3;
+
+/// Shared dictionaries known to this node, keyed by dictionary id.
+///
+/// Empty until a hardfork pins a dictionary trained on real payment traffic;
+/// entries are append-only and never removed, since envelopes referencing an
+/// old dictionary must stay decodable.
+#[cfg(feature = "std")]
+const DICTIONARIES: &[(u8, &[u8])] = &[];
+
+/// Errors decoding or producing a compressed transport envelope.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressedEnvelopeError {
+    /// The bytes are not a compressed transport envelope (bad magic or too
+    /// short to carry the header).
+    NotAnEnvelope,
+    /// The envelope references a dictionary id this node does not know.
+    UnknownDictionary(u8),
+    /// The header declares a decompressed length above
+    /// [`MAX_DECOMPRESSED_PAYLOAD`].
+    TooLarge(u64),
+    /// The zstd frame is malformed or does not decompress to the declared
+    /// length.
+    InvalidFrame,
+}
+
+/// Whether `bytes` carry the compressed transport envelope magic.
+pub fn is_compressed_envelope(bytes: &[u8]) -> bool {
+    bytes.len() >= COMPRESSED_ENVELOPE_HEADER_LEN
+        && bytes[..COMPRESSED_ENVELOPE_MAGIC.len()] == COMPRESSED_ENVELOPE_MAGIC
+}
+
+/// Returns the dictionary for `id`, or `None` for [`DICTIONARY_ID_NONE`].
+#[cfg(feature = "std")]
+fn dictionary(id: u8) -> Result<Option<&'static [u8]>, CompressedEnvelopeError> {
+    if id == DICTIONARY_ID_NONE {
+        return Ok(None);
+    }
+    DICTIONARIES
+        .iter()
+        .find(|(known, _)| *known == id)
+        .map(|(_, dict)| Some(*dict))
+        .ok_or(CompressedEnvelopeError::UnknownDictionary(id))
+}
+
+/// Reads the declared decompressed length from an envelope header without
+/// doing any decompression work.
+pub fn decompressed_payload_len(bytes: &[u8]) -> Result<usize, CompressedEnvelopeError> {
+    if !is_compressed_envelope(bytes) {
+        return Err(CompressedEnvelopeError::NotAnEnvelope);
+    }
+    let declared = u32::from_be_bytes(bytes[4..8].try_into().expect("header length checked"));
+    if declared as usize > MAX_DECOMPRESSED_PAYLOAD {
+        return Err(CompressedEnvelopeError::TooLarge(u64::from(declared)));
+    }
+    Ok(declared as usize)
+}
+
+/// Wraps `payload` in a compressed envelope using `dictionary_id`.
+///
+/// The envelope may be larger than the input for small or incompressible
+/// payloads; use [`maybe_compress_payload`] when the envelope should only be
+/// emitted if it wins.
+#[cfg(feature = "std")]
+pub fn compress_payload(
+    payload: &[u8],
+    dictionary_id: u8,
+) -> Result<Vec<u8>, CompressedEnvelopeError> {
+    if payload.len() > MAX_DECOMPRESSED_PAYLOAD {
+        return Err(CompressedEnvelopeError::TooLarge(payload.len() as u64));
+    }
+
+    let frame = match dictionary(dictionary_id)? {
+        None => zstd::bulk::compress(payload, COMPRESSION_LEVEL)
+            .map_err(|_| CompressedEnvelopeError::InvalidFrame)?,
+        Some(dict) => {
+            let mut compressor = zstd::bulk::Compressor::with_dictionary(COMPRESSION_LEVEL, dict)
+                .map_err(|_| CompressedEnvelopeError::InvalidFrame)?;
+            compressor
+                .compress(payload)
+                .map_err(|_| CompressedEnvelopeError::InvalidFrame)?
+        }
+    };
+
+    let mut out = Vec::with_capacity(COMPRESSED_ENVELOPE_HEADER_LEN + frame.len());
+    out.extend_from_slice(&COMPRESSED_ENVELOPE_MAGIC);
+    out.push(dictionary_id);
+    out.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    out.extend_from_slice(&frame);
+    Ok(out)
+}
+
+/// [`compress_payload`], returning `None` unless the envelope is strictly
+/// smaller than the raw payload.
+#[cfg(feature = "std")]
+pub fn maybe_compress_payload(payload: &[u8], dictionary_id: u8) -> Option<Vec<u8>> {
+    let envelope = compress_payload(payload, dictionary_id).ok()?;
+    (envelope.len() < payload.len()).then_some(envelope)
+}
+
+/// Restores the canonical payload from a compressed envelope.
+///
+/// Rejects unknown dictionaries, declared lengths above
+/// [`MAX_DECOMPRESSED_PAYLOAD`], and frames that do not decompress to exactly
+/// the declared length.
+#[cfg(feature = "std")]
+pub fn decompress_payload(bytes: &[u8]) -> Result<Vec<u8>, CompressedEnvelopeError> {
+    let declared = decompressed_payload_len(bytes)?;
+    let dict = dictionary(bytes[3])?;
+    let frame = &bytes[COMPRESSED_ENVELOPE_HEADER_LEN..];
+
+    let decompressed = match dict {
+        None => zstd::bulk::decompress(frame, declared)
+            .map_err(|_| CompressedEnvelopeError::InvalidFrame)?,
+        Some(dict) => {
+            let mut decompressor = zstd::bulk::Decompressor::with_dictionary(dict)
+                .map_err(|_| CompressedEnvelopeError::InvalidFrame)?;
+            let mut out = vec![0u8; declared];
+            let written = decompressor
+                .decompress_to_buffer(frame, &mut out)
+                .map_err(|_| CompressedEnvelopeError::InvalidFrame)?;
+            out.truncate(written);
+            out
+        }
+    };
+
+    if decompressed.len() != declared {
+        return Err(CompressedEnvelopeError::InvalidFrame);
+    }
+    Ok(decompressed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::{
+        AASigned, Call, PrimitiveSignature, TempoSignature, TempoTransaction,
+    };
+    use alloy_primitives::{Address, Signature, TxKind, U256};
+
+    /// Repetitive payment-batch-shaped calldata that compresses well.
+    fn batch_calldata() -> Vec<u8> {
+        let mut data = Vec::new();
+        for i in 0u32..200 {
+            data.extend_from_slice(&[0xa9, 0x05, 0x9c, 0xbb]); // transfer selector
+            data.extend_from_slice(&[0x11; 32]); // recipient word
+            data.extend_from_slice(&i.to_be_bytes());
+            data.extend_from_slice(&[0x00; 28]); // amount word
+        }
+        data
+    }
+
+    fn signed_with_input(input: Vec<u8>) -> AASigned {
+        let tx = TempoTransaction {
+            chain_id: 1,
+            gas_limit: 1_000_000,
+            calls: vec![Call {
+                to: TxKind::Call(Address::repeat_byte(0x42)),
+                value: U256::ZERO,
+                input: input.into(),
+            }],
+            ..Default::default()
+        };
+        AASigned::new_unhashed(
+            tx,
+            TempoSignature::Primitive(PrimitiveSignature::Secp256k1(Signature::test_signature())),
+        )
+    }
+
+    #[test]
+    fn roundtrips_and_shrinks_batch_payload() {
+        let payload = batch_calldata();
+        let envelope = maybe_compress_payload(&payload, DICTIONARY_ID_NONE)
+            .expect("repetitive batch must compress");
+        assert!(is_compressed_envelope(&envelope));
+        assert!(envelope.len() < payload.len());
+
+        assert_eq!(decompressed_payload_len(&envelope).unwrap(), payload.len());
+        assert_eq!(decompress_payload(&envelope).unwrap(), payload);
+    }
+
+    #[test]
+    fn incompressible_payload_is_left_raw() {
+        // Pseudo-random bytes: the envelope cannot win, so none is emitted.
+        let payload: Vec<u8> = (0u32..512)
+            .map(|i| (i.wrapping_mul(2654435761) >> 24) as u8)
+            .collect();
+        assert!(maybe_compress_payload(&payload, DICTIONARY_ID_NONE).is_none());
+    }
+
+    #[test]
+    fn rejects_unknown_dictionary_and_bad_magic() {
+        let payload = batch_calldata();
+        let mut envelope = compress_payload(&payload, DICTIONARY_ID_NONE).unwrap();
+
+        envelope[3] = 42;
+        assert_eq!(
+            decompress_payload(&envelope),
+            Err(CompressedEnvelopeError::UnknownDictionary(42))
+        );
+
+        envelope[0] = b'X';
+        assert_eq!(
+            decompress_payload(&envelope),
+            Err(CompressedEnvelopeError::NotAnEnvelope)
+        );
+        assert!(!is_compressed_envelope(&envelope));
+    }
+
+    #[test]
+    fn signed_envelope_roundtrips_through_compressed_encoding() {
+        let signed = signed_with_input(batch_calldata());
+
+        let compressed = signed
+            .eip2718_encode_compressed(DICTIONARY_ID_NONE)
+            .expect("batch calldata must compress");
+        let mut canonical = Vec::new();
+        signed.eip2718_encode(&mut canonical);
+        assert!(compressed.len() < canonical.len());
+
+        // Explicit decoding restores the canonical transaction: same calldata,
+        // hashes, and calldata gas as the uncompressed encoding.
+        let decoded =
+            AASigned::eip2718_decode_compressed(&compressed).expect("decode compressed envelope");
+        assert_eq!(decoded.tx(), signed.tx());
+        assert_eq!(decoded.hash(), signed.hash());
+        assert_eq!(decoded.signature_hash(), signed.signature_hash());
+        assert_eq!(
+            decoded.tx().estimate_resources().calldata_gas,
+            signed.tx().estimate_resources().calldata_gas,
+        );
+    }
+
+    #[test]
+    fn canonical_decoder_rejects_the_envelope() {
+        let signed = signed_with_input(batch_calldata());
+        let compressed = signed
+            .eip2718_encode_compressed(DICTIONARY_ID_NONE)
+            .expect("batch calldata must compress");
+
+        // The canonical payload is always an RLP list; the envelope is not,
+        // so it can never slip through the consensus decode path.
+        assert!(AASigned::rlp_decode(&mut &compressed[1..]).is_err());
+    }
+
+    #[test]
+    fn calldata_with_envelope_magic_is_plain_calldata() {
+        // Calldata that happens to start with the envelope magic is never
+        // reinterpreted: the canonical encoding round-trips it byte-exactly.
+        let mut input = COMPRESSED_ENVELOPE_MAGIC.to_vec();
+        input.extend_from_slice(&[0x00, 0xde, 0xad, 0xbe, 0xef, 0x01, 0x02]);
+        let signed = signed_with_input(input.clone());
+
+        let mut canonical = Vec::new();
+        signed.eip2718_encode(&mut canonical);
+        let decoded = AASigned::rlp_decode(&mut &canonical[1..]).expect("canonical decode");
+        assert_eq!(decoded.tx().calls[0].input.as_ref(), input.as_slice());
+        assert_eq!(decoded.hash(), signed.hash());
+
+        let mut reencoded = Vec::new();
+        decoded.eip2718_encode(&mut reencoded);
+        assert_eq!(reencoded, canonical);
+    }
+
+    #[test]
+    fn incompressible_transaction_has_no_compressed_encoding() {
+        let signed = signed_with_input(b"hi".to_vec());
+        assert!(
+            signed
+                .eip2718_encode_compressed(DICTIONARY_ID_NONE)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn corrupt_envelope_is_rejected() {
+        let signed = signed_with_input(batch_calldata());
+        let mut compressed = signed
+            .eip2718_encode_compressed(DICTIONARY_ID_NONE)
+            .expect("batch calldata must compress");
+
+        // Corrupt the first zstd frame byte; the explicit decoder must surface
+        // the error rather than hand anything to the transaction decoder.
+        compressed[1 + COMPRESSED_ENVELOPE_HEADER_LEN] ^= 0xff;
+        assert!(AASigned::eip2718_decode_compressed(&compressed).is_err());
+    }
+
+    #[test]
+    fn declared_length_is_enforced() {
+        let payload = batch_calldata();
+        let mut envelope = compress_payload(&payload, DICTIONARY_ID_NONE).unwrap();
+
+        // A lying header must not survive: oversize is rejected before any
+        // decompression, and a wrong (but in-bounds) length fails the frame.
+        envelope[4..8].copy_from_slice(&(MAX_DECOMPRESSED_PAYLOAD as u32 + 1).to_be_bytes());
+        assert!(matches!(
+            decompress_payload(&envelope),
+            Err(CompressedEnvelopeError::TooLarge(_))
+        ));
+
+        envelope[4..8].copy_from_slice(&(payload.len() as u32 - 1).to_be_bytes());
+        assert_eq!(
+            decompress_payload(&envelope),
+            Err(CompressedEnvelopeError::InvalidFrame)
+        );
+    }
+
+    #[test]
+    fn compressed_encoding_wraps_the_whole_payload() {
+        let signed = signed_with_input(batch_calldata());
+        let compressed = signed
+            .eip2718_encode_compressed(DICTIONARY_ID_NONE)
+            .expect("batch calldata must compress");
+
+        assert_eq!(compressed[0], crate::transaction::TEMPO_TX_TYPE_ID);
+        assert!(is_compressed_envelope(&compressed[1..]));
+
+        let mut canonical_payload = Vec::new();
+        signed.rlp_encode(&mut canonical_payload);
+        assert_eq!(
+            decompress_payload(&compressed[1..]).unwrap(),
+            canonical_payload
+        );
+    }
+}
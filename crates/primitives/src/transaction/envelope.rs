@@ -105,6 +105,14 @@ impl TempoTxEnvelope {
         }
     }
 
+    /// Returns the ordered fee token preference list (empty if unset).
+    pub fn fee_token_preferences(&self) -> &[Address] {
+        match self {
+            Self::AA(tx) => &tx.tx().fee_token_preferences,
+            _ => &[],
+        }
+    }
+
     /// Resolves fee payer for the transaction.
     pub fn fee_payer(&self, sender: Address) -> Result<Address, RecoveryError> {
         match self {
@@ -872,7 +880,9 @@ mod tests {
                     expiry: None,
                     limits: None,
                     allowed_calls: None,
-                },
+                    max_uses: None,
+                }
+                .into(),
                 signature: PrimitiveSignature::Secp256k1(Signature::test_signature()),
             }),
             ..Default::default()
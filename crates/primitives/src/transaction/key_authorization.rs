@@ -1,5 +1,5 @@
 use super::SignatureType;
-use crate::transaction::PrimitiveSignature;
+use crate::transaction::{PrimitiveSignature, Versioned};
 use alloc::vec::Vec;
 use alloy_consensus::crypto::RecoveryError;
 use alloy_primitives::{Address, B256, U256, keccak256};
@@ -165,12 +165,14 @@ impl From<SelectorRule> for AbiSelectorRule {
 /// Used in TempoTransaction to add a new key to the AccountKeychain precompile.
 /// The transaction must be signed by the root key to authorize adding this access key.
 ///
-/// RLP encoding: `[chain_id, key_type, key_id, expiry?, limits?, allowed_calls?]`
+/// RLP encoding: `[chain_id, key_type, key_id, expiry?, limits?, allowed_calls?, max_uses?]`
 /// - Non-optional fields come first, followed by optional (trailing) fields
 /// - `expiry`: `None` (omitted or 0x80) = key never expires, `Some(timestamp)` = expires at timestamp
 /// - `limits`: `None` (omitted or 0x80) = unlimited spending, `Some([])` = no spending, `Some([...])` = specific limits
 /// - `allowed_calls`: `None` (canonically omitted, explicit 0x80 accepted) = unrestricted,
 ///   `Some([])` = scoped with no allowed calls, `Some([...])` = scoped calls
+/// - `max_uses`: `None` (canonically omitted, explicit 0x80 accepted) = unlimited uses,
+///   `Some(n)` = key valid for at most `n` transactions
 #[derive(Clone, Debug, PartialEq, Eq, Hash, alloy_rlp::RlpEncodable, alloy_rlp::RlpDecodable)]
 #[rlp(trailing(canonical))]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -207,7 +209,19 @@ pub struct KeyAuthorization {
     /// - `None` (canonically omitted, explicit 0x80 accepted) = unrestricted calls
     /// - `Some([])` = scoped mode with no allowed calls
     /// - `Some([CallScope{...}])` = explicit target/selector scope list
-    pub allowed_calls: Option<Vec<CallScope>>,
+    ///
+    /// Scopes decode through [`Versioned`] so fields appended by a future
+    /// `CallScope` version survive re-encoding (and thus the signature hash).
+    pub allowed_calls: Option<Vec<Versioned<CallScope>>>,
+
+    /// Maximum number of transactions this key may sign.
+    /// - `None` (canonically omitted, explicit 0x80 accepted) = unlimited uses
+    /// - `Some(n)` = the AccountKeychain precompile decrements a per-key counter on every
+    ///   use and rejects the key once `n` transactions have executed
+    ///
+    /// Like `expiry`, this uses `Option<NonZeroU64>` so `Some(0)` is unrepresentable.
+    #[cfg_attr(feature = "serde", serde(default, with = "serde_nonzero_quantity_opt"))]
+    pub max_uses: Option<NonZeroU64>,
 }
 
 impl KeyAuthorization {
@@ -221,6 +235,7 @@ impl KeyAuthorization {
             expiry: None,
             limits: None,
             allowed_calls: None,
+            max_uses: None,
         }
     }
 
@@ -230,6 +245,12 @@ impl KeyAuthorization {
         self
     }
 
+    /// Limit this key to at most `max_uses` transactions.
+    pub fn with_max_uses(mut self, max_uses: u64) -> Self {
+        self.max_uses = NonZeroU64::new(max_uses);
+        self
+    }
+
     /// Set token spending limits on this key authorization.
     pub fn with_limits(mut self, limits: Vec<TokenLimit>) -> Self {
         self.limits = Some(limits);
@@ -238,7 +259,7 @@ impl KeyAuthorization {
 
     /// Set call-scope restrictions on this key authorization.
     pub fn with_allowed_calls(mut self, allowed_calls: Vec<CallScope>) -> Self {
-        self.allowed_calls = Some(allowed_calls);
+        self.allowed_calls = Some(allowed_calls.into_iter().map(Into::into).collect());
         self
     }
 
@@ -273,6 +294,11 @@ impl KeyAuthorization {
         self.allowed_calls.is_some()
     }
 
+    /// Returns whether this authorization limits the key to a maximum number of uses.
+    pub fn has_max_uses(&self) -> bool {
+        self.max_uses.is_some()
+    }
+
     /// Returns whether this key has unlimited spending (limits is None)
     pub fn has_unlimited_spending(&self) -> bool {
         self.limits.is_none()
@@ -285,13 +311,13 @@ impl KeyAuthorization {
 
     /// Returns whether this authorization can be encoded with the legacy pre-T3 ABI.
     pub fn is_legacy_compatible(&self) -> bool {
-        !(self.has_periodic_limits() || self.has_call_scopes())
+        !(self.has_periodic_limits() || self.has_call_scopes() || self.has_max_uses())
     }
 
     /// Convert the key authorization into a [`SignedKeyAuthorization`] with a signature.
     pub fn into_signed(self, signature: PrimitiveSignature) -> SignedKeyAuthorization {
         SignedKeyAuthorization {
-            authorization: self,
+            authorization: self.into(),
             signature,
         }
     }
@@ -329,8 +355,11 @@ impl KeyAuthorization {
                 .as_ref()
                 .map_or(0, |limits| limits.capacity() * size_of::<TokenLimit>())
             + self.allowed_calls.as_ref().map_or(0, |scopes| {
-                scopes.capacity() * size_of::<CallScope>()
-                    + scopes.iter().map(CallScope::heap_size).sum::<usize>()
+                scopes.capacity() * size_of::<Versioned<CallScope>>()
+                    + scopes
+                        .iter()
+                        .map(|scope| scope.heap_size() + scope.unknown_fields.len())
+                        .sum::<usize>()
             })
     }
 }
@@ -360,15 +389,32 @@ pub struct KeyAuthorizationChainIdError {
 #[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
 #[cfg_attr(test, reth_codecs::add_arbitrary_tests(compact, rlp))]
 pub struct SignedKeyAuthorization {
-    /// Key authorization for provisioning access keys
+    /// Key authorization for provisioning access keys.
+    ///
+    /// Wrapped in [`Versioned`] so an authorization signed under a future
+    /// version keeps its appended fields — and thus its signature hash —
+    /// when it passes through this node.
     #[cfg_attr(feature = "serde", serde(flatten))]
     #[deref]
-    pub authorization: KeyAuthorization,
+    pub authorization: Versioned<KeyAuthorization>,
 
     /// Signature authorizing this key (signed by root key)
     pub signature: PrimitiveSignature,
 }
 
+impl Versioned<KeyAuthorization> {
+    /// Computes the authorization message hash over the versioned encoding.
+    ///
+    /// This deliberately shadows [`KeyAuthorization::signature_hash`] behind
+    /// the `Deref`: hashing only the inner fields would drop unknown trailing
+    /// fields and invalidate signatures made under a newer version.
+    pub fn signature_hash(&self) -> B256 {
+        let mut buf = Vec::new();
+        self.encode(&mut buf);
+        keccak256(&buf)
+    }
+}
+
 impl SignedKeyAuthorization {
     /// Recover the signer of the [`KeyAuthorization`].
     pub fn recover_signer(&self) -> Result<Address, RecoveryError> {
@@ -378,7 +424,9 @@ impl SignedKeyAuthorization {
 
     /// Calculates a heuristic for the in-memory size of the signed key authorization
     pub fn size(&self) -> usize {
-        self.authorization.size() + self.signature.size()
+        self.authorization.inner.size()
+            + self.authorization.unknown_fields.len()
+            + self.signature.size()
     }
 }
 
@@ -392,6 +440,7 @@ impl<'a> arbitrary::Arbitrary<'a> for KeyAuthorization {
             expiry: u.arbitrary()?,
             limits: u.arbitrary()?,
             allowed_calls: u.arbitrary()?,
+            max_uses: u.arbitrary()?,
         })
     }
 }
@@ -532,6 +581,7 @@ mod tests {
             expiry: expiry.and_then(NonZeroU64::new),
             limits,
             allowed_calls: None,
+            max_uses: None,
         }
     }
 
@@ -623,7 +673,7 @@ mod tests {
         let recipients = &selector_rules[0].recipients;
 
         let expected = size_of::<KeyAuthorization>()
-            + scope_rules.capacity() * size_of::<CallScope>()
+            + scope_rules.capacity() * size_of::<Versioned<CallScope>>()
             + selector_rules.capacity() * size_of::<SelectorRule>()
             + recipients.capacity() * size_of::<Address>();
 
@@ -644,6 +694,7 @@ mod tests {
             expiry: None,
             limits: None,
             allowed_calls: None,
+            max_uses: None,
         }
     }
 
@@ -1033,6 +1084,37 @@ mod tests {
         assert_eq!(reencoded, encoded);
     }
 
+    #[test]
+    fn test_max_uses_rlp_roundtrip_and_omission() {
+        // max_uses = None is canonically omitted, so the encoding matches one
+        // without the trailing field at all.
+        let unlimited =
+            KeyAuthorization::unrestricted(1, SignatureType::Secp256k1, Address::repeat_byte(0x11));
+        let mut encoded_unlimited = Vec::new();
+        unlimited.encode(&mut encoded_unlimited);
+
+        let decoded = <KeyAuthorization as Decodable>::decode(&mut encoded_unlimited.as_slice())
+            .expect("decode unlimited auth");
+        assert_eq!(decoded.max_uses, None);
+
+        // A bounded key roundtrips with the trailing field intact.
+        let bounded = unlimited.with_max_uses(5);
+        assert!(bounded.has_max_uses());
+        assert!(!bounded.is_legacy_compatible());
+
+        let mut encoded_bounded = Vec::new();
+        bounded.encode(&mut encoded_bounded);
+        assert!(encoded_bounded.len() > encoded_unlimited.len());
+
+        let decoded = <KeyAuthorization as Decodable>::decode(&mut encoded_bounded.as_slice())
+            .expect("decode bounded auth");
+        assert_eq!(decoded, bounded);
+        assert_eq!(decoded.max_uses, NonZeroU64::new(5));
+
+        // with_max_uses(0) is the unlimited spelling.
+        assert!(!bounded.with_max_uses(0).has_max_uses());
+    }
+
     #[test]
     fn test_validate_chain_id_pre_t1c() {
         let expected = 42431;
@@ -1,29 +1,43 @@
+pub mod compressed_envelope;
 pub mod envelope;
 pub mod key_authorization;
+pub mod multisig;
 pub mod tempo_transaction;
 pub mod tt_authorization;
 pub mod tt_signature;
 pub mod tt_signed;
+pub mod versioned_rlp;
 
 pub use tt_authorization::{MAGIC, RecoveredTempoAuthorization, TempoSignedAuthorization};
 // Re-export Authorization from alloy for convenience
 pub use tt_signature::{
     KeychainSignature, KeychainVersion, KeychainVersionError, PrimitiveSignature, TempoSignature,
-    derive_p256_address,
+    derive_p256_address, verify_p256_signature,
 };
 
 pub use alloy_eips::eip7702::Authorization;
+pub use compressed_envelope::{
+    COMPRESSED_ENVELOPE_MAGIC, CompressedEnvelopeError, DICTIONARY_ID_NONE,
+    MAX_DECOMPRESSED_PAYLOAD, decompressed_payload_len, is_compressed_envelope,
+};
+#[cfg(feature = "std")]
+pub use compressed_envelope::{compress_payload, decompress_payload, maybe_compress_payload};
 pub use envelope::{TIP20_PAYMENT_PREFIX, TempoTxEnvelope, TempoTxType, TempoTypedTransaction};
 pub use key_authorization::{
     CallScope, KeyAuthorization, KeyAuthorizationChainIdError, SelectorRule,
     SignedKeyAuthorization, TokenLimit,
 };
+pub use multisig::{
+    MAX_MULTISIG_SIGNERS, MultisigAuthorization, MultisigAuthorizationError, MultisigSignature,
+    WeightedSigner,
+};
 pub use tempo_transaction::{
     Call, MAX_WEBAUTHN_SIGNATURE_LENGTH, P256_SIGNATURE_LENGTH, SECP256K1_SIGNATURE_LENGTH,
     SignatureType, TEMPO_EXPIRING_NONCE_KEY, TEMPO_EXPIRING_NONCE_MAX_EXPIRY_SECS,
-    TEMPO_TX_TYPE_ID, TempoTransaction, validate_calls,
+    TEMPO_TX_TYPE_ID, TempoTransaction, TransactionResources, validate_calls,
 };
 pub use tt_signed::AASigned;
+pub use versioned_rlp::{Versioned, VersionedRlp, count_rlp_fields, split_rlp_fields};
 
 use alloy_primitives::{U256, uint};
 
@@ -0,0 +1,483 @@
+//! Threshold (M-of-N) root authority for Tempo accounts.
+//!
+//! A multisig root account is counterfactual: its address is derived from a
+//! [`MultisigAuthorization`] — the weighted signer set and threshold — so the
+//! configuration never has to be registered on-chain before use. A
+//! transaction from such an account carries the authorization alongside one
+//! inner signature per participating signer; recovery verifies every inner
+//! signature, sums the weights of the distinct configured signers that
+//! signed, and only yields the account address once the threshold is met.
+//!
+//! Inner signatures are [`PrimitiveSignature`]s (secp256k1, P256, or
+//! WebAuthn); keychain signatures are excluded so a multisig cannot nest
+//! delegation. The [`AccountKeychain`] precompile enforces the same
+//! authorization binding when multisig accounts manage access keys.
+//!
+//! [`AccountKeychain`]: <https://docs.tempo.xyz/protocol/account-keychain>
+
+use super::tt_signature::PrimitiveSignature;
+use alloc::vec::Vec;
+use alloy_consensus::crypto::RecoveryError;
+use alloy_primitives::{Address, B256, Bytes, keccak256};
+use alloy_rlp::{RlpDecodable, RlpEncodable};
+
+/// Wire type identifier for multisig signatures, continuing the sequence
+/// after the keychain types (`0x03`/`0x04`). Doubles as the domain separator
+/// byte in [`MultisigAuthorization::account_address`].
+pub const SIGNATURE_TYPE_MULTISIG: u8 = 0x05;
+
+/// Maximum number of signers in a multisig authorization. Bounds signature
+/// verification work per transaction.
+pub const MAX_MULTISIG_SIGNERS: usize = 16;
+
+/// One signer in a multisig authorization.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, RlpEncodable, RlpDecodable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct WeightedSigner {
+    /// Address of the signer's key.
+    pub address: Address,
+    /// Voting weight contributed when this signer signs.
+    pub weight: u32,
+}
+
+/// M-of-N root authority configuration: a weighted signer set and the weight
+/// threshold required to act as the account.
+///
+/// The configuration is canonical: signers must be sorted by ascending
+/// address with no duplicates, so equal signer sets always derive the same
+/// account address.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, RlpEncodable, RlpDecodable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+#[cfg_attr(any(test, feature = "arbitrary"), derive(arbitrary::Arbitrary))]
+pub struct MultisigAuthorization {
+    /// Total signer weight required for a valid signature.
+    pub threshold: u32,
+    /// Signer set, sorted by ascending address.
+    pub signers: Vec<WeightedSigner>,
+}
+
+/// Validation errors for a [`MultisigAuthorization`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultisigAuthorizationError {
+    /// The signer set is empty.
+    NoSigners,
+    /// The signer set exceeds [`MAX_MULTISIG_SIGNERS`].
+    TooManySigners,
+    /// The threshold is zero.
+    ZeroThreshold,
+    /// A signer has zero weight.
+    ZeroWeight,
+    /// Signers are not sorted by ascending address, or contain duplicates.
+    UnsortedSigners,
+    /// The threshold exceeds the total signer weight and can never be met.
+    UnreachableThreshold,
+}
+
+impl MultisigAuthorization {
+    /// Validates the configuration invariants.
+    pub fn validate(&self) -> Result<(), MultisigAuthorizationError> {
+        if self.signers.is_empty() {
+            return Err(MultisigAuthorizationError::NoSigners);
+        }
+        if self.signers.len() > MAX_MULTISIG_SIGNERS {
+            return Err(MultisigAuthorizationError::TooManySigners);
+        }
+        if self.threshold == 0 {
+            return Err(MultisigAuthorizationError::ZeroThreshold);
+        }
+
+        let mut total_weight = 0u64;
+        for (idx, signer) in self.signers.iter().enumerate() {
+            if signer.weight == 0 {
+                return Err(MultisigAuthorizationError::ZeroWeight);
+            }
+            if idx > 0 && self.signers[idx - 1].address >= signer.address {
+                return Err(MultisigAuthorizationError::UnsortedSigners);
+            }
+            total_weight += u64::from(signer.weight);
+        }
+
+        if u64::from(self.threshold) > total_weight {
+            return Err(MultisigAuthorizationError::UnreachableThreshold);
+        }
+        Ok(())
+    }
+
+    /// Total weight of all signers.
+    pub fn total_weight(&self) -> u64 {
+        self.signers.iter().map(|s| u64::from(s.weight)).sum()
+    }
+
+    /// Weight of a single signer, if it is part of the set.
+    pub fn weight_of(&self, address: Address) -> Option<u32> {
+        self.signers
+            .iter()
+            .find(|s| s.address == address)
+            .map(|s| s.weight)
+    }
+
+    /// Derives the counterfactual account address controlled by this
+    /// configuration: `keccak256(0x05 || rlp(authorization))[12..]`.
+    ///
+    /// The `0x05` domain separator ([`SIGNATURE_TYPE_MULTISIG`]) prevents
+    /// collisions with other address derivations over RLP payloads.
+    pub fn account_address(&self) -> Address {
+        let mut buf = Vec::with_capacity(1 + alloy_rlp::Encodable::length(self));
+        buf.push(SIGNATURE_TYPE_MULTISIG);
+        alloy_rlp::Encodable::encode(self, &mut buf);
+        Address::from_slice(&keccak256(&buf)[12..])
+    }
+}
+
+/// Aggregate threshold signature from a multisig root account.
+///
+/// Wire format: `0x05 || rlp([authorization, signatures])`. The
+/// authorization travels with the signature (like the embedded public key of
+/// a P256 signature); it is bound to the account by address derivation, so a
+/// forged configuration recovers to a different — unfunded — address.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, RlpEncodable, RlpDecodable)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct MultisigSignature {
+    /// The account's root authority configuration.
+    pub authorization: MultisigAuthorization,
+    /// Inner signatures over the transaction signature hash, one per
+    /// participating signer.
+    pub signatures: Vec<PrimitiveSignature>,
+}
+
+impl MultisigSignature {
+    /// Parses a multisig signature from its wire encoding.
+    pub fn from_bytes(data: &[u8]) -> Result<Self, &'static str> {
+        let Some((&type_id, mut payload)) = data.split_first() else {
+            return Err("Signature data is empty");
+        };
+        if type_id != SIGNATURE_TYPE_MULTISIG {
+            return Err("Not a multisig signature");
+        }
+        let decoded = <Self as alloy_rlp::Decodable>::decode(&mut payload)
+            .map_err(|_| "Failed to decode multisig signature")?;
+        if !payload.is_empty() {
+            return Err("Trailing bytes after multisig signature");
+        }
+        Ok(decoded)
+    }
+
+    /// Encodes the signature to its wire format.
+    pub fn to_bytes(&self) -> Bytes {
+        let mut bytes = Vec::with_capacity(1 + alloy_rlp::Encodable::length(self));
+        bytes.push(SIGNATURE_TYPE_MULTISIG);
+        alloy_rlp::Encodable::encode(self, &mut bytes);
+        Bytes::from(bytes)
+    }
+
+    /// Length of the wire encoding produced by [`Self::to_bytes`].
+    pub fn encoded_length(&self) -> usize {
+        1 + alloy_rlp::Encodable::length(self)
+    }
+
+    /// Heap-aware in-memory size of the signature.
+    pub fn size(&self) -> usize {
+        size_of::<Self>()
+            + self.authorization.signers.len() * size_of::<WeightedSigner>()
+            + self
+                .signatures
+                .iter()
+                .map(PrimitiveSignature::size)
+                .sum::<usize>()
+    }
+
+    /// Verifies the aggregate signature and recovers the multisig account
+    /// address.
+    ///
+    /// Every inner signature must verify against `sig_hash` and recover to a
+    /// distinct configured signer; each signer's weight counts once no matter
+    /// how many of its signatures are present. Fails unless the accumulated
+    /// weight reaches the threshold. An inner signature from a key outside
+    /// the configuration is rejected outright rather than ignored, so a
+    /// transaction carrying stray signatures never reaches the mempool.
+    pub fn recover_signer(&self, sig_hash: &B256) -> Result<Address, RecoveryError> {
+        self.authorization
+            .validate()
+            .map_err(|_| RecoveryError::new())?;
+
+        if self.signatures.is_empty() || self.signatures.len() > self.authorization.signers.len() {
+            return Err(RecoveryError::new());
+        }
+
+        let mut signed = [false; MAX_MULTISIG_SIGNERS];
+        let mut weight = 0u64;
+        for signature in &self.signatures {
+            let signer = signature.recover_signer(sig_hash)?;
+            let idx = self
+                .authorization
+                .signers
+                .iter()
+                .position(|s| s.address == signer)
+                .ok_or_else(RecoveryError::new)?;
+            if signed[idx] {
+                continue;
+            }
+            signed[idx] = true;
+            weight += u64::from(self.authorization.signers[idx].weight);
+        }
+
+        if weight < u64::from(self.authorization.threshold) {
+            return Err(RecoveryError::new());
+        }
+        Ok(self.authorization.account_address())
+    }
+}
+
+// Manual Arbitrary implementation that always generates at least one inner
+// signature: a bare 65-byte blob is parsed as a raw secp256k1 signature for
+// backward compatibility, so the wire encoding must stay longer than that to
+// round-trip through `TempoSignature::from_bytes`. An empty signature set can
+// never meet a threshold anyway.
+#[cfg(any(test, feature = "arbitrary"))]
+impl<'a> arbitrary::Arbitrary<'a> for MultisigSignature {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        let authorization = u.arbitrary()?;
+        let mut signatures: Vec<PrimitiveSignature> = u.arbitrary()?;
+        if signatures.is_empty() {
+            signatures.push(u.arbitrary()?);
+        }
+        Ok(Self {
+            authorization,
+            signatures,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::tt_signature::{
+        P256SignatureWithPreHash, derive_p256_address, normalize_p256_s,
+    };
+    use alloy_primitives::address;
+    use p256::ecdsa::{SigningKey, signature::hazmat::PrehashSigner};
+
+    fn signing_key(seed: u8) -> SigningKey {
+        SigningKey::from_slice(&[seed; 32]).unwrap()
+    }
+
+    fn pub_key(key: &SigningKey) -> (B256, B256) {
+        let point = key.verifying_key().to_encoded_point(false);
+        (
+            B256::from_slice(point.x().unwrap().as_ref()),
+            B256::from_slice(point.y().unwrap().as_ref()),
+        )
+    }
+
+    fn key_address(key: &SigningKey) -> Address {
+        let (x, y) = pub_key(key);
+        derive_p256_address(&x, &y)
+    }
+
+    fn sign(key: &SigningKey, hash: &B256) -> PrimitiveSignature {
+        let signature: p256::ecdsa::Signature = key.sign_prehash(hash.as_slice()).unwrap();
+        let sig_bytes = signature.to_bytes();
+        let (x, y) = pub_key(key);
+        PrimitiveSignature::P256(P256SignatureWithPreHash {
+            r: B256::from_slice(&sig_bytes[0..32]),
+            s: normalize_p256_s(&sig_bytes[32..64]).expect("p256 crate produces valid s"),
+            pub_key_x: x,
+            pub_key_y: y,
+            pre_hash: false,
+        })
+    }
+
+    /// 2-of-3 configuration with equal weights, sorted canonically.
+    fn two_of_three() -> (MultisigAuthorization, Vec<SigningKey>) {
+        let mut keys = vec![signing_key(1), signing_key(2), signing_key(3)];
+        keys.sort_by_key(key_address);
+        let signers = keys
+            .iter()
+            .map(|k| WeightedSigner {
+                address: key_address(k),
+                weight: 1,
+            })
+            .collect();
+        (
+            MultisigAuthorization {
+                threshold: 2,
+                signers,
+            },
+            keys,
+        )
+    }
+
+    #[test]
+    fn validate_rejects_malformed_configurations() {
+        let (valid, _) = two_of_three();
+        assert_eq!(valid.validate(), Ok(()));
+
+        let mut auth = valid.clone();
+        auth.signers.clear();
+        assert_eq!(auth.validate(), Err(MultisigAuthorizationError::NoSigners));
+
+        let mut auth = valid.clone();
+        auth.threshold = 0;
+        assert_eq!(
+            auth.validate(),
+            Err(MultisigAuthorizationError::ZeroThreshold)
+        );
+
+        let mut auth = valid.clone();
+        auth.threshold = 4;
+        assert_eq!(
+            auth.validate(),
+            Err(MultisigAuthorizationError::UnreachableThreshold)
+        );
+
+        let mut auth = valid.clone();
+        auth.signers[0].weight = 0;
+        assert_eq!(auth.validate(), Err(MultisigAuthorizationError::ZeroWeight));
+
+        let mut auth = valid.clone();
+        auth.signers.swap(0, 1);
+        assert_eq!(
+            auth.validate(),
+            Err(MultisigAuthorizationError::UnsortedSigners)
+        );
+
+        let mut auth = valid.clone();
+        auth.signers[1] = auth.signers[0];
+        assert_eq!(
+            auth.validate(),
+            Err(MultisigAuthorizationError::UnsortedSigners)
+        );
+
+        let mut auth = valid;
+        let filler = WeightedSigner {
+            address: address!("0xffffffffffffffffffffffffffffffffffffffff"),
+            weight: 1,
+        };
+        auth.signers.resize(MAX_MULTISIG_SIGNERS + 1, filler);
+        assert_eq!(
+            auth.validate(),
+            Err(MultisigAuthorizationError::TooManySigners)
+        );
+    }
+
+    #[test]
+    fn account_address_is_canonical_and_config_sensitive() {
+        let (auth, _) = two_of_three();
+        assert_eq!(auth.account_address(), auth.clone().account_address());
+
+        // Any change to the configuration derives a different account.
+        let mut bumped = auth.clone();
+        bumped.threshold = 3;
+        assert_ne!(auth.account_address(), bumped.account_address());
+
+        let mut reweighted = auth.clone();
+        reweighted.signers[0].weight = 2;
+        assert_ne!(auth.account_address(), reweighted.account_address());
+    }
+
+    #[test]
+    fn recover_signer_enforces_threshold() {
+        let (auth, keys) = two_of_three();
+        let account = auth.account_address();
+        let sig_hash = keccak256(b"multisig test transaction");
+
+        // One signature is short of the 2-of-3 threshold.
+        let short = MultisigSignature {
+            authorization: auth.clone(),
+            signatures: vec![sign(&keys[0], &sig_hash)],
+        };
+        assert!(short.recover_signer(&sig_hash).is_err());
+
+        // Two distinct signers meet it.
+        let met = MultisigSignature {
+            authorization: auth.clone(),
+            signatures: vec![sign(&keys[0], &sig_hash), sign(&keys[2], &sig_hash)],
+        };
+        assert_eq!(met.recover_signer(&sig_hash).unwrap(), account);
+
+        // A duplicated signer only counts once.
+        let duplicated = MultisigSignature {
+            authorization: auth.clone(),
+            signatures: vec![sign(&keys[0], &sig_hash), sign(&keys[0], &sig_hash)],
+        };
+        assert!(duplicated.recover_signer(&sig_hash).is_err());
+
+        // A signature from a key outside the set is rejected outright.
+        let outsider = MultisigSignature {
+            authorization: auth,
+            signatures: vec![sign(&keys[0], &sig_hash), sign(&signing_key(9), &sig_hash)],
+        };
+        assert!(outsider.recover_signer(&sig_hash).is_err());
+    }
+
+    #[test]
+    fn recover_signer_respects_weights() {
+        let (mut auth, keys) = two_of_three();
+        // First signer alone carries the threshold.
+        auth.signers[0].weight = 2;
+        let account = auth.account_address();
+        let sig_hash = keccak256(b"weighted multisig");
+
+        let heavy = MultisigSignature {
+            authorization: auth.clone(),
+            signatures: vec![sign(&keys[0], &sig_hash)],
+        };
+        assert_eq!(heavy.recover_signer(&sig_hash).unwrap(), account);
+
+        let light = MultisigSignature {
+            authorization: auth,
+            signatures: vec![sign(&keys[1], &sig_hash)],
+        };
+        assert!(light.recover_signer(&sig_hash).is_err());
+    }
+
+    #[test]
+    fn wire_encoding_round_trips() {
+        let (auth, keys) = two_of_three();
+        let sig_hash = keccak256(b"roundtrip");
+        let signature = MultisigSignature {
+            authorization: auth,
+            signatures: vec![sign(&keys[0], &sig_hash), sign(&keys[1], &sig_hash)],
+        };
+
+        let bytes = signature.to_bytes();
+        assert_eq!(bytes[0], SIGNATURE_TYPE_MULTISIG);
+        assert_eq!(MultisigSignature::from_bytes(&bytes).unwrap(), signature);
+
+        assert!(MultisigSignature::from_bytes(&[]).is_err());
+        assert!(MultisigSignature::from_bytes(&[0x03, 0x00]).is_err());
+        let mut trailing = bytes.to_vec();
+        trailing.push(0);
+        assert!(MultisigSignature::from_bytes(&trailing).is_err());
+    }
+
+    #[test]
+    fn tempo_signature_dispatches_multisig() {
+        use crate::transaction::tt_signature::TempoSignature;
+
+        let (auth, keys) = two_of_three();
+        let account = auth.account_address();
+        let sig_hash = keccak256(b"tempo signature dispatch");
+        let signature = TempoSignature::Multisig(MultisigSignature {
+            authorization: auth,
+            signatures: vec![sign(&keys[0], &sig_hash), sign(&keys[2], &sig_hash)],
+        });
+
+        // The 0x05 type byte routes through the multisig path, not
+        // PrimitiveSignature.
+        let bytes = signature.to_bytes();
+        assert_eq!(bytes[0], SIGNATURE_TYPE_MULTISIG);
+        assert_eq!(bytes.len(), signature.encoded_length());
+
+        let decoded = TempoSignature::from_bytes(&bytes).unwrap();
+        assert!(decoded.is_multisig());
+        assert_eq!(decoded, signature);
+
+        // Recovery through the envelope-level enum yields the multisig account.
+        assert_eq!(decoded.recover_signer(&sig_hash).unwrap(), account);
+    }
+}
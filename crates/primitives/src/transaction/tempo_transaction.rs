@@ -5,6 +5,7 @@ use crate::{
     transaction::{
         AASigned, TempoSignature, TempoSignedAuthorization,
         key_authorization::SignedKeyAuthorization,
+        versioned_rlp::{VersionedRlp, count_rlp_fields, split_rlp_fields},
     },
 };
 use alloc::vec::Vec;
@@ -203,6 +204,17 @@ pub struct TempoTransaction {
     /// Optional fee token preference (`None` means no preference)
     pub fee_token: Option<Address>,
 
+    /// Ordered list of acceptable fee tokens.
+    ///
+    /// When non-empty, the fee manager uses the first token with sufficient
+    /// balance and liquidity; `fee_token` must be `None`. Both the user and
+    /// the fee payer commit to this list in their signatures.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub fee_token_preferences: Vec<Address>,
+
     /// Max Priority fee per gas (EIP-1559)
     #[cfg_attr(feature = "serde", serde(with = "alloy_serde::quantity"))]
     pub max_priority_fee_per_gas: u128,
@@ -257,6 +269,22 @@ pub struct TempoTransaction {
     /// Authorization list (EIP-7702 style with Tempo signatures)
     #[cfg_attr(feature = "serde", serde(rename = "aaAuthorizationList"))]
     pub tempo_authorization_list: Vec<TempoSignedAuthorization>,
+
+    /// Raw RLP bytes of trailing fields appended by a future transaction
+    /// version (see [`VersionedRlp`](super::VersionedRlp)).
+    ///
+    /// The signed envelope inlines the transaction fields next to the
+    /// signature rather than nesting them in a sub-list, so the unknown tail
+    /// is captured here instead of through [`Versioned`](super::Versioned).
+    /// Re-encoding (including both signing hashes) appends these bytes
+    /// verbatim, letting a transaction built under a newer version pass
+    /// through this node without invalidating its signatures. JSON only
+    /// carries the fields this node understands.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Bytes::is_empty")
+    )]
+    pub unknown_fields: Bytes,
 }
 
 /// Validates the calls list structure for Tempo transactions.
@@ -298,6 +326,39 @@ pub fn validate_calls(calls: &[Call], has_authorization_list: bool) -> Result<()
     Ok(())
 }
 
+/// Gas per calldata token, per EIP-2028 as applied by the node's intrinsic
+/// gas rules (a zero byte is 1 token, a non-zero byte is 4).
+pub const CALLDATA_GAS_PER_TOKEN: u64 = 4;
+
+/// Static resource footprint of a [`TempoTransaction`], computed by
+/// [`TempoTransaction::estimate_resources`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TransactionResources {
+    /// RLP-encoded size of the unsigned transaction in bytes, including the
+    /// type byte. The signed envelope adds the sender signature on top
+    /// (~70 bytes for secp256k1, more for P256/WebAuthn).
+    pub encoded_size: usize,
+    /// Total calldata bytes across all calls.
+    pub calldata_size: usize,
+    /// Number of storage-touching components: one per key authorization
+    /// plus one per spending limit it carries, and one per entry in the
+    /// authorization list.
+    pub storage_components: usize,
+    /// EIP-2028 calldata tokens across all calls (zero byte = 1 token,
+    /// non-zero byte = 4 tokens).
+    pub calldata_tokens: u64,
+    /// Calldata gas across all calls, consistent with the intrinsic gas
+    /// charged at execution: `calldata_tokens` × [`CALLDATA_GAS_PER_TOKEN`].
+    pub calldata_gas: u64,
+}
+
+///// Counts EIP-2028 calldata tokens: 1 per zero byte, 4 per non-zero byte.
+fn tokens_in_calldata(input: &[u8]) -> u64 {
+    let zero_bytes = input.iter().filter(|byte| **byte == 0).count() as u64;
+    let non_zero_bytes = input.len() as u64 - zero_bytes;
+    zero_bytes + non_zero_bytes * 4
+}
+
 impl TempoTransaction {
     /// Get the transaction type
     #[doc(alias = "transaction_type")]
@@ -323,6 +384,11 @@ impl TempoTransaction {
         // Validate calls list structure using the shared function
         validate_calls(&self.calls, !self.tempo_authorization_list.is_empty())?;
 
+        // fee_token_preferences supersedes fee_token; setting both is ambiguous
+        if self.fee_token.is_some() && !self.fee_token_preferences.is_empty() {
+            return Err("cannot set both fee_token and fee_token_preferences");
+        }
+
         // validBefore must be greater than validAfter if both are set
         if let Some(valid_after) = self.valid_after
             && let Some(valid_before) = self.valid_before
@@ -334,10 +400,21 @@ impl TempoTransaction {
         Ok(())
     }
 
+    /// Returns the ordered fee token candidates: the preference list when
+    /// non-empty, otherwise the single `fee_token` (if any).
+    pub fn fee_token_candidates(&self) -> &[Address] {
+        if self.fee_token_preferences.is_empty() {
+            self.fee_token.as_slice()
+        } else {
+            &self.fee_token_preferences
+        }
+    }
+
     /// Calculates a heuristic for the in-memory size of the transaction
     #[inline]
     pub fn size(&self) -> usize {
         size_of::<Self>()
+            + self.fee_token_preferences.capacity() * size_of::<Address>()
             + self.calls.iter().map(|call| call.size()).sum::<usize>()
             + self.access_list.size()
             + self.key_authorization.as_ref().map_or(0, |k| k.size())
@@ -346,6 +423,43 @@ impl TempoTransaction {
                 .iter()
                 .map(|auth| auth.size())
                 .sum::<usize>()
+            + self.unknown_fields.len()
+    }
+
+    /// Estimates the transaction's static resource footprint without
+    /// executing it.
+    ///
+    /// Intended for wallets sizing an envelope before signing, and for the
+    /// pool's pre-validation to reject oversized transactions cheaply. All
+    /// numbers are derived from the unsigned transaction alone; see
+    /// [`TransactionResources`] for what each covers.
+    pub fn estimate_resources(&self) -> TransactionResources {
+        let payload_length = self.rlp_encoded_fields_length_default();
+        let encoded_size = 1 + rlp_header(payload_length).length_with_payload();
+
+        let mut calldata_size = 0usize;
+        let mut calldata_tokens = 0u64;
+        for call in &self.calls {
+            calldata_size += call.input.len();
+            calldata_tokens += tokens_in_calldata(&call.input);
+        }
+
+        let key_auth_components = self.key_authorization.as_ref().map_or(0, |key_auth| {
+            1 + key_auth
+                .authorization
+                .limits
+                .as_ref()
+                .map_or(0, |limits| limits.len())
+        });
+        let storage_components = key_auth_components + self.tempo_authorization_list.len();
+
+        TransactionResources {
+            encoded_size,
+            calldata_size,
+            storage_components,
+            calldata_tokens,
+            calldata_gas: calldata_tokens * CALLDATA_GAS_PER_TOKEN,
+        }
     }
 
     /// Convert the transaction into a signed transaction
@@ -431,12 +545,26 @@ impl TempoTransaction {
             signature_length(&self.fee_payer_signature) +
             // authorization_list
             self.tempo_authorization_list.length() +
-            // key_authorization (only included if present)
+            // key_authorization (only included if present, or as an explicit
+            // placeholder when unknown trailing fields keep its position occupied)
             if let Some(key_auth) = &self.key_authorization {
                 key_auth.length()
-            } else {
+            } else if self.unknown_fields.is_empty() {
                 0 // No bytes when None
-            }
+            } else {
+                1 // EMPTY_STRING_CODE placeholder
+            } +
+            // fee_token_preferences (trailing, only included if non-empty or
+            // position-holding like key_authorization above)
+            if !self.fee_token_preferences.is_empty() {
+                self.fee_token_preferences.length()
+            } else if self.unknown_fields.is_empty() {
+                0
+            } else {
+                1 // EMPTY_STRING_CODE placeholder
+            } +
+            // unknown trailing fields from a future version, re-encoded verbatim
+            self.unknown_fields.len()
     }
 
     fn rlp_encode_fields(
@@ -477,11 +605,27 @@ impl TempoTransaction {
         // Encode authorization_list
         self.tempo_authorization_list.encode(out);
 
-        // Encode key_authorization (truly optional - only encoded if present)
+        // Encode key_authorization (truly optional - only encoded if present).
+        // No bytes at all when None - maintains backwards compatibility. When
+        // unknown trailing fields follow, an absent value must be spelled out
+        // as an explicit placeholder so every position keeps its meaning.
         if let Some(key_auth) = &self.key_authorization {
             key_auth.encode(out);
+        } else if !self.unknown_fields.is_empty() {
+            out.put_u8(EMPTY_STRING_CODE);
         }
-        // No bytes at all when None - maintains backwards compatibility
+
+        // Encode fee_token_preferences (trailing, only encoded if non-empty or
+        // position-holding like key_authorization above)
+        if !self.fee_token_preferences.is_empty() {
+            self.fee_token_preferences.encode(out);
+        } else if !self.unknown_fields.is_empty() {
+            out.put_u8(EMPTY_STRING_CODE);
+        }
+
+        // Re-append fields from a future version byte-exactly, so their
+        // signatures (which commit to the full field list) stay valid.
+        out.put_slice(&self.unknown_fields);
     }
 
     /// Public version for normal RLP encoding
@@ -564,10 +708,26 @@ impl TempoTransaction {
         // If it's a bytes string (0x80-0xbf for short, 0xb8-0xbf for long), it's not a
         // KeyAuthorization and most likely a signature bytes following the AA transaction.
         let key_authorization = if let Some(&first) = buf.first() {
+            if first == EMPTY_STRING_CODE {
+                // Explicit placeholder: once a future version appends fields
+                // beyond this optional, it must spell the absent value out so
+                // positions keep their meaning.
+                buf.advance(1);
+                None
+            }
             // Check if this looks like an RLP list (KeyAuthorization is always a list)
-            if first >= 0xc0 {
-                // This could be a KeyAuthorization
-                Some(Decodable::decode(buf)?)
+            else if first >= 0xc0 {
+                // Both KeyAuthorization and the trailing fee_token_preferences are
+                // RLP lists. Attempt the KeyAuthorization first and rewind on
+                // failure so that encodings without one keep decoding unchanged.
+                let mut attempt = *buf;
+                match Decodable::decode(&mut attempt) {
+                    Ok(key_auth) => {
+                        *buf = attempt;
+                        Some(key_auth)
+                    }
+                    Err(_) => None,
+                }
             } else {
                 // This is likely not a KeyAuthorization (probably signature bytes in AASigned context)
                 None
@@ -576,9 +736,21 @@ impl TempoTransaction {
             None
         };
 
+        // Decode trailing fee_token_preferences (only present if non-empty,
+        // or as an explicit placeholder ahead of unknown trailing fields)
+        let fee_token_preferences = match buf.first() {
+            Some(&first) if first >= 0xc0 => Decodable::decode(buf)?,
+            Some(&EMPTY_STRING_CODE) => {
+                buf.advance(1);
+                Vec::new()
+            }
+            _ => Vec::new(),
+        };
+
         let tx = Self {
             chain_id,
             fee_token,
+            fee_token_preferences,
             max_priority_fee_per_gas,
             max_fee_per_gas,
             gas_limit,
@@ -591,6 +763,9 @@ impl TempoTransaction {
             valid_after,
             key_authorization,
             tempo_authorization_list,
+            // Trailing unknown fields are split off by the caller (the field
+            // boundary differs between the bare and the signed envelope).
+            unknown_fields: Bytes::new(),
         };
 
         // Validate the transaction
@@ -783,12 +958,22 @@ impl Decodable for TempoTransaction {
             return Err(alloy_rlp::Error::InputTooShort);
         }
 
-        let mut fields_buf = &buf[..header.payload_length];
-        let this = Self::rlp_decode_fields(&mut fields_buf)?;
+        // Fields beyond the newest version this node understands are kept as
+        // raw bytes so re-encoding (and both signing hashes) stays byte-exact.
+        let payload = &buf[..header.payload_length];
+        let total_fields = count_rlp_fields(payload)?;
+        let (known, unknown) = split_rlp_fields(
+            payload,
+            total_fields.min(<Self as VersionedRlp>::KNOWN_FIELDS),
+        )?;
+
+        let mut fields_buf = known;
+        let mut this = Self::rlp_decode_fields(&mut fields_buf)?;
 
         if !fields_buf.is_empty() {
             return Err(alloy_rlp::Error::UnexpectedLength);
         }
+        this.unknown_fields = Bytes::copy_from_slice(unknown);
         buf.advance(header.payload_length);
 
         Ok(this)
@@ -854,9 +1039,17 @@ impl<'a> arbitrary::Arbitrary<'a> for TempoTransaction {
             None => u.arbitrary()?,
         };
 
+        // Preferences are only valid when no single fee_token is set.
+        let fee_token_preferences: Vec<Address> = if fee_token.is_none() {
+            u.arbitrary()?
+        } else {
+            Vec::new()
+        };
+
         Ok(Self {
             chain_id,
             fee_token,
+            fee_token_preferences,
             max_priority_fee_per_gas,
             max_fee_per_gas,
             gas_limit,
@@ -869,6 +1062,9 @@ impl<'a> arbitrary::Arbitrary<'a> for TempoTransaction {
             valid_after,
             key_authorization: u.arbitrary()?,
             tempo_authorization_list: vec![],
+            // Unknown trailing fields force explicit placeholders for the
+            // optional tail, so leave them empty to keep round-trips canonical.
+            unknown_fields: Bytes::new(),
         })
     }
 }
@@ -1028,6 +1224,7 @@ mod tests {
         let tx = TempoTransaction {
             chain_id: 1,
             fee_token: Some(address!("0000000000000000000000000000000000000001")),
+            fee_token_preferences: vec![],
             max_priority_fee_per_gas: 1000000000,
             max_fee_per_gas: 2000000000,
             gas_limit: 21000,
@@ -1040,6 +1237,7 @@ mod tests {
             valid_after: Some(nz(500000)),
             key_authorization: None,
             tempo_authorization_list: vec![],
+            unknown_fields: Default::default(),
         };
 
         // Encode
@@ -1069,6 +1267,74 @@ mod tests {
         assert_eq!(decoded.fee_payer_signature, tx.fee_payer_signature);
     }
 
+    #[test]
+    fn test_rlp_roundtrip_fee_token_preferences() {
+        let call = Call {
+            to: TxKind::Call(address!("0000000000000000000000000000000000000002")),
+            value: U256::from(1000),
+            input: Bytes::new(),
+        };
+
+        let tx = TempoTransaction {
+            chain_id: 1,
+            fee_token: None,
+            fee_token_preferences: vec![
+                address!("0000000000000000000000000000000000000003"),
+                address!("0000000000000000000000000000000000000004"),
+            ],
+            max_priority_fee_per_gas: 1000000000,
+            max_fee_per_gas: 2000000000,
+            gas_limit: 21000,
+            calls: vec![call.clone()],
+            access_list: Default::default(),
+            nonce_key: U256::ZERO,
+            nonce: 1,
+            fee_payer_signature: None,
+            valid_before: None,
+            valid_after: None,
+            key_authorization: None,
+            tempo_authorization_list: vec![],
+            unknown_fields: Default::default(),
+        };
+
+        let mut buf = Vec::new();
+        tx.encode(&mut buf);
+        let decoded = TempoTransaction::decode(&mut buf.as_slice()).unwrap();
+        assert_eq!(decoded.fee_token_preferences, tx.fee_token_preferences);
+        assert_eq!(decoded, tx);
+
+        // An empty list is omitted from the encoding and must roundtrip to empty.
+        let tx_empty = TempoTransaction {
+            fee_token_preferences: vec![],
+            ..tx.clone()
+        };
+        let mut buf = Vec::new();
+        tx_empty.encode(&mut buf);
+        let decoded = TempoTransaction::decode(&mut buf.as_slice()).unwrap();
+        assert!(decoded.fee_token_preferences.is_empty());
+
+        // The preference list changes the signing hash.
+        assert_ne!(tx.signature_hash(), tx_empty.signature_hash());
+    }
+
+    #[test]
+    fn test_fee_token_and_preferences_are_mutually_exclusive() {
+        let tx = TempoTransaction {
+            fee_token: Some(address!("0000000000000000000000000000000000000001")),
+            fee_token_preferences: vec![address!("0000000000000000000000000000000000000002")],
+            calls: vec![Call {
+                to: TxKind::Call(address!("0000000000000000000000000000000000000002")),
+                value: U256::ZERO,
+                input: Bytes::new(),
+            }],
+            ..Default::default()
+        };
+        assert_eq!(
+            tx.validate(),
+            Err("cannot set both fee_token and fee_token_preferences")
+        );
+    }
+
     #[test]
     fn test_rlp_roundtrip_no_optional_fields() {
         let call = Call {
@@ -1080,6 +1346,7 @@ mod tests {
         let tx = TempoTransaction {
             chain_id: 1,
             fee_token: None,
+            fee_token_preferences: vec![],
             max_priority_fee_per_gas: 1000000000,
             max_fee_per_gas: 2000000000,
             gas_limit: 21000,
@@ -1092,6 +1359,7 @@ mod tests {
             valid_after: None,
             key_authorization: None,
             tempo_authorization_list: vec![],
+            unknown_fields: Default::default(),
         };
 
         // Encode
@@ -1376,6 +1644,7 @@ mod tests {
         let tx_no_payer_no_token = TempoTransaction {
             chain_id: 1,
             fee_token: None,
+            fee_token_preferences: vec![],
             max_priority_fee_per_gas: 1000000000,
             max_fee_per_gas: 2000000000,
             gas_limit: 21000,
@@ -1388,6 +1657,7 @@ mod tests {
             tempo_authorization_list: vec![],
             access_list: Default::default(),
             key_authorization: None,
+            unknown_fields: Default::default(),
         };
 
         // Transaction WITHOUT fee_payer, fee_token = token1
@@ -1438,6 +1708,7 @@ mod tests {
         let tx_with_token = TempoTransaction {
             chain_id: 1,
             fee_token: Some(token),
+            fee_token_preferences: vec![],
             max_priority_fee_per_gas: 1000000000,
             max_fee_per_gas: 2000000000,
             gas_limit: 21000,
@@ -1450,6 +1721,7 @@ mod tests {
             tempo_authorization_list: vec![],
             access_list: Default::default(),
             key_authorization: None,
+            unknown_fields: Default::default(),
         };
 
         // Transaction without fee_token
@@ -1502,6 +1774,7 @@ mod tests {
         let tx_no_payer_no_token = TempoTransaction {
             chain_id: 1,
             fee_token: None,
+            fee_token_preferences: vec![],
             max_priority_fee_per_gas: 1000000000,
             max_fee_per_gas: 2000000000,
             gas_limit: 21000,
@@ -1514,6 +1787,7 @@ mod tests {
             tempo_authorization_list: vec![],
             access_list: Default::default(),
             key_authorization: None,
+            unknown_fields: Default::default(),
         };
 
         // Scenario 2: No fee payer, with token
@@ -1573,6 +1847,7 @@ mod tests {
         let tx_without = TempoTransaction {
             chain_id: 1,
             fee_token: Some(address!("0000000000000000000000000000000000000001")),
+            fee_token_preferences: vec![],
             max_priority_fee_per_gas: 1000000000,
             max_fee_per_gas: 2000000000,
             gas_limit: 21000,
@@ -1585,6 +1860,7 @@ mod tests {
             valid_after: Some(nz(500000)),
             key_authorization: None, // No key authorization
             tempo_authorization_list: vec![],
+            unknown_fields: Default::default(),
         };
 
         // Encode the transaction
@@ -1662,6 +1938,7 @@ mod tests {
         let tx = TempoTransaction {
             chain_id: 0,
             fee_token: None,
+            fee_token_preferences: vec![],
             max_priority_fee_per_gas: 0,
             max_fee_per_gas: 0,
             gas_limit: 0,
@@ -1674,6 +1951,7 @@ mod tests {
             valid_after: None,
             key_authorization: None, // No key_authorization
             tempo_authorization_list: vec![],
+            unknown_fields: Default::default(),
         };
 
         let signature =
@@ -1702,6 +1980,7 @@ mod tests {
         let tx = TempoTransaction {
             chain_id: 0,
             fee_token: None,
+            fee_token_preferences: vec![],
             max_priority_fee_per_gas: 0,
             max_fee_per_gas: 0,
             gas_limit: 0,
@@ -1714,6 +1993,7 @@ mod tests {
             valid_after: None,
             key_authorization: None, // No key_authorization
             tempo_authorization_list: vec![],
+            unknown_fields: Default::default(),
         };
 
         let signature =
@@ -1779,6 +2059,7 @@ mod tests {
         let tx = TempoTransaction {
             chain_id: 1,
             fee_token: Some(Address::random()),
+            fee_token_preferences: vec![],
             max_priority_fee_per_gas: 1000000000,
             max_fee_per_gas: 2000000000,
             gas_limit: 21000,
@@ -1791,6 +2072,7 @@ mod tests {
             valid_after: Some(nz(500000)),
             key_authorization: None,
             tempo_authorization_list: vec![],
+            unknown_fields: Default::default(),
         };
 
         // Encode the transaction normally
@@ -2002,6 +2284,83 @@ mod tests {
         };
         assert!(valid_expiring_tx.validate().is_ok());
     }
+
+    #[test]
+    fn test_estimate_resources() {
+        // Two calls with known calldata: 00ff00ff is 2 zero + 2 non-zero
+        // bytes (2*1 + 2*4 = 10 tokens), the all-zero input is 4 tokens.
+        let tx = TempoTransaction {
+            chain_id: 1,
+            calls: vec![
+                Call {
+                    to: TxKind::Call(Address::ZERO),
+                    value: U256::ZERO,
+                    input: bytes!("00ff00ff"),
+                },
+                Call {
+                    to: TxKind::Create,
+                    value: U256::ZERO,
+                    input: Bytes::from(vec![0u8; 4]),
+                },
+            ],
+            ..Default::default()
+        };
+
+        let resources = tx.estimate_resources();
+        assert_eq!(resources.calldata_size, 8);
+        assert_eq!(resources.calldata_tokens, 14);
+        assert_eq!(resources.calldata_gas, 14 * CALLDATA_GAS_PER_TOKEN);
+        assert_eq!(resources.storage_components, 0);
+
+        // encoded_size is the type byte plus the actual unsigned RLP encoding.
+        let mut buf = Vec::new();
+        tx.encode(&mut buf);
+        assert_eq!(resources.encoded_size, 1 + buf.len());
+
+        // Key authorization counts the key itself plus each token limit; each
+        // 7702 authorization counts once.
+        let key_auth = KeyAuthorization::unrestricted(
+            1,
+            SignatureType::Secp256k1,
+            address!("0000000000000000000000000000000000000004"),
+        )
+        .with_limits(vec![
+            crate::transaction::TokenLimit {
+                token: address!("0000000000000000000000000000000000000003"),
+                limit: U256::from(10000),
+                period: 0,
+            },
+            crate::transaction::TokenLimit {
+                token: address!("0000000000000000000000000000000000000005"),
+                limit: U256::from(20000),
+                period: 86400,
+            },
+        ])
+        .into_signed(PrimitiveSignature::Secp256k1(Signature::test_signature()));
+
+        let tx_with_storage = TempoTransaction {
+            key_authorization: Some(key_auth),
+            tempo_authorization_list: vec![TempoSignedAuthorization::new_unchecked(
+                Authorization {
+                    chain_id: U256::from(1u64),
+                    address: Address::ZERO,
+                    nonce: 0,
+                },
+                TempoSignature::Primitive(PrimitiveSignature::Secp256k1(
+                    Signature::test_signature(),
+                )),
+            )],
+            ..tx
+        };
+
+        let resources = tx_with_storage.estimate_resources();
+        assert_eq!(resources.storage_components, (1 + 2) + 1);
+        // The extra fields grow the envelope but leave calldata untouched.
+        assert_eq!(resources.calldata_gas, 14 * CALLDATA_GAS_PER_TOKEN);
+        let mut buf = Vec::new();
+        tx_with_storage.encode(&mut buf);
+        assert_eq!(resources.encoded_size, 1 + buf.len());
+    }
 }
 
 #[cfg(all(test, feature = "reth-codec"))]
@@ -2053,6 +2412,7 @@ mod compact_tests {
         let tx = TempoTransaction {
             chain_id: 42170,
             fee_token: Some(address!("0x0000000000000000000000000000000000000abc")),
+            fee_token_preferences: vec![],
             max_priority_fee_per_gas: 1_000_000_000,
             max_fee_per_gas: 50_000_000_000,
             gas_limit: 21000,
@@ -2089,7 +2449,9 @@ mod compact_tests {
                         period: 86400,
                     }]),
                     allowed_calls: None,
-                },
+                    max_uses: None,
+                }
+                .into(),
                 signature: PrimitiveSignature::P256(P256SignatureWithPreHash {
                     r: b256!("0x1111111111111111111111111111111111111111111111111111111111111111"),
                     s: b256!("0x2222222222222222222222222222222222222222222222222222222222222222"),
@@ -2114,6 +2476,7 @@ mod compact_tests {
                     true,
                 ))),
             )],
+            unknown_fields: Default::default(),
         };
 
         let expected = hex!(
@@ -1,5 +1,9 @@
-use super::tempo_transaction::{
-    MAX_WEBAUTHN_SIGNATURE_LENGTH, P256_SIGNATURE_LENGTH, SECP256K1_SIGNATURE_LENGTH, SignatureType,
+use super::{
+    multisig::{MultisigSignature, SIGNATURE_TYPE_MULTISIG},
+    tempo_transaction::{
+        MAX_WEBAUTHN_SIGNATURE_LENGTH, P256_SIGNATURE_LENGTH, SECP256K1_SIGNATURE_LENGTH,
+        SignatureType,
+    },
 };
 use alloc::vec::Vec;
 use alloy_primitives::{Address, B256, Bytes, Signature, U256, keccak256, uint};
@@ -538,6 +542,12 @@ pub enum TempoSignature {
     /// IMP: The inner signature MUST NOT be another Keychain (validated at runtime)
     /// Note: Recursion is prevented by KeychainSignature's custom Arbitrary impl
     Keychain(KeychainSignature),
+
+    /// M-of-N threshold signature from a multisig root account
+    /// Format: `0x05 || rlp([authorization, signatures])`
+    /// Inner signatures are [`PrimitiveSignature`]s, so a multisig cannot nest
+    /// keychain delegation
+    Multisig(MultisigSignature),
 }
 
 impl TempoSignature {
@@ -584,6 +594,15 @@ impl TempoSignature {
             }));
         }
 
+        // Multisig signatures (type identifier 0x05) likewise carry structure
+        // that PrimitiveSignature does not know about
+        if data.len() > 1
+            && data.len() != SECP256K1_SIGNATURE_LENGTH
+            && data[0] == SIGNATURE_TYPE_MULTISIG
+        {
+            return MultisigSignature::from_bytes(data).map(Self::Multisig);
+        }
+
         // For all non-Keychain signatures, delegate to PrimitiveSignature
         let primitive = PrimitiveSignature::from_bytes(data)?;
         Ok(Self::Primitive(primitive))
@@ -610,6 +629,7 @@ impl TempoSignature {
                 bytes.extend_from_slice(&inner_bytes);
                 Bytes::from(bytes)
             }
+            Self::Multisig(multisig_sig) => multisig_sig.to_bytes(),
         }
     }
 
@@ -622,14 +642,24 @@ impl TempoSignature {
         match self {
             Self::Primitive(primitive_sig) => primitive_sig.encoded_length(),
             Self::Keychain(keychain_sig) => 1 + 20 + keychain_sig.signature.encoded_length(),
+            Self::Multisig(multisig_sig) => multisig_sig.encoded_length(),
         }
     }
 
     /// Get signature type
+    ///
+    /// A multisig aggregates primitive signatures of potentially different
+    /// schemes and has no single type; the first participant's type is
+    /// reported. Keychain key-type parity checks never reach the multisig arm
+    /// because a multisig cannot be a keychain access key.
     pub fn signature_type(&self) -> SignatureType {
         match self {
             Self::Primitive(primitive_sig) => primitive_sig.signature_type(),
             Self::Keychain(keychain_sig) => keychain_sig.signature.signature_type(),
+            Self::Multisig(multisig_sig) => multisig_sig
+                .signatures
+                .first()
+                .map_or(SignatureType::Secp256k1, PrimitiveSignature::signature_type),
         }
     }
 
@@ -638,6 +668,7 @@ impl TempoSignature {
         match self {
             Self::Primitive(primitive_sig) => primitive_sig.size(),
             Self::Keychain(keychain_sig) => 1 + 20 + keychain_sig.signature.size(),
+            Self::Multisig(multisig_sig) => multisig_sig.size(),
         }
     }
 
@@ -648,6 +679,8 @@ impl TempoSignature {
     /// - P256: Verifies P256 signature then derives address from public key
     /// - WebAuthn: Parses WebAuthn data, verifies P256 signature, derives address
     /// - Keychain: Validates inner signature and returns user_address
+    /// - Multisig: Verifies every inner signature, requires the weight threshold,
+    ///   and returns the account address derived from the authorization
     ///
     /// For Keychain signatures, this performs full validation of the inner signature.
     /// The access key address is cached in the KeychainSignature for later use.
@@ -668,6 +701,7 @@ impl TempoSignature {
                 // Return the user_address - the root account this transaction is for
                 Ok(keychain_sig.user_address)
             }
+            Self::Multisig(multisig_sig) => multisig_sig.recover_signer(sig_hash),
         }
     }
 
@@ -713,6 +747,19 @@ impl TempoSignature {
             _ => None,
         }
     }
+
+    /// Check if this is a Multisig signature
+    pub fn is_multisig(&self) -> bool {
+        matches!(self, Self::Multisig(_))
+    }
+
+    /// Get the Multisig signature if this is a Multisig signature
+    pub fn as_multisig(&self) -> Option<&MultisigSignature> {
+        match self {
+            Self::Multisig(multisig_sig) => Some(multisig_sig),
+            _ => None,
+        }
+    }
 }
 
 impl Default for TempoSignature {
@@ -835,21 +882,46 @@ fn verify_p256_signature_with_p256(
 /// ECDSA signature (r, s), a second valid signature (r, n-s) exists. By
 /// requiring s <= n/2 (the "low-s" requirement), we ensure only one canonical
 /// form is accepted, preventing transaction hash malleability attacks.
+fn verify_p256_signature_internal(
+    r: &[u8],
+    s: &[u8],
+    pub_key_x: &[u8],
+    pub_key_y: &[u8],
+    message_hash: &B256,
+) -> Result<(), &'static str> {
+    // High-s value check: reject signatures where s > n/2 to prevent malleability
+    if U256::from_be_slice(s) > P256N_HALF {
+        return Err("P256 signature has high s value");
+    }
+
+    verify_p256_signature(r, s, pub_key_x, pub_key_y, message_hash)
+}
+
+/// Verifies a raw P256 (secp256r1) ECDSA signature over a 32-byte message hash.
+///
+/// Unlike [the transaction path](PrimitiveSignature::recover_signer), this does
+/// NOT enforce the low-s requirement: both (r, s) and (r, n-s) are accepted,
+/// matching RIP-7212 `P256VERIFY` semantics. Transaction signatures must stay
+/// canonical and go through `recover_signer` instead; this entry point exists
+/// for the precompile, where malleability is the caller contract's concern.
 ///
 /// NOTE: this function conditionally compiles based on the cfg
 /// - !std → p256
 /// - std && !test → aws-lc-rs (best performance)
 /// - std && test → both (ensures verification backend alignment)
-fn verify_p256_signature_internal(
+pub fn verify_p256_signature(
     r: &[u8],
     s: &[u8],
     pub_key_x: &[u8],
     pub_key_y: &[u8],
     message_hash: &B256,
 ) -> Result<(), &'static str> {
-    // High-s value check: reject signatures where s > n/2 to prevent malleability
-    if U256::from_be_slice(s) > P256N_HALF {
-        return Err("P256 signature has high s value");
+    // Scalars must be in [1, n-1]; the backends reject zero but not out-of-range values uniformly.
+    let r_scalar = U256::from_be_slice(r);
+    let s_scalar = U256::from_be_slice(s);
+    if r_scalar.is_zero() || r_scalar >= P256_ORDER || s_scalar.is_zero() || s_scalar >= P256_ORDER
+    {
+        return Err("P256 signature scalar out of range");
     }
 
     #[cfg(all(feature = "std", not(test)))]
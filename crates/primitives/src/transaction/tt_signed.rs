@@ -1,6 +1,7 @@
 use super::{
     tempo_transaction::{TEMPO_TX_TYPE_ID, TempoTransaction},
     tt_signature::TempoSignature,
+    versioned_rlp::{VersionedRlp, count_rlp_fields, split_rlp_fields},
 };
 use alloc::vec::Vec;
 use alloy_consensus::{SignableTransaction, Transaction, transaction::TxHashRef};
@@ -11,7 +12,7 @@ use alloy_eips::{
     eip7702::SignedAuthorization,
 };
 use alloy_primitives::{Address, B256, Bytes, TxKind, U256};
-use alloy_rlp::{BufMut, Decodable, Encodable};
+use alloy_rlp::{Buf, BufMut, Decodable, Encodable};
 use core::{
     fmt::Debug,
     hash::{Hash, Hasher},
@@ -167,29 +168,100 @@ impl AASigned {
         self.rlp_encode(out);
     }
 
+    /// EIP-2718 encodes the signed transaction with the whole RLP payload
+    /// wrapped in a [`compressed_envelope`](super::compressed_envelope), i.e.
+    /// the type byte followed by the envelope instead of the canonical list.
+    ///
+    /// This is a transport representation only, accepted solely by the
+    /// explicit `tempo_sendRawTransactionCompressed` RPC (T4+); it never
+    /// enters consensus. [`Self::eip2718_decode_compressed`] restores the
+    /// canonical transaction with the same hash and signatures as
+    /// [`Self::eip2718_encode`]. Returns `None` when the payload does not
+    /// compress, in which case callers should use the canonical encoding.
+    #[cfg(feature = "std")]
+    pub fn eip2718_encode_compressed(&self, dictionary_id: u8) -> Option<Vec<u8>> {
+        use super::compressed_envelope::maybe_compress_payload;
+
+        let mut payload = Vec::with_capacity(self.rlp_encoded_length());
+        self.rlp_encode(&mut payload);
+
+        let envelope = maybe_compress_payload(&payload, dictionary_id)?;
+        let mut out = Vec::with_capacity(1 + envelope.len());
+        out.push(TEMPO_TX_TYPE_ID);
+        out.extend_from_slice(&envelope);
+        Some(out)
+    }
+
+    /// Decodes a transaction from the compressed transport encoding produced
+    /// by [`Self::eip2718_encode_compressed`].
+    ///
+    /// This is the explicit opt-in counterpart used by the
+    /// `tempo_sendRawTransactionCompressed` RPC; the canonical decode paths
+    /// ([`Self::rlp_decode`] and [`Decodable2718`]) never interpret the
+    /// envelope, so historical bytes and arbitrary calldata are unaffected.
+    #[cfg(feature = "std")]
+    pub fn eip2718_decode_compressed(buf: &[u8]) -> alloy_rlp::Result<Self> {
+        use super::compressed_envelope::{decompress_payload, is_compressed_envelope};
+
+        let Some((&TEMPO_TX_TYPE_ID, envelope)) = buf.split_first() else {
+            return Err(alloy_rlp::Error::Custom("unexpected tx type"));
+        };
+        if !is_compressed_envelope(envelope) {
+            return Err(alloy_rlp::Error::Custom(
+                "not a compressed transaction envelope",
+            ));
+        }
+        let payload = decompress_payload(envelope)
+            .map_err(|_| alloy_rlp::Error::Custom("invalid compressed transaction envelope"))?;
+
+        let mut payload_buf = payload.as_slice();
+        let this = Self::rlp_decode(&mut payload_buf)?;
+        if !payload_buf.is_empty() {
+            return Err(alloy_rlp::Error::UnexpectedLength);
+        }
+        Ok(this)
+    }
+
     /// Decode the RLP fields (without type byte).
     pub fn rlp_decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
         let header = alloy_rlp::Header::decode(buf)?;
         if !header.list {
             return Err(alloy_rlp::Error::UnexpectedString);
         }
-        let remaining = buf.len();
 
-        if header.payload_length > remaining {
+        if header.payload_length > buf.len() {
             return Err(alloy_rlp::Error::InputTooShort);
         }
-
-        // Decode transaction fields directly from the buffer
-        let tx = TempoTransaction::rlp_decode_fields(buf)?;
+        let payload = &buf[..header.payload_length];
+
+        // The last item is the signature; everything before it belongs to the
+        // transaction. Transaction fields beyond the newest version this node
+        // understands are preserved verbatim (see
+        // [`VersionedRlp`](super::VersionedRlp)) so the signature we are about
+        // to verify still covers them.
+        const KNOWN_FIELDS: usize = <TempoTransaction as VersionedRlp>::KNOWN_FIELDS;
+        let total_fields = count_rlp_fields(payload)?;
+        let tx_fields = total_fields
+            .checked_sub(1)
+            .ok_or(alloy_rlp::Error::InputTooShort)?;
+        let (known, rest) = split_rlp_fields(payload, tx_fields.min(KNOWN_FIELDS))?;
+        let (unknown, mut sig_buf) =
+            split_rlp_fields(rest, tx_fields.saturating_sub(KNOWN_FIELDS))?;
+
+        // Decode transaction fields from the known region
+        let mut known_buf = known;
+        let mut tx = TempoTransaction::rlp_decode_fields(&mut known_buf)?;
+        if !known_buf.is_empty() {
+            return Err(alloy_rlp::Error::UnexpectedLength);
+        }
+        tx.unknown_fields = Bytes::copy_from_slice(unknown);
 
         // Decode signature bytes
-        let sig_bytes: Bytes = Decodable::decode(buf)?;
-
-        // Check that we consumed the expected amount
-        let consumed = remaining - buf.len();
-        if consumed != header.payload_length {
+        let sig_bytes: Bytes = Decodable::decode(&mut sig_buf)?;
+        if !sig_buf.is_empty() {
             return Err(alloy_rlp::Error::UnexpectedLength);
         }
+        buf.advance(header.payload_length);
 
         // Parse signature
         let signature = TempoSignature::from_bytes(&sig_bytes).map_err(alloy_rlp::Error::Custom)?;
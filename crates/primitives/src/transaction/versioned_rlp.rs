@@ -0,0 +1,464 @@
+//! Forward-compatible RLP decoding for versioned transaction payloads.
+//!
+//! Tempo structures evolve by appending fields to their RLP lists (see
+//! [`TokenLimit`](super::TokenLimit)'s V1/V2 handling and the trailing
+//! optionals on [`KeyAuthorization`](super::KeyAuthorization) and
+//! [`TempoTransaction`](super::TempoTransaction)). [`VersionedRlp`] captures
+//! that convention: a type declares how many fields its oldest and newest
+//! known versions carry, and [`Versioned`] dispatches on the field count of
+//! the incoming list — decoding the fields this node understands through the
+//! type's canonical [`Decodable`] impl and preserving any trailing fields
+//! added by a future version as raw bytes, so re-encoding stays byte-exact
+//! and signature hashes survive passing through an older node.
+
+use alloc::vec::Vec;
+use alloy_primitives::Bytes;
+use alloy_rlp::{Buf, BufMut, Decodable, Encodable, Header};
+
+/// RLP list types that version themselves by appending fields.
+///
+/// Implementors keep their existing canonical [`Encodable`]/[`Decodable`]
+/// impls (including any trailing-optional rules); the constants describe the
+/// range of field counts those impls understand so [`Versioned`] can split a
+/// newer encoding into known fields and unknown trailing bytes.
+pub trait VersionedRlp: Encodable + Decodable {
+    /// Minimum number of fields a valid encoding of the oldest supported
+    /// version carries.
+    const MIN_FIELDS: usize;
+
+    /// Number of fields in the newest version this node understands. Fields
+    /// beyond this count are preserved as unknown trailing bytes.
+    const KNOWN_FIELDS: usize;
+}
+
+/// Counts the top-level RLP items in a list payload.
+pub fn count_rlp_fields(mut payload: &[u8]) -> alloy_rlp::Result<usize> {
+    let mut count = 0;
+    while !payload.is_empty() {
+        let header = Header::decode(&mut payload)?;
+        if payload.len() < header.payload_length {
+            return Err(alloy_rlp::Error::InputTooShort);
+        }
+        payload = &payload[header.payload_length..];
+        count += 1;
+    }
+    Ok(count)
+}
+
+/// Splits a list payload after its first `fields` top-level items.
+pub fn split_rlp_fields(payload: &[u8], fields: usize) -> alloy_rlp::Result<(&[u8], &[u8])> {
+    let mut rest = payload;
+    for _ in 0..fields {
+        let header = Header::decode(&mut rest)?;
+        if rest.len() < header.payload_length {
+            return Err(alloy_rlp::Error::InputTooShort);
+        }
+        rest = &rest[header.payload_length..];
+    }
+    let consumed = payload.len() - rest.len();
+    Ok(payload.split_at(consumed))
+}
+
+/// A [`VersionedRlp`] value decoded tolerantly, preserving fields appended by
+/// versions newer than this node.
+///
+/// Decoding accepts any field count from `T::MIN_FIELDS` upward: the first
+/// `T::KNOWN_FIELDS` items go through `T`'s canonical decoder (which applies
+/// its usual trailing-optional rules when fewer are present) and the
+/// remainder is kept verbatim in [`unknown_fields`](Self::unknown_fields).
+/// Encoding re-appends those bytes, so a payload from a newer version
+/// round-trips byte-exactly.
+///
+/// Note that once a version encodes fields beyond a trailing optional, that
+/// optional must be encoded explicitly (its position determines its meaning).
+#[derive(Clone, Debug, PartialEq, Eq, Hash, derive_more::Deref)]
+pub struct Versioned<T> {
+    /// The fields this node understands.
+    #[deref]
+    pub inner: T,
+    /// Raw RLP bytes of trailing fields added by a future version.
+    pub unknown_fields: Bytes,
+}
+
+impl<T> Versioned<T> {
+    /// Returns the inner value, discarding any unknown trailing fields.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Returns `true` if the encoding carried fields this node does not
+    /// understand.
+    pub fn has_unknown_fields(&self) -> bool {
+        !self.unknown_fields.is_empty()
+    }
+}
+
+impl<T> From<T> for Versioned<T> {
+    fn from(inner: T) -> Self {
+        Self {
+            inner,
+            unknown_fields: Bytes::new(),
+        }
+    }
+}
+
+impl<T: Default> Default for Versioned<T> {
+    fn default() -> Self {
+        T::default().into()
+    }
+}
+
+/// JSON carries only the fields this node understands: serialization delegates
+/// to the inner value and deserialization yields no unknown fields. The raw
+/// trailing bytes only matter for consensus encodings, which go through RLP.
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for Versioned<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.inner.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de>> serde::Deserialize<'de> for Versioned<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        T::deserialize(deserializer).map(Into::into)
+    }
+}
+
+/// Arbitrary values carry no unknown fields so encode/decode round-trips stay
+/// canonical.
+#[cfg(any(test, feature = "arbitrary"))]
+impl<'a, T: arbitrary::Arbitrary<'a>> arbitrary::Arbitrary<'a> for Versioned<T> {
+    fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+        T::arbitrary(u).map(Into::into)
+    }
+}
+
+impl<T: VersionedRlp> Decodable for Versioned<T> {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let header = Header::decode(buf)?;
+        if !header.list {
+            return Err(alloy_rlp::Error::UnexpectedString);
+        }
+        if buf.len() < header.payload_length {
+            return Err(alloy_rlp::Error::InputTooShort);
+        }
+        let payload = &buf[..header.payload_length];
+
+        let fields = count_rlp_fields(payload)?;
+        if fields < T::MIN_FIELDS {
+            return Err(alloy_rlp::Error::Custom("too few fields for version"));
+        }
+        let (known, unknown) = split_rlp_fields(payload, fields.min(T::KNOWN_FIELDS))?;
+
+        // Re-wrap the known fields as a standalone list so the type's
+        // canonical decoder applies all of its usual rules.
+        let mut known_list = Vec::with_capacity(known.len() + 9);
+        Header {
+            list: true,
+            payload_length: known.len(),
+        }
+        .encode(&mut known_list);
+        known_list.extend_from_slice(known);
+
+        let mut known_slice = known_list.as_slice();
+        let inner = T::decode(&mut known_slice)?;
+        if !known_slice.is_empty() {
+            return Err(alloy_rlp::Error::UnexpectedLength);
+        }
+
+        let unknown_fields = Bytes::copy_from_slice(unknown);
+        buf.advance(header.payload_length);
+
+        Ok(Self {
+            inner,
+            unknown_fields,
+        })
+    }
+}
+
+impl<T: VersionedRlp> Encodable for Versioned<T> {
+    fn encode(&self, out: &mut dyn BufMut) {
+        // Encode the inner value, then splice the unknown trailing bytes into
+        // its list payload.
+        let mut encoded = Vec::with_capacity(self.inner.length());
+        self.inner.encode(&mut encoded);
+
+        let mut slice = encoded.as_slice();
+        let header = Header::decode(&mut slice).expect("inner encoding is valid RLP");
+
+        Header {
+            list: true,
+            payload_length: header.payload_length + self.unknown_fields.len(),
+        }
+        .encode(out);
+        out.put_slice(slice);
+        out.put_slice(&self.unknown_fields);
+    }
+
+    fn length(&self) -> usize {
+        let mut encoded = Vec::with_capacity(self.inner.length());
+        self.inner.encode(&mut encoded);
+
+        let mut slice = encoded.as_slice();
+        let header = Header::decode(&mut slice).expect("inner encoding is valid RLP");
+
+        let payload_length = header.payload_length + self.unknown_fields.len();
+        Header {
+            list: true,
+            payload_length,
+        }
+        .length_with_payload()
+    }
+}
+
+/// RLP: `[token, limit, period?]` — V1 carried `[token, limit]`.
+impl VersionedRlp for super::TokenLimit {
+    const MIN_FIELDS: usize = 2;
+    const KNOWN_FIELDS: usize = 3;
+}
+
+/// RLP: `[target, selector_rules]` — both fields required since V1.
+impl VersionedRlp for super::CallScope {
+    const MIN_FIELDS: usize = 2;
+    const KNOWN_FIELDS: usize = 2;
+}
+
+/// RLP: `[chain_id, key_type, key_id, expiry?, limits?, allowed_calls?, max_uses?]`.
+impl VersionedRlp for super::KeyAuthorization {
+    const MIN_FIELDS: usize = 3;
+    const KNOWN_FIELDS: usize = 7;
+}
+
+/// The TempoTransaction envelope body: 13 required fields followed by the
+/// trailing `key_authorization?` and `fee_token_preferences?`.
+impl VersionedRlp for super::TempoTransaction {
+    const MIN_FIELDS: usize = 13;
+    const KNOWN_FIELDS: usize = 15;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transaction::{Call, CallScope, KeyAuthorization, SignatureType, TempoTransaction};
+    use alloy_primitives::{Address, TxKind, U256};
+
+    /// Appends `extra` as additional raw fields to an encoded RLP list.
+    fn append_fields(encoded: &[u8], extra: &[u8]) -> Vec<u8> {
+        let mut slice = encoded;
+        let header = Header::decode(&mut slice).expect("valid list");
+
+        let mut out = Vec::new();
+        Header {
+            list: true,
+            payload_length: header.payload_length + extra.len(),
+        }
+        .encode(&mut out);
+        out.extend_from_slice(slice);
+        out.extend_from_slice(extra);
+        out
+    }
+
+    fn sample_auth() -> KeyAuthorization {
+        KeyAuthorization::unrestricted(1, SignatureType::Secp256k1, Address::repeat_byte(0x11))
+            .with_expiry(1000)
+    }
+
+    #[test]
+    fn test_count_rlp_fields() {
+        let auth = sample_auth();
+        let mut encoded = Vec::new();
+        auth.encode(&mut encoded);
+
+        let mut slice = encoded.as_slice();
+        let header = Header::decode(&mut slice).expect("valid list");
+        // chain_id, key_type, key_id, expiry
+        assert_eq!(count_rlp_fields(&slice[..header.payload_length]), Ok(4));
+    }
+
+    #[test]
+    fn test_current_encoding_roundtrips_without_unknown_fields() {
+        let auth = sample_auth();
+        let mut encoded = Vec::new();
+        auth.encode(&mut encoded);
+
+        let versioned = <Versioned<KeyAuthorization> as Decodable>::decode(&mut encoded.as_slice())
+            .expect("decode versioned auth");
+        assert_eq!(versioned.inner, auth);
+        assert!(!versioned.has_unknown_fields());
+
+        let mut reencoded = Vec::new();
+        versioned.encode(&mut reencoded);
+        assert_eq!(reencoded, encoded);
+        assert_eq!(versioned.length(), encoded.len());
+    }
+
+    #[test]
+    fn test_unknown_trailing_fields_are_preserved_byte_exactly() {
+        let auth = sample_auth();
+        let mut encoded = Vec::new();
+        auth.encode(&mut encoded);
+
+        // A hypothetical V-next appends two fields. Trailing optionals before
+        // them must be encoded explicitly, so pad out to KNOWN_FIELDS first.
+        let mut extra = Vec::new();
+        extra.extend_from_slice(&[alloy_rlp::EMPTY_STRING_CODE; 3]); // limits, allowed_calls, max_uses
+        42u64.encode(&mut extra);
+        Address::repeat_byte(0x22).encode(&mut extra);
+        let future = append_fields(&encoded, &extra);
+
+        let versioned = <Versioned<KeyAuthorization> as Decodable>::decode(&mut future.as_slice())
+            .expect("decode future-version auth");
+        assert_eq!(versioned.inner, auth);
+        assert!(versioned.has_unknown_fields());
+
+        let mut reencoded = Vec::new();
+        versioned.encode(&mut reencoded);
+        assert_eq!(reencoded, future);
+        assert_eq!(versioned.length(), future.len());
+    }
+
+    #[test]
+    fn test_too_few_fields_are_rejected() {
+        // A single-field list is below CallScope's V1 field count.
+        let target = Address::repeat_byte(0x11);
+        let mut encoded = Vec::new();
+        Header {
+            list: true,
+            payload_length: target.length(),
+        }
+        .encode(&mut encoded);
+        target.encode(&mut encoded);
+
+        let err = <Versioned<CallScope> as Decodable>::decode(&mut encoded.as_slice())
+            .expect_err("one field must be rejected");
+        assert_eq!(err, alloy_rlp::Error::Custom("too few fields for version"));
+    }
+
+    #[test]
+    fn test_call_scope_with_future_field_roundtrips() {
+        let scope = CallScope {
+            target: Address::repeat_byte(0x11),
+            selector_rules: vec![],
+        };
+        let mut encoded = Vec::new();
+        scope.encode(&mut encoded);
+
+        let mut extra = Vec::new();
+        U256::from(7).encode(&mut extra);
+        let future = append_fields(&encoded, &extra);
+
+        let versioned = <Versioned<CallScope> as Decodable>::decode(&mut future.as_slice())
+            .expect("decode future-version scope");
+        assert_eq!(versioned.inner, scope);
+        assert_eq!(versioned.unknown_fields.as_ref(), extra.as_slice());
+
+        let mut reencoded = Vec::new();
+        versioned.encode(&mut reencoded);
+        assert_eq!(reencoded, future);
+    }
+
+    #[test]
+    fn test_tempo_transaction_with_future_field_roundtrips() {
+        // Both trailing optionals must be present so the future field lands
+        // beyond KNOWN_FIELDS rather than in an optional's position.
+        let tx = TempoTransaction {
+            chain_id: 1,
+            gas_limit: 100_000,
+            calls: vec![Call {
+                to: TxKind::Call(Address::repeat_byte(0x11)),
+                value: U256::ZERO,
+                input: Default::default(),
+            }],
+            key_authorization: Some(
+                sample_auth().into_signed(crate::transaction::PrimitiveSignature::default()),
+            ),
+            fee_token_preferences: vec![Address::repeat_byte(0x22)],
+            ..Default::default()
+        };
+        let mut encoded = Vec::new();
+        tx.encode(&mut encoded);
+
+        let mut extra = Vec::new();
+        1u64.encode(&mut extra);
+        let future = append_fields(&encoded, &extra);
+
+        let versioned = <Versioned<TempoTransaction> as Decodable>::decode(&mut future.as_slice())
+            .expect("decode future-version transaction");
+        assert_eq!(versioned.inner, tx);
+
+        let mut reencoded = Vec::new();
+        versioned.encode(&mut reencoded);
+        assert_eq!(reencoded, future);
+    }
+
+    #[test]
+    fn test_tempo_transaction_decode_preserves_future_fields() {
+        // A base transaction omitting both trailing optionals: the future
+        // encoding carries explicit placeholders in their positions.
+        let tx = TempoTransaction {
+            chain_id: 1,
+            gas_limit: 100_000,
+            calls: vec![Call {
+                to: TxKind::Call(Address::repeat_byte(0x11)),
+                value: U256::ZERO,
+                input: Default::default(),
+            }],
+            ..Default::default()
+        };
+        let mut encoded = Vec::new();
+        tx.encode(&mut encoded);
+
+        let mut extra = Vec::new();
+        // key_authorization and fee_token_preferences placeholders
+        extra.extend_from_slice(&[alloy_rlp::EMPTY_STRING_CODE; 2]);
+        42u64.encode(&mut extra);
+        let future = append_fields(&encoded, &extra);
+
+        let decoded =
+            TempoTransaction::decode(&mut future.as_slice()).expect("decode future-version tx");
+        assert_eq!(decoded.key_authorization, None);
+        assert!(decoded.fee_token_preferences.is_empty());
+        assert!(!decoded.unknown_fields.is_empty());
+
+        // Re-encoding keeps the placeholders so the future fields stay in
+        // their positions, and the signature hash commits to them.
+        let mut reencoded = Vec::new();
+        decoded.encode(&mut reencoded);
+        assert_eq!(reencoded, future);
+        assert_ne!(decoded.signature_hash(), tx.signature_hash());
+    }
+
+    #[test]
+    fn test_signed_envelope_roundtrips_future_fields() {
+        use crate::transaction::{AASigned, PrimitiveSignature, TempoSignature};
+
+        let mut extra = Vec::new();
+        42u64.encode(&mut extra);
+        let tx = TempoTransaction {
+            chain_id: 1,
+            gas_limit: 100_000,
+            calls: vec![Call {
+                to: TxKind::Call(Address::repeat_byte(0x11)),
+                value: U256::ZERO,
+                input: Default::default(),
+            }],
+            unknown_fields: extra.into(),
+            ..Default::default()
+        };
+        let signature = TempoSignature::Primitive(PrimitiveSignature::Secp256k1(
+            alloy_primitives::Signature::test_signature(),
+        ));
+        let signed = AASigned::new_unhashed(tx, signature);
+
+        let mut encoded = Vec::new();
+        signed.rlp_encode(&mut encoded);
+        let decoded =
+            AASigned::rlp_decode(&mut encoded.as_slice()).expect("decode signed envelope");
+        assert_eq!(decoded.tx(), signed.tx());
+
+        let mut reencoded = Vec::new();
+        decoded.rlp_encode(&mut reencoded);
+        assert_eq!(reencoded, encoded);
+    }
+}
@@ -0,0 +1,69 @@
+//! Per-transaction tracing spans.
+//!
+//! A transaction crosses several tasks on its way through the node — pool
+//! admission, subblock inclusion, consensus, execution, receipt emission —
+//! that share no parent span, so stage spans cannot form a hierarchy. Instead
+//! every stage creates its span through [`stage_span`], which fixes the span
+//! name and field layout (`stage`, `tx_hash`) so trace backends can join the
+//! stages into one per-transaction timeline keyed on the hash. With OTLP trace
+//! export enabled, stage durations attribute in-node latency to the pipeline
+//! stage that produced it.
+
+use alloy_primitives::B256;
+
+/// A pipeline stage a transaction passes through.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TxStage {
+    /// Validation and insertion into the transaction pool.
+    PoolAdmission,
+    /// Inclusion in a validator subblock.
+    SubblockInclusion,
+    /// Consensus over the block carrying the transaction.
+    Consensus,
+    /// EVM execution during payload building or block import.
+    Execution,
+    /// Receipt becoming available to consumers.
+    Receipt,
+}
+
+impl TxStage {
+    /// The stable `stage` field value recorded on the span.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::PoolAdmission => "pool_admission",
+            Self::SubblockInclusion => "subblock_inclusion",
+            Self::Consensus => "consensus",
+            Self::Execution => "execution",
+            Self::Receipt => "receipt",
+        }
+    }
+}
+
+/// Creates the span for one pipeline stage of one transaction.
+///
+/// All stages use the same span name and target so a single filter directive
+/// (`tx_stage=debug`) enables the whole per-transaction timeline.
+pub fn stage_span(stage: TxStage, tx_hash: B256) -> tracing::Span {
+    tracing::debug_span!(
+        target: "tx_stage",
+        "tx_stage",
+        stage = stage.as_str(),
+        %tx_hash,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stage_names_are_stable() {
+        // Dashboards and trace queries key on these values; renaming one is a
+        // breaking telemetry change.
+        assert_eq!(TxStage::PoolAdmission.as_str(), "pool_admission");
+        assert_eq!(TxStage::SubblockInclusion.as_str(), "subblock_inclusion");
+        assert_eq!(TxStage::Consensus.as_str(), "consensus");
+        assert_eq!(TxStage::Execution.as_str(), "execution");
+        assert_eq!(TxStage::Receipt.as_str(), "receipt");
+    }
+}
@@ -1,11 +1,15 @@
 use crate::TempoTxEnv;
-use alloy_consensus::transaction::{Either, Recovered};
+use alloy_consensus::{
+    Transaction,
+    transaction::{Either, Recovered},
+};
 use alloy_primitives::{Address, Bytes, LogData, TxKind, U256};
 use alloy_sol_types::SolCall;
 use core::marker::PhantomData;
 use revm::{
     Database,
     context::JournalTr,
+    precompile::PrecompileResult,
     state::{AccountInfo, Bytecode},
 };
 use tempo_chainspec::hardfork::TempoHardfork;
@@ -15,7 +19,7 @@ use tempo_contracts::precompiles::{
 use tempo_precompiles::{
     TIP_FEE_MANAGER_ADDRESS,
     error::{Result as TempoResult, TempoPrecompileError},
-    storage::{Handler, PrecompileStorageProvider, StorageCtx},
+    storage::{Handler, PrecompileStorageProvider, SimulationOutcome, StorageCtx},
     tip_fee_manager::TipFeeManager,
     tip20::{ITIP20, TIP20Token},
     tip403_registry::{AuthRole, TIP403Registry},
@@ -41,6 +45,13 @@ pub trait TempoTx {
     /// Returns the transaction's `feeToken` field, if configured.
     fn fee_token(&self) -> Option<Address>;
 
+    /// Returns the transaction's ordered fee token preference list (empty if unset).
+    fn fee_token_preferences(&self) -> &[Address];
+
+    /// Returns the maximum gas cost the fee payer can be charged
+    /// (`gas_limit * max_fee_per_gas`).
+    fn max_gas_cost(&self) -> U256;
+
     /// Returns true if this is an AA transaction.
     fn is_aa(&self) -> bool;
 
@@ -56,6 +67,16 @@ impl TempoTx for TempoTxEnv {
         self.fee_token
     }
 
+    fn fee_token_preferences(&self) -> &[Address] {
+        self.tempo_tx_env
+            .as_ref()
+            .map_or(&[], |aa| aa.fee_token_preferences.as_slice())
+    }
+
+    fn max_gas_cost(&self) -> U256 {
+        U256::from(self.inner.gas_limit).saturating_mul(U256::from(self.inner.gas_price))
+    }
+
     fn is_aa(&self) -> bool {
         self.tempo_tx_env.is_some()
     }
@@ -78,6 +99,15 @@ impl TempoTx for Recovered<TempoTxEnvelope> {
         self.inner().fee_token()
     }
 
+    fn fee_token_preferences(&self) -> &[Address] {
+        self.inner().fee_token_preferences()
+    }
+
+    fn max_gas_cost(&self) -> U256 {
+        U256::from(self.inner().gas_limit())
+            .saturating_mul(U256::from(self.inner().max_fee_per_gas()))
+    }
+
     fn is_aa(&self) -> bool {
         self.inner().is_aa()
     }
@@ -114,6 +144,32 @@ pub trait TempoStateAccess<M = ()> {
         StorageCtx::enter(&mut ReadOnlyStorageProvider::new(self, spec), f)
     }
 
+    /// Simulates a state-mutating precompile call against a copy-on-write overlay over
+    /// this state, returning the dispatch result together with the events and state diff
+    /// the call would have produced. Nothing is written back, so the same state can serve
+    /// many simulations.
+    ///
+    /// `env` supplies the block-level context precompiles may read (timestamps for key
+    /// expiries, the chain id, ...). Returns `None` when `address` does not host an
+    /// active Tempo precompile under `spec`.
+    fn simulate_precompile_call(
+        &mut self,
+        spec: TempoHardfork,
+        env: SimulationBlockEnv,
+        address: Address,
+        calldata: &[u8],
+        msg_sender: Address,
+    ) -> Option<(PrecompileResult, SimulationOutcome)>
+    where
+        Self: Sized,
+    {
+        let mut base = SimulationBaseProvider {
+            inner: ReadOnlyStorageProvider::new(self, spec),
+            env,
+        };
+        tempo_precompiles::simulate_precompile_call(&mut base, address, calldata, msg_sender)
+    }
+
     /// Resolves user-level or transaction-level fee token preference.
     fn get_fee_token(
         &mut self,
@@ -129,6 +185,23 @@ pub trait TempoStateAccess<M = ()> {
             return Ok(fee_token);
         }
 
+        // If the tx carries an ordered fee token preference list, use the first
+        // valid token whose balance covers the max gas cost. If none qualifies,
+        // fall back to the first entry so the regular balance check downstream
+        // reports the failure against a deterministic token.
+        let preferences = tx.fee_token_preferences();
+        if !preferences.is_empty() {
+            let max_cost = tx.max_gas_cost();
+            for &token in preferences {
+                if self.is_valid_fee_token(spec, token)?
+                    && self.get_token_balance(token, fee_payer, spec)? >= max_cost
+                {
+                    return Ok(token);
+                }
+            }
+            return Ok(preferences[0]);
+        }
+
         // If the fee payer is also the msg.sender and the transaction is calling FeeManager to set a
         // new preference, the newly set preference should be used immediately instead of the
         // previously stored one
@@ -447,6 +520,127 @@ where
     }
 }
 
+/// Block-level context for [`TempoStateAccess::simulate_precompile_call`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SimulationBlockEnv {
+    /// Chain id reported to the simulated precompile.
+    pub chain_id: u64,
+    /// Timestamp of the block the simulation runs against.
+    pub timestamp: u64,
+    /// Number of the block the simulation runs against.
+    pub number: u64,
+    /// Beneficiary of the block the simulation runs against.
+    pub beneficiary: Address,
+}
+
+/// Base provider for precompile simulation: read-only state plus a block env.
+///
+/// The copy-on-write overlay entered by [`tempo_precompiles::simulate_precompile_call`]
+/// handles every write, event, gas unit, and checkpoint itself, so only reads and
+/// block-env getters ever reach this base.
+struct SimulationBaseProvider<'a, S, M = ()> {
+    inner: ReadOnlyStorageProvider<'a, S, M>,
+    env: SimulationBlockEnv,
+}
+
+impl<S, M> PrecompileStorageProvider for SimulationBaseProvider<'_, S, M>
+where
+    S: TempoStateAccess<M>,
+{
+    fn spec(&self) -> TempoHardfork {
+        self.inner.spec()
+    }
+
+    fn is_static(&self) -> bool {
+        // Simulation exists precisely to run mutating entrypoints.
+        false
+    }
+
+    fn sload(&mut self, address: Address, key: U256) -> TempoResult<U256> {
+        self.inner.sload(address, key)
+    }
+
+    fn with_account_info(
+        &mut self,
+        address: Address,
+        f: &mut dyn FnMut(&AccountInfo),
+    ) -> TempoResult<()> {
+        self.inner.with_account_info(address, f)
+    }
+
+    fn chain_id(&self) -> u64 {
+        self.env.chain_id
+    }
+
+    fn timestamp(&self) -> U256 {
+        U256::from(self.env.timestamp)
+    }
+
+    fn block_number(&self) -> u64 {
+        self.env.number
+    }
+
+    fn beneficiary(&self) -> Address {
+        self.env.beneficiary
+    }
+
+    fn tload(&mut self, _: Address, _: U256) -> TempoResult<U256> {
+        // Transient storage starts empty for every transaction and the overlay shadows
+        // every `tstore`, so a base lookup is always a miss.
+        Ok(U256::ZERO)
+    }
+
+    fn gas_used(&self) -> u64 {
+        0
+    }
+
+    fn gas_refunded(&self) -> i64 {
+        0
+    }
+
+    fn reservoir(&self) -> u64 {
+        0
+    }
+
+    // Writes, gas metering, and checkpoints never reach the base: the overlay handles
+    // them all.
+    fn sstore(&mut self, _: Address, _: U256, _: U256) -> TempoResult<()> {
+        unreachable!("'sstore' is handled by the simulation overlay")
+    }
+
+    fn set_code(&mut self, _: Address, _: Bytecode) -> TempoResult<()> {
+        unreachable!("'set_code' is handled by the simulation overlay")
+    }
+
+    fn emit_event(&mut self, _: Address, _: LogData) -> TempoResult<()> {
+        unreachable!("'emit_event' is handled by the simulation overlay")
+    }
+
+    fn tstore(&mut self, _: Address, _: U256, _: U256) -> TempoResult<()> {
+        unreachable!("'tstore' is handled by the simulation overlay")
+    }
+
+    fn deduct_gas(&mut self, _: u64) -> TempoResult<()> {
+        unreachable!("'deduct_gas' is handled by the simulation overlay")
+    }
+
+    fn refund_gas(&mut self, _: i64) {
+        unreachable!("'refund_gas' is handled by the simulation overlay")
+    }
+
+    fn checkpoint(&mut self) -> revm::context::journaled_state::JournalCheckpoint {
+        unreachable!("'checkpoint' is handled by the simulation overlay")
+    }
+
+    fn checkpoint_commit(&mut self, _: revm::context::journaled_state::JournalCheckpoint) {
+        unreachable!("'checkpoint_commit' is handled by the simulation overlay")
+    }
+
+    fn checkpoint_revert(&mut self, _: revm::context::journaled_state::JournalCheckpoint) {
+        unreachable!("'checkpoint_revert' is handled by the simulation overlay")
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
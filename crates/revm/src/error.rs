@@ -207,6 +207,14 @@ pub enum TempoInvalidTransaction {
     #[error("V2 keychain signature (type 0x04) is not valid before T1C activation")]
     V2KeychainBeforeActivation,
 
+    /// Multisig signature used before T4 activation.
+    ///
+    /// Multisig signatures (type 0x05) are only valid after the T4 hardfork activates.
+    /// Rejecting them before activation prevents chain splits between upgraded and
+    /// non-upgraded nodes.
+    #[error("multisig signature (type 0x05) is not valid before T4 activation")]
+    MultisigBeforeActivation,
+
     /// Keychain operations are not supported in subblock transactions.
     #[error("keychain operations are not supported in subblock transactions")]
     KeychainOpInSubblockTransaction,
@@ -299,7 +307,8 @@ impl TempoInvalidTransaction {
             | Self::KeychainValidationFailed { .. }
             | Self::CollectFeePreTx(_)
             | Self::NonceManagerError(_)
-            | Self::V2KeychainBeforeActivation => false,
+            | Self::V2KeychainBeforeActivation
+            | Self::MultisigBeforeActivation => false,
         }
     }
 }
@@ -3,14 +3,21 @@ use alloy_evm::{Database, precompiles::PrecompilesMap};
 use alloy_primitives::{Address, U256};
 use revm::{
     Context, Inspector,
-    context::{CfgEnv, ContextError, Evm, FrameStack},
+    context::{CfgEnv, ContextError, ContextTr, Evm, FrameStack, JournalTr},
     handler::{
         EthFrame, EvmTr, FrameInitOrResult, FrameTr, ItemOrResult, instructions::EthInstructions,
     },
     inspector::InspectorEvmTr,
     interpreter::interpreter::EthInterpreter,
+    state::EvmState,
 };
+use std::collections::BTreeMap;
 use tempo_chainspec::hardfork::TempoHardfork;
+use tempo_precompiles::{
+    error::TempoPrecompileError,
+    storage::StorageCtx,
+    tip_fee_manager::{FeeSettlement, TipFeeManager},
+};
 
 /// The Tempo EVM context type.
 pub type TempoContext<DB> = Context<TempoBlockEnv, TempoTxEnv, CfgEnv<TempoHardfork>, DB>;
@@ -50,6 +57,46 @@ pub struct TempoEvm<DB: Database, I> {
     /// The transaction pool sets this because it performs its own liquidity
     /// validation against a cached view of the AMM state.
     pub skip_liquidity_check: bool,
+    /// Block-scoped ledger of per-payer fee refunds accumulated when fee settlement
+    /// netting is active (T4+).
+    ///
+    /// The block executor enables this and drains it via [`Self::take_fee_settlement`]
+    /// at the end of the block. `None` means refunds are credited per transaction.
+    /// Unlike the other per-transaction fields, this survives [`Self::clear`].
+    pub fee_netting: Option<FeeNettingLedger>,
+}
+
+/// Block-scoped ledger of fee refunds accumulated for end-of-block net settlement.
+///
+/// When fee settlement netting is active, the pre-tx escrow is still taken per
+/// transaction (so a payer cannot spend reserved fees mid-block), but the fee manager
+/// leaves the unused part of each escrow in place and the handler records the refund
+/// owed here instead. The block executor drains the ledger at the end of the block and
+/// credits the accumulated refunds with a single balance update per (payer, token) pair.
+#[derive(Debug, Default)]
+pub struct FeeNettingLedger {
+    /// Net fee refund per (payer, token) pair, ordered for deterministic settlement.
+    refunds: BTreeMap<(Address, Address), U256>,
+}
+
+impl FeeNettingLedger {
+    /// Records `amount` of refund owed to `payer` in `token`.
+    pub fn record(&mut self, payer: Address, token: Address, amount: U256) {
+        let refund = self.refunds.entry((payer, token)).or_default();
+        *refund = refund.saturating_add(amount);
+    }
+
+    /// Consumes the ledger and returns the recorded refunds in deterministic order.
+    pub fn into_settlements(self) -> Vec<FeeSettlement> {
+        self.refunds
+            .into_iter()
+            .map(|((payer, token), amount)| FeeSettlement {
+                payer,
+                token,
+                amount,
+            })
+            .collect()
+    }
 }
 
 impl<DB: Database, I> TempoEvm<DB, I> {
@@ -86,6 +133,7 @@ impl<DB: Database, I> TempoEvm<DB, I> {
             key_expiry: None,
             skip_valid_after_check: false,
             skip_liquidity_check: false,
+            fee_netting: None,
         }
     }
 }
@@ -112,6 +160,28 @@ impl<DB: Database, I> TempoEvm<DB, I> {
         self.fee_token = None;
         self.key_expiry = None;
     }
+
+    /// Drains the fee netting ledger and applies the end-of-block net refund settlement
+    /// to the journal.
+    ///
+    /// Returns the resulting state changes for the block executor to commit, or `None`
+    /// if netting is not active or no refunds were recorded.
+    pub fn take_fee_settlement(&mut self) -> Result<Option<EvmState>, TempoPrecompileError> {
+        let Some(ledger) = self.fee_netting.take() else {
+            return Ok(None);
+        };
+
+        let settlements = ledger.into_settlements();
+        if settlements.is_empty() {
+            return Ok(None);
+        }
+
+        StorageCtx::enter_ctx(&mut self.inner.ctx, || {
+            TipFeeManager::new().settle_block_fees(&settlements)
+        })?;
+
+        Ok(Some(self.inner.ctx.journal_mut().finalize()))
+    }
 }
 
 impl<DB, I> EvmTr for TempoEvm<DB, I>
@@ -633,6 +703,7 @@ mod tests {
             TempoTransaction {
                 chain_id: 1,
                 fee_token: None,
+                fee_token_preferences: vec![],
                 max_priority_fee_per_gas: self.max_priority_fee_per_gas,
                 max_fee_per_gas: self.max_fee_per_gas,
                 gas_limit: self.gas_limit,
@@ -645,6 +716,7 @@ mod tests {
                 valid_after: self.valid_after.and_then(core::num::NonZeroU64::new),
                 key_authorization: self.key_authorization,
                 tempo_authorization_list: self.authorization_list,
+                unknown_fields: Default::default(),
             }
         }
     }
@@ -135,6 +135,15 @@ fn tempo_signature_verification_gas(signature: &TempoSignature) -> u64 {
             // Keychain = inner signature + key validation overhead (SLOAD + processing)
             primitive_signature_verification_gas(&keychain_sig.signature) + KEYCHAIN_VALIDATION_GAS
         }
+        TempoSignature::Multisig(multisig_sig) => {
+            // Every participant signature is verified independently against the
+            // same hash; the weight/threshold bookkeeping is pure computation.
+            multisig_sig
+                .signatures
+                .iter()
+                .map(primitive_signature_verification_gas)
+                .sum()
+        }
     }
 }
 
@@ -437,17 +446,25 @@ where
             ));
         };
 
-        // It's fine to set reservoir to 0 because this won't create any state.
+        // It's fine to set reservoir to 0: pre-T4 this only reads scopes, and the T4+
+        // batch deduction is a plain journal write.
         let (validation, gas_used) =
             StorageCtx::enter_ctx_with_gas_limit(evm.ctx_mut(), *remaining_gas, 0, || {
-                let keychain = AccountKeychain::default();
-                for call in calls {
-                    keychain.validate_call_scope_for_transaction(
-                        user_address,
-                        access_key_addr,
-                        &call.to,
-                        call.input.as_ref(),
-                    )?;
+                let mut keychain = AccountKeychain::default();
+                if spec.is_t4() {
+                    // T4+: validate every call scope and deduct the batch's aggregate
+                    // TIP-20 spend in one pass. The recorded credit makes the
+                    // execution-time TIP-20 hooks skip the already-charged amounts.
+                    keychain.authorize_batch(user_address, access_key_addr, calls)?;
+                } else {
+                    for call in calls {
+                        keychain.validate_call_scope_for_transaction(
+                            user_address,
+                            access_key_addr,
+                            &call.to,
+                            call.input.as_ref(),
+                        )?;
+                    }
                 }
                 Ok::<(), TempoPrecompileError>(())
             });
@@ -907,7 +924,9 @@ where
             return Err(TempoInvalidTransaction::InvalidFeeToken(fee_token).into());
         }
 
-        // Load the fee payer balance
+        // Load the fee payer balance. When fee settlement netting is active, the escrows
+        // of earlier transactions in the block have already debited it, and their refunds
+        // are intentionally not counted until the end of the block.
         let account_balance = get_token_balance(journal, fee_token, fee_payer)?;
 
         // Load caller's account
@@ -1130,18 +1149,40 @@ where
             // Validate that the KeyAuthorization is signed by the root account
             let root_account = &tx.caller;
 
-            // Recover the signer of the KeyAuthorization
-            let auth_signer = key_auth
-                .recover_signer()
-                .map_err(|_| TempoInvalidTransaction::KeyAuthorizationSignatureRecoveryFailed)?;
-
-            // Verify the KeyAuthorization is signed by the root account
-            if auth_signer != *root_account {
-                return Err(TempoInvalidTransaction::KeyAuthorizationNotSignedByRoot {
-                    expected: *root_account,
-                    actual: auth_signer,
+            if let Some(multisig_sig) = tempo_tx_env.signature.as_multisig() {
+                // A multisig root cannot produce a single primitive signature that
+                // recovers to its counterfactual address, so the root's approval is the
+                // outer threshold signature itself: it is verified in recover_signer()
+                // and its signature hash commits to the key authorization. What remains
+                // is the keychain-side structural check that the carried configuration
+                // actually derives the root account.
+                StorageCtx::enter_precompile(
+                    journal,
+                    block,
+                    cfg,
+                    tx,
+                    |keychain: AccountKeychain| {
+                        keychain
+                            .validate_multisig_root(*root_account, &multisig_sig.authorization)
+                            .map_err(|e| TempoInvalidTransaction::KeychainValidationFailed {
+                                reason: format!("{e:?}"),
+                            })
+                    },
+                )?;
+            } else {
+                // Recover the signer of the KeyAuthorization
+                let auth_signer = key_auth.recover_signer().map_err(|_| {
+                    TempoInvalidTransaction::KeyAuthorizationSignatureRecoveryFailed
+                })?;
+
+                // Verify the KeyAuthorization is signed by the root account
+                if auth_signer != *root_account {
+                    return Err(TempoInvalidTransaction::KeyAuthorizationNotSignedByRoot {
+                        expected: *root_account,
+                        actual: auth_signer,
+                    }
+                    .into());
                 }
-                .into());
             }
 
             // Validate KeyAuthorization chain_id.
@@ -1169,6 +1210,14 @@ where
                 }
             }
 
+            // Transaction use limits are a T4 extension on top of TIP-1011.
+            if !spec.is_t4() && key_auth.has_max_uses() {
+                return Err(TempoInvalidTransaction::KeychainValidationFailed {
+                    reason: "transaction use limits are not active before T4".to_string(),
+                }
+                .into());
+            }
+
             let keychain_checkpoint = if spec.is_t1() {
                 Some(journal.checkpoint())
             } else {
@@ -1260,6 +1309,9 @@ where
                 let allow_any_calls = key_auth.allowed_calls.is_none();
                 let precompile_allowed_calls = translate_allowed_calls_for_precompile(key_auth);
 
+                // None means unlimited uses, encoded as 0 in the precompile ABI.
+                let max_uses = key_auth.max_uses.map_or(0, |max_uses| max_uses.get());
+
                 // Create the authorize key call
                 let authorize_call = authorizeKeyCall {
                     keyId: access_key_addr,
@@ -1270,6 +1322,7 @@ where
                         limits: precompile_limits,
                         allowAnyCalls: allow_any_calls,
                         allowedCalls: precompile_allowed_calls,
+                        maxUses: max_uses,
                     },
                 };
 
@@ -1404,6 +1457,17 @@ where
                         Some(key.expiry)
                     };
 
+                    // T3+: atomically consume one use for use-limited keys. This runs in the
+                    // same phase as the nonce increment so a bounded session key can never
+                    // execute more transactions than it was authorized for.
+                    if spec.is_t3() {
+                        keychain
+                            .consume_key_use(keychain_sig.user_address, access_key_addr)
+                            .map_err(|e| TempoInvalidTransaction::KeychainValidationFailed {
+                                reason: format!("{e:?}"),
+                            })?;
+                    }
+
                     // Set the transaction key in the keychain precompile
                     // This marks that the current transaction is using an access key
                     // The TIP20 precompile will read this during execution to enforce spending limits
@@ -1428,6 +1492,9 @@ where
 
         let skip_liquidity_check = evm.skip_liquidity_check;
         let result = StorageCtx::enter_evm(journal, &block, cfg, tx, || {
+            // The escrow is taken per transaction even when fee settlement netting is
+            // active, so the payer cannot spend the reserved fee mid-block; netting only
+            // defers the refund of the unused part.
             TipFeeManager::new().collect_fee_pre_tx(
                 fee_payer,
                 fee_token,
@@ -1506,8 +1573,9 @@ where
         // Create storage provider and fee manager
         let (journal, block, tx) = (&mut context.journaled_state, &context.block, &context.tx);
         let beneficiary = context.block.beneficiary();
+        let netting_active = evm.fee_netting.is_some();
 
-        StorageCtx::enter_evm(&mut *journal, block, &context.cfg, tx, || {
+        let net_refund = StorageCtx::enter_evm(&mut *journal, block, &context.cfg, tx, || {
             let mut fee_manager = TipFeeManager::new();
 
             if !actual_spending.is_zero() || !refund_amount.is_zero() {
@@ -1515,6 +1583,22 @@ where
                 let fee_token = evm
                     .fee_token
                     .expect("set in `validate_against_state_and_deduct_caller`");
+
+                if netting_active {
+                    // Netting active: leave the unused escrow with the fee manager and
+                    // record the refund owed in the block executor's ledger instead of
+                    // crediting it per transaction.
+                    return fee_manager
+                        .collect_fee_post_tx_netted(
+                            fee_payer,
+                            actual_spending,
+                            refund_amount,
+                            fee_token,
+                            beneficiary,
+                        )
+                        .map_err(|e| EVMError::Custom(format!("{e:?}")));
+                }
+
                 // Call collectFeePostTx (handles both refund and fee queuing)
                 fee_manager
                     .collect_fee_post_tx(
@@ -1527,8 +1611,24 @@ where
                     .map_err(|e| EVMError::Custom(format!("{e:?}")))?;
             }
 
-            Ok(())
-        })
+            Ok(U256::ZERO)
+        })?;
+
+        if !net_refund.is_zero()
+            && let Some(ledger) = evm.fee_netting.as_mut()
+        {
+            let fee_payer = evm
+                .ctx
+                .tx
+                .fee_payer()
+                .expect("pre-validated in `validate_env`");
+            let fee_token = evm
+                .fee_token
+                .expect("set in `validate_against_state_and_deduct_caller`");
+            ledger.record(fee_payer, fee_token, net_refund);
+        }
+
+        Ok(())
     }
 
     #[inline]
@@ -1609,6 +1709,17 @@ where
                     .map_err(TempoInvalidTransaction::from)?;
             }
 
+            // Multisig signatures (type 0x05) activate at T4 (outer + authorization list).
+            if !cfg.spec().is_t4()
+                && (aa_env.signature.is_multisig()
+                    || aa_env
+                        .tempo_authorization_list
+                        .iter()
+                        .any(|auth| auth.signature().is_multisig()))
+            {
+                return Err(TempoInvalidTransaction::MultisigBeforeActivation.into());
+            }
+
             let has_keychain_fields =
                 aa_env.key_authorization.is_some() || aa_env.signature.is_keychain();
 
@@ -2770,7 +2881,7 @@ mod tests {
                 );
             }
             SignedKeyAuthorization {
-                authorization: auth,
+                authorization: auth.into(),
                 signature: PrimitiveSignature::Secp256k1(
                     alloy_primitives::Signature::test_signature(),
                 ),
@@ -2866,7 +2977,8 @@ mod tests {
                     selector: [0xa9, 0x05, 0x9c, 0xbb],
                     recipients: vec![Address::random(), Address::random()],
                 }],
-            }]),
+            }])
+            .into(),
             signature: PrimitiveSignature::Secp256k1(alloy_primitives::Signature::test_signature()),
         };
 
@@ -2895,7 +3007,8 @@ mod tests {
             .with_allowed_calls(vec![CallScope {
                 target: Address::random(),
                 selector_rules: vec![],
-            }]),
+            }])
+            .into(),
             signature: PrimitiveSignature::Secp256k1(alloy_primitives::Signature::test_signature()),
         };
 
@@ -2915,7 +3028,8 @@ mod tests {
                     selector: [0xa9, 0x05, 0x9c, 0xbb],
                     recipients: vec![],
                 }],
-            }]),
+            }])
+            .into(),
             signature: PrimitiveSignature::Secp256k1(alloy_primitives::Signature::test_signature()),
         };
 
@@ -2961,7 +3075,8 @@ mod tests {
                     limit: U256::from(2000),
                     period: 0,
                 },
-            ]),
+            ])
+            .into(),
             signature: PrimitiveSignature::Secp256k1(alloy_primitives::Signature::test_signature()),
         };
 
@@ -3290,6 +3405,7 @@ mod tests {
                                     recipients: vec![],
                                 }],
                             }],
+                            maxUses: 0,
                         },
                     },
                 )
@@ -3418,6 +3534,7 @@ mod tests {
                                     recipients: vec![],
                                 }],
                             }],
+                            maxUses: 0,
                         },
                     },
                 )
@@ -3941,7 +4058,7 @@ mod tests {
                     }).collect());
                 }
                 SignedKeyAuthorization {
-                    authorization: auth,
+                    authorization: auth.into(),
                     signature: PrimitiveSignature::Secp256k1(alloy_primitives::Signature::test_signature()),
                 }
             };
@@ -3999,7 +4116,7 @@ mod tests {
                 }).collect());
             }
             let key_auth = SignedKeyAuthorization {
-                authorization: auth,
+                authorization: auth.into(),
                 signature,
             };
 
@@ -4305,6 +4422,7 @@ mod tests {
                                 limits: vec![],
                                 allowAnyCalls: true,
                                 allowedCalls: vec![],
+                                maxUses: 0,
                             },
                         },
                     )
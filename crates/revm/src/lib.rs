@@ -9,7 +9,7 @@ mod block;
 use tracing as _;
 
 mod common;
-pub use common::{TempoStateAccess, TempoTx};
+pub use common::{SimulationBlockEnv, TempoStateAccess, TempoTx};
 pub mod error;
 pub mod evm;
 pub mod exec;
@@ -33,6 +33,10 @@ pub struct TempoBatchCallEnv {
     /// Multiple calls for Tempo transactions
     pub aa_calls: Vec<Call>,
 
+    /// Ordered list of acceptable fee tokens; the fee manager uses the first
+    /// with sufficient balance and liquidity. Empty if the tx uses `fee_token`.
+    pub fee_token_preferences: Vec<Address>,
+
     /// Authorization list (EIP-7702 with Tempo signatures)
     ///
     /// Each authorization lazily recovers the authority on first access and caches the result.
@@ -282,6 +286,7 @@ impl FromRecoveredTx<AASigned> for TempoTxEnv {
         let TempoTransaction {
             chain_id,
             fee_token,
+            fee_token_preferences,
             max_priority_fee_per_gas,
             max_fee_per_gas,
             gas_limit,
@@ -294,6 +299,9 @@ impl FromRecoveredTx<AASigned> for TempoTxEnv {
             valid_after,
             key_authorization,
             tempo_authorization_list,
+            // Unknown future-version fields only matter for encoding/hashing,
+            // which happens on the signed envelope, not the execution env.
+            unknown_fields: _,
         } = tx;
 
         // Extract to/value/input from calls (use first call or defaults)
@@ -346,6 +354,7 @@ impl FromRecoveredTx<AASigned> for TempoTxEnv {
                 valid_before: valid_before.map(NonZeroU64::get),
                 valid_after: valid_after.map(NonZeroU64::get),
                 aa_calls: calls.clone(),
+                fee_token_preferences: fee_token_preferences.clone(),
                 // Recover authorizations upfront to avoid recovery during execution
                 tempo_authorization_list: tempo_authorization_list
                     .iter()
@@ -0,0 +1,104 @@
+//! Committed golden vectors shared across Tempo test suites.
+//!
+//! Holds hex fixtures of real finalization certificates, block headers, and
+//! the BLS message digests they commit to, captured from a running network.
+//! Fixture-based tests (see `tempo-e2e`) decode and re-verify these vectors
+//! on every run, so any drift in the certificate or header byte encodings is
+//! caught against bytes that once verified on a live network. The same
+//! vectors are meant to be fed to the Solidity `FinalizationBridge` once that
+//! contract lands in this tree.
+//!
+//! Vectors are stored as JSON files under `vectors/` and are re-captured by
+//! the generating tests when `UPDATE_GOLDEN_VECTORS=1` is set.
+
+#![cfg_attr(not(test), warn(unused_crate_dependencies))]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use eyre::WrapErr as _;
+use serde::{Deserialize, Serialize};
+
+/// A finalized block captured from a running network: the certificate, the
+/// header it finalizes, and the digest the BLS signature commits to.
+///
+/// All byte fields are lower-case hex without a `0x` prefix, matching the
+/// encoding used by the consensus RPC.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FinalizationVector {
+    /// Fixture name; doubles as the file stem under `vectors/finalization/`.
+    pub name: String,
+    /// Free-form note on where the vector was captured.
+    pub description: String,
+    /// Consensus epoch of the finalization.
+    pub epoch: u64,
+    /// Consensus view of the finalization.
+    pub view: u64,
+    /// Execution block height of the finalized header.
+    pub height: u64,
+    /// BLS message digest the certificate commits to (the eth header hash).
+    pub digest: String,
+    /// RLP encoding of the finalized block header.
+    pub header_rlp: String,
+    /// Full finalization certificate in its wire encoding.
+    pub certificate: String,
+    /// BLS network identity (public key) the certificate verifies against.
+    pub network_identity: String,
+}
+
+/// Root directory of the committed vectors.
+fn vectors_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("vectors")
+}
+
+/// Directory holding the finalization vectors.
+fn finalization_dir() -> PathBuf {
+    vectors_root().join("finalization")
+}
+
+/// Loads all committed finalization vectors, sorted by name.
+///
+/// Returns an empty list if no vectors have been captured yet, so fixture
+/// tests degrade to a no-op on a fresh checkout rather than failing.
+pub fn load_finalization_vectors() -> eyre::Result<Vec<FinalizationVector>> {
+    let dir = finalization_dir();
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut vectors = Vec::new();
+    for entry in
+        fs::read_dir(&dir).wrap_err_with(|| format!("failed reading `{}`", dir.display()))?
+    {
+        let path = entry?.path();
+        if path.extension().is_none_or(|ext| ext != "json") {
+            continue;
+        }
+        vectors.push(load_vector(&path)?);
+    }
+    vectors.sort_by(|a, b| a.name.cmp(&b.name));
+    Ok(vectors)
+}
+
+fn load_vector(path: &Path) -> eyre::Result<FinalizationVector> {
+    let raw = fs::read_to_string(path)
+        .wrap_err_with(|| format!("failed reading `{}`", path.display()))?;
+    serde_json::from_str(&raw)
+        .wrap_err_with(|| format!("malformed finalization vector `{}`", path.display()))
+}
+
+/// Writes `vector` to `vectors/finalization/<name>.json`, replacing any
+/// existing fixture of the same name. Returns the written path.
+pub fn write_finalization_vector(vector: &FinalizationVector) -> eyre::Result<PathBuf> {
+    let dir = finalization_dir();
+    fs::create_dir_all(&dir).wrap_err_with(|| format!("failed creating `{}`", dir.display()))?;
+
+    let path = dir.join(format!("{}.json", vector.name));
+    let mut raw = serde_json::to_string_pretty(vector).wrap_err("failed serializing vector")?;
+    raw.push('\n');
+    fs::write(&path, raw).wrap_err_with(|| format!("failed writing `{}`", path.display()))?;
+    Ok(path)
+}
@@ -1,10 +1,24 @@
 //! An iterator over the best transactions in the tempo pool.
 
-use crate::transaction::TempoPooledTransaction;
+use crate::{
+    transaction::{TempoPoolTransactionError, TempoPooledTransaction},
+    tt_2d_pool::AASequenceId,
+};
+use alloy_primitives::{Address, B256, U256};
 use reth_transaction_pool::{
-    BestTransactions, CoinbaseTipOrdering, Priority, TransactionOrdering,
+    BestTransactions, CoinbaseTipOrdering, Priority, TransactionOrdering, ValidPoolTransaction,
     error::InvalidPoolTransactionError,
 };
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Arc,
+};
+
+/// Maximum number of key-authorization transactions a single account may contribute per block.
+pub const MAX_KEY_AUTHS_PER_ACCOUNT_PER_BLOCK: usize = 2;
+
+/// Maximum number of new keys (key-authorization transactions) admitted into a single block.
+pub const MAX_NEW_KEYS_PER_BLOCK: usize = 64;
 
 /// An extension trait for [`BestTransactions`] that in addition to the transaction also yields the priority value.
 pub trait BestPriorityTransactions<T: TransactionOrdering>: BestTransactions {
@@ -156,6 +170,260 @@ where
     }
 }
 
+impl<L, R, T> BestPriorityTransactions<T> for MergeBestTransactions<L, R, T>
+where
+    L: BestPriorityTransactions<T, Item: Send> + Send,
+    R: BestPriorityTransactions<T, Item = L::Item> + Send,
+    T: TransactionOrdering,
+{
+    fn next_tx_and_priority(&mut self) -> Option<(Self::Item, Priority<T::PriorityValue>)> {
+        self.next_best()
+    }
+}
+
+/// Item type shared by all pool iterators in this module.
+type PoolItem = Arc<ValidPoolTransaction<TempoPooledTransaction>>;
+
+/// Inclusion lane of a buffered transaction in [`LaneRoundRobin`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Lane {
+    /// Sequential transactions: one lane per `(sender, nonce key)`. Protocol
+    /// nonce transactions map to nonce key zero, so they stay a single lane
+    /// per sender.
+    Sequence(AASequenceId),
+    /// Expiring nonce transactions have no sequence; each is its own lane,
+    /// keyed by its expiring nonce hash.
+    ExpiringNonce(B256),
+}
+
+impl Lane {
+    fn of(tx: &PoolItem) -> Self {
+        if tx.transaction.is_expiring_nonce() {
+            let hash = tx
+                .transaction
+                .expiring_nonce_hash()
+                .expect("expiring nonce tx must be AA");
+            return Self::ExpiringNonce(hash);
+        }
+        Self::Sequence(AASequenceId::new(
+            tx.sender(),
+            tx.transaction.nonce_key().unwrap_or(U256::ZERO),
+        ))
+    }
+}
+
+/// A [`BestTransactions`] wrapper that interleaves equal-priority transactions
+/// across `(sender, nonce key)` lanes.
+///
+/// Tempo's 2D nonces give one account independent payment lanes, and the
+/// underlying iterators break priority ties by submission id. An enterprise
+/// account that filled one lane before starting the next would therefore see
+/// that lane drained to exhaustion while its sibling lanes — equally ready,
+/// equally priced — wait at the back of the block. This wrapper buffers each
+/// run of equal-priority transactions and yields it round-robin across lanes,
+/// so concurrent lanes progress together. Per-lane nonce order is preserved
+/// and transactions of different priority are never reordered.
+pub struct LaneRoundRobin<I, T: TransactionOrdering> {
+    inner: I,
+    /// Lanes of the currently buffered priority band, rotated on every yield.
+    /// Bands are as small as the number of ready lanes at one priority, so a
+    /// linear scan per insertion is fine.
+    lanes: VecDeque<(Lane, VecDeque<PoolItem>)>,
+    /// First transaction of the next band, pulled while draining the inner
+    /// iterator past the current priority.
+    pending: Option<(PoolItem, Priority<T::PriorityValue>)>,
+}
+
+impl<I, T> LaneRoundRobin<I, T>
+where
+    I: BestPriorityTransactions<T, Item = PoolItem>,
+    T: TransactionOrdering,
+{
+    /// Creates a new lane-interleaving wrapper around the given iterator.
+    pub fn new(inner: I) -> Self {
+        Self {
+            inner,
+            lanes: VecDeque::new(),
+            pending: None,
+        }
+    }
+
+    /// Appends a transaction to its lane, creating the lane at the back of
+    /// the rotation if it is new.
+    fn buffer(&mut self, tx: PoolItem) {
+        let lane = Lane::of(&tx);
+        match self.lanes.iter_mut().find(|(id, _)| *id == lane) {
+            Some((_, queue)) => queue.push_back(tx),
+            None => self.lanes.push_back((lane, VecDeque::from([tx]))),
+        }
+    }
+
+    /// Pulls the next run of equal-priority transactions into the lane
+    /// buffer. Returns `false` when the inner iterator is exhausted.
+    fn refill(&mut self) -> bool {
+        let next = self
+            .pending
+            .take()
+            .or_else(|| self.inner.next_tx_and_priority());
+        let Some((tx, band_priority)) = next else {
+            return false;
+        };
+        self.buffer(tx);
+        while let Some((tx, priority)) = self.inner.next_tx_and_priority() {
+            if priority == band_priority {
+                self.buffer(tx);
+            } else {
+                self.pending = Some((tx, priority));
+                break;
+            }
+        }
+        true
+    }
+}
+
+impl<I, T> Iterator for LaneRoundRobin<I, T>
+where
+    I: BestPriorityTransactions<T, Item = PoolItem>,
+    T: TransactionOrdering,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.lanes.is_empty() && !self.refill() {
+            return None;
+        }
+        let (lane, mut queue) = self.lanes.pop_front()?;
+        let tx = queue.pop_front().expect("buffered lanes are never empty");
+        if !queue.is_empty() {
+            self.lanes.push_back((lane, queue));
+        }
+        Some(tx)
+    }
+}
+
+impl<I, T> BestTransactions for LaneRoundRobin<I, T>
+where
+    I: BestPriorityTransactions<T, Item = PoolItem> + Send,
+    T: TransactionOrdering,
+{
+    fn mark_invalid(&mut self, transaction: &Self::Item, kind: &InvalidPoolTransactionError) {
+        // Buffered transactions behind the invalid one in the same sequence
+        // lane would be included with a nonce gap; drop and invalidate them
+        // too. Expiring nonce lanes hold a single independent transaction.
+        let lane = Lane::of(transaction);
+        if matches!(lane, Lane::Sequence(_))
+            && let Some(position) = self.lanes.iter().position(|(id, _)| *id == lane)
+        {
+            let (_, queue) = self
+                .lanes
+                .remove(position)
+                .expect("position was just found");
+            for tx in queue {
+                self.inner.mark_invalid(&tx, kind);
+            }
+        }
+        self.inner.mark_invalid(transaction, kind);
+    }
+
+    fn no_updates(&mut self) {
+        self.inner.no_updates();
+    }
+
+    fn set_skip_blobs(&mut self, skip_blobs: bool) {
+        self.inner.set_skip_blobs(skip_blobs);
+    }
+}
+
+/// A [`BestTransactions`] wrapper that enforces per-block budgets on transactions carrying
+/// a key authorization.
+///
+/// Key authorizations provision new access keys and carry comparatively large payloads
+/// (token limits, call scopes, selector rules). The validator bounds each individual
+/// payload, but without a per-block budget a spammer could still fill blocks with keychain
+/// churn. This wrapper caps how many key-authorization transactions a single account may
+/// contribute to one block and how many new keys a block admits in total.
+///
+/// Over-budget transactions are marked invalid on the underlying iterator — removing them
+/// (and their descendants) from the current round only. They remain in the pool and become
+/// eligible again when building the next block.
+pub struct KeyAuthorizationBudget<I> {
+    inner: I,
+    max_per_account: usize,
+    max_total: usize,
+    per_account: HashMap<Address, usize>,
+    total: usize,
+}
+
+impl<I> KeyAuthorizationBudget<I> {
+    /// Creates a new budget-enforcing wrapper around the given iterator.
+    pub fn new(inner: I, max_per_account: usize, max_total: usize) -> Self {
+        Self {
+            inner,
+            max_per_account,
+            max_total,
+            per_account: HashMap::new(),
+            total: 0,
+        }
+    }
+}
+
+impl<I> Iterator for KeyAuthorizationBudget<I>
+where
+    I: BestTransactions<Item = Arc<ValidPoolTransaction<TempoPooledTransaction>>>,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let tx = self.inner.next()?;
+
+            let carries_key_auth = tx
+                .transaction
+                .inner()
+                .as_aa()
+                .is_some_and(|aa| aa.tx().key_authorization.is_some());
+            if !carries_key_auth {
+                return Some(tx);
+            }
+
+            let sender = tx.sender();
+            let account_count = self.per_account.get(&sender).copied().unwrap_or(0);
+            if self.total >= self.max_total || account_count >= self.max_per_account {
+                // Over budget: skip the transaction (and its descendants, which would
+                // otherwise have a nonce gap) for this block only.
+                self.inner.mark_invalid(
+                    &tx,
+                    &InvalidPoolTransactionError::other(
+                        TempoPoolTransactionError::KeyAuthorizationBudgetExhausted,
+                    ),
+                );
+                continue;
+            }
+
+            self.total += 1;
+            self.per_account.insert(sender, account_count + 1);
+            return Some(tx);
+        }
+    }
+}
+
+impl<I> BestTransactions for KeyAuthorizationBudget<I>
+where
+    I: BestTransactions<Item = Arc<ValidPoolTransaction<TempoPooledTransaction>>>,
+{
+    fn mark_invalid(&mut self, transaction: &Self::Item, kind: &InvalidPoolTransactionError) {
+        self.inner.mark_invalid(transaction, kind);
+    }
+
+    fn no_updates(&mut self) {
+        self.inner.no_updates();
+    }
+
+    fn set_skip_blobs(&mut self, skip_blobs: bool) {
+        self.inner.set_skip_blobs(skip_blobs);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -386,4 +654,198 @@ mod tests {
         assert_eq!(merged.next(), Some("L2"));
         assert_eq!(merged.next(), None);
     }
+
+    // ============================================
+    // Key-authorization budget tests
+    // ============================================
+
+    use crate::test_utils::{TxBuilder, wrap_valid_tx};
+    use alloy_primitives::{Address, Signature};
+    use reth_transaction_pool::TransactionOrigin;
+    use tempo_primitives::{
+        SignatureType,
+        transaction::{
+            key_authorization::{KeyAuthorization, SignedKeyAuthorization},
+            tt_signature::PrimitiveSignature,
+        },
+    };
+
+    /// Builds a pool-wrapped AA transaction carrying an (unrestricted) key authorization.
+    fn key_auth_tx(
+        sender: Address,
+        nonce: u64,
+    ) -> Arc<ValidPoolTransaction<TempoPooledTransaction>> {
+        let key_auth = SignedKeyAuthorization {
+            authorization: KeyAuthorization::unrestricted(
+                1,
+                SignatureType::Secp256k1,
+                Address::random(),
+            )
+            .into(),
+            signature: PrimitiveSignature::Secp256k1(Signature::test_signature()),
+        };
+        Arc::new(wrap_valid_tx(
+            TxBuilder::aa(sender)
+                .nonce(nonce)
+                .key_authorization(key_auth)
+                .build(),
+            TransactionOrigin::External,
+        ))
+    }
+
+    /// Builds a pool-wrapped AA transaction without a key authorization.
+    fn plain_tx(sender: Address, nonce: u64) -> Arc<ValidPoolTransaction<TempoPooledTransaction>> {
+        Arc::new(wrap_valid_tx(
+            TxBuilder::aa(sender).nonce(nonce).build(),
+            TransactionOrigin::External,
+        ))
+    }
+
+    #[test]
+    fn test_key_auth_budget_per_account_cap() {
+        let sender = Address::random();
+        let txs = vec![
+            (key_auth_tx(sender, 0), 10),
+            (key_auth_tx(sender, 1), 9),
+            (key_auth_tx(sender, 2), 8),
+        ];
+        let expected: Vec<_> = txs.iter().map(|(tx, _)| *tx.hash()).collect();
+
+        let inner = MockBestTransactions::new(txs);
+        let invalidated = inner.invalidated();
+        let mut best = KeyAuthorizationBudget::new(inner, 2, 64);
+
+        assert_eq!(*best.next().unwrap().hash(), expected[0]);
+        assert_eq!(*best.next().unwrap().hash(), expected[1]);
+        // Third key-auth tx from the same account is over budget and skipped.
+        assert!(best.next().is_none());
+        let invalidated = invalidated.lock().unwrap();
+        assert_eq!(invalidated.len(), 1);
+        assert_eq!(*invalidated[0].hash(), expected[2]);
+    }
+
+    #[test]
+    fn test_key_auth_budget_total_cap() {
+        let txs = vec![
+            (key_auth_tx(Address::random(), 0), 10),
+            (key_auth_tx(Address::random(), 0), 9),
+            (key_auth_tx(Address::random(), 0), 8),
+        ];
+        let expected: Vec<_> = txs.iter().map(|(tx, _)| *tx.hash()).collect();
+
+        let inner = MockBestTransactions::new(txs);
+        let invalidated = inner.invalidated();
+        let mut best = KeyAuthorizationBudget::new(inner, 64, 2);
+
+        assert_eq!(*best.next().unwrap().hash(), expected[0]);
+        assert_eq!(*best.next().unwrap().hash(), expected[1]);
+        // Block-wide new-key budget is exhausted; the third sender is skipped too.
+        assert!(best.next().is_none());
+        assert_eq!(invalidated.lock().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_key_auth_budget_ignores_plain_transactions() {
+        let sender = Address::random();
+        let txs = vec![
+            (plain_tx(sender, 0), 10),
+            (plain_tx(sender, 1), 9),
+            (plain_tx(sender, 2), 8),
+        ];
+
+        let inner = MockBestTransactions::new(txs);
+        let invalidated = inner.invalidated();
+        // Zero budgets must not affect transactions without a key authorization.
+        let mut best = KeyAuthorizationBudget::new(inner, 0, 0);
+
+        assert_eq!(best.by_ref().count(), 3);
+        assert!(invalidated.lock().unwrap().is_empty());
+    }
+
+    /// Builds a pool-wrapped AA transaction on the given nonce-key lane.
+    fn lane_tx(sender: Address, nonce_key: u64, nonce: u64) -> PoolItem {
+        Arc::new(wrap_valid_tx(
+            TxBuilder::aa(sender)
+                .nonce_key(U256::from(nonce_key))
+                .nonce(nonce)
+                .build(),
+            TransactionOrigin::External,
+        ))
+    }
+
+    #[test]
+    fn test_lane_round_robin_interleaves_equal_priority_lanes() {
+        let sender = Address::random();
+        // Lane 1 was submitted in full before lane 2; everything has the
+        // same priority, so submission order alone would drain lane 1 first.
+        let txs = vec![
+            (lane_tx(sender, 1, 0), 10),
+            (lane_tx(sender, 1, 1), 10),
+            (lane_tx(sender, 1, 2), 10),
+            (lane_tx(sender, 2, 0), 10),
+            (lane_tx(sender, 2, 1), 10),
+        ];
+        let hashes: Vec<_> = txs.iter().map(|(tx, _)| *tx.hash()).collect();
+
+        let mut best = LaneRoundRobin::new(MockBestTransactions::new(txs));
+        let yielded: Vec<_> = best.by_ref().map(|tx| *tx.hash()).collect();
+
+        // Lanes alternate; per-lane nonce order is intact.
+        assert_eq!(
+            yielded,
+            vec![hashes[0], hashes[3], hashes[1], hashes[4], hashes[2]]
+        );
+    }
+
+    #[test]
+    fn test_lane_round_robin_never_reorders_across_priorities() {
+        let sender = Address::random();
+        let txs = vec![
+            (lane_tx(sender, 1, 0), 10),
+            (lane_tx(sender, 1, 1), 10),
+            (lane_tx(sender, 2, 0), 5),
+        ];
+        let hashes: Vec<_> = txs.iter().map(|(tx, _)| *tx.hash()).collect();
+
+        let mut best = LaneRoundRobin::new(MockBestTransactions::new(txs));
+        let yielded: Vec<_> = best.by_ref().map(|tx| *tx.hash()).collect();
+
+        // The lower-priority lane is not interleaved into the higher band.
+        assert_eq!(yielded, hashes);
+    }
+
+    #[test]
+    fn test_lane_round_robin_mark_invalid_drops_buffered_lane() {
+        let sender = Address::random();
+        let txs = vec![
+            (lane_tx(sender, 1, 0), 10),
+            (lane_tx(sender, 1, 1), 10),
+            (lane_tx(sender, 2, 0), 10),
+        ];
+        let hashes: Vec<_> = txs.iter().map(|(tx, _)| *tx.hash()).collect();
+
+        let inner = MockBestTransactions::new(txs);
+        let invalidated = inner.invalidated();
+        let mut best = LaneRoundRobin::new(inner);
+
+        let first = best.next().unwrap();
+        assert_eq!(*first.hash(), hashes[0]);
+
+        // Invalidating the lane head must also discard its buffered
+        // descendant, which would otherwise be yielded with a nonce gap.
+        let kind =
+            InvalidPoolTransactionError::Consensus(InvalidTransactionError::TxTypeNotSupported);
+        best.mark_invalid(&first, &kind);
+
+        assert_eq!(*best.next().unwrap().hash(), hashes[2]);
+        assert!(best.next().is_none());
+
+        let invalidated: Vec<_> = invalidated
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|tx| *tx.hash())
+            .collect();
+        assert_eq!(invalidated, vec![hashes[1], hashes[0]]);
+    }
 }
@@ -3,8 +3,14 @@
 // Routes user nonces (nonce_key>0) to minimal 2D nonce pool
 
 use crate::{
-    amm::AmmLiquidityCache, best::MergeBestTransactions, transaction::TempoPooledTransaction,
-    tt_2d_pool::AA2dPool, validator::TempoTransactionValidator,
+    amm::AmmLiquidityCache,
+    best::{
+        KeyAuthorizationBudget, LaneRoundRobin, MAX_KEY_AUTHS_PER_ACCOUNT_PER_BLOCK,
+        MAX_NEW_KEYS_PER_BLOCK, MergeBestTransactions,
+    },
+    transaction::TempoPooledTransaction,
+    tt_2d_pool::AA2dPool,
+    validator::TempoTransactionValidator,
 };
 use alloy_consensus::Transaction;
 use alloy_primitives::{
@@ -41,8 +47,12 @@ use tempo_precompiles::{
     tip20::TIP20Token,
     tip403_registry::{REJECT_ALL_POLICY_ID, TIP403Registry},
 };
-use tempo_primitives::Block;
+use tempo_primitives::{
+    Block,
+    tx_trace::{TxStage, stage_span},
+};
 use tempo_revm::TempoStateAccess;
+use tracing::Instrument as _;
 
 /// Tempo transaction pool that routes based on nonce_key
 pub struct TempoTransactionPool<Client> {
@@ -576,14 +586,19 @@ where
         origin: TransactionOrigin,
         transaction: Self::Transaction,
     ) -> PoolResult<TransactionEvents> {
-        let tx = self
-            .protocol_pool
-            .validator()
-            .validate_transaction(origin, transaction)
-            .await;
-        let res = self.add_validated_transaction(origin, tx)?;
-        self.transaction_event_listener(res.hash)
-            .ok_or_else(|| PoolError::new(res.hash, PoolErrorKind::DiscardedOnInsert))
+        let span = stage_span(TxStage::PoolAdmission, *transaction.hash());
+        async {
+            let tx = self
+                .protocol_pool
+                .validator()
+                .validate_transaction(origin, transaction)
+                .await;
+            let res = self.add_validated_transaction(origin, tx)?;
+            self.transaction_event_listener(res.hash)
+                .ok_or_else(|| PoolError::new(res.hash, PoolErrorKind::DiscardedOnInsert))
+        }
+        .instrument(span)
+        .await
     }
 
     async fn add_transaction(
@@ -591,12 +606,17 @@ where
         origin: TransactionOrigin,
         transaction: Self::Transaction,
     ) -> PoolResult<AddedTransactionOutcome> {
-        let tx = self
-            .protocol_pool
-            .validator()
-            .validate_transaction(origin, transaction)
-            .await;
-        self.add_validated_transaction(origin, tx)
+        let span = stage_span(TxStage::PoolAdmission, *transaction.hash());
+        async {
+            let tx = self
+                .protocol_pool
+                .validator()
+                .validate_transaction(origin, transaction)
+                .await;
+            self.add_validated_transaction(origin, tx)
+        }
+        .instrument(span)
+        .await
     }
 
     async fn add_transactions(
@@ -795,7 +815,11 @@ where
     ) -> Box<dyn BestTransactions<Item = Arc<ValidPoolTransaction<Self::Transaction>>>> {
         let left = self.protocol_pool.inner().best_transactions();
         let right = self.aa_2d_pool.read().best_transactions();
-        Box::new(MergeBestTransactions::new(left, right))
+        Box::new(KeyAuthorizationBudget::new(
+            LaneRoundRobin::new(MergeBestTransactions::new(left, right)),
+            MAX_KEY_AUTHS_PER_ACCOUNT_PER_BLOCK,
+            MAX_NEW_KEYS_PER_BLOCK,
+        ))
     }
 
     fn best_transactions_with_attributes(
@@ -1326,6 +1350,8 @@ mod tests {
                     expiry: u64::MAX,
                     enforce_limits: true,
                     is_revoked: false,
+                    use_limited: false,
+                    remaining_uses: 0,
                 })?;
                 let limit_key = AccountKeychain::spending_limit_key(account, key_id);
                 keychain.spending_limits[limit_key][fee_token].write(limit_state)?;
@@ -1815,6 +1841,8 @@ mod tests {
                     expiry: u64::MAX,
                     enforce_limits: true,
                     is_revoked: false,
+                    use_limited: false,
+                    remaining_uses: 0,
                 })
             })
             .unwrap();
@@ -1850,6 +1878,8 @@ mod tests {
                     expiry: u64::MAX,
                     enforce_limits: false,
                     is_revoked: false,
+                    use_limited: false,
+                    remaining_uses: 0,
                 })
             })
             .unwrap();
@@ -23,6 +23,7 @@ use tempo_primitives::{
     TempoPrimitives, TempoTxEnvelope,
     transaction::{
         TempoSignedAuthorization, TempoTransaction,
+        key_authorization::SignedKeyAuthorization,
         tempo_transaction::Call,
         tt_signature::{KeychainVersion, PrimitiveSignature, TempoSignature},
         tt_signed::AASigned,
@@ -68,6 +69,8 @@ pub(crate) struct TxBuilder {
     authorization_list: Option<Vec<TempoSignedAuthorization>>,
     /// Access list for AA transactions.
     access_list: AccessList,
+    /// Key authorization for AA transactions.
+    key_authorization: Option<SignedKeyAuthorization>,
 }
 
 impl Default for TxBuilder {
@@ -88,6 +91,7 @@ impl Default for TxBuilder {
             calls: None,
             authorization_list: None,
             access_list: Default::default(),
+            key_authorization: None,
         }
     }
 }
@@ -179,7 +183,13 @@ impl TxBuilder {
         self
     }
 
-    /// Set the access list for the AA transaction.
+    /// Set the key authorization (AA transactions only).
+    pub(crate) fn key_authorization(mut self, key_authorization: SignedKeyAuthorization) -> Self {
+        self.key_authorization = Some(key_authorization);
+        self
+    }
+
+    /// Set the access list (AA transactions only).
     pub(crate) fn access_list(mut self, access_list: AccessList) -> Self {
         self.access_list = access_list;
         self
@@ -204,12 +214,14 @@ impl TxBuilder {
             nonce_key: self.nonce_key,
             nonce: self.nonce,
             fee_token: self.fee_token,
+            fee_token_preferences: vec![],
             fee_payer_signature: None,
             valid_after: self.valid_after,
             valid_before: self.valid_before,
             access_list: self.access_list,
             tempo_authorization_list: self.authorization_list.unwrap_or_default(),
-            key_authorization: None,
+            key_authorization: self.key_authorization,
+            unknown_fields: Default::default(),
         };
 
         let signature =
@@ -260,12 +272,14 @@ impl TxBuilder {
             nonce_key: self.nonce_key,
             nonce: self.nonce,
             fee_token: self.fee_token,
+            fee_token_preferences: vec![],
             fee_payer_signature: None,
             valid_after: self.valid_after,
             valid_before: self.valid_before,
             access_list: self.access_list,
             tempo_authorization_list: self.authorization_list.unwrap_or_default(),
-            key_authorization: None,
+            key_authorization: self.key_authorization,
+            unknown_fields: Default::default(),
         };
 
         // Create a temp AASigned to get the signature hash
@@ -372,6 +386,8 @@ pub(crate) fn create_mock_provider() -> MockEthProvider<TempoPrimitives, TempoCh
 ///         expiry: u64::MAX,
 ///         enforce_limits: false,
 ///         is_revoked: false,
+///         use_limited: false,
+///         remaining_uses: 0,
 ///     })
 /// });
 /// ```
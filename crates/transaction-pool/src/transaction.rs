@@ -302,12 +302,25 @@ pub enum TempoPoolTransactionError {
     )]
     TooManyTotalStorageKeys { count: usize, max_allowed: usize },
 
+    /// Thrown when an AA transaction has too many fee token preferences.
+    #[error("Too many fee token preferences: {count} exceeds maximum allowed {max_allowed}")]
+    TooManyFeeTokenPreferences { count: usize, max_allowed: usize },
+
     /// Thrown when a key authorization has too many token limits.
     #[error(
         "Too many token limits in key authorization: {count} exceeds maximum allowed {max_allowed}"
     )]
     TooManyTokenLimits { count: usize, max_allowed: usize },
 
+    /// Thrown when a key authorization's RLP encoding exceeds the maximum allowed size.
+    #[error("Key authorization payload size {size} exceeds maximum allowed {max_allowed} bytes")]
+    KeyAuthorizationTooLarge { size: usize, max_allowed: usize },
+
+    /// Returned when a key-authorization transaction is deferred from the current block
+    /// because the per-account or per-block key-authorization budget is exhausted.
+    #[error("Key authorization budget for this block is exhausted, transaction deferred")]
+    KeyAuthorizationBudgetExhausted,
+
     /// Thrown when an access key has expired or is expiring within the propagation buffer.
     #[error("Access key expired: expiry {expiry} <= min allowed {min_allowed}")]
     AccessKeyExpired { expiry: u64, min_allowed: u64 },
@@ -330,6 +343,7 @@ impl PoolTransactionError for TempoPoolTransactionError {
             | Self::InvalidValidAfter { .. }
             | Self::AccessKeyExpired { .. }
             | Self::KeyAuthorizationExpired { .. }
+            | Self::KeyAuthorizationBudgetExhausted
             | Self::Keychain(_) => false,
             Self::SubblockNonceKey
             | Self::TooManyAuthorizations { .. }
@@ -338,7 +352,8 @@ impl PoolTransactionError for TempoPoolTransactionError {
             | Self::TooManyAccessListAccounts { .. }
             | Self::TooManyStorageKeysPerAccount { .. }
             | Self::TooManyTotalStorageKeys { .. }
-            | Self::TooManyTokenLimits { .. } => true,
+            | Self::TooManyTokenLimits { .. }
+            | Self::KeyAuthorizationTooLarge { .. } => true,
         }
     }
 
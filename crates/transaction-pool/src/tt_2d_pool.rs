@@ -5306,12 +5306,14 @@ mod tests {
                 nonce_key: U256::MAX,
                 nonce: 0,
                 fee_token: Some(fee_token),
+                fee_token_preferences: vec![],
                 fee_payer_signature: Some(fee_payer_signature),
                 valid_after: None,
                 valid_before: Some(core::num::NonZeroU64::new(123).unwrap()),
                 access_list: AccessList::default(),
                 tempo_authorization_list: Vec::new(),
                 key_authorization: None,
+                unknown_fields: Default::default(),
             };
 
             let signature = TempoSignature::Primitive(PrimitiveSignature::Secp256k1(
@@ -5391,12 +5393,14 @@ mod tests {
             nonce_key: U256::MAX,
             nonce: 0,
             fee_token: Some(fee_token),
+            fee_token_preferences: vec![],
             fee_payer_signature: Some(Signature::new(U256::from(1), U256::from(2), false)),
             valid_before: Some(core::num::NonZeroU64::new(123).unwrap()),
             access_list: AccessList::default(),
             tempo_authorization_list: Vec::new(),
             key_authorization: None,
             valid_after: None,
+            unknown_fields: Default::default(),
         };
 
         let signature =
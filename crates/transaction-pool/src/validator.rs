@@ -56,6 +56,17 @@ pub const MAX_ACCESS_LIST_STORAGE_KEYS_TOTAL: usize = 2048;
 /// Maximum number of token limits in a KeyAuthorization (DoS protection).
 pub const MAX_TOKEN_LIMITS: usize = 256;
 
+/// Maximum RLP-encoded size of a key authorization in bytes (DoS protection).
+///
+/// Bounds the aggregate payload (limits + call scopes + selector rules) so the
+/// per-field cardinality limits cannot be combined into a multi-hundred-kilobyte
+/// keychain blob that still passes every individual count check.
+pub const MAX_KEY_AUTHORIZATION_BYTES: usize = 16 * 1024;
+
+/// Maximum number of fee token preferences in an AA transaction (DoS protection:
+/// each entry costs a balance + validity lookup during fee token resolution).
+pub const MAX_FEE_TOKEN_PREFERENCES: usize = 8;
+
 /// Default maximum allowed `valid_after` offset for AA txs (in seconds).
 ///
 /// Aligned with the default queued transaction lifetime (`max_queued_lifetime = 120s`)
@@ -255,10 +266,28 @@ where
             });
         }
 
+        // Check fee token preference list size
+        if tx.fee_token_preferences.len() > MAX_FEE_TOKEN_PREFERENCES {
+            return Err(TempoPoolTransactionError::TooManyFeeTokenPreferences {
+                count: tx.fee_token_preferences.len(),
+                max_allowed: MAX_FEE_TOKEN_PREFERENCES,
+            });
+        }
+
         // Check key_authorization cardinality limits (DoS protection).
         // Semantic validation (duplicates, zero-address, TIP-20, u128 cap) is handled by the
         // EVM precompile via `validate_with_evm`.
         if let Some(ref key_auth) = tx.key_authorization {
+            // Bound the encoded size before inspecting the nested structures so a
+            // single giant authorization can't dominate block space.
+            let encoded_size = alloy_rlp::Encodable::length(key_auth);
+            if encoded_size > MAX_KEY_AUTHORIZATION_BYTES {
+                return Err(TempoPoolTransactionError::KeyAuthorizationTooLarge {
+                    size: encoded_size,
+                    max_allowed: MAX_KEY_AUTHORIZATION_BYTES,
+                });
+            }
+
             if let Some(limits) = &key_auth.limits
                 && limits.len() > MAX_TOKEN_LIMITS
             {
@@ -1957,12 +1986,14 @@ mod tests {
             nonce_key: U256::ZERO,
             nonce: 0,
             fee_token: Some(address!("0000000000000000000000000000000000000002")),
+            fee_token_preferences: vec![],
             fee_payer_signature: None,
             valid_after: None,
             valid_before: None,
             access_list: Default::default(),
             tempo_authorization_list: authorizations,
             key_authorization: None,
+            unknown_fields: Default::default(),
         };
 
         let signed_tx = AASigned::new_unhashed(
@@ -0,0 +1,207 @@
+//! Analyzes consensus timing metrics and recommends config adjustments
+//! (`xtask analyze-consensus`).
+
+use eyre::Context as _;
+use std::path::PathBuf;
+
+/// Analyzes a Prometheus scrape of a node's metrics endpoint and recommends
+/// adjustments to the consensus timing knobs.
+///
+/// Compares the measured proposal build times, certificate collection
+/// durations, and block fullness against the configured budgets, replacing
+/// the log spelunking that tuning epoch/consensus durations used to require:
+///
+/// ```text
+/// curl -s localhost:9090/metrics > scrape.txt
+/// cargo run -p tempo-xtask -- analyze-consensus \
+///     --metrics scrape.txt --time-to-propose-ms 250 --time-to-collect-notarizations-ms 200
+/// ```
+#[derive(Debug, clap::Args)]
+pub struct AnalyzeConsensus {
+    /// Path to a Prometheus text scrape of the node's metrics endpoint.
+    #[arg(long)]
+    metrics: PathBuf,
+
+    /// The node's configured proposal budget (`wait_for_proposal`), in
+    /// milliseconds.
+    #[arg(long)]
+    time_to_propose_ms: u64,
+
+    /// The node's configured notarization collection budget
+    /// (`time_to_collect_notarizations`), in milliseconds.
+    #[arg(long)]
+    time_to_collect_notarizations_ms: u64,
+}
+
+impl AnalyzeConsensus {
+    pub fn run(self) -> eyre::Result<()> {
+        let text = std::fs::read_to_string(&self.metrics)
+            .wrap_err_with(|| format!("failed to read scrape at {}", self.metrics.display()))?;
+        let scrape = Scrape::parse(&text);
+
+        let propose_budget = self.time_to_propose_ms as f64 / 1_000.0;
+        let notarization_budget = self.time_to_collect_notarizations_ms as f64 / 1_000.0;
+        let mut recommendations = Vec::new();
+
+        match scrape.histogram_avg("propose_duration") {
+            Some(avg) => {
+                println!(
+                    "proposal build time: avg {:.0}ms (budget {:.0}ms)",
+                    avg * 1_000.0,
+                    propose_budget * 1_000.0,
+                );
+                if let Some(overruns) = scrape.value("propose_budget_exceeded_total")
+                    && overruns > 0.0
+                {
+                    println!("  {overruns:.0} proposals overran the budget");
+                }
+                if avg > propose_budget * 0.9 {
+                    recommendations.push(format!(
+                        "proposals consume over 90% of the budget; raise wait_for_proposal \
+                         above {}ms to avoid shipping partially built blocks",
+                        self.time_to_propose_ms,
+                    ));
+                } else if avg < propose_budget * 0.4 {
+                    recommendations.push(format!(
+                        "proposals finish well inside the budget; wait_for_proposal could \
+                         drop towards {:.0}ms to cut block time",
+                        (avg * 1_250.0).max(50.0),
+                    ));
+                }
+            }
+            None => println!("proposal build time: no samples in scrape"),
+        }
+
+        match scrape.histogram_avg("notarization_collection_duration") {
+            Some(avg) => {
+                println!(
+                    "notarization collection: avg {:.0}ms (budget {:.0}ms)",
+                    avg * 1_000.0,
+                    notarization_budget * 1_000.0,
+                );
+                if avg > notarization_budget * 0.9 {
+                    recommendations.push(format!(
+                        "notarizations take over 90% of the collection budget; raise \
+                         time_to_collect_notarizations above {}ms or expect leader skips",
+                        self.time_to_collect_notarizations_ms,
+                    ));
+                } else if avg < notarization_budget * 0.4 {
+                    recommendations.push(format!(
+                        "notarizations land well inside the budget; \
+                         time_to_collect_notarizations could drop towards {:.0}ms",
+                        (avg * 1_250.0).max(50.0),
+                    ));
+                }
+            }
+            None => println!("notarization collection: no samples in scrape"),
+        }
+
+        if let Some(avg) = scrape.histogram_avg("finalization_duration") {
+            println!(
+                "finalization: avg {:.0}ms after block timestamp",
+                avg * 1_000.0
+            );
+        }
+
+        match scrape.value("built_block_fullness_bps") {
+            Some(bps) => {
+                println!("last built block fullness: {:.1}%", bps / 100.0);
+                if bps > 9_000.0 {
+                    recommendations.push(
+                        "blocks are over 90% full; raise the gas limit or expect fee \
+                         pressure — shortening the block interval will not add capacity"
+                            .to_string(),
+                    );
+                } else if bps < 1_000.0 {
+                    recommendations.push(
+                        "blocks are under 10% full; the block interval could be \
+                         shortened without saturating the builder"
+                            .to_string(),
+                    );
+                }
+            }
+            None => println!("block fullness: not found in scrape"),
+        }
+
+        if recommendations.is_empty() {
+            println!("\nno adjustments recommended; timing knobs match observed behavior");
+        } else {
+            println!("\nrecommendations:");
+            for recommendation in recommendations {
+                println!("  - {recommendation}");
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A parsed Prometheus text scrape.
+///
+/// Metric names are matched by suffix because commonware prefixes them with
+/// the runtime context labels (e.g. `engine_feed_notarization_collection_duration`).
+struct Scrape {
+    samples: Vec<(String, f64)>,
+}
+
+impl Scrape {
+    fn parse(text: &str) -> Self {
+        let samples = text
+            .lines()
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let (name_and_labels, value) = line.rsplit_once(' ')?;
+                let name = name_and_labels
+                    .split_once('{')
+                    .map_or(name_and_labels, |(name, _)| name);
+                Some((name.to_string(), value.parse().ok()?))
+            })
+            .collect();
+        Self { samples }
+    }
+
+    /// Sums all samples whose metric name ends with `suffix`.
+    fn value(&self, suffix: &str) -> Option<f64> {
+        let mut found = false;
+        let mut total = 0.0;
+        for (name, value) in &self.samples {
+            if name.ends_with(suffix) {
+                found = true;
+                total += value;
+            }
+        }
+        found.then_some(total)
+    }
+
+    /// Average observation of the histogram whose name ends with `base`.
+    fn histogram_avg(&self, base: &str) -> Option<f64> {
+        let sum = self.value(&format!("{base}_sum"))?;
+        let count = self.value(&format!("{base}_count"))?;
+        (count > 0.0).then(|| sum / count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_prefixed_and_labeled_samples() {
+        let scrape = Scrape::parse(
+            "# HELP engine_application_propose_duration seconds spent building a proposal\n\
+             # TYPE engine_application_propose_duration histogram\n\
+             engine_application_propose_duration_sum 1.5\n\
+             engine_application_propose_duration_count 10\n\
+             engine_application_propose_budget_exceeded_total 2\n\
+             engine_feed_notarization_collection_duration_bucket{le=\"0.5\"} 4\n\
+             engine_application_built_block_fullness_bps 4200\n",
+        );
+
+        assert_eq!(scrape.histogram_avg("propose_duration"), Some(0.15));
+        assert_eq!(scrape.value("propose_budget_exceeded_total"), Some(2.0));
+        assert_eq!(scrape.value("built_block_fullness_bps"), Some(4200.0));
+        assert_eq!(
+            scrape.histogram_avg("notarization_collection_duration"),
+            None
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Compares two state diff exports from different node builds
+//! (`xtask compare-state-diffs`).
+
+use eyre::Context as _;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs::File,
+    io::{BufRead as _, BufReader},
+    path::{Path, PathBuf},
+};
+use tempo_evm::state_diff::BlockStateDiff;
+
+/// Compares two per-transaction state diff exports block by block.
+///
+/// Each export is a JSONL file produced by a node build running with the state
+/// diff recorder enabled (one `BlockStateDiff` per line). Blocks are matched by
+/// number; for every block present in both exports the canonical digests are
+/// compared first, and on mismatch the first divergent transaction/slot is
+/// reported. This is the comparison half of differential testing across
+/// hardfork implementations: run the same blocks through two builds, export,
+/// then diff the exports offline.
+#[derive(Debug, clap::Args)]
+pub struct CompareStateDiffs {
+    /// Path to the first export (one JSON block record per line).
+    #[arg(long)]
+    left: PathBuf,
+
+    /// Path to the second export.
+    #[arg(long)]
+    right: PathBuf,
+}
+
+impl CompareStateDiffs {
+    pub fn run(self) -> eyre::Result<()> {
+        let left = read_export(&self.left)?;
+        let right = read_export(&self.right)?;
+
+        let mut compared = 0usize;
+        let mut divergent = 0usize;
+
+        let block_numbers: BTreeSet<u64> = left.keys().chain(right.keys()).copied().collect();
+        for block_number in &block_numbers {
+            match (left.get(block_number), right.get(block_number)) {
+                (Some(left_block), Some(right_block)) => {
+                    compared += 1;
+                    if left_block.digest() == right_block.digest() {
+                        continue;
+                    }
+                    divergent += 1;
+                    match left_block.compare(right_block) {
+                        Some(divergence) => {
+                            println!("block {block_number}: {divergence}");
+                        }
+                        None => {
+                            // Digests cover encoding, compare() covers slots; disagreement
+                            // between the two means a non-canonical export.
+                            println!(
+                                "block {block_number}: digests differ but no slot divergence found \
+                                 (non-canonical export?)"
+                            );
+                        }
+                    }
+                }
+                (only_left, _) => {
+                    let side = if only_left.is_some() { "left" } else { "right" };
+                    println!("block {block_number}: only present in the {side} export");
+                    divergent += 1;
+                }
+            }
+        }
+
+        if divergent == 0 {
+            println!("exports agree: {compared} blocks compared, no divergence");
+            Ok(())
+        } else {
+            eyre::bail!("{divergent} divergent block(s) out of {compared} compared")
+        }
+    }
+}
+
+fn read_export(path: &Path) -> eyre::Result<BTreeMap<u64, BlockStateDiff>> {
+    let file = File::open(path)
+        .wrap_err_with(|| format!("failed to open state diff export at {}", path.display()))?;
+
+    let mut blocks = BTreeMap::new();
+    for (idx, line) in BufReader::new(file).lines().enumerate() {
+        let line = line.wrap_err_with(|| format!("failed to read {}", path.display()))?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let block: BlockStateDiff = serde_json::from_str(&line).wrap_err_with(|| {
+            format!("malformed record on line {} of {}", idx + 1, path.display())
+        })?;
+        let block_number = block.block_number;
+        if blocks.insert(block_number, block).is_some() {
+            eyre::bail!("duplicate block {} in {}", block_number, path.display());
+        }
+    }
+    Ok(blocks)
+}
@@ -1,8 +1,8 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use eyre::WrapErr as _;
 
-use crate::genesis_args::GenesisArgs;
+use crate::genesis_args::{ConsensusConfig, GenesisArgs};
 
 #[derive(clap::Parser, Debug)]
 pub(crate) struct GenerateGenesis {
@@ -40,48 +40,7 @@ impl GenerateGenesis {
         })?;
 
         if let Some(consensus_config) = consensus_config {
-            println!(
-                "consensus config generated for `{}` validators; writing to disk...",
-                consensus_config.validators.len()
-            );
-            for validator in consensus_config.validators {
-                std::fs::create_dir_all(validator.dst_dir(&output)).wrap_err_with(|| {
-                    format!(
-                        "failed creating target directory to store validator specifici keys at `{}`",
-                        validator.dst_dir(&output).display()
-                    )
-                })?;
-                let signing_key_dst = validator.dst_signing_key(&output);
-                std::fs::File::create(&signing_key_dst)
-                    .map_err(eyre::Report::new)
-                    .and_then(|f| {
-                        validator
-                            .signing_key
-                            .to_writer(f)
-                            .map_err(eyre::Report::new)
-                    })
-                    .wrap_err_with(|| {
-                        format!(
-                            "failed writing ed25519 signing key to `{}`",
-                            signing_key_dst.display()
-                        )
-                    })?;
-                let signing_share_dst = validator.dst_signing_share(&output);
-                validator
-                    .signing_share
-                    .write_to_file(&signing_share_dst)
-                    .wrap_err_with(|| {
-                        format!(
-                            "failed writing bls12381 signing share to `{}`",
-                            signing_share_dst.display()
-                        )
-                    })?;
-                println!(
-                    "validator keys written to `{}`, `{}`",
-                    signing_key_dst.display(),
-                    signing_share_dst.display()
-                );
-            }
+            write_validator_keys(&output, consensus_config)?;
         } else {
             println!("no consensus config generated; likely didn't provide --validators flag");
         }
@@ -89,3 +48,53 @@ impl GenerateGenesis {
         Ok(())
     }
 }
+
+/// Writes per-validator signing keys and shares under `output`.
+pub(crate) fn write_validator_keys(
+    output: &Path,
+    consensus_config: ConsensusConfig,
+) -> eyre::Result<()> {
+    println!(
+        "consensus config generated for `{}` validators; writing to disk...",
+        consensus_config.validators.len()
+    );
+    for validator in consensus_config.validators {
+        std::fs::create_dir_all(validator.dst_dir(output)).wrap_err_with(|| {
+            format!(
+                "failed creating target directory to store validator specifici keys at `{}`",
+                validator.dst_dir(output).display()
+            )
+        })?;
+        let signing_key_dst = validator.dst_signing_key(output);
+        std::fs::File::create(&signing_key_dst)
+            .map_err(eyre::Report::new)
+            .and_then(|f| {
+                validator
+                    .signing_key
+                    .to_writer(f)
+                    .map_err(eyre::Report::new)
+            })
+            .wrap_err_with(|| {
+                format!(
+                    "failed writing ed25519 signing key to `{}`",
+                    signing_key_dst.display()
+                )
+            })?;
+        let signing_share_dst = validator.dst_signing_share(output);
+        validator
+            .signing_share
+            .write_to_file(&signing_share_dst)
+            .wrap_err_with(|| {
+                format!(
+                    "failed writing bls12381 signing share to `{}`",
+                    signing_share_dst.display()
+                )
+            })?;
+        println!(
+            "validator keys written to `{}`, `{}`",
+            signing_key_dst.display(),
+            signing_share_dst.display()
+        );
+    }
+    Ok(())
+}
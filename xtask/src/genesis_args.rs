@@ -67,7 +67,7 @@ use tempo_precompiles::{
 pub(crate) struct GenesisArgs {
     /// Number of accounts to generate
     #[arg(short, long, default_value = "50000")]
-    accounts: u32,
+    pub(crate) accounts: u32,
 
     /// Mnemonic to use for account generation
     #[arg(
@@ -75,23 +75,23 @@ pub(crate) struct GenesisArgs {
         long,
         default_value = "test test test test test test test test test test test junk"
     )]
-    mnemonic: String,
+    pub(crate) mnemonic: String,
 
     /// Coinbase address
     #[arg(long, default_value = "0x0000000000000000000000000000000000000000")]
-    coinbase: Address,
+    pub(crate) coinbase: Address,
 
     /// Chain ID
     #[arg(long, short, default_value = "1337")]
-    chain_id: u64,
+    pub(crate) chain_id: u64,
 
     /// Genesis block gas limit
     #[arg(long, default_value_t = 500_000_000)]
-    gas_limit: u64,
+    pub(crate) gas_limit: u64,
 
     /// The hard-coded length of an epoch in blocks.
     #[arg(long, default_value_t = 302_400)]
-    epoch_length: u64,
+    pub(crate) epoch_length: u64,
 
     /// A comma-separated list of `<ip>:<port>`.
     #[arg(
@@ -100,12 +100,12 @@ pub(crate) struct GenesisArgs {
         value_delimiter = ',',
         required_unless_present_all(["no_dkg_in_genesis"]),
     )]
-    validators: Vec<SocketAddr>,
+    pub(crate) validators: Vec<SocketAddr>,
 
     /// Will not write the initial DKG outcome into the extra_data field of
     /// the genesis header.
     #[arg(long)]
-    no_dkg_in_genesis: bool,
+    pub(crate) no_dkg_in_genesis: bool,
 
     /// A fixed seed to generate all signing keys and group shares. This is
     /// intended for use in development and testing. Use at your own peril.
@@ -115,68 +115,138 @@ pub(crate) struct GenesisArgs {
     /// Custom admin address for pathUSD token.
     /// If not set, uses the first generated account.
     #[arg(long)]
-    pathusd_admin: Option<Address>,
+    pub(crate) pathusd_admin: Option<Address>,
 
     #[arg(long, default_value_t = u64::MAX)]
-    pathusd_amount: u64,
+    pub(crate) pathusd_amount: u64,
 
     /// Custom admin address for validator config.
     /// If not set, uses the first generated account.
     #[arg(long)]
-    validator_admin: Option<Address>,
+    pub(crate) validator_admin: Option<Address>,
 
     /// Custom onchain addresses for validators.
     /// Must match the number of validators if provided.
     #[arg(long, value_delimiter = ',')]
-    validator_addresses: Vec<Address>,
+    pub(crate) validator_addresses: Vec<Address>,
 
     /// Disable creating Alpha/Beta/ThetaUSD tokens.
     #[arg(long)]
-    no_extra_tokens: bool,
+    pub(crate) no_extra_tokens: bool,
 
     /// Enable creating deployment gas token.
     #[arg(long)]
-    deployment_gas_token: bool,
+    pub(crate) deployment_gas_token: bool,
 
     /// Custom admin address for deployment gas token.
     #[arg(long)]
-    deployment_gas_token_admin: Option<Address>,
+    pub(crate) deployment_gas_token_admin: Option<Address>,
 
     /// Disable minting pairwise FeeAMM liquidity.
     #[arg(long)]
-    no_pairwise_liquidity: bool,
+    pub(crate) no_pairwise_liquidity: bool,
 
     /// Timestamp for T0 hardfork activation (0 = genesis).
     #[arg(long, default_value = "0")]
-    t0_time: u64,
+    pub(crate) t0_time: u64,
 
     /// T1 hardfork activation time.
     #[arg(long, default_value = "0")]
-    t1_time: u64,
+    pub(crate) t1_time: u64,
 
     /// T1.A hardfork activation time.
     #[arg(long, default_value = "0")]
-    t1a_time: u64,
+    pub(crate) t1a_time: u64,
 
     /// T1.B hardfork activation time.
     #[arg(long, default_value = "0")]
-    t1b_time: u64,
+    pub(crate) t1b_time: u64,
 
     /// T1.C hardfork activation time.
     #[arg(long, default_value = "0")]
-    t1c_time: u64,
+    pub(crate) t1c_time: u64,
 
     /// T2 hardfork activation time.
     #[arg(long, default_value = "0")]
-    t2_time: u64,
+    pub(crate) t2_time: u64,
 
     /// T3 hardfork activation time.
     #[arg(long, default_value = "0")]
-    t3_time: u64,
+    pub(crate) t3_time: u64,
 
     /// T4 hardfork activation time.
     #[arg(long, default_value = "0")]
-    t4_time: u64,
+    pub(crate) t4_time: u64,
+    /// Extra TIP-20 tokens created through the factory in addition to the
+    /// standard Alpha/Beta/ThetaUSD set. Not settable from the CLI; composed
+    /// via [`crate::genesis_builder::GenesisBuilder`].
+    #[clap(skip)]
+    pub(crate) custom_tokens: Vec<CustomToken>,
+
+    /// Additional native-balance allocations applied to the final genesis.
+    #[clap(skip)]
+    pub(crate) prefunds: Vec<(Address, U256)>,
+
+    /// Raw storage writes applied to the final allocation after all system
+    /// contracts are initialized (e.g. keychain entries or DEX pool seeds).
+    /// Later writes win over initialization.
+    #[clap(skip)]
+    pub(crate) storage_overrides: Vec<(Address, B256, B256)>,
+}
+
+impl Default for GenesisArgs {
+    /// Mirrors the clap defaults, so the builder starts from the same
+    /// configuration as `xtask generate-genesis` with no flags.
+    fn default() -> Self {
+        Self {
+            accounts: 50_000,
+            mnemonic: "test test test test test test test test test test test junk".to_string(),
+            coinbase: Address::ZERO,
+            chain_id: 1337,
+            gas_limit: 500_000_000,
+            epoch_length: 302_400,
+            validators: Vec::new(),
+            no_dkg_in_genesis: false,
+            seed: None,
+            pathusd_admin: None,
+            pathusd_amount: u64::MAX,
+            validator_admin: None,
+            validator_addresses: Vec::new(),
+            no_extra_tokens: false,
+            deployment_gas_token: false,
+            deployment_gas_token_admin: None,
+            no_pairwise_liquidity: false,
+            t0_time: 0,
+            t1_time: 0,
+            t1a_time: 0,
+            t1b_time: 0,
+            t1c_time: 0,
+            t2_time: 0,
+            t3_time: 0,
+            t4_time: 0,
+            custom_tokens: Vec::new(),
+            prefunds: Vec::new(),
+            storage_overrides: Vec::new(),
+        }
+    }
+}
+
+/// A TIP-20 token created through the factory at genesis.
+#[derive(Clone, Debug)]
+pub(crate) struct CustomToken {
+    /// Token symbol, also used to derive a deterministic salt when no fixed
+    /// address is given.
+    pub(crate) symbol: String,
+    /// Token name.
+    pub(crate) name: String,
+    /// ISO currency code.
+    pub(crate) currency: String,
+    /// Token admin; the pathUSD admin when `None`.
+    pub(crate) admin: Option<Address>,
+    /// Fixed address under the TIP-20 prefix; derived from the symbol when `None`.
+    pub(crate) address: Option<Address>,
+    /// Amount minted to every generated account.
+    pub(crate) mint_amount: U256,
 }
 
 #[derive(Clone, Debug)]
@@ -340,6 +410,26 @@ impl GenesisArgs {
             }
         };
 
+        for token in &self.custom_tokens {
+            let admin = token.admin.unwrap_or(pathusd_admin);
+            let salt_or_address = match token.address {
+                Some(address) => SaltOrAddress::Address(address),
+                None => SaltOrAddress::Salt(alloy_primitives::keccak256(token.symbol.as_bytes())),
+            };
+            let address = create_and_mint_token(
+                &token.symbol,
+                &token.name,
+                &token.currency,
+                PATH_USD_ADDRESS,
+                admin,
+                &addresses,
+                token.mint_amount,
+                salt_or_address,
+                &mut evm,
+            )?;
+            println!("Created custom token {} at {address}", token.symbol);
+        }
+
         println!(
             "generating consensus config for validators: {:?}",
             self.validators
@@ -541,6 +631,19 @@ impl GenesisArgs {
         chain_config
             .extra_fields
             .insert_value("t4Time".to_string(), self.t4_time)?;
+        for (address, balance) in &self.prefunds {
+            genesis_alloc.entry(*address).or_default().balance = *balance;
+        }
+
+        for (address, slot, value) in &self.storage_overrides {
+            genesis_alloc
+                .entry(*address)
+                .or_default()
+                .storage
+                .get_or_insert_with(Default::default)
+                .insert(*slot, *value);
+        }
+
         let mut extra_data = Bytes::from_static(b"tempo-genesis");
 
         if let Some(consensus_config) = &consensus_config {
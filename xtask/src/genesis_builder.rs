@@ -0,0 +1,163 @@
+//! Programmatic genesis composition for custom devnets.
+//!
+//! [`GenesisBuilder`] replaces the hand-edited genesis JSON workflow: it
+//! composes prefunded accounts, predeployed TIP-20 tokens, the validator
+//! set, and raw precompile storage (e.g. keychain entries or DEX pool
+//! seeds) on top of the standard system-contract initialization, and emits
+//! both the genesis and a matching bridge sidecar config.
+//!
+//! Driven from the CLI via `xtask genesis` (see [`crate::genesis_cmd`]),
+//! or directly from test harnesses that need a one-off devnet genesis.
+
+use std::net::SocketAddr;
+
+use alloy::genesis::Genesis;
+use alloy_primitives::{Address, B256, U256};
+use tempo_bridge::config::{BridgeConfig, EscrowEntry, OriginSection, SubmitterSection};
+
+use crate::genesis_args::{ConsensusConfig, CustomToken, GenesisArgs};
+
+/// Everything `GenesisBuilder::build` emits for one devnet.
+pub(crate) struct GenesisBundle {
+    /// The composed genesis, ready to be written as `genesis.json`.
+    pub(crate) genesis: Genesis,
+    /// Validator keys and DKG output, when a validator set was configured.
+    pub(crate) consensus_config: Option<ConsensusConfig>,
+    /// Bridge sidecar config matching the genesis (chain id, escrows).
+    pub(crate) bridge_config: BridgeConfig,
+}
+
+/// Composes a devnet genesis programmatically.
+///
+/// Starts from the same defaults as `xtask generate-genesis` with no flags
+/// and layers custom allocations on top.
+pub(crate) struct GenesisBuilder {
+    args: GenesisArgs,
+    origin_rpc_url: String,
+    escrows: Vec<EscrowEntry>,
+}
+
+impl GenesisBuilder {
+    /// Creates a builder for the given chain id.
+    pub(crate) fn new(chain_id: u64) -> Self {
+        Self {
+            args: GenesisArgs {
+                chain_id,
+                ..Default::default()
+            },
+            origin_rpc_url: "ws://127.0.0.1:8546".to_string(),
+            escrows: Vec::new(),
+        }
+    }
+
+    /// Number of mnemonic-derived accounts to generate and fund.
+    pub(crate) fn accounts(mut self, accounts: u32) -> Self {
+        self.args.accounts = accounts;
+        self
+    }
+
+    /// Mnemonic the generated accounts are derived from.
+    pub(crate) fn mnemonic(mut self, mnemonic: impl Into<String>) -> Self {
+        self.args.mnemonic = mnemonic.into();
+        self
+    }
+
+    /// Validator p2p addresses; keys and the genesis DKG outcome are
+    /// generated for them.
+    pub(crate) fn validators(mut self, validators: Vec<SocketAddr>) -> Self {
+        self.args.validators = validators;
+        self
+    }
+
+    /// On-chain addresses registered for the validators, in order.
+    pub(crate) fn validator_addresses(mut self, addresses: Vec<Address>) -> Self {
+        self.args.validator_addresses = addresses;
+        self
+    }
+
+    /// Fixed seed for all generated keys. Development only.
+    pub(crate) fn seed(mut self, seed: u64) -> Self {
+        self.args.seed = Some(seed);
+        self
+    }
+
+    /// Epoch length in blocks.
+    pub(crate) fn epoch_length(mut self, epoch_length: u64) -> Self {
+        self.args.epoch_length = epoch_length;
+        self
+    }
+
+    /// Funds `address` with `balance` in the final allocation.
+    pub(crate) fn prefund(mut self, address: Address, balance: U256) -> Self {
+        self.args.prefunds.push((address, balance));
+        self
+    }
+
+    /// Creates a TIP-20 token through the factory at genesis.
+    pub(crate) fn token(mut self, token: CustomToken) -> Self {
+        self.args.custom_tokens.push(token);
+        self
+    }
+
+    /// Writes a raw storage slot into the final allocation, after all
+    /// system-contract initialization. This is the escape hatch for state
+    /// the initializers cannot express, e.g. keychain entries or DEX pool
+    /// seeds.
+    pub(crate) fn precompile_storage(mut self, address: Address, slot: B256, value: B256) -> Self {
+        self.args.storage_overrides.push((address, slot, value));
+        self
+    }
+
+    /// Skips the standard Alpha/Beta/ThetaUSD tokens.
+    pub(crate) fn no_extra_tokens(mut self) -> Self {
+        self.args.no_extra_tokens = true;
+        self.args.no_pairwise_liquidity = true;
+        self
+    }
+
+    /// Origin chain RPC endpoint written into the bridge config.
+    pub(crate) fn origin_rpc_url(mut self, url: impl Into<String>) -> Self {
+        self.origin_rpc_url = url.into();
+        self
+    }
+
+    /// Adds an origin-chain escrow to the bridge config.
+    pub(crate) fn escrow(mut self, address: Address, confirmations: u64) -> Self {
+        self.escrows.push(EscrowEntry {
+            address,
+            confirmations,
+            cap: None,
+        });
+        self
+    }
+
+    /// Generates the genesis, consensus config, and matching bridge config.
+    pub(crate) async fn build(self) -> eyre::Result<GenesisBundle> {
+        let Self {
+            args,
+            origin_rpc_url,
+            escrows,
+        } = self;
+
+        let bridge_config = BridgeConfig {
+            journal_dir: "bridge-journal".into(),
+            origin: OriginSection {
+                rpc_url: origin_rpc_url,
+                secondary_rpc_url: None,
+                require_quorum: false,
+                poll_interval_secs: 12,
+                escrows,
+            },
+            submitter: SubmitterSection::default(),
+            prune: Default::default(),
+        };
+
+        let (genesis, consensus_config) = args.generate_genesis().await?;
+
+        Ok(GenesisBundle {
+            genesis,
+            consensus_config,
+            bridge_config,
+        })
+    }
+}
@@ -0,0 +1,200 @@
+//! `xtask genesis` — manifest-driven devnet genesis generation.
+//!
+//! Reads a JSON manifest describing the devnet (validators, prefunded
+//! accounts, tokens, raw precompile storage, bridge escrows), drives
+//! [`GenesisBuilder`], and writes `genesis.json`, per-validator keys, and a
+//! matching `bridge.toml` — replacing the hand-edited genesis JSON workflow.
+//!
+//! Example manifest:
+//!
+//! ```json
+//! {
+//!   "chainId": 1337,
+//!   "validators": ["127.0.0.1:4000", "127.0.0.1:4001"],
+//!   "seed": 42,
+//!   "prefunds": [{ "address": "0x...", "balance": "0xde0b6b3a7640000" }],
+//!   "tokens": [{ "symbol": "TestUSD", "mintAmount": "0xffffffffffffffff" }],
+//!   "precompileStorage": [{ "address": "0x...", "slot": "0x...", "value": "0x..." }],
+//!   "escrows": [{ "address": "0x...", "confirmations": 12 }]
+//! }
+//! ```
+
+use std::{net::SocketAddr, path::PathBuf};
+
+use alloy_primitives::{Address, B256, U256};
+use eyre::WrapErr as _;
+use serde::Deserialize;
+
+use crate::{
+    generate_genesis::write_validator_keys, genesis_args::CustomToken,
+    genesis_builder::GenesisBuilder,
+};
+
+#[derive(clap::Parser, Debug)]
+pub(crate) struct GenesisCmd {
+    /// Path to the JSON devnet manifest.
+    #[arg(short, long)]
+    manifest: PathBuf,
+
+    /// Output directory.
+    #[arg(short, long)]
+    output: PathBuf,
+}
+
+/// The JSON devnet manifest.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct Manifest {
+    chain_id: u64,
+    #[serde(default)]
+    accounts: Option<u32>,
+    #[serde(default)]
+    mnemonic: Option<String>,
+    #[serde(default)]
+    validators: Vec<SocketAddr>,
+    #[serde(default)]
+    validator_addresses: Vec<Address>,
+    #[serde(default)]
+    seed: Option<u64>,
+    #[serde(default)]
+    epoch_length: Option<u64>,
+    #[serde(default)]
+    no_extra_tokens: bool,
+    #[serde(default)]
+    prefunds: Vec<Prefund>,
+    #[serde(default)]
+    tokens: Vec<TokenSpec>,
+    #[serde(default)]
+    precompile_storage: Vec<StorageEntry>,
+    #[serde(default)]
+    origin_rpc_url: Option<String>,
+    #[serde(default)]
+    escrows: Vec<EscrowSpec>,
+}
+
+/// A prefunded account.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct Prefund {
+    address: Address,
+    balance: U256,
+}
+
+/// A TIP-20 token created at genesis.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct TokenSpec {
+    symbol: String,
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    currency: Option<String>,
+    #[serde(default)]
+    admin: Option<Address>,
+    #[serde(default)]
+    address: Option<Address>,
+    #[serde(default)]
+    mint_amount: Option<U256>,
+}
+
+/// A raw precompile storage write.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct StorageEntry {
+    address: Address,
+    slot: B256,
+    value: B256,
+}
+
+/// An origin-chain escrow watched by the bridge.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+struct EscrowSpec {
+    address: Address,
+    #[serde(default)]
+    confirmations: u64,
+}
+
+impl GenesisCmd {
+    pub(crate) async fn run(self) -> eyre::Result<()> {
+        let manifest = std::fs::read_to_string(&self.manifest)
+            .wrap_err_with(|| format!("failed reading manifest `{}`", self.manifest.display()))?;
+        let manifest: Manifest =
+            serde_json::from_str(&manifest).wrap_err("failed parsing manifest")?;
+
+        let mut builder =
+            GenesisBuilder::new(manifest.chain_id).validators(manifest.validators.clone());
+        if let Some(accounts) = manifest.accounts {
+            builder = builder.accounts(accounts);
+        }
+        if let Some(mnemonic) = manifest.mnemonic {
+            builder = builder.mnemonic(mnemonic);
+        }
+        if !manifest.validator_addresses.is_empty() {
+            builder = builder.validator_addresses(manifest.validator_addresses);
+        }
+        if let Some(seed) = manifest.seed {
+            builder = builder.seed(seed);
+        }
+        if let Some(epoch_length) = manifest.epoch_length {
+            builder = builder.epoch_length(epoch_length);
+        }
+        if manifest.no_extra_tokens {
+            builder = builder.no_extra_tokens();
+        }
+        for prefund in manifest.prefunds {
+            builder = builder.prefund(prefund.address, prefund.balance);
+        }
+        for token in manifest.tokens {
+            builder = builder.token(CustomToken {
+                name: token.name.unwrap_or_else(|| token.symbol.clone()),
+                currency: token.currency.unwrap_or_else(|| "USD".to_string()),
+                admin: token.admin,
+                address: token.address,
+                mint_amount: token.mint_amount.unwrap_or(U256::from(u64::MAX)),
+                symbol: token.symbol,
+            });
+        }
+        for entry in manifest.precompile_storage {
+            builder = builder.precompile_storage(entry.address, entry.slot, entry.value);
+        }
+        if let Some(url) = manifest.origin_rpc_url {
+            builder = builder.origin_rpc_url(url);
+        }
+        for escrow in manifest.escrows {
+            builder = builder.escrow(escrow.address, escrow.confirmations);
+        }
+
+        let bundle = builder.build().await.wrap_err("failed composing genesis")?;
+
+        std::fs::create_dir_all(&self.output).wrap_err_with(|| {
+            format!(
+                "failed creating output directory `{}`",
+                self.output.display()
+            )
+        })?;
+
+        let genesis_dst = self.output.join("genesis.json");
+        let json = serde_json::to_string_pretty(&bundle.genesis)
+            .wrap_err("failed encoding genesis as JSON")?;
+        std::fs::write(&genesis_dst, json)
+            .wrap_err_with(|| format!("failed writing genesis to `{}`", genesis_dst.display()))?;
+        println!("genesis written to `{}`", genesis_dst.display());
+
+        let bridge_dst = self.output.join("bridge.toml");
+        let bridge_toml = toml::to_string_pretty(&bundle.bridge_config)
+            .wrap_err("failed encoding bridge config as TOML")?;
+        std::fs::write(&bridge_dst, bridge_toml).wrap_err_with(|| {
+            format!("failed writing bridge config to `{}`", bridge_dst.display())
+        })?;
+        println!("bridge config written to `{}`", bridge_dst.display());
+
+        if let Some(consensus_config) = bundle.consensus_config {
+            write_validator_keys(&self.output, consensus_config)?;
+        } else {
+            println!("no validators configured; skipping key generation");
+        }
+
+        Ok(())
+    }
+}
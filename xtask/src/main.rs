@@ -2,9 +2,10 @@
 use std::net::SocketAddr;
 
 use crate::{
+    analyze_consensus::AnalyzeConsensus, compare_state_diffs::CompareStateDiffs,
     generate_devnet::GenerateDevnet, generate_genesis::GenerateGenesis,
     generate_localnet::GenerateLocalnet, generate_state_bloat::GenerateStateBloat,
-    get_dkg_outcome::GetDkgOutcome,
+    genesis_cmd::GenesisCmd, get_dkg_outcome::GetDkgOutcome, verify_audit_log::VerifyAuditLog,
 };
 
 use alloy::signers::{local::MnemonicBuilder, utils::secret_key_to_address};
@@ -12,12 +13,17 @@ use clap::Parser as _;
 use commonware_codec::DecodeExt;
 use eyre::Context;
 
+mod analyze_consensus;
+mod compare_state_diffs;
 mod generate_devnet;
 mod generate_genesis;
 mod generate_localnet;
 mod generate_state_bloat;
 mod genesis_args;
+mod genesis_builder;
+mod genesis_cmd;
 mod get_dkg_outcome;
+mod verify_audit_log;
 
 #[tokio::main]
 async fn main() -> eyre::Result<()> {
@@ -25,6 +31,10 @@ async fn main() -> eyre::Result<()> {
     match args.action {
         Action::GetDkgOutcome(args) => args.run().await.wrap_err("failed to get DKG outcome"),
         Action::GenerateGenesis(args) => args.run().await.wrap_err("failed generating genesis"),
+        Action::Genesis(args) => args
+            .run()
+            .await
+            .wrap_err("failed generating genesis from manifest"),
         Action::GenerateDevnet(args) => args
             .run()
             .await
@@ -38,6 +48,13 @@ async fn main() -> eyre::Result<()> {
             .run()
             .await
             .wrap_err("failed to generate state bloat file"),
+        Action::VerifyAuditLog(args) => args.run().wrap_err("failed to verify audit log"),
+        Action::AnalyzeConsensus(args) => {
+            args.run().wrap_err("failed to analyze consensus metrics")
+        }
+        Action::CompareStateDiffs(args) => {
+            args.run().wrap_err("failed to compare state diff exports")
+        }
     }
 }
 
@@ -55,10 +72,14 @@ struct Args {
 enum Action {
     GetDkgOutcome(GetDkgOutcome),
     GenerateGenesis(GenerateGenesis),
+    Genesis(GenesisCmd),
     GenerateDevnet(GenerateDevnet),
     GenerateLocalnet(GenerateLocalnet),
     GenerateAddPeer(GenerateAddPeer),
     GenerateStateBloat(GenerateStateBloat),
+    VerifyAuditLog(VerifyAuditLog),
+    AnalyzeConsensus(AnalyzeConsensus),
+    CompareStateDiffs(CompareStateDiffs),
 }
 
 #[derive(Debug, clap::Args)]
@@ -0,0 +1,39 @@
+//! Verifies a bridge signature audit log (`xtask verify-audit-log`).
+
+use eyre::Context as _;
+use std::{fs::File, path::PathBuf};
+use tempo_bridge::audit_log::verify_audit_log;
+
+/// Verifies the hash chain of a bridge signature audit log.
+///
+/// Checks that every record parses, that sequence numbers are gapless, that
+/// each record commits to the hash of its predecessor, and that timestamps
+/// never go backwards — i.e. that the log is exactly what the validator signed,
+/// in order, with nothing removed or edited.
+#[derive(Debug, clap::Args)]
+pub struct VerifyAuditLog {
+    /// Path to the audit log (one JSON record per line).
+    #[arg(long)]
+    path: PathBuf,
+}
+
+impl VerifyAuditLog {
+    pub fn run(self) -> eyre::Result<()> {
+        let file = File::open(&self.path)
+            .wrap_err_with(|| format!("failed to open audit log at {}", self.path.display()))?;
+
+        let summary = verify_audit_log(file).wrap_err("audit log verification failed")?;
+
+        if summary.records == 0 {
+            println!("audit log is empty (valid)");
+            return Ok(());
+        }
+
+        println!("audit log OK: {} records", summary.records);
+        println!("  head hash: {}", summary.head);
+        if let (Some(first), Some(last)) = (summary.first_timestamp, summary.last_timestamp) {
+            println!("  covers unix time {first} ..= {last}");
+        }
+        Ok(())
+    }
+}